@@ -47,11 +47,18 @@ fn multi_job_100_benchmark(c: &mut Criterion) {
     });
 }
 
+fn skills_100_benchmark(c: &mut Criterion) {
+    c.bench_function("a problem with 100 deliveries which require vehicle skills", |b| {
+        b.iter(|| solve_problem_with_max_generations("../data/pragmatic/benches/skills.100.json", black_box(100)))
+    });
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(15);
     targets = simple_deliveries_100_benchmark,
               simple_reload_100_benchmark,
-              multi_job_100_benchmark
+              multi_job_100_benchmark,
+              skills_100_benchmark
 }
 criterion_main!(benches);
@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/analyze_test.rs"]
+mod analyze_test;
+
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::analyze::analyze_pragmatic_matrices;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const MATRIX_ARG_NAME: &str = "matrix";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_analyze_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("analyze")
+        .about("Provides the way to analyze routing data for common issues")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for analysis report output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_analyze(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let matrix_files = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let result = match input_format {
+        "pragmatic" => analyze_pragmatic_matrices(matrix_files),
+        _ => Err(format!("unknown format: '{}'", input_format)),
+    };
+
+    match result {
+        Ok(reports) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let out_buffer = create_write_buffer(out_result);
+
+            if let Err(err) = serde_json::to_writer_pretty(out_buffer, &reports) {
+                eprintln!("Cannot write analysis report: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot analyze routing data: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
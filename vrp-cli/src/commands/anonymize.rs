@@ -0,0 +1,90 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/anonymize_test.rs"]
+mod anonymize_test;
+
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::anonymize::{anonymize_pragmatic_problem, AnonymizationOptions};
+use vrp_core::utils::{DefaultRandom, RandomAlgorithm};
+use vrp_pragmatic::format::problem::serialize_problem;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const DEMAND_PERTURBATION_ARG_NAME: &str = "demand-perturbation";
+const SEED_ARG_NAME: &str = "seed";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_anonymize_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("anonymize")
+        .about("Provides the way to anonymize problem, so it can be shared without leaking customer data")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(DEMAND_PERTURBATION_ARG_NAME)
+                .help("Specifies max relative change of job demands. Default is 0.1")
+                .long(DEMAND_PERTURBATION_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SEED_ARG_NAME)
+                .help("Specifies a seed of random number generator")
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for anonymized problem output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_anonymize(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let problem_file = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let options = AnonymizationOptions {
+        demand_perturbation: parse_float_value::<f64>(matches, DEMAND_PERTURBATION_ARG_NAME, "demand perturbation")
+            .unwrap_or_else(|| AnonymizationOptions::default().demand_perturbation),
+    };
+    let seed = parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed");
+    let random = DefaultRandom::new_with_known_seed(RandomAlgorithm::default(), seed);
+
+    let result = match input_format {
+        "pragmatic" => anonymize_pragmatic_problem(problem_file, &options, &random),
+        _ => Err(format!("unknown format: '{}'", input_format)),
+    };
+
+    match result {
+        Ok(problem) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let out_buffer = create_write_buffer(out_result);
+
+            if let Err(err) = serialize_problem(out_buffer, &problem) {
+                eprintln!("Cannot write anonymized problem: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot anonymize problem: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/export_test.rs"]
+mod export_test;
+
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::export::{export_navigation, OsrmApi, RoutingApi, ValhallaApi};
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const ROUTING_API_ARG_NAME: &str = "routing-api";
+const ROUTING_URL_ARG_NAME: &str = "routing-url";
+const ROUTING_PROFILE_ARG_NAME: &str = "routing-profile";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_export_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("export")
+        .about("Provides the way to export solution as turn-by-turn navigation documents using routing service")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file with geo coordinates")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ROUTING_API_ARG_NAME)
+                .help("Specifies routing service api")
+                .long(ROUTING_API_ARG_NAME)
+                .required(true)
+                .possible_values(&["osrm", "valhalla"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ROUTING_URL_ARG_NAME)
+                .help("Specifies routing service url, e.g. http://localhost:5000")
+                .long(ROUTING_URL_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ROUTING_PROFILE_ARG_NAME)
+                .help("Specifies routing profile (OSRM) or costing model (Valhalla). Default is driving and auto")
+                .long(ROUTING_PROFILE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for navigation documents output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_export(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let solution_file = BufReader::new(open_file(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let routing_url = matches.value_of(ROUTING_URL_ARG_NAME).unwrap();
+    let routing_profile = matches.value_of(ROUTING_PROFILE_ARG_NAME);
+
+    let routing_api: Box<dyn RoutingApi> = match matches.value_of(ROUTING_API_ARG_NAME).unwrap() {
+        "osrm" => Box::new(OsrmApi::new(routing_url, routing_profile.unwrap_or("driving"))),
+        "valhalla" => Box::new(ValhallaApi::new(routing_url, routing_profile.unwrap_or("auto"))),
+        api => {
+            eprintln!("unknown routing api: '{}'", api);
+            process::exit(1);
+        }
+    };
+
+    let result = match input_format {
+        "pragmatic" => deserialize_solution(solution_file)
+            .map_err(|err| format!("cannot read solution: '{}'", err))
+            .and_then(|solution| export_navigation(&solution, routing_api.as_ref())),
+        _ => Err(format!("unknown format: '{}'", input_format)),
+    };
+
+    match result {
+        Ok(documents) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let out_buffer = create_write_buffer(out_result);
+
+            if let Err(err) = serde_json::to_writer_pretty(out_buffer, &documents) {
+                eprintln!("Cannot write navigation documents: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot export solution: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use clap::{App, Arg, ArgMatches, Values};
 
+pub mod analyze;
+pub mod anonymize;
 pub mod check;
+pub mod export;
 pub mod generate;
 pub mod import;
 pub mod solve;
@@ -25,6 +28,13 @@ fn open_file(path: &str, description: &str) -> File {
     })
 }
 
+fn read_file(path: &str, description: &str) -> String {
+    std::fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("Cannot read {} file '{}': '{}'", description, path, err);
+        process::exit(1);
+    })
+}
+
 fn create_file(path: &str, description: &str) -> File {
     File::create(path).unwrap_or_else(|err| {
         eprintln!("Cannot create {} file '{}': '{}'", description, path, err.to_string());
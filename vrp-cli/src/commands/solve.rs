@@ -11,12 +11,16 @@ use std::process;
 use std::sync::Arc;
 use vrp_cli::core::solver::population::{get_default_population, Population};
 use vrp_cli::extensions::check::check_pragmatic_solution;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::config::{
+    create_builder_from_config_file, create_mutation_tracer, create_progress_sink,
+};
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
+use vrp_core::models::problem::get_problem_statistics;
 use vrp_core::models::{Problem, Solution};
 use vrp_core::solver::population::{get_default_selection_size, Elitism};
 use vrp_core::solver::{Builder, Metrics, Telemetry, TelemetryMode};
-use vrp_core::utils::{DefaultRandom, Environment, Parallelism, Random};
+use vrp_core::utils::{DefaultRandom, Environment, Parallelism, ParallelismPhase, Random, RandomAlgorithm};
+use vrp_pragmatic::format::solution::Metadata;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -34,6 +38,17 @@ const LOG_ARG_NAME: &str = "log";
 const CHECK_ARG_NAME: &str = "check";
 const SEARCH_MODE_ARG_NAME: &str = "search-mode";
 const PARALELLISM_ARG_NAME: &str = "parallelism";
+const SOLVER_THREADS_ARG_NAME: &str = "solver-threads";
+const PHASE_THREADS_ARG_NAME: &str = "phase-threads";
+const STATISTICS_ARG_NAME: &str = "problem-statistics";
+const PARETO_ARG_NAME: &str = "pareto";
+const SEED_ARG_NAME: &str = "seed";
+const DETERMINISTIC_ARG_NAME: &str = "deterministic";
+const PROGRESS_ARG_NAME: &str = "progress";
+const TRACE_ARG_NAME: &str = "trace";
+const ALTERNATIVE_ARG_NAME: &str = "alternative";
+const OUT_ALTERNATIVE_ARG_NAME: &str = "out-alternative";
+const GEOMETRY_ARG_NAME: &str = "geometry";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
@@ -44,7 +59,7 @@ struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Solution,
 struct SolutionWriter(
     pub  Box<
         dyn Fn(
-            &Problem,
+            &Arc<Problem>,
             Solution,
             Option<Metrics>,
             BufWriter<Box<dyn Write>>,
@@ -57,13 +72,26 @@ struct SolutionWriter(
 struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
 
 #[allow(clippy::type_complexity)]
-type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)>;
+struct ParetoWriter(
+    pub Box<dyn Fn(&Arc<Problem>, Vec<(Solution, Vec<f64>)>, BufWriter<Box<dyn Write>>) -> Result<(), String>>,
+);
+
+fn create_unsupported_pareto_writer() -> ParetoWriter {
+    ParetoWriter(Box::new(|_, _, _| Err("pareto output is supported only for pragmatic format".to_string())))
+}
+
+#[allow(clippy::type_complexity)]
+type FormatMap<'a> =
+    HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter, ParetoWriter)>;
 
 fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
     if cfg!(feature = "scientific-format") {
+        use vrp_scientific::hfvrp::{HfvrpProblem, HfvrpSolution};
+        use vrp_scientific::lilim::read_init_solution as read_init_lilim;
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
         use vrp_scientific::solomon::read_init_solution as read_init_solomon;
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
+        use vrp_scientific::tsplib::{TsplibProblem, TsplibSolution};
 
         formats.insert(
             "solomon",
@@ -72,11 +100,15 @@ fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_solomon()
                 })),
-                InitSolutionReader(Box::new(move |file, problem| {
-                    read_init_solomon(BufReader::new(file), problem, random.clone())
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem| read_init_solomon(BufReader::new(file), problem, random.clone())
+                })),
+                SolutionWriter(Box::new(|problem, solution, _, writer, _| {
+                    solution.write_solomon_with_summary(problem, writer)
                 })),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_solomon(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
+                create_unsupported_pareto_writer(),
             ),
         );
         formats.insert(
@@ -86,9 +118,38 @@ fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_lilim()
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
+                InitSolutionReader(Box::new(move |file, problem| {
+                    read_init_lilim(BufReader::new(file), problem, random.clone())
+                })),
                 SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_lilim(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
+                create_unsupported_pareto_writer(),
+            ),
+        );
+        formats.insert(
+            "hfvrp",
+            (
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_hfvrp()
+                })),
+                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
+                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_hfvrp(writer))),
+                LocationWriter(Box::new(|_, _| unimplemented!())),
+                create_unsupported_pareto_writer(),
+            ),
+        );
+        formats.insert(
+            "tsplib",
+            (
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_tsplib()
+                })),
+                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
+                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_tsplib(writer))),
+                LocationWriter(Box::new(|_, _| unimplemented!())),
+                create_unsupported_pareto_writer(),
             ),
         );
     }
@@ -97,7 +158,7 @@ fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>
 fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
-    use vrp_pragmatic::format::solution::PragmaticSolution;
+    use vrp_pragmatic::format::solution::{check_must_serve_jobs, write_pareto_json, PragmaticSolution};
 
     formats.insert(
         "pragmatic",
@@ -115,8 +176,11 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
             })),
             SolutionWriter(Box::new(|problem, solution, metrics, default_writer, geojson_writer| {
-                geojson_writer
-                    .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
+                check_must_serve_jobs(problem.clone(), &solution)
+                    .map_err(|err| err.to_string())
+                    .and_then(|_| {
+                        geojson_writer.map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
+                    })
                     .and_then(|_| {
                         if let Some(metrics) = metrics {
                             (solution, metrics).write_pragmatic_json(problem, default_writer)
@@ -132,6 +196,9 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
                     .and_then(|problem| get_locations_serialized(&problem))
                     .and_then(|locations| writer.write_all(locations.as_bytes()).map_err(|err| err.to_string()))
             })),
+            ParetoWriter(Box::new(|problem, solutions, writer| {
+                write_pareto_json(problem, solutions.as_slice(), writer)
+            })),
         ),
     );
 }
@@ -152,7 +219,7 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
             Arg::with_name(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .possible_values(&["solomon", "lilim", "pragmatic"])
+                .possible_values(&["solomon", "lilim", "hfvrp", "tsplib", "pragmatic"])
                 .index(1),
         )
         .arg(Arg::with_name(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
@@ -259,6 +326,98 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(SOLVER_THREADS_ARG_NAME)
+                .help("Specifies amount of threads in dedicated solver thread pool. Use 1 to run on a single thread")
+                .long(SOLVER_THREADS_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .conflicts_with(CONFIG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(PHASE_THREADS_ARG_NAME)
+                .help(
+                    "Specifies amount of threads in dedicated thread pools of solver phases in format \
+                     \"construction,mutation,evaluation\"",
+                )
+                .long(PHASE_THREADS_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .conflicts_with(CONFIG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(STATISTICS_ARG_NAME)
+                .help("Specifies whether problem statistics should be logged before solving")
+                .long(STATISTICS_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name(PARETO_ARG_NAME)
+                .help("Specifies whether all non-dominated solutions should be written instead of the best one")
+                .long(PARETO_ARG_NAME)
+                .required(false)
+                .takes_value(false)
+                .conflicts_with_all(&[CHECK_ARG_NAME, GEO_JSON_ARG_NAME]),
+        )
+        .arg(
+            Arg::with_name(SEED_ARG_NAME)
+                .help("Specifies a seed of random number generator. Use config to set it together with other settings")
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .conflicts_with(CONFIG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(DETERMINISTIC_ARG_NAME)
+                .help(
+                    "Specifies whether search should be reproducible for the same seed regardless of thread scheduling",
+                )
+                .long(DETERMINISTIC_ARG_NAME)
+                .required(false)
+                .takes_value(false)
+                .conflicts_with(CONFIG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(PROGRESS_ARG_NAME)
+                .help("Specifies path to search progress output in csv format (json lines if path ends with .json)")
+                .long(PROGRESS_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .conflicts_with(CONFIG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(TRACE_ARG_NAME)
+                .help("Specifies path to mutation trace output in csv format. Intended for search analysis only")
+                .long(TRACE_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .conflicts_with(CONFIG_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(ALTERNATIVE_ARG_NAME)
+                .help("Specifies minimum amount of job reassignments for alternative solution output")
+                .long(ALTERNATIVE_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(OUT_ALTERNATIVE_ARG_NAME)
+                .conflicts_with(PARETO_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(OUT_ALTERNATIVE_ARG_NAME)
+                .help("Specifies path to file for alternative solution output")
+                .long(OUT_ALTERNATIVE_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .requires(ALTERNATIVE_ARG_NAME),
+        )
+        .arg(
+            Arg::with_name(GEOMETRY_ARG_NAME)
+                .help("Specifies whether route geometry metrics should be written into solution extras")
+                .long(GEOMETRY_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
 }
 
 /// Runs solver commands.
@@ -275,7 +434,8 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
     // optional
     let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations");
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time");
-    let telemetry = Telemetry::new(if matches.is_present(LOG_ARG_NAME) {
+    let is_log_enabled = matches.is_present(LOG_ARG_NAME);
+    let telemetry = Telemetry::new(if is_log_enabled {
         TelemetryMode::OnlyLogging {
             logger: Arc::new(|msg| println!("{}", msg)),
             log_best: 100,
@@ -285,19 +445,41 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
     } else {
         TelemetryMode::None
     });
+    let telemetry = match matches.value_of(PROGRESS_ARG_NAME) {
+        Some(path) => {
+            let format = if path.ends_with(".json") { "json" } else { "csv" };
+            telemetry.with_progress_sink(create_progress_sink(path, Some(format)).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            }))
+        }
+        None => telemetry,
+    };
+    let tracer = matches.value_of(TRACE_ARG_NAME).map(|path| {
+        create_mutation_tracer(path).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        })
+    });
     let is_check_requested = matches.is_present(CHECK_ARG_NAME);
+    let is_statistics_requested = matches.is_present(STATISTICS_ARG_NAME);
+    let is_pareto_requested = matches.is_present(PARETO_ARG_NAME);
+    let is_geometry_requested = matches.is_present(GEOMETRY_ARG_NAME);
+    let min_reassignments = parse_int_value::<usize>(matches, ALTERNATIVE_ARG_NAME, "alternative reassignments");
 
     let cost_variation = get_cost_variation(matches);
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
-    let config = matches.value_of(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
+    let config = matches.value_of(CONFIG_ARG_NAME).map(|path| read_file(path, "config"));
     let matrix_files = get_matrix_files(matches);
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let out_alternative =
+        matches.value_of(OUT_ALTERNATIVE_ARG_NAME).map(|path| create_file(path, "out alternative solution"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let mode = matches.value_of(SEARCH_MODE_ARG_NAME);
 
     match formats.get(problem_format) {
-        Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
+        Some((problem_reader, init_reader, solution_writer, locations_writer, pareto_writer)) => {
             let out_buffer = out_writer_func(out_result);
             let geo_buffer = out_geojson.map(|geojson| create_write_buffer(Some(geojson)));
 
@@ -310,6 +492,11 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
                 match problem_reader.0(problem_file, matrix_files) {
                     Ok(problem) => {
                         let problem = Arc::new(problem);
+
+                        if is_statistics_requested {
+                            println!("problem statistics: {}", get_problem_statistics(problem.as_ref()));
+                        }
+
                         let solutions = init_solution.map_or_else(Vec::new, |file| {
                             init_reader.0(file, problem.clone())
                                 .map_err(|err| {
@@ -320,13 +507,13 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
                                 .unwrap()
                         });
 
+                        let config_hash = config.as_ref().map(|config| get_hash(config.as_bytes()));
                         let builder = if let Some(config) = config {
-                            create_builder_from_config_file(problem.clone(), BufReader::new(config)).unwrap_or_else(
-                                |err| {
+                            create_builder_from_config_file(problem.clone(), BufReader::new(config.as_bytes()))
+                                .unwrap_or_else(|err| {
                                     eprintln!("cannot read config: '{}'", err);
                                     process::exit(1);
-                                },
-                            )
+                                })
                         } else {
                             Builder::new(problem.clone(), environment.clone())
                                 .with_max_generations(max_generations)
@@ -335,17 +522,76 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
                                 .with_population(get_population(mode, problem.clone(), environment))
                                 .with_telemetry(telemetry)
                         };
+                        let builder = builder.with_mutation_tracer(tracer);
+
+                        let metadata = create_metadata(builder.config.environment.as_ref(), config_hash);
+                        let solver = builder.with_init_solutions(solutions).build().unwrap_or_else(|err| {
+                            eprintln!("cannot build solver: '{}'", err);
+                            process::exit(1);
+                        });
 
-                        let (solution, _, metrics) = builder
-                            .with_init_solutions(solutions)
-                            .build()
-                            .and_then(|solver| solver.solve())
-                            .unwrap_or_else(|err| {
+                        if is_pareto_requested {
+                            let (solutions, _) = solver.solve_pareto().unwrap_or_else(|err| {
                                 eprintln!("cannot find any solution: '{}'", err);
                                 process::exit(1);
                             });
 
-                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer).unwrap();
+                            let solutions = solutions
+                                .into_iter()
+                                .map(|(solution, _, fitness)| {
+                                    (add_extras(solution, &metadata, is_geometry_requested), fitness)
+                                })
+                                .collect();
+                            pareto_writer.0(&problem, solutions, out_buffer).unwrap_or_else(|err| {
+                                eprintln!("cannot write solutions: '{}'", err);
+                                process::exit(1);
+                            });
+
+                            return;
+                        }
+
+                        let (solution, alternative, metrics) = if let Some(min_reassignments) = min_reassignments {
+                            solver.solve_with_alternative(min_reassignments).map(
+                                |((solution, _), alternative, metrics)| {
+                                    (solution, alternative.map(|(solution, _)| solution), metrics)
+                                },
+                            )
+                        } else {
+                            solver.solve().map(|(solution, _, metrics)| (solution, None, metrics))
+                        }
+                        .unwrap_or_else(|err| {
+                            eprintln!("cannot find any solution: '{}'", err);
+                            process::exit(1);
+                        });
+                        let solution = add_extras(solution, &metadata, is_geometry_requested);
+
+                        if is_log_enabled {
+                            log_best_known_gap(problem_format, problem_path, problem.as_ref(), &solution);
+                        }
+
+                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer).unwrap_or_else(|err| {
+                            eprintln!("cannot write solution: '{}'", err);
+                            process::exit(1);
+                        });
+
+                        if let (Some(min_reassignments), Some(out_alternative)) = (min_reassignments, out_alternative) {
+                            match alternative {
+                                Some(alternative) => {
+                                    let alternative = add_extras(alternative, &metadata, is_geometry_requested);
+                                    let out_buffer = create_write_buffer(Some(out_alternative));
+                                    solution_writer.0(&problem, alternative, None, out_buffer, None).unwrap_or_else(
+                                        |err| {
+                                            eprintln!("cannot write alternative solution: '{}'", err);
+                                            process::exit(1);
+                                        },
+                                    );
+                                }
+                                None => eprintln!(
+                                    "cannot find alternative solution with at least {} job reassignments in final population",
+                                    min_reassignments
+                                ),
+                            }
+                        }
 
                         if is_check_requested {
                             check_solution(matches);
@@ -365,6 +611,48 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
     }
 }
 
+fn create_metadata(environment: &Environment, config_hash: Option<String>) -> Metadata {
+    Metadata {
+        seed: environment.random.seed(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        config_hash,
+        threads: environment.parallelism.available_cpus(),
+    }
+}
+
+fn add_extras(solution: Solution, metadata: &Metadata, is_geometry_requested: bool) -> Solution {
+    let mut extras = solution.extras.as_ref().clone();
+    extras.insert("metadata".to_string(), Arc::new(metadata.clone()));
+    extras.insert("route_geometry".to_string(), Arc::new(is_geometry_requested));
+
+    Solution { extras: Arc::new(extras), ..solution }
+}
+
+/// Returns FNV-1a hash of given data which is stable across platforms and versions.
+fn get_hash(data: &[u8]) -> String {
+    let hash = data
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3));
+
+    format!("{:016x}", hash)
+}
+
+fn log_best_known_gap(problem_format: &str, problem_path: &str, problem: &Problem, solution: &Solution) {
+    if cfg!(feature = "scientific-format") {
+        use std::path::Path;
+        use vrp_scientific::best_known::{get_best_known_solution, get_solution_distance};
+
+        let name = Path::new(problem_path).file_stem().and_then(|name| name.to_str()).unwrap_or_default();
+        if let Some(best_known) = get_best_known_solution(problem_format, name) {
+            let gap = best_known.get_gap(solution.routes.len(), get_solution_distance(problem, solution));
+            println!(
+                "best known solution: vehicles: {}, distance: {:.2}, gap: vehicles: {}, distance: {:.2}%",
+                best_known.vehicles, best_known.distance, gap.vehicles, gap.distance
+            );
+        }
+    }
+}
+
 fn get_cost_variation(matches: &ArgMatches) -> Option<(usize, f64)> {
     matches.value_of(COST_VARIATION_ARG_NAME).map(|arg| {
         if let [sample, threshold] =
@@ -379,20 +667,61 @@ fn get_cost_variation(matches: &ArgMatches) -> Option<(usize, f64)> {
 }
 
 fn get_environment(matches: &ArgMatches) -> Arc<Environment> {
-    matches
+    let seed = parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed");
+    let random = Arc::new(DefaultRandom::new_with_known_seed(RandomAlgorithm::default(), seed));
+
+    let parallelism = matches
         .value_of(PARALELLISM_ARG_NAME)
         .map(|arg| {
             if let [num_thread_pools, threads_per_pool] =
                 arg.split(',').filter_map(|line| line.parse::<usize>().ok()).collect::<Vec<_>>().as_slice()
             {
-                let parallelism = Parallelism::new(*num_thread_pools, *threads_per_pool);
-                Arc::new(Environment::new(Arc::new(DefaultRandom::default()), parallelism))
+                Parallelism::new(*num_thread_pools, *threads_per_pool)
             } else {
                 eprintln!("cannot parse parallelism parameter");
                 process::exit(1);
             }
         })
-        .unwrap_or_else(|| Arc::new(Environment::default()))
+        .unwrap_or_default();
+
+    let parallelism = match parse_int_value::<usize>(matches, SOLVER_THREADS_ARG_NAME, "solver threads") {
+        Some(0) => {
+            eprintln!("amount of solver threads should be positive");
+            process::exit(1);
+        }
+        Some(num_threads) => parallelism.with_solver_threads(num_threads),
+        None => parallelism,
+    };
+
+    let parallelism = matches
+        .value_of(PHASE_THREADS_ARG_NAME)
+        .map(|arg| {
+            let phase_threads = arg.split(',').map(|line| line.parse::<usize>().ok()).collect::<Vec<_>>();
+            if let [Some(construction), Some(mutation), Some(evaluation)] = phase_threads.as_slice() {
+                vec![
+                    (ParallelismPhase::Construction, *construction),
+                    (ParallelismPhase::Mutation, *mutation),
+                    (ParallelismPhase::Evaluation, *evaluation),
+                ]
+            } else {
+                eprintln!("cannot parse phase threads parameter");
+                process::exit(1);
+            }
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .fold(parallelism, |parallelism, (phase, num_threads)| {
+            if num_threads == 0 {
+                eprintln!("amount of threads for {:?} phase should be positive", phase);
+                process::exit(1);
+            }
+            parallelism.with_phase_threads(phase, num_threads)
+        });
+
+    let mut environment = Environment::new(random, parallelism);
+    environment.is_deterministic = matches.is_present(DETERMINISTIC_ARG_NAME);
+
+    Arc::new(environment)
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
@@ -0,0 +1,185 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/matrix_test.rs"]
+mod matrix_test;
+
+use serde::Serialize;
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::problem::{deserialize_matrix, Matrix};
+use vrp_pragmatic::format::FormatError;
+
+/// Max amount of location pairs kept as examples of each issue.
+const MAX_EXAMPLES: usize = 10;
+/// Max amount of intermediate locations used to check triangle inequality.
+const MAX_INTERMEDIATE_LOCATIONS: usize = 100;
+/// A relative tolerance used to check triangle inequality.
+const TRIANGLE_TOLERANCE: f64 = 0.1;
+/// Max plausible distance in meters (about a half of earth circumference).
+const MAX_DISTANCE: i64 = 20_000_000;
+/// Max plausible duration in seconds (a week).
+const MAX_DURATION: i64 = 7 * 24 * 3600;
+/// Max plausible speed in meters per second.
+const MAX_SPEED: f64 = 70.;
+/// Min plausible speed in meters per second, used only for distances longer than `MIN_SPEED_DISTANCE`.
+const MIN_SPEED: f64 = 0.5;
+const MIN_SPEED_DISTANCE: i64 = 1000;
+
+/// Specifies location pairs which have the same issue.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MatrixIssues {
+    /// Amount of location pairs with the issue.
+    pub count: usize,
+    /// Some of location pairs (from, to) with the issue.
+    pub examples: Vec<(usize, usize)>,
+}
+
+/// Specifies asymmetry of travel times: a relative difference between travel times in opposite directions.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixAsymmetry {
+    /// Max asymmetry ratio.
+    pub max_ratio: f64,
+    /// Average asymmetry ratio.
+    pub average_ratio: f64,
+}
+
+/// A report of routing matrix analysis.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixReport {
+    /// A name of profile.
+    pub profile: Option<String>,
+    /// A date of routing matrix.
+    pub timestamp: Option<String>,
+    /// Amount of locations.
+    pub size: usize,
+    /// Travel times asymmetry.
+    pub asymmetry: MatrixAsymmetry,
+    /// Location pairs for which a detour via another location is significantly shorter or faster.
+    pub triangle_violations: MatrixIssues,
+    /// Location pairs with zero travel time or distance.
+    pub zero_entries: MatrixIssues,
+    /// Location pairs with negative or implausibly large travel time or distance.
+    pub absurd_entries: MatrixIssues,
+    /// Location pairs with implausible speed implied by distance and travel time.
+    pub speed_inconsistencies: MatrixIssues,
+}
+
+impl MatrixIssues {
+    fn add(&mut self, from: usize, to: usize) {
+        self.count += 1;
+        if self.examples.len() < MAX_EXAMPLES {
+            self.examples.push((from, to));
+        }
+    }
+}
+
+/// Analyzes routing matrices in pragmatic format.
+pub fn analyze_pragmatic_matrices<R: Read>(readers: Vec<BufReader<R>>) -> Result<Vec<MatrixReport>, String> {
+    readers
+        .into_iter()
+        .map(|reader| {
+            deserialize_matrix(reader)
+                .map_err(|errors| FormatError::format_many(errors.as_slice(), "\t\n"))
+                .and_then(|matrix| analyze_matrix(&matrix))
+        })
+        .collect()
+}
+
+/// Analyzes routing matrix for common data issues: asymmetry, triangle inequality violations,
+/// zero or absurd entries and inconsistencies between travel times and distances. Location pairs
+/// marked as unreachable by error codes are ignored.
+pub fn analyze_matrix(matrix: &Matrix) -> Result<MatrixReport, String> {
+    let length = matrix.travel_times.len();
+    let size = (length as f64).sqrt().round() as usize;
+
+    if size * size != length || matrix.distances.len() != length {
+        return Err(format!(
+            "matrix should be square and have the same amount of travel times and distances, profile: '{}'",
+            matrix.profile.clone().unwrap_or_default()
+        ));
+    }
+
+    let is_reachable = |from: usize, to: usize| {
+        matrix.error_codes.as_ref().map_or(true, |codes| codes.get(from * size + to).map_or(true, |code| *code == 0))
+    };
+    let pairs = || (0..size).flat_map(move |from| (0..size).map(move |to| (from, to))).filter(|(from, to)| from != to);
+
+    let mut zero_entries = MatrixIssues::default();
+    let mut absurd_entries = MatrixIssues::default();
+    let mut speed_inconsistencies = MatrixIssues::default();
+    let mut triangle_violations = MatrixIssues::default();
+
+    pairs().filter(|&(from, to)| is_reachable(from, to)).for_each(|(from, to)| {
+        let duration = matrix.travel_times[from * size + to];
+        let distance = matrix.distances[from * size + to];
+
+        if duration == 0 || distance == 0 {
+            zero_entries.add(from, to);
+        }
+
+        if duration < 0 || distance < 0 || duration > MAX_DURATION || distance > MAX_DISTANCE {
+            absurd_entries.add(from, to);
+        }
+
+        if duration > 0 && distance > 0 {
+            let speed = distance as f64 / duration as f64;
+            if speed > MAX_SPEED || (distance > MIN_SPEED_DISTANCE && speed < MIN_SPEED) {
+                speed_inconsistencies.add(from, to);
+            }
+        }
+
+        if has_triangle_violation(&matrix.travel_times, size, from, to, &is_reachable)
+            || has_triangle_violation(&matrix.distances, size, from, to, &is_reachable)
+        {
+            triangle_violations.add(from, to);
+        }
+    });
+
+    let ratios = pairs()
+        .filter(|&(from, to)| from < to && is_reachable(from, to) && is_reachable(to, from))
+        .map(|(from, to)| (matrix.travel_times[from * size + to], matrix.travel_times[to * size + from]))
+        .filter(|&(forward, backward)| forward > 0 && backward > 0)
+        .map(|(forward, backward)| (forward - backward).abs() as f64 / forward.max(backward) as f64)
+        .collect::<Vec<_>>();
+
+    let asymmetry = MatrixAsymmetry {
+        max_ratio: ratios.iter().cloned().fold(0., f64::max),
+        average_ratio: if ratios.is_empty() { 0. } else { ratios.iter().sum::<f64>() / ratios.len() as f64 },
+    };
+
+    Ok(MatrixReport {
+        profile: matrix.profile.clone(),
+        timestamp: matrix.timestamp.clone(),
+        size,
+        asymmetry,
+        triangle_violations,
+        zero_entries,
+        absurd_entries,
+        speed_inconsistencies,
+    })
+}
+
+fn has_triangle_violation(
+    values: &[i64],
+    size: usize,
+    from: usize,
+    to: usize,
+    is_reachable: &impl Fn(usize, usize) -> bool,
+) -> bool {
+    let direct = values[from * size + to];
+    if direct <= 0 {
+        return false;
+    }
+
+    let step = (size / MAX_INTERMEDIATE_LOCATIONS).max(1);
+
+    (0..size).step_by(step).filter(|&via| via != from && via != to).any(|via| {
+        if !is_reachable(from, via) || !is_reachable(via, to) {
+            return false;
+        }
+
+        let (first, second) = (values[from * size + via], values[via * size + to]);
+
+        first >= 0 && second >= 0 && direct as f64 > (first + second) as f64 * (1. + TRIANGLE_TOLERANCE)
+    })
+}
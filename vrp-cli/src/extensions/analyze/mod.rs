@@ -0,0 +1,4 @@
+//! Analyze command helpers.
+
+mod matrix;
+pub use self::matrix::*;
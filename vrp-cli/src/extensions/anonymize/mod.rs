@@ -0,0 +1,348 @@
+//! Anonymize command helpers.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/anonymize/anonymize_test.rs"]
+mod anonymize_test;
+
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use vrp_core::utils::Random;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::{FormatError, Location};
+
+/// Specifies anonymization parameters.
+pub struct AnonymizationOptions {
+    /// Max relative change of each demand value, e.g. 0.1 means that demand is changed up to 10%.
+    pub demand_perturbation: f64,
+}
+
+impl Default for AnonymizationOptions {
+    fn default() -> Self {
+        Self { demand_perturbation: 0.1 }
+    }
+}
+
+/// Anonymizes pragmatic problem read from the given reader.
+pub fn anonymize_pragmatic_problem<R: Read>(
+    reader: BufReader<R>,
+    options: &AnonymizationOptions,
+    random: &(dyn Random + Send + Sync),
+) -> Result<Problem, String> {
+    let problem = deserialize_problem(reader).map_err(|errors| FormatError::format_many(errors.as_slice(), "\t\n"))?;
+
+    Ok(anonymize_problem(&problem, options, random))
+}
+
+/// Anonymizes pragmatic problem preserving its structure, so it can be shared without leaking
+/// customer data:
+///
+/// - geo coordinates are moved to a random place and rotated by a random angle around their centroid,
+///   so distances between locations are approximately preserved;
+/// - job, vehicle, vehicle type ids, skills and tags are renamed consistently;
+/// - non-zero demands are randomly perturbed within given ratio.
+///
+/// Times, durations, capacities and routing profiles are kept as is, so the same routing matrix
+/// can be used with the anonymized problem if locations are specified by index references.
+pub fn anonymize_problem(
+    problem: &Problem,
+    options: &AnonymizationOptions,
+    random: &(dyn Random + Send + Sync),
+) -> Problem {
+    let mut anonymizer = Anonymizer::new(problem, options, random);
+    let mut problem = problem.clone();
+
+    problem.plan.jobs.iter_mut().for_each(|job| anonymizer.anonymize_job(job));
+    problem.plan.relations.iter_mut().flatten().for_each(|relation| {
+        relation.jobs.iter_mut().for_each(|id| *id = anonymizer.rename_job(id));
+        relation.vehicle_id = anonymizer.rename(IdKind::Vehicle, relation.vehicle_id.as_str());
+    });
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| anonymizer.anonymize_vehicle(vehicle));
+
+    problem
+        .objectives
+        .iter_mut()
+        .flat_map(|objectives| objectives.primary.iter_mut().chain(objectives.secondary.iter_mut().flatten()))
+        .for_each(|objective| {
+            if let Objective::MinimizeDriverChanges { previous, .. } = objective {
+                previous.iter_mut().for_each(|assignment| {
+                    assignment.vehicle_id = anonymizer.rename(IdKind::Vehicle, assignment.vehicle_id.as_str());
+                    assignment.jobs.iter_mut().for_each(|id| *id = anonymizer.rename_job(id));
+                });
+            }
+        });
+
+    problem
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+enum IdKind {
+    Job,
+    Vehicle,
+    VehicleType,
+    Skill,
+    Tag,
+}
+
+impl IdKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            IdKind::Job => "job",
+            IdKind::Vehicle => "vehicle",
+            IdKind::VehicleType => "type",
+            IdKind::Skill => "skill",
+            IdKind::Tag => "tag",
+        }
+    }
+}
+
+struct Anonymizer<'a> {
+    random: &'a (dyn Random + Send + Sync),
+    demand_perturbation: f64,
+    ids: HashMap<(IdKind, String), String>,
+    counters: HashMap<IdKind, usize>,
+    /// Centroid of original coordinates as (latitude, longitude).
+    origin: (f64, f64),
+    /// A new place of centroid as (latitude, longitude).
+    target: (f64, f64),
+    /// Rotation angle in radians.
+    angle: f64,
+}
+
+impl<'a> Anonymizer<'a> {
+    fn new(problem: &Problem, options: &AnonymizationOptions, random: &'a (dyn Random + Send + Sync)) -> Self {
+        let coordinates = get_coordinates(problem);
+        let origin = if coordinates.is_empty() {
+            (0., 0.)
+        } else {
+            let (lat, lng) = coordinates.iter().fold((0., 0.), |(lat, lng), (x, y)| (lat + x, lng + y));
+            (lat / coordinates.len() as f64, lng / coordinates.len() as f64)
+        };
+
+        Self {
+            random,
+            demand_perturbation: options.demand_perturbation.max(0.),
+            ids: Default::default(),
+            counters: Default::default(),
+            origin,
+            target: (random.uniform_real(-50., 50.), random.uniform_real(-170., 170.)),
+            angle: random.uniform_real(0., 2. * std::f64::consts::PI),
+        }
+    }
+
+    fn anonymize_job(&mut self, job: &mut Job) {
+        job.id = self.rename(IdKind::Job, job.id.as_str());
+
+        let ratio = self.random.uniform_real(1. - self.demand_perturbation, 1. + self.demand_perturbation);
+        perturb_demands(job, ratio);
+
+        job.pickups
+            .iter_mut()
+            .chain(job.deliveries.iter_mut())
+            .chain(job.replacements.iter_mut())
+            .chain(job.services.iter_mut())
+            .flatten()
+            .for_each(|task| {
+                task.places.iter_mut().for_each(|place| place.location = self.transform(&place.location));
+                task.tag = task.tag.as_ref().map(|tag| self.rename(IdKind::Tag, tag));
+            });
+
+        if let Some(skills) = job.skills.as_mut() {
+            skills
+                .all_of
+                .iter_mut()
+                .chain(skills.one_of.iter_mut())
+                .chain(skills.none_of.iter_mut())
+                .flatten()
+                .for_each(|skill| *skill = self.rename(IdKind::Skill, skill));
+        }
+    }
+
+    fn anonymize_vehicle(&mut self, vehicle: &mut VehicleType) {
+        vehicle.type_id = self.rename(IdKind::VehicleType, vehicle.type_id.as_str());
+        vehicle.vehicle_ids.iter_mut().for_each(|id| *id = self.rename(IdKind::Vehicle, id));
+        vehicle.skills.iter_mut().flatten().for_each(|skill| *skill = self.rename(IdKind::Skill, skill));
+
+        vehicle
+            .costs
+            .zone_fees
+            .iter_mut()
+            .flatten()
+            .for_each(|zone| zone.outer_shape.iter_mut().for_each(|location| *location = self.transform(location)));
+
+        vehicle.shifts.iter_mut().for_each(|shift| {
+            shift.start.location = self.transform(&shift.start.location);
+            if let Some(driver) = shift.start.driver.as_mut() {
+                driver.location = self.transform(&driver.location);
+            }
+            if let Some(end) = shift.end.as_mut() {
+                end.location = self.transform(&end.location);
+            }
+
+            shift.dispatch.iter_mut().flatten().for_each(|dispatch| {
+                dispatch.location = self.transform(&dispatch.location);
+                dispatch.tag = dispatch.tag.as_ref().map(|tag| self.rename(IdKind::Tag, tag));
+            });
+            shift
+                .breaks
+                .iter_mut()
+                .flatten()
+                .flat_map(|vehicle_break| vehicle_break.locations.iter_mut().flatten())
+                .for_each(|location| *location = self.transform(location));
+            shift.unavailability.iter_mut().flatten().for_each(|unavailability| {
+                unavailability.location = self.transform(&unavailability.location);
+                unavailability.tag = unavailability.tag.as_ref().map(|tag| self.rename(IdKind::Tag, tag));
+            });
+            shift.reloads.iter_mut().flatten().for_each(|reload| {
+                reload.location = self.transform(&reload.location);
+                reload.tag = reload.tag.as_ref().map(|tag| self.rename(IdKind::Tag, tag));
+            });
+        });
+
+        vehicle.trailer.iter_mut().flat_map(|trailer| trailer.parkings.iter_mut()).for_each(|parking| {
+            parking.location = self.transform(&parking.location);
+            parking.tag = parking.tag.as_ref().map(|tag| self.rename(IdKind::Tag, tag));
+        });
+
+        vehicle
+            .limits
+            .iter_mut()
+            .flat_map(|limits| limits.allowed_areas.iter_mut().flatten())
+            .for_each(|area| area.outer_shape.iter_mut().for_each(|location| *location = self.transform(location)));
+    }
+
+    /// Returns a new name for the given id. The same id of the same kind always gets the same name.
+    fn rename(&mut self, kind: IdKind, id: &str) -> String {
+        let counters = &mut self.counters;
+
+        self.ids
+            .entry((kind, id.to_string()))
+            .or_insert_with(|| {
+                let counter = counters.entry(kind).or_insert(0);
+                *counter += 1;
+                format!("{}{}", kind.prefix(), counter)
+            })
+            .clone()
+    }
+
+    /// Returns a new name for the given job id keeping ids reserved for special activities as is.
+    fn rename_job(&mut self, id: &str) -> String {
+        match id {
+            "departure" | "arrival" | "break" | "reload" | "dispatch" | "unavailability" => id.to_string(),
+            _ => self.rename(IdKind::Job, id),
+        }
+    }
+
+    /// Transforms geo coordinate keeping index references as is.
+    fn transform(&self, location: &Location) -> Location {
+        match location {
+            Location::Coordinate { lat, lng } => {
+                let (origin_lat, origin_lng) = self.origin;
+                let (target_lat, target_lng) = self.target;
+
+                // NOTE use local planar approximation to keep distances between locations
+                let x = (lng - origin_lng) * origin_lat.to_radians().cos();
+                let y = lat - origin_lat;
+
+                let (sin, cos) = self.angle.sin_cos();
+                let (x, y) = (x * cos - y * sin, x * sin + y * cos);
+
+                Location::Coordinate {
+                    lat: target_lat + y,
+                    lng: wrap_longitude(target_lng + x / target_lat.to_radians().cos()),
+                }
+            }
+            Location::Reference { .. } => location.clone(),
+        }
+    }
+}
+
+/// Changes job demands using given ratio. Demands do not vanish and, for jobs with pickups and
+/// deliveries, total picked up and delivered amounts are kept equal.
+fn perturb_demands(job: &mut Job, ratio: f64) {
+    let original = job.clone();
+    job.pickups
+        .iter_mut()
+        .chain(job.deliveries.iter_mut())
+        .chain(job.replacements.iter_mut())
+        .chain(job.services.iter_mut())
+        .flatten()
+        .flat_map(|task| task.demand.iter_mut().flatten())
+        .for_each(|value| {
+            *value = match (*value as f64 * ratio).round() as i32 {
+                0 => value.signum(),
+                perturbed => perturbed,
+            }
+        });
+
+    let get_totals = |tasks: &Option<Vec<JobTask>>| {
+        tasks.iter().flatten().filter_map(|task| task.demand.as_ref()).fold(vec![], |mut totals, demand| {
+            totals.resize(totals.len().max(demand.len()), 0);
+            demand.iter().enumerate().for_each(|(idx, value)| totals[idx] += value);
+            totals
+        })
+    };
+
+    if job.pickups.is_none() || job.deliveries.is_none() {
+        return;
+    }
+
+    // NOTE rounding can make totals different, so last delivery compensates the difference
+    let pickups = get_totals(&job.pickups);
+    let deliveries = get_totals(&job.deliveries);
+    let original_last = original.deliveries.iter().flatten().filter_map(|task| task.demand.as_ref()).next_back();
+    let last = job.deliveries.iter_mut().flatten().filter_map(|task| task.demand.as_mut()).next_back();
+
+    let is_balanced = match (last, original_last) {
+        (Some(last), Some(original_last)) if pickups.len() == deliveries.len() && last.len() == deliveries.len() => {
+            last.iter_mut().zip(pickups.iter().zip(deliveries.iter())).for_each(|(value, (p, d))| *value += p - d);
+            last.iter().zip(original_last.iter()).all(|(value, original)| value.signum() == original.signum())
+        }
+        _ => false,
+    };
+
+    if !is_balanced {
+        job.pickups = original.pickups;
+        job.deliveries = original.deliveries;
+        job.replacements = original.replacements;
+        job.services = original.services;
+    }
+}
+
+fn wrap_longitude(lng: f64) -> f64 {
+    if lng > 180. {
+        lng - 360.
+    } else if lng < -180. {
+        lng + 360.
+    } else {
+        lng
+    }
+}
+
+/// Returns all job and vehicle geo coordinates as (latitude, longitude) pairs.
+fn get_coordinates(problem: &Problem) -> Vec<(f64, f64)> {
+    let job_locations = problem.plan.jobs.iter().flat_map(|job| {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .chain(job.replacements.iter())
+            .chain(job.services.iter())
+            .flatten()
+            .flat_map(|task| task.places.iter().map(|place| &place.location))
+    });
+
+    let vehicle_locations = problem.fleet.vehicles.iter().flat_map(|vehicle| {
+        vehicle
+            .shifts
+            .iter()
+            .flat_map(|shift| std::iter::once(&shift.start.location).chain(shift.end.iter().map(|end| &end.location)))
+    });
+
+    job_locations
+        .chain(vehicle_locations)
+        .filter_map(|location| match location {
+            Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+            Location::Reference { .. } => None,
+        })
+        .collect()
+}
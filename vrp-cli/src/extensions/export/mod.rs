@@ -0,0 +1,7 @@
+//! Export command helpers.
+
+mod navigation;
+pub use self::navigation::*;
+
+mod routing;
+pub use self::routing::*;
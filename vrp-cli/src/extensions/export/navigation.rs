@@ -0,0 +1,137 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/export/navigation_test.rs"]
+mod navigation_test;
+
+use serde::Serialize;
+use vrp_pragmatic::format::solution::{Solution, Stop, Tour};
+use vrp_pragmatic::format::Location;
+
+/// A geo coordinate as longitude and latitude pair.
+pub type Coordinate = (f64, f64);
+
+/// A route between two consecutive waypoints returned by routing api.
+#[derive(Clone, Debug)]
+pub struct RouteLeg {
+    /// Distance in meters.
+    pub distance: f64,
+    /// Duration in seconds.
+    pub duration: f64,
+    /// Leg geometry as a list of coordinates.
+    pub geometry: Vec<Coordinate>,
+}
+
+/// Provides the way to get routes between waypoints from some routing service, e.g. OSRM or Valhalla.
+pub trait RoutingApi {
+    /// Returns route legs between consecutive waypoints: exactly one leg per waypoint pair is expected.
+    fn route(&self, waypoints: &[Coordinate]) -> Result<Vec<RouteLeg>, String>;
+}
+
+/// Specifies what should be done when driver arrives at stop.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrivalInstructions {
+    /// Expected arrival time in RFC3339 format.
+    pub arrival: String,
+    /// Expected departure time in RFC3339 format.
+    pub departure: String,
+    /// Activities performed at the stop as (job id, activity type) pairs.
+    pub activities: Vec<(String, String)>,
+    /// A placeholder for free text instructions, e.g. parking or access notes.
+    pub notes: Option<String>,
+}
+
+/// A navigation leg which leads from one tour stop to the next one.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigationLeg {
+    /// An index of the stop where leg starts.
+    pub from_stop: usize,
+    /// An index of the stop where leg ends.
+    pub to_stop: usize,
+    /// Distance in meters.
+    pub distance: f64,
+    /// Duration in seconds.
+    pub duration: f64,
+    /// Leg geometry as a list of (longitude, latitude) pairs.
+    pub geometry: Vec<Coordinate>,
+    /// Instructions at destination stop.
+    pub arrival: ArrivalInstructions,
+}
+
+/// A navigation document of one tour which is intended to be handed over to driver's app.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TourNavigation {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Total distance in meters.
+    pub distance: f64,
+    /// Total duration in seconds.
+    pub duration: f64,
+    /// Navigation legs.
+    pub legs: Vec<NavigationLeg>,
+}
+
+/// Creates navigation documents for all tours of pragmatic solution using given routing api.
+/// Solution stops are expected to be specified using geo coordinates.
+pub fn export_navigation(solution: &Solution, routing_api: &dyn RoutingApi) -> Result<Vec<TourNavigation>, String> {
+    solution.tours.iter().map(|tour| create_tour_navigation(tour, routing_api)).collect()
+}
+
+fn create_tour_navigation(tour: &Tour, routing_api: &dyn RoutingApi) -> Result<TourNavigation, String> {
+    let waypoints = tour
+        .stops
+        .iter()
+        .map(|stop| match stop.location {
+            Location::Coordinate { lat, lng } => Ok((lng, lat)),
+            Location::Reference { .. } => Err(format!("tour of '{}' has stop without geo coordinate", tour.vehicle_id)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let route_legs = if waypoints.len() > 1 { routing_api.route(waypoints.as_slice())? } else { vec![] };
+
+    if route_legs.len() != waypoints.len().max(1) - 1 {
+        return Err(format!(
+            "routing api returned {} legs for tour of '{}' with {} stops",
+            route_legs.len(),
+            tour.vehicle_id,
+            waypoints.len()
+        ));
+    }
+
+    let legs = route_legs
+        .into_iter()
+        .enumerate()
+        .map(|(idx, route_leg)| NavigationLeg {
+            from_stop: idx,
+            to_stop: idx + 1,
+            distance: route_leg.distance,
+            duration: route_leg.duration,
+            geometry: route_leg.geometry,
+            arrival: create_arrival_instructions(&tour.stops[idx + 1]),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(TourNavigation {
+        vehicle_id: tour.vehicle_id.clone(),
+        shift_index: tour.shift_index,
+        distance: legs.iter().map(|leg| leg.distance).sum(),
+        duration: legs.iter().map(|leg| leg.duration).sum(),
+        legs,
+    })
+}
+
+fn create_arrival_instructions(stop: &Stop) -> ArrivalInstructions {
+    ArrivalInstructions {
+        arrival: stop.time.arrival.clone(),
+        departure: stop.time.departure.clone(),
+        activities: stop
+            .activities
+            .iter()
+            .map(|activity| (activity.job_id.clone(), activity.activity_type.clone()))
+            .collect(),
+        notes: None,
+    }
+}
@@ -0,0 +1,240 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/export/routing_test.rs"]
+mod routing_test;
+
+use super::{Coordinate, RouteLeg, RoutingApi};
+use serde::Deserialize;
+
+/// A routing api which uses route service of OSRM server.
+pub struct OsrmApi {
+    url: String,
+    profile: String,
+}
+
+impl OsrmApi {
+    /// Creates a new instance of `OsrmApi` for given server url, e.g. `http://localhost:5000`,
+    /// and routing profile, e.g. `driving`.
+    pub fn new(url: &str, profile: &str) -> Self {
+        Self { url: url.trim_end_matches('/').to_string(), profile: profile.to_string() }
+    }
+}
+
+impl RoutingApi for OsrmApi {
+    fn route(&self, waypoints: &[Coordinate]) -> Result<Vec<RouteLeg>, String> {
+        let coordinates = waypoints.iter().map(|(lng, lat)| format!("{},{}", lng, lat)).collect::<Vec<_>>().join(";");
+        let url = format!(
+            "{}/route/v1/{}/{}?overview=false&steps=true&geometries=geojson",
+            self.url, self.profile, coordinates
+        );
+
+        parse_osrm_response(send_http_request("GET", url.as_str(), None)?.as_slice())
+    }
+}
+
+/// A routing api which uses route service of Valhalla server.
+pub struct ValhallaApi {
+    url: String,
+    costing: String,
+}
+
+impl ValhallaApi {
+    /// Creates a new instance of `ValhallaApi` for given server url, e.g. `http://localhost:8002`,
+    /// and costing model, e.g. `auto`.
+    pub fn new(url: &str, costing: &str) -> Self {
+        Self { url: url.trim_end_matches('/').to_string(), costing: costing.to_string() }
+    }
+}
+
+impl RoutingApi for ValhallaApi {
+    fn route(&self, waypoints: &[Coordinate]) -> Result<Vec<RouteLeg>, String> {
+        let locations = waypoints
+            .iter()
+            .map(|(lng, lat)| format!(r#"{{"lat":{},"lon":{}}}"#, lat, lng))
+            .collect::<Vec<_>>()
+            .join(",");
+        let body = format!(
+            r#"{{"locations":[{}],"costing":"{}","directions_options":{{"units":"kilometers"}}}}"#,
+            locations, self.costing
+        );
+        let url = format!("{}/route", self.url);
+
+        parse_valhalla_response(send_http_request("POST", url.as_str(), Some(body.as_str()))?.as_slice())
+    }
+}
+
+#[derive(Deserialize)]
+struct OsrmResponse {
+    code: String,
+    message: Option<String>,
+    routes: Option<Vec<OsrmRoute>>,
+}
+
+#[derive(Deserialize)]
+struct OsrmRoute {
+    legs: Vec<OsrmLeg>,
+}
+
+#[derive(Deserialize)]
+struct OsrmLeg {
+    distance: f64,
+    duration: f64,
+    steps: Vec<OsrmStep>,
+}
+
+#[derive(Deserialize)]
+struct OsrmStep {
+    geometry: OsrmGeometry,
+}
+
+#[derive(Deserialize)]
+struct OsrmGeometry {
+    coordinates: Vec<Coordinate>,
+}
+
+#[derive(Deserialize)]
+struct ValhallaResponse {
+    trip: ValhallaTrip,
+}
+
+#[derive(Deserialize)]
+struct ValhallaTrip {
+    legs: Vec<ValhallaLeg>,
+}
+
+#[derive(Deserialize)]
+struct ValhallaLeg {
+    summary: ValhallaSummary,
+    shape: String,
+}
+
+#[derive(Deserialize)]
+struct ValhallaSummary {
+    length: f64,
+    time: f64,
+}
+
+/// Parses response of OSRM route service requested with steps and geojson geometries.
+fn parse_osrm_response(response: &[u8]) -> Result<Vec<RouteLeg>, String> {
+    let response = serde_json::from_slice::<OsrmResponse>(response)
+        .map_err(|err| format!("cannot parse OSRM response: {}", err))?;
+
+    if response.code != "Ok" {
+        return Err(format!("OSRM returned '{}': {}", response.code, response.message.unwrap_or_default()));
+    }
+
+    let route = response.routes.and_then(|routes| routes.into_iter().next());
+    let route = route.ok_or_else(|| "OSRM returned no routes".to_string())?;
+
+    Ok(route
+        .legs
+        .into_iter()
+        .map(|leg| {
+            let mut geometry = leg.steps.into_iter().flat_map(|step| step.geometry.coordinates).collect::<Vec<_>>();
+            geometry.dedup();
+
+            RouteLeg { distance: leg.distance, duration: leg.duration, geometry }
+        })
+        .collect())
+}
+
+/// Parses response of Valhalla route service requested with kilometers as units.
+fn parse_valhalla_response(response: &[u8]) -> Result<Vec<RouteLeg>, String> {
+    let response = serde_json::from_slice::<ValhallaResponse>(response)
+        .map_err(|err| format!("cannot parse Valhalla response: {}", err))?;
+
+    response
+        .trip
+        .legs
+        .into_iter()
+        .map(|leg| {
+            Ok(RouteLeg {
+                distance: leg.summary.length * 1000.,
+                duration: leg.summary.time,
+                geometry: decode_polyline(leg.shape.as_str(), 6)?,
+            })
+        })
+        .collect()
+}
+
+/// Decodes encoded polyline with given precision into a list of (longitude, latitude) pairs.
+fn decode_polyline(encoded: &str, precision: u32) -> Result<Vec<Coordinate>, String> {
+    let factor = 10_f64.powi(precision as i32);
+    let mut bytes = encoded.bytes();
+
+    let mut next_value = || -> Result<Option<i64>, String> {
+        let (mut result, mut shift) = (0_i64, 0);
+        loop {
+            let byte = match bytes.next() {
+                Some(byte) if (63..127).contains(&byte) => (byte - 63) as i64,
+                Some(_) => return Err("invalid polyline character".to_string()),
+                None if shift == 0 => return Ok(None),
+                None => return Err("unexpected end of polyline".to_string()),
+            };
+
+            if shift >= 64 {
+                return Err("polyline value is too long".to_string());
+            }
+
+            result |= (byte & 0x1f) << shift;
+            shift += 5;
+
+            if byte < 0x20 {
+                return Ok(Some(if result & 1 != 0 { !(result >> 1) } else { result >> 1 }));
+            }
+        }
+    };
+
+    let (mut lat, mut lng) = (0_i64, 0_i64);
+    let mut coordinates = vec![];
+
+    while let Some(lat_delta) = next_value()? {
+        let lng_delta = next_value()?.ok_or_else(|| "unexpected end of polyline".to_string())?;
+        lat += lat_delta;
+        lng += lng_delta;
+        coordinates.push((lng as f64 / factor, lat as f64 / factor));
+    }
+
+    Ok(coordinates)
+}
+
+/// Sends http request and returns response body.
+#[cfg(all(feature = "routing-api", not(target_arch = "wasm32")))]
+fn send_http_request(method: &str, url: &str, body: Option<&str>) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+    use std::time::Duration;
+
+    // NOTE url is parsed by the client, so special characters, e.g. in profile name, are percent encoded
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(Duration::from_secs(10))
+        .timeout_read(Duration::from_secs(60))
+        .timeout_write(Duration::from_secs(60))
+        .build();
+    let request = agent.request(method, url).set("Accept", "application/json");
+
+    let response = match body {
+        Some(body) => request.set("Content-Type", "application/json").send_string(body),
+        None => request.call(),
+    };
+
+    let read_body = |response: ureq::Response| -> Result<Vec<u8>, String> {
+        let mut bytes = vec![];
+        response.into_reader().read_to_end(&mut bytes).map_err(|err| format!("cannot read response: {}", err))?;
+        Ok(bytes)
+    };
+
+    match response {
+        Ok(response) => read_body(response),
+        Err(ureq::Error::Status(status, response)) => Err(format!(
+            "routing service returned status '{}': {}",
+            status,
+            String::from_utf8_lossy(read_body(response)?.as_slice())
+        )),
+        Err(ureq::Error::Transport(err)) => Err(format!("cannot send request to '{}': {}", url, err)),
+    }
+}
+
+/// Sends http request and returns response body.
+#[cfg(not(all(feature = "routing-api", not(target_arch = "wasm32"))))]
+fn send_http_request(_: &str, _: &str, _: Option<&str>) -> Result<Vec<u8>, String> {
+    Err("routing api requests are not supported: vrp-cli is built without 'routing-api' feature".to_string())
+}
@@ -36,6 +36,7 @@ pub(crate) fn generate_plan(
                             location: get_location_fn(&rnd),
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
+                            preferred_times: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -44,6 +45,8 @@ pub(crate) fn generate_plan(
                         get_random_item(demands.as_slice(), &rnd).cloned()
                     },
 
+                    volume: None,
+                    density: None,
                     tag: None,
                 })
                 .collect::<Vec<_>>()
@@ -65,12 +68,17 @@ pub(crate) fn generate_plan(
                 replacements: generate_tasks(&job_proto.replacements, false),
                 services: generate_tasks(&job_proto.services, true),
                 priority: job_proto.priority,
+                value: None,
+                must_serve: None,
+                truck_only: None,
+                loading_policy: None,
+                compatibility: None,
                 skills: job_proto.skills.clone(),
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None })
+    Ok(Plan { jobs, relations: None, clustering: None })
 }
 
 fn get_location_fn(
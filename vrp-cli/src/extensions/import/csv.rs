@@ -70,8 +70,11 @@ mod actual {
                 location: Location::Coordinate { lat: job.lat, lng: job.lng },
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
+                preferred_times: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
+            volume: None,
+            density: None,
             tag: None,
         };
 
@@ -98,6 +101,11 @@ mod actual {
                 replacements: None,
                 services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
                 priority: None,
+                value: None,
+                must_serve: None,
+                truck_only: None,
+                loading_policy: None,
+                compatibility: None,
                 skills: None,
             })
             .collect();
@@ -115,19 +123,25 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: vehicle.profile,
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, zone_fees: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
                             latest: None,
                             location: depot_location.clone(),
+                            driver: None,
                         },
                         end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
                         dispatch: None,
                         breaks: None,
+                        unavailability: None,
                         reloads: None,
                     }],
                     capacity: vec![vehicle.capacity],
+                    volume: None,
+                    trailer: None,
+                    team: None,
+                    crew_size: None,
                     skills: None,
                     limits: None,
                 }
@@ -156,12 +170,18 @@ mod actual {
         let profiles = vehicles.iter().map(|v| v.profile.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None },
+            plan: Plan { jobs, relations: None, clustering: None },
             fleet: Fleet {
                 vehicles,
                 profiles: profiles
                     .into_iter()
-                    .map(|p| Profile { name: p.clone(), profile_type: p, speed: None })
+                    .map(|p| Profile {
+                        name: p.clone(),
+                        profile_type: p,
+                        speed: None,
+                        distance_multiplier: None,
+                        time_bucket: None,
+                    })
                     .collect(),
             },
             objectives: None,
@@ -252,7 +252,12 @@ mod deserialize {
     use vrp_pragmatic::format::Location;
 
     fn all_of_skills(skills: Option<Vec<String>>) -> Option<JobSkills> {
-        skills.as_ref().map(|skills| JobSkills { all_of: Some(skills.clone()), one_of: None, none_of: None })
+        skills.as_ref().map(|skills| JobSkills {
+            all_of: Some(skills.clone()),
+            one_of: None,
+            none_of: None,
+            preferred: None,
+        })
     }
 
     fn to_pragmatic_loc(loc: &models::Location) -> Location {
@@ -269,8 +274,11 @@ mod deserialize {
                             location: to_pragmatic_loc(&place.location),
                             duration: place.duration,
                             times: place.times.clone(),
+                            preferred_times: None,
                         }],
                         demand: Some(place.demand.clone()),
+                        volume: None,
+                        density: None,
                         tag: place.tag.clone(),
                     })
                     .collect()
@@ -288,6 +296,11 @@ mod deserialize {
                     replacements: None,
                     services: None,
                     priority: job.priority.as_ref().copied(),
+                    value: None,
+                    must_serve: None,
+                    truck_only: None,
+                    loading_policy: None,
+                    compatibility: None,
                     skills: all_of_skills(job.skills.clone()),
                 })
                 .collect(),
@@ -306,6 +319,7 @@ mod deserialize {
                     })
                     .collect()
             }),
+            clustering: None,
         })
     }
 
@@ -318,7 +332,12 @@ mod deserialize {
                     type_id: v.id.clone(),
                     vehicle_ids: (1..=v.amount).map(|seq| format!("{}_{}", v.id, seq)).collect(),
                     profile: v.profile.clone(),
-                    costs: VehicleCosts { fixed: v.costs.fixed, distance: v.costs.distance, time: v.costs.time },
+                    costs: VehicleCosts {
+                        fixed: v.costs.fixed,
+                        distance: v.costs.distance,
+                        time: v.costs.time,
+                        zone_fees: None,
+                    },
                     shifts: v
                         .shifts
                         .iter()
@@ -327,6 +346,7 @@ mod deserialize {
                                 earliest: shift.start.time.clone(),
                                 latest: None,
                                 location: to_pragmatic_loc(&shift.start.location),
+                                driver: None,
                             },
                             end: shift.end.as_ref().map(|end| ShiftEnd {
                                 earliest: None,
@@ -341,26 +361,41 @@ mod deserialize {
                                         time: VehicleBreakTime::TimeWindow(b.times.first().unwrap().clone()),
                                         duration: b.duration,
                                         locations: b.location.as_ref().map(|l| vec![to_pragmatic_loc(l)]),
+                                        policy: None,
                                     })
                                     .collect()
                             }),
+                            unavailability: None,
                             reloads: None,
                         })
                         .collect(),
                     capacity: v.capacity.clone(),
+                    volume: None,
+                    trailer: None,
+                    team: None,
+                    crew_size: None,
                     skills: v.skills.clone(),
                     limits: v.limits.as_ref().map(|l| VehicleLimits {
                         max_distance: l.max_distance,
                         shift_time: l.shift_time,
                         tour_size: None,
+                        max_radius: None,
+                        overload: None,
                         allowed_areas: None,
+                        overtime: None,
                     }),
                 })
                 .collect(),
             profiles: fleet
                 .profiles
                 .iter()
-                .map(|p| Profile { name: p.name.clone(), profile_type: p.profile_type.clone(), speed: None })
+                .map(|p| Profile {
+                    name: p.name.clone(),
+                    profile_type: p.profile_type.clone(),
+                    speed: None,
+                    distance_multiplier: None,
+                    time_bucket: None,
+                })
                 .collect(),
         })
     }
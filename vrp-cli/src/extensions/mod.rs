@@ -3,7 +3,11 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod export;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
 
+pub mod analyze;
+pub mod anonymize;
 pub mod import;
 pub mod solve;
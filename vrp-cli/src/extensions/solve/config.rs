@@ -9,15 +9,19 @@ mod config_test;
 extern crate serde_json;
 
 use serde::Deserialize;
-use std::io::{BufReader, Read};
-use std::sync::Arc;
-use vrp_core::models::common::SingleDimLoad;
+use std::fs::File;
+use std::io::{BufReader, LineWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use vrp_core::algorithms::gsom::{write_network_state_csv, write_network_state_json};
 use vrp_core::models::Problem;
 use vrp_core::solver::hyper::*;
 use vrp_core::solver::mutation::*;
 use vrp_core::solver::population::*;
-use vrp_core::solver::{Builder, Telemetry, TelemetryMode};
-use vrp_core::utils::{Environment, Parallelism, Random};
+use vrp_core::solver::{
+    Builder, PopulationDumper, PopulationSnapshot, ProgressRecord, ProgressSink, Telemetry, TelemetryMode,
+};
+use vrp_core::utils::{DefaultRandom, Environment, Parallelism, ParallelismPhase, Random, RandomAlgorithm};
 
 /// An algorithm configuration.
 #[derive(Clone, Deserialize, Debug)]
@@ -81,9 +85,23 @@ pub enum PopulationType {
         rebalance_count: Option<usize>,
         /// An exploration phase ratio. Default is 0.9.
         exploration_ratio: Option<f64>,
+        /// A network state export configuration. Default is none.
+        network_export: Option<NetworkExportConfig>,
     },
 }
 
+/// A GSOM network state export configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkExportConfig {
+    /// An existing directory where network state files are written.
+    pub directory: String,
+    /// Specifies how often (in generations) network state is exported.
+    pub frequency: usize,
+    /// An export format: json or csv. Default is json.
+    pub format: Option<String>,
+}
+
 /// An initial solution configuration.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -113,6 +131,27 @@ pub enum HyperType {
     StaticSelective {
         /// A collection of inner mutation operators (metaheuristics).
         mutations: Vec<MutationType>,
+        /// An acceptance criterion for results of mutations applied one after another.
+        acceptance: Option<AcceptanceType>,
+    },
+
+    /// A hyper heuristic which learns which mutation to apply depending on search state.
+    #[serde(rename(deserialize = "dynamic-selective"))]
+    DynamicSelective,
+}
+
+/// An acceptance criterion configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum AcceptanceType {
+    /// Accepts worse results with probability which decreases with each generation.
+    #[serde(rename(deserialize = "simulated-annealing"))]
+    #[serde(rename_all = "camelCase")]
+    SimulatedAnnealing {
+        /// An initial temperature, relative to the individual's cost.
+        initial_temperature: f64,
+        /// A factor which temperature is multiplied by in each generation.
+        cooling_rate: f64,
     },
 }
 
@@ -132,6 +171,17 @@ pub enum MutationType {
         probability: MutationProbabilityType,
     },
 
+    /// A metaheuristic which splits solution into temporal slices and refines them one by one.
+    #[serde(rename(deserialize = "temporal-decomposition"))]
+    TemporalDecomposition {
+        /// Amount of temporal slices.
+        slices: MinMaxConfig,
+        /// Amount of attempts to repeat refinement of each slice.
+        repeat: usize,
+        /// Probability of mutation.
+        probability: MutationProbabilityType,
+    },
+
     /// A local search heuristic.
     #[serde(rename(deserialize = "local-search"))]
     LocalSearch {
@@ -141,6 +191,19 @@ pub enum MutationType {
         times: MinMaxConfig,
         /// Local search operator.
         operators: Vec<LocalOperatorType>,
+        /// Tabu tenure in generations: a job moved to another route is not moved back during it.
+        tabu: Option<usize>,
+    },
+
+    /// A mutation which removes the smallest route and inserts its jobs into the remaining routes.
+    #[serde(rename(deserialize = "route-reduction"))]
+    RouteReduction {
+        /// Probability of mutation.
+        probability: MutationProbabilityType,
+        /// Amount of attempts to insert all jobs of the removed route.
+        attempts: usize,
+        /// Recreate methods.
+        recreates: Vec<RecreateMethod>,
     },
 
     /// A ruin and recreate metaheuristic settings.
@@ -224,22 +287,32 @@ pub struct RuinGroupConfig {
 pub enum RuinMethod {
     /// Adjusted string removal method.
     #[serde(rename(deserialize = "adjusted-string"))]
-    AdjustedString { probability: f64, lmax: usize, cavg: usize, alpha: f64 },
+    #[serde(rename_all = "camelCase")]
+    AdjustedString { probability: f64, lmax: usize, cavg: usize, alpha: f64, split_rate: Option<f64> },
     /// Neighbour jobs method
     #[serde(rename(deserialize = "neighbour"))]
     Neighbour { probability: f64, min: usize, max: usize, threshold: f64 },
     /// Random job removal method.
     #[serde(rename(deserialize = "random-job"))]
     RandomJob { probability: f64, min: usize, max: usize, threshold: f64 },
+    /// Demand proportional random job removal method.
+    #[serde(rename(deserialize = "demand-job"))]
+    DemandJob { probability: f64, min: usize, max: usize, threshold: f64 },
     /// Random route removal method.
     #[serde(rename(deserialize = "random-route"))]
     RandomRoute { probability: f64, min: usize, max: usize, threshold: f64 },
     /// Worst job removal method.
     #[serde(rename(deserialize = "worst-job"))]
     WorstJob { probability: f64, min: usize, max: usize, threshold: f64, skip: usize },
+    /// Worst segment removal method.
+    #[serde(rename(deserialize = "worst-segment"))]
+    WorstSegment { probability: f64, min: usize, max: usize, threshold: f64, skip: usize, smin: usize, smax: usize },
     /// Clustered jobs removal method.
     #[serde(rename(deserialize = "cluster"))]
     Cluster { probability: f64, min: usize, max: usize, threshold: f64, cmin: usize, cmax: usize },
+    /// Removal of jobs from regions created by k-medoids algorithm.
+    #[serde(rename(deserialize = "k-medoids"))]
+    KMedoids { probability: f64, min: usize, max: usize, threshold: f64, kmin: usize, kmax: usize },
 }
 
 /// Specifies recreate methods with their probability weight and specific parameters.
@@ -275,13 +348,30 @@ pub enum RecreateMethod {
 #[serde(tag = "type")]
 pub enum LocalOperatorType {
     #[serde(rename(deserialize = "inter-route-best"))]
-    InterRouteBest { weight: usize, noise: NoiseConfig },
+    InterRouteBest { weight: usize, noise: NoiseConfig, neighborhood: Option<NeighborhoodConfig> },
 
     #[serde(rename(deserialize = "inter-route-random"))]
-    InterRouteRandom { weight: usize, noise: NoiseConfig },
+    InterRouteRandom { weight: usize, noise: NoiseConfig, neighborhood: Option<NeighborhoodConfig> },
+
+    #[serde(rename(deserialize = "exchange-sequence"))]
+    ExchangeSequence { weight: usize, min: usize, max: usize },
 
     #[serde(rename(deserialize = "intra-route-random"))]
     IntraRouteRandom { weight: usize, noise: NoiseConfig },
+
+    #[serde(rename(deserialize = "two-opt"))]
+    #[serde(rename_all = "camelCase")]
+    TwoOpt { weight: usize, max_segment: Option<usize> },
+
+    #[serde(rename(deserialize = "or-opt"))]
+    #[serde(rename_all = "camelCase")]
+    OrOpt { weight: usize, max_segment: Option<usize> },
+
+    #[serde(rename(deserialize = "split-route"))]
+    SplitRoute { weight: usize },
+
+    #[serde(rename(deserialize = "merge-routes"))]
+    MergeRoutes { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -291,12 +381,25 @@ pub struct NoiseConfig {
     max: f64,
 }
 
+/// A granular neighborhood configuration: restricts local search moves to `size` nearest jobs
+/// within `threshold` distance.
+#[derive(Clone, Deserialize, Debug)]
+pub struct NeighborhoodConfig {
+    size: usize,
+    threshold: Option<f64>,
+}
+
 #[derive(Clone, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TerminationConfig {
     pub max_time: Option<usize>,
     pub max_generations: Option<usize>,
     pub variation: Option<VariationConfig>,
+    pub target_cost: Option<f64>,
+    pub min_improvement: Option<MinImprovementConfig>,
+    pub all_assigned: Option<bool>,
+    pub goals: Option<GoalsMode>,
+    pub time_budget: Option<TimeBudgetConfig>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -305,11 +408,44 @@ pub struct VariationConfig {
     cv: f64,
 }
 
+/// Specifies min relative improvement of the best known cost over given amount of generations.
+#[derive(Clone, Deserialize, Debug)]
+pub struct MinImprovementConfig {
+    generations: usize,
+    epsilon: f64,
+}
+
+/// Specifies how goal termination criteria (variation, target cost, min improvement, all assigned)
+/// are combined.
+#[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalsMode {
+    /// Stop when any of goals is met.
+    Any,
+    /// Stop when all goals are met.
+    All,
+}
+
+/// Specifies shares of max time used by solver phases.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeBudgetConfig {
+    pub construction: f64,
+    pub search: f64,
+    pub post_optimization: f64,
+    /// A mutation applied to the best known solution within post-optimization share. Default is local search.
+    pub post_optimization_mutation: Option<MutationType>,
+}
+
 /// A telemetry config.
 #[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct TelemetryConfig {
     logging: Option<LoggingConfig>,
     metrics: Option<MetricsConfig>,
+    progress: Option<ProgressConfig>,
+    trace: Option<TraceConfig>,
+    population_dump: Option<PopulationDumpConfig>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -334,11 +470,52 @@ pub struct MetricsConfig {
     track_population: Option<usize>,
 }
 
+#[derive(Clone, Deserialize, Debug)]
+pub struct ProgressConfig {
+    /// Specifies path to the file where search progress is written.
+    path: String,
+    /// Specifies progress format: csv or json (json lines). Default is csv.
+    format: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct TraceConfig {
+    /// Specifies path to the file where mutation trace is written in csv format.
+    path: String,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct PopulationDumpConfig {
+    /// Specifies path to the file where population snapshots are written in json lines format.
+    path: String,
+    /// Specifies how often population is dumped. Default is 1000 (generations).
+    frequency: Option<usize>,
+}
+
 /// An environment specific configuration.
 #[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct EnvironmentConfig {
     /// Specifies a data parallelism configuration.
     pub parallelism: Option<ParallelismConfig>,
+    /// Specifies a random number generator configuration.
+    pub random: Option<RandomConfig>,
+    /// Specifies an approximate memory limit in megabytes.
+    pub memory_limit: Option<usize>,
+    /// Specifies a capacity of job insertion evaluation cache, zero disables it. Default is 10000.
+    pub insertion_cache: Option<usize>,
+}
+
+/// Random number generator configuration.
+#[derive(Clone, Deserialize, Debug)]
+pub struct RandomConfig {
+    /// An algorithm: std, chacha, pcg or small. Default is std.
+    pub algorithm: Option<String>,
+    /// A master seed used to derive random streams. Default is none: generator is seeded from entropy.
+    pub seed: Option<u64>,
+    /// Specifies whether search is reproducible for the same seed regardless of thread scheduling.
+    /// Default is false.
+    pub deterministic: Option<bool>,
 }
 
 /// Data parallelism configuration.
@@ -349,6 +526,22 @@ pub struct ParallelismConfig {
     pub num_thread_pools: usize,
     /// Specifies amount of threads in each thread pool.
     pub threads_per_pool: usize,
+    /// Specifies amount of threads in dedicated solver thread pool. When not set, the global thread pool is used.
+    pub solver_threads: Option<usize>,
+    /// Specifies amount of threads in dedicated thread pools of solver phases.
+    pub phase_threads: Option<PhaseThreadsConfig>,
+}
+
+/// Specifies amount of threads per solver phase. When not set, the phase uses the global thread pool.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseThreadsConfig {
+    /// Amount of threads used to build initial solutions.
+    pub construction: Option<usize>,
+    /// Amount of threads used to apply mutation operators.
+    pub mutation: Option<usize>,
+    /// Amount of threads used to evaluate new individuals.
+    pub evaluation: Option<usize>,
 }
 
 #[derive(Clone, Deserialize, Debug, Eq, PartialEq)]
@@ -379,10 +572,9 @@ fn configure_from_evolution(
         if let Some(initial) = &config.initial {
             builder = builder.with_init_params(
                 initial.size,
-                initial
-                    .methods
-                    .as_ref()
-                    .map(|methods| methods.iter().map(|method| create_recreate_method(method)).collect()),
+                initial.methods.as_ref().map(|methods| {
+                    methods.iter().map(|method| create_recreate_method(method, problem.as_ref())).collect()
+                }),
             );
         }
 
@@ -407,6 +599,7 @@ fn configure_from_evolution(
                     rebalance_memory,
                     rebalance_count,
                     exploration_ratio,
+                    network_export,
                 } => {
                     let mut config = RosomaxaConfig::new_with_defaults(default_selection_size);
                     if let Some(selection_size) = selection_size {
@@ -439,6 +632,9 @@ fn configure_from_evolution(
                     if let Some(exploration_ratio) = exploration_ratio {
                         config.exploration_ratio = *exploration_ratio;
                     }
+                    if let Some(network_export) = network_export {
+                        config.network_export = Some(create_network_export(network_export)?);
+                    }
 
                     Box::new(Rosomaxa::new(problem, environment, config)?)
                 }
@@ -451,46 +647,126 @@ fn configure_from_evolution(
     Ok(builder)
 }
 
+fn create_network_export(config: &NetworkExportConfig) -> Result<(usize, NetworkStateExporter), String> {
+    let format = config.format.clone().unwrap_or_else(|| "json".to_string());
+    let write_func = match format.as_str() {
+        "json" => write_network_state_json::<File>,
+        "csv" => write_network_state_csv::<File>,
+        _ => return Err(format!("unknown network export format: '{}'", format)),
+    };
+
+    let directory = PathBuf::from(config.directory.as_str());
+
+    Ok((
+        config.frequency,
+        Arc::new(move |generation, state| {
+            let path = directory.join(format!("network_{}.{}", generation, format));
+            let result =
+                File::create(&path).map_err(|err| err.to_string()).and_then(|mut file| write_func(state, &mut file));
+
+            if let Err(err) = result {
+                eprintln!("cannot export network state to '{}': {}", path.display(), err);
+            }
+        }),
+    ))
+}
+
 fn configure_from_hyper(
     mut builder: Builder,
     hyper_config: &Option<HyperType>,
     environment: Arc<Environment>,
 ) -> Result<Builder, String> {
-    if let Some(config) = hyper_config {
-        match config {
-            HyperType::StaticSelective { mutations } => {
-                let mutation_group = mutations
-                    .iter()
-                    .map(|mutation| create_mutation(&builder.config.problem, environment.random.clone(), mutation))
-                    .collect::<Result<Vec<_>, _>>()?;
-                let static_selective = vrp_core::solver::hyper::StaticSelective::new(mutation_group);
-                builder = builder.with_hyper(Box::new(static_selective));
-            }
+    let hyper: Option<Box<dyn HyperHeuristic + Send + Sync>> = match hyper_config {
+        Some(HyperType::StaticSelective { mutations, acceptance }) => {
+            let mutation_group = mutations
+                .iter()
+                .map(|mutation| create_mutation(&builder.config.problem, environment.random.clone(), mutation))
+                .collect::<Result<Vec<_>, _>>()?;
+            let static_selective = StaticSelective::new(mutation_group);
+            let static_selective = match acceptance {
+                Some(AcceptanceType::SimulatedAnnealing { initial_temperature, cooling_rate }) => static_selective
+                    .with_acceptance(create_simulated_annealing_acceptance(
+                        *initial_temperature,
+                        *cooling_rate,
+                        environment.random.clone(),
+                    )),
+                None => static_selective,
+            };
+            Some(Box::new(static_selective))
         }
+        Some(HyperType::DynamicSelective) => {
+            Some(Box::new(DynamicSelective::new_with_defaults(builder.config.problem.clone(), environment)))
+        }
+        None => None,
+    };
+
+    if let Some(hyper) = hyper {
+        builder = builder.with_hyper(hyper);
     }
 
     Ok(builder)
 }
 
+/// Creates a mutation tracer which writes information about each applied mutation into the file
+/// using csv format. Mutation is identified by its operator name.
+pub fn create_mutation_tracer(path: &str) -> Result<MutationTracer, String> {
+    let mut writer =
+        LineWriter::new(File::create(path).map_err(|err| format!("cannot create trace file '{}': {}", path, err))?);
+
+    writeln!(writer, "generation,individual,mutation,cost_before,cost_after,is_improved")
+        .map_err(|err| err.to_string())?;
+
+    let writer = Mutex::new(writer);
+
+    Ok(Arc::new(move |trace: &MutationTrace| {
+        let mut writer = writer.lock().unwrap();
+        let result = writeln!(
+            writer,
+            "{},{},{},{:.3},{:.3},{}",
+            trace.generation, trace.individual, trace.mutation, trace.cost_before, trace.cost_after, trace.is_improved
+        );
+
+        if let Err(err) = result {
+            eprintln!("cannot write mutation trace: {}", err);
+        }
+    }))
+}
+
 fn configure_from_termination(
     mut builder: Builder,
     termination_config: &Option<TerminationConfig>,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
 ) -> Result<Builder, String> {
     if let Some(config) = termination_config {
         builder = builder.with_max_time(config.max_time);
         builder = builder.with_max_generations(config.max_generations);
         builder = builder.with_cost_variation(config.variation.as_ref().map(|v| (v.sample, v.cv)));
+        builder = builder.with_target_cost(config.target_cost);
+        builder = builder.with_min_improvement(config.min_improvement.as_ref().map(|m| (m.generations, m.epsilon)));
+        builder = builder.with_all_assigned(config.all_assigned.unwrap_or(false));
+        builder = builder.with_all_goals(config.goals == Some(GoalsMode::All));
+        builder = builder.with_time_budget(
+            config.time_budget.as_ref().map(|budget| (budget.construction, budget.search, budget.post_optimization)),
+        );
+
+        if let Some(mutation) =
+            config.time_budget.as_ref().and_then(|budget| budget.post_optimization_mutation.as_ref())
+        {
+            let (mutation, _) = create_mutation(&problem, environment.random.clone(), mutation)?;
+            builder = builder.with_post_optimization(Some(mutation));
+        }
     }
 
     Ok(builder)
 }
 
-fn create_recreate_method(method: &RecreateMethod) -> (Box<dyn Recreate + Send + Sync>, usize) {
+fn create_recreate_method(method: &RecreateMethod, problem: &Problem) -> (Box<dyn Recreate + Send + Sync>, usize) {
     match method {
         RecreateMethod::Cheapest { weight } => (Box::new(RecreateWithCheapest::default()), *weight),
         RecreateMethod::Farthest { weight } => (Box::new(RecreateWithFarthest::default()), *weight),
         RecreateMethod::SkipBest { weight, start, end } => (Box::new(RecreateWithSkipBest::new(*start, *end)), *weight),
-        RecreateMethod::Blinks { weight } => (Box::new(RecreateWithBlinks::<SingleDimLoad>::default()), *weight),
+        RecreateMethod::Blinks { weight } => (create_default_blinks(problem), *weight),
         RecreateMethod::Gaps { weight, min } => (Box::new(RecreateWithGaps::new(*min)), *weight),
         RecreateMethod::Nearest { weight } => (Box::new(RecreateWithNearestNeighbor::default()), *weight),
         RecreateMethod::Regret { weight, start, end } => (Box::new(RecreateWithRegret::new(*start, *end)), *weight),
@@ -507,14 +783,34 @@ fn create_mutation(
 ) -> Result<(Arc<dyn Mutation + Send + Sync>, MutationProbability), String> {
     Ok(match mutation {
         MutationType::RuinRecreate { probability, ruins, recreates } => {
-            let ruin = Box::new(CompositeRuin::new(ruins.iter().map(|g| create_ruin_group(problem, g)).collect()));
-            let recreate =
-                Box::new(CompositeRecreate::new(recreates.iter().map(|r| create_recreate_method(r)).collect()));
+            let ruin = Box::new(CompositeRuin::new(
+                ruins.iter().map(|g| create_ruin_group(problem, random.clone(), g)).collect(),
+            ));
+            let recreate = Box::new(CompositeRecreate::new(
+                recreates.iter().map(|r| create_recreate_method(r, problem.as_ref())).collect(),
+            ));
             (Arc::new(RuinAndRecreate::new(recreate, ruin)), create_mutation_probability(probability, random.clone()))
         }
-        MutationType::LocalSearch { probability, times, operators: inners } => {
+        MutationType::LocalSearch { probability, times, operators: inners, tabu } => {
             let operator = create_local_search(times, inners);
-            (Arc::new(LocalSearch::new(operator)), create_mutation_probability(probability, random.clone()))
+            let local_search = match tabu {
+                Some(tenure) => LocalSearch::new(operator).with_tabu(*tenure),
+                None => LocalSearch::new(operator),
+            };
+            (Arc::new(local_search), create_mutation_probability(probability, random.clone()))
+        }
+        MutationType::RouteReduction { probability, attempts, recreates } => {
+            if *attempts < 1 {
+                return Err(format!("attempts must be greater than 1. Specified: {}", attempts));
+            }
+
+            let recreate = Arc::new(CompositeRecreate::new(
+                recreates.iter().map(|r| create_recreate_method(r, problem.as_ref())).collect(),
+            ));
+            (
+                Arc::new(RouteReduction::new(recreate, *attempts)),
+                create_mutation_probability(probability, random.clone()),
+            )
         }
         MutationType::Decomposition { routes, repeat, probability } => {
             if *repeat < 1 {
@@ -530,6 +826,20 @@ fn create_mutation(
                 create_mutation_probability(probability, random.clone()),
             )
         }
+        MutationType::TemporalDecomposition { slices, repeat, probability } => {
+            if *repeat < 1 {
+                return Err(format!("repeat must be greater than 1. Specified: {}", repeat));
+            }
+            if slices.min < 2 {
+                return Err(format!("min slices must be greater than 2. Specified: {}", slices.min));
+            }
+
+            let mutation = vrp_core::solver::hyper::StaticSelective::create_default_mutation(problem.clone());
+            (
+                Arc::new(TemporalDecomposeSearch::new(mutation, (slices.min, slices.max), *repeat)),
+                create_mutation_probability(probability, random.clone()),
+            )
+        }
     })
 }
 
@@ -555,14 +865,22 @@ fn create_mutation_probability(
     }
 }
 
-fn create_ruin_group(problem: &Arc<Problem>, group: &RuinGroupConfig) -> RuinGroup {
-    (group.methods.iter().map(|r| create_ruin_method(problem, r)).collect(), group.weight)
+fn create_ruin_group(
+    problem: &Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+    group: &RuinGroupConfig,
+) -> RuinGroup {
+    (group.methods.iter().map(|r| create_ruin_method(problem, random.clone(), r)).collect(), group.weight)
 }
 
-fn create_ruin_method(problem: &Arc<Problem>, method: &RuinMethod) -> (Arc<dyn Ruin + Send + Sync>, f64) {
+fn create_ruin_method(
+    problem: &Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+    method: &RuinMethod,
+) -> (Arc<dyn Ruin + Send + Sync>, f64) {
     match method {
-        RuinMethod::AdjustedString { probability, lmax, cavg, alpha } => {
-            (Arc::new(AdjustedStringRemoval::new(*lmax, *cavg, *alpha)), *probability)
+        RuinMethod::AdjustedString { probability, lmax, cavg, alpha, split_rate } => {
+            (Arc::new(AdjustedStringRemoval::new(*lmax, *cavg, *alpha, split_rate.unwrap_or(0.5))), *probability)
         }
         RuinMethod::Neighbour { probability, min, max, threshold } => {
             (Arc::new(NeighbourRemoval::new(JobRemovalLimit::new(*min, *max, *threshold))), *probability)
@@ -570,16 +888,32 @@ fn create_ruin_method(problem: &Arc<Problem>, method: &RuinMethod) -> (Arc<dyn R
         RuinMethod::RandomJob { probability, min, max, threshold } => {
             (Arc::new(RandomJobRemoval::new(JobRemovalLimit::new(*min, *max, *threshold))), *probability)
         }
+        RuinMethod::DemandJob { probability, min, max, threshold } => {
+            (Arc::new(DemandJobRemoval::new(JobRemovalLimit::new(*min, *max, *threshold))), *probability)
+        }
         RuinMethod::RandomRoute { probability, min, max, threshold } => {
             (Arc::new(RandomRouteRemoval::new(*min, *max, *threshold)), *probability)
         }
         RuinMethod::WorstJob { probability, min, max, threshold, skip: worst_skip } => {
             (Arc::new(WorstJobRemoval::new(*worst_skip, JobRemovalLimit::new(*min, *max, *threshold))), *probability)
         }
+        RuinMethod::WorstSegment { probability, min, max, threshold, skip, smin, smax } => (
+            Arc::new(WorstSegmentRemoval::new((*smin, *smax), *skip, JobRemovalLimit::new(*min, *max, *threshold))),
+            *probability,
+        ),
         RuinMethod::Cluster { probability, min, max, threshold, cmin, cmax } => (
             Arc::new(ClusterRemoval::new(problem.clone(), *cmin..*cmax, JobRemovalLimit::new(*min, *max, *threshold))),
             *probability,
         ),
+        RuinMethod::KMedoids { probability, min, max, threshold, kmin, kmax } => (
+            Arc::new(KMedoidsRemoval::new(
+                problem.clone(),
+                random,
+                *kmin..*kmax,
+                JobRemovalLimit::new(*min, *max, *threshold),
+            )),
+            *probability,
+        ),
     }
 }
 
@@ -587,25 +921,51 @@ fn create_local_search(times: &MinMaxConfig, inners: &[LocalOperatorType]) -> Bo
     let operators = inners
         .iter()
         .map::<(Box<dyn LocalOperator + Send + Sync>, usize), _>(|op| match op {
-            LocalOperatorType::InterRouteBest { weight, noise } => {
-                (Box::new(ExchangeInterRouteBest::new(noise.probability, noise.min, noise.max)), *weight)
+            LocalOperatorType::InterRouteBest { weight, noise, neighborhood } => {
+                let operator = ExchangeInterRouteBest::new(noise.probability, noise.min, noise.max);
+                let operator = match neighborhood {
+                    Some(neighborhood) => operator.with_neighborhood(create_neighborhood(neighborhood)),
+                    None => operator,
+                };
+                (Box::new(operator), *weight)
+            }
+            LocalOperatorType::InterRouteRandom { weight, noise, neighborhood } => {
+                let operator = ExchangeInterRouteRandom::new(noise.probability, noise.min, noise.max);
+                let operator = match neighborhood {
+                    Some(neighborhood) => operator.with_neighborhood(create_neighborhood(neighborhood)),
+                    None => operator,
+                };
+                (Box::new(operator), *weight)
             }
-            LocalOperatorType::InterRouteRandom { weight, noise } => {
-                (Box::new(ExchangeInterRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
+            LocalOperatorType::ExchangeSequence { weight, min, max } => {
+                (Box::new(ExchangeSequence::new(*min, *max)), *weight)
             }
             LocalOperatorType::IntraRouteRandom { weight, noise } => {
                 (Box::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
+            LocalOperatorType::TwoOpt { weight, max_segment } => {
+                (Box::new(max_segment.map_or_else(TwoOptWithinRoute::default, TwoOptWithinRoute::new)), *weight)
+            }
+            LocalOperatorType::OrOpt { weight, max_segment } => {
+                (Box::new(max_segment.map_or_else(OrOptMove::default, OrOptMove::new)), *weight)
+            }
+            LocalOperatorType::SplitRoute { weight } => (Box::new(SplitRoute::default()), *weight),
+            LocalOperatorType::MergeRoutes { weight } => (Box::new(MergeRoutes::default()), *weight),
         })
         .collect::<Vec<_>>();
 
     Box::new(CompositeLocalOperator::new(operators, times.min, times.max))
 }
 
+fn create_neighborhood(neighborhood: &NeighborhoodConfig) -> GranularNeighborhood {
+    GranularNeighborhood::new(neighborhood.size, neighborhood.threshold)
+}
+
 fn configure_from_telemetry(builder: Builder, telemetry_config: &Option<TelemetryConfig>) -> Result<Builder, String> {
     const LOG_BEST: usize = 100;
     const LOG_POPULATION: usize = 1000;
     const TRACK_POPULATION: usize = 1000;
+    const DUMP_POPULATION: usize = 1000;
 
     let create_logger = || Arc::new(|msg: &str| println!("{}", msg));
 
@@ -645,15 +1005,146 @@ fn configure_from_telemetry(builder: Builder, telemetry_config: &Option<Telemetr
         _ => TelemetryMode::None,
     };
 
-    Ok(builder.with_telemetry(Telemetry::new(telemetry_mode)))
+    let telemetry = Telemetry::new(telemetry_mode);
+    let telemetry = match telemetry_config.as_ref().and_then(|t| t.progress.as_ref()) {
+        Some(ProgressConfig { path, format }) => {
+            telemetry.with_progress_sink(create_progress_sink(path.as_str(), format.as_deref())?)
+        }
+        None => telemetry,
+    };
+    let telemetry = match telemetry_config.as_ref().and_then(|t| t.population_dump.as_ref()) {
+        Some(PopulationDumpConfig { path, frequency }) => telemetry
+            .with_population_dumper(frequency.unwrap_or(DUMP_POPULATION), create_population_dumper(path.as_str())?),
+        None => telemetry,
+    };
+    let tracer = telemetry_config
+        .as_ref()
+        .and_then(|t| t.trace.as_ref())
+        .map(|trace| create_mutation_tracer(trace.path.as_str()))
+        .transpose()?;
+
+    Ok(builder.with_telemetry(telemetry).with_mutation_tracer(tracer))
+}
+
+/// Creates a progress sink which writes per generation search progress into the file using csv
+/// or json lines format.
+pub fn create_progress_sink(path: &str, format: Option<&str>) -> Result<ProgressSink, String> {
+    let format = match format.unwrap_or("csv") {
+        "csv" => ProgressFormat::Csv,
+        "json" => ProgressFormat::Json,
+        format => return Err(format!("unknown progress format: '{}'", format)),
+    };
+
+    let mut writer =
+        LineWriter::new(File::create(path).map_err(|err| format!("cannot create progress file '{}': {}", path, err))?);
+
+    if let ProgressFormat::Csv = format {
+        writeln!(writer, "generation,elapsed,best_cost,mean_cost,unassigned").map_err(|err| err.to_string())?;
+    }
+
+    let writer = Mutex::new(writer);
+
+    Ok(Arc::new(move |record: &ProgressRecord| {
+        let line = match format {
+            ProgressFormat::Csv => format!(
+                "{},{:.3},{:.3},{:.3},{}",
+                record.generation, record.elapsed, record.best_cost, record.mean_cost, record.unassigned
+            ),
+            ProgressFormat::Json => format!(
+                r#"{{"generation":{},"elapsed":{:.3},"bestCost":{:.3},"meanCost":{:.3},"unassigned":{}}}"#,
+                record.generation, record.elapsed, record.best_cost, record.mean_cost, record.unassigned
+            ),
+        };
+
+        let mut writer = writer.lock().unwrap();
+        if let Err(err) = writeln!(writer, "{}", line) {
+            eprintln!("cannot write search progress: {}", err);
+        }
+    }))
+}
+
+/// Creates a population dumper which writes each population snapshot as a single line in json
+/// format: individuals are written in rank order with their fitness values and routes.
+pub fn create_population_dumper(path: &str) -> Result<PopulationDumper, String> {
+    let writer = LineWriter::new(
+        File::create(path).map_err(|err| format!("cannot create population dump file '{}': {}", path, err))?,
+    );
+    let writer = Mutex::new(writer);
+
+    Ok(Arc::new(move |snapshot: &PopulationSnapshot| {
+        let individuals = snapshot
+            .individuals
+            .iter()
+            .map(|individual| {
+                let routes = individual
+                    .routes
+                    .iter()
+                    .map(|(vehicle, jobs)| serde_json::json!({ "vehicle": vehicle, "jobs": jobs }))
+                    .collect::<Vec<_>>();
+
+                serde_json::json!({ "fitness": individual.fitness, "routes": routes })
+            })
+            .collect::<Vec<_>>();
+        let line = serde_json::json!({ "generation": snapshot.generation, "individuals": individuals });
+
+        let mut writer = writer.lock().unwrap();
+        if let Err(err) = writeln!(writer, "{}", line) {
+            eprintln!("cannot write population snapshot: {}", err);
+        }
+    }))
+}
+
+#[derive(Clone, Copy)]
+enum ProgressFormat {
+    Csv,
+    Json,
 }
 
 fn configure_from_environment(environment_config: &Option<EnvironmentConfig>) -> Result<Arc<Environment>, String> {
     let mut environment = Environment::default();
 
+    let random_config = environment_config.as_ref().and_then(|c| c.random.as_ref());
+    let algorithm = match random_config.and_then(|config| config.algorithm.as_deref()) {
+        None | Some("std") => RandomAlgorithm::Std,
+        Some("chacha") => RandomAlgorithm::ChaCha,
+        Some("pcg") => RandomAlgorithm::Pcg,
+        Some("small") => RandomAlgorithm::Small,
+        Some(algorithm) => return Err(format!("unknown random algorithm: '{}'", algorithm)),
+    };
+
+    // NOTE seed is always set, so it can be reported to reproduce results
+    let seed = random_config.and_then(|config| config.seed);
+    environment.random = Arc::new(DefaultRandom::new_with_known_seed(algorithm, seed));
+    environment.is_deterministic = random_config.and_then(|config| config.deterministic).unwrap_or(false);
+
     // TODO validate parameters
     if let Some(config) = environment_config.as_ref().and_then(|c| c.parallelism.as_ref()) {
-        environment.parallelism = Parallelism::new(config.num_thread_pools, config.threads_per_pool);
+        let mut parallelism = Parallelism::new(config.num_thread_pools, config.threads_per_pool);
+
+        match config.solver_threads {
+            Some(0) => return Err("amount of solver threads should be positive".to_string()),
+            Some(num_threads) => parallelism = parallelism.with_solver_threads(num_threads),
+            None => {}
+        }
+
+        if let Some(phase_threads) = config.phase_threads.as_ref() {
+            parallelism = vec![
+                (ParallelismPhase::Construction, phase_threads.construction),
+                (ParallelismPhase::Mutation, phase_threads.mutation),
+                (ParallelismPhase::Evaluation, phase_threads.evaluation),
+            ]
+            .into_iter()
+            .filter_map(|(phase, num_threads)| num_threads.map(|num_threads| (phase, num_threads)))
+            .try_fold(parallelism, |parallelism, (phase, num_threads)| {
+                if num_threads == 0 {
+                    Err(format!("amount of threads for {:?} phase should be positive", phase))
+                } else {
+                    Ok(parallelism.with_phase_threads(phase, num_threads))
+                }
+            })?;
+        }
+
+        environment.parallelism = parallelism;
     }
 
     Ok(Arc::new(environment))
@@ -678,9 +1169,11 @@ pub fn create_builder_from_config(problem: Arc<Problem>, config: &Config) -> Res
     let mut builder = Builder::new(problem.clone(), environment.clone());
 
     builder = configure_from_telemetry(builder, &config.telemetry)?;
-    builder = configure_from_evolution(builder, &config.evolution, problem, environment.clone())?;
-    builder = configure_from_hyper(builder, &config.hyper, environment)?;
-    builder = configure_from_termination(builder, &config.termination)?;
+    builder = builder.with_memory_limit(config.environment.as_ref().and_then(|config| config.memory_limit));
+    builder = builder.with_insertion_cache(config.environment.as_ref().and_then(|config| config.insertion_cache));
+    builder = configure_from_evolution(builder, &config.evolution, problem.clone(), environment.clone())?;
+    builder = configure_from_hyper(builder, &config.hyper, environment.clone())?;
+    builder = configure_from_termination(builder, &config.termination, problem, environment)?;
 
     Ok(builder)
 }
@@ -38,7 +38,7 @@ use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
-use vrp_pragmatic::format::solution::PragmaticSolution;
+use vrp_pragmatic::format::solution::{check_must_serve_jobs, PragmaticSolution};
 use vrp_pragmatic::format::FormatError;
 use vrp_pragmatic::get_unique_locations;
 
@@ -249,6 +249,8 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
             .to_json()
         })?;
 
+    check_must_serve_jobs(problem.clone(), &solution).map_err(|err| err.to_json())?;
+
     let mut buffer = String::new();
     let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
     if let Some(metrics) = metrics {
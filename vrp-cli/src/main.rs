@@ -10,10 +10,13 @@ fn main() {
 
 #[cfg(not(target_arch = "wasm32"))]
 mod cli {
+    use super::commands::analyze::{get_analyze_app, run_analyze};
+    use super::commands::anonymize::{get_anonymize_app, run_anonymize};
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
+    use crate::commands::export::{get_export_app, run_export};
     use crate::commands::generate::{get_generate_app, run_generate};
     use clap::{crate_version, App};
     use std::process;
@@ -27,6 +30,9 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_analyze_app())
+            .subcommand(get_export_app())
+            .subcommand(get_anonymize_app())
             .get_matches();
 
         match matches.subcommand() {
@@ -34,6 +40,9 @@ mod cli {
             ("import", Some(import_matches)) => run_import(import_matches),
             ("check", Some(check_matches)) => run_check(check_matches),
             ("generate", Some(generate_matches)) => run_generate(generate_matches),
+            ("analyze", Some(analyze_matches)) => run_analyze(analyze_matches),
+            ("export", Some(export_matches)) => run_export(export_matches),
+            ("anonymize", Some(anonymize_matches)) => run_anonymize(anonymize_matches),
             ("", None) => {
                 eprintln!("No subcommand was used. Use -h to print help information.");
                 process::exit(1);
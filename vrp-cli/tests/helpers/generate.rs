@@ -9,16 +9,26 @@ pub fn create_empty_job() -> Job {
         replacements: None,
         services: None,
         priority: None,
+        value: None,
+        must_serve: None,
+        truck_only: None,
+        loading_policy: None,
+        compatibility: None,
         skills: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, tag: None }
+    JobTask { places: vec![], demand: None, volume: None, density: None, tag: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        preferred_times: None,
+    }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -26,26 +36,38 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: "car".to_string(),
-        costs: VehicleCosts { fixed: None, distance: 0.0, time: 0.0 },
+        costs: VehicleCosts { fixed: None, distance: 0.0, time: 0.0, zone_fees: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
                 latest: None,
                 location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                driver: None,
             },
             end: None,
             dispatch: None,
             breaks: None,
+            unavailability: None,
             reloads: None,
         }],
         capacity: vec![10],
+        volume: None,
+        trailer: None,
+        team: None,
+        crew_size: None,
         skills: None,
         limits: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> Profile {
-    Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }
+    Profile {
+        name: "car".to_string(),
+        profile_type: "car".to_string(),
+        speed: None,
+        distance_multiplier: None,
+        time_bucket: None,
+    }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -0,0 +1,11 @@
+use super::*;
+
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+
+#[test]
+fn can_run_analyze_matrix() {
+    let args = vec!["analyze", "pragmatic", "--matrix", PRAGMATIC_MATRIX_PATH];
+    let matches = get_analyze_app().get_matches_from_safe(args).unwrap();
+
+    run_analyze(&matches);
+}
@@ -0,0 +1,26 @@
+use super::*;
+use vrp_pragmatic::format::problem::deserialize_problem;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+#[test]
+fn can_run_anonymize_problem() {
+    let path = std::env::temp_dir().join("vrp_cli_anonymize_test.json");
+    let args = vec![
+        "anonymize",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--seed",
+        "42",
+        "-o",
+        path.to_str().unwrap(),
+    ];
+    let matches = get_anonymize_app().get_matches_from_safe(args).unwrap();
+
+    run_anonymize(&matches);
+
+    let problem = deserialize_problem(BufReader::new(File::open(&path).unwrap()));
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(problem.ok().map(|problem| problem.plan.jobs.len()), Some(3));
+}
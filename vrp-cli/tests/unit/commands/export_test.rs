@@ -0,0 +1,26 @@
+use super::*;
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_require_routing_url() {
+    let args = vec!["export", "pragmatic", "--solution-file", PRAGMATIC_SOLUTION_PATH, "--routing-api", "osrm"];
+
+    assert!(get_export_app().get_matches_from_safe(args).is_err());
+}
+
+#[test]
+fn can_validate_routing_api() {
+    let args = vec![
+        "export",
+        "pragmatic",
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--routing-api",
+        "unknown",
+        "--routing-url",
+        "http://localhost:5000",
+    ];
+
+    assert!(get_export_app().get_matches_from_safe(args).is_err());
+}
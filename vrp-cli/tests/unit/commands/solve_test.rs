@@ -28,6 +28,166 @@ fn can_solve_pragmatic_problem_with_generation_limit() {
     run_solve_with_out_writer(&matches);
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_statistics() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "10", "--problem-statistics"];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_pareto_output() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "10", "--pareto"];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_seed() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "10", "--seed", "42"];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_in_deterministic_mode() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--seed",
+        "42",
+        "--deterministic",
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    assert!(get_environment(&matches).is_deterministic);
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_dedicated_threads() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--solver-threads",
+        "1",
+        "--phase-threads",
+        "1,2,1",
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    let environment = get_environment(&matches);
+    let parallelism = &environment.parallelism;
+    assert_eq!(parallelism.solver_threads(), Some(1));
+    assert_eq!(parallelism.available_cpus(), 1);
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Mutation), Some(2));
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_progress() {
+    let path = std::env::temp_dir().join("vrp_cli_solve_progress_test.json");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--progress",
+        path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(content.lines().count() > 0);
+    assert!(content.lines().all(|line| line.starts_with(r#"{"generation":"#)));
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_trace() {
+    let path = std::env::temp_dir().join("vrp_cli_solve_trace_test.csv");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--trace",
+        path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(content.lines().count() > 1);
+    assert_eq!(content.lines().next(), Some("generation,individual,mutation,cost_before,cost_after,is_improved"));
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_alternative() {
+    let path = std::env::temp_dir().join("vrp_cli_solve_alternative_test.json");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--alternative",
+        "1",
+        "--out-alternative",
+        path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    assert!(path.exists());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_geometry() {
+    let path = std::env::temp_dir().join("vrp_cli_solve_geometry_test.json");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--out-result",
+        path.to_str().unwrap(),
+        "--geometry",
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve(&matches, |file| BufWriter::new(Box::new(file.unwrap())));
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(content.contains(r#""geometry": {"#));
+}
+
+#[test]
+fn can_require_out_alternative_path() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--alternative", "1"];
+
+    assert!(get_solve_app().get_matches_from_safe(args).is_err());
+}
+
 #[test]
 fn can_solve_solomon_problem_with_generation_limit() {
     let args = vec!["solve", "solomon", SOLOMON_PROBLEM_PATH, "--max-generations", "10"];
@@ -58,3 +218,14 @@ fn can_specify_search_mode_setting() {
         get_solve_app().get_matches_from_safe(args).unwrap();
     }
 }
+
+#[test]
+fn can_reject_pareto_output_for_non_pragmatic_format() {
+    let formats = get_formats(Arc::new(DefaultRandom::default()));
+    let (problem_reader, _, _, _, pareto_writer) = formats.get("solomon").unwrap();
+    let problem = Arc::new(problem_reader.0(open_file(SOLOMON_PROBLEM_PATH, "problem"), None).unwrap());
+
+    let result = pareto_writer.0(&problem, vec![], BufWriter::new(Box::new(DummyWrite {})));
+
+    assert_eq!(result, Err("pareto output is supported only for pragmatic format".to_string()));
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+fn create_matrix(travel_times: Vec<i64>, distances: Vec<i64>, error_codes: Option<Vec<i64>>) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times,
+        distances,
+        error_codes,
+        travel_time_deviations: None,
+        tolls: None,
+    }
+}
+
+#[test]
+fn can_analyze_consistent_matrix() {
+    let matrix = create_matrix(
+        vec![0, 100, 200, 100, 0, 100, 200, 100, 0],
+        vec![0, 1000, 2000, 1000, 0, 1000, 2000, 1000, 0],
+        None,
+    );
+
+    let report = analyze_matrix(&matrix).unwrap();
+
+    assert_eq!(report.size, 3);
+    assert_eq!(report.asymmetry.max_ratio, 0.);
+    assert_eq!(report.triangle_violations.count, 0);
+    assert_eq!(report.zero_entries.count, 0);
+    assert_eq!(report.absurd_entries.count, 0);
+    assert_eq!(report.speed_inconsistencies.count, 0);
+}
+
+#[test]
+fn can_detect_asymmetry() {
+    let matrix = create_matrix(vec![0, 100, 40, 0], vec![0, 1000, 1000, 0], None);
+
+    let report = analyze_matrix(&matrix).unwrap();
+
+    assert_eq!(report.asymmetry.max_ratio, 0.6);
+    assert_eq!(report.asymmetry.average_ratio, 0.6);
+}
+
+#[test]
+fn can_detect_triangle_violations() {
+    let matrix = create_matrix(
+        vec![0, 100, 500, 100, 0, 100, 500, 100, 0],
+        vec![0, 1000, 2000, 1000, 0, 1000, 2000, 1000, 0],
+        None,
+    );
+
+    let report = analyze_matrix(&matrix).unwrap();
+
+    assert_eq!(report.triangle_violations.count, 2);
+    assert_eq!(report.triangle_violations.examples, vec![(0, 2), (2, 0)]);
+}
+
+#[test]
+fn can_detect_zero_and_absurd_entries() {
+    let matrix = create_matrix(
+        vec![0, 0, 100, 100, 0, 100, 100, MAX_DURATION + 1, 0],
+        vec![0, 1000, 1000, 1000, 0, -1, 1000, 1000, 0],
+        None,
+    );
+
+    let report = analyze_matrix(&matrix).unwrap();
+
+    assert_eq!(report.zero_entries.count, 1);
+    assert_eq!(report.zero_entries.examples, vec![(0, 1)]);
+    assert_eq!(report.absurd_entries.count, 2);
+    assert_eq!(report.absurd_entries.examples, vec![(1, 2), (2, 1)]);
+}
+
+#[test]
+fn can_detect_speed_inconsistencies() {
+    let matrix = create_matrix(vec![0, 10, 10000, 0], vec![0, 10000, 2000, 0], None);
+
+    let report = analyze_matrix(&matrix).unwrap();
+
+    assert_eq!(report.speed_inconsistencies.count, 2);
+    assert_eq!(report.speed_inconsistencies.examples, vec![(0, 1), (1, 0)]);
+}
+
+#[test]
+fn can_ignore_unreachable_locations() {
+    let matrix = create_matrix(vec![0, 0, -1, 0], vec![0, 0, -1, 0], Some(vec![0, 1, 1, 0]));
+
+    let report = analyze_matrix(&matrix).unwrap();
+
+    assert_eq!(report.zero_entries.count, 0);
+    assert_eq!(report.absurd_entries.count, 0);
+}
+
+#[test]
+fn can_return_error_for_non_square_matrix() {
+    let matrix = create_matrix(vec![0, 1, 1], vec![0, 1, 1], None);
+
+    assert!(analyze_matrix(&matrix).is_err());
+}
@@ -0,0 +1,162 @@
+use super::*;
+use std::fs::File;
+use vrp_core::utils::DefaultRandom;
+
+const SIMPLE_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const INDEX_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.index.problem.json";
+const RELATION_PROBLEM_PATH: &str = "../examples/data/pragmatic/basics/relation-strict.basic.problem.json";
+const MULTI_JOB_PROBLEM_PATH: &str = "../examples/data/pragmatic/basics/multi-job.basic.problem.json";
+
+fn read_problem(path: &str) -> Problem {
+    deserialize_problem(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+fn anonymize(path: &str, demand_perturbation: f64, seed: u64) -> (Problem, Problem) {
+    let original = read_problem(path);
+    let anonymized = anonymize_with_seed(&original, demand_perturbation, seed);
+
+    (original, anonymized)
+}
+
+fn anonymize_with_seed(problem: &Problem, demand_perturbation: f64, seed: u64) -> Problem {
+    anonymize_problem(problem, &AnonymizationOptions { demand_perturbation }, &DefaultRandom::new_with_seed(seed))
+}
+
+fn get_job_locations(problem: &Problem) -> Vec<Location> {
+    problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flatten()
+                .flat_map(|task| task.places.iter().map(|place| place.location.clone()))
+        })
+        .collect()
+}
+
+fn get_demands(tasks: &Option<Vec<JobTask>>) -> Vec<i32> {
+    tasks.iter().flatten().flat_map(|task| task.demand.iter().flatten().cloned()).collect()
+}
+
+fn get_haversine_distance(a: &Location, b: &Location) -> f64 {
+    match (a, b) {
+        (Location::Coordinate { lat: lat1, lng: lng1 }, Location::Coordinate { lat: lat2, lng: lng2 }) => {
+            let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+            let (d_lat, d_lng) = (lat2 - lat1, (lng2 - lng1).to_radians());
+            let a = (d_lat / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.).sin().powi(2);
+
+            6_371_000. * 2. * a.sqrt().asin()
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn can_rename_ids_consistently() {
+    let (original, anonymized) = anonymize(RELATION_PROBLEM_PATH, 0., 0);
+
+    let original_job_ids = original.plan.jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+    let job_ids = anonymized.plan.jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+    let vehicle_ids =
+        anonymized.fleet.vehicles.iter().flat_map(|vehicle| vehicle.vehicle_ids.clone()).collect::<Vec<_>>();
+    let relations = anonymized.plan.relations.unwrap();
+
+    assert_eq!(job_ids, (1..=original_job_ids.len()).map(|idx| format!("job{}", idx)).collect::<Vec<_>>());
+    assert!(vehicle_ids.iter().all(|id| id.starts_with("vehicle")));
+    assert!(!relations.is_empty());
+    relations.iter().for_each(|relation| {
+        assert!(vehicle_ids.contains(&relation.vehicle_id));
+        assert!(relation.jobs.iter().all(|id| job_ids.contains(id) || id == "departure" || id == "arrival"));
+    });
+    original.plan.relations.unwrap().iter().zip(relations.iter()).for_each(|(original, relation)| {
+        original.jobs.iter().zip(relation.jobs.iter()).filter(|(id, _)| original_job_ids.contains(id)).for_each(
+            |(original_id, id)| {
+                let idx = original_job_ids.iter().position(|job_id| job_id == original_id).unwrap();
+                assert_eq!(id, &job_ids[idx]);
+            },
+        );
+    });
+}
+
+#[test]
+fn can_move_coordinates_keeping_distances() {
+    let (original, anonymized) = anonymize(SIMPLE_PROBLEM_PATH, 0., 0);
+    let original = get_job_locations(&original);
+    let anonymized = get_job_locations(&anonymized);
+
+    assert!(original.iter().zip(anonymized.iter()).all(|(a, b)| get_haversine_distance(a, b) > 100_000.));
+    (0..original.len()).flat_map(|i| (0..original.len()).map(move |j| (i, j))).for_each(|(i, j)| {
+        let expected = get_haversine_distance(&original[i], &original[j]);
+        let actual = get_haversine_distance(&anonymized[i], &anonymized[j]);
+
+        assert!((expected - actual).abs() <= expected * 0.01 + 0.1, "{} vs {}", expected, actual);
+    });
+}
+
+#[test]
+fn can_keep_reference_locations() {
+    let (original, anonymized) = anonymize(INDEX_PROBLEM_PATH, 0., 0);
+
+    let to_indices = |problem: &Problem| {
+        get_job_locations(problem)
+            .into_iter()
+            .map(|location| match location {
+                Location::Reference { index } => index,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(to_indices(&original), to_indices(&anonymized));
+}
+
+parameterized_test! {can_perturb_demands_keeping_job_consistency, seed, {
+    can_perturb_demands_keeping_job_consistency_impl(seed);
+}}
+
+can_perturb_demands_keeping_job_consistency! {
+    case01: 0,
+    case02: 1,
+    case03: 2,
+    case04: 3,
+}
+
+fn can_perturb_demands_keeping_job_consistency_impl(seed: u64) {
+    let mut original = read_problem(MULTI_JOB_PROBLEM_PATH);
+    original
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| job.pickups.iter_mut().chain(job.deliveries.iter_mut()).flatten())
+        .for_each(|task| task.demand.iter_mut().flatten().for_each(|value| *value *= 10));
+
+    let anonymized = anonymize_with_seed(&original, 0.5, seed);
+
+    assert!(original.plan.jobs.iter().zip(anonymized.plan.jobs.iter()).any(|(original, anonymized)| get_demands(
+        &original.pickups
+    ) != get_demands(
+        &anonymized.pickups
+    )));
+    anonymized.plan.jobs.iter().for_each(|job| {
+        let pickups = get_demands(&job.pickups);
+        let deliveries = get_demands(&job.deliveries);
+
+        assert!(pickups.iter().chain(deliveries.iter()).all(|demand| *demand > 0));
+        assert_eq!(pickups.iter().sum::<i32>(), deliveries.iter().sum::<i32>());
+    });
+}
+
+#[test]
+fn can_reproduce_result_with_the_same_seed() {
+    let serialize = |problem: &Problem| serde_json::to_string(problem).unwrap();
+
+    let (_, first) = anonymize(SIMPLE_PROBLEM_PATH, 0.1, 42);
+    let (_, second) = anonymize(SIMPLE_PROBLEM_PATH, 0.1, 42);
+
+    assert_eq!(serialize(&first), serialize(&second));
+}
@@ -0,0 +1,69 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+struct FakeRoutingApi {
+    legs: Option<usize>,
+}
+
+impl RoutingApi for FakeRoutingApi {
+    fn route(&self, waypoints: &[Coordinate]) -> Result<Vec<RouteLeg>, String> {
+        let legs = self.legs.unwrap_or(waypoints.len() - 1);
+
+        Ok(waypoints
+            .windows(2)
+            .take(legs)
+            .enumerate()
+            .map(|(idx, pair)| RouteLeg {
+                distance: (idx + 1) as f64 * 100.,
+                duration: (idx + 1) as f64 * 10.,
+                geometry: pair.to_vec(),
+            })
+            .collect())
+    }
+}
+
+fn get_solution() -> Solution {
+    deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap()
+}
+
+#[test]
+fn can_export_navigation() {
+    let solution = get_solution();
+
+    let documents = export_navigation(&solution, &FakeRoutingApi { legs: None }).unwrap();
+
+    assert_eq!(documents.len(), 1);
+    let document = documents.first().unwrap();
+    assert_eq!(document.vehicle_id, "vehicle_1");
+    assert_eq!(document.legs.len(), 4);
+    assert_eq!(document.distance, 1000.);
+    assert_eq!(document.duration, 100.);
+
+    let leg = document.legs.first().unwrap();
+    assert_eq!((leg.from_stop, leg.to_stop), (0, 1));
+    assert_eq!(leg.geometry, vec![(13.3884, 52.5316), (13.45413, 52.52599)]);
+    assert_eq!(leg.arrival.arrival, solution.tours[0].stops[1].time.arrival);
+    assert_eq!(leg.arrival.activities, vec![("job1".to_string(), "delivery".to_string())]);
+    assert_eq!(leg.arrival.notes, None);
+}
+
+#[test]
+fn can_detect_unexpected_amount_of_legs() {
+    let result = export_navigation(&get_solution(), &FakeRoutingApi { legs: Some(2) });
+
+    assert_eq!(result.err(), Some("routing api returned 2 legs for tour of 'vehicle_1' with 5 stops".to_string()));
+}
+
+#[test]
+fn can_detect_stops_without_coordinates() {
+    let mut solution = get_solution();
+    solution.tours[0].stops[2].location = Location::Reference { index: 0 };
+
+    let result = export_navigation(&solution, &FakeRoutingApi { legs: None });
+
+    assert_eq!(result.err(), Some("tour of 'vehicle_1' has stop without geo coordinate".to_string()));
+}
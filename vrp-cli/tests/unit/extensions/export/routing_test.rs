@@ -0,0 +1,137 @@
+use super::*;
+#[cfg(feature = "routing-api")]
+use std::io::{Read, Write};
+#[cfg(feature = "routing-api")]
+use std::net::TcpListener;
+#[cfg(feature = "routing-api")]
+use std::thread;
+
+const OSRM_RESPONSE: &str = r#"{
+  "code": "Ok",
+  "routes": [{
+    "legs": [
+      { "distance": 120.5, "duration": 15.2, "steps": [
+          { "geometry": { "coordinates": [[13.1, 52.1], [13.2, 52.2]] } },
+          { "geometry": { "coordinates": [[13.2, 52.2], [13.3, 52.3]] } }
+      ]},
+      { "distance": 80, "duration": 9, "steps": [
+          { "geometry": { "coordinates": [[13.3, 52.3], [13.4, 52.4]] } }
+      ]}
+    ]
+  }]
+}"#;
+
+#[cfg(feature = "routing-api")]
+fn serve_once(head: String, body: Vec<u8>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buffer = [0; 4096];
+        let _ = stream.read(&mut buffer).unwrap();
+        stream.write_all(head.as_bytes()).unwrap();
+        stream.write_all(body.as_slice()).unwrap();
+    });
+
+    format!("http://{}", address)
+}
+
+#[cfg(feature = "routing-api")]
+fn create_chunked_body(body: &str) -> Vec<u8> {
+    let (first, second) = body.split_at(body.len() / 2);
+
+    format!("{:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n", first.len(), first, second.len(), second).into_bytes()
+}
+
+#[test]
+fn can_parse_osrm_response() {
+    let legs = parse_osrm_response(OSRM_RESPONSE.as_bytes()).unwrap();
+
+    assert_eq!(legs.len(), 2);
+    assert_eq!((legs[0].distance, legs[0].duration), (120.5, 15.2));
+    assert_eq!(legs[0].geometry, vec![(13.1, 52.1), (13.2, 52.2), (13.3, 52.3)]);
+    assert_eq!((legs[1].distance, legs[1].duration), (80., 9.));
+    assert_eq!(legs[1].geometry, vec![(13.3, 52.3), (13.4, 52.4)]);
+}
+
+#[test]
+fn can_handle_osrm_error_response() {
+    let result = parse_osrm_response(r#"{ "code": "NoRoute", "message": "Impossible route" }"#.as_bytes());
+
+    assert_eq!(result.err(), Some("OSRM returned 'NoRoute': Impossible route".to_string()));
+}
+
+#[test]
+fn can_parse_valhalla_response() {
+    let response = r#"{
+      "trip": { "legs": [
+        { "summary": { "length": 1.5, "time": 120.0 }, "shape": "_izlhA~rlgdF_{geC~ywl@_kwzCn`{nI" }
+      ]}
+    }"#;
+
+    let legs = parse_valhalla_response(response.as_bytes()).unwrap();
+
+    assert_eq!(legs.len(), 1);
+    assert_eq!((legs[0].distance, legs[0].duration), (1500., 120.));
+    assert_eq!(legs[0].geometry, vec![(-120.2, 38.5), (-120.95, 40.7), (-126.453, 43.252)]);
+}
+
+parameterized_test! {can_decode_polyline, (encoded, precision, expected), {
+    can_decode_polyline_impl(encoded, precision, expected);
+}}
+
+can_decode_polyline! {
+    case01: ("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5, Ok(vec![(-120.2, 38.5), (-120.95, 40.7), (-126.453, 43.252)])),
+    case02: ("", 5, Ok(vec![])),
+    case03: ("_p~iF", 5, Err("unexpected end of polyline".to_string())),
+    case04: ("_p~iF~ps|", 5, Err("unexpected end of polyline".to_string())),
+    case05: ("_p~iF ps|U", 5, Err("invalid polyline character".to_string())),
+    case06: ("______________", 5, Err("polyline value is too long".to_string())),
+}
+
+fn can_decode_polyline_impl(encoded: &str, precision: u32, expected: Result<Vec<Coordinate>, String>) {
+    let result = decode_polyline(encoded, precision);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[cfg(feature = "routing-api")]
+fn can_route_using_osrm_api() {
+    let head = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nConnection: close\r\n";
+    let url = serve_once(
+        format!("{}Content-Length: {}\r\n\r\n", head, OSRM_RESPONSE.len()),
+        OSRM_RESPONSE.as_bytes().to_vec(),
+    );
+
+    let legs = OsrmApi::new(url.as_str(), "driving").route(&[(13.1, 52.1), (13.3, 52.3), (13.4, 52.4)]).unwrap();
+
+    assert_eq!(legs.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "routing-api")]
+fn can_route_using_osrm_api_with_chunked_response() {
+    let url = serve_once(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n".to_string(),
+        create_chunked_body(OSRM_RESPONSE),
+    );
+
+    let legs = OsrmApi::new(url.as_str(), "driving").route(&[(13.1, 52.1), (13.3, 52.3), (13.4, 52.4)]).unwrap();
+
+    assert_eq!(legs.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "routing-api")]
+fn can_handle_error_status_with_non_utf8_body() {
+    let url = serve_once(
+        "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 4\r\n\r\n".to_string(),
+        vec![b'e', 0xff, 0xfe, b'r'],
+    );
+
+    let result = OsrmApi::new(url.as_str(), "driving").route(&[(13.1, 52.1), (13.3, 52.3)]);
+
+    assert_eq!(result.err(), Some("routing service returned status '500': e\u{fffd}\u{fffd}r".to_string()));
+}
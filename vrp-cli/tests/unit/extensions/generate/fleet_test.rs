@@ -5,13 +5,15 @@ use vrp_pragmatic::format::problem::{Plan, Profile};
 #[test]
 fn can_generate_fleet_of_specific_size() {
     let prototype = Problem {
-        plan: Plan { jobs: vec![], relations: None },
+        plan: Plan { jobs: vec![], relations: None, clustering: None },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![Profile {
                 name: "normal_car".to_string(),
                 profile_type: "car_type".to_string(),
                 speed: None,
+                distance_multiplier: None,
+                time_bucket: None,
             }],
         },
         objectives: None,
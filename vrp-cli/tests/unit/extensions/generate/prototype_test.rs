@@ -13,6 +13,7 @@ fn can_generate_jobs_with_time_windows() {
                 create_test_job(1., 2.),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![create_test_vehicle_profile()] },
         objectives: None,
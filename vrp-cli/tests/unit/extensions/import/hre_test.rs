@@ -168,7 +168,12 @@ fn can_write_hre_problem() {
                         demand: Some(vec![1]),
                         ..create_empty_job_task()
                     }]),
-                    skills: Some(JobSkills { all_of: Some(vec!["skill1".to_string()]), one_of: None, none_of: None }),
+                    skills: Some(JobSkills {
+                        all_of: Some(vec!["skill1".to_string()]),
+                        one_of: None,
+                        none_of: None,
+                        preferred: None,
+                    }),
                     ..create_empty_job()
                 },
                 Job {
@@ -260,18 +265,20 @@ fn can_write_hre_problem() {
                     shift_index: None,
                 },
             ]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 type_id: "vehicle".to_string(),
                 vehicle_ids: vec!["vehicle_1".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 0.0 },
+                costs: VehicleCosts { fixed: None, distance: 0.0, time: 0.0, zone_fees: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "2020-05-01T09:00:00.00Z".to_string(),
                         latest: None,
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        driver: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
@@ -286,19 +293,34 @@ fn can_write_hre_problem() {
                         ]),
                         duration: 1800.,
                         locations: None,
+                        policy: None,
                     }]),
+                    unavailability: None,
                     reloads: None,
                 }],
                 capacity: vec![10],
+                volume: None,
+                trailer: None,
+                team: None,
+                crew_size: None,
                 skills: Some(vec!["skill1".to_string()]),
                 limits: Some(VehicleLimits {
                     max_distance: Some(10000.),
                     shift_time: Some(14400.),
                     tour_size: None,
+                    max_radius: None,
+                    overload: None,
                     allowed_areas: None,
+                    overtime: None,
                 }),
             }],
-            profiles: vec![Profile { name: "normal_car".to_string(), profile_type: "car".to_string(), speed: None }],
+            profiles: vec![Profile {
+                name: "normal_car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                distance_multiplier: None,
+                time_bucket: None,
+            }],
         },
         objectives: None,
     };
@@ -1,6 +1,7 @@
 use super::*;
 use std::fs::File;
 use vrp_core::models::examples::create_example_problem;
+use vrp_core::solver::IndividualSnapshot;
 
 #[test]
 fn can_read_full_config() {
@@ -36,6 +37,7 @@ fn can_read_full_config() {
             rebalance_memory,
             rebalance_count,
             exploration_ratio,
+            network_export,
         } => {
             assert_eq!(selection_size, Some(12));
             assert_eq!(max_elite_size, Some(2));
@@ -47,13 +49,20 @@ fn can_read_full_config() {
             assert_eq!(rebalance_memory, Some(500));
             assert_eq!(rebalance_count, Some(4));
             assert_eq!(exploration_ratio, Some(0.9));
+            assert!(network_export.is_none());
         }
         PopulationType::Elitism { .. } => unreachable!(),
     }
 
     let hyper_config = config.hyper.expect("cannot get hyper");
     match hyper_config {
-        HyperType::StaticSelective { mutations } => {
+        HyperType::StaticSelective { mutations, acceptance } => {
+            match acceptance.expect("no acceptance config") {
+                AcceptanceType::SimulatedAnnealing { initial_temperature, cooling_rate } => {
+                    assert_eq!(initial_temperature, 0.01);
+                    assert_eq!(cooling_rate, 0.999);
+                }
+            }
             assert_eq!(mutations.len(), 4);
             match mutations.first().unwrap() {
                 MutationType::Decomposition { routes, repeat, probability } => {
@@ -73,10 +82,13 @@ fn can_read_full_config() {
             }
 
             match mutations.get(1).unwrap() {
-                MutationType::LocalSearch { probability, times, operators: inners } => {
+                MutationType::LocalSearch { probability, times, operators: inners, .. } => {
                     assert_eq!(as_scalar_probability(probability), 0.05);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 3);
+                    assert_eq!(inners.len(), 6);
+                    assert!(matches!(inners[3], LocalOperatorType::TwoOpt { weight: 20, max_segment: Some(8) }));
+                    assert!(matches!(inners[4], LocalOperatorType::OrOpt { weight: 20, max_segment: None }));
+                    assert!(matches!(inners[5], LocalOperatorType::ExchangeSequence { weight: 20, min: 2, max: 4 }));
                 }
                 _ => unreachable!(),
             }
@@ -91,23 +103,44 @@ fn can_read_full_config() {
             }
 
             match mutations.last().unwrap() {
-                MutationType::LocalSearch { probability, times, operators: inners } => {
+                MutationType::LocalSearch { probability, times, operators: inners, tabu } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
                     assert_eq!(inners.len(), 3);
+                    assert_eq!(*tabu, Some(10));
                 }
                 _ => unreachable!(),
             }
         }
+        HyperType::DynamicSelective => unreachable!(),
     }
 
     let termination = config.termination.expect("no termination config");
     assert_eq!(termination.max_time, Some(300));
     assert_eq!(termination.max_generations, Some(3000));
+    assert_eq!(termination.all_assigned, Some(false));
+    assert_eq!(termination.goals, Some(GoalsMode::Any));
+    let time_budget = termination.time_budget.expect("no time budget config");
+    assert_eq!(time_budget.construction, 0.1);
+    assert_eq!(time_budget.search, 0.9);
+    assert_eq!(time_budget.post_optimization, 0.);
+    assert!(time_budget.post_optimization_mutation.is_some());
 
-    let parallelism = config.environment.expect("no environment config").parallelism.expect("no parallelism config");
+    let environment = config.environment.expect("no environment config");
+    let random = environment.random.expect("no random config");
+    assert_eq!(random.algorithm, Some("pcg".to_string()));
+    assert_eq!(random.seed, None);
+    assert_eq!(random.deterministic, Some(false));
+    assert_eq!(environment.memory_limit, Some(4096));
+    assert_eq!(environment.insertion_cache, Some(10000));
+    let parallelism = environment.parallelism.expect("no parallelism config");
     assert_eq!(parallelism.num_thread_pools, 6);
     assert_eq!(parallelism.threads_per_pool, 8);
+    assert_eq!(parallelism.solver_threads, Some(8));
+    let phase_threads = parallelism.phase_threads.expect("no phase threads config");
+    assert_eq!(phase_threads.construction, Some(2));
+    assert_eq!(phase_threads.mutation, Some(4));
+    assert_eq!(phase_threads.evaluation, Some(1));
 }
 
 #[test]
@@ -125,6 +158,14 @@ fn can_create_builder_from_config() {
     assert_eq!(builder.config.population.initial.methods.len(), 1);
     assert_eq!(builder.max_time, Some(300));
     assert_eq!(builder.max_generations, Some(3000));
+    assert_eq!(builder.time_budget, Some((0.1, 0.9, 0.)));
+    assert!(builder.config.post_optimization.is_some());
+    assert_eq!(builder.config.memory_guard.as_ref().map(|guard| guard.limit()), Some(4096 * 1024 * 1024));
+    let parallelism = &builder.config.environment.parallelism;
+    assert_eq!(parallelism.solver_threads(), Some(8));
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Construction), Some(2));
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Mutation), Some(4));
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Evaluation), Some(1));
 }
 
 #[test]
@@ -137,9 +178,285 @@ fn can_create_default_config() {
     assert!(config.telemetry.is_none());
 }
 
+#[test]
+fn can_create_temporal_decomposition_mutation() {
+    let config =
+        r#"{"type":"temporal-decomposition","slices":{"min":2,"max":3},"repeat":4,"probability":{"scalar":0.1}}"#;
+    let mutation: MutationType = serde_json::from_str(config).unwrap();
+    let problem = create_example_problem();
+
+    let result = create_mutation(&problem, Environment::default().random, &mutation).map(|_| ());
+    assert!(result.is_ok());
+
+    let mutation = MutationType::TemporalDecomposition {
+        slices: MinMaxConfig { min: 1, max: 3 },
+        repeat: 4,
+        probability: MutationProbabilityType::Scalar { scalar: 0.1 },
+    };
+    let result = create_mutation(&problem, Environment::default().random, &mutation).map(|_| ());
+    assert_eq!(result, Err("min slices must be greater than 2. Specified: 1".to_string()));
+}
+
+#[test]
+fn can_create_route_reduction_mutation() {
+    let config = r#"{"type":"route-reduction","attempts":3,"probability":{"scalar":0.01},
+                     "recreates":[{"type":"cheapest","weight":1},{"type":"regret","weight":1,"start":2,"end":3}]}"#;
+    let mutation: MutationType = serde_json::from_str(config).unwrap();
+    let problem = create_example_problem();
+
+    assert!(matches!(&mutation, MutationType::RouteReduction { attempts: 3, recreates, .. } if recreates.len() == 2));
+    let result = create_mutation(&problem, Environment::default().random, &mutation).map(|_| ());
+    assert!(result.is_ok());
+
+    let mutation = MutationType::RouteReduction {
+        probability: MutationProbabilityType::Scalar { scalar: 0.01 },
+        attempts: 0,
+        recreates: vec![],
+    };
+    let result = create_mutation(&problem, Environment::default().random, &mutation).map(|_| ());
+    assert_eq!(result, Err("attempts must be greater than 1. Specified: 0".to_string()));
+}
+
+#[test]
+fn can_configure_goal_termination() {
+    let config = r#"{"maxTime":10,"targetCost":100,"minImprovement":{"generations":200,"epsilon":0.001},
+                     "allAssigned":true,"goals":"all"}"#;
+    let config: TerminationConfig = serde_json::from_str(config).unwrap();
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let builder = Builder::new(problem.clone(), environment.clone());
+
+    let builder = configure_from_termination(builder, &Some(config), problem, environment).unwrap();
+
+    assert_eq!(builder.max_time, Some(10));
+    assert_eq!(builder.target_cost, Some(100.));
+    assert_eq!(builder.min_improvement, Some((200, 0.001)));
+    assert!(builder.all_assigned);
+    assert!(builder.all_goals);
+}
+
+#[test]
+fn can_read_split_and_merge_route_operators() {
+    let config = r#"[{"type":"split-route","weight":5},{"type":"merge-routes","weight":10}]"#;
+
+    let operators: Vec<LocalOperatorType> = serde_json::from_str(config).unwrap();
+
+    assert_eq!(operators.len(), 2);
+    assert!(matches!(operators[0], LocalOperatorType::SplitRoute { weight: 5 }));
+    assert!(matches!(operators[1], LocalOperatorType::MergeRoutes { weight: 10 }));
+    create_local_search(&MinMaxConfig { min: 1, max: 2 }, operators.as_slice());
+}
+
+#[test]
+fn can_create_worst_segment_ruin_method() {
+    let config =
+        r#"{"type":"worst-segment","probability":1,"min":8,"max":16,"threshold":0.1,"skip":2,"smin":2,"smax":4}"#;
+    let method: RuinMethod = serde_json::from_str(config).unwrap();
+
+    let (_, probability) = create_ruin_method(&create_example_problem(), Environment::default().random, &method);
+
+    assert_eq!(probability, 1.);
+}
+
+#[test]
+fn can_create_k_medoids_ruin_method() {
+    let config = r#"{"type":"k-medoids","probability":0.5,"min":8,"max":16,"threshold":0.1,"kmin":2,"kmax":4}"#;
+    let method: RuinMethod = serde_json::from_str(config).unwrap();
+
+    assert!(matches!(method, RuinMethod::KMedoids { kmin: 2, kmax: 4, .. }));
+    let (_, probability) = create_ruin_method(&create_example_problem(), Environment::default().random, &method);
+    assert_eq!(probability, 0.5);
+}
+
+#[test]
+fn can_create_adjusted_string_ruin_method() {
+    let config = r#"{"type":"adjusted-string","probability":1,"lmax":20,"cavg":15,"alpha":0.02,"splitRate":0.3}"#;
+    let method: RuinMethod = serde_json::from_str(config).unwrap();
+
+    assert!(matches!(
+        method,
+        RuinMethod::AdjustedString { lmax: 20, cavg: 15, split_rate: Some(split_rate), .. } if split_rate == 0.3
+    ));
+    let (_, probability) = create_ruin_method(&create_example_problem(), Environment::default().random, &method);
+    assert_eq!(probability, 1.);
+}
+
+#[test]
+fn can_create_demand_job_ruin_method() {
+    let config = r#"{"type":"demand-job","probability":1,"min":8,"max":16,"threshold":0.1}"#;
+    let method: RuinMethod = serde_json::from_str(config).unwrap();
+
+    let (_, probability) = create_ruin_method(&create_example_problem(), Environment::default().random, &method);
+
+    assert_eq!(probability, 1.);
+}
+
 fn as_scalar_probability(probability: &MutationProbabilityType) -> f64 {
     match probability {
         MutationProbabilityType::Scalar { scalar } => *scalar,
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_detect_unknown_network_export_format() {
+    let config = NetworkExportConfig { directory: ".".to_string(), frequency: 100, format: Some("xml".to_string()) };
+
+    let result = create_network_export(&config).map(|_| ());
+
+    assert_eq!(result, Err("unknown network export format: 'xml'".to_string()));
+}
+
+#[test]
+fn can_write_progress_in_csv_format() {
+    let path = std::env::temp_dir().join("vrp_cli_progress_test.csv");
+    let path = path.to_str().unwrap();
+
+    let sink = create_progress_sink(path, None).unwrap();
+    sink(&ProgressRecord { generation: 1, elapsed: 0.5, best_cost: 10., mean_cost: 12.5, unassigned: 2 });
+    drop(sink);
+
+    let content = std::fs::read_to_string(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(content, "generation,elapsed,best_cost,mean_cost,unassigned\n1,0.500,10.000,12.500,2\n");
+}
+
+#[test]
+fn can_write_mutation_trace() {
+    let path = std::env::temp_dir().join("vrp_cli_trace_test.csv");
+    let path = path.to_str().unwrap();
+
+    let tracer = create_mutation_tracer(path).unwrap();
+    tracer(&MutationTrace {
+        generation: 2,
+        individual: 1,
+        mutation: "ruin-recreate".to_string(),
+        cost_before: 10.,
+        cost_after: 9.5,
+        is_improved: true,
+    });
+    drop(tracer);
+
+    let content = std::fs::read_to_string(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(
+        content,
+        "generation,individual,mutation,cost_before,cost_after,is_improved\n2,1,ruin-recreate,10.000,9.500,true\n"
+    );
+}
+
+#[test]
+fn can_write_population_snapshot() {
+    let path = std::env::temp_dir().join("vrp_cli_population_dump_test.json");
+    let path = path.to_str().unwrap();
+
+    let dumper = create_population_dumper(path).unwrap();
+    dumper(&PopulationSnapshot {
+        generation: 100,
+        individuals: vec![IndividualSnapshot {
+            fitness: vec![0., 12.5],
+            routes: vec![("v1".to_string(), vec!["job1".to_string(), "job2".to_string()])],
+        }],
+    });
+    drop(dumper);
+
+    let content = std::fs::read_to_string(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(
+        content,
+        r#"{"generation":100,"individuals":[{"fitness":[0.0,12.5],"routes":[{"jobs":["job1","job2"],"vehicle":"v1"}]}]}"#
+            .to_string()
+            + "\n"
+    );
+}
+
+#[test]
+fn can_detect_unknown_progress_format() {
+    let result = create_progress_sink("progress.xml", Some("xml")).map(|_| ());
+
+    assert_eq!(result, Err("unknown progress format: 'xml'".to_string()));
+}
+
+#[test]
+fn can_detect_invalid_phase_threads() {
+    let config = EnvironmentConfig {
+        parallelism: Some(ParallelismConfig {
+            num_thread_pools: 1,
+            threads_per_pool: 1,
+            solver_threads: None,
+            phase_threads: Some(PhaseThreadsConfig { construction: None, mutation: Some(0), evaluation: None }),
+        }),
+        random: None,
+        memory_limit: None,
+        insertion_cache: None,
+    };
+
+    let result = configure_from_environment(&Some(config)).map(|_| ());
+
+    assert_eq!(result, Err("amount of threads for Mutation phase should be positive".to_string()));
+}
+
+#[test]
+fn can_detect_invalid_solver_threads() {
+    let config = EnvironmentConfig {
+        parallelism: Some(ParallelismConfig {
+            num_thread_pools: 1,
+            threads_per_pool: 1,
+            solver_threads: Some(0),
+            phase_threads: None,
+        }),
+        random: None,
+        memory_limit: None,
+        insertion_cache: None,
+    };
+
+    let result = configure_from_environment(&Some(config)).map(|_| ());
+
+    assert_eq!(result, Err("amount of solver threads should be positive".to_string()));
+}
+
+#[test]
+fn can_detect_unknown_random_algorithm() {
+    let config = EnvironmentConfig {
+        parallelism: None,
+        random: Some(RandomConfig { algorithm: Some("mt".to_string()), seed: Some(42), deterministic: None }),
+        memory_limit: None,
+        insertion_cache: None,
+    };
+
+    let result = configure_from_environment(&Some(config)).map(|_| ());
+
+    assert_eq!(result, Err("unknown random algorithm: 'mt'".to_string()));
+}
+
+parameterized_test! {can_configure_random_with_known_seed, (seed, expected), {
+    let config = EnvironmentConfig {
+        parallelism: None,
+        random: seed.map(|seed| RandomConfig { algorithm: None, seed: Some(seed), deterministic: None }),
+        memory_limit: None,
+        insertion_cache: None,
+    };
+
+    let environment = configure_from_environment(&Some(config)).unwrap();
+
+    let seed = environment.random.seed();
+    assert!(seed.is_some());
+    if let Some(expected) = expected {
+        assert_eq!(seed, Some(expected));
+    }
+}}
+
+can_configure_random_with_known_seed! {
+    case01_explicit_seed: (Some(42), Some(42)),
+    case02_generated_seed: (None, None),
+}
+
+#[test]
+fn can_create_builder_with_dynamic_selective_hyper() {
+    let hyper: HyperType = serde_json::from_str(r#"{"type":"dynamic-selective"}"#).unwrap();
+    let config = Config { hyper: Some(hyper), ..Config::default() };
+
+    let result = create_builder_from_config(create_example_problem(), &config).map(|_| ());
+
+    assert!(result.is_ok());
+}
@@ -1,11 +1,11 @@
 use super::*;
 use crate::helpers::generate::{create_test_job, create_test_vehicle_type};
-use vrp_pragmatic::format::problem::{Fleet, Plan, Profile};
+use vrp_pragmatic::format::problem::{Fleet, Job, JobSkills, Plan, Profile};
 
 #[test]
 fn can_get_locations_serialized() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], relations: None },
+        plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], relations: None, clustering: None },
         fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![] },
         objectives: None,
     };
@@ -18,10 +18,16 @@ fn can_get_locations_serialized() {
 #[test]
 fn can_get_solution_serialized() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_test_job(1., 0.)], relations: None },
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], relations: None, clustering: None },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                distance_multiplier: None,
+                time_bucket: None,
+            }],
         },
         objectives: None,
     };
@@ -35,3 +41,40 @@ fn can_get_solution_serialized() {
     assert!(solution.contains("tours"));
     assert!(solution.contains("stops"));
 }
+
+#[test]
+fn can_get_error_for_unassigned_must_serve_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                must_serve: Some(true),
+                skills: Some(JobSkills {
+                    all_of: Some(vec!["unknown".to_string()]),
+                    one_of: None,
+                    none_of: None,
+                    preferred: None,
+                }),
+                ..create_test_job(1., 0.)
+            }],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                distance_multiplier: None,
+                time_bucket: None,
+            }],
+        },
+        objectives: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+
+    let error = get_solution_serialized(problem, Config::default()).expect_err("error is expected");
+
+    assert!(error.contains("E0005"));
+    assert!(error.contains("SKILL_CONSTRAINT"));
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/clustering/kmedoids_test.rs"]
+mod kmedoids_test;
+
+use crate::utils::{compare_floats, Random};
+use std::cmp::Ordering;
+
+/// Represents a cluster created by k-medoids algorithm.
+pub struct MedoidCluster<'a, T> {
+    /// A medoid of the cluster: the item with minimal sum of distances to other items of the cluster.
+    pub medoid: &'a T,
+    /// Items of the cluster, including medoid.
+    pub items: Vec<&'a T>,
+}
+
+/// Specifies k-medoids algorithm parameters.
+#[derive(Clone, Debug)]
+pub struct KMedoidsParams {
+    /// Amount of clusters.
+    pub k: usize,
+    /// Max amount of assignment-update iterations.
+    pub max_iterations: usize,
+    /// If set, clusters have balanced size: each cluster has at most `ceil(n / k)` items.
+    pub is_balanced: bool,
+}
+
+impl KMedoidsParams {
+    /// Creates a new instance of `KMedoidsParams` with default settings for given amount of clusters.
+    pub fn new(k: usize) -> Self {
+        Self { k, max_iterations: 100, is_balanced: false }
+    }
+}
+
+/// Creates clusters of items using k-medoids algorithm: initial medoids are selected using k-means++ like
+/// seeding, then item assignment and medoid update steps are repeated till convergence or iteration limit.
+/// Distance function is used to build a distance matrix, so it is called n^2 times.
+pub fn create_k_medoids<'a, T, F>(
+    items: &'a [T],
+    params: &KMedoidsParams,
+    distance_fn: F,
+    random: &(dyn Random + Send + Sync),
+) -> Vec<MedoidCluster<'a, T>>
+where
+    F: Fn(&T, &T) -> f64,
+{
+    if items.is_empty() || params.k == 0 {
+        return vec![];
+    }
+
+    let size = items.len();
+    let k = params.k.min(size);
+    let matrix = items
+        .iter()
+        .flat_map(|from| items.iter().map(|to| distance_fn(from, to)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let distance = |from: usize, to: usize| matrix[from * size + to];
+
+    let mut medoids = select_initial_medoids(size, k, &distance, random);
+    let mut assignment = vec![0; size];
+
+    for _ in 0..params.max_iterations.max(1) {
+        assignment = assign_items(size, medoids.as_slice(), params.is_balanced, &distance);
+
+        let new_medoids = (0..k)
+            .map(|cluster_idx| {
+                let members = (0..size).filter(|item| assignment[*item] == cluster_idx).collect::<Vec<_>>();
+                members
+                    .iter()
+                    .map(|&candidate| (candidate, members.iter().map(|&other| distance(candidate, other)).sum::<f64>()))
+                    .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+                    .map(|(candidate, _)| candidate)
+                    .unwrap_or(medoids[cluster_idx])
+            })
+            .collect::<Vec<_>>();
+
+        if new_medoids == medoids {
+            break;
+        }
+
+        medoids = new_medoids;
+    }
+
+    medoids
+        .iter()
+        .enumerate()
+        .map(|(cluster_idx, &medoid)| MedoidCluster {
+            medoid: &items[medoid],
+            items: (0..size).filter(|item| assignment[*item] == cluster_idx).map(|item| &items[item]).collect(),
+        })
+        .collect()
+}
+
+/// Selects initial medoids: the first one randomly, others with probability proportional to
+/// squared distance to the nearest already selected medoid.
+fn select_initial_medoids<D>(size: usize, k: usize, distance: &D, random: &(dyn Random + Send + Sync)) -> Vec<usize>
+where
+    D: Fn(usize, usize) -> f64,
+{
+    let mut medoids = vec![random.uniform_int(0, size as i32 - 1) as usize];
+
+    while medoids.len() < k {
+        let weights = (0..size)
+            .map(|item| {
+                let nearest = medoids.iter().map(|&medoid| distance(item, medoid)).fold(f64::MAX, f64::min);
+                nearest * nearest
+            })
+            .collect::<Vec<_>>();
+        let total: f64 = weights.iter().sum();
+
+        let next = if compare_floats(total, 0.) == Ordering::Greater {
+            let mut threshold = random.uniform_real(0., total);
+            (0..size)
+                .filter(|item| !medoids.contains(item))
+                .find(|&item| {
+                    threshold -= weights[item];
+                    threshold <= 0.
+                })
+                .or_else(|| (0..size).rev().find(|item| !medoids.contains(item)))
+        } else {
+            (0..size).find(|item| !medoids.contains(item))
+        };
+
+        medoids.push(next.expect("k cannot be greater than amount of items"));
+    }
+
+    medoids
+}
+
+/// Assigns items to the closest medoids. When balancing is requested, items are assigned greedily
+/// in ascending order of their distance to medoids while respecting cluster size limit.
+fn assign_items<D>(size: usize, medoids: &[usize], is_balanced: bool, distance: &D) -> Vec<usize>
+where
+    D: Fn(usize, usize) -> f64,
+{
+    let k = medoids.len();
+
+    if !is_balanced {
+        return (0..size)
+            .map(|item| {
+                (0..k).min_by(|&a, &b| compare_floats(distance(item, medoids[a]), distance(item, medoids[b]))).unwrap()
+            })
+            .collect();
+    }
+
+    let max_size = (size + k - 1) / k;
+    let mut pairs = (0..size)
+        .flat_map(|item| (0..k).map(move |cluster_idx| (item, cluster_idx)))
+        .map(|(item, cluster_idx)| (item, cluster_idx, distance(item, medoids[cluster_idx])))
+        .collect::<Vec<_>>();
+    pairs.sort_by(|(_, _, a), (_, _, b)| compare_floats(*a, *b));
+
+    let mut assignment = vec![None; size];
+    let mut cluster_sizes = vec![0; k];
+
+    // NOTE assign medoids first to make sure that they stay in their own clusters
+    medoids.iter().enumerate().for_each(|(cluster_idx, &medoid)| {
+        assignment[medoid] = Some(cluster_idx);
+        cluster_sizes[cluster_idx] += 1;
+    });
+
+    pairs.into_iter().for_each(|(item, cluster_idx, _)| {
+        if assignment[item].is_none() && cluster_sizes[cluster_idx] < max_size {
+            assignment[item] = Some(cluster_idx);
+            cluster_sizes[cluster_idx] += 1;
+        }
+    });
+
+    assignment.into_iter().map(|cluster_idx| cluster_idx.unwrap()).collect()
+}
@@ -0,0 +1,4 @@
+//! This module contains partitioning clustering algorithms.
+
+mod kmedoids;
+pub use self::kmedoids::*;
@@ -1,12 +1,34 @@
 //! This module contains an implementation of Density-Based Spatial Clustering of Applications with
 //! Noise (DBSCAN)
+//!
+//! Items are clustered using user supplied neighborhood function. For small amount of items, a brute force
+//! neighborhood function can be created from any distance function using [`create_neighborhood_fn`]. Epsilon
+//! parameter can be estimated with [`estimate_epsilon`]:
+//!
+//! ```
+//! use vrp_core::algorithms::dbscan::*;
+//! use vrp_core::algorithms::geometry::Point;
+//!
+//! let points = vec![Point::new(0., 0.), Point::new(0., 1.), Point::new(1., 0.), Point::new(10., 10.)];
+//! let distance_fn = |a: &Point, b: &Point| a.distance_to_point(b);
+//!
+//! let neighborhood_fn = create_neighborhood_fn(points.as_slice(), distance_fn);
+//! let clusters = create_clusters(points.as_slice(), 2., 3, &neighborhood_fn);
+//!
+//! assert_eq!(clusters.len(), 1);
+//! assert_eq!(clusters[0].len(), 3);
+//! assert!(estimate_epsilon(points.as_slice(), 2, distance_fn) > 0.);
+//! ```
 
 #[cfg(test)]
 #[path = "../../../tests/unit/algorithms/dbscan/dbscan_test.rs"]
 mod dbscan_test;
 
+use crate::algorithms::geometry::Point;
+use crate::utils::compare_floats;
 use hashbrown::{HashMap, HashSet};
 use std::hash::Hash;
+use std::iter::once;
 
 /// Represents a cluster of items.
 pub type Cluster<'a, T> = Vec<&'a T>;
@@ -74,6 +96,86 @@ where
     clusters
 }
 
+/// Creates a neighborhood function which uses given distance function to find neighbors. Returned neighbors
+/// include the item itself as required by [`create_clusters`].
+/// NOTE: it uses a brute force search, so its complexity is O(n) per item.
+pub fn create_neighborhood_fn<'a, T, F>(items: &'a [T], distance_fn: F) -> NeighborhoodFn<'a, T>
+where
+    F: Fn(&T, &T) -> f64 + 'a,
+{
+    let distance_fn = std::sync::Arc::new(distance_fn);
+    Box::new(move |item: &'a T, eps: f64| {
+        let distance_fn = distance_fn.clone();
+        Box::new(
+            once(item)
+                .chain(items.iter().filter(move |other| !std::ptr::eq(*other, item) && distance_fn(item, other) < eps)),
+        )
+    })
+}
+
+/// Estimates epsilon parameter using k-distance graph: for each item, a distance to its k-th nearest
+/// neighbor is calculated, where k is `min_items`. Then, distances are sorted and the value at the point
+/// of maximum curvature is returned.
+pub fn estimate_epsilon<T, F>(items: &[T], min_items: usize, distance_fn: F) -> f64
+where
+    F: Fn(&T, &T) -> f64,
+{
+    let distances = items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let mut distances = items
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, _)| *other_idx != idx)
+                .map(|(_, other)| distance_fn(item, other))
+                .collect::<Vec<_>>();
+            distances.sort_by(|a, b| compare_floats(*a, *b));
+
+            distances.get(min_items.max(1) - 1).cloned().unwrap_or(0.)
+        })
+        .collect();
+
+    estimate_epsilon_from_distances(distances)
+}
+
+/// Estimates epsilon parameter from precomputed distances to k-th nearest neighbor of each item.
+/// See [`estimate_epsilon`] for details.
+pub fn estimate_epsilon_from_distances(distances: Vec<f64>) -> f64 {
+    let mut distances = distances;
+
+    // sort all distances in ascending order and form the curve
+    distances.sort_by(|&a, &b| compare_floats(a, b));
+    let curve = distances.into_iter().enumerate().map(|(idx, cost)| Point::new(idx as f64, cost)).collect::<Vec<_>>();
+
+    // get max curvature approximation and return it as a guess for optimal epsilon value
+    get_max_curvature(curve.as_slice())
+}
+
+/// Gets max curvature approximation: for each point p on the curve, find the one with the maximum
+/// distance d to a line drawn from the first to the last point of the curves.
+fn get_max_curvature(values: &[Point]) -> f64 {
+    if values.is_empty() {
+        return 0.;
+    }
+
+    let first = values.first().unwrap();
+    let last = values.last().unwrap();
+
+    values
+        .iter()
+        .fold((0., f64::MIN), |acc, p| {
+            let distance = p.distance_to_line(first, last);
+
+            if distance > acc.1 {
+                (p.y, distance)
+            } else {
+                acc
+            }
+        })
+        .0
+}
+
 #[derive(Eq, PartialEq)]
 enum ItemType {
     Noise,
@@ -2,3 +2,6 @@
 
 mod point;
 pub use self::point::Point;
+
+mod polygon;
+pub use self::polygon::*;
@@ -0,0 +1,154 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/geometry/polygon_test.rs"]
+mod polygon_test;
+
+use super::Point;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// Builds a convex hull of given points using monotone chain algorithm. Returns hull vertices in
+/// counter-clockwise order without collinear points.
+pub fn get_convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| compare_floats(a.x, b.x).then_with(|| compare_floats(a.y, b.y)));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let build_chain = |points: &mut dyn Iterator<Item = &Point>| {
+        points.fold(Vec::<Point>::new(), |mut chain, point| {
+            while chain.len() >= 2
+                && Point::cross_product(&chain[chain.len() - 2], &chain[chain.len() - 1], point) <= 0.
+            {
+                chain.pop();
+            }
+            chain.push(point.clone());
+            chain
+        })
+    };
+
+    let mut lower = build_chain(&mut points.iter());
+    let mut upper = build_chain(&mut points.iter().rev());
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+
+    lower
+}
+
+/// Calculates area of a simple polygon using shoelace formula.
+pub fn get_polygon_area(polygon: &[Point]) -> f64 {
+    if polygon.len() < 3 {
+        return 0.;
+    }
+
+    let doubled_area = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(current, next)| current.x * next.y - next.x * current.y)
+        .sum::<f64>();
+
+    doubled_area.abs() / 2.
+}
+
+/// Calculates intersection area of two convex polygons defined in counter-clockwise order
+/// (e.g. returned by `get_convex_hull`) using Sutherland-Hodgman clipping algorithm.
+pub fn get_intersection_area(subject: &[Point], clip: &[Point]) -> f64 {
+    if subject.len() < 3 || clip.len() < 3 {
+        return 0.;
+    }
+
+    let intersection = clip.iter().zip(clip.iter().cycle().skip(1)).fold(subject.to_vec(), |polygon, (a, b)| {
+        if polygon.is_empty() {
+            return polygon;
+        }
+
+        let is_inside = |point: &Point| Point::cross_product(a, b, point) >= 0.;
+
+        polygon.iter().zip(polygon.iter().cycle().skip(1)).fold(Vec::new(), |mut result, (current, next)| {
+            match (is_inside(current), is_inside(next)) {
+                (true, true) => result.push(next.clone()),
+                (true, false) => result.push(get_line_intersection(current, next, a, b)),
+                (false, true) => {
+                    result.push(get_line_intersection(current, next, a, b));
+                    result.push(next.clone());
+                }
+                (false, false) => {}
+            }
+            result
+        })
+    });
+
+    get_polygon_area(intersection.as_slice())
+}
+
+/// Checks whether two segments properly intersect: they cross each other in a single point which
+/// is not an end of any segment. Touching or collinear segments are not considered as intersecting.
+pub fn is_segments_cross(a1: &Point, a2: &Point, b1: &Point, b2: &Point) -> bool {
+    let get_sign = |value: f64| compare_floats(value, 0.);
+
+    let d1 = get_sign(Point::cross_product(b1, b2, a1));
+    let d2 = get_sign(Point::cross_product(b1, b2, a2));
+    let d3 = get_sign(Point::cross_product(a1, a2, b1));
+    let d4 = get_sign(Point::cross_product(a1, a2, b2));
+
+    d1 != Ordering::Equal
+        && d2 != Ordering::Equal
+        && d1 != d2
+        && d3 != Ordering::Equal
+        && d4 != Ordering::Equal
+        && d3 != d4
+}
+
+/// Counts amount of crossings between segments of different routes. Each route is defined by
+/// a sequence of its stop points.
+pub fn count_route_crossings(routes: &[Vec<Point>]) -> usize {
+    let segments = routes
+        .iter()
+        .map(|route| route.windows(2).map(|points| (&points[0], &points[1])).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    segments
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, left)| segments.iter().skip(idx + 1).map(move |right| (left, right)))
+        .map(|(left, right)| {
+            left.iter()
+                .flat_map(|(a1, a2)| right.iter().map(move |(b1, b2)| (*a1, *a2, *b1, *b2)))
+                .filter(|(a1, a2, b1, b2)| is_segments_cross(a1, a2, b1, b2))
+                .count()
+        })
+        .sum()
+}
+
+/// Calculates route overlap metric: a sum of pairwise intersection areas of routes' convex hulls
+/// divided by a sum of their areas. Zero value means that routes do not overlap at all.
+pub fn get_route_overlap(routes: &[Vec<Point>]) -> f64 {
+    let hulls = routes.iter().map(|route| get_convex_hull(route.as_slice())).collect::<Vec<_>>();
+
+    let total_area = hulls.iter().map(|hull| get_polygon_area(hull.as_slice())).sum::<f64>();
+    if compare_floats(total_area, 0.) != Ordering::Greater {
+        return 0.;
+    }
+
+    let intersection_area = hulls
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, left)| hulls.iter().skip(idx + 1).map(move |right| (left, right)))
+        .map(|(left, right)| get_intersection_area(left.as_slice(), right.as_slice()))
+        .fold(0., |acc, area| acc + area);
+
+    intersection_area / total_area
+}
+
+/// Returns intersection point of segment (p1, p2) and infinite line (a, b).
+fn get_line_intersection(p1: &Point, p2: &Point, a: &Point, b: &Point) -> Point {
+    let d1 = Point::cross_product(a, b, p1);
+    let d2 = Point::cross_product(a, b, p2);
+    let ratio = d1 / (d1 - d2);
+
+    Point::new(p1.x + (p2.x - p1.x) * ratio, p1.y + (p2.y - p1.y) * ratio)
+}
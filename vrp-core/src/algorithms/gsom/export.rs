@@ -0,0 +1,83 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/gsom/export_test.rs"]
+mod export_test;
+
+use super::NetworkState;
+use std::io::Write;
+
+/// Writes network state in json format. A node's storage dump is written as a string.
+pub fn write_network_state_json<W: Write>(state: &NetworkState, writer: &mut W) -> Result<(), String> {
+    let (rows, cols, dim) = &state.shape;
+
+    let nodes = state
+        .nodes
+        .iter()
+        .map(|node| {
+            format!(
+                r#"{{"x":{},"y":{},"unifiedDistance":{},"totalHits":{},"lastHits":{},"weights":[{}],"storage":"{}"}}"#,
+                node.coordinate.0,
+                node.coordinate.1,
+                format_number(node.unified_distance),
+                node.total_hits,
+                node.last_hits,
+                node.weights.iter().map(|weight| format_number(*weight)).collect::<Vec<_>>().join(","),
+                escape_json(node.dump.as_str())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    write!(
+        writer,
+        r#"{{"shape":{{"rows":[{},{}],"cols":[{},{}],"weights":{}}},"nodes":[{}]}}"#,
+        rows.start, rows.end, cols.start, cols.end, dim, nodes
+    )
+    .map_err(|err| err.to_string())
+}
+
+/// Writes network state in csv format: one line per node with weights in separate columns.
+pub fn write_network_state_csv<W: Write>(state: &NetworkState, writer: &mut W) -> Result<(), String> {
+    let weight_columns = (0..state.shape.2).map(|idx| format!(",w{}", idx)).collect::<String>();
+    let header = format!("x,y,unified_distance,total_hits,last_hits{},storage", weight_columns);
+
+    let lines = state.nodes.iter().map(|node| {
+        format!(
+            "{},{},{},{},{},{}\"{}\"",
+            node.coordinate.0,
+            node.coordinate.1,
+            format_number(node.unified_distance),
+            node.total_hits,
+            node.last_hits,
+            node.weights.iter().map(|weight| format!("{},", format_number(*weight))).collect::<String>(),
+            node.dump.replace('"', "\"\"")
+        )
+    });
+
+    std::iter::once(header)
+        .chain(lines)
+        .try_for_each(|line| writeln!(writer, "{}", line).map_err(|err| err.to_string()))
+}
+
+fn format_number(value: f64) -> String {
+    // NOTE json has no representation for nan and infinity
+    if value.is_finite() {
+        format!("{:.7}", value)
+    } else {
+        "0".to_string()
+    }
+}
+
+fn escape_json(value: &str) -> String {
+    value.chars().fold(String::with_capacity(value.len()), |mut result, ch| {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => result.push_str(format!("\\u{:04x}", ch as u32).as_str()),
+            ch => result.push(ch),
+        }
+        result
+    })
+}
@@ -2,6 +2,9 @@
 
 use std::fmt::Display;
 
+mod export;
+pub use self::export::*;
+
 mod network;
 pub use self::network::*;
 
@@ -3,7 +3,8 @@
 mod simulator_test;
 
 use super::*;
-use crate::utils::{parallel_into_collect, CollectGroupBy};
+use crate::utils::{compare_floats, parallel_into_collect, CollectGroupBy};
+use std::io::{BufRead, BufReader, Read, Write};
 
 /// A simulator to train agent with multiple episodes.
 pub struct Simulator<S: State> {
@@ -31,8 +32,82 @@ impl<S: State> Simulator<S> {
         })
     }
 
+    /// Returns learned state-action estimates (Q-table).
+    pub fn get_state_estimates(&self) -> &HashMap<S, ActionsEstimate<S>> {
+        &self.q
+    }
+
+    /// Sets state-action estimates, e.g. learned on previous runs of similar problems, which
+    /// are used to bootstrap action selection.
+    pub fn set_state_estimates(&mut self, estimates: HashMap<S, ActionsEstimate<S>>) {
+        self.q = estimates;
+    }
+
+    /// Writes learned state-action estimates in text format: one `<state>\t<action>\t<value>` line per
+    /// state-action pair. Encoded states and actions must not contain tab or new line characters.
+    pub fn write_state_estimates<W: Write>(
+        &self,
+        writer: &mut W,
+        state_fn: impl Fn(&S) -> String,
+        action_fn: impl Fn(&S::Action) -> String,
+    ) -> Result<(), String> {
+        let mut lines = self
+            .q
+            .iter()
+            .flat_map(|(state, estimates)| estimates.iter().map(move |(action, value)| (state, action, *value)))
+            .map(|(state, action, value)| (state_fn(state), action_fn(action), value))
+            .collect::<Vec<_>>();
+
+        // NOTE sort to have stable output
+        lines.sort_by(|(a_state, a_action, a_value), (b_state, b_action, b_value)| {
+            a_state.cmp(b_state).then_with(|| a_action.cmp(b_action)).then_with(|| compare_floats(*a_value, *b_value))
+        });
+
+        lines.into_iter().try_for_each(|(state, action, value)| {
+            writeln!(writer, "{}\t{}\t{}", state, action, value).map_err(|err| err.to_string())
+        })
+    }
+
+    /// Reads state-action estimates written by `write_state_estimates`. Read estimates overwrite
+    /// existing ones for the same state-action pairs.
+    pub fn read_state_estimates<R: Read>(
+        &mut self,
+        reader: BufReader<R>,
+        state_fn: impl Fn(&str) -> Result<S, String>,
+        action_fn: impl Fn(&str) -> Result<S::Action, String>,
+    ) -> Result<(), String> {
+        let estimates = reader
+            .lines()
+            .map(|line| line.map_err(|err| err.to_string()))
+            .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+            .map(|line| {
+                let line = line?;
+                let mut parts = line.split('\t');
+
+                match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                    (Some(state), Some(action), Some(value), None) => Ok((
+                        state_fn(state)?,
+                        action_fn(action)?,
+                        value.parse::<f64>().map_err(|_| format!("cannot parse estimate value: '{}'", value))?,
+                    )),
+                    _ => Err(format!("cannot read state estimate line: '{}'", line)),
+                }
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        estimates.into_iter().for_each(|(state, action, value)| {
+            self.q.entry(state).or_default().insert(action, value);
+        });
+
+        Ok(())
+    }
+
     /// Runs single episode for each of the given agents in parallel.
-    pub fn run_episodes(&mut self, agents: Vec<Box<dyn Agent<S> + Send + Sync>>, reduce: impl Fn(&[f64]) -> f64) {
+    pub fn run_episodes<'a>(
+        &mut self,
+        agents: Vec<Box<dyn Agent<S> + Send + Sync + 'a>>,
+        reduce: impl Fn(&[f64]) -> f64,
+    ) {
         let qs = parallel_into_collect(agents, |mut a| {
             Self::run_episode(a.as_mut(), self.learning_strategy.as_ref(), self.policy_strategy.as_ref(), &self.q)
         });
@@ -1,5 +1,6 @@
 //! A collection of reusable algorithms without dependencies on any other module in the project.
 
+pub mod clustering;
 pub mod dbscan;
 pub mod gsom;
 pub mod mdp;
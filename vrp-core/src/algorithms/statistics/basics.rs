@@ -19,6 +19,23 @@ pub fn get_mean(values: &[f64]) -> f64 {
     sum / values.len() as f64
 }
 
+/// Gets median of values.
+pub fn get_median(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.;
+    }
+
+    let mut values = values.to_vec();
+    values.sort_by(|a, b| compare_floats(*a, *b));
+
+    let middle = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[middle - 1] + values[middle]) / 2.
+    } else {
+        values[middle]
+    }
+}
+
 /// Returns variance.
 pub fn get_variance(values: &[f64]) -> f64 {
     get_variance_mean(values).0
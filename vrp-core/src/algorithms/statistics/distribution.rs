@@ -0,0 +1,74 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/statistics/distribution_test.rs"]
+mod distribution_test;
+
+/// Approximates standard normal cumulative distribution function using Abramowitz and Stegun formula 7.1.26.
+pub fn get_normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / 2_f64.sqrt();
+    let t = 1. / (1. + 0.327_591_1 * x);
+    let poly =
+        t * (0.254_829_592 + t * (-0.284_496_736 + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1. - poly * (-x * x).exp();
+
+    if z >= 0. {
+        0.5 * (1. + erf)
+    } else {
+        0.5 * (1. - erf)
+    }
+}
+
+/// Approximates quantile function (inverse of cumulative distribution function) of standard normal
+/// distribution using Acklam's algorithm. Returns infinity for probabilities outside of `(0, 1)` range.
+pub fn get_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -39.696_830_286_653_76,
+        220.946_098_424_520_5,
+        -275.928_510_446_968_7,
+        138.357_751_867_269,
+        -30.664_798_066_147_16,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -54.476_098_798_224_06,
+        161.585_836_858_040_9,
+        -155.698_979_859_886_6,
+        66.801_311_887_719_72,
+        -13.280_681_552_885_72,
+    ];
+    const C: [f64; 6] = [
+        -0.007_784_894_002_430_293,
+        -0.322_396_458_041_136_5,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] =
+        [0.007_784_695_709_041_462, 0.322_467_129_070_039_8, 2.445_134_137_142_996, 3.754_408_661_907_416];
+    const P_LOW: f64 = 0.024_25;
+
+    if p <= 0. {
+        return f64::NEG_INFINITY;
+    }
+
+    if p >= 1. {
+        return f64::INFINITY;
+    }
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    };
+
+    if p < P_LOW {
+        tail((-2. * p.ln()).sqrt())
+    } else if p > 1. - P_LOW {
+        -tail((-2. * (1. - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.)
+    }
+}
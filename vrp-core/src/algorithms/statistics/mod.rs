@@ -2,3 +2,9 @@
 
 mod basics;
 pub use self::basics::*;
+
+mod distribution;
+pub use self::distribution::*;
+
+mod significance;
+pub use self::significance::*;
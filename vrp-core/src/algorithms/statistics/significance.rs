@@ -0,0 +1,116 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/statistics/significance_test.rs"]
+mod significance_test;
+
+use crate::algorithms::statistics::get_normal_cdf;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// Represents a result of statistical significance test.
+#[derive(Clone, Debug)]
+pub struct SignificanceResult {
+    /// A test statistic: U for Mann-Whitney test, W for Wilcoxon signed-rank test.
+    pub statistic: f64,
+    /// A two-sided p-value calculated using normal approximation.
+    pub p_value: f64,
+}
+
+impl SignificanceResult {
+    /// Checks whether the difference is significant at given level (e.g. 0.05).
+    pub fn is_significant(&self, level: f64) -> bool {
+        self.p_value < level
+    }
+}
+
+/// Runs Mann-Whitney U test (also known as Wilcoxon rank-sum test) for two independent samples.
+/// Uses normal approximation with tie correction, so results are not precise for very small samples.
+pub fn mann_whitney_u_test(a: &[f64], b: &[f64]) -> Option<SignificanceResult> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+
+    let (n1, n2) = (a.len() as f64, b.len() as f64);
+    let values = a.iter().map(|v| (*v, true)).chain(b.iter().map(|v| (*v, false))).collect::<Vec<_>>();
+    let (ranks, tie_correction) = get_ranks(values.iter().map(|(v, _)| *v).collect());
+
+    let rank_sum_a: f64 = ranks.iter().zip(values.iter()).filter(|(_, (_, is_a))| *is_a).map(|(rank, _)| rank).sum();
+    let u_a = rank_sum_a - n1 * (n1 + 1.) / 2.;
+    let u = u_a.min(n1 * n2 - u_a);
+
+    let n = n1 + n2;
+    let mean = n1 * n2 / 2.;
+    let variance = n1 * n2 / 12. * ((n + 1.) - tie_correction / (n * (n - 1.)));
+
+    Some(SignificanceResult { statistic: u, p_value: get_two_sided_p_value(u, mean, variance) })
+}
+
+/// Runs Wilcoxon signed-rank test for two paired samples of the same size.
+/// Zero differences are discarded. Uses normal approximation with tie correction.
+pub fn wilcoxon_signed_rank_test(a: &[f64], b: &[f64]) -> Option<SignificanceResult> {
+    if a.len() != b.len() {
+        return None;
+    }
+
+    let differences = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a, b)| a - b)
+        .filter(|diff| compare_floats(*diff, 0.) != Ordering::Equal)
+        .collect::<Vec<_>>();
+
+    if differences.is_empty() {
+        return None;
+    }
+
+    let n = differences.len() as f64;
+    let (ranks, tie_correction) = get_ranks(differences.iter().map(|diff| diff.abs()).collect());
+
+    let w_plus: f64 = ranks.iter().zip(differences.iter()).filter(|(_, diff)| **diff > 0.).map(|(rank, _)| rank).sum();
+    let w_minus = n * (n + 1.) / 2. - w_plus;
+    let w = w_plus.min(w_minus);
+
+    let mean = n * (n + 1.) / 4.;
+    let variance = n * (n + 1.) * (2. * n + 1.) / 24. - tie_correction / 48.;
+
+    Some(SignificanceResult { statistic: w, p_value: get_two_sided_p_value(w, mean, variance) })
+}
+
+/// Returns ranks (average ones for ties) of values in original order and tie correction term: sum(t^3 - t).
+fn get_ranks(values: Vec<f64>) -> (Vec<f64>, f64) {
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| compare_floats(values[a], values[b]));
+
+    let mut ranks = vec![0.; values.len()];
+    let mut tie_correction = 0.;
+    let mut start = 0;
+
+    while start < indices.len() {
+        let mut end = start;
+        while end + 1 < indices.len()
+            && compare_floats(values[indices[end + 1]], values[indices[start]]) == Ordering::Equal
+        {
+            end += 1;
+        }
+
+        let rank = (start + end) as f64 / 2. + 1.;
+        indices[start..=end].iter().for_each(|&idx| ranks[idx] = rank);
+
+        let ties = (end - start + 1) as f64;
+        tie_correction += ties * ties * ties - ties;
+
+        start = end + 1;
+    }
+
+    (ranks, tie_correction)
+}
+
+fn get_two_sided_p_value(statistic: f64, mean: f64, variance: f64) -> f64 {
+    if compare_floats(variance, 0.) != Ordering::Greater {
+        return 1.;
+    }
+
+    // NOTE continuity correction is applied
+    let z = ((statistic - mean).abs() - 0.5).max(0.) / variance.sqrt();
+
+    (2. * (1. - get_normal_cdf(z))).min(1.)
+}
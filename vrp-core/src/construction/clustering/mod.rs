@@ -0,0 +1,3 @@
+//! This module contains preprocessing logic which groups jobs into clusters to reduce problem size.
+
+pub mod vicinity;
@@ -0,0 +1,310 @@
+//! Provides a vicinity clustering: jobs which are close to each other are served within a single
+//! stop, so the solver deals with one compound job instead of many.
+//!
+//! A cluster is represented by a single job placed at the location of its first job (parking).
+//! Its duration includes service times of all clustered jobs and commute between them, its time
+//! window guarantees that each clustered job is started within its own time window. Original jobs
+//! are kept in the cluster job's dimensions, so they can be restored when the solution is written.
+
+#[cfg(test)]
+#[path = "../../../../tests/unit/construction/clustering/vicinity_test.rs"]
+mod vicinity_test;
+
+use crate::models::common::*;
+use crate::models::problem::{Job, Place, Single, TransportCost};
+use crate::utils::compare_floats;
+use hashbrown::HashSet;
+use std::ops::{Add, Deref, Sub};
+use std::sync::Arc;
+
+/// A key to store cluster info in job dimensions.
+const CLUSTER_DIMENSION_KEY: &str = "vicinity";
+
+/// Specifies vicinity clustering configuration.
+#[derive(Clone)]
+pub struct ClusterConfig {
+    /// A routing profile used to calculate commute between jobs.
+    pub profile: Profile,
+    /// Specifies thresholds for jobs to be clustered.
+    pub threshold: ThresholdPolicy,
+    /// Specifies how jobs in the cluster are visited.
+    pub visiting: VisitPolicy,
+    /// Specifies which jobs can be clustered.
+    pub filtering: FilterPolicy,
+}
+
+/// Specifies max commute between the cluster's parking and its jobs.
+#[derive(Clone, Debug)]
+pub struct ThresholdPolicy {
+    /// Max commute duration in each direction.
+    pub moving_duration: Duration,
+    /// Max commute distance in each direction.
+    pub moving_distance: Distance,
+    /// Max amount of jobs in one cluster.
+    pub max_jobs_per_cluster: Option<usize>,
+}
+
+/// Specifies how jobs in the cluster are visited.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VisitPolicy {
+    /// All jobs are served in place where vehicle is parked: commute is ignored.
+    ServeInPlace,
+    /// Driver walks from the parking to each job and back.
+    WalkFromParking,
+}
+
+/// Specifies which jobs can be clustered.
+#[derive(Clone)]
+pub struct FilterPolicy {
+    /// Returns true if job can be clustered.
+    pub job_filter: Arc<dyn Fn(&Job) -> bool + Send + Sync>,
+}
+
+/// Keeps commute information.
+#[derive(Clone, Debug, Default)]
+pub struct CommuteInfo {
+    /// Commute distance.
+    pub distance: Distance,
+    /// Commute duration.
+    pub duration: Duration,
+}
+
+/// Keeps commute from the parking to the job and back.
+#[derive(Clone, Debug, Default)]
+pub struct Commute {
+    /// Commute from the parking to the job.
+    pub forward: CommuteInfo,
+    /// Commute from the job back to the parking.
+    pub backward: CommuteInfo,
+}
+
+/// Keeps information about original job inside the cluster.
+#[derive(Clone)]
+pub struct ClusterInfo {
+    /// An original job.
+    pub job: Job,
+    /// A service time of the job.
+    pub service_time: Duration,
+    /// A commute to the job and back.
+    pub commute: Commute,
+}
+
+/// A trait to get or set cluster info.
+pub trait ClusterDimension {
+    /// Sets cluster info.
+    fn set_cluster_info(&mut self, info: Vec<ClusterInfo>) -> &mut Self;
+    /// Gets cluster info: original jobs in the order they are served.
+    fn get_cluster_info(&self) -> Option<&Vec<ClusterInfo>>;
+}
+
+impl ClusterDimension for Dimensions {
+    fn set_cluster_info(&mut self, info: Vec<ClusterInfo>) -> &mut Self {
+        self.set_value(CLUSTER_DIMENSION_KEY, info);
+        self
+    }
+
+    fn get_cluster_info(&self) -> Option<&Vec<ClusterInfo>> {
+        self.get_value(CLUSTER_DIMENSION_KEY)
+    }
+}
+
+/// Replaces jobs located in the vicinity of each other by cluster jobs. Only single jobs with one
+/// place and at most one time window are considered. Returned jobs keep original order: cluster
+/// job takes the position of its first job.
+pub fn create_job_clusters<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    jobs: Vec<Job>,
+    transport: &(dyn TransportCost + Send + Sync),
+    config: &ClusterConfig,
+) -> Vec<Job> {
+    let candidates = jobs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, job)| {
+            let single = job.as_single().filter(|_| config.filtering.job_filter.deref()(job))?;
+            match single.places.as_slice() {
+                [place] if place.location.is_some() && place.times.len() < 2 => {
+                    let time = match place.times.first() {
+                        Some(time) => Some(time.as_time_window()?),
+                        None => None,
+                    };
+                    Some((idx, single.clone(), time))
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let get_location = |single: &Single| single.places.first().and_then(|place| place.location).unwrap();
+    let get_commute_info = |from: Location, to: Location| CommuteInfo {
+        distance: transport.distance(config.profile, from, to, Timestamp::default()),
+        duration: transport.duration(config.profile, from, to, Timestamp::default()),
+    };
+    let is_close = |info: &CommuteInfo| {
+        info.duration <= config.threshold.moving_duration && info.distance <= config.threshold.moving_distance
+    };
+
+    // NOTE neighbours are sorted by commute duration, the job's index is used to break ties
+    let neighbours = candidates
+        .iter()
+        .map(|(_, center, _)| {
+            let mut neighbours = candidates
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, other, _))| !Arc::ptr_eq(center, other))
+                .filter_map(|(candidate_idx, (_, other, _))| {
+                    let commute = Commute {
+                        forward: get_commute_info(get_location(center), get_location(other)),
+                        backward: get_commute_info(get_location(other), get_location(center)),
+                    };
+
+                    if is_close(&commute.forward) && is_close(&commute.backward) {
+                        Some((candidate_idx, commute))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+            neighbours.sort_by(|(a_idx, a), (b_idx, b)| {
+                compare_floats(a.forward.duration, b.forward.duration).then_with(|| a_idx.cmp(b_idx))
+            });
+            neighbours
+        })
+        .collect::<Vec<_>>();
+
+    let mut centers = (0..candidates.len()).collect::<Vec<_>>();
+    centers.sort_by(|a, b| neighbours[*b].len().cmp(&neighbours[*a].len()).then_with(|| a.cmp(b)));
+
+    let mut used = HashSet::<usize>::default();
+    let mut clusters = centers.into_iter().fold(Vec::<(usize, Vec<usize>, Job)>::default(), |mut acc, center| {
+        if used.contains(&center) || neighbours[center].is_empty() {
+            return acc;
+        }
+
+        let (_, center_single, center_time) = &candidates[center];
+        let center_member =
+            Member { single: center_single.clone(), time: center_time.clone(), commute: Commute::default() };
+
+        let (indices, members) = neighbours[center].iter().filter(|(idx, _)| !used.contains(idx)).fold(
+            (vec![center], vec![center_member]),
+            |(mut indices, mut members), (idx, commute)| {
+                let is_full = matches!(config.threshold.max_jobs_per_cluster, Some(max) if members.len() >= max);
+                if !is_full {
+                    let (_, single, time) = &candidates[*idx];
+                    let commute = match config.visiting {
+                        VisitPolicy::ServeInPlace => Commute::default(),
+                        VisitPolicy::WalkFromParking => commute.clone(),
+                    };
+                    members.push(Member { single: single.clone(), time: time.clone(), commute });
+
+                    if get_cluster_time(members.as_slice()).is_some() {
+                        indices.push(*idx);
+                    } else {
+                        members.pop();
+                    }
+                }
+                (indices, members)
+            },
+        );
+
+        if members.len() > 1 {
+            used.extend(indices.iter().cloned());
+            let job_indices = indices.iter().map(|idx| candidates[*idx].0).collect();
+            acc.push((candidates[center].0, job_indices, create_cluster::<T>(members)));
+        }
+
+        acc
+    });
+
+    clusters.sort_by_key(|(idx, _, _)| *idx);
+
+    let clustered = clusters.iter().flat_map(|(_, indices, _)| indices.iter().cloned()).collect::<HashSet<_>>();
+    let mut clusters = clusters.into_iter().map(|(idx, _, job)| (idx, job)).peekable();
+
+    jobs.into_iter()
+        .enumerate()
+        .filter_map(|(idx, job)| match clusters.peek() {
+            Some((center_idx, _)) if *center_idx == idx => clusters.next().map(|(_, cluster)| cluster),
+            _ if clustered.contains(&idx) => None,
+            _ => Some(job),
+        })
+        .collect()
+}
+
+/// A job which is a part of the cluster.
+struct Member {
+    single: Arc<Single>,
+    time: Option<TimeWindow>,
+    commute: Commute,
+}
+
+/// Returns time window of the cluster start which guarantees that each member starts within its
+/// own time window. Returns None if there is no such time window.
+fn get_cluster_time(members: &[Member]) -> Option<TimeWindow> {
+    let (time, _) = members.iter().fold((TimeWindow::max(), 0.), |(time, offset), member| {
+        let start = offset + member.commute.forward.duration;
+        let time = match &member.time {
+            Some(tw) => TimeWindow::new(time.start.max(tw.start - start), time.end.min(tw.end - start)),
+            None => time,
+        };
+        let duration = member.single.places.first().map_or(0., |place| place.duration);
+
+        (time, start + duration + member.commute.backward.duration)
+    });
+
+    if time.start <= time.end {
+        Some(time)
+    } else {
+        None
+    }
+}
+
+/// Creates a cluster job from its members where the first one is the cluster's center.
+fn create_cluster<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(members: Vec<Member>) -> Job {
+    let time = get_cluster_time(members.as_slice()).expect("cluster time window should be feasible");
+    let has_times = members.iter().any(|member| member.time.is_some());
+
+    let center = &members.first().unwrap().single;
+    let location = center.places.first().and_then(|place| place.location);
+
+    let info = members
+        .iter()
+        .map(|member| ClusterInfo {
+            job: Job::Single(member.single.clone()),
+            service_time: member.single.places.first().map_or(0., |place| place.duration),
+            commute: member.commute.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let duration = info
+        .iter()
+        .map(|info| info.commute.forward.duration + info.service_time + info.commute.backward.duration)
+        .sum::<Duration>();
+
+    let demand = members.iter().filter_map(|member| member.single.dimens.get_demand()).fold(
+        None,
+        |acc: Option<Demand<T>>, demand: &Demand<T>| {
+            Some(match acc {
+                Some(acc) => Demand {
+                    pickup: (acc.pickup.0 + demand.pickup.0, acc.pickup.1 + demand.pickup.1),
+                    delivery: (acc.delivery.0 + demand.delivery.0, acc.delivery.1 + demand.delivery.1),
+                },
+                None => demand.clone(),
+            })
+        },
+    );
+
+    let mut dimens = center.dimens.clone();
+    if let Some(demand) = demand {
+        dimens.set_demand(demand);
+    }
+    dimens.set_cluster_info(info);
+
+    Job::Single(Arc::new(Single {
+        places: vec![Place {
+            location,
+            duration,
+            times: if has_times { vec![TimeSpan::Window(time)] } else { vec![] },
+        }],
+        dimens,
+    }))
+}
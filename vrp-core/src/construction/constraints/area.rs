@@ -16,8 +16,35 @@ use std::sync::Arc;
 pub struct Area {
     /// An area priority, bigger value - less important.
     pub priority: Option<usize>,
-    /// An area outer shape.
-    pub outer_shape: Vec<(f64, f64)>,
+    outer_shape: Vec<(f64, f64)>,
+    bounding_box: ((f64, f64), (f64, f64)),
+}
+
+impl Area {
+    /// Creates a new instance of `Area`.
+    pub fn new(priority: Option<usize>, outer_shape: Vec<(f64, f64)>) -> Self {
+        let bounding_box = outer_shape
+            .iter()
+            .fold(((f64::MAX, f64::MAX), (f64::MIN, f64::MIN)), |((min_x, min_y), (max_x, max_y)), &(x, y)| {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            });
+
+        Self { priority, outer_shape, bounding_box }
+    }
+
+    /// Returns an area outer shape.
+    pub fn outer_shape(&self) -> &[(f64, f64)] {
+        self.outer_shape.as_slice()
+    }
+
+    /// Checks whether given location is inside the area. Bounding box of the area is checked first
+    /// to avoid expensive point in polygon test for locations which are far away.
+    pub fn contains(&self, location: &(f64, f64)) -> bool {
+        let &(x, y) = location;
+        let ((min_x, min_y), (max_x, max_y)) = self.bounding_box;
+
+        x >= min_x && x <= max_x && y >= min_y && y <= max_y && is_location_in_area(location, self.outer_shape())
+    }
 }
 
 /// A function which returns operating areas for given actor.
@@ -29,6 +56,7 @@ pub type LocationResolver = Arc<dyn Fn(Location) -> (f64, f64) + Sync + Send>;
 pub struct AreaModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
+    codes: Vec<i32>,
 }
 
 impl AreaModule {
@@ -52,6 +80,7 @@ impl AreaModule {
                 })),
             ],
             keys: vec![],
+            codes: vec![code],
         }
     }
 }
@@ -70,6 +99,10 @@ impl ConstraintModule for AreaModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct AreaHardRouteConstraint {
@@ -111,7 +144,7 @@ impl HardActivityConstraint for AreaHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         if let Some(areas) = self.area_resolver.deref()(&route_ctx.route.actor) {
             let location = self.location_resolver.deref()(activity_ctx.target.place.location);
-            let can_serve = areas.iter().any(|area| is_location_in_area(&location, area.outer_shape.as_slice()));
+            let can_serve = areas.iter().any(|area| area.contains(&location));
 
             if !can_serve {
                 // NOTE do not stop job insertion evaluation if it has multiple locations
@@ -157,7 +190,7 @@ fn find_allowed_area_for_location<'a>(
     location_resolver: &LocationResolver,
 ) -> Option<&'a Area> {
     let location = location_resolver.deref()(location);
-    areas.iter().find(|area| is_location_in_area(&location, area.outer_shape.as_slice()))
+    areas.iter().find(|area| area.contains(&location))
 }
 
 fn find_allowed_area_for_job<'a>(
@@ -174,7 +207,7 @@ fn find_allowed_area_for_job<'a>(
 
 /// Checks whether given location is inside area using ray casting algorithm.
 /// Location is interpreted as 2D point, area - as 2D polygon.
-fn is_location_in_area(location: &(f64, f64), outer_shape: &[(f64, f64)]) -> bool {
+pub fn is_location_in_area(location: &(f64, f64), outer_shape: &[(f64, f64)]) -> bool {
     let &(x, y) = location;
 
     let mut is_inside = false;
@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/cancellation_test.rs"]
+mod cancellation_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::problem::Job;
+use hashbrown::HashSet;
+use std::slice::Iter;
+use std::sync::{Arc, RwLock};
+
+/// Keeps jobs withdrawn from the plan while solver is running, e.g. cancelled by customers.
+/// Jobs can be cancelled from another thread.
+#[derive(Default)]
+pub struct JobCancellation {
+    jobs: RwLock<HashSet<Job>>,
+}
+
+impl JobCancellation {
+    /// Cancels given job. Returns false if job is already cancelled.
+    pub fn cancel(&self, job: Job) -> bool {
+        self.jobs.write().unwrap().insert(job)
+    }
+
+    /// Returns true if job is cancelled.
+    pub fn is_cancelled(&self, job: &Job) -> bool {
+        self.jobs.read().unwrap().contains(job)
+    }
+
+    /// Returns amount of cancelled jobs.
+    pub fn size(&self) -> usize {
+        self.jobs.read().unwrap().len()
+    }
+}
+
+/// A module which withdraws cancelled jobs from the solution: they are removed from routes and
+/// moved to ignored jobs, so released capacity and time can be used by other jobs.
+pub struct JobCancellationModule {
+    cancellation: Arc<JobCancellation>,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    codes: Vec<i32>,
+}
+
+impl JobCancellationModule {
+    /// Creates a new instance of `JobCancellationModule`.
+    pub fn new(cancellation: Arc<JobCancellation>, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(JobCancellationHardRouteConstraint {
+                cancellation: cancellation.clone(),
+                code,
+            }))],
+            cancellation,
+            state_keys: vec![],
+            codes: vec![code],
+        }
+    }
+}
+
+impl ConstraintModule for JobCancellationModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        if self.cancellation.size() == 0 {
+            return;
+        }
+
+        let cancellation = self.cancellation.as_ref();
+
+        let withdrawn = ctx
+            .routes
+            .iter_mut()
+            .flat_map(|route_ctx| {
+                let jobs = route_ctx.route.tour.jobs().filter(|job| cancellation.is_cancelled(job)).collect::<Vec<_>>();
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+
+                jobs.into_iter()
+            })
+            .chain(ctx.required.iter().filter(|job| cancellation.is_cancelled(job)).cloned())
+            .chain(ctx.unassigned.keys().filter(|job| cancellation.is_cancelled(job)).cloned())
+            .collect::<HashSet<_>>();
+
+        if withdrawn.is_empty() {
+            return;
+        }
+
+        ctx.required.retain(|job| !withdrawn.contains(job));
+        ctx.unassigned.retain(|job, _| !withdrawn.contains(job));
+        ctx.locked.retain(|job| !withdrawn.contains(job));
+        ctx.ignored.extend(withdrawn);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
+}
+
+struct JobCancellationHardRouteConstraint {
+    cancellation: Arc<JobCancellation>,
+    code: i32,
+}
+
+impl HardRouteConstraint for JobCancellationHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if self.cancellation.is_cancelled(job) {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
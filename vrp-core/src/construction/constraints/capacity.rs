@@ -60,6 +60,7 @@ pub struct CapacityConstraintModule<T: Load + Add<Output = T> + Sub<Output = T>
     conditional: ConditionalJobModule,
     constraints: Vec<ConstraintVariant>,
     multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>,
+    codes: Vec<i32>,
 }
 
 impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output = T> + 'static>
@@ -73,7 +74,13 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output
     /// Creates a new instance of `CapacityConstraintModule` with multi trip (reload) functionality
     pub fn new_with_multi_trip(code: i32, multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>) -> Self {
         Self {
-            state_keys: vec![CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY],
+            state_keys: vec![
+                CURRENT_CAPACITY_KEY,
+                MAX_FUTURE_CAPACITY_KEY,
+                MAX_PAST_CAPACITY_KEY,
+                CAPACITY_OVERLOAD_KEY,
+            ],
+            codes: vec![code],
             conditional: ConditionalJobModule::new(Box::new(ConcreteJobContextTransition {
                 remove_required: {
                     let multi_trip = multi_trip.clone();
@@ -88,6 +95,7 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output
             })),
             constraints: vec![
                 ConstraintVariant::SoftRoute(Arc::new(CapacitySoftRouteConstraint { multi_trip: multi_trip.clone() })),
+                ConstraintVariant::SoftActivity(Arc::new(CapacitySoftActivityConstraint::<T> { phantom: PhantomData })),
                 ConstraintVariant::HardRoute(Arc::new(CapacityHardRouteConstraint::<T> {
                     code,
                     multi_trip: multi_trip.clone(),
@@ -102,9 +110,11 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output
     }
 
     fn recalculate_states(&self, ctx: &mut RouteContext) {
-        let (_, max_load) = self.actualize_intervals(ctx).into_iter().fold(
-            (T::default(), T::default()),
-            |(acc, max), (start_idx, end_idx)| {
+        let capacity = ctx.route.actor.vehicle.dimens.get_capacity().cloned();
+
+        let (_, max_load, overload) = self.actualize_intervals(ctx).into_iter().fold(
+            (T::default(), T::default(), 0),
+            |(acc, max, overload), (start_idx, end_idx)| {
                 let (route, state) = ctx.as_mut();
 
                 // determine static deliveries loaded at the begin and static pickups brought to the end
@@ -141,12 +151,18 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output
                         max
                     });
 
-                (current - end_pickup, current_max.max_load(max))
+                let overload = overload + capacity.as_ref().map_or(0, |capacity| current_max.overload(capacity));
+
+                (current - end_pickup, current_max.max_load(max), overload)
             },
         );
 
-        if let Some(capacity) = ctx.route.actor.clone().vehicle.dimens.get_capacity() {
-            ctx.state_mut().put_route_state(MAX_LOAD_KEY, max_load.ratio(capacity));
+        if let Some(capacity) = capacity {
+            ctx.state_mut().put_route_state(MAX_LOAD_KEY, max_load.ratio(&capacity));
+        }
+
+        if ctx.route.actor.vehicle.dimens.get_capacity_overload().is_some() {
+            ctx.state_mut().put_route_state(CAPACITY_OVERLOAD_KEY, overload);
         }
     }
 
@@ -255,14 +271,9 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output
         demand: Option<&Demand<T>>,
         insert_idx: Option<usize>,
     ) -> bool {
+        let capacity = get_max_capacity::<T>(ctx);
         let has_demand_violation = |activity: &Activity| {
-            CapacityConstraintModule::<T>::has_demand_violation(
-                &ctx.state,
-                activity,
-                ctx.route.actor.vehicle.dimens.get_capacity(),
-                demand,
-                true,
-            )
+            CapacityConstraintModule::<T>::has_demand_violation(&ctx.state, activity, capacity.as_ref(), demand, true)
         };
 
         ctx.state
@@ -333,6 +344,10 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct CapacitySoftRouteConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
@@ -349,6 +364,37 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> SoftRouteConstraint
     }
 }
 
+/// Penalizes insertions which increase vehicle overload.
+struct CapacitySoftActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> SoftActivityConstraint
+    for CapacitySoftActivityConstraint<T>
+{
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let dimens = &route_ctx.route.actor.vehicle.dimens;
+        let demand = CapacityConstraintModule::<T>::get_demand(activity_ctx.target);
+
+        match (dimens.get_capacity_overload(), dimens.get_capacity(), demand) {
+            (Some(overload), Some(capacity), Some(demand)) => {
+                let default = T::default();
+                let state = &route_ctx.state;
+                let past = *state.get_activity_state(MAX_PAST_CAPACITY_KEY, activity_ctx.prev).unwrap_or(&default);
+                let future = *state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, activity_ctx.prev).unwrap_or(&default);
+
+                // NOTE this is an approximation: static delivery increases load before the activity,
+                // pickup increases it after
+                let old_peak = past.max_load(future);
+                let new_peak = old_peak.max_load(past + demand.delivery.0).max_load(future + demand.change());
+
+                (new_peak.overload(capacity) - old_peak.overload(capacity)) as f64 * overload.penalty
+            }
+            _ => 0.,
+        }
+    }
+}
+
 /// Locks reload jobs to specific vehicles
 struct CapacityHardRouteConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
     code: i32,
@@ -424,7 +470,7 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstrai
             CapacityConstraintModule::<T>::has_demand_violation(
                 &route_ctx.state,
                 activity_ctx.prev,
-                route_ctx.route.actor.vehicle.dimens.get_capacity(),
+                get_max_capacity::<T>(route_ctx).as_ref(),
                 demand,
                 !self.multi_trip.has_reloads(route_ctx),
             )
@@ -438,6 +484,36 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstrai
     }
 }
 
+/// Returns amount of route activities where vehicle load exceeds its capacity (including allowed
+/// overload, if any). Expects capacity states to be calculated.
+pub fn count_capacity_violations<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    route_ctx: &RouteContext,
+) -> usize {
+    get_max_capacity::<T>(route_ctx).map_or(0, |capacity| {
+        route_ctx
+            .route
+            .tour
+            .all_activities()
+            .filter(|activity| {
+                route_ctx
+                    .state
+                    .get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity)
+                    .map_or(false, |load| !capacity.can_fit(load))
+            })
+            .count()
+    })
+}
+
+/// Returns vehicle capacity extended by allowed overload, if any.
+fn get_max_capacity<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(route_ctx: &RouteContext) -> Option<T> {
+    let dimens = &route_ctx.route.actor.vehicle.dimens;
+
+    dimens.get_capacity().map(|capacity: &T| match dimens.get_capacity_overload() {
+        Some(overload) => *capacity * (1. + overload.max_ratio),
+        None => *capacity,
+    })
+}
+
 /// A no multi trip strategy.
 struct NoMultiTrip<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
     phantom: PhantomData<T>,
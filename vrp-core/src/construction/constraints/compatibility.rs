@@ -0,0 +1,222 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/compatibility_test.rs"]
+mod compatibility_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Demand, DemandDimension, Load};
+use crate::models::problem::Job;
+use crate::models::solution::Activity;
+use hashbrown::HashMap;
+use std::marker::PhantomData;
+use std::ops::{Add, Deref, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies how jobs from incompatible groups are kept apart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CompatibilityPolicy {
+    /// Jobs cannot be served in the same tour.
+    NeverSameTour,
+    /// Jobs can be served in the same tour, but cannot be on board of the vehicle at the same time.
+    NeverSimultaneouslyLoaded,
+}
+
+/// Specifies compatibility group of the job and groups it cannot be mixed with.
+#[derive(Clone, Debug)]
+pub struct Compatibility {
+    /// A group of the job, e.g. food.
+    pub group: String,
+    /// Groups which are incompatible with the job's group, e.g. chemicals.
+    pub incompatible: Vec<String>,
+    /// A policy applied to the job and jobs from incompatible groups.
+    pub policy: CompatibilityPolicy,
+}
+
+/// A function which returns compatibility of given job.
+pub type CompatibilityResolver = Arc<dyn Fn(&Job) -> Option<&Compatibility> + Sync + Send>;
+
+/// A compatibility module forbids mixing jobs from incompatible groups in the same route or on the
+/// vehicle at the same time. Incompatibility is symmetric: it is enough to declare it for one of
+/// the groups. When both jobs declare it with different policies, the stricter one is applied.
+///
+/// A job is considered to be on board from the tour start till its static delivery, from its static
+/// pickup till the tour end, or between its dynamic pickup and delivery. Please note that reloads
+/// are not taken into account, so the check is conservative for multi trip routes.
+pub struct CompatibilityConstraintModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> CompatibilityConstraintModule<T> {
+    /// Creates a new instance of `CompatibilityConstraintModule`.
+    pub fn new(resolver: CompatibilityResolver, code: i32) -> Self {
+        Self {
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(CompatibilityHardRouteConstraint {
+                    resolver: resolver.clone(),
+                    code,
+                })),
+                ConstraintVariant::HardActivity(Arc::new(CompatibilityHardActivityConstraint::<T> {
+                    resolver,
+                    code,
+                    phantom: PhantomData,
+                })),
+            ],
+            keys: vec![],
+            codes: vec![code],
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for CompatibilityConstraintModule<T> {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<'_, i32> {
+        self.codes.iter()
+    }
+}
+
+struct CompatibilityHardRouteConstraint {
+    resolver: CompatibilityResolver,
+    code: i32,
+}
+
+impl HardRouteConstraint for CompatibilityHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let target = self.resolver.deref()(job)?;
+
+        let is_violated = ctx.route.tour.jobs().any(|other| {
+            matches!(
+                self.resolver.deref()(&other).and_then(|other| get_policy(target, other)),
+                Some(CompatibilityPolicy::NeverSameTour)
+            )
+        });
+
+        if is_violated {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+struct CompatibilityHardActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    resolver: CompatibilityResolver,
+    code: i32,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstraint
+    for CompatibilityHardActivityConstraint<T>
+{
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let target_job = activity_ctx.target.retrieve_job()?;
+        let target = self.resolver.deref()(&target_job)?;
+
+        let incompatible = route_ctx
+            .route
+            .tour
+            .jobs()
+            .filter(|job| self.resolver.deref()(job).and_then(|other| get_policy(target, other)).is_some())
+            .collect::<Vec<_>>();
+
+        if incompatible.is_empty() {
+            return None;
+        }
+
+        // NOTE positions are doubled to fit the target activity between its neighbours
+        let mut intervals = route_ctx.route.tour.all_activities().enumerate().fold(
+            HashMap::<Job, Interval>::default(),
+            |mut acc, (idx, activity)| {
+                if let Some(job) = activity.retrieve_job() {
+                    acc.entry(job).or_insert_with(Interval::default).add(idx * 2, get_demand::<T>(activity));
+                }
+                acc
+            },
+        );
+
+        let mut target_interval = intervals.remove(&target_job).unwrap_or_default();
+        target_interval.add(activity_ctx.index * 2 + 1, get_demand::<T>(activity_ctx.target));
+
+        // NOTE on board interval of the job is known only when its last activity is inserted
+        let activities = target_job.as_multi().map_or(1, |multi| multi.jobs.len());
+        if target_interval.count < activities {
+            return None;
+        }
+
+        let is_violated = incompatible
+            .iter()
+            .filter_map(|job| intervals.get(job))
+            .any(|interval| target_interval.start < interval.end && interval.start < target_interval.end);
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Keeps on board interval of the job in the tour.
+#[derive(Clone, Default)]
+struct Interval {
+    start: usize,
+    end: usize,
+    count: usize,
+}
+
+impl Interval {
+    fn add<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+        &mut self,
+        position: usize,
+        demand: Option<&Demand<T>>,
+    ) {
+        let (start, end) = match demand {
+            Some(demand) if demand.delivery.0.is_not_empty() => (0, position),
+            Some(demand) if demand.pickup.0.is_not_empty() => (position, usize::MAX),
+            _ => (position, position),
+        };
+
+        self.start = if self.count == 0 { start } else { self.start.min(start) };
+        self.end = if self.count == 0 { end } else { self.end.max(end) };
+        self.count += 1;
+    }
+}
+
+fn get_demand<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(activity: &Activity) -> Option<&Demand<T>> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_demand())
+}
+
+/// Returns the strictest policy applied to two jobs if their groups are incompatible.
+fn get_policy(first: &Compatibility, second: &Compatibility) -> Option<CompatibilityPolicy> {
+    let first_policy = if first.incompatible.contains(&second.group) { Some(first.policy) } else { None };
+    let second_policy = if second.incompatible.contains(&first.group) { Some(second.policy) } else { None };
+
+    match (first_policy, second_policy) {
+        (Some(CompatibilityPolicy::NeverSameTour), _) | (_, Some(CompatibilityPolicy::NeverSameTour)) => {
+            Some(CompatibilityPolicy::NeverSameTour)
+        }
+        (Some(policy), _) | (_, Some(policy)) => Some(policy),
+        _ => None,
+    }
+}
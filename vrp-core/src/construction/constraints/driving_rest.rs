@@ -0,0 +1,202 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/driving_rest_test.rs"]
+mod driving_rest_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Duration;
+use crate::models::problem::{Actor, Job, Single, TransportCost};
+use crate::models::solution::Activity;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies continuous driving time limit of the vehicle driver.
+#[derive(Clone, Debug)]
+pub struct DrivingLimit {
+    /// Max continuous driving time between rests.
+    pub max_driving_time: Duration,
+    /// Amount of rests available for the driver.
+    pub rests: usize,
+}
+
+/// A function which returns continuous driving time limit for given actor.
+pub type DrivingLimitResolver = Arc<dyn Fn(&Actor) -> Option<DrivingLimit> + Sync + Send>;
+
+/// A function which checks whether given job is a driver rest which resets continuous driving time.
+pub type DrivingRestResolver = Arc<dyn Fn(&Single) -> bool + Sync + Send>;
+
+/// A driving rest module limits continuous driving time similar to tachograph rules: the driver
+/// cannot drive longer than allowed without a rest, so a rest job has to be inserted into the tour
+/// to split driving. Continuous driving time is tracked in both directions from each activity, so
+/// insertion can be checked in constant time. A job which exceeds the limit is still accepted if
+/// the driver has an available rest which can be taken at the job to split driving. In this case,
+/// the next rest has to be inserted into the exceeding part of the tour.
+pub struct DrivingRestModule {
+    limit_resolver: DrivingLimitResolver,
+    rest_resolver: DrivingRestResolver,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+}
+
+impl DrivingRestModule {
+    /// Creates a new instance of `DrivingRestModule`.
+    pub fn new(
+        limit_resolver: DrivingLimitResolver,
+        rest_resolver: DrivingRestResolver,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            limit_resolver: limit_resolver.clone(),
+            rest_resolver: rest_resolver.clone(),
+            transport: transport.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(DrivingRestHardActivityConstraint {
+                limit_resolver,
+                rest_resolver,
+                transport,
+                code,
+            }))],
+            keys: vec![CONTINUOUS_DRIVING_KEY, DRIVING_REST_KEY],
+            codes: vec![code],
+        }
+    }
+}
+
+impl ConstraintModule for DrivingRestModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let limit = if let Some(limit) = self.limit_resolver.deref()(ctx.route.actor.as_ref()) {
+            limit
+        } else {
+            return;
+        };
+
+        let profile = ctx.route.actor.vehicle.profile;
+        let legs = ctx
+            .route
+            .tour
+            .all_activities()
+            .zip(ctx.route.tour.all_activities().skip(1))
+            .map(|(from, to)| {
+                self.transport.duration(profile, from.place.location, to.place.location, from.schedule.departure)
+            })
+            .collect::<Vec<_>>();
+        let is_rest = ctx.route.tour.all_activities().map(|a| is_rest(&self.rest_resolver, a)).collect::<Vec<_>>();
+
+        // NOTE past keeps continuous driving time till departure from the activity, future keeps
+        // continuous driving time after departure till arrival at the next rest or tour end
+        let past = legs.iter().enumerate().fold(vec![0.], |mut acc, (idx, duration)| {
+            let driving = if is_rest[idx + 1] { 0. } else { acc.last().unwrap() + *duration };
+            acc.push(driving);
+            acc
+        });
+        let future = legs.iter().enumerate().rev().fold(vec![0.], |mut acc, (idx, duration)| {
+            let driving = if is_rest[idx + 1] { *duration } else { acc.last().unwrap() + *duration };
+            acc.push(driving);
+            acc
+        });
+
+        let rest_state = RestState {
+            rests: is_rest.iter().filter(|is_rest| **is_rest).count(),
+            is_exceeded: past.iter().chain(future.iter()).any(|driving| *driving > limit.max_driving_time),
+        };
+
+        let (route, state) = ctx.as_mut();
+        route.tour.all_activities().zip(past.into_iter().zip(future.into_iter().rev())).for_each(
+            |(activity, times)| {
+                state.put_activity_state::<(Duration, Duration)>(CONTINUOUS_DRIVING_KEY, activity, times);
+            },
+        );
+        state.put_route_state::<Duration>(CONTINUOUS_DRIVING_KEY, legs.iter().sum());
+        state.put_route_state(DRIVING_REST_KEY, rest_state);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<'_, i32> {
+        self.codes.iter()
+    }
+}
+
+struct DrivingRestHardActivityConstraint {
+    limit_resolver: DrivingLimitResolver,
+    rest_resolver: DrivingRestResolver,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: i32,
+}
+
+impl HardActivityConstraint for DrivingRestHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let limit = self.limit_resolver.deref()(route_ctx.route.actor.as_ref())?;
+        let max_driving_time = limit.max_driving_time;
+
+        let state = &route_ctx.state;
+        let get_driving = |activity: &Activity| {
+            state
+                .get_activity_state::<(Duration, Duration)>(CONTINUOUS_DRIVING_KEY, activity)
+                .cloned()
+                .unwrap_or((0., 0.))
+        };
+
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let prev_dur =
+            self.transport.duration(profile, prev.place.location, target.place.location, prev.schedule.departure);
+        let (next_dur, next_future) = activity_ctx.next.map_or((0., 0.), |next| {
+            let departure = (prev.schedule.departure + prev_dur).max(target.place.time.start) + target.place.duration;
+            // NOTE driving after the next activity is not continued when it is a rest
+            let future = if is_rest(&self.rest_resolver, next) { 0. } else { get_driving(next).1 };
+            (self.transport.duration(profile, target.place.location, next.place.location, departure), future)
+        });
+        let (prev_past, prev_future) = get_driving(prev);
+        let rest_state = state.get_route_state::<RestState>(DRIVING_REST_KEY).cloned().unwrap_or_default();
+
+        let is_split_violated = prev_past + prev_dur > max_driving_time || next_dur + next_future > max_driving_time;
+
+        let is_violated = if is_rest(&self.rest_resolver, target) {
+            // NOTE when driving is exceeded somewhere, the rest has to split exceeding part of the tour
+            is_split_violated || (rest_state.is_exceeded && prev_past + prev_future <= max_driving_time)
+        } else if prev_past + prev_dur + next_dur + next_future > max_driving_time {
+            // NOTE rest is not yet in the tour, but it can be inserted later at the target to split driving
+            rest_state.is_exceeded || rest_state.rests >= limit.rests || is_split_violated
+        } else {
+            false
+        };
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct RestState {
+    rests: usize,
+    is_exceeded: bool,
+}
+
+fn is_rest(rest_resolver: &DrivingRestResolver, activity: &Activity) -> bool {
+    matches!(activity.job.as_ref(), Some(job) if rest_resolver.deref()(job.as_ref()))
+}
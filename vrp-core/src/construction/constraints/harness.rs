@@ -0,0 +1,471 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/harness_test.rs"]
+mod harness_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext};
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::{Activity, Place};
+use crate::models::Problem;
+use crate::utils::{compare_floats, DefaultRandom, Environment, Parallelism, Random};
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+
+/// A function which creates constraint modules under validation for generated problem using its
+/// activity and transport costs.
+pub type HarnessModuleFactory = Arc<
+    dyn Fn(
+            Arc<dyn ActivityCost + Send + Sync>,
+            Arc<dyn TransportCost + Send + Sync>,
+        ) -> Vec<Box<dyn ConstraintModule + Send + Sync>>
+        + Send
+        + Sync,
+>;
+
+/// A function which checks route state kept incrementally by constraint modules against its
+/// brute-force recomputation. Returns error description if they are different.
+pub type HarnessStateChecker = Arc<dyn Fn(&Problem, &RouteContext) -> Result<(), String> + Send + Sync>;
+
+/// Specifies parameters of problems generated by `ConstraintHarness`.
+pub struct HarnessSettings {
+    /// Amount of problems to generate.
+    pub problems: usize,
+    /// Amount of jobs in each problem.
+    pub jobs: usize,
+    /// Amount of vehicles in each problem.
+    pub vehicles: usize,
+    /// Amount of distinct locations, including depot.
+    pub locations: usize,
+    /// Vehicle capacity. Job demands are generated within a half of it.
+    pub capacity: i32,
+    /// A seed of random generator: problem with index `i` uses `seed + i`, so failures can be reproduced.
+    pub seed: u64,
+}
+
+impl Default for HarnessSettings {
+    fn default() -> Self {
+        Self { problems: 20, jobs: 20, vehicles: 3, locations: 15, capacity: 10, seed: 0 }
+    }
+}
+
+/// A harness which validates constraint modules systematically: it generates random problems with
+/// time windows and capacities, inserts their jobs in random order at random feasible positions and,
+/// after each insertion, cross-checks route states kept by constraint modules using state checkers.
+///
+/// Generated problems have closed tours and single jobs with `SingleDimLoad` demands.
+pub struct ConstraintHarness {
+    settings: HarnessSettings,
+    module_factory: HarnessModuleFactory,
+    checkers: Vec<HarnessStateChecker>,
+}
+
+impl ConstraintHarness {
+    /// Creates a new instance of `ConstraintHarness`.
+    pub fn new(settings: HarnessSettings, module_factory: HarnessModuleFactory) -> Self {
+        Self { settings, module_factory, checkers: vec![] }
+    }
+
+    /// Adds route state checker.
+    pub fn with_checker(mut self, checker: HarnessStateChecker) -> Self {
+        self.checkers.push(checker);
+        self
+    }
+
+    /// Runs harness and returns total amount of checked insertions or description of the first
+    /// found discrepancy.
+    pub fn run(&self) -> Result<usize, String> {
+        (0..self.settings.problems).try_fold(0, |total, idx| {
+            let seed = self.settings.seed + idx as u64;
+            self.run_problem(seed)
+                .map(|insertions| total + insertions)
+                .map_err(|err| format!("problem with seed {}: {}", seed, err))
+        })
+    }
+
+    fn run_problem(&self, seed: u64) -> Result<usize, String> {
+        let random = Arc::new(DefaultRandom::new_with_seed(seed));
+        let environment = Arc::new(Environment::new(random.clone(), Parallelism::default()));
+        let problem = self.create_problem(random.as_ref());
+
+        let mut insertion_ctx = InsertionContext::new(problem.clone(), environment);
+        let solution = &mut insertion_ctx.solution;
+        solution.required.extend(solution.unassigned.drain().map(|(job, _)| job));
+        problem.constraint.accept_solution_state(solution);
+
+        let mut jobs = solution.required.clone();
+        let mut insertions = 0;
+
+        while !jobs.is_empty() {
+            let job = jobs.swap_remove(random.uniform_int(0, jobs.len() as i32 - 1) as usize);
+
+            if insert_randomly(&mut insertion_ctx, &job, random.as_ref()) {
+                insertions += 1;
+                self.check_routes(&insertion_ctx).map_err(|err| format!("insertion {}: {}", insertions, err))?;
+            }
+        }
+
+        problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+        self.check_routes(&insertion_ctx).map_err(|err| format!("solution state: {}", err))?;
+
+        Ok(insertions)
+    }
+
+    fn check_routes(&self, insertion_ctx: &InsertionContext) -> Result<(), String> {
+        insertion_ctx.solution.routes.iter().enumerate().try_for_each(|(route_idx, route_ctx)| {
+            self.checkers
+                .iter()
+                .try_for_each(|checker| checker(insertion_ctx.problem.as_ref(), route_ctx))
+                .map_err(|err| format!("route {}: {}", route_idx, err))
+        })
+    }
+
+    fn create_problem(&self, random: &(dyn Random + Send + Sync)) -> Arc<Problem> {
+        let settings = &self.settings;
+        let horizon = 1000.;
+        let size = settings.locations.max(2);
+
+        let points = (0..size).map(|_| (random.uniform_int(0, 100), random.uniform_int(0, 100))).collect::<Vec<_>>();
+        let matrix = points
+            .iter()
+            .flat_map(|(x1, y1)| {
+                points.iter().map(move |(x2, y2)| (((x1 - x2).pow(2) + (y1 - y2).pow(2)) as f64).sqrt().round())
+            })
+            .collect::<Vec<_>>();
+
+        let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(SimpleActivityCost::default());
+        let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)])
+            .expect("cannot create transport costs");
+
+        let drivers = vec![Arc::new(Driver { costs: create_costs(0.), dimens: Default::default(), details: vec![] })];
+        let vehicles = (0..settings.vehicles.max(1))
+            .map(|idx| {
+                let mut dimens = Dimensions::default();
+                dimens.set_id(format!("v{}", idx + 1).as_str()).set_capacity(SingleDimLoad::new(settings.capacity));
+
+                Arc::new(Vehicle {
+                    profile: 0,
+                    costs: create_costs(1.),
+                    dimens,
+                    details: vec![VehicleDetail {
+                        start: Some(VehiclePlace {
+                            location: 0,
+                            time: TimeInterval { earliest: Some(0.), latest: None },
+                        }),
+                        end: Some(VehiclePlace {
+                            location: 0,
+                            time: TimeInterval { earliest: None, latest: Some(horizon) },
+                        }),
+                        driver: None,
+                    }],
+                })
+            })
+            .collect();
+        let fleet = Arc::new(Fleet::new(drivers, vehicles, Box::new(|_| Box::new(|_| 0))));
+
+        let jobs = (0..settings.jobs)
+            .map(|idx| {
+                let time = if random.is_head_not_tails() {
+                    TimeWindow::new(0., horizon)
+                } else {
+                    let start = random.uniform_int(0, 800) as f64;
+                    TimeWindow::new(start, start + random.uniform_int(50, 300) as f64)
+                };
+
+                let amount = SingleDimLoad::new(random.uniform_int(1, (settings.capacity / 2).max(1)));
+                let demand = if random.is_head_not_tails() {
+                    Demand { pickup: (amount, Default::default()), delivery: Default::default() }
+                } else {
+                    Demand { pickup: Default::default(), delivery: (amount, Default::default()) }
+                };
+
+                let mut dimens = Dimensions::default();
+                dimens.set_id(format!("job{}", idx + 1).as_str()).set_demand(demand);
+
+                Job::Single(Arc::new(Single {
+                    places: vec![crate::models::problem::Place {
+                        location: Some(random.uniform_int(1, size as i32 - 1) as usize),
+                        duration: random.uniform_int(0, 20) as f64,
+                        times: vec![TimeSpan::Window(time)],
+                    }],
+                    dimens,
+                }))
+            })
+            .collect();
+        let jobs = Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport));
+
+        let mut constraint = ConstraintPipeline::default();
+        (self.module_factory)(activity.clone(), transport.clone()).into_iter().for_each(|module| {
+            constraint.add_module(module);
+        });
+
+        Arc::new(Problem {
+            fleet,
+            jobs,
+            locks: vec![],
+            constraint: Arc::new(constraint),
+            activity,
+            transport,
+            objective: Arc::new(ObjectiveCost::default()),
+            extras: Arc::new(Default::default()),
+        })
+    }
+}
+
+/// Creates a state checker which simulates schedule of the whole route and compares it with
+/// activity schedules. Also checks that no time window is violated.
+pub fn create_schedule_checker() -> HarnessStateChecker {
+    Arc::new(|problem, route_ctx| {
+        let actor = route_ctx.route.actor.as_ref();
+        let tour = &route_ctx.route.tour;
+
+        (1..tour.total()).try_for_each(|idx| {
+            let prev = tour.get(idx - 1).unwrap();
+            let activity = tour.get(idx).unwrap();
+
+            let departure = prev.schedule.departure;
+            let arrival = departure
+                + problem.transport.duration(
+                    actor.vehicle.profile,
+                    prev.place.location,
+                    activity.place.location,
+                    departure,
+                );
+            let departure = arrival.max(activity.place.time.start)
+                + problem.activity.duration_after(
+                    actor,
+                    &mut tour.activities_slice(0, idx - 1).iter().rev(),
+                    activity,
+                    arrival,
+                );
+
+            if compare_floats(arrival, activity.schedule.arrival) != Ordering::Equal
+                || compare_floats(departure, activity.schedule.departure) != Ordering::Equal
+            {
+                return Err(format!(
+                    "activity {} has schedule ({}, {}), but ({}, {}) is expected",
+                    idx, activity.schedule.arrival, activity.schedule.departure, arrival, departure
+                ));
+            }
+
+            if compare_floats(arrival, activity.place.time.end) == Ordering::Greater {
+                return Err(format!("activity {} is visited at {} after its time window end", idx, arrival));
+            }
+
+            Ok(())
+        })
+    })
+}
+
+/// Creates a state checker which compares latest arrival state of job activities with the latest
+/// arrival time found by simulation of the rest of the route.
+pub fn create_latest_arrival_checker() -> HarnessStateChecker {
+    Arc::new(|problem, route_ctx| {
+        let tour = &route_ctx.route.tour;
+
+        (1..tour.total()).filter(|idx| tour.get(*idx).unwrap().job.is_some()).try_for_each(|idx| {
+            let activity = tour.get(idx).unwrap();
+            let actual = route_ctx
+                .state
+                .get_activity_state::<f64>(LATEST_ARRIVAL_KEY, activity)
+                .cloned()
+                .ok_or_else(|| format!("activity {} has no latest arrival state", idx))?;
+
+            let is_feasible = |arrival: Timestamp| is_feasible_arrival(problem, route_ctx, idx, arrival);
+
+            let (mut low, mut high) = (activity.schedule.arrival, activity.place.time.end);
+            if !is_feasible(low) {
+                return Err(format!("activity {} is scheduled at infeasible arrival time {}", idx, low));
+            }
+
+            let expected = if is_feasible(high) {
+                high
+            } else {
+                (0..100).for_each(|_| {
+                    let middle = low + (high - low) / 2.;
+                    if is_feasible(middle) {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                });
+                low
+            };
+
+            if (expected - actual).abs() > 1E-6 * expected.abs().max(1.) {
+                return Err(format!("activity {} has latest arrival {}, but {} is expected", idx, actual, expected));
+            }
+
+            Ok(())
+        })
+    })
+}
+
+/// Creates a state checker which recomputes vehicle load at each activity and compares it with
+/// current, max past and max future capacity states. Also checks that vehicle capacity is not exceeded.
+/// Reloads are not supported.
+pub fn create_capacity_checker<T: Load + Add<Output = T> + Sub<Output = T> + 'static>() -> HarnessStateChecker {
+    Arc::new(|_, route_ctx| {
+        let get_demand = |activity: &Activity| -> Option<Demand<T>> {
+            activity.job.as_ref().and_then(|job| job.dimens.get_demand().cloned())
+        };
+
+        let tour = &route_ctx.route.tour;
+        let start =
+            tour.all_activities().filter_map(get_demand).fold(T::default(), |acc, demand| acc + demand.delivery.0);
+        let loads = tour
+            .all_activities()
+            .scan(start, |current, activity| {
+                *current = *current + get_demand(activity).map(|demand| demand.change()).unwrap_or_default();
+                Some(*current)
+            })
+            .collect::<Vec<_>>();
+
+        let capacity: Option<&T> = route_ctx.route.actor.vehicle.dimens.get_capacity();
+        let state = route_ctx.state.as_ref();
+
+        tour.all_activities().enumerate().filter(|(_, activity)| activity.job.is_some()).try_for_each(
+            |(idx, activity)| {
+                let max_past = loads[..=idx].iter().fold(T::default(), |acc, load| acc.max_load(*load));
+                let max_future = loads[idx..].iter().fold(T::default(), |acc, load| acc.max_load(*load));
+
+                let expected = [
+                    (CURRENT_CAPACITY_KEY, "current", loads[idx]),
+                    (MAX_PAST_CAPACITY_KEY, "max past", max_past),
+                    (MAX_FUTURE_CAPACITY_KEY, "max future", max_future),
+                ];
+
+                expected.iter().try_for_each(|(key, name, expected)| {
+                    match state.get_activity_state::<T>(*key, activity) {
+                        Some(actual) if actual == expected => Ok(()),
+                        Some(_) => Err(format!("activity {} has unexpected {} load", idx, name)),
+                        None => Err(format!("activity {} has no {} load state", idx, name)),
+                    }
+                })?;
+
+                match capacity {
+                    Some(capacity) if !capacity.can_fit(&loads[idx]) => {
+                        Err(format!("activity {} has load which exceeds vehicle capacity", idx))
+                    }
+                    _ => Ok(()),
+                }
+            },
+        )
+    })
+}
+
+/// Inserts job into random route at random feasible position. Returns false if there is no such position.
+fn insert_randomly(insertion_ctx: &mut InsertionContext, job: &Job, random: &(dyn Random + Send + Sync)) -> bool {
+    let single = match job {
+        Job::Single(single) => single.clone(),
+        Job::Multi(_) => return false,
+    };
+
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let solution = &mut insertion_ctx.solution;
+
+    let candidates = solution.routes.iter().cloned().chain(solution.registry.next()).collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return false;
+    }
+    let route_ctx = candidates[random.uniform_int(0, candidates.len() as i32 - 1) as usize].clone();
+
+    if constraint.evaluate_hard_route(solution, &route_ctx, job).is_some() {
+        return false;
+    }
+
+    let start_time = route_ctx.route.tour.start().unwrap().schedule.departure;
+    let positions = route_ctx
+        .route
+        .tour
+        .legs()
+        .flat_map(|(items, index)| {
+            let (prev, next) = match items {
+                [prev] => (prev, None),
+                [prev, next] => (prev, Some(next)),
+                _ => panic!("unexpected route leg configuration"),
+            };
+
+            single
+                .places
+                .iter()
+                .flat_map(|place| place.times.iter().map(move |time| (place, time)))
+                .filter_map(|(place, time)| {
+                    let mut target = Activity::new_with_job(single.clone());
+                    target.place = Place {
+                        location: place.location.unwrap_or(prev.place.location),
+                        duration: place.duration,
+                        time: time.to_time_window(start_time),
+                    };
+
+                    let activity_ctx = ActivityContext { index, prev, target: &target, next };
+
+                    if constraint.evaluate_hard_activity(&route_ctx, &activity_ctx).is_none() {
+                        Some((target, index))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if positions.is_empty() {
+        return false;
+    }
+
+    let position = random.uniform_int(0, positions.len() as i32 - 1) as usize;
+    let (activity, index) = positions.into_iter().nth(position).unwrap();
+
+    solution.registry.use_route(&route_ctx);
+    let route_index = solution.routes.iter().position(|ctx| ctx == &route_ctx).unwrap_or_else(|| {
+        solution.routes.push(route_ctx.deep_copy());
+        solution.routes.len() - 1
+    });
+
+    let route_ctx = solution.routes.get_mut(route_index).unwrap();
+    constraint.insertion_cache().invalidate(route_ctx);
+    route_ctx.route_mut().tour.insert_at(activity, index + 1);
+
+    solution.required.retain(|required| required != job);
+    constraint.accept_insertion(solution, route_index, job);
+
+    true
+}
+
+/// Checks whether the rest of the route is feasible when activity with given index is reached at given time.
+fn is_feasible_arrival(problem: &Problem, route_ctx: &RouteContext, idx: usize, arrival: Timestamp) -> bool {
+    let profile = route_ctx.route.actor.vehicle.profile;
+    let get_service_time =
+        |activity: &Activity| activity.schedule.departure - activity.schedule.arrival.max(activity.place.time.start);
+
+    let activity = route_ctx.route.tour.get(idx).unwrap();
+    let init = (activity.place.location, arrival.max(activity.place.time.start) + get_service_time(activity));
+
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .skip(idx + 1)
+        .try_fold(init, |(location, departure), activity| {
+            let arrival = departure + problem.transport.duration(profile, location, activity.place.location, departure);
+
+            if compare_floats(arrival, activity.place.time.end) == Ordering::Greater {
+                None
+            } else {
+                Some((activity.place.location, arrival.max(activity.place.time.start) + get_service_time(activity)))
+            }
+        })
+        .is_some()
+}
+
+fn create_costs(per_unit: f64) -> Costs {
+    Costs {
+        fixed: 0.,
+        per_distance: per_unit,
+        per_driving_time: per_unit,
+        per_waiting_time: per_unit,
+        per_service_time: per_unit,
+    }
+}
@@ -14,6 +14,7 @@ use std::sync::Arc;
 pub struct StrictLockingModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
+    codes: Vec<i32>,
 }
 
 impl ConstraintModule for StrictLockingModule {
@@ -30,6 +31,10 @@ impl ConstraintModule for StrictLockingModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 impl StrictLockingModule {
@@ -67,6 +72,7 @@ impl StrictLockingModule {
 
         Self {
             state_keys: vec![],
+            codes: vec![code],
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(StrictLockingHardRouteConstraint { code, conditions })),
                 ConstraintVariant::HardActivity(Arc::new(StrictLockingHardActivityConstraint {
@@ -63,6 +63,10 @@ pub const WAITING_KEY: i32 = 2;
 pub const TOTAL_DISTANCE_KEY: i32 = 3;
 /// A key which track total duration.
 pub const TOTAL_DURATION_KEY: i32 = 4;
+/// A key which tracks extra cost penalty of the route added to its cost.
+pub const ROUTE_PENALTY_KEY: i32 = 5;
+/// A key which tracks total amount of monetary fees (e.g. tolls) paid in the route.
+pub const TOTAL_TOLL_KEY: i32 = 6;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -74,6 +78,14 @@ pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
 /// A key which tracks max load in tour.
 pub const MAX_LOAD_KEY: i32 = 15;
+/// A key which tracks total amount of units loaded above vehicle capacity.
+pub const CAPACITY_OVERLOAD_KEY: i32 = 16;
+/// A key which tracks driving time of each driver in the vehicle team.
+pub const DRIVING_TIME_KEY: i32 = 17;
+/// A key which tracks continuous driving time between driver rests.
+pub const CONTINUOUS_DRIVING_KEY: i32 = 18;
+/// A key which tracks driver rests in the tour.
+pub const DRIVING_REST_KEY: i32 = 19;
 
 mod pipeline;
 pub use self::pipeline::*;
@@ -93,8 +105,32 @@ pub use self::locking::*;
 mod tour_size;
 pub use self::tour_size::*;
 
+mod radius;
+pub use self::radius::*;
+
+mod team_driving;
+pub use self::team_driving::*;
+
+mod driving_rest;
+pub use self::driving_rest::*;
+
+mod precedence;
+pub use self::precedence::*;
+
+mod compatibility;
+pub use self::compatibility::*;
+
 mod conditional;
 pub use self::conditional::*;
 
 mod fleet_usage;
 pub use self::fleet_usage::*;
+
+mod weights;
+pub use self::weights::*;
+
+mod cancellation;
+pub use self::cancellation::*;
+
+mod harness;
+pub use self::harness::*;
@@ -2,7 +2,8 @@
 #[path = "../../../tests/unit/construction/constraints/pipeline_test.rs"]
 mod pipeline_test;
 
-use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::construction::constraints::ConstraintWeights;
+use crate::construction::heuristics::{ActivityContext, InsertionCache, RouteContext, SolutionContext};
 use crate::models::common::Cost;
 use crate::models::problem::Job;
 use hashbrown::HashSet;
@@ -100,32 +101,148 @@ pub trait ConstraintModule {
 
     /// Returns list of constraints to be used.
     fn get_constraints(&self) -> Iter<ConstraintVariant>;
+
+    /// Returns violation codes which can be reported by module's hard constraints.
+    /// Used only for introspection.
+    fn violation_codes(&self) -> Iter<i32> {
+        [].iter()
+    }
+}
+
+/// Describes a constraint module registered in constraint pipeline.
+#[derive(Clone, Debug)]
+pub struct ConstraintModuleInfo {
+    /// Evaluation priority of module's hard constraints: lower values are evaluated first.
+    pub priority: i32,
+    /// State keys used by the module.
+    pub state_keys: Vec<i32>,
+    /// Violation codes which can be reported by the module.
+    pub violation_codes: Vec<i32>,
+    /// Amount of hard route constraints.
+    pub hard_route: usize,
+    /// Amount of hard activity constraints.
+    pub hard_activity: usize,
+    /// Amount of soft route constraints.
+    pub soft_route: usize,
+    /// Amount of soft activity constraints.
+    pub soft_activity: usize,
 }
 
 /// Provides the way to work with multiple constraints.
 pub struct ConstraintPipeline {
     modules: Vec<Box<dyn ConstraintModule + Send + Sync>>,
+    priorities: Vec<i32>,
     state_keys: HashSet<i32>,
-    hard_route_constraints: Vec<Arc<dyn HardRouteConstraint + Send + Sync>>,
-    hard_activity_constraints: Vec<Arc<dyn HardActivityConstraint + Send + Sync>>,
+    hard_route_constraints: Vec<(i32, Arc<dyn HardRouteConstraint + Send + Sync>)>,
+    hard_activity_constraints: Vec<(i32, Arc<dyn HardActivityConstraint + Send + Sync>)>,
     soft_route_constraints: Vec<Arc<dyn SoftRouteConstraint + Send + Sync>>,
     soft_activity_constraints: Vec<Arc<dyn SoftActivityConstraint + Send + Sync>>,
+    insertion_cache: InsertionCache,
 }
 
 impl Default for ConstraintPipeline {
     fn default() -> Self {
         ConstraintPipeline {
             modules: vec![],
+            priorities: vec![],
             state_keys: Default::default(),
             hard_route_constraints: vec![],
             hard_activity_constraints: vec![],
             soft_route_constraints: vec![],
             soft_activity_constraints: vec![],
+            insertion_cache: InsertionCache::default(),
         }
     }
 }
 
 impl ConstraintPipeline {
+    /// Creates a new pipeline which ignores violations of hard constraints with given codes.
+    /// Modules of the original pipeline are used to manage states.
+    pub fn new_relaxed(original: Arc<ConstraintPipeline>, codes: &[i32]) -> Self {
+        Self::new_penalized(original, codes.iter().map(|code| (*code, 0.)).collect::<Vec<_>>().as_slice())
+    }
+
+    /// Creates a new pipeline which penalizes violations of hard constraints with given codes
+    /// instead of rejecting them: each violation adds its penalty to insertion cost.
+    /// Modules of the original pipeline are used to manage states.
+    pub fn new_penalized(original: Arc<ConstraintPipeline>, penalties: &[(i32, Cost)]) -> Self {
+        let codes = penalties.iter().map(|(code, _)| *code).collect::<HashSet<_>>();
+        let weights = if penalties.iter().all(|(_, penalty)| *penalty == 0.) {
+            None
+        } else {
+            Some(Arc::new(ConstraintWeights::new(penalties)))
+        };
+
+        Self::new_relaxed_with_weights(original, codes, weights)
+    }
+
+    /// Creates a new pipeline which penalizes violations of hard constraints with codes from given
+    /// weights instead of rejecting them. Weights are read on each evaluation, so they can be changed
+    /// at runtime. Modules of the original pipeline are used to manage states.
+    pub fn new_weighted(original: Arc<ConstraintPipeline>, weights: Arc<ConstraintWeights>) -> Self {
+        let codes = weights.all().into_iter().map(|(code, _)| code).collect::<HashSet<_>>();
+
+        Self::new_relaxed_with_weights(original, codes, Some(weights))
+    }
+
+    fn new_relaxed_with_weights(
+        original: Arc<ConstraintPipeline>,
+        codes: HashSet<i32>,
+        weights: Option<Arc<ConstraintWeights>>,
+    ) -> Self {
+        let relaxed = original
+            .hard_route_constraints
+            .iter()
+            .map(|(_, c)| {
+                ConstraintVariant::HardRoute(Arc::new(RelaxedHardRouteConstraint {
+                    inner: c.clone(),
+                    codes: codes.clone(),
+                }))
+            })
+            .chain(original.hard_activity_constraints.iter().map(|(_, c)| {
+                ConstraintVariant::HardActivity(Arc::new(RelaxedHardActivityConstraint {
+                    inner: c.clone(),
+                    codes: codes.clone(),
+                }))
+            }));
+
+        let penalized = if let Some(weights) = weights {
+            original
+                .hard_route_constraints
+                .iter()
+                .map(|(_, c)| {
+                    ConstraintVariant::SoftRoute(Arc::new(PenalizedSoftRouteConstraint {
+                        inner: c.clone(),
+                        weights: weights.clone(),
+                    }))
+                })
+                .chain(original.hard_activity_constraints.iter().map(|(_, c)| {
+                    ConstraintVariant::SoftActivity(Arc::new(PenalizedSoftActivityConstraint {
+                        inner: c.clone(),
+                        weights: weights.clone(),
+                    }))
+                }))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let constraints = relaxed
+            .chain(penalized)
+            .chain(original.soft_route_constraints.iter().map(|c| ConstraintVariant::SoftRoute(c.clone())))
+            .chain(original.soft_activity_constraints.iter().map(|c| ConstraintVariant::SoftActivity(c.clone())))
+            .collect();
+
+        let mut pipeline = ConstraintPipeline::default();
+        pipeline.add_module(Box::new(RelaxedConstraintModule {
+            state_keys: original.state_keys.iter().cloned().collect(),
+            original,
+            constraints,
+        }));
+
+        pipeline
+    }
+
     /// Accepts job insertion.
     pub fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
         self.modules.iter().for_each(|c| c.accept_insertion(solution_ctx, route_index, job));
@@ -145,6 +262,7 @@ impl ConstraintPipeline {
         loop {
             let required = solution_ctx.required.len();
             let ignored = solution_ctx.ignored.len();
+            let unassigned = solution_ctx.unassigned.len();
 
             self.modules.iter().for_each(|c| c.accept_solution_state(solution_ctx));
 
@@ -152,7 +270,10 @@ impl ConstraintPipeline {
             // job removal from a tour. As modules should not know about each other and it is
             // hard to maintain implicit dependencies between them, we recalculate everything.
             // However we do not expect recalculation to happen often.
-            if solution_ctx.required.len() == required && solution_ctx.ignored.len() == ignored {
+            if solution_ctx.required.len() == required
+                && solution_ctx.ignored.len() == ignored
+                && solution_ctx.unassigned.len() == unassigned
+            {
                 break;
             }
         }
@@ -164,6 +285,18 @@ impl ConstraintPipeline {
 
     /// Adds constraint module.
     pub fn add_module(&mut self, module: Box<dyn ConstraintModule + Send + Sync>) -> &mut Self {
+        self.add_module_with_priority(module, 0)
+    }
+
+    /// Adds constraint module with given evaluation priority: hard constraints of modules with lower
+    /// priority are evaluated first, modules with the same priority are evaluated in order of their
+    /// registration. As evaluation stops on the first violation, expensive constraints can be added
+    /// with higher priority to be evaluated after cheap ones.
+    pub fn add_module_with_priority(
+        &mut self,
+        module: Box<dyn ConstraintModule + Send + Sync>,
+        priority: i32,
+    ) -> &mut Self {
         module.state_keys().for_each(|key| {
             if let Some(duplicate) = self.state_keys.get(key) {
                 panic!("Attempt to register constraint with key duplication: {}", duplicate)
@@ -172,17 +305,47 @@ impl ConstraintPipeline {
         });
 
         module.get_constraints().for_each(|c| match c {
-            ConstraintVariant::HardRoute(c) => self.hard_route_constraints.push(c.clone()),
-            ConstraintVariant::HardActivity(c) => self.hard_activity_constraints.push(c.clone()),
+            ConstraintVariant::HardRoute(c) => insert_with_priority(&mut self.hard_route_constraints, priority, c),
+            ConstraintVariant::HardActivity(c) => {
+                insert_with_priority(&mut self.hard_activity_constraints, priority, c)
+            }
             ConstraintVariant::SoftRoute(c) => self.soft_route_constraints.push(c.clone()),
             ConstraintVariant::SoftActivity(c) => self.soft_activity_constraints.push(c.clone()),
         });
 
         self.modules.push(module);
+        self.priorities.push(priority);
 
         self
     }
 
+    /// Returns information about registered modules in order of their registration.
+    pub fn get_module_infos(&self) -> Vec<ConstraintModuleInfo> {
+        self.modules
+            .iter()
+            .zip(self.priorities.iter())
+            .map(|(module, priority)| {
+                let count =
+                    |filter: fn(&ConstraintVariant) -> bool| module.get_constraints().filter(|c| filter(c)).count();
+
+                ConstraintModuleInfo {
+                    priority: *priority,
+                    state_keys: module.state_keys().cloned().collect(),
+                    violation_codes: module.violation_codes().cloned().collect(),
+                    hard_route: count(|c| matches!(c, ConstraintVariant::HardRoute(_))),
+                    hard_activity: count(|c| matches!(c, ConstraintVariant::HardActivity(_))),
+                    soft_route: count(|c| matches!(c, ConstraintVariant::SoftRoute(_))),
+                    soft_activity: count(|c| matches!(c, ConstraintVariant::SoftActivity(_))),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns insertion cache used to avoid repeating job insertion evaluations in non changed routes.
+    pub fn insertion_cache(&self) -> &InsertionCache {
+        &self.insertion_cache
+    }
+
     /// Checks whether all hard route constraints are fulfilled.
     /// Returns result of first failed constraint or empty value.
     pub fn evaluate_hard_route(
@@ -191,7 +354,7 @@ impl ConstraintPipeline {
         route_ctx: &RouteContext,
         job: &Job,
     ) -> Option<RouteConstraintViolation> {
-        self.hard_route_constraints.iter().find_map(|c| c.evaluate_job(solution_ctx, route_ctx, job))
+        self.hard_route_constraints.iter().find_map(|(_, c)| c.evaluate_job(solution_ctx, route_ctx, job))
     }
 
     /// Checks whether all activity route constraints are fulfilled.
@@ -201,7 +364,30 @@ impl ConstraintPipeline {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ActivityConstraintViolation> {
-        self.hard_activity_constraints.iter().find_map(|c| c.evaluate_activity(route_ctx, activity_ctx))
+        self.hard_activity_constraints.iter().find_map(|(_, c)| c.evaluate_activity(route_ctx, activity_ctx))
+    }
+
+    /// Returns codes of all failed hard route constraints in order of their evaluation.
+    pub fn get_hard_route_violations(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Vec<i32> {
+        self.hard_route_constraints
+            .iter()
+            .filter_map(|(_, c)| c.evaluate_job(solution_ctx, route_ctx, job))
+            .map(|violation| violation.code)
+            .collect()
+    }
+
+    /// Returns codes of all failed hard activity constraints in order of their evaluation.
+    pub fn get_hard_activity_violations(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Vec<i32> {
+        self.hard_activity_constraints
+            .iter()
+            .filter_map(|(_, c)| c.evaluate_activity(route_ctx, activity_ctx))
+            .map(|violation| violation.code)
+            .collect()
     }
 
     /// Checks soft route constraints and aggregates associated actual and penalty costs.
@@ -215,6 +401,100 @@ impl ConstraintPipeline {
     }
 }
 
+/// Inserts constraint after all constraints with the same or lower priority.
+fn insert_with_priority<T: Clone>(constraints: &mut Vec<(i32, T)>, priority: i32, constraint: &T) {
+    let index = constraints.iter().take_while(|(other, _)| *other <= priority).count();
+    constraints.insert(index, (priority, constraint.clone()));
+}
+
+/// A module which delegates state management to the original pipeline and keeps its relaxed constraints.
+struct RelaxedConstraintModule {
+    original: Arc<ConstraintPipeline>,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for RelaxedConstraintModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.original.modules.iter().for_each(|c| c.accept_insertion(solution_ctx, route_index, job));
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.original.modules.iter().for_each(|c| c.accept_route_state(ctx));
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.original.modules.iter().for_each(|c| c.accept_solution_state(ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct RelaxedHardRouteConstraint {
+    inner: Arc<dyn HardRouteConstraint + Send + Sync>,
+    codes: HashSet<i32>,
+}
+
+impl HardRouteConstraint for RelaxedHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        self.inner.evaluate_job(solution_ctx, ctx, job).filter(|violation| !self.codes.contains(&violation.code))
+    }
+}
+
+struct RelaxedHardActivityConstraint {
+    inner: Arc<dyn HardActivityConstraint + Send + Sync>,
+    codes: HashSet<i32>,
+}
+
+impl HardActivityConstraint for RelaxedHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        self.inner.evaluate_activity(route_ctx, activity_ctx).filter(|violation| !self.codes.contains(&violation.code))
+    }
+}
+
+struct PenalizedSoftRouteConstraint {
+    inner: Arc<dyn HardRouteConstraint + Send + Sync>,
+    weights: Arc<ConstraintWeights>,
+}
+
+impl SoftRouteConstraint for PenalizedSoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
+        self.inner
+            .evaluate_job(solution_ctx, route_ctx, job)
+            .and_then(|violation| self.weights.get(violation.code))
+            .unwrap_or(0.)
+    }
+}
+
+struct PenalizedSoftActivityConstraint {
+    inner: Arc<dyn HardActivityConstraint + Send + Sync>,
+    weights: Arc<ConstraintWeights>,
+}
+
+impl SoftActivityConstraint for PenalizedSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        self.inner
+            .evaluate_activity(route_ctx, activity_ctx)
+            .and_then(|violation| self.weights.get(violation.code))
+            .unwrap_or(0.)
+    }
+}
+
 impl PartialEq<RouteConstraintViolation> for RouteConstraintViolation {
     fn eq(&self, other: &RouteConstraintViolation) -> bool {
         self.code == other.code
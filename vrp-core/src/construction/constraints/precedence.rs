@@ -0,0 +1,151 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/precedence_test.rs"]
+mod precedence_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::problem::{Job, Multi};
+use hashbrown::HashMap;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies loading policy of a job with pickup and delivery.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadingPolicy {
+    /// Last in, first out: jobs loaded while the job is on board have to be unloaded before it.
+    Lifo,
+    /// First in, first out: jobs loaded while the job is on board have to be unloaded after it.
+    Fifo,
+}
+
+/// A function which returns loading policy for given multi job.
+pub type LoadingPolicyResolver = Arc<dyn Fn(&Multi) -> Option<LoadingPolicy> + Sync + Send>;
+
+/// A precedence module enforces loading policies of multi jobs (e.g. pickup and delivery) served
+/// by the same route. Please note that order of activities inside the multi job itself is controlled
+/// by its permutations, so this module checks only the precedence between different jobs.
+pub struct PrecedenceConstraintModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+}
+
+impl PrecedenceConstraintModule {
+    /// Creates a new instance of `PrecedenceConstraintModule`.
+    pub fn new(policy_resolver: LoadingPolicyResolver, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(PrecedenceHardActivityConstraint {
+                policy_resolver,
+                code,
+            }))],
+            keys: vec![],
+            codes: vec![code],
+        }
+    }
+}
+
+impl ConstraintModule for PrecedenceConstraintModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<'_, i32> {
+        self.codes.iter()
+    }
+}
+
+struct PrecedenceHardActivityConstraint {
+    policy_resolver: LoadingPolicyResolver,
+    code: i32,
+}
+
+impl HardActivityConstraint for PrecedenceHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let target = activity_ctx.target.job.as_ref().and_then(|single| Multi::roots(single))?;
+
+        // NOTE positions are doubled to fit the target activity between its neighbours
+        let mut intervals = route_ctx.route.tour.all_activities().enumerate().fold(
+            HashMap::<Job, Interval>::default(),
+            |mut acc, (idx, activity)| {
+                if let Some(multi) = activity.job.as_ref().and_then(|single| Multi::roots(single)) {
+                    acc.entry(Job::Multi(multi)).or_insert_with(Interval::default).add(idx * 2);
+                }
+                acc
+            },
+        );
+
+        let target_job = Job::Multi(target.clone());
+        let target_interval = intervals.remove(&target_job).unwrap_or_default().with(activity_ctx.index * 2 + 1);
+
+        // NOTE loading interval of the job is known only when its last activity is inserted
+        if target_interval.count < target.jobs.len() {
+            return None;
+        }
+
+        let target_policy = self.policy_resolver.deref()(target.as_ref());
+
+        let is_violated = intervals.iter().any(|(job, interval)| {
+            let multi = job.as_multi().unwrap();
+            if interval.count < multi.jobs.len() {
+                return false;
+            }
+
+            is_policy_violated(target_policy.as_ref(), &target_interval, interval)
+                || is_policy_violated(self.policy_resolver.deref()(multi.as_ref()).as_ref(), interval, &target_interval)
+        });
+
+        if is_violated {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Keeps positions of the first and the last activities of the job in the tour.
+#[derive(Clone, Default)]
+struct Interval {
+    start: usize,
+    end: usize,
+    count: usize,
+}
+
+impl Interval {
+    fn add(&mut self, position: usize) {
+        self.start = if self.count == 0 { position } else { self.start.min(position) };
+        self.end = if self.count == 0 { position } else { self.end.max(position) };
+        self.count += 1;
+    }
+
+    fn with(mut self, position: usize) -> Self {
+        self.add(position);
+        self
+    }
+}
+
+/// Checks whether policy of the job with `outer` interval is violated by the job with `inner`
+/// interval, which is loaded while the first one is on board.
+fn is_policy_violated(policy: Option<&LoadingPolicy>, outer: &Interval, inner: &Interval) -> bool {
+    let is_loaded_on_board = outer.start < inner.start && inner.start < outer.end;
+
+    match policy {
+        Some(LoadingPolicy::Lifo) if is_loaded_on_board => inner.end > outer.end,
+        Some(LoadingPolicy::Fifo) if is_loaded_on_board => inner.end < outer.end,
+        _ => false,
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/radius_test.rs"]
+mod radius_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Distance, Location};
+use crate::models::problem::{Actor, Job, Single};
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns max radius (distance from start location) for given actor.
+pub type RadiusResolver = Arc<dyn Fn(&Actor) -> Option<Distance> + Sync + Send>;
+/// A function which returns distance between two locations for given actor.
+pub type RadiusDistanceFunc = Arc<dyn Fn(&Actor, Location, Location) -> Distance + Sync + Send>;
+
+/// A radius module provides way to restrict given actor to serve jobs only within some distance
+/// from its start location.
+pub struct RadiusModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+}
+
+impl RadiusModule {
+    /// Creates a new instance of `RadiusModule`.
+    pub fn new(radius_resolver: RadiusResolver, distance_func: RadiusDistanceFunc, code: i32) -> Self {
+        Self {
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(RadiusHardRouteConstraint {
+                    radius_resolver: radius_resolver.clone(),
+                    distance_func: distance_func.clone(),
+                    code,
+                })),
+                ConstraintVariant::HardActivity(Arc::new(RadiusHardActivityConstraint {
+                    radius_resolver,
+                    distance_func,
+                    code,
+                })),
+            ],
+            keys: vec![],
+            codes: vec![code],
+        }
+    }
+}
+
+impl ConstraintModule for RadiusModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
+}
+
+struct RadiusHardRouteConstraint {
+    radius_resolver: RadiusResolver,
+    distance_func: RadiusDistanceFunc,
+    code: i32,
+}
+
+impl HardRouteConstraint for RadiusHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let actor = ctx.route.actor.as_ref();
+
+        if let Some(radius) = self.radius_resolver.deref()(actor) {
+            // NOTE place without location, e.g. break, is served where vehicle is, so it is within radius
+            let is_within = |single: &Single| {
+                single.places.iter().any(|place| match place.location {
+                    Some(location) => is_location_within_radius(actor, location, radius, &self.distance_func),
+                    None => true,
+                })
+            };
+
+            let can_serve = match job {
+                Job::Single(single) => is_within(single),
+                Job::Multi(multi) => multi.jobs.iter().all(|single| is_within(single)),
+            };
+
+            if !can_serve {
+                return Some(RouteConstraintViolation { code: self.code });
+            }
+        }
+
+        None
+    }
+}
+
+struct RadiusHardActivityConstraint {
+    radius_resolver: RadiusResolver,
+    distance_func: RadiusDistanceFunc,
+    code: i32,
+}
+
+impl HardActivityConstraint for RadiusHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let actor = route_ctx.route.actor.as_ref();
+
+        if let Some(radius) = self.radius_resolver.deref()(actor) {
+            let location = activity_ctx.target.place.location;
+
+            if !is_location_within_radius(actor, location, radius, &self.distance_func) {
+                // NOTE do not stop job insertion evaluation if it has multiple locations
+                let stopped = activity_ctx
+                    .target
+                    .job
+                    .as_ref()
+                    .map_or(false, |job| job.places.iter().filter_map(|place| place.location).count() == 1);
+
+                return Some(ActivityConstraintViolation { code: self.code, stopped });
+            }
+        }
+
+        None
+    }
+}
+
+fn is_location_within_radius(
+    actor: &Actor,
+    location: Location,
+    radius: Distance,
+    distance_func: &RadiusDistanceFunc,
+) -> bool {
+    actor.detail.start.as_ref().map_or(true, |start| distance_func.deref()(actor, start.location, location) <= radius)
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/team_driving_test.rs"]
+mod team_driving_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Duration;
+use crate::models::problem::{Actor, Job, TransportCost};
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a team of drivers assigned to the vehicle.
+#[derive(Clone, Debug)]
+pub struct DrivingTeam {
+    /// Amount of drivers in the team.
+    pub drivers: usize,
+    /// Max driving time of each driver.
+    pub max_driving_time: Duration,
+}
+
+/// A function which returns driving team for given actor.
+pub type DrivingTeamResolver = Arc<dyn Fn(&Actor) -> Option<DrivingTeam> + Sync + Send>;
+
+/// A team driving module limits driving time of each driver in the vehicle team. Drivers take
+/// turns after each leg with actual driving: such leg is driven by one driver while the co-driver
+/// rests, so driving time is accumulated per driver and rest of one driver overlaps with driving
+/// of another one. Legs without driving, e.g. between activities at the same location, do not
+/// change the driver.
+pub struct TeamDrivingModule {
+    team_resolver: DrivingTeamResolver,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+}
+
+impl TeamDrivingModule {
+    /// Creates a new instance of `TeamDrivingModule`.
+    pub fn new(team_resolver: DrivingTeamResolver, transport: Arc<dyn TransportCost + Send + Sync>, code: i32) -> Self {
+        Self {
+            team_resolver: team_resolver.clone(),
+            transport: transport.clone(),
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TeamDrivingHardActivityConstraint {
+                team_resolver,
+                transport,
+                code,
+            }))],
+            keys: vec![DRIVING_TIME_KEY],
+            codes: vec![code],
+        }
+    }
+}
+
+impl ConstraintModule for TeamDrivingModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let drivers = match self.team_resolver.deref()(ctx.route.actor.as_ref()) {
+            Some(team) if team.drivers > 0 => team.drivers,
+            _ => return,
+        };
+
+        let profile = ctx.route.actor.vehicle.profile;
+        let legs = ctx
+            .route
+            .tour
+            .all_activities()
+            .zip(ctx.route.tour.all_activities().skip(1))
+            .map(|(from, to)| {
+                self.transport.duration(profile, from.place.location, to.place.location, from.schedule.departure)
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE past keeps driving time of each driver before the activity together with amount of
+        // driving legs (turns) before it, future keeps driving time after the activity where driver
+        // index is relative to the first driving leg after the activity
+        let past = legs.iter().fold(vec![(vec![0.; drivers], 0)], |mut acc, duration| {
+            let (mut times, turns) = acc.last().cloned().unwrap();
+            let turns = if *duration > 0. {
+                times[turns % drivers] += *duration;
+                turns + 1
+            } else {
+                turns
+            };
+            acc.push((times, turns));
+            acc
+        });
+        let future = legs.iter().rev().fold(vec![vec![0.; drivers]], |mut acc, duration| {
+            let future = acc.last().unwrap();
+            let shifted = if *duration > 0. {
+                let mut shifted = vec![0.; drivers];
+                shifted[0] = *duration + future[drivers - 1];
+                (1..drivers).for_each(|idx| shifted[idx] = future[idx - 1]);
+                shifted
+            } else {
+                future.clone()
+            };
+            acc.push(shifted);
+            acc
+        });
+
+        let (route, state) = ctx.as_mut();
+        let total = past.last().map(|(times, _)| times.clone()).unwrap_or_default();
+        route.tour.all_activities().zip(past.into_iter().zip(future.into_iter().rev())).for_each(
+            |(activity, ((past, turns), future))| {
+                state.put_activity_state(DRIVING_TIME_KEY, activity, DrivingTimes { past, turns, future });
+            },
+        );
+        state.put_route_state(DRIVING_TIME_KEY, total);
+    }
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
+}
+
+struct TeamDrivingHardActivityConstraint {
+    team_resolver: DrivingTeamResolver,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: i32,
+}
+
+impl HardActivityConstraint for TeamDrivingHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let team = self.team_resolver.deref()(route_ctx.route.actor.as_ref()).filter(|team| team.drivers > 0)?;
+        let drivers = team.drivers;
+
+        let state = &route_ctx.state;
+        let default_times = DrivingTimes { past: vec![0.; drivers], turns: 0, future: vec![0.; drivers] };
+        let prev_times =
+            state.get_activity_state::<DrivingTimes>(DRIVING_TIME_KEY, activity_ctx.prev).unwrap_or(&default_times);
+
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let prev_dur =
+            self.transport.duration(profile, prev.place.location, target.place.location, prev.schedule.departure);
+        let departure = (prev.schedule.departure + prev_dur).max(target.place.time.start) + target.place.duration;
+
+        let mut times = prev_times.past.clone();
+        let mut turns = prev_times.turns;
+        let mut drive = |duration: Duration| {
+            if duration > 0. {
+                times[turns % drivers] += duration;
+                turns += 1;
+            }
+        };
+
+        drive(prev_dur);
+
+        if let Some(next) = activity_ctx.next {
+            drive(self.transport.duration(profile, target.place.location, next.place.location, departure));
+
+            let next_times = state.get_activity_state::<DrivingTimes>(DRIVING_TIME_KEY, next).unwrap_or(&default_times);
+            next_times.future.iter().enumerate().for_each(|(relative_idx, duration)| {
+                times[(turns + relative_idx) % drivers] += *duration;
+            });
+        }
+
+        if times.iter().any(|time| *time > team.max_driving_time) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Keeps driving time of each driver before and after the activity.
+struct DrivingTimes {
+    past: Vec<Duration>,
+    turns: usize,
+    future: Vec<Duration>,
+}
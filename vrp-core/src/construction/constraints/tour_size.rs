@@ -16,6 +16,7 @@ pub type TourSizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 pub struct TourSizeModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
+    codes: Vec<i32>,
 }
 
 impl TourSizeModule {
@@ -24,6 +25,7 @@ impl TourSizeModule {
         Self {
             constraints: vec![ConstraintVariant::HardRoute(Arc::new(TourSizeHardRouteConstraint { limit_func, code }))],
             state_keys: vec![],
+            codes: vec![code],
         }
     }
 }
@@ -42,6 +44,10 @@ impl ConstraintModule for TourSizeModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct TourSizeHardRouteConstraint {
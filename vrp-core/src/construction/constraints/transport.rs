@@ -4,11 +4,10 @@ mod transport_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::models::common::{Cost, Distance, Duration, Profile, Timestamp};
+use crate::models::common::{Cost, Distance, Duration, Profile, ShiftOvertimeDimension, Timestamp};
 use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost};
 use crate::models::solution::Activity;
-use crate::models::OP_START_MSG;
-use std::ops::Deref;
+use std::iter::once;
 use std::slice::Iter;
 use std::sync::Arc;
 
@@ -25,6 +24,8 @@ pub struct TransportConstraintModule {
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
     limit_func: TravelLimitFunc,
+    codes: Vec<i32>,
+    is_rescheduling: bool,
 }
 
 impl ConstraintModule for TransportConstraintModule {
@@ -38,7 +39,7 @@ impl ConstraintModule for TransportConstraintModule {
         self.update_route_states(ctx);
         // NOTE Rescheduling during the insertion process makes sense only if the traveling limit
         // is set (for duration limit, not for distance).
-        if has_travel_limits(&self.limit_func, ctx) {
+        if self.is_rescheduling && has_travel_limits(&self.limit_func, ctx) {
             self.reschedule_departure(ctx)
         }
         self.update_statistics(ctx);
@@ -51,7 +52,9 @@ impl ConstraintModule for TransportConstraintModule {
                 self.update_route_states(route_ctx);
             }
 
-            self.reschedule_departure(route_ctx);
+            if self.is_rescheduling {
+                self.reschedule_departure(route_ctx);
+            }
 
             if route_ctx.is_stale() {
                 self.update_statistics(route_ctx);
@@ -66,6 +69,10 @@ impl ConstraintModule for TransportConstraintModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 impl TransportConstraintModule {
@@ -80,6 +87,7 @@ impl TransportConstraintModule {
     ) -> Self {
         Self {
             state_keys: vec![LATEST_ARRIVAL_KEY, WAITING_KEY],
+            codes: vec![time_window_code, distance_code, duration_code],
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(TimeHardRouteConstraint { code: time_window_code })),
                 ConstraintVariant::SoftRoute(Arc::new(RouteCostSoftRouteConstraint {})),
@@ -98,41 +106,54 @@ impl TransportConstraintModule {
                     transport: transport.clone(),
                     activity: activity.clone(),
                 })),
+                ConstraintVariant::SoftActivity(Arc::new(OvertimeSoftActivityConstraint {
+                    transport: transport.clone(),
+                })),
             ],
             activity,
             transport,
             limit_func,
+            is_rescheduling: true,
         }
     }
 
-    fn update_route_schedules(&self, ctx: &mut RouteContext) {
-        let (init, actor) = {
-            let start = ctx.route.tour.start().unwrap();
-            ((start.place.location, start.schedule.departure), ctx.route.actor.clone())
-        };
+    /// Sets whether departure time of the route should be shifted to start as late as possible
+    /// without violating any activity time window. It is enabled by default: disable it when
+    /// vehicles are expected to depart at their earliest start time.
+    pub fn with_departure_rescheduling(mut self, is_rescheduling: bool) -> Self {
+        self.is_rescheduling = is_rescheduling;
+        self
+    }
 
-        ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(loc, dep), a| {
-            a.schedule.arrival = dep + self.transport.duration(actor.vehicle.profile, loc, a.place.location, dep);
-            a.schedule.departure = a.schedule.arrival.max(a.place.time.start)
-                + self.activity.duration(actor.as_ref(), a.deref(), a.schedule.arrival);
+    fn update_route_schedules(&self, ctx: &mut RouteContext) {
+        let actor = ctx.route.actor.clone();
 
-            (a.place.location, a.schedule.departure)
+        (1..ctx.route.tour.total()).for_each(|idx| {
+            let tour = &ctx.route.tour;
+            let (prev, a) = (tour.get(idx - 1).unwrap(), tour.get(idx).unwrap());
+
+            let dep = prev.schedule.departure;
+            let arrival =
+                dep + self.transport.duration(actor.vehicle.profile, prev.place.location, a.place.location, dep);
+            let departure = arrival.max(a.place.time.start)
+                + self.activity.duration_after(
+                    actor.as_ref(),
+                    &mut tour.activities_slice(0, idx - 1).iter().rev(),
+                    a,
+                    arrival,
+                );
+
+            let schedule = &mut ctx.route_mut().tour.get_mut(idx).unwrap().schedule;
+            schedule.arrival = arrival;
+            schedule.departure = departure;
         });
     }
 
     fn update_route_states(&self, ctx: &mut RouteContext) {
         // update latest arrival and waiting states of non-terminate (jobs) activities
         let actor = ctx.route.actor.clone();
-        let init = (
-            actor.detail.time.end,
-            actor
-                .detail
-                .end
-                .as_ref()
-                .unwrap_or_else(|| actor.detail.start.as_ref().unwrap_or_else(|| panic!(OP_START_MSG)))
-                .location,
-            0_f64,
-        );
+        // NOTE open tour has no end location, so there is no travel after the last activity
+        let init = (actor.detail.time.end, actor.detail.end.as_ref().map(|end| end.location), 0_f64);
 
         let (route, state) = ctx.as_mut();
 
@@ -142,9 +163,12 @@ impl TransportConstraintModule {
             }
 
             let (end_time, prev_loc, waiting) = acc;
-            let potential_latest = end_time
-                - self.transport.duration(actor.vehicle.profile, act.place.location, prev_loc, end_time)
-                - self.activity.duration(actor.as_ref(), act.deref(), end_time);
+            let travel_duration = prev_loc.map_or(0., |prev_loc| {
+                self.transport.duration(actor.vehicle.profile, act.place.location, prev_loc, end_time)
+            });
+            // NOTE use scheduled service time as it might depend on previous activity
+            let potential_latest =
+                end_time - travel_duration - (act.schedule.departure - act.schedule.arrival.max(act.place.time.start));
 
             let latest_arrival_time = act.place.time.end.min(potential_latest);
             let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
@@ -152,7 +176,7 @@ impl TransportConstraintModule {
             state.put_activity_state(LATEST_ARRIVAL_KEY, &act, latest_arrival_time);
             state.put_activity_state(WAITING_KEY, &act, future_waiting);
 
-            (latest_arrival_time, act.place.location, future_waiting)
+            (latest_arrival_time, Some(act.place.location), future_waiting)
         });
     }
 
@@ -194,16 +218,20 @@ impl TransportConstraintModule {
 
         let total_dur = end.schedule.departure - start.schedule.departure;
 
-        let init = (start.place.location, start.schedule.departure, Distance::default());
-        let (_, _, total_dist) = ctx.route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
-            let total_dist =
-                total_dist + self.transport.distance(ctx.route.actor.vehicle.profile, loc, a.place.location, dep);
+        let actor = ctx.route.actor.as_ref();
+        let init = (start.place.location, start.schedule.departure, Distance::default(), Cost::default());
+        let (_, _, total_dist, total_toll) =
+            ctx.route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist, total_toll), a| {
+                let total_dist =
+                    total_dist + self.transport.distance(actor.vehicle.profile, loc, a.place.location, dep);
+                let total_toll = total_toll + self.transport.toll(actor, loc, a.place.location, dep);
 
-            (a.place.location, a.schedule.departure, total_dist)
-        });
+                (a.place.location, a.schedule.departure, total_dist, total_toll)
+            });
 
         ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, total_dist);
         ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_dur);
+        ctx.state_mut().put_route_state(TOTAL_TOLL_KEY, total_toll);
     }
 }
 
@@ -290,9 +318,10 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
 
         let arr_time_at_target_act =
             departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+        let previous = || route_ctx.route.tour.activities_slice(0, activity_ctx.index).iter().rev();
+        let target_duration = self.activity.duration_after(actor, &mut previous(), target, arr_time_at_target_act);
 
-        let end_time_at_new_act = arr_time_at_target_act.max(target.place.time.start)
-            + self.activity.duration(actor, target.deref(), arr_time_at_target_act);
+        let end_time_at_new_act = arr_time_at_target_act.max(target.place.time.start) + target_duration;
 
         let latest_arr_time_at_new_act = target.place.time.end.min(
             latest_arr_time_at_next_act
@@ -302,7 +331,7 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                     next_act_location,
                     latest_arr_time_at_next_act,
                 )
-                + self.activity.duration(actor, target.deref(), arr_time_at_target_act),
+                + target_duration,
         );
 
         if arr_time_at_target_act > latest_arr_time_at_new_act {
@@ -316,7 +345,16 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
         let arr_time_at_next_act = end_time_at_new_act
             + self.transport.duration(profile, target.place.location, next_act_location, end_time_at_new_act);
 
-        if arr_time_at_next_act > latest_arr_time_at_next_act {
+        // NOTE service time of the next activity can be increased as its previous activities are changed
+        let next_extra_duration = next.map_or(0., |next| {
+            let old_duration = next.schedule.departure - next.schedule.arrival.max(next.place.time.start);
+            let new_duration =
+                self.activity.duration_after(actor, &mut once(target).chain(previous()), next, arr_time_at_next_act);
+
+            (new_duration - old_duration).max(0.)
+        });
+
+        if arr_time_at_next_act > latest_arr_time_at_next_act - next_extra_duration {
             stop(self.code)
         } else {
             success()
@@ -340,7 +378,7 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         let limit = (self.limit_func)(&route_ctx.route.actor);
         if limit.0.is_some() || limit.1.is_some() {
-            let (change_distance, change_duration) = self.calculate_travel(route_ctx, activity_ctx);
+            let (change_distance, change_duration) = calculate_travel(self.transport.as_ref(), route_ctx, activity_ctx);
 
             let curr_dis = route_ctx.state.get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
             let curr_dur = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
@@ -359,49 +397,70 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     }
 }
 
-impl TravelHardActivityConstraint {
-    fn calculate_travel(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let actor = &route_ctx.route.actor;
-        let profile = actor.vehicle.profile;
+/// A soft activity constraint which penalizes shift time increase above overtime threshold.
+struct OvertimeSoftActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
 
-        let prev = activity_ctx.prev;
-        let tar = activity_ctx.target;
-        let next = activity_ctx.next;
+impl SoftActivityConstraint for OvertimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        if let Some(overtime) = route_ctx.route.actor.vehicle.dimens.get_shift_overtime() {
+            let (_, change_duration) = calculate_travel(self.transport.as_ref(), route_ctx, activity_ctx);
+            let curr_dur = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        let prev_dep = prev.schedule.departure;
+            let get_overtime = |duration: Duration| (duration - overtime.shift_time).max(0.);
 
-        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(profile, prev, tar, prev_dep);
-        if next.is_none() {
-            return (prev_to_tar_dis, prev_to_tar_dur);
+            (get_overtime(curr_dur + change_duration) - get_overtime(curr_dur)) * overtime.penalty
+        } else {
+            0.
         }
+    }
+}
 
-        let next = next.unwrap();
-        let tar_dep = prev_dep + prev_to_tar_dur;
+/// Calculates distance and duration change caused by the target activity insertion.
+fn calculate_travel(
+    transport: &(dyn TransportCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+) -> (Distance, Duration) {
+    let actor = &route_ctx.route.actor;
+    let profile = actor.vehicle.profile;
 
-        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(profile, prev, next, prev_dep);
-        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(profile, tar, next, tar_dep);
+    let prev = activity_ctx.prev;
+    let tar = activity_ctx.target;
+    let next = activity_ctx.next;
 
-        (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
-    }
+    let prev_dep = prev.schedule.departure;
 
-    fn calculate_leg_travel_info(
-        &self,
-        profile: Profile,
-        first: &Activity,
-        second: &Activity,
-        departure: Timestamp,
-    ) -> (Distance, Duration) {
-        let first_to_second_dis =
-            self.transport.distance(profile, first.place.location, second.place.location, departure);
-        let first_to_second_dur =
-            self.transport.duration(profile, first.place.location, second.place.location, departure);
-
-        let second_arr = departure + first_to_second_dur;
-        let second_wait = (second.place.time.start - second_arr).max(0.);
-        let second_dep = second_arr + second_wait + second.place.duration;
-
-        (first_to_second_dis, second_dep - departure)
+    let (prev_to_tar_dis, prev_to_tar_dur) = calculate_leg_travel_info(transport, profile, prev, tar, prev_dep);
+    if next.is_none() {
+        return (prev_to_tar_dis, prev_to_tar_dur);
     }
+
+    let next = next.unwrap();
+    let tar_dep = prev_dep + prev_to_tar_dur;
+
+    let (prev_to_next_dis, prev_to_next_dur) = calculate_leg_travel_info(transport, profile, prev, next, prev_dep);
+    let (tar_to_next_dis, tar_to_next_dur) = calculate_leg_travel_info(transport, profile, tar, next, tar_dep);
+
+    (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+}
+
+fn calculate_leg_travel_info(
+    transport: &(dyn TransportCost + Send + Sync),
+    profile: Profile,
+    first: &Activity,
+    second: &Activity,
+    departure: Timestamp,
+) -> (Distance, Duration) {
+    let first_to_second_dis = transport.distance(profile, first.place.location, second.place.location, departure);
+    let first_to_second_dur = transport.duration(profile, first.place.location, second.place.location, departure);
+
+    let second_arr = departure + first_to_second_dur;
+    let second_wait = (second.place.time.start - second_arr).max(0.);
+    let second_dep = second_arr + second_wait + second.place.duration;
+
+    (first_to_second_dis, second_dep - departure)
 }
 
 fn has_travel_limits(limit_func: &TravelLimitFunc, route_ctx: &RouteContext) -> bool {
@@ -432,12 +491,13 @@ impl CostSoftActivityConstraint {
         &self,
         actor: &Actor,
         start: &Activity,
+        previous: &mut dyn Iterator<Item = &Activity>,
         end: &Activity,
         time: Timestamp,
     ) -> (Cost, Cost, Timestamp) {
         let arrival =
             time + self.transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time);
-        let departure = arrival.max(end.place.time.start) + self.activity.duration(actor, end, arrival);
+        let departure = arrival.max(end.place.time.start) + self.activity.duration_after(actor, previous, end, arrival);
 
         let transport_cost = self.transport.cost(actor, start.place.location, end.place.location, time);
         let activity_cost = self.activity.cost(actor, end, arrival);
@@ -453,12 +513,13 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let prev = activity_ctx.prev;
         let target = activity_ctx.target;
         let next = activity_ctx.next;
+        let previous = || route_ctx.route.tour.activities_slice(0, activity_ctx.index).iter().rev();
 
         let (tp_cost_left, act_cost_left, dep_time_left) =
-            self.analyze_route_leg(actor, prev, target, prev.schedule.departure);
+            self.analyze_route_leg(actor, prev, &mut previous(), target, prev.schedule.departure);
 
         let (tp_cost_right, act_cost_right, dep_time_right) = if let Some(next) = next {
-            self.analyze_route_leg(actor, target, next, dep_time_left)
+            self.analyze_route_leg(actor, target, &mut once(target).chain(previous()), next, dep_time_left)
         } else {
             (0., 0., 0.)
         };
@@ -474,7 +535,7 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let waiting_time = *route_ctx.state.get_activity_state(WAITING_KEY, next).unwrap_or(&0_f64);
 
         let (tp_cost_old, act_cost_old, dep_time_old) =
-            self.analyze_route_leg(actor, prev, next, prev.schedule.departure);
+            self.analyze_route_leg(actor, prev, &mut previous(), next, prev.schedule.departure);
 
         let waiting_cost =
             waiting_time.min(0.0_f64.max(dep_time_right - dep_time_old)) * actor.vehicle.costs.per_waiting_time;
@@ -0,0 +1,46 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/weights_test.rs"]
+mod weights_test;
+
+use crate::models::common::Cost;
+use hashbrown::HashMap;
+use std::sync::RwLock;
+
+/// Keeps penalty weights of constraints by their codes. Weights are shared between constraint
+/// pipeline and objectives and they can be changed at runtime, e.g. between generations, without
+/// rebuilding the problem. A set of constraint codes is fixed on creation.
+pub struct ConstraintWeights {
+    weights: RwLock<HashMap<i32, Cost>>,
+}
+
+impl ConstraintWeights {
+    /// Creates a new instance of `ConstraintWeights` with initial weights.
+    pub fn new(weights: &[(i32, Cost)]) -> Self {
+        Self { weights: RwLock::new(weights.iter().cloned().collect()) }
+    }
+
+    /// Returns a weight of the constraint with given code.
+    pub fn get(&self, code: i32) -> Option<Cost> {
+        self.weights.read().unwrap().get(&code).cloned()
+    }
+
+    /// Changes a weight of the constraint with given code.
+    pub fn set(&self, code: i32, weight: Cost) -> Result<(), String> {
+        match self.weights.write().unwrap().get_mut(&code) {
+            Some(value) => {
+                *value = weight;
+                Ok(())
+            }
+            None => Err(format!("unknown constraint code: {}", code)),
+        }
+    }
+
+    /// Returns all weights sorted by constraint code.
+    pub fn all(&self) -> Vec<(i32, Cost)> {
+        let mut weights =
+            self.weights.read().unwrap().iter().map(|(code, weight)| (*code, *weight)).collect::<Vec<_>>();
+        weights.sort_by_key(|(code, _)| *code);
+
+        weights
+    }
+}
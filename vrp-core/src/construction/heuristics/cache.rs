@@ -0,0 +1,217 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/cache_test.rs"]
+mod cache_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::Activity;
+use hashbrown::HashMap;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+/// A default amount of cached insertion results.
+pub const DEFAULT_INSERTION_CACHE_CAPACITY: usize = 10000;
+
+/// A default amount of cache shards.
+const DEFAULT_SHARDS: usize = 16;
+
+/// Keeps recently evaluated job insertions on activity level, so identical evaluations are not
+/// repeated while a route is not changed. Entries are keyed by job and route version: any change of
+/// the route produces a new version, so outdated entries are never hit and they are removed either
+/// explicitly, via `invalidate`, or when capacity is exceeded.
+///
+/// The cache is shared by all parallel evaluations, so its state is split into shards by route
+/// version and each shard is guarded by its own lock. Cached evaluations are performed without best
+/// known cost, so they cannot stop early. The cache is disabled by constraint pipeline default and
+/// it is enabled by solver with `DEFAULT_INSERTION_CACHE_CAPACITY` unless configured otherwise.
+pub struct InsertionCache {
+    capacity: AtomicUsize,
+    shards: Vec<RwLock<CacheState>>,
+}
+
+/// Specifies cached result of job insertion evaluation without route level costs.
+enum CachedInsertion {
+    Success { cost: Cost, activities: Vec<(Activity, usize)> },
+    Failure { code: i32, stopped: bool },
+}
+
+type EntryKey = (Job, (usize, usize));
+
+#[derive(Default)]
+struct CacheState {
+    routes: HashMap<u64, HashMap<EntryKey, CachedInsertion>>,
+    versions: VecDeque<u64>,
+    size: usize,
+}
+
+impl InsertionCache {
+    /// Creates a new instance of `InsertionCache` which keeps up to `capacity` insertion results
+    /// split between `shards`. Zero capacity disables the cache.
+    pub fn new(capacity: usize, shards: usize) -> Self {
+        Self {
+            capacity: AtomicUsize::new(capacity),
+            shards: (0..shards.max(1)).map(|_| RwLock::new(CacheState::default())).collect(),
+        }
+    }
+
+    /// Returns true if cache is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.capacity.load(Ordering::Relaxed) > 0
+    }
+
+    /// Sets max amount of cached insertion results. Zero capacity disables the cache.
+    /// All cached results are removed.
+    pub fn set_capacity(&self, capacity: usize) {
+        self.shards.iter().for_each(|shard| *shard.write().unwrap() = CacheState::default());
+        self.capacity.store(capacity, Ordering::Relaxed);
+    }
+
+    /// Returns amount of cached insertion results.
+    pub fn size(&self) -> usize {
+        self.shards.iter().map(|shard| shard.read().unwrap().size).sum()
+    }
+
+    /// Removes all cached insertion results of given route.
+    pub fn invalidate(&self, route_ctx: &RouteContext) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let version = route_ctx.version();
+        let mut state = self.get_shard(version).write().unwrap();
+
+        if let Some(entries) = state.routes.remove(&version) {
+            state.size -= entries.len();
+        }
+
+        // NOTE keep versions of removed routes bounded
+        if state.versions.len() > 2 * state.routes.len() + 16 {
+            let CacheState { routes, versions, .. } = &mut *state;
+            versions.retain(|version| routes.contains_key(version));
+        }
+    }
+
+    /// Removes all cached insertion results.
+    pub fn clear(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        self.shards.iter().for_each(|shard| *shard.write().unwrap() = CacheState::default());
+    }
+
+    /// Evaluates job insertion in given route using `evaluate_func` only if there is no cached
+    /// result for the same job, route version and position. The evaluation function is expected to
+    /// be called without route costs and best known cost, they are applied to the result after.
+    pub(crate) fn evaluate(
+        &self,
+        job: &Job,
+        route_ctx: &RouteContext,
+        position: InsertionPosition,
+        route_costs: Cost,
+        best_known_cost: Option<Cost>,
+        evaluate_func: impl Fn() -> InsertionResult,
+    ) -> InsertionResult {
+        let version = route_ctx.version();
+        let key = (job.clone(), get_position_key(position));
+
+        let result = self
+            .get_shard(version)
+            .read()
+            .unwrap()
+            .routes
+            .get(&version)
+            .and_then(|entries| entries.get(&key))
+            .map(|cached| cached.to_result(job, route_ctx, route_costs, best_known_cost));
+
+        result.unwrap_or_else(|| {
+            let cached = CachedInsertion::from_result(evaluate_func());
+            let result = cached.to_result(job, route_ctx, route_costs, best_known_cost);
+
+            self.insert(version, key, cached);
+
+            result
+        })
+    }
+
+    fn insert(&self, version: u64, key: EntryKey, cached: CachedInsertion) {
+        let capacity = self.capacity.load(Ordering::Relaxed);
+        let shard_capacity = (capacity as f64 / self.shards.len() as f64).ceil() as usize;
+
+        let mut state = self.get_shard(version).write().unwrap();
+        let state = &mut *state;
+
+        if !state.routes.contains_key(&version) {
+            state.versions.push_back(version);
+        }
+
+        if state.routes.entry(version).or_default().insert(key, cached).is_none() {
+            state.size += 1;
+        }
+
+        while state.size > shard_capacity {
+            match state.versions.pop_front() {
+                Some(version) => {
+                    if let Some(entries) = state.routes.remove(&version) {
+                        state.size -= entries.len();
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn get_shard(&self, version: u64) -> &RwLock<CacheState> {
+        &self.shards[(version % self.shards.len() as u64) as usize]
+    }
+}
+
+impl Default for InsertionCache {
+    fn default() -> Self {
+        Self::new(0, DEFAULT_SHARDS)
+    }
+}
+
+impl CachedInsertion {
+    fn from_result(result: InsertionResult) -> Self {
+        match result {
+            InsertionResult::Success(success) => Self::Success { cost: success.cost, activities: success.activities },
+            InsertionResult::Failure(failure) => Self::Failure { code: failure.constraint, stopped: failure.stopped },
+        }
+    }
+
+    fn to_result(
+        &self,
+        job: &Job,
+        route_ctx: &RouteContext,
+        route_costs: Cost,
+        best_known_cost: Option<Cost>,
+    ) -> InsertionResult {
+        match self {
+            // NOTE evaluators do not return insertions which are not better than best known cost
+            Self::Success { cost, .. } if matches!(best_known_cost, Some(best_known_cost) if *cost >= best_known_cost) => {
+                InsertionResult::make_failure_with_code(0, false, Some(job.clone()))
+            }
+            Self::Success { cost, activities } => InsertionResult::make_success(
+                *cost + route_costs,
+                job.clone(),
+                activities.iter().map(|(activity, index)| (activity.deep_copy(), *index)).collect(),
+                route_ctx.clone(),
+            ),
+            Self::Failure { code, stopped } => {
+                InsertionResult::make_failure_with_code(*code, *stopped, Some(job.clone()))
+            }
+        }
+    }
+}
+
+fn get_position_key(position: InsertionPosition) -> (usize, usize) {
+    match position {
+        InsertionPosition::Any => (0, 0),
+        InsertionPosition::Concrete(idx) => (1, idx),
+        InsertionPosition::Last => (2, 0),
+        InsertionPosition::Horizon(time) => (3, time.to_bits() as usize),
+    }
+}
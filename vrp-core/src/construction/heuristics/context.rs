@@ -3,9 +3,11 @@
 mod context_test;
 
 use crate::algorithms::nsga2::MultiObjective;
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{
+    CAPACITY_OVERLOAD_KEY, ROUTE_PENALTY_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY, TOTAL_TOLL_KEY,
+};
 use crate::construction::heuristics::factories::*;
-use crate::models::common::Cost;
+use crate::models::common::{CapacityOverloadDimension, Cost, ShiftOvertimeDimension};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Extras, Problem, Solution};
@@ -13,6 +15,7 @@ use crate::utils::{as_mut, compare_floats, Environment};
 use hashbrown::{HashMap, HashSet};
 use std::any::Any;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 /// A context which contains information needed for heuristic and metaheuristic.
@@ -181,10 +184,19 @@ pub struct RouteContext {
     /// Insertion state.
     pub state: Arc<RouteState>,
 
-    /// A flag which is used to signalize that context was touched as mutable.
+    /// A flag which is used to signalize that context was touched as mutable and
+    /// a version of route content.
     stale: Arc<StaleState>,
 }
 
+/// Keeps a copy of route and its state taken by `RouteContext::snapshot`.
+pub struct RouteSnapshot {
+    route: Route,
+    state: RouteState,
+    is_stale: bool,
+    version: u64,
+}
+
 /// Provides the way to associate arbitrary data within route and activity.
 pub struct RouteState {
     route_states: HashMap<i32, StateValue>,
@@ -199,46 +211,69 @@ impl RouteContext {
         RouteContext {
             route: Arc::new(Route { actor, tour }),
             state: Arc::new(RouteState::default()),
-            stale: Arc::new(StaleState { is_stale: true }),
+            stale: Arc::new(StaleState::new()),
         }
     }
 
     /// Creates a new instance of `RouteContext` with arguments provided.
     pub fn new_with_state(route: Arc<Route>, state: Arc<RouteState>) -> Self {
-        RouteContext { route, state, stale: Arc::new(StaleState { is_stale: true }) }
+        RouteContext { route, state, stale: Arc::new(StaleState::new()) }
     }
 
     /// Creates a deep copy of `RouteContext`.
     pub fn deep_copy(&self) -> Self {
-        let new_route = Route { actor: self.route.actor.clone(), tour: self.route.tour.deep_copy() };
-        let mut new_state = RouteState::new_with_sizes(self.state.sizes());
-
-        // copy activity states
-        self.route.tour.all_activities().zip(0_usize..).for_each(|(a, index)| {
-            self.state.all_keys().for_each(|key| {
-                if let Some(value) = self.state.get_activity_state_raw(key, a) {
-                    let a = new_route.tour.get(index).unwrap();
-                    new_state.put_activity_state_raw(key, a, value.clone());
-                }
-            });
-        });
-
-        // copy route states
-        self.state.all_keys().for_each(|key| {
-            if let Some(value) = self.state.get_route_state_raw(key) {
-                new_state.put_route_state_raw(key, value.clone());
-            }
-        });
+        let (new_route, new_state) = self.copy_route_and_state();
 
         RouteContext {
             route: Arc::new(new_route),
             state: Arc::new(new_state),
-            stale: Arc::new(StaleState { is_stale: self.stale.is_stale }),
+            stale: Arc::new(StaleState {
+                is_stale: self.stale.is_stale,
+                version: self.stale.version,
+                cost: AtomicU64::new(self.stale.cost.load(Ordering::Relaxed)),
+            }),
         }
     }
 
-    /// Gets route cost.
+    /// Creates a snapshot of route and its state which can be used to rollback changes made later
+    /// within this route context. Unlike `deep_copy` of the whole insertion context, it copies
+    /// only the given route.
+    pub fn snapshot(&self) -> RouteSnapshot {
+        let (route, state) = self.copy_route_and_state();
+
+        RouteSnapshot { route, state, is_stale: self.stale.is_stale, version: self.stale.version }
+    }
+
+    /// Restores route and its state from the snapshot. Route context keeps its identity, so all
+    /// its clones see the restored route.
+    pub fn rollback(&mut self, snapshot: RouteSnapshot) {
+        let (route, state) = self.as_mut();
+
+        // NOTE activity states are keyed by activity address which is kept when tour is moved
+        *route = snapshot.route;
+        *state = snapshot.state;
+
+        let stale: &mut StaleState = unsafe { as_mut(&self.stale) };
+        stale.is_stale = snapshot.is_stale;
+        stale.version = snapshot.version;
+        stale.cost.store(UNKNOWN_COST, Ordering::Relaxed);
+    }
+
+    /// Gets route cost. The cost is cached until route or its state is accessed by `mut` methods,
+    /// so solution cost is recalculated only for changed routes.
     pub fn get_route_cost(&self) -> Cost {
+        let cost = self.stale.cost.load(Ordering::Relaxed);
+        if cost != UNKNOWN_COST {
+            return Cost::from_bits(cost);
+        }
+
+        let cost = self.calculate_route_cost();
+        self.stale.cost.store(cost.to_bits(), Ordering::Relaxed);
+
+        cost
+    }
+
+    fn calculate_route_cost(&self) -> Cost {
         let get_cost = |costs: &Costs, distance: f64, duration: f64| {
             costs.fixed
                 + costs.per_distance * distance
@@ -254,13 +289,31 @@ impl RouteContext {
         let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        let overload_penalty = self
+            .state
+            .get_route_state::<i32>(CAPACITY_OVERLOAD_KEY)
+            .zip(actor.vehicle.dimens.get_capacity_overload())
+            .map_or(0., |(units, overload)| *units as f64 * overload.penalty);
+        let route_penalty = self.state.get_route_state::<Cost>(ROUTE_PENALTY_KEY).cloned().unwrap_or(0.);
+        let total_toll = self.state.get_route_state::<Cost>(TOTAL_TOLL_KEY).cloned().unwrap_or(0.);
+        let overtime_penalty = actor
+            .vehicle
+            .dimens
+            .get_shift_overtime()
+            .map_or(0., |overtime| (duration - overtime.shift_time).max(0.) * overtime.penalty);
+
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + overload_penalty
+            + overtime_penalty
+            + route_penalty
+            + total_toll
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
     /// Marks context as stale.
     pub fn as_mut(&mut self) -> (&mut Route, &mut RouteState) {
-        self.mark_stale(true);
+        self.mark_changed();
 
         let route: &mut Route = unsafe { as_mut(&self.route) };
         let state: &mut RouteState = unsafe { as_mut(&self.state) };
@@ -271,14 +324,14 @@ impl RouteContext {
     /// Returns mutable reference to used `Route`.
     /// Marks context as stale.
     pub fn route_mut(&mut self) -> &mut Route {
-        self.mark_stale(true);
+        self.mark_changed();
         unsafe { as_mut(&self.route) }
     }
 
     /// Returns mutable reference to used `RouteState`.
     /// Marks context as stale.
     pub fn state_mut(&mut self) -> &mut RouteState {
-        self.mark_stale(true);
+        self.mark_changed();
         unsafe { as_mut(&self.state) }
     }
 
@@ -293,6 +346,43 @@ impl RouteContext {
         let stale: &mut StaleState = unsafe { as_mut(&self.stale) };
         stale.is_stale = is_stale;
     }
+
+    /// Returns a version of route content. The version is changed on every `mut` access and
+    /// kept by `deep_copy`, so two contexts with the same version have the same route and state.
+    pub fn version(&self) -> u64 {
+        self.stale.version
+    }
+
+    fn copy_route_and_state(&self) -> (Route, RouteState) {
+        let new_route = Route { actor: self.route.actor.clone(), tour: self.route.tour.deep_copy() };
+        let mut new_state = RouteState::new_with_sizes(self.state.sizes());
+
+        // copy activity states
+        self.route.tour.all_activities().zip(0_usize..).for_each(|(a, index)| {
+            self.state.all_keys().for_each(|key| {
+                if let Some(value) = self.state.get_activity_state_raw(key, a) {
+                    let a = new_route.tour.get(index).unwrap();
+                    new_state.put_activity_state_raw(key, a, value.clone());
+                }
+            });
+        });
+
+        // copy route states
+        self.state.all_keys().for_each(|key| {
+            if let Some(value) = self.state.get_route_state_raw(key) {
+                new_state.put_route_state_raw(key, value.clone());
+            }
+        });
+
+        (new_route, new_state)
+    }
+
+    fn mark_changed(&mut self) {
+        let stale: &mut StaleState = unsafe { as_mut(&self.stale) };
+        stale.is_stale = true;
+        stale.version = StaleState::next_version();
+        stale.cost.store(UNKNOWN_COST, Ordering::Relaxed);
+    }
 }
 
 impl PartialEq<RouteContext> for RouteContext {
@@ -388,8 +478,26 @@ impl RouteState {
     }
 }
 
+/// A bit pattern of route cost which is not calculated yet. It is NaN, so it is not produced by
+/// cost calculation.
+const UNKNOWN_COST: u64 = u64::MAX;
+
 struct StaleState {
     pub is_stale: bool,
+    pub version: u64,
+    /// A cached route cost stored as bits, so it can be updated using shared reference.
+    pub cost: AtomicU64,
+}
+
+impl StaleState {
+    fn new() -> Self {
+        Self { is_stale: true, version: Self::next_version(), cost: AtomicU64::new(UNKNOWN_COST) }
+    }
+
+    fn next_version() -> u64 {
+        static NEXT_VERSION: AtomicU64 = AtomicU64::new(0);
+        NEXT_VERSION.fetch_add(1, Ordering::Relaxed)
+    }
 }
 
 /// A wrapper around route context modifier function.
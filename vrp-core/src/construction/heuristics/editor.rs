@@ -0,0 +1,320 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/editor_test.rs"]
+mod editor_test;
+
+use super::*;
+use crate::models::common::{TimeWindow, Timestamp};
+use crate::models::problem::{Actor, Job, Single};
+use crate::models::solution::{Activity, Place};
+use crate::models::Solution;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// Specifies a manual edit of the solution.
+pub enum SolutionEdit {
+    /// Moves job to the route of given actor. Job activities are inserted after the activity with
+    /// given index in the tour, where zero index is a tour start. Job is removed from its current route
+    /// or from the list of unassigned jobs.
+    MoveJob {
+        /// A job to be moved.
+        job: Job,
+        /// An actor of the target route.
+        actor: Arc<Actor>,
+        /// An index of the activity after which job is inserted.
+        position: usize,
+    },
+    /// Removes job from its route. Removed job is moved to ignored jobs, so it is not reinserted
+    /// by repair.
+    RemoveJob {
+        /// A job to be removed.
+        job: Job,
+    },
+}
+
+/// Specifies a change of job assignment made by re-planning.
+pub struct AssignmentChange {
+    /// A job which assignment is changed.
+    pub job: Job,
+    /// An actor of the route where job was assigned before re-planning.
+    pub from: Arc<Actor>,
+    /// An actor of the route where job is assigned after re-planning. None means that job cannot
+    /// be assigned and it is moved to the list of unassigned jobs.
+    pub to: Option<Arc<Actor>>,
+}
+
+/// Applies manual edits to the solution. Edits are applied even if they violate constraints: a job
+/// is inserted at the requested position using the best feasible place and time window, if there is
+/// one, or the first place and time window otherwise. Use `repair_solution` to restore feasibility.
+pub fn apply_solution_edits(insertion_ctx: &mut InsertionContext, edits: &[SolutionEdit]) -> Result<(), String> {
+    edits.iter().try_for_each(|edit| match edit {
+        SolutionEdit::MoveJob { job, actor, position } => move_job(insertion_ctx, job, actor, *position),
+        SolutionEdit::RemoveJob { job } => {
+            if !remove_job(insertion_ctx, job) {
+                return Err("cannot remove job which is not assigned".to_string());
+            }
+            insertion_ctx.solution.ignored.push(job.clone());
+
+            Ok(())
+        }
+    })
+}
+
+/// Restores feasibility of the solution with minimal further changes: only jobs which violate hard
+/// constraints are removed from their routes and then reinserted using cheapest insertion. Other
+/// routes and jobs, including previously unassigned ones, are kept as is.
+pub fn repair_solution(insertion_ctx: InsertionContext) -> InsertionContext {
+    let mut insertion_ctx = insertion_ctx;
+    insertion_ctx.restore();
+
+    let unassigned = insertion_ctx
+        .solution
+        .required
+        .drain(0..)
+        .map(|job| (job, 0))
+        .chain(insertion_ctx.solution.unassigned.drain())
+        .collect::<Vec<_>>();
+
+    let verifier = SolutionVerifier::new(insertion_ctx.problem.clone(), insertion_ctx.environment.clone());
+    loop {
+        let solution = insertion_ctx.solution.to_solution(insertion_ctx.problem.extras.clone());
+        let jobs = match verifier.verify(&solution) {
+            Ok(_) => break,
+            Err(violations) => get_violated_jobs(&solution, violations),
+        };
+
+        if jobs.is_empty() {
+            break;
+        }
+
+        jobs.iter().for_each(|job| {
+            remove_job(&mut insertion_ctx, job);
+            insertion_ctx.solution.required.push(job.clone());
+        });
+        insertion_ctx.restore();
+    }
+
+    let mut insertion_ctx = InsertionHeuristic::default().process(
+        &AllJobSelector::default(),
+        &PairJobMapReducer::new(Box::new(AllRouteSelector::default()), Box::new(BestResultSelector::default())),
+        insertion_ctx,
+        &None,
+    );
+
+    insertion_ctx.solution.unassigned.extend(unassigned);
+
+    insertion_ctx
+}
+
+/// Re-plans solution when the vehicle of given actor breaks down at given time: jobs which are not
+/// yet started by the vehicle are removed from its route and reinserted into other routes using
+/// cheapest insertion. Activities of all routes which are departed before the breakdown time are kept
+/// as they are, other jobs are not moved. Returns assignments changed by re-planning.
+pub fn replan_vehicle_breakdown(
+    insertion_ctx: InsertionContext,
+    actor: &Arc<Actor>,
+    time: Timestamp,
+) -> Result<(InsertionContext, Vec<AssignmentChange>), String> {
+    let mut insertion_ctx = insertion_ctx;
+    let constraint = insertion_ctx.problem.constraint.clone();
+
+    let route_ctx = insertion_ctx
+        .solution
+        .routes
+        .iter_mut()
+        .find(|route_ctx| route_ctx.route.actor == *actor)
+        .ok_or_else(|| "cannot find route for given actor".to_string())?;
+
+    let tour = &route_ctx.route.tour;
+    let jobs = tour
+        .jobs()
+        .filter(|job| tour.job_activities(job).all(|activity| activity.schedule.departure >= time))
+        .collect::<Vec<_>>();
+
+    constraint.insertion_cache().invalidate(route_ctx);
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    constraint.accept_route_state(route_ctx);
+
+    let unassigned = insertion_ctx
+        .solution
+        .required
+        .drain(0..)
+        .map(|job| (job, 0))
+        .chain(insertion_ctx.solution.unassigned.drain())
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.required.extend(jobs.iter().cloned());
+
+    let mut insertion_ctx = InsertionHeuristic::new(InsertionPosition::Horizon(time)).process(
+        &AllJobSelector::default(),
+        &PairJobMapReducer::new(
+            Box::new(ExcludedActorRouteSelector { actor: actor.clone() }),
+            Box::new(BestResultSelector::default()),
+        ),
+        insertion_ctx,
+        &None,
+    );
+
+    insertion_ctx.solution.unassigned.extend(unassigned);
+    insertion_ctx.restore();
+
+    let changes = jobs
+        .into_iter()
+        .map(|job| {
+            let to = insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .find(|route_ctx| route_ctx.route.tour.contains(&job))
+                .map(|route_ctx| route_ctx.route.actor.clone());
+
+            AssignmentChange { job, from: actor.clone(), to }
+        })
+        .collect();
+
+    Ok((insertion_ctx, changes))
+}
+
+/// Selects all routes except the one of given actor.
+struct ExcludedActorRouteSelector {
+    actor: Arc<Actor>,
+}
+
+impl RouteSelector for ExcludedActorRouteSelector {
+    fn select<'a>(&'a self, ctx: &'a InsertionContext, _job: &'a Job) -> Box<dyn Iterator<Item = RouteContext> + 'a> {
+        Box::new(
+            ctx.solution
+                .routes
+                .iter()
+                .cloned()
+                .chain(ctx.solution.registry.next())
+                .filter(move |route_ctx| route_ctx.route.actor != self.actor),
+        )
+    }
+}
+
+fn move_job(
+    insertion_ctx: &mut InsertionContext,
+    job: &Job,
+    actor: &Arc<Actor>,
+    position: usize,
+) -> Result<(), String> {
+    // NOTE resolve route and check position first, so the solution is not changed on error
+    let solution = &insertion_ctx.solution;
+    let route_index = solution.routes.iter().position(|route_ctx| route_ctx.route.actor == *actor);
+    let new_route_ctx = match route_index {
+        Some(_) => None,
+        None => Some(
+            solution
+                .registry
+                .next()
+                .find(|route_ctx| route_ctx.route.actor == *actor)
+                .ok_or_else(|| "cannot find route for given actor".to_string())?,
+        ),
+    };
+
+    let tour = &route_index.map(|idx| &solution.routes[idx]).or(new_route_ctx.as_ref()).unwrap().route.tour;
+    if position > tour.activity_count() - tour.job_activities(job).count() {
+        return Err(format!("cannot insert job at position {}", position));
+    }
+
+    remove_job(insertion_ctx, job);
+
+    let solution = &mut insertion_ctx.solution;
+    solution.required.retain(|other| other != job);
+    solution.ignored.retain(|other| other != job);
+    solution.unassigned.remove(job);
+
+    let route_index = match new_route_ctx {
+        Some(route_ctx) => {
+            solution.registry.use_route(&route_ctx);
+            solution.routes.push(route_ctx.deep_copy());
+
+            solution.routes.len() - 1
+        }
+        None => route_index.unwrap(),
+    };
+
+    let route_ctx = insertion_ctx.solution.routes.get(route_index).unwrap();
+
+    let result = evaluate_job_insertion_in_route(
+        job,
+        insertion_ctx,
+        route_ctx,
+        InsertionPosition::Concrete(position),
+        InsertionResult::make_failure(),
+        &BestResultSelector::default(),
+    );
+
+    let activities = match result {
+        InsertionResult::Success(success) => success.activities,
+        InsertionResult::Failure(_) => {
+            let tour = &route_ctx.route.tour;
+            let prev = tour.get(position).unwrap();
+            let singles = match job {
+                Job::Single(single) => vec![single.clone()],
+                Job::Multi(multi) => multi.jobs.clone(),
+            };
+
+            singles
+                .into_iter()
+                .enumerate()
+                .map(|(idx, single)| (create_forced_activity(prev, single, tour.start()), position + idx))
+                .collect()
+        }
+    };
+
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_index).unwrap();
+    insertion_ctx.problem.constraint.insertion_cache().invalidate(route_ctx);
+    activities.into_iter().for_each(|(activity, index)| {
+        route_ctx.route_mut().tour.insert_at(activity, index + 1);
+    });
+    insertion_ctx.problem.constraint.accept_insertion(&mut insertion_ctx.solution, route_index, job);
+
+    Ok(())
+}
+
+/// Removes job from its route and returns true if job was found in some route.
+fn remove_job(insertion_ctx: &mut InsertionContext, job: &Job) -> bool {
+    let constraint = insertion_ctx.problem.constraint.clone();
+
+    insertion_ctx.solution.routes.iter_mut().filter(|route_ctx| route_ctx.route.tour.contains(job)).any(|route_ctx| {
+        constraint.insertion_cache().invalidate(route_ctx);
+        route_ctx.route_mut().tour.remove(job);
+        constraint.accept_route_state(route_ctx);
+
+        true
+    })
+}
+
+fn create_forced_activity(prev: &Activity, single: Arc<Single>, start: Option<&Activity>) -> Activity {
+    let start_time = start.map_or(0., |start| start.schedule.departure);
+    let place = single.places.first().map(|place| Place {
+        location: place.location.unwrap_or(prev.place.location),
+        duration: place.duration,
+        time: place.times.first().map_or_else(TimeWindow::max, |time| time.to_time_window(start_time)),
+    });
+
+    let mut activity = Activity::new_with_job(single);
+    if let Some(place) = place {
+        activity.place = place;
+    }
+
+    activity
+}
+
+fn get_violated_jobs(solution: &Solution, violations: Vec<SolutionViolation>) -> Vec<Job> {
+    violations
+        .into_iter()
+        .filter_map(|violation| match violation {
+            SolutionViolation::Route { job, .. } | SolutionViolation::DuplicatedJob { job } => Some(job),
+            SolutionViolation::Activity { route_index, activity_index, .. } => solution
+                .routes
+                .get(route_index)
+                .and_then(|route| route.tour.get(activity_index))
+                .and_then(|activity| activity.retrieve_job()),
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect()
+}
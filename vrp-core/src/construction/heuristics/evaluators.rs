@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use crate::construction::constraints::{ActivityConstraintViolation, ConstraintPipeline};
 use crate::construction::heuristics::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, Timestamp};
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Place};
 use crate::utils::unwrap_from_result;
@@ -21,6 +21,10 @@ pub enum InsertionPosition {
     Concrete(usize),
     /// Job can be inserted only to the end of the route.
     Last,
+    /// Job can be inserted only after activities departed before given time (frozen horizon), so
+    /// already dispatched part of the route is kept as is. Useful for re-planning when vehicles are
+    /// already en route.
+    Horizon(Timestamp),
 }
 
 /// Evaluates possibility to preform insertion from given insertion context in all available
@@ -69,11 +73,16 @@ pub fn evaluate_job_insertion_in_route(
         }
     }
 
-    result_selector.select(
-        ctx,
-        alternative,
-        evaluate_job_constraint_in_route(job, constraint, &route_ctx, position, route_costs, best_known_cost),
-    )
+    let cache = constraint.insertion_cache();
+    let result = if cache.is_enabled() {
+        cache.evaluate(job, route_ctx, position, route_costs, best_known_cost, || {
+            evaluate_job_constraint_in_route(job, constraint, route_ctx, position, 0., None)
+        })
+    } else {
+        evaluate_job_constraint_in_route(job, constraint, route_ctx, position, route_costs, best_known_cost)
+    };
+
+    result_selector.select(ctx, alternative, result)
 }
 
 /// Evaluates possibility to preform insertion in route context only.
@@ -111,7 +120,7 @@ fn evaluate_single(
         insertion_idx,
         single,
         &mut activity,
-        SingleContext::new(best_known_cost, 0),
+        SingleContext::new(best_known_cost, get_insertion_start(route_ctx, position)),
     );
 
     if result.is_success() {
@@ -133,7 +142,8 @@ fn evaluate_multi(
     route_costs: Cost,
     best_known_cost: Option<Cost>,
 ) -> InsertionResult {
-    let insertion_idx = get_insertion_index(route_ctx, position).unwrap_or(0);
+    let insertion_idx =
+        get_insertion_index(route_ctx, position).unwrap_or_else(|| get_insertion_start(route_ctx, position));
     // 1. analyze permutations
     let result = unwrap_from_result(multi.permutations().into_iter().try_fold(
         MultiContext::new(best_known_cost, insertion_idx),
@@ -260,6 +270,20 @@ fn get_insertion_index(route_ctx: &RouteContext, position: InsertionPosition) ->
         InsertionPosition::Any => None,
         InsertionPosition::Concrete(idx) => Some(idx),
         InsertionPosition::Last => Some(route_ctx.route.tour.legs().count().max(1) - 1),
+        InsertionPosition::Horizon(_) => None,
+    }
+}
+
+/// Returns index of the first leg where job can be inserted.
+fn get_insertion_start(route_ctx: &RouteContext, position: InsertionPosition) -> usize {
+    match position {
+        InsertionPosition::Horizon(time) => {
+            let tour = &route_ctx.route.tour;
+            let frozen = tour.all_activities().take_while(|activity| activity.schedule.departure < time).count();
+
+            (frozen.max(1) - 1).min(tour.activity_count())
+        }
+        _ => 0,
     }
 }
 
@@ -0,0 +1,109 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/explanation_test.rs"]
+mod explanation_test;
+
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::models::problem::{Actor, Job, Single};
+use crate::models::solution::{Activity, Place};
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// Specifies a minimal constraint relaxation which makes job assignable.
+pub struct JobRelaxation {
+    /// Codes of constraints which have to be relaxed. Empty when job can be assigned without relaxation.
+    pub codes: Vec<i32>,
+    /// An actor which can serve the job when constraints are relaxed.
+    pub actor: Arc<Actor>,
+}
+
+/// Finds a minimal relaxation of constraints with given codes which makes job assignable in given
+/// insertion context. Job insertion is evaluated once per route: codes of all failed hard constraints
+/// are collected for each insertion position and the position with the smallest set of relaxable
+/// codes defines the relaxation. Returns `None` if job cannot be assigned even when all given
+/// constraints are relaxed.
+///
+/// Sub jobs of multi job are checked independently from each other, so relaxation is approximate
+/// for them.
+pub fn find_job_relaxation(insertion_ctx: &InsertionContext, job: &Job, codes: &[i32]) -> Option<JobRelaxation> {
+    let constraint = insertion_ctx.problem.constraint.as_ref();
+    let relaxable = codes.iter().cloned().collect::<HashSet<_>>();
+    let singles = match job {
+        Job::Single(single) => vec![single.clone()],
+        Job::Multi(multi) => multi.jobs.clone(),
+    };
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .cloned()
+        .chain(insertion_ctx.solution.registry.next())
+        .filter_map(|route_ctx| {
+            let route_codes = constraint.get_hard_route_violations(&insertion_ctx.solution, &route_ctx, job);
+            if !route_codes.iter().all(|code| relaxable.contains(code)) {
+                return None;
+            }
+
+            let violated =
+                singles.iter().try_fold(route_codes.into_iter().collect::<HashSet<_>>(), |mut acc, single| {
+                    acc.extend(get_min_activity_violations(constraint, &route_ctx, single, &relaxable)?);
+                    Some(acc)
+                })?;
+
+            Some((codes.iter().filter(|code| violated.contains(*code)).cloned().collect::<Vec<_>>(), route_ctx))
+        })
+        .min_by_key(|(codes, _)| codes.len())
+        .map(|(codes, route_ctx)| JobRelaxation { codes, actor: route_ctx.route.actor.clone() })
+}
+
+/// Returns the smallest set of codes of failed hard activity constraints among all insertion positions
+/// of the job in the route. Positions with failed constraints which cannot be relaxed are ignored.
+fn get_min_activity_violations(
+    constraint: &ConstraintPipeline,
+    route_ctx: &RouteContext,
+    single: &Arc<Single>,
+    relaxable: &HashSet<i32>,
+) -> Option<Vec<i32>> {
+    let start_time = route_ctx.route.tour.start()?.schedule.departure;
+    let mut target = Activity::new_with_job(single.clone());
+    let mut result: Option<Vec<i32>> = None;
+
+    for (items, index) in route_ctx.route.tour.legs() {
+        let (prev, next) = match items {
+            [prev] => (prev, None),
+            [prev, next] => (prev, Some(next)),
+            _ => continue,
+        };
+
+        for detail in single.places.iter() {
+            for time in detail.times.iter() {
+                target.place = Place {
+                    location: detail.location.unwrap_or(prev.place.location),
+                    duration: detail.duration,
+                    time: time.to_time_window(start_time),
+                };
+
+                let activity_ctx = ActivityContext { index, prev, target: &target, next };
+                let mut codes = constraint.get_hard_activity_violations(route_ctx, &activity_ctx);
+                codes.sort_unstable();
+                codes.dedup();
+
+                if codes.is_empty() {
+                    return Some(codes);
+                }
+
+                let is_better = match &result {
+                    Some(best) => codes.len() < best.len(),
+                    None => true,
+                };
+
+                if is_better && codes.iter().all(|code| relaxable.contains(code)) {
+                    result = Some(codes);
+                }
+            }
+        }
+    }
+
+    result
+}
@@ -1,7 +1,11 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/insertions_test.rs"]
+mod insertions_test;
+
 use crate::construction::heuristics::evaluators::InsertionPosition;
 use crate::construction::heuristics::{InsertionContext, JobMapReducer, JobSelector, RouteContext};
 use crate::construction::Quota;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, PriorityDimension};
 use crate::models::problem::Job;
 use crate::models::solution::Activity;
 use rand::prelude::SliceRandom;
@@ -73,13 +77,31 @@ impl InsertionHeuristic {
 
         prepare_insertion_ctx(&mut ctx);
 
-        while !ctx.solution.required.is_empty() && !quota.as_ref().map_or(false, |q| q.is_reached()) {
-            ctx.solution.routes.shuffle(&mut ctx.environment.random.get_rng());
+        // NOTE jobs are inserted in tiers by their priority: jobs with lower priority are postponed
+        // until all jobs with higher priority are either inserted or cannot be inserted
+        let mut postponed = take_low_priority_jobs(&mut ctx.solution.required);
+
+        loop {
+            while !ctx.solution.required.is_empty() && !matches!(quota, Some(quota) if quota.is_reached()) {
+                ctx.solution.routes.shuffle(&mut ctx.environment.random.get_rng());
+
+                let jobs = job_selector.select(&mut ctx).collect::<Vec<Job>>();
+                let result = job_reducer.reduce(&ctx, jobs, self.insertion_position);
+
+                apply_insertion_result(&mut ctx, result);
+            }
+
+            if postponed.is_empty() {
+                break;
+            }
 
-            let jobs = job_selector.select(&mut ctx).collect::<Vec<Job>>();
-            let result = job_reducer.reduce(&ctx, jobs, self.insertion_position);
+            ctx.solution.required.append(&mut postponed);
 
-            apply_insertion_result(&mut ctx, result);
+            if matches!(quota, Some(quota) if quota.is_reached()) {
+                break;
+            }
+
+            postponed = take_low_priority_jobs(&mut ctx.solution.required);
         }
 
         finalize_insertion_ctx(&mut ctx);
@@ -121,9 +143,36 @@ impl InsertionResult {
     }
 }
 
+/// Removes from required jobs all jobs with priority lower than the highest one and returns them.
+fn take_low_priority_jobs(required: &mut Vec<Job>) -> Vec<Job> {
+    let get_priority = |job: &Job| job.dimens().get_priority().unwrap_or(1);
+
+    let highest = if let Some(highest) = required.iter().map(get_priority).min() {
+        highest
+    } else {
+        return vec![];
+    };
+
+    if required.iter().all(|job| get_priority(job) == highest) {
+        return vec![];
+    }
+
+    let (high, low): (Vec<_>, Vec<_>) = required.drain(0..).partition(|job| get_priority(job) == highest);
+    required.extend(high);
+
+    low
+}
+
 pub(crate) fn prepare_insertion_ctx(ctx: &mut InsertionContext) {
     ctx.solution.required.extend(ctx.solution.unassigned.drain().map(|(job, _)| job));
     ctx.problem.constraint.accept_solution_state(&mut ctx.solution);
+
+    if ctx.environment.is_deterministic {
+        // NOTE required jobs are often collected from hash containers which iteration order depends
+        // on job addresses, so it differs between runs
+        let jobs = &ctx.problem.jobs;
+        ctx.solution.required.sort_by_key(|job| jobs.position(job));
+    }
 }
 
 pub(crate) fn finalize_insertion_ctx(ctx: &mut InsertionContext) {
@@ -142,6 +191,8 @@ pub(crate) fn apply_insertion_result(ctx: &mut InsertionContext, result: Inserti
             });
 
             let route_ctx = ctx.solution.routes.get_mut(route_index).unwrap();
+            ctx.problem.constraint.insertion_cache().invalidate(route_ctx);
+
             let route = route_ctx.route_mut();
             success.activities.into_iter().for_each(|(a, index)| {
                 route.tour.insert_at(a, index + 1);
@@ -3,12 +3,21 @@
 //! # Design
 //!
 
+mod cache;
+pub use self::cache::*;
+
 mod context;
 pub use self::context::*;
 
+mod editor;
+pub use self::editor::*;
+
 mod evaluators;
 pub use self::evaluators::*;
 
+mod explanation;
+pub use self::explanation::*;
+
 mod factories;
 
 mod insertions;
@@ -17,5 +26,11 @@ pub use self::insertions::*;
 mod metrics;
 pub use self::metrics::*;
 
+mod quote;
+pub use self::quote::*;
+
 mod selectors;
 pub use self::selectors::*;
+
+mod verifier;
+pub use self::verifier::*;
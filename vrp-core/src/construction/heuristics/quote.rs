@@ -0,0 +1,76 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/quote_test.rs"]
+mod quote_test;
+
+use super::*;
+use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::models::common::{Cost, Distance, Duration, Schedule};
+use crate::models::problem::{Actor, Job};
+use std::sync::Arc;
+
+/// Specifies the cheapest feasible insertion of the job into existing solution.
+pub struct InsertionQuote {
+    /// An actor of the route where job can be inserted.
+    pub actor: Arc<Actor>,
+    /// Tour indices of job activities after insertion.
+    pub positions: Vec<usize>,
+    /// Schedules of job activities after insertion.
+    pub schedules: Vec<Schedule>,
+    /// Insertion cost as estimated by constraint pipeline.
+    pub cost: Cost,
+    /// Change of the route's total distance.
+    pub distance_delta: Distance,
+    /// Change of the route's total duration.
+    pub duration_delta: Duration,
+}
+
+/// Finds the cheapest feasible insertion of the job into existing solution without changing it.
+/// Returns a code of violated constraint if job cannot be inserted.
+pub fn quote_job_insertion(insertion_ctx: &InsertionContext, job: &Job) -> Result<InsertionQuote, i32> {
+    let result = evaluate_job_insertion(
+        job,
+        insertion_ctx,
+        &AllRouteSelector::default(),
+        &BestResultSelector::default(),
+        InsertionPosition::Any,
+    );
+
+    let success = match result {
+        InsertionResult::Success(success) => success,
+        InsertionResult::Failure(failure) => return Err(failure.constraint),
+    };
+
+    let get_totals = |route_ctx: &RouteContext| {
+        let state = &route_ctx.state;
+        (
+            state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.),
+            state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.),
+        )
+    };
+
+    let mut route_ctx = success.context.deep_copy();
+    let (distance_before, duration_before) = get_totals(&route_ctx);
+
+    let positions = success.activities.into_iter().fold(vec![], |mut positions, (activity, index)| {
+        route_ctx.route_mut().tour.insert_at(activity, index + 1);
+        positions.push(index + 1);
+        positions
+    });
+    insertion_ctx.problem.constraint.accept_route_state(&mut route_ctx);
+
+    let (distance_after, duration_after) = get_totals(&route_ctx);
+    let schedules = positions
+        .iter()
+        .filter_map(|position| route_ctx.route.tour.get(*position))
+        .map(|activity| activity.schedule.clone())
+        .collect();
+
+    Ok(InsertionQuote {
+        actor: route_ctx.route.actor.clone(),
+        positions,
+        schedules,
+        cost: success.cost,
+        distance_delta: distance_after - distance_before,
+        duration_delta: duration_after - duration_before,
+    })
+}
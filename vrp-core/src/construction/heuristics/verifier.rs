@@ -0,0 +1,145 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/verifier_test.rs"]
+mod verifier_test;
+
+use super::*;
+use crate::models::problem::Job;
+use crate::models::solution::Route;
+use crate::models::{Problem, Solution};
+use crate::utils::Environment;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+/// Specifies a violation found by `SolutionVerifier`.
+pub enum SolutionViolation {
+    /// Job cannot be assigned to the route due to hard route constraint with given code.
+    Route {
+        /// Index of the route in the solution.
+        route_index: usize,
+        /// A job assigned to the route.
+        job: Job,
+        /// A code of violated constraint.
+        code: i32,
+    },
+    /// Activity cannot be visited within the route due to hard activity constraint with given code.
+    Activity {
+        /// Index of the route in the solution.
+        route_index: usize,
+        /// Index of the activity in the route tour.
+        activity_index: usize,
+        /// A code of violated constraint.
+        code: i32,
+    },
+    /// Job is assigned more than once or it is both assigned and unassigned.
+    DuplicatedJob {
+        /// A duplicated job.
+        job: Job,
+    },
+}
+
+/// Verifies solution feasibility against constraint pipeline of the problem. Each route is rebuilt
+/// from scratch appending activities in their tour order, so every activity is evaluated by hard
+/// constraints exactly in the state which precedes it in the original route.
+pub struct SolutionVerifier {
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+}
+
+impl SolutionVerifier {
+    /// Creates a new instance of `SolutionVerifier`.
+    pub fn new(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        Self { problem, environment }
+    }
+
+    /// Verifies given solution and returns all found violations.
+    pub fn verify(&self, solution: &Solution) -> Result<(), Vec<SolutionViolation>> {
+        let mut solution_ctx = InsertionContext::new_empty(self.problem.clone(), self.environment.clone()).solution;
+
+        let violations = get_duplicated_jobs(solution)
+            .into_iter()
+            .map(|job| SolutionViolation::DuplicatedJob { job })
+            .chain(
+                solution
+                    .routes
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(route_index, route)| self.verify_route(&mut solution_ctx, route_index, route)),
+            )
+            .collect::<Vec<_>>();
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn verify_route(
+        &self,
+        solution_ctx: &mut SolutionContext,
+        route_index: usize,
+        route: &Route,
+    ) -> Vec<SolutionViolation> {
+        let constraint = self.problem.constraint.as_ref();
+
+        let mut route_ctx = RouteContext::new(route.actor.clone());
+        if let (Some(start), Some(original)) = (route_ctx.route_mut().tour.get_mut(0), route.tour.start()) {
+            start.schedule = original.schedule.clone();
+        }
+        solution_ctx.registry.use_route(&route_ctx);
+        solution_ctx.routes.push(route_ctx);
+        let ctx_index = solution_ctx.routes.len() - 1;
+
+        let mut violations = vec![];
+
+        route.tour.all_activities().enumerate().filter(|(_, activity)| activity.job.is_some()).for_each(
+            |(activity_index, activity)| {
+                let job = activity.retrieve_job().expect("activity should have job");
+                let route_ctx = solution_ctx.routes.get(ctx_index).unwrap();
+
+                if !route_ctx.route.tour.contains(&job) {
+                    if let Some(violation) = constraint.evaluate_hard_route(solution_ctx, route_ctx, &job) {
+                        violations.push(SolutionViolation::Route {
+                            route_index,
+                            job: job.clone(),
+                            code: violation.code,
+                        });
+                    }
+                }
+
+                let tour = &route_ctx.route.tour;
+                let index = tour.activity_count();
+                let target = activity.deep_copy();
+                let activity_ctx = ActivityContext {
+                    index,
+                    prev: tour.get(index).unwrap(),
+                    target: &target,
+                    next: tour.get(index + 1),
+                };
+
+                if let Some(violation) = constraint.evaluate_hard_activity(route_ctx, &activity_ctx) {
+                    violations.push(SolutionViolation::Activity { route_index, activity_index, code: violation.code });
+                }
+
+                solution_ctx.routes.get_mut(ctx_index).unwrap().route_mut().tour.insert_at(target, index + 1);
+                constraint.accept_insertion(solution_ctx, ctx_index, &job);
+            },
+        );
+
+        violations
+    }
+}
+
+fn get_duplicated_jobs(solution: &Solution) -> Vec<Job> {
+    let counts = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.jobs())
+        .chain(solution.unassigned.iter().map(|(job, _)| job.clone()))
+        .fold(HashMap::<Job, usize>::new(), |mut acc, job| {
+            *acc.entry(job).or_insert(0) += 1;
+            acc
+        });
+
+    counts.into_iter().filter(|(_, count)| *count > 1).map(|(job, _)| job).collect()
+}
@@ -16,5 +16,6 @@ pub trait Quota {
     fn is_reached(&self) -> bool;
 }
 
+pub mod clustering;
 pub mod constraints;
 pub mod heuristics;
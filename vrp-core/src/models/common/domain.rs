@@ -1,4 +1,4 @@
-use crate::models::common::Timestamp;
+use crate::models::common::{Duration, Timestamp};
 use crate::utils::compare_floats;
 use hashbrown::HashMap;
 use std::any::Any;
@@ -6,6 +6,9 @@ use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+const SHIFT_OVERTIME_DIMENSION_KEY: &str = "sho";
+const PRIORITY_DIMENSION_KEY: &str = "priority";
+
 /// Specifies location type.
 pub type Location = usize;
 
@@ -199,6 +202,54 @@ impl IdDimension for Dimensions {
     }
 }
 
+/// A trait to get or set job priority. Priority is represented as integer where the lower value
+/// means higher priority. When not set, the job is considered to have the highest priority of `1`.
+pub trait PriorityDimension {
+    /// Sets job priority.
+    fn set_priority(&mut self, priority: i32) -> &mut Self;
+    /// Gets job priority if present.
+    fn get_priority(&self) -> Option<i32>;
+}
+
+impl PriorityDimension for Dimensions {
+    fn set_priority(&mut self, priority: i32) -> &mut Self {
+        self.set_value(PRIORITY_DIMENSION_KEY, priority);
+        self
+    }
+
+    fn get_priority(&self) -> Option<i32> {
+        self.get_value(PRIORITY_DIMENSION_KEY).cloned()
+    }
+}
+
+/// Specifies after which time the shift is considered as overtime and at which cost.
+#[derive(Clone, Debug)]
+pub struct ShiftOvertime {
+    /// A shift time after which overtime starts.
+    pub shift_time: Duration,
+    /// A penalty cost per each time unit of overtime.
+    pub penalty: Cost,
+}
+
+/// A trait to get or set vehicle's shift overtime.
+pub trait ShiftOvertimeDimension {
+    /// Sets shift overtime.
+    fn set_shift_overtime(&mut self, overtime: ShiftOvertime) -> &mut Self;
+    /// Gets shift overtime.
+    fn get_shift_overtime(&self) -> Option<&ShiftOvertime>;
+}
+
+impl ShiftOvertimeDimension for Dimensions {
+    fn set_shift_overtime(&mut self, overtime: ShiftOvertime) -> &mut Self {
+        self.set_value(SHIFT_OVERTIME_DIMENSION_KEY, overtime);
+        self
+    }
+
+    fn get_shift_overtime(&self) -> Option<&ShiftOvertime> {
+        self.get_value(SHIFT_OVERTIME_DIMENSION_KEY)
+    }
+}
+
 impl Hash for TimeInterval {
     fn hash<H: Hasher>(&self, state: &mut H) {
         let earliest = self.earliest.unwrap_or(0.).to_bits() as i64;
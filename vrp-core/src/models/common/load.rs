@@ -9,10 +9,11 @@ use std::ops::{Add, Mul, Sub};
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
+const CAPACITY_OVERLOAD_DIMENSION_KEY: &str = "cpo";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
-pub trait Load: Add + Sub + Ord + Copy + Default + Send + Sync {
+pub trait Load: Add + Sub + Mul<f64, Output = Self> + Ord + Copy + Default + Send + Sync {
     /// Returns true if it represents an empty load.
     fn is_not_empty(&self) -> bool;
 
@@ -24,6 +25,9 @@ pub trait Load: Add + Sub + Ord + Copy + Default + Send + Sync {
 
     /// Returns ratio.
     fn ratio(&self, other: &Self) -> f64;
+
+    /// Returns total amount of units which exceed given capacity.
+    fn overload(&self, capacity: &Self) -> i32;
 }
 
 /// Represents job demand, both static and dynamic.
@@ -42,6 +46,23 @@ pub trait CapacityDimension<T: Load + Add<Output = T> + Sub<Output = T> + 'stati
     fn get_capacity(&self) -> Option<&T>;
 }
 
+/// Specifies how much vehicle capacity can be exceeded and at which cost.
+#[derive(Clone, Debug)]
+pub struct CapacityOverload {
+    /// Max overload as a ratio of capacity, e.g. 0.1 allows to load 10% more than capacity.
+    pub max_ratio: f64,
+    /// A penalty cost per each overloaded unit.
+    pub penalty: f64,
+}
+
+/// A trait to get or set vehicle's capacity overload.
+pub trait CapacityOverloadDimension {
+    /// Sets capacity overload.
+    fn set_capacity_overload(&mut self, overload: CapacityOverload) -> &mut Self;
+    /// Gets capacity overload.
+    fn get_capacity_overload(&self) -> Option<&CapacityOverload>;
+}
+
 /// A trait to get or set demand.
 pub trait DemandDimension<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
     /// Sets demand.
@@ -80,6 +101,17 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> CapacityDimension<T>
     }
 }
 
+impl CapacityOverloadDimension for Dimensions {
+    fn set_capacity_overload(&mut self, overload: CapacityOverload) -> &mut Self {
+        self.set_value(CAPACITY_OVERLOAD_DIMENSION_KEY, overload);
+        self
+    }
+
+    fn get_capacity_overload(&self) -> Option<&CapacityOverload> {
+        self.get_value(CAPACITY_OVERLOAD_DIMENSION_KEY)
+    }
+}
+
 impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> DemandDimension<T> for Dimensions {
     fn set_demand(&mut self, demand: Demand<T>) -> &mut Self {
         self.set_value(DEMAND_DIMENSION_KEY, demand);
@@ -128,6 +160,10 @@ impl Load for SingleDimLoad {
     fn ratio(&self, other: &Self) -> f64 {
         self.value as f64 / other.value as f64
     }
+
+    fn overload(&self, capacity: &Self) -> i32 {
+        (self.value - capacity.value).max(0)
+    }
 }
 
 impl Add for SingleDimLoad {
@@ -231,6 +267,10 @@ impl Load for MultiDimLoad {
     fn ratio(&self, other: &Self) -> f64 {
         self.load.iter().zip(other.load.iter()).fold(0., |acc, (a, b)| (*a as f64 / *b as f64).max(acc))
     }
+
+    fn overload(&self, capacity: &Self) -> i32 {
+        self.load.iter().zip(capacity.load.iter()).map(|(a, b)| (*a - *b).max(0)).sum()
+    }
 }
 
 impl Default for MultiDimLoad {
@@ -48,6 +48,7 @@ fn create_example_fleet() -> Arc<Fleet> {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
             end: None,
+            driver: None,
         }],
     })];
 
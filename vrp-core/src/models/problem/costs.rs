@@ -3,26 +3,69 @@
 mod costs_test;
 
 use crate::algorithms::nsga2::{dominance_order, MultiObjective, Objective};
+use crate::algorithms::statistics::get_normal_quantile;
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::*;
 use crate::models::problem::{Actor, TargetObjective};
 use crate::models::solution::Activity;
 use crate::solver::objectives::{TotalRoutes, TotalTransportCost, TotalUnassignedJobs};
-use crate::utils::CollectGroupBy;
+use crate::utils::{compare_floats, CollectGroupBy};
 use hashbrown::HashMap;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
-/// A hierarchical multi objective for vehicle routing problem.
+/// A hierarchical multi objective for vehicle routing problem. Objectives are organized in layers
+/// which are compared lexicographically: the next layer is considered only if solutions are equal
+/// by all previous layers.
 pub struct ObjectiveCost {
-    primary_objectives: Vec<TargetObjective>,
-    secondary_objectives: Vec<TargetObjective>,
+    layers: Vec<ObjectiveLayer>,
+}
+
+/// Specifies how objectives within one layer are compared.
+enum ObjectiveLayer {
+    /// Objectives are compared using dominance order: a solution is better only if it is not worse
+    /// by any objective of the layer.
+    Dominance(Vec<TargetObjective>),
+    /// Objectives are compared using weighted sum of their fitness values.
+    WeightedSum(Vec<TargetObjective>, Vec<f64>),
+}
+
+impl ObjectiveLayer {
+    fn total_order(&self, a: &InsertionContext, b: &InsertionContext) -> Ordering {
+        match self {
+            ObjectiveLayer::Dominance(objectives) => dominance_order(a, b, objectives),
+            ObjectiveLayer::WeightedSum(objectives, weights) => {
+                let get_weighted_sum = |solution: &InsertionContext| {
+                    objectives
+                        .iter()
+                        .zip(weights.iter())
+                        .map(|(objective, weight)| objective.fitness(solution) * weight)
+                        .sum()
+                };
+
+                compare_floats(get_weighted_sum(a), get_weighted_sum(b))
+            }
+        }
+    }
+
+    fn objectives(&self) -> &Vec<TargetObjective> {
+        match self {
+            ObjectiveLayer::Dominance(objectives) => objectives,
+            ObjectiveLayer::WeightedSum(objectives, _) => objectives,
+        }
+    }
 }
 
 impl ObjectiveCost {
-    /// Creates an instance of `ObjectiveCost`.
+    /// Creates an instance of `ObjectiveCost` with two layers of objectives compared using
+    /// dominance order.
     pub fn new(primary_objectives: Vec<TargetObjective>, secondary_objectives: Vec<TargetObjective>) -> Self {
-        Self { primary_objectives, secondary_objectives }
+        Self {
+            layers: vec![
+                ObjectiveLayer::Dominance(primary_objectives),
+                ObjectiveLayer::Dominance(secondary_objectives),
+            ],
+        }
     }
 }
 
@@ -30,10 +73,11 @@ impl Objective for ObjectiveCost {
     type Solution = InsertionContext;
 
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
-        match dominance_order(a, b, &self.primary_objectives) {
-            Ordering::Equal => dominance_order(a, b, &self.secondary_objectives),
-            order => order,
-        }
+        self.layers
+            .iter()
+            .map(|layer| layer.total_order(a, b))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
     }
 
     fn distance(&self, _a: &Self::Solution, _b: &Self::Solution) -> f64 {
@@ -47,7 +91,7 @@ impl Objective for ObjectiveCost {
 
 impl MultiObjective for ObjectiveCost {
     fn objectives<'a>(&'a self) -> Box<dyn Iterator<Item = &TargetObjective> + 'a> {
-        Box::new(self.primary_objectives.iter().chain(self.secondary_objectives.iter()))
+        Box::new(self.layers.iter().flat_map(|layer| layer.objectives().iter()))
     }
 }
 
@@ -60,6 +104,53 @@ impl Default for ObjectiveCost {
     }
 }
 
+/// Provides the way to compose `ObjectiveCost` from multiple layers of objectives. Layers are
+/// compared lexicographically in the order they are added.
+#[derive(Default)]
+pub struct ObjectiveBuilder {
+    layers: Vec<ObjectiveLayer>,
+}
+
+impl ObjectiveBuilder {
+    /// Adds a layer of objectives compared using dominance order. A layer with a single objective
+    /// is a plain lexicographic level.
+    pub fn add_layer(mut self, objectives: Vec<TargetObjective>) -> Self {
+        self.layers.push(ObjectiveLayer::Dominance(objectives));
+        self
+    }
+
+    /// Adds a layer of objectives compared using weighted sum of their fitness values. Please note
+    /// that fitness values are minimized and are not normalized, so weights should take objective
+    /// scales into account.
+    pub fn add_weighted_layer(mut self, objectives: Vec<(TargetObjective, f64)>) -> Self {
+        let (objectives, weights) = objectives.into_iter().unzip();
+        self.layers.push(ObjectiveLayer::WeightedSum(objectives, weights));
+        self
+    }
+
+    /// Builds `ObjectiveCost` from added layers.
+    pub fn build(self) -> Result<ObjectiveCost, String> {
+        if self.layers.is_empty() {
+            return Err("no objective layers specified".to_string());
+        }
+
+        if self.layers.iter().any(|layer| layer.objectives().is_empty()) {
+            return Err("objective layer cannot be empty".to_string());
+        }
+
+        let has_invalid_weight = self.layers.iter().any(|layer| match layer {
+            ObjectiveLayer::WeightedSum(_, weights) => weights.iter().any(|weight| !weight.is_finite() || *weight < 0.),
+            _ => false,
+        });
+
+        if has_invalid_weight {
+            return Err("objective weight should be non-negative finite number".to_string());
+        }
+
+        Ok(ObjectiveCost { layers: self.layers })
+    }
+}
+
 /// Provides the way to get cost information for specific activities done by specific actor.
 pub trait ActivityCost {
     /// Returns cost to perform activity.
@@ -75,6 +166,20 @@ pub trait ActivityCost {
     fn duration(&self, _actor: &Actor, activity: &Activity, _arrival: Timestamp) -> Cost {
         activity.place.duration
     }
+
+    /// Returns operation time spent to perform activity which follows given ones in the tour.
+    /// Previous activities are iterated in reverse order, starting from the closest one. Can be
+    /// used to serve several activities at the same place in parallel. By default, previous
+    /// activities are ignored.
+    fn duration_after(
+        &self,
+        actor: &Actor,
+        _previous: &mut dyn Iterator<Item = &Activity>,
+        activity: &Activity,
+        arrival: Timestamp,
+    ) -> Cost {
+        self.duration(actor, activity, arrival)
+    }
 }
 
 /// Default activity costs.
@@ -97,6 +202,12 @@ pub trait TransportCost {
 
         distance * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
             + duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
+            + self.toll(actor, from, to, departure)
+    }
+
+    /// Returns monetary fees (e.g. tolls, congestion charges) paid for traveling between two locations.
+    fn toll(&self, _actor: &Actor, _from: Location, _to: Location, _departure: Timestamp) -> Cost {
+        0.
     }
 
     /// Returns transport time between two locations.
@@ -104,9 +215,61 @@ pub trait TransportCost {
 
     /// Returns transport distance between two locations.
     fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance;
+
+    /// Returns approximate size of routing data in bytes.
+    fn memory_size(&self) -> usize {
+        0
+    }
+
+    /// Returns locations which have known routing data from given one when routing data is sparse.
+    /// Default is None: routing data is known between all locations.
+    fn sparse_neighbors(&self, _profile: Profile, _location: Location) -> Option<Vec<Location>> {
+        None
+    }
+}
+
+/// A function which returns monetary fees for actor traveling between two locations.
+pub type TollFunc = Arc<dyn Fn(&Actor, Location, Location) -> Cost + Send + Sync>;
+
+/// Creates routing costs which add fees returned by toll function to transport cost of the given
+/// routing costs, keeping their durations and distances as is.
+pub fn create_toll_transport_cost(
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    toll_func: TollFunc,
+) -> Arc<dyn TransportCost + Send + Sync> {
+    Arc::new(TollTransportCost { transport, toll_func })
+}
+
+/// Routing costs with monetary fees.
+struct TollTransportCost {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    toll_func: TollFunc,
+}
+
+impl TransportCost for TollTransportCost {
+    fn toll(&self, actor: &Actor, from: Location, to: Location, departure: Timestamp) -> Cost {
+        self.transport.toll(actor, from, to, departure) + (self.toll_func)(actor, from, to)
+    }
+
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.transport.duration(profile, from, to, departure)
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.transport.distance(profile, from, to, departure)
+    }
+
+    fn memory_size(&self) -> usize {
+        self.transport.memory_size()
+    }
+
+    fn sparse_neighbors(&self, profile: Profile, location: Location) -> Option<Vec<Location>> {
+        self.transport.sparse_neighbors(profile, location)
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
+#[derive(Clone)]
 pub struct MatrixData {
     /// A routing profile.
     pub profile: Profile,
@@ -130,6 +293,35 @@ impl MatrixData {
     }
 }
 
+/// Returns durations which are replaced by given percentile of their distribution assuming that
+/// travel time is normally distributed with given standard deviations. Negative durations, used to
+/// mark unreachable locations, are kept as is.
+pub fn get_percentile_durations(
+    durations: &[Duration],
+    deviations: &[Duration],
+    percentile: f64,
+) -> Result<Vec<Duration>, String> {
+    if durations.len() != deviations.len() {
+        return Err("travel time deviations should have the same size as durations".to_string());
+    }
+
+    if !(percentile > 0. && percentile < 1.) {
+        return Err("percentile should be in (0, 1) range".to_string());
+    }
+
+    if deviations.iter().any(|deviation| *deviation < 0.) {
+        return Err("travel time deviations should be non-negative".to_string());
+    }
+
+    let z = get_normal_quantile(percentile);
+
+    Ok(durations
+        .iter()
+        .zip(deviations.iter())
+        .map(|(duration, deviation)| if *duration < 0. { *duration } else { (duration + z * deviation).max(0.) })
+        .collect())
+}
+
 /// Creates time agnostic or time aware routing costs based on matrix data passed.
 pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
     if costs.is_empty() {
@@ -157,6 +349,239 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
     })
 }
 
+/// A max amount of time buckets per profile.
+pub const MAX_TIME_BUCKETS: usize = 1024;
+
+/// Returns amount of time buckets of given size required to cover time range between given timestamps.
+pub fn get_time_bucket_count(start: Timestamp, end: Timestamp, bucket_size: Duration) -> usize {
+    ((end - start) / bucket_size).ceil() as usize + 1
+}
+
+/// Creates routing costs based on matrix data passed. For time aware routing of profiles with
+/// specified bucket size, durations and distances are precomputed for each time bucket between the
+/// first and the last matrix timestamps, so queries return step values for the start of the bucket
+/// instead of interpolating them. Values after the last timestamp are the same as for the last matrix.
+/// Other profiles use interpolation.
+///
+/// Please note, that each bucket keeps a full matrix, so memory usage grows as `buckets * n^2`,
+/// where `n` is amount of locations. Amount of buckets per profile is limited by [`MAX_TIME_BUCKETS`].
+///
+/// [`MAX_TIME_BUCKETS`]: constant.MAX_TIME_BUCKETS.html
+pub fn create_matrix_transport_cost_with_time_buckets(
+    costs: Vec<MatrixData>,
+    bucket_sizes: HashMap<Profile, Duration>,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    if bucket_sizes.values().any(|bucket_size| *bucket_size <= 0.) {
+        return Err("time bucket size should be positive".to_string());
+    }
+
+    let transport = create_matrix_transport_cost(costs.clone())?;
+
+    let size = (costs.first().unwrap().durations.len() as f64).sqrt().round() as usize;
+    let buckets = costs
+        .iter()
+        .filter(|matrix| matrix.timestamp.is_some())
+        .collect_group_by_key(|matrix| matrix.profile)
+        .into_iter()
+        .filter_map(|(profile, matrices)| {
+            bucket_sizes.get(&profile).map(|bucket_size| (profile, matrices, *bucket_size))
+        })
+        .map(|(profile, matrices, bucket_size)| {
+            let start = matrices.iter().filter_map(|matrix| matrix.timestamp).fold(std::f64::MAX, f64::min);
+            let end = matrices.iter().filter_map(|matrix| matrix.timestamp).fold(std::f64::MIN, f64::max);
+            let count = get_time_bucket_count(start, end, bucket_size);
+
+            if count > MAX_TIME_BUCKETS {
+                return Err(format!(
+                    "too many time buckets for profile {}: {}, max is {}",
+                    profile, count, MAX_TIME_BUCKETS
+                ));
+            }
+
+            let (durations, distances) = (0..count)
+                .map(|bucket| {
+                    let timestamp = (start + bucket as f64 * bucket_size).min(end);
+                    let locations = (0..size).flat_map(|from| (0..size).map(move |to| (from, to)));
+
+                    locations.fold((vec![], vec![]), |mut acc, (from, to)| {
+                        acc.0.push(transport.duration(profile, from, to, timestamp));
+                        acc.1.push(transport.distance(profile, from, to, timestamp));
+                        acc
+                    })
+                })
+                .unzip();
+
+            Ok((profile, TimeBuckets { start, bucket_size, durations, distances }))
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    if buckets.is_empty() {
+        return Ok(transport);
+    }
+
+    Ok(Arc::new(TimeBucketMatrixTransportCost { buckets, transport, size }))
+}
+
+/// Contains sparse routing data for specific profile: for each location, only durations and distances
+/// to some of its nearest locations are known.
+#[derive(Clone)]
+pub struct SparseMatrixData {
+    /// A routing profile.
+    pub profile: Profile,
+    /// Known destinations within travel duration and distance, the outer index is a source location.
+    pub neighbors: Vec<Vec<(Location, Duration, Distance)>>,
+}
+
+impl SparseMatrixData {
+    /// Creates `SparseMatrixData`.
+    pub fn new(profile: Profile, neighbors: Vec<Vec<(Location, Duration, Distance)>>) -> Self {
+        Self { profile, neighbors }
+    }
+}
+
+/// A function which estimates travel duration and distance between two locations which are not
+/// present in sparse routing data.
+pub type TransportFallbackFunc = Arc<dyn Fn(Profile, Location, Location) -> (Duration, Distance) + Send + Sync>;
+
+/// Creates routing costs based on sparse matrix data: durations and distances which are not present
+/// there are estimated using fallback function. Memory usage is proportional to amount of known
+/// destinations instead of squared amount of locations, so it can be used for very large problems.
+/// Job neighborhood is also built only from known destinations.
+pub fn create_sparse_transport_cost(
+    costs: Vec<SparseMatrixData>,
+    fallback: TransportFallbackFunc,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    if costs.is_empty() {
+        return Err("no sparse matrix data found".to_string());
+    }
+
+    let mut costs = costs;
+    costs.sort_by_key(|matrix| matrix.profile);
+
+    if (0..).zip(costs.iter().map(|c| c.profile)).any(|(a, b)| a != b) {
+        return Err("sparse matrix data should have unique profiles".to_string());
+    }
+
+    let size = costs.first().unwrap().neighbors.len();
+    if costs.iter().any(|matrix| matrix.neighbors.len() != size) {
+        return Err("sparse matrix data should have the same amount of locations".to_string());
+    }
+
+    let is_out_of_range = |matrix: &SparseMatrixData| {
+        matrix.neighbors.iter().flat_map(|neighbors| neighbors.iter()).any(|(location, ..)| *location >= size)
+    };
+    if costs.iter().any(is_out_of_range) {
+        return Err("sparse matrix data has unknown location".to_string());
+    }
+
+    let neighbors = costs
+        .into_iter()
+        .map(|matrix| {
+            matrix
+                .neighbors
+                .into_iter()
+                .map(|mut neighbors| {
+                    neighbors.sort_by_key(|(location, ..)| *location);
+                    neighbors.dedup_by(|(a, ..), (b, ..)| a == b);
+                    neighbors.shrink_to_fit();
+                    neighbors
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(Arc::new(SparseMatrixTransportCost { neighbors, fallback }))
+}
+
+/// Creates routing costs based on sparse matrix data with great-circle fallback: distances which are
+/// not present there are estimated using great-circle distance between location coordinates (latitude
+/// and longitude in degrees) multiplied by detour factor to account for road network, durations - using
+/// given speed in meters per second. Coordinates should be specified for each location.
+pub fn create_great_circle_sparse_transport_cost(
+    costs: Vec<SparseMatrixData>,
+    coordinates: Vec<(f64, f64)>,
+    speed: f64,
+    detour_factor: f64,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    if costs.iter().any(|matrix| matrix.neighbors.len() != coordinates.len()) {
+        return Err("amount of coordinates should match amount of locations in sparse matrix data".to_string());
+    }
+
+    create_sparse_transport_cost(costs, create_great_circle_fallback(coordinates, speed, detour_factor)?)
+}
+
+/// Creates fallback function which estimates travel duration and distance using great-circle distance.
+/// Coordinates are expected to be validated against amount of locations.
+fn create_great_circle_fallback(
+    coordinates: Vec<(f64, f64)>,
+    speed: f64,
+    detour_factor: f64,
+) -> Result<TransportFallbackFunc, String> {
+    if speed <= 0. || detour_factor <= 0. {
+        return Err("speed and detour factor should be positive".to_string());
+    }
+
+    Ok(Arc::new(move |_, from, to| {
+        let distance = get_great_circle_distance(coordinates[from], coordinates[to]) * detour_factor;
+
+        (distance / speed, distance)
+    }))
+}
+
+/// Returns great-circle distance in meters between two points using haversine formula.
+fn get_great_circle_distance((lat1, lng1): (f64, f64), (lat2, lng2): (f64, f64)) -> Distance {
+    const EARTH_RADIUS: f64 = 6_371_008.8;
+
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lng = (lng2 - lng1).to_radians();
+
+    let a = (d_lat / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.).sin().powi(2);
+
+    2. * EARTH_RADIUS * a.sqrt().atan2((1. - a).sqrt())
+}
+
+/// A sparse matrix routing costs.
+struct SparseMatrixTransportCost {
+    neighbors: Vec<Vec<Vec<(Location, Duration, Distance)>>>,
+    fallback: TransportFallbackFunc,
+}
+
+impl SparseMatrixTransportCost {
+    fn get(&self, profile: Profile, from: Location, to: Location) -> (Duration, Distance) {
+        let neighbors = self.neighbors.get(profile as usize).unwrap().get(from).unwrap();
+
+        match neighbors.binary_search_by(|(location, ..)| location.cmp(&to)) {
+            Ok(idx) => neighbors.get(idx).map(|&(_, duration, distance)| (duration, distance)).unwrap(),
+            Err(_) if from == to => (0., 0.),
+            Err(_) => (self.fallback)(profile, from, to),
+        }
+    }
+}
+
+impl TransportCost for SparseMatrixTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, _: Timestamp) -> Duration {
+        self.get(profile, from, to).0
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+        self.get(profile, from, to).1
+    }
+
+    fn memory_size(&self) -> usize {
+        let values = self.neighbors.iter().flat_map(|matrix| matrix.iter()).map(|data| data.len()).sum::<usize>();
+
+        values * std::mem::size_of::<(Location, Duration, Distance)>()
+    }
+
+    fn sparse_neighbors(&self, profile: Profile, location: Location) -> Option<Vec<Location>> {
+        self.neighbors
+            .get(profile as usize)
+            .and_then(|matrix| matrix.get(location))
+            .map(|neighbors| neighbors.iter().map(|(location, ..)| *location).collect())
+    }
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost {
     durations: Vec<Vec<Duration>>,
@@ -197,6 +622,12 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
     fn distance(&self, profile: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
         *self.distances.get(profile as usize).unwrap().get(from * self.size + to).unwrap()
     }
+
+    fn memory_size(&self) -> usize {
+        let values = self.durations.iter().chain(self.distances.iter()).map(|data| data.len()).sum::<usize>();
+
+        values * std::mem::size_of::<f64>()
+    }
 }
 
 /// A time aware matrix costs.
@@ -272,4 +703,65 @@ impl TransportCost for TimeAwareMatrixTransportCost {
             Err(matrix_idx) => *matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx).unwrap(),
         }
     }
+
+    fn memory_size(&self) -> usize {
+        let values = self
+            .costs
+            .values()
+            .flat_map(|(_, matrices)| matrices.iter())
+            .map(|matrix| matrix.durations.len() + matrix.distances.len())
+            .sum::<usize>();
+
+        values * std::mem::size_of::<f64>()
+    }
+}
+
+/// Keeps durations and distances for each time bucket.
+struct TimeBuckets {
+    start: Timestamp,
+    bucket_size: Duration,
+    durations: Vec<Vec<Duration>>,
+    distances: Vec<Vec<Distance>>,
+}
+
+impl TimeBuckets {
+    fn get_bucket(&self, timestamp: Timestamp) -> usize {
+        let bucket = ((timestamp - self.start) / self.bucket_size).floor().max(0.) as usize;
+
+        bucket.min(self.durations.len() - 1)
+    }
+}
+
+/// A time aware matrix costs with values precomputed for time buckets.
+struct TimeBucketMatrixTransportCost {
+    buckets: HashMap<Profile, TimeBuckets>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    size: usize,
+}
+
+impl TransportCost for TimeBucketMatrixTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Duration {
+        match self.buckets.get(&profile) {
+            Some(buckets) => buckets.durations[buckets.get_bucket(timestamp)][from * self.size + to],
+            None => self.transport.duration(profile, from, to, timestamp),
+        }
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, timestamp: Timestamp) -> Distance {
+        match self.buckets.get(&profile) {
+            Some(buckets) => buckets.distances[buckets.get_bucket(timestamp)][from * self.size + to],
+            None => self.transport.distance(profile, from, to, timestamp),
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        let values = self
+            .buckets
+            .values()
+            .flat_map(|buckets| buckets.durations.iter().chain(buckets.distances.iter()))
+            .map(|data| data.len())
+            .sum::<usize>();
+
+        values * std::mem::size_of::<f64>() + self.transport.memory_size()
+    }
 }
@@ -23,9 +23,17 @@ pub struct Costs {
     pub per_service_time: f64,
 }
 
-/// Represents driver detail (reserved for future use).
+/// Represents driver detail: specifies how driver reaches the vehicle.
 #[derive(Clone, Hash, Eq, PartialEq)]
-pub struct DriverDetail {}
+pub struct DriverDetail {
+    /// A place where driver starts before taking the vehicle. Its time interval specifies
+    /// driver's availability.
+    pub start: Option<VehiclePlace>,
+
+    /// Time interval when driver can take the vehicle at its start place, e.g. the earliest time
+    /// is driver's availability time plus time needed to reach the vehicle.
+    pub time: TimeInterval,
+}
 
 /// Represents a driver, person who drives Vehicle.
 /// Introduced to allow the following scenarios:
@@ -56,11 +64,14 @@ pub struct VehiclePlace {
 /// Represents a vehicle detail (vehicle shift).
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct VehicleDetail {
-    /// A place where vehicle starts.
+    /// A place where vehicle starts. NOTE: vehicle without start place is not yet supported.
     pub start: Option<VehiclePlace>,
 
-    /// A place where vehicle ends.
+    /// A place where vehicle ends. If it is not specified, the tour is open: it ends at the last job.
     pub end: Option<VehiclePlace>,
+
+    /// A driver detail, if driver starts at a different place than vehicle.
+    pub driver: Option<DriverDetail>,
 }
 
 /// Represents a vehicle.
@@ -89,6 +100,9 @@ pub struct ActorDetail {
 
     /// Time window when actor allowed to work.
     pub time: TimeWindow,
+
+    /// A driver detail, if driver starts at a different place than vehicle.
+    pub driver: Option<DriverDetail>,
 }
 
 /// Represents an actor.
@@ -138,16 +152,18 @@ impl Fleet {
         let mut actors: Vec<Arc<Actor>> = Default::default();
         vehicles.iter().for_each(|vehicle| {
             vehicle.details.iter().for_each(|detail| {
+                let start = get_actor_start(detail);
                 actors.push(Arc::new(Actor {
                     vehicle: vehicle.clone(),
                     driver: drivers.first().unwrap().clone(),
                     detail: ActorDetail {
-                        start: detail.start.clone(),
-                        end: detail.end.clone(),
                         time: TimeWindow {
-                            start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
+                            start: start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
                             end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(std::f64::MAX),
                         },
+                        start,
+                        end: detail.end.clone(),
+                        driver: detail.driver.clone(),
                     },
                 }));
             });
@@ -163,6 +179,29 @@ impl Fleet {
     }
 }
 
+/// Returns actor's start place: vehicle cannot depart earlier than driver is able to take it.
+/// If driver cannot take the vehicle before its latest start or end time, the earliest time is
+/// clamped to keep time window valid, so such driver start is expected to be rejected by validation.
+fn get_actor_start(detail: &VehicleDetail) -> Option<VehiclePlace> {
+    let driver_earliest = detail.driver.as_ref().and_then(|driver| driver.time.earliest);
+    let end_latest = detail.end.as_ref().and_then(|end| end.time.latest);
+
+    detail.start.clone().map(|start| {
+        let earliest = match (start.time.earliest, driver_earliest) {
+            (Some(vehicle), Some(driver)) => Some(vehicle.max(driver)),
+            (vehicle, driver) => vehicle.or(driver),
+        };
+        let earliest = start
+            .time
+            .latest
+            .into_iter()
+            .chain(end_latest)
+            .fold(earliest, |earliest, latest| earliest.map(|earliest| earliest.min(latest)));
+
+        VehiclePlace { time: TimeInterval { earliest, latest: start.time.latest }, ..start }
+    })
+}
+
 impl PartialEq<Actor> for Actor {
     fn eq(&self, other: &Actor) -> bool {
         std::ptr::eq(&*self, &*other)
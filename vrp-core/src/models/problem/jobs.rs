@@ -4,10 +4,11 @@ mod jobs_test;
 
 use crate::models::common::*;
 use crate::models::problem::{Costs, Fleet, TransportCost};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use std::cell::UnsafeCell;
 use std::cmp::Ordering::Less;
 use std::hash::{Hash, Hasher};
+use std::iter::once;
 use std::sync::{Arc, Weak};
 
 /// Represents a job variant.
@@ -67,6 +68,7 @@ pub struct Place {
 }
 
 /// Represents a job which should be performed once but actual place/time might vary.
+#[repr(C)]
 pub struct Single {
     /// Specifies job details: where and when it can be performed.
     pub places: Vec<Place>,
@@ -154,6 +156,7 @@ impl Multi {
     /// Wraps given multi job into [`Arc`] adding reference to it from all sub-jobs.
     pub fn bind(multi: Self) -> Arc<Self> {
         // NOTE: layout must be identical
+        #[repr(C)]
         struct SingleConstruct {
             pub places: UnsafeCell<Vec<Place>>,
             pub dimens: UnsafeCell<Dimensions>,
@@ -179,16 +182,21 @@ impl Multi {
 
 type JobIndex = HashMap<Job, (Vec<(Job, Cost)>, HashMap<Job, Cost>, Cost)>;
 
+/// Maps locations to jobs which have them, jobs without location are kept separately.
+type LocationIndex = (HashMap<Location, Vec<Job>>, Vec<Job>);
+
 /// Stores all jobs taking into account their neighborhood.
 pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<Profile, JobIndex>,
+    positions: HashMap<Job, usize>,
 }
 
 impl Jobs {
     /// Creates a new [`Jobs`].
     pub fn new(fleet: &Fleet, jobs: Vec<Job>, transport: &Arc<dyn TransportCost + Send + Sync>) -> Jobs {
-        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport) }
+        let positions = jobs.iter().cloned().enumerate().map(|(idx, job)| (job, idx)).collect();
+        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport), positions }
     }
 
     /// Returns all jobs in original order.
@@ -207,9 +215,10 @@ impl Jobs {
         self.index.get(&profile).unwrap().get(job).unwrap().0.iter()
     }
 
-    /// Returns cost distance between two jobs.
+    /// Returns cost distance between two jobs. When routing data is sparse, only distances to
+    /// neighbors are known, other jobs are considered as unreachable.
     pub fn distance(&self, profile: Profile, from: &Job, to: &Job, _: Timestamp) -> Cost {
-        *self.index.get(&profile).unwrap().get(from).unwrap().1.get(to).unwrap()
+        self.index.get(&profile).unwrap().get(from).unwrap().1.get(to).cloned().unwrap_or(UNREACHABLE_COST)
     }
 
     /// Returns job rank as relative cost from any vehicle's start position.
@@ -217,6 +226,12 @@ impl Jobs {
         self.index.get(&profile).unwrap().get(job).unwrap().2
     }
 
+    /// Returns job position in original order. Unlike job hash, which is based on its address, the
+    /// position is stable between runs, so it can be used to order jobs in reproducible way.
+    pub fn position(&self, job: &Job) -> Option<usize> {
+        self.positions.get(job).cloned()
+    }
+
     /// Returns amount of jobs.
     pub fn size(&self) -> usize {
         self.jobs.len()
@@ -276,12 +291,24 @@ fn create_index(
             .map(|s| s.unwrap())
             .collect();
 
+        // NOTE with sparse routing data, only jobs at known destinations are considered as neighbors
+        let location_index = create_location_index(profile, jobs.as_slice(), transport.as_ref());
+
         // create job index
         let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut sorted_job_costs: Vec<(Job, Cost)> = jobs
-                .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport.as_ref(), &job, j)))
+            let candidates: Box<dyn Iterator<Item = Job>> = match location_index.as_ref() {
+                Some(location_index) => {
+                    Box::new(get_sparse_candidates(profile, &job, location_index, transport.as_ref()).into_iter())
+                }
+                None => Box::new(jobs.iter().cloned()),
+            };
+
+            let mut sorted_job_costs: Vec<(Job, Cost)> = candidates
+                .filter(|j| *j != job)
+                .map(|j| {
+                    let cost = get_cost_between_jobs(profile, avg_costs, transport.as_ref(), &job, &j);
+                    (j, cost)
+                })
                 .collect();
             sorted_job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
 
@@ -303,6 +330,55 @@ fn create_index(
     })
 }
 
+/// Creates location index if routing data is sparse.
+fn create_location_index(
+    profile: Profile,
+    jobs: &[Job],
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Option<LocationIndex> {
+    let is_sparse = jobs
+        .iter()
+        .flat_map(get_job_locations)
+        .flatten()
+        .next()
+        .and_then(|location| transport.sparse_neighbors(profile, location))
+        .is_some();
+
+    if !is_sparse {
+        return None;
+    }
+
+    Some(jobs.iter().fold((HashMap::new(), vec![]), |(mut located, mut unlocated), job| {
+        get_job_locations(job).for_each(|location| match location {
+            Some(location) => located.entry(location).or_insert_with(Vec::new).push(job.clone()),
+            None => unlocated.push(job.clone()),
+        });
+
+        (located, unlocated)
+    }))
+}
+
+/// Returns jobs which are located at job's locations or their known destinations and jobs without location.
+fn get_sparse_candidates(
+    profile: Profile,
+    job: &Job,
+    location_index: &LocationIndex,
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Vec<Job> {
+    let (located, unlocated) = location_index;
+    let mut visited = HashSet::new();
+
+    get_job_locations(job)
+        .flatten()
+        .flat_map(|location| once(location).chain(transport.sparse_neighbors(profile, location).unwrap_or_default()))
+        .filter_map(|location| located.get(&location))
+        .flat_map(|jobs| jobs.iter())
+        .chain(unlocated.iter())
+        .filter(|candidate| visited.insert((*candidate).clone()))
+        .cloned()
+        .collect()
+}
+
 fn get_cost_between_locations(
     profile: Profile,
     costs: &Costs,
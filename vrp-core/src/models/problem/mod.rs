@@ -13,6 +13,9 @@ pub use self::jobs::*;
 mod fleet;
 pub use self::fleet::*;
 
+mod statistics;
+pub use self::statistics::*;
+
 /// An actual objective on solution type.
 pub type TargetObjective = Box<dyn Objective<Solution = InsertionContext> + Send + Sync>;
 
@@ -0,0 +1,219 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/statistics_test.rs"]
+mod statistics_test;
+
+use crate::models::common::*;
+use crate::models::problem::{Job, Single};
+use crate::models::Problem;
+use std::ops::{Add, Sub};
+
+/// Max amount of locations used to estimate spatial dispersion.
+const MAX_LOCATIONS: usize = 1000;
+
+/// Specifies problem instance descriptors which characterize its difficulty.
+#[derive(Clone, Debug)]
+pub struct ProblemStatistics {
+    /// Amount of jobs.
+    pub jobs: usize,
+    /// Amount of vehicles.
+    pub vehicles: usize,
+    /// An average distance between job locations.
+    pub average_distance: f64,
+    /// A ratio of average distance to the nearest job location and average distance between job
+    /// locations: values close to zero mean clustered jobs, close to one - uniformly dispersed.
+    pub dispersion: f64,
+    /// An average share of planning horizon excluded by job time windows: zero means no time
+    /// windows, close to one - very tight time windows.
+    pub time_window_tightness: f64,
+    /// A ratio of total job demand to total fleet capacity, if capacity is defined.
+    pub demand_ratio: Option<f64>,
+    /// Amount of distinct vehicle types defined by profile, costs and capacity.
+    pub vehicle_types: usize,
+    /// A fleet heterogeneity: zero means that all vehicles are of the same type, one - that all
+    /// vehicles are different.
+    pub fleet_heterogeneity: f64,
+}
+
+impl std::fmt::Display for ProblemStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "jobs: {}, vehicles: {}, average distance: {:.2}, dispersion: {:.3}, time window tightness: {:.3}, \
+             demand ratio: {}, vehicle types: {}, fleet heterogeneity: {:.3}",
+            self.jobs,
+            self.vehicles,
+            self.average_distance,
+            self.dispersion,
+            self.time_window_tightness,
+            self.demand_ratio.map_or("n/a".to_string(), |ratio| format!("{:.3}", ratio)),
+            self.vehicle_types,
+            self.fleet_heterogeneity
+        )
+    }
+}
+
+/// Calculates problem statistics. Demand and capacity are estimated using either single or multi
+/// dimensional load, depending on which one is used by the problem.
+pub fn get_problem_statistics(problem: &Problem) -> ProblemStatistics {
+    let singles = problem.jobs.all().flat_map(get_singles).collect::<Vec<_>>();
+    let (average_distance, dispersion) = get_spatial_statistics(problem, singles.as_slice());
+    let (vehicle_types, fleet_heterogeneity) = get_fleet_statistics(problem);
+
+    ProblemStatistics {
+        jobs: problem.jobs.size(),
+        vehicles: problem.fleet.vehicles.len(),
+        average_distance,
+        dispersion,
+        time_window_tightness: get_time_window_tightness(problem, singles.as_slice()),
+        demand_ratio: get_demand_ratio::<SingleDimLoad>(problem, singles.as_slice())
+            .or_else(|| get_demand_ratio::<MultiDimLoad>(problem, singles.as_slice())),
+        vehicle_types,
+        fleet_heterogeneity,
+    }
+}
+
+fn get_singles(job: Job) -> Vec<Job> {
+    match job {
+        Job::Single(_) => vec![job],
+        Job::Multi(multi) => multi.jobs.iter().map(|single| Job::Single(single.clone())).collect(),
+    }
+}
+
+fn as_single(job: &Job) -> &Single {
+    job.as_single().expect("single job expected")
+}
+
+fn get_spatial_statistics(problem: &Problem, singles: &[Job]) -> (f64, f64) {
+    let mut locations = singles
+        .iter()
+        .flat_map(|job| as_single(job).places.iter().filter_map(|place| place.location))
+        .collect::<Vec<_>>();
+    locations.sort_unstable();
+    locations.dedup();
+
+    let step = (locations.len() / MAX_LOCATIONS).max(1);
+    let locations = locations.into_iter().step_by(step).collect::<Vec<_>>();
+
+    if locations.len() < 2 {
+        return (0., 0.);
+    }
+
+    let profile = problem.fleet.profiles.first().cloned().unwrap_or_default();
+    let (total, nearest) = locations.iter().fold((0., 0.), |(total, nearest), &from| {
+        let distances = locations
+            .iter()
+            .filter(|&&to| to != from)
+            .map(|&to| problem.transport.distance(profile, from, to, 0.))
+            .collect::<Vec<_>>();
+
+        (total + distances.iter().sum::<f64>(), nearest + distances.iter().cloned().fold(f64::MAX, f64::min))
+    });
+
+    let average_distance = total / (locations.len() * (locations.len() - 1)) as f64;
+    let average_nearest = nearest / locations.len() as f64;
+    let dispersion = if average_distance > 0. { average_nearest / average_distance } else { 0. };
+
+    (average_distance, dispersion)
+}
+
+fn get_time_window_tightness(problem: &Problem, singles: &[Job]) -> f64 {
+    let horizon = problem.fleet.actors.iter().fold(None, |acc: Option<TimeWindow>, actor| {
+        let time = &actor.detail.time;
+        Some(acc.map_or_else(|| time.clone(), |acc| TimeWindow::new(acc.start.min(time.start), acc.end.max(time.end))))
+    });
+
+    let horizon = match horizon {
+        Some(horizon) if horizon.end > horizon.start && horizon.end < f64::MAX => horizon,
+        _ => return 0.,
+    };
+
+    if singles.is_empty() {
+        return 0.;
+    }
+
+    let total = singles
+        .iter()
+        .map(|job| {
+            let coverage = as_single(job)
+                .places
+                .iter()
+                .map(|place| {
+                    if place.times.is_empty() {
+                        return 1.;
+                    }
+
+                    place
+                        .times
+                        .iter()
+                        .map(|time| {
+                            let time = time.to_time_window(horizon.start);
+                            (time.end.min(horizon.end) - time.start.max(horizon.start)).max(0.)
+                        })
+                        .sum::<f64>()
+                        / (horizon.end - horizon.start)
+                })
+                .fold(0., f64::max);
+
+            1. - coverage.min(1.)
+        })
+        .sum::<f64>();
+
+    total / singles.len() as f64
+}
+
+fn get_demand_ratio<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    problem: &Problem,
+    singles: &[Job],
+) -> Option<f64> {
+    let capacity = problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| CapacityDimension::<T>::get_capacity(&vehicle.dimens).cloned())
+        .fold(None, |acc: Option<T>, capacity| Some(acc.map_or(capacity, |acc| acc + capacity)))?;
+
+    // NOTE dynamic demand is counted only once, on pickup side
+    let demand = singles
+        .iter()
+        .filter_map(|job| DemandDimension::<T>::get_demand(&as_single(job).dimens))
+        .fold(T::default(), |acc, demand| acc + demand.pickup.0 + demand.pickup.1 + demand.delivery.0);
+
+    Some(demand.ratio(&capacity)).filter(|ratio| ratio.is_finite())
+}
+
+fn get_fleet_statistics(problem: &Problem) -> (usize, f64) {
+    let mut types = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            let costs = &vehicle.costs;
+            let costs = [
+                costs.fixed,
+                costs.per_distance,
+                costs.per_driving_time,
+                costs.per_waiting_time,
+                costs.per_service_time,
+            ]
+            .iter()
+            .map(|cost| cost.to_bits())
+            .collect::<Vec<_>>();
+
+            let dimens = &vehicle.dimens;
+            let capacity = (
+                CapacityDimension::<SingleDimLoad>::get_capacity(dimens).cloned(),
+                CapacityDimension::<MultiDimLoad>::get_capacity(dimens).cloned(),
+            );
+
+            (vehicle.profile, costs, capacity)
+        })
+        .collect::<Vec<_>>();
+    types.sort();
+    types.dedup();
+
+    let vehicles = problem.fleet.vehicles.len();
+    let vehicle_types = types.len();
+    let heterogeneity = if vehicles > 1 { (vehicle_types - 1) as f64 / (vehicles - 1) as f64 } else { 0. };
+
+    (vehicle_types, heterogeneity)
+}
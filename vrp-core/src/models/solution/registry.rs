@@ -12,6 +12,7 @@ pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    groups: Arc<Vec<(usize, Vec<Arc<Actor>>)>>,
     random: Arc<dyn Random + Send + Sync>,
 }
 
@@ -24,7 +25,10 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        let all = fleet.actors.to_vec();
+        let groups = Arc::new(create_groups(&all, &index));
+
+        Self { available: fleet.groups.clone(), index, all, groups, random }
     }
 
     /// Removes an actor from the list of available actors.
@@ -51,11 +55,17 @@ impl Registry {
 
     /// Returns next available actors from each different type.
     pub fn next(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.iter().flat_map(move |(_, set)| {
-            // NOTE pick a random actor from set of available actors.
-            let skip_amount = if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize };
-            set.iter().skip(skip_amount).take(1).cloned()
-        })
+        // NOTE groups and their actors are iterated in fleet order, not in order of hash containers,
+        // which depends on actor addresses, so the same random values give the same actors.
+        self.groups
+            .iter()
+            .filter_map(move |(group_id, actors)| self.available.get(group_id).map(|set| (actors, set)))
+            .flat_map(move |(actors, set)| {
+                // NOTE pick a random actor from set of available actors.
+                let skip_amount =
+                    if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize };
+                actors.iter().filter(move |actor| set.contains(*actor)).skip(skip_amount).take(1).cloned()
+            })
     }
 
     /// Creates a deep copy of registry.
@@ -64,6 +74,7 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            groups: self.groups.clone(),
             random: self.random.clone(),
         }
     }
@@ -86,7 +97,31 @@ impl Registry {
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            groups: Arc::new(
+                self.groups
+                    .iter()
+                    .map(|(group_id, actors)| {
+                        (*group_id, actors.iter().filter(|actor| filter(actor.as_ref())).cloned().collect())
+                    })
+                    .collect(),
+            ),
             random: self.random.clone(),
         }
     }
 }
+
+/// Creates actor groups sorted by group id with actors in fleet order.
+fn create_groups(all: &[Arc<Actor>], index: &HashMap<Arc<Actor>, usize>) -> Vec<(usize, Vec<Arc<Actor>>)> {
+    let mut groups = all
+        .iter()
+        .filter_map(|actor| index.get(actor).map(|group_id| (*group_id, actor.clone())))
+        .fold(HashMap::<usize, Vec<_>>::new(), |mut acc, (group_id, actor)| {
+            acc.entry(group_id).or_default().push(actor);
+            acc
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+    groups.sort_by_key(|(group_id, _)| *group_id);
+
+    groups
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/analysis/landscape_test.rs"]
+mod landscape_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::heuristics::InsertionContext;
+use crate::solver::mutation::LocalOperator;
+use crate::solver::RefinementContext;
+
+/// Specifies fitness landscape characteristics estimated from a random walk.
+pub struct LandscapeAnalysis {
+    /// Fitness values observed during the walk, starting from initial solution.
+    pub walk: Vec<f64>,
+    /// Autocorrelation estimates for lags starting from one.
+    pub autocorrelation: Vec<f64>,
+    /// Correlation length: an average distance (in steps) between uncorrelated solutions. Bigger
+    /// values mean smoother landscape.
+    pub correlation_length: f64,
+    /// Ruggedness as a share of steps which change direction of fitness change: values close to
+    /// zero mean smooth landscape, close to one - very rugged.
+    pub ruggedness: f64,
+}
+
+/// Estimates fitness landscape characteristics using a random walk over neighborhood defined by
+/// local search operator: each step applies the operator to the current solution and moves to the
+/// resulting one without any acceptance criteria. Steps where operator cannot find a move are skipped.
+pub fn analyze_landscape(
+    refinement_ctx: &RefinementContext,
+    insertion_ctx: &InsertionContext,
+    operator: &(dyn LocalOperator + Send + Sync),
+    walk_length: usize,
+    max_lag: usize,
+) -> LandscapeAnalysis {
+    let objective = refinement_ctx.problem.objective.as_ref();
+    let max_attempts = walk_length * 2;
+
+    let mut current = insertion_ctx.deep_copy();
+    let mut walk = vec![objective.fitness(&current)];
+
+    for _ in 0..max_attempts {
+        if walk.len() > walk_length {
+            break;
+        }
+
+        if let Some(next) = operator.explore(refinement_ctx, &current) {
+            walk.push(objective.fitness(&next));
+            current = next;
+        }
+    }
+
+    let autocorrelation = (1..=max_lag).filter_map(|lag| estimate_autocorrelation(walk.as_slice(), lag)).collect();
+
+    LandscapeAnalysis {
+        correlation_length: get_correlation_length(walk.as_slice()),
+        ruggedness: get_ruggedness(walk.as_slice()),
+        autocorrelation,
+        walk,
+    }
+}
+
+/// Estimates autocorrelation of the series for given lag. Returns `None` when series is too short
+/// or has no variance.
+pub fn estimate_autocorrelation(series: &[f64], lag: usize) -> Option<f64> {
+    if lag == 0 || series.len() <= lag {
+        return None;
+    }
+
+    let size = series.len() as f64;
+    let mean = series.iter().sum::<f64>() / size;
+    let variance = series.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / size;
+
+    if variance == 0. {
+        return None;
+    }
+
+    let covariance = series.iter().zip(series.iter().skip(lag)).map(|(a, b)| (a - mean) * (b - mean)).sum::<f64>()
+        / (size - lag as f64);
+
+    Some(covariance / variance)
+}
+
+fn get_correlation_length(walk: &[f64]) -> f64 {
+    match estimate_autocorrelation(walk, 1).map(f64::abs) {
+        Some(r1) if r1 >= 1. => f64::INFINITY,
+        Some(r1) if r1 > 0. => -1. / r1.ln(),
+        _ => 0.,
+    }
+}
+
+fn get_ruggedness(walk: &[f64]) -> f64 {
+    let directions = walk
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|change| *change != 0.)
+        .map(|change| change > 0.)
+        .collect::<Vec<_>>();
+
+    if directions.len() < 2 {
+        return 0.;
+    }
+
+    let turns = directions.windows(2).filter(|pair| pair[0] != pair[1]).count();
+
+    turns as f64 / (directions.len() - 1) as f64
+}
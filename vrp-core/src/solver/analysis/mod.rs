@@ -0,0 +1,4 @@
+//! This module contains utilities to analyze problem instances and search behavior.
+
+mod landscape;
+pub use self::landscape::*;
@@ -0,0 +1,214 @@
+//! A module which contains logic to split total time limit across solver phases.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/budget_test.rs"]
+mod budget_test;
+
+use crate::utils::{compare_floats, Timer};
+use std::cmp::Ordering;
+use std::sync::RwLock;
+
+/// Specifies a solver phase which has its own share of total time budget.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BudgetPhase {
+    /// Initial solutions construction.
+    Construction,
+    /// Main search.
+    Search,
+    /// Post-optimization of the best known solution.
+    PostOptimization,
+}
+
+/// Contains time budget allocation and its actual consumption by specific solver phase.
+#[derive(Clone, Debug)]
+pub struct PhaseConsumption {
+    /// A solver phase.
+    pub phase: BudgetPhase,
+    /// Time allocated to the phase in seconds.
+    pub allocated: f64,
+    /// Time consumed by the phase in seconds.
+    pub consumed: f64,
+}
+
+/// Splits total time limit across solver phases using their shares and tracks actual time consumption.
+/// Time left unused by finished (or skipped) phase is reallocated to the next phases proportionally
+/// to their shares.
+pub struct TimeBudget {
+    total_in_secs: f64,
+    shares: [f64; 3],
+    state: RwLock<BudgetState>,
+}
+
+struct BudgetState {
+    active: Option<(BudgetPhase, Timer)>,
+    finished: [Option<(f64, f64)>; 3],
+}
+
+impl TimeBudget {
+    /// Creates a new instance of `TimeBudget` with total time limit and shares of construction,
+    /// search and post-optimization phases. Shares are normalized, so they can be specified in
+    /// any scale.
+    pub fn new(total_in_secs: f64, shares: (f64, f64, f64)) -> Result<Self, String> {
+        let shares = [shares.0, shares.1, shares.2];
+
+        if compare_floats(total_in_secs, 0.) != Ordering::Greater {
+            return Err("time budget should be positive".to_string());
+        }
+
+        if shares.iter().any(|share| !share.is_finite() || *share < 0.) {
+            return Err("time budget shares should be non-negative".to_string());
+        }
+
+        let total_shares = shares.iter().sum::<f64>();
+        if compare_floats(total_shares, 0.) != Ordering::Greater {
+            return Err("at least one time budget share should be positive".to_string());
+        }
+
+        Ok(Self {
+            total_in_secs,
+            shares: [shares[0] / total_shares, shares[1] / total_shares, shares[2] / total_shares],
+            state: RwLock::new(BudgetState { active: None, finished: [None; 3] }),
+        })
+    }
+
+    /// Starts given phase. Active phase, if any, is finished.
+    pub fn start(&self, phase: BudgetPhase) {
+        let mut state = self.state.write().unwrap();
+
+        self.finish_active(&mut state);
+
+        if state.finished[get_index(phase)].is_none() {
+            state.active = Some((phase, Timer::start()));
+        }
+    }
+
+    /// Finishes active phase, if any.
+    pub fn finish(&self) {
+        let mut state = self.state.write().unwrap();
+
+        self.finish_active(&mut state);
+    }
+
+    /// Skips given phase if it was not started, so its share is given to other phases.
+    pub fn skip(&self, phase: BudgetPhase) {
+        let mut state = self.state.write().unwrap();
+
+        let is_active = state.active.as_ref().map_or(false, |(active, _)| *active == phase);
+        let index = get_index(phase);
+
+        if !is_active && state.finished[index].is_none() {
+            state.finished[index] = Some((0., 0.));
+        }
+    }
+
+    /// Returns time in seconds allocated to given phase.
+    pub fn get_allocated(&self, phase: BudgetPhase) -> f64 {
+        let state = self.state.read().unwrap();
+
+        self.get_allocated_impl(&state, phase)
+    }
+
+    /// Returns time in seconds consumed by given phase.
+    pub fn get_consumed(&self, phase: BudgetPhase) -> f64 {
+        let state = self.state.read().unwrap();
+
+        get_consumed_impl(&state, phase)
+    }
+
+    /// Returns true if given phase has consumed its allocated time or it is already finished.
+    pub fn is_exhausted(&self, phase: BudgetPhase) -> bool {
+        let state = self.state.read().unwrap();
+
+        if state.finished[get_index(phase)].is_some() {
+            return true;
+        }
+
+        get_consumed_impl(&state, phase) >= self.get_allocated_impl(&state, phase)
+    }
+
+    /// Returns a relative estimation of the phase's time consumption. Value is in the `[0, 1]` range.
+    pub fn estimate(&self, phase: BudgetPhase) -> f64 {
+        let state = self.state.read().unwrap();
+
+        let allocated = self.get_allocated_impl(&state, phase);
+        if compare_floats(allocated, 0.) == Ordering::Greater {
+            (get_consumed_impl(&state, phase) / allocated).min(1.)
+        } else {
+            1.
+        }
+    }
+
+    /// Returns time allocation and consumption of all phases.
+    pub fn get_consumption(&self) -> Vec<PhaseConsumption> {
+        let state = self.state.read().unwrap();
+
+        get_phases()
+            .iter()
+            .map(|&phase| PhaseConsumption {
+                phase,
+                allocated: self.get_allocated_impl(&state, phase),
+                consumed: get_consumed_impl(&state, phase),
+            })
+            .collect()
+    }
+
+    fn finish_active(&self, state: &mut BudgetState) {
+        if let Some((phase, timer)) = state.active.take() {
+            let allocated = self.get_allocated_impl(state, phase);
+            state.finished[get_index(phase)] = Some((allocated, timer.elapsed_secs_as_f64()));
+        }
+    }
+
+    fn get_allocated_impl(&self, state: &BudgetState, phase: BudgetPhase) -> f64 {
+        let index = get_index(phase);
+
+        if let Some((allocated, _)) = state.finished[index] {
+            return allocated;
+        }
+
+        let consumed = state.finished.iter().flatten().map(|(_, consumed)| *consumed).sum::<f64>();
+        let remaining = (self.total_in_secs - consumed).max(0.);
+
+        let unfinished = (0..self.shares.len()).filter(|idx| state.finished[*idx].is_none());
+        let unfinished_shares = unfinished.map(|idx| self.shares[idx]).sum::<f64>();
+
+        // NOTE when all unfinished phases have zero share, each of them can use the remaining time
+        if compare_floats(unfinished_shares, 0.) == Ordering::Greater {
+            remaining * self.shares[index] / unfinished_shares
+        } else {
+            remaining
+        }
+    }
+}
+
+impl std::fmt::Display for PhaseConsumption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.phase {
+            BudgetPhase::Construction => "construction",
+            BudgetPhase::Search => "search",
+            BudgetPhase::PostOptimization => "post-optimization",
+        };
+
+        write!(f, "{}: {:.3}s of {:.3}s", name, self.consumed, self.allocated)
+    }
+}
+
+fn get_consumed_impl(state: &BudgetState, phase: BudgetPhase) -> f64 {
+    match (&state.active, state.finished[get_index(phase)]) {
+        (_, Some((_, consumed))) => consumed,
+        (Some((active, timer)), _) if *active == phase => timer.elapsed_secs_as_f64(),
+        _ => 0.,
+    }
+}
+
+fn get_index(phase: BudgetPhase) -> usize {
+    match phase {
+        BudgetPhase::Construction => 0,
+        BudgetPhase::Search => 1,
+        BudgetPhase::PostOptimization => 2,
+    }
+}
+
+fn get_phases() -> [BudgetPhase; 3] {
+    [BudgetPhase::Construction, BudgetPhase::Search, BudgetPhase::PostOptimization]
+}
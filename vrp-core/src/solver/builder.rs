@@ -1,13 +1,14 @@
+use crate::construction::constraints::JobCancellation;
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
 use crate::models::{Problem, Solution};
 use crate::solver::evolution::EvolutionConfig;
-use crate::solver::hyper::HyperHeuristic;
+use crate::solver::hyper::{HyperHeuristic, MutationGroup, MutationTracer, StaticSelective};
 use crate::solver::mutation::*;
-use crate::solver::population::Population;
+use crate::solver::population::{get_default_population, Population};
 use crate::solver::termination::*;
-use crate::solver::{Solver, Telemetry};
-use crate::utils::{Environment, TimeQuota};
+use crate::solver::{IterationCallback, MemoryGuard, Solver, Telemetry, TelemetryMode, TimeBudget, WeightSchedule};
+use crate::utils::{Environment, Parallelism, TimeQuota};
 use std::sync::Arc;
 
 /// Provides configurable way to build Vehile Routing Problem [`Solver`] instance using fluent
@@ -56,8 +57,39 @@ pub struct Builder {
     /// A cost variation parameters for termination criteria.
     pub cost_variation: Option<(usize, f64)>,
 
+    /// A target cost for termination criteria.
+    pub target_cost: Option<f64>,
+
+    /// A min improvement parameters (generations and epsilon) for termination criteria.
+    pub min_improvement: Option<(usize, f64)>,
+
+    /// Specifies whether evolution should stop when all jobs are assigned.
+    pub all_assigned: bool,
+
+    /// Specifies whether all goal termination criteria should be met to stop evolution.
+    pub all_goals: bool,
+
+    /// Time budget shares of construction, search and post-optimization phases.
+    pub time_budget: Option<(f64, f64, f64)>,
+
+    /// A mutation group used by static selective hyper-heuristic.
+    pub mutation_group: Option<MutationGroup>,
+
+    /// Ruin groups with their weights used by default ruin and recreate mutation.
+    pub ruin_weights: Option<Vec<RuinGroup>>,
+
+    /// Recreate methods with their weights used by default ruin and recreate mutation.
+    pub recreate_weights: Option<Vec<(Box<dyn Recreate + Send + Sync>, usize)>>,
+
+    /// A callback which is called each time a new best individual is found.
+    pub iteration_callback: Option<IterationCallback>,
+
     /// An evolution configuration..
     pub config: EvolutionConfig,
+
+    has_custom_parallelism: bool,
+    has_custom_population: bool,
+    has_custom_hyper: bool,
 }
 
 impl Builder {
@@ -67,7 +99,19 @@ impl Builder {
             max_generations: None,
             max_time: None,
             cost_variation: None,
+            target_cost: None,
+            min_improvement: None,
+            all_assigned: false,
+            all_goals: false,
+            time_budget: None,
+            mutation_group: None,
+            ruin_weights: None,
+            recreate_weights: None,
+            iteration_callback: None,
             config: EvolutionConfig::new(problem, environment),
+            has_custom_parallelism: false,
+            has_custom_population: false,
+            has_custom_hyper: false,
         }
     }
 }
@@ -79,6 +123,23 @@ impl Builder {
         self
     }
 
+    /// Sets callback which receives a snapshot of the best individual each time a new best individual
+    /// is found, e.g. to stream intermediate results while solver is running. Snapshot contains its
+    /// cost and amount of unassigned jobs, solution itself is created on demand. Default is None.
+    pub fn with_iteration_callback(mut self, callback: IterationCallback) -> Self {
+        self.iteration_callback = Some(callback);
+        self
+    }
+
+    /// Sets data parallelism settings, e.g. to pin solver to its own thread pools with fixed amount of
+    /// threads. Default is taken from environment. As default population and hyper-heuristic depend on
+    /// environment, they are recreated on build unless custom ones are specified.
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.config.environment = Arc::new(Environment { parallelism, ..self.config.environment.as_ref().clone() });
+        self.has_custom_parallelism = true;
+        self
+    }
+
     /// Sets max generations to be run by evolution. Default is 3000.
     pub fn with_max_generations(mut self, limit: Option<usize>) -> Self {
         self.max_generations = limit;
@@ -91,12 +152,100 @@ impl Builder {
         self
     }
 
+    /// Sets target cost termination criteria: evolution stops when the best known solution's cost
+    /// is not higher than target. Default is None.
+    pub fn with_target_cost(mut self, target: Option<f64>) -> Self {
+        self.target_cost = target;
+        self
+    }
+
+    /// Sets min improvement termination criteria: evolution stops when relative change of the best
+    /// known solution's cost over given amount of generations is below epsilon. Default is None.
+    pub fn with_min_improvement(mut self, improvement: Option<(usize, f64)>) -> Self {
+        self.min_improvement = improvement;
+        self
+    }
+
+    /// Sets termination criteria which stops evolution when the best known solution has no
+    /// unassigned jobs. Default is false.
+    pub fn with_all_assigned(mut self, all_assigned: bool) -> Self {
+        self.all_assigned = all_assigned;
+        self
+    }
+
+    /// Sets how goal termination criteria (cost variation, target cost, min improvement and all
+    /// assigned) are combined: when true, all of them should be met to stop evolution, otherwise any
+    /// of them is enough. Max generations and max time are always applied as limits. Default is false.
+    pub fn with_all_goals(mut self, all_goals: bool) -> Self {
+        self.all_goals = all_goals;
+        self
+    }
+
     /// Sets max running time limit for evolution. Default is 300 seconds.
     pub fn with_max_time(mut self, limit: Option<usize>) -> Self {
         self.max_time = limit;
         self
     }
 
+    /// Sets time budget shares of construction, search and post-optimization phases which are used
+    /// to split max running time. Unused time of a phase is given to the next ones. Default is None.
+    pub fn with_time_budget(mut self, shares: Option<(f64, f64, f64)>) -> Self {
+        self.time_budget = shares;
+        self
+    }
+
+    /// Sets mutation which is applied to the best known solution after the main search within
+    /// post-optimization time budget. Default is local search when time budget is used.
+    pub fn with_post_optimization(mut self, mutation: Option<Arc<dyn Mutation + Send + Sync>>) -> Self {
+        self.config.post_optimization = mutation;
+        self
+    }
+
+    /// Sets approximate memory limit in megabytes. When memory usage is nearing the limit, population
+    /// is shrunk and memory hungry operators are disabled. Default is None.
+    pub fn with_memory_limit(mut self, limit: Option<usize>) -> Self {
+        if let Some(limit) = limit {
+            self.config.telemetry.log(format!("configured to use memory limit: {}MB", limit).as_str());
+        }
+
+        self.config.memory_guard = limit.map(|limit| Arc::new(MemoryGuard::new(limit * 1024 * 1024)));
+        self
+    }
+
+    /// Sets max amount of cached job insertion evaluations which are reused while routes are not
+    /// changed. Zero disables the cache. Default is `DEFAULT_INSERTION_CACHE_CAPACITY`.
+    pub fn with_insertion_cache(mut self, capacity: Option<usize>) -> Self {
+        if let Some(capacity) = capacity {
+            self.config.telemetry.log(format!("configured to use insertion cache capacity: {}", capacity).as_str());
+            self.config.insertion_cache = capacity;
+        }
+
+        self
+    }
+
+    /// Sets schedule which adjusts constraint weights between generations, e.g. to increase penalties
+    /// of relaxed constraints as search progresses. Default is None.
+    pub fn with_weight_schedule(mut self, schedule: Option<Arc<WeightSchedule>>) -> Self {
+        self.config.weight_schedule = schedule;
+        self
+    }
+
+    /// Sets job cancellation which allows to withdraw jobs from the plan while solver is running.
+    /// Problem's constraint pipeline is expected to have `JobCancellationModule` which uses the same
+    /// job cancellation. Default is None.
+    pub fn with_job_cancellation(mut self, cancellation: Option<Arc<JobCancellation>>) -> Self {
+        self.config.job_cancellation = cancellation;
+        self
+    }
+
+    /// Sets mutation tracer which receives information about each mutation applied by the configured
+    /// hyper heuristic. Tracing requires extra cost estimations, so it is intended for search
+    /// analysis only. Default is None.
+    pub fn with_mutation_tracer(mut self, tracer: Option<MutationTracer>) -> Self {
+        self.config.mutation_tracer = tracer;
+        self
+    }
+
     /// Sets initial parameters used to construct initial population.
     pub fn with_init_params(
         mut self,
@@ -116,7 +265,8 @@ impl Builder {
         self
     }
 
-    /// Sets initial solutions in population. Default is no solutions in population.
+    /// Sets initial solutions in population. Default is no solutions in population. Unassigned jobs
+    /// of partial solutions are inserted using one of initial methods before search starts.
     pub fn with_init_solutions(mut self, solutions: Vec<Solution>) -> Self {
         self.config.telemetry.log(format!("provided {} initial solutions to start with", solutions.len()).as_str());
         self.config.population.initial.individuals = solutions
@@ -136,6 +286,7 @@ impl Builder {
     pub fn with_population(mut self, population: Box<dyn Population + Send + Sync>) -> Self {
         self.config.telemetry.log("configured to use custom population");
         self.config.population.variation = Some(population);
+        self.has_custom_population = true;
         self
     }
 
@@ -143,6 +294,34 @@ impl Builder {
     pub fn with_hyper(mut self, hyper: Box<dyn HyperHeuristic + Send + Sync>) -> Self {
         self.config.telemetry.log("configured to use custom hyper-heuristic");
         self.config.hyper = hyper;
+        self.has_custom_hyper = true;
+        self
+    }
+
+    /// Sets mutation group used by static selective hyper-heuristic: each mutation is applied with
+    /// its own probability. When set, it replaces hyper-heuristic and ruin and recreate weights
+    /// specified by other methods. Default is None.
+    pub fn with_mutation_group(mut self, mutation_group: MutationGroup) -> Self {
+        self.config.telemetry.log("configured to use custom mutation group");
+        self.mutation_group = Some(mutation_group);
+        self
+    }
+
+    /// Sets ruin groups with their weights used by default ruin and recreate mutation of static
+    /// selective hyper-heuristic. When set, it replaces hyper-heuristic specified by `with_hyper`.
+    /// Default is None.
+    pub fn with_ruin_weights(mut self, ruins: Vec<RuinGroup>) -> Self {
+        self.config.telemetry.log("configured to use custom ruin weights");
+        self.ruin_weights = Some(ruins);
+        self
+    }
+
+    /// Sets recreate methods with their weights used by default ruin and recreate mutation of static
+    /// selective hyper-heuristic. When set, it replaces hyper-heuristic specified by `with_hyper`.
+    /// Default is None.
+    pub fn with_recreate_weights(mut self, recreates: Vec<(Box<dyn Recreate + Send + Sync>, usize)>) -> Self {
+        self.config.telemetry.log("configured to use custom recreate weights");
+        self.recreate_weights = Some(recreates);
         self
     }
 
@@ -157,13 +336,37 @@ impl Builder {
     pub fn build(self) -> Result<Solver, String> {
         let problem = self.config.problem.clone();
 
-        let (criterias, quota): (Vec<Box<dyn Termination + Send + Sync>>, _) =
-            match (self.max_generations, self.max_time, self.cost_variation) {
-                (None, None, None) => {
+        let budget = if let Some((construction, search, post)) = self.time_budget {
+            self.config.telemetry.log(
+                format!(
+                    "configured to use time budget shares: construction: {}, search: {}, post-optimization: {}",
+                    construction, search, post
+                )
+                .as_str(),
+            );
+            let limit = self.max_time.unwrap_or(300);
+            Some(Arc::new(TimeBudget::new(limit as f64, (construction, search, post))?))
+        } else {
+            None
+        };
+
+        let create_max_time = |limit: usize| -> Box<dyn Termination + Send + Sync> {
+            if let Some(budget) = budget.as_ref() {
+                Box::new(SearchBudget::new(budget.clone()))
+            } else {
+                Box::new(MaxTime::new(limit as f64))
+            }
+        };
+
+        let goals = self.get_goals();
+
+        let (mut criterias, quota): (Vec<Box<dyn Termination + Send + Sync>>, _) =
+            match (self.max_generations, self.max_time, goals.is_empty()) {
+                (None, None, true) => {
                     self.config
                         .telemetry
                         .log("configured to use default max-generations (3000) and max-time (300secs)");
-                    (vec![Box::new(MaxGeneration::new(3000)), Box::new(MaxTime::new(300.))], None)
+                    (vec![Box::new(MaxGeneration::new(3000)), create_max_time(300)], None)
                 }
                 _ => {
                     let mut criterias: Vec<Box<dyn Termination + Send + Sync>> = vec![];
@@ -175,35 +378,110 @@ impl Builder {
 
                     let quota = if let Some(limit) = self.max_time {
                         self.config.telemetry.log(format!("configured to use max-time: {}s", limit).as_str());
-                        criterias.push(Box::new(MaxTime::new(limit as f64)));
+                        criterias.push(create_max_time(limit));
                         create_time_quota(limit)
                     } else {
                         None
                     };
 
-                    if let Some((sample, threshold)) = self.cost_variation {
-                        self.config.telemetry.log(
-                            format!(
-                                "configured to use cost variation with sample: {}, threshold: {}",
-                                sample, threshold
-                            )
-                            .as_str(),
-                        );
-                        criterias.push(Box::new(CostVariation::new(sample, threshold)))
-                    }
-
                     (criterias, quota)
                 }
             };
 
+        match (goals.len(), self.all_goals) {
+            (0, _) => {}
+            (1, _) | (_, false) => criterias.extend(goals),
+            (_, true) => {
+                self.config.telemetry.log("configured to stop when all goal termination criteria are met");
+                criterias.push(Box::new(CompositeTermination::new_all(goals)))
+            }
+        }
+
         let mut config = self.config;
         config.termination = Arc::new(CompositeTermination::new(criterias));
+
+        if self.has_custom_parallelism {
+            let environment = config.environment.clone();
+
+            if !self.has_custom_population {
+                config.population.variation = Some(get_default_population(problem.clone(), environment.clone()));
+            }
+
+            if !self.has_custom_hyper {
+                config.hyper = Box::new(StaticSelective::new_with_defaults(problem.clone(), environment.clone()));
+            }
+
+            config.population.initial.individuals.iter_mut().for_each(|individual| {
+                individual.environment = environment.clone();
+            });
+        }
+
+        if let Some(callback) = self.iteration_callback {
+            let telemetry = std::mem::replace(&mut config.telemetry, Telemetry::new(TelemetryMode::None));
+            config.telemetry = telemetry.with_iteration_callback(callback);
+        }
+
+        let mutation_group = match (self.mutation_group, self.ruin_weights, self.recreate_weights) {
+            (Some(mutation_group), _, _) => Some(mutation_group),
+            (None, None, None) => None,
+            (None, ruins, recreates) => {
+                let mutation = StaticSelective::create_ruin_recreate_mutation(
+                    ruins.unwrap_or_else(|| StaticSelective::create_default_ruins(problem.clone())),
+                    recreates.unwrap_or_else(|| StaticSelective::create_default_recreates(problem.clone())),
+                );
+                Some(StaticSelective::create_default_mutation_group(mutation, config.environment.clone()))
+            }
+        };
+
+        if let Some(mutation_group) = mutation_group {
+            config.hyper = Box::new(StaticSelective::new(mutation_group));
+        }
+
         config.quota = quota;
+        config.budget = budget;
+
+        if config.budget.is_some() && config.post_optimization.is_none() {
+            config.telemetry.log("configured to use default local search as post-optimization");
+            config.post_optimization = Some(StaticSelective::create_default_local_search());
+        }
 
         Ok(Solver { problem, config })
     }
 }
 
+impl Builder {
+    fn get_goals(&self) -> Vec<Box<dyn Termination + Send + Sync>> {
+        let mut goals: Vec<Box<dyn Termination + Send + Sync>> = vec![];
+
+        if let Some((sample, threshold)) = self.cost_variation {
+            self.config.telemetry.log(
+                format!("configured to use cost variation with sample: {}, threshold: {}", sample, threshold).as_str(),
+            );
+            goals.push(Box::new(CostVariation::new(sample, threshold)))
+        }
+
+        if let Some(target) = self.target_cost {
+            self.config.telemetry.log(format!("configured to use target cost: {}", target).as_str());
+            goals.push(Box::new(TargetCost::new(target)))
+        }
+
+        if let Some((generations, epsilon)) = self.min_improvement {
+            self.config.telemetry.log(
+                format!("configured to use min improvement with generations: {}, epsilon: {}", generations, epsilon)
+                    .as_str(),
+            );
+            goals.push(Box::new(MinImprovement::new(generations, epsilon)))
+        }
+
+        if self.all_assigned {
+            self.config.telemetry.log("configured to stop when all jobs are assigned");
+            goals.push(Box::new(AllAssigned::default()))
+        }
+
+        goals
+    }
+}
+
 fn create_time_quota(limit: usize) -> Option<Arc<dyn Quota + Sync + Send>> {
     Some(Arc::new(TimeQuota::new(limit as f64)))
 }
@@ -0,0 +1,48 @@
+//! A module which contains logic to withdraw cancelled jobs from population while solver is running.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/cancellation_test.rs"]
+mod cancellation_test;
+
+use crate::construction::constraints::JobCancellation;
+use crate::solver::RefinementContext;
+use std::sync::Arc;
+
+const JOB_CANCELLATION_KEY: &str = "job_cancellation";
+
+/// Attaches job cancellation to refinement context.
+pub(crate) fn set_job_cancellation(refinement_ctx: &mut RefinementContext, cancellation: Arc<JobCancellation>) {
+    refinement_ctx.state.insert(JOB_CANCELLATION_KEY.to_string(), Box::new((cancellation, 0_usize)));
+}
+
+/// Checks job cancellation attached to refinement context, if any, and, when new jobs are cancelled,
+/// replaces population individuals with their copies without them, so subsequent generations use
+/// released capacity and time. Returns total amount of cancelled jobs if any new cancellation is applied.
+pub(crate) fn apply_job_cancellation(refinement_ctx: &mut RefinementContext) -> Option<usize> {
+    let (cancellation, applied) = refinement_ctx
+        .state
+        .get(JOB_CANCELLATION_KEY)
+        .and_then(|value| value.downcast_ref::<(Arc<JobCancellation>, usize)>())
+        .cloned()?;
+
+    let size = cancellation.size();
+    if size == applied {
+        return None;
+    }
+
+    refinement_ctx.state.insert(JOB_CANCELLATION_KEY.to_string(), Box::new((cancellation, size)));
+    refinement_ctx.problem.constraint.insertion_cache().clear();
+
+    let individuals = refinement_ctx
+        .population
+        .ranked()
+        .map(|(individual, _)| {
+            let mut individual = individual.deep_copy();
+            individual.restore();
+            individual
+        })
+        .collect();
+    refinement_ctx.population.replace_all(individuals);
+
+    Some(size)
+}
@@ -1,13 +1,14 @@
-use crate::construction::heuristics::InsertionContext;
+use crate::construction::constraints::JobCancellation;
+use crate::construction::heuristics::{InsertionContext, DEFAULT_INSERTION_CACHE_CAPACITY};
 use crate::construction::Quota;
 use crate::models::Problem;
 use crate::solver::evolution::{EvolutionStrategy, RunSimple};
-use crate::solver::hyper::{HyperHeuristic, StaticSelective};
-use crate::solver::mutation::{Recreate, RecreateWithCheapest};
+use crate::solver::hyper::{HyperHeuristic, MutationTracer, StaticSelective};
+use crate::solver::mutation::{Mutation, Recreate, RecreateWithCheapest};
 use crate::solver::population::*;
 use crate::solver::telemetry::Telemetry;
 use crate::solver::termination::*;
-use crate::solver::TelemetryMode;
+use crate::solver::{MemoryGuard, TelemetryMode, TimeBudget, WeightSchedule};
 use crate::utils::Environment;
 use std::sync::Arc;
 
@@ -31,6 +32,28 @@ pub struct EvolutionConfig {
     /// A quota for evolution execution.
     pub quota: Option<Arc<dyn Quota + Send + Sync>>,
 
+    /// A time budget which splits time limit across solver phases.
+    pub budget: Option<Arc<TimeBudget>>,
+
+    /// A mutation applied to the best known solution after the main search while post-optimization
+    /// time budget is not exhausted.
+    pub post_optimization: Option<Arc<dyn Mutation + Send + Sync>>,
+
+    /// A memory guard which keeps approximate memory usage under the limit.
+    pub memory_guard: Option<Arc<MemoryGuard>>,
+
+    /// A schedule which adjusts constraint weights between generations.
+    pub weight_schedule: Option<Arc<WeightSchedule>>,
+
+    /// A job cancellation which allows to withdraw jobs while evolution is running.
+    pub job_cancellation: Option<Arc<JobCancellation>>,
+
+    /// A tracer which receives information about each mutation applied by the hyper heuristic.
+    pub mutation_tracer: Option<MutationTracer>,
+
+    /// A max amount of cached job insertion evaluations. Zero disables the cache.
+    pub insertion_cache: usize,
+
     /// An environmental context.
     pub environment: Arc<Environment>,
 
@@ -79,6 +102,13 @@ impl EvolutionConfig {
             ])),
             strategy: Arc::new(RunSimple::default()),
             quota: None,
+            budget: None,
+            post_optimization: None,
+            memory_guard: None,
+            weight_schedule: None,
+            job_cancellation: None,
+            mutation_tracer: None,
+            insertion_cache: DEFAULT_INSERTION_CACHE_CAPACITY,
             telemetry: Telemetry::new(TelemetryMode::None),
             environment,
         }
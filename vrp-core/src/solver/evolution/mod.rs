@@ -3,11 +3,15 @@
 mod evolution_test;
 
 use crate::construction::heuristics::InsertionContext;
-use crate::solver::hyper::HyperHeuristic;
+use crate::solver::cancellation::{apply_job_cancellation, set_job_cancellation};
+use crate::solver::hyper::{set_mutation_tracer, HyperHeuristic};
+use crate::solver::memory::{check_memory_usage, set_memory_guard};
+use crate::solver::mutation::Mutation;
 use crate::solver::telemetry::Telemetry;
 use crate::solver::termination::*;
-use crate::solver::{Metrics, Population, RefinementContext};
-use crate::utils::Timer;
+use crate::solver::weights::{apply_weight_schedule, set_weight_schedule};
+use crate::solver::{BudgetPhase, Metrics, Population, RefinementContext, TimeBudget};
+use crate::utils::{ParallelismPhase, Timer};
 
 mod config;
 pub use self::config::*;
@@ -15,6 +19,8 @@ pub use self::config::*;
 mod run_simple;
 pub use self::run_simple::RunSimple;
 
+const BYTES_IN_MB: usize = 1024 * 1024;
+
 /// Defines evolution result type.
 pub type EvolutionResult = Result<(Box<dyn Population + Send + Sync>, Option<Metrics>), String>;
 
@@ -47,10 +53,49 @@ impl EvolutionSimulator {
     /// Runs evolution for given `problem` using evolution `config`.
     /// Returns populations filled with solutions.
     pub fn run(mut self) -> EvolutionResult {
+        let budget = self.config.budget.clone();
+        let quota = self.config.quota.clone();
+        let logger = self.config.telemetry.get_logger();
+
+        if let Some(budget) = budget.as_ref() {
+            if self.config.post_optimization.is_none() {
+                budget.skip(BudgetPhase::PostOptimization);
+            }
+            budget.start(BudgetPhase::Construction);
+        }
+
         let refinement_ctx = self.create_refinement_ctx()?;
         let strategy = self.config.strategy.clone();
 
-        strategy.run(refinement_ctx, self.config.hyper, self.config.termination.as_ref(), self.config.telemetry)
+        if let Some(budget) = budget.as_ref() {
+            budget.start(BudgetPhase::Search);
+        }
+
+        let (population, metrics) =
+            strategy.run(refinement_ctx, self.config.hyper, self.config.termination.as_ref(), self.config.telemetry)?;
+
+        // NOTE post-optimization is run only within time budget
+        let population = match (budget.as_ref(), self.config.post_optimization.as_ref()) {
+            (Some(budget), Some(mutation)) => {
+                budget.start(BudgetPhase::PostOptimization);
+                let refinement_ctx =
+                    RefinementContext::new(self.config.problem.clone(), population, self.config.environment, quota);
+                run_post_optimization(refinement_ctx, budget, mutation.as_ref())
+            }
+            _ => population,
+        };
+
+        if let Some(budget) = budget.as_ref() {
+            budget.finish();
+
+            if let Some(logger) = logger {
+                let consumption =
+                    budget.get_consumption().iter().map(|phase| phase.to_string()).collect::<Vec<_>>().join(", ");
+                logger(format!("time budget consumption: {}", consumption).as_str());
+            }
+        }
+
+        Ok((population, metrics))
     }
 
     /// Creates refinement context with population containing initial individuals.
@@ -62,6 +107,24 @@ impl EvolutionSimulator {
             std::mem::replace(&mut self.config.quota, None),
         );
 
+        self.config.problem.constraint.insertion_cache().set_capacity(self.config.insertion_cache);
+
+        if let Some(memory_guard) = self.config.memory_guard.clone() {
+            set_memory_guard(&mut refinement_ctx, memory_guard);
+        }
+
+        if let Some(weight_schedule) = self.config.weight_schedule.clone() {
+            set_weight_schedule(&mut refinement_ctx, weight_schedule);
+        }
+
+        if let Some(job_cancellation) = self.config.job_cancellation.clone() {
+            set_job_cancellation(&mut refinement_ctx, job_cancellation);
+        }
+
+        if let Some(mutation_tracer) = self.config.mutation_tracer.clone() {
+            set_mutation_tracer(&mut refinement_ctx, mutation_tracer);
+        }
+
         self.config.telemetry.log(
             format!(
                 "problem has total jobs: {}, actors: {}",
@@ -71,11 +134,26 @@ impl EvolutionSimulator {
             .as_str(),
         );
 
+        let weights = self.config.population.initial.methods.iter().map(|(_, weight)| *weight).collect::<Vec<_>>();
+
         std::mem::replace(&mut self.config.population.initial.individuals, vec![])
             .into_iter()
             .zip(0_usize..)
             .take(self.config.population.initial.size)
             .for_each(|(ctx, idx)| {
+                // NOTE provided solution can be partial, e.g. built for previous version of the problem,
+                // so its unassigned and new jobs are inserted using one of initial methods
+                let ctx = if ctx.solution.required.is_empty() && ctx.solution.unassigned.is_empty() {
+                    ctx
+                } else {
+                    let method_idx = self.config.environment.random.weighted(weights.as_slice());
+                    let method = &self.config.population.initial.methods[method_idx].0;
+                    let environment = refinement_ctx.environment.clone();
+                    environment.parallelism.phase_execute(ParallelismPhase::Construction, || {
+                        environment.stream_execute(get_construction_stream(idx), || method.run(&refinement_ctx, ctx))
+                    })
+                };
+
                 if should_add_solution(&refinement_ctx) {
                     self.config.telemetry.on_initial(idx, self.config.population.initial.size, Timer::start());
                     refinement_ctx.population.add(ctx);
@@ -84,7 +162,6 @@ impl EvolutionSimulator {
                 }
             });
 
-        let weights = self.config.population.initial.methods.iter().map(|(_, weight)| *weight).collect::<Vec<_>>();
         let empty_ctx = InsertionContext::new(self.config.problem.clone(), refinement_ctx.environment.clone());
 
         let initial_time = Timer::start();
@@ -95,10 +172,22 @@ impl EvolutionSimulator {
                 return Err(());
             }
 
+            // NOTE construction budget cannot stop the phase until at least one solution is built
+            let is_budget_exhausted =
+                self.config.budget.as_ref().map_or(false, |budget| budget.is_exhausted(BudgetPhase::Construction));
+            if is_budget_exhausted && refinement_ctx.population.size() > 0 {
+                self.config.telemetry.log("construction phase has exhausted its time budget");
+                return Err(());
+            }
+
             let method_idx = self.config.environment.random.weighted(weights.as_slice());
 
-            let insertion_ctx =
-                self.config.population.initial.methods[method_idx].0.run(&refinement_ctx, empty_ctx.deep_copy());
+            let method = &self.config.population.initial.methods[method_idx].0;
+            let environment = refinement_ctx.environment.clone();
+            let insertion_ctx = environment.parallelism.phase_execute(ParallelismPhase::Construction, || {
+                environment
+                    .stream_execute(get_construction_stream(idx), || method.run(&refinement_ctx, empty_ctx.deep_copy()))
+            });
 
             if should_add_solution(&refinement_ctx) {
                 refinement_ctx.population.add(insertion_ctx);
@@ -126,6 +215,39 @@ impl EvolutionSimulator {
     }
 }
 
+/// Applies post-optimization mutation to the best known individual while its time budget allows.
+fn run_post_optimization(
+    refinement_ctx: RefinementContext,
+    budget: &TimeBudget,
+    mutation: &(dyn Mutation + Send + Sync),
+) -> Box<dyn Population + Send + Sync> {
+    let mut refinement_ctx = refinement_ctx;
+    refinement_ctx.statistics.termination_estimate = 1.;
+
+    while !budget.is_exhausted(BudgetPhase::PostOptimization)
+        && !refinement_ctx.quota.as_ref().map_or(false, |quota| quota.is_reached())
+    {
+        let offspring = match refinement_ctx.population.ranked().next() {
+            Some((individual, _)) => refinement_ctx
+                .environment
+                .parallelism
+                .phase_execute(ParallelismPhase::Mutation, || mutation.mutate(&refinement_ctx, individual)),
+            None => break,
+        };
+
+        refinement_ctx.population.add(offspring);
+        refinement_ctx.statistics.generation += 1;
+    }
+
+    refinement_ctx.population
+}
+
+/// Returns random stream id used to build initial individual with given index. Search uses streams
+/// based on generation and individual index, so construction ones are taken from the end of range.
+fn get_construction_stream(idx: usize) -> u64 {
+    u64::MAX - idx as u64
+}
+
 fn should_add_solution(refinement_ctx: &RefinementContext) -> bool {
     let is_quota_reached = refinement_ctx.quota.as_ref().map_or(false, |quota| quota.is_reached());
     let is_population_empty = refinement_ctx.population.size() == 0;
@@ -152,4 +274,21 @@ fn on_generation(
 
     telemetry.on_generation(refinement_ctx, termination_estimate, generation_time, is_improved);
     refinement_ctx.population.on_generation(&refinement_ctx.statistics);
+    apply_weight_schedule(refinement_ctx);
+
+    if let Some(cancelled) = apply_job_cancellation(refinement_ctx) {
+        telemetry.log(format!("cancelled jobs are withdrawn from population, total cancelled: {}", cancelled).as_str());
+    }
+
+    if let Some((usage, limit)) = check_memory_usage(refinement_ctx) {
+        telemetry.log(
+            format!(
+                "memory usage is close to the limit ({}MB of {}MB), population is reduced to {} individuals",
+                usage / BYTES_IN_MB,
+                limit / BYTES_IN_MB,
+                refinement_ctx.population.size()
+            )
+            .as_str(),
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use crate::solver::evolution::*;
 use crate::solver::hyper::HyperHeuristic;
 use crate::solver::{RefinementContext, Telemetry};
-use crate::utils::Timer;
+use crate::utils::{ParallelismPhase, Timer};
 
 /// A simple evolution algorithm which maintains single population.
 pub struct RunSimple {}
@@ -29,10 +29,16 @@ impl EvolutionStrategy for RunSimple {
 
             let parents = refinement_ctx.population.select().collect();
 
-            let offspring = hyper.search(&refinement_ctx, parents);
+            let environment = refinement_ctx.environment.clone();
+            let parallelism = &environment.parallelism;
+            let offspring =
+                parallelism.phase_execute(ParallelismPhase::Mutation, || hyper.search(&refinement_ctx, parents));
 
-            let is_improved =
-                if should_add_solution(&refinement_ctx) { refinement_ctx.population.add_all(offspring) } else { false };
+            let is_improved = if should_add_solution(&refinement_ctx) {
+                parallelism.phase_execute(ParallelismPhase::Evaluation, || refinement_ctx.population.add_all(offspring))
+            } else {
+                false
+            };
 
             on_generation(&mut refinement_ctx, &mut telemetry, termination, generation_time, is_improved);
         }
@@ -0,0 +1,175 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/hyper/dynamic_selective_test.rs"]
+mod dynamic_selective_test;
+
+use super::*;
+use crate::algorithms::mdp::*;
+use crate::algorithms::nsga2::Objective;
+use crate::models::Problem;
+use crate::solver::mutation::*;
+use crate::solver::population::SelectionPhase;
+use crate::utils::Environment;
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+
+/// A dynamic hyper-heuristic which models mutation selection as Markov Decision Process: a state
+/// describes the search phase and whether the individual is the best known one, an action is a
+/// mutation applied to the individual. Action estimates are learned online using rewards given
+/// for improving the individual or the best known solution.
+pub struct DynamicSelective {
+    mutations: Vec<Arc<dyn Mutation + Send + Sync>>,
+    simulator: Simulator<SearchState>,
+}
+
+impl HyperHeuristic for DynamicSelective {
+    fn search(&mut self, refinement_ctx: &RefinementContext, individuals: Vec<&Individual>) -> Vec<Individual> {
+        let objective = &refinement_ctx.problem.objective;
+        let best_known = refinement_ctx.population.ranked().next().map(|(best_known, _)| best_known);
+        let phase = refinement_ctx.population.selection_phase();
+        let mutations = self.mutations.as_slice();
+
+        let results = individuals.iter().map(|_| Mutex::new(None)).collect::<Vec<_>>();
+
+        let agents = individuals
+            .iter()
+            .zip(results.iter())
+            .enumerate()
+            .map(|(idx, (individual, result))| {
+                let is_best_known = !matches!(best_known,
+                    Some(best_known) if objective.total_order(best_known, individual) == Ordering::Less);
+
+                Box::new(SearchAgent {
+                    refinement_ctx,
+                    mutations,
+                    individual_idx: idx,
+                    individual,
+                    state: if is_best_known { SearchState::BestKnown(phase) } else { SearchState::Diverse(phase) },
+                    result,
+                }) as Box<dyn Agent<SearchState> + Send + Sync>
+            })
+            .collect();
+
+        self.simulator.run_episodes(agents, |values| values.iter().sum::<f64>() / values.len() as f64);
+
+        results
+            .into_iter()
+            .zip(individuals)
+            .map(|(result, individual)| result.into_inner().unwrap().unwrap_or_else(|| individual.deep_copy()))
+            .collect()
+    }
+}
+
+impl DynamicSelective {
+    /// Creates an instance of `DynamicSelective` from the list of mutations.
+    pub fn new(mutations: Vec<Arc<dyn Mutation + Send + Sync>>, environment: Arc<Environment>) -> Self {
+        Self {
+            mutations,
+            simulator: Simulator::new(
+                Box::new(QLearning::new(0.2, 0.01)),
+                Box::new(EpsilonGreedy::new(0.1, environment.random.clone())),
+            ),
+        }
+    }
+
+    /// Creates an instance of `DynamicSelective` with default parameters.
+    pub fn new_with_defaults(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        let default_mutation = StaticSelective::create_default_mutation(problem);
+
+        Self::new(
+            vec![
+                default_mutation.clone(),
+                StaticSelective::create_default_local_search(),
+                Arc::new(DecomposeSearch::new(default_mutation, (2, 4), 4)),
+            ],
+            environment,
+        )
+    }
+}
+
+/// Specifies a search state.
+#[derive(Clone, Hash, Eq, PartialEq)]
+enum SearchState {
+    /// An individual is the best known one.
+    BestKnown(SelectionPhase),
+    /// An individual is not the best known one.
+    Diverse(SelectionPhase),
+    /// A mutation has found a new best known solution.
+    BestImprovement,
+    /// A mutation has improved the individual, but not the best known solution.
+    DiverseImprovement,
+    /// A mutation has not improved the individual.
+    Stagnated,
+}
+
+impl State for SearchState {
+    type Action = SearchAction;
+
+    fn reward(&self) -> f64 {
+        match self {
+            SearchState::BestImprovement => 100.,
+            SearchState::DiverseImprovement => 10.,
+            SearchState::Stagnated => -1.,
+            _ => 0.,
+        }
+    }
+}
+
+/// Specifies a search action.
+#[derive(Clone, Hash, Eq, PartialEq)]
+enum SearchAction {
+    /// Applies a mutation with given index.
+    Mutate(usize),
+}
+
+/// An agent which applies one mutation to the individual.
+struct SearchAgent<'a> {
+    refinement_ctx: &'a RefinementContext,
+    mutations: &'a [Arc<dyn Mutation + Send + Sync>],
+    individual_idx: usize,
+    individual: &'a Individual,
+    state: SearchState,
+    result: &'a Mutex<Option<Individual>>,
+}
+
+impl<'a> Agent<SearchState> for SearchAgent<'a> {
+    fn get_state(&self) -> &SearchState {
+        &self.state
+    }
+
+    fn get_actions(&self, state: &SearchState) -> ActionsEstimate<SearchState> {
+        match state {
+            SearchState::BestKnown(_) | SearchState::Diverse(_) => {
+                (0..self.mutations.len()).map(|idx| (SearchAction::Mutate(idx), 0.)).collect()
+            }
+            _ => ActionsEstimate::<SearchState>::default(),
+        }
+    }
+
+    fn take_action(&mut self, action: &SearchAction) {
+        let SearchAction::Mutate(mutation_idx) = action;
+
+        let objective = &self.refinement_ctx.problem.objective;
+        let new_individual = self.mutations[*mutation_idx].mutate(self.refinement_ctx, self.individual);
+
+        let is_improved = objective.total_order(self.individual, &new_individual) == Ordering::Greater;
+        let is_best_improved = is_improved
+            && !matches!(self.refinement_ctx.population.ranked().next(),
+                Some((best_known, _)) if objective.total_order(best_known, &new_individual) != Ordering::Greater);
+
+        trace_mutation(
+            self.refinement_ctx,
+            self.individual_idx,
+            self.mutations[*mutation_idx].as_ref(),
+            self.individual,
+            &new_individual,
+        );
+
+        self.state = match (is_best_improved, is_improved) {
+            (true, _) => SearchState::BestImprovement,
+            (false, true) => SearchState::DiverseImprovement,
+            _ => SearchState::Stagnated,
+        };
+
+        *self.result.lock().unwrap() = Some(new_individual);
+    }
+}
@@ -1,15 +1,73 @@
 //! This module contains a hyper-heuristic logic.
 
+mod dynamic_selective;
+pub use self::dynamic_selective::*;
+
 mod static_selective;
 pub use self::static_selective::*;
 
+use crate::algorithms::nsga2::Objective;
+use crate::solver::mutation::Mutation;
 use crate::solver::population::Individual;
 use crate::solver::RefinementContext;
 use crate::utils::Random;
 use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+const MUTATION_TRACER_KEY: &str = "mutation_tracer";
 
 /// Represents a hyper heuristic functionality.
 pub trait HyperHeuristic {
     /// Performs a new search in solution space using individuals provided.
     fn search(&mut self, refinement_ctx: &RefinementContext, individuals: Vec<&Individual>) -> Vec<Individual>;
 }
+
+/// A type which receives information about each applied mutation.
+pub type MutationTracer = Arc<dyn Fn(&MutationTrace) + Send + Sync>;
+
+/// Specifies information about mutation applied to the individual.
+pub struct MutationTrace {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Index of the individual among individuals selected for mutation in given generation.
+    pub individual: usize,
+    /// A name of the applied mutation operator.
+    pub mutation: String,
+    /// Cost of the individual before mutation.
+    pub cost_before: f64,
+    /// Cost of the individual after mutation.
+    pub cost_after: f64,
+    /// True if mutation has improved the individual.
+    pub is_improved: bool,
+}
+
+/// Attaches mutation tracer to refinement context, so it receives mutations applied by any hyper-heuristic.
+pub(crate) fn set_mutation_tracer(refinement_ctx: &mut RefinementContext, tracer: MutationTracer) {
+    refinement_ctx.state.insert(MUTATION_TRACER_KEY.to_string(), Box::new(tracer));
+}
+
+/// Passes information about applied mutation to the tracer attached to refinement context, if any.
+/// Please note that tracing requires extra cost estimations, so it is intended for search analysis.
+pub(crate) fn trace_mutation(
+    refinement_ctx: &RefinementContext,
+    individual: usize,
+    mutation: &(dyn Mutation + Send + Sync),
+    original: &Individual,
+    mutated: &Individual,
+) {
+    let tracer =
+        refinement_ctx.state.get(MUTATION_TRACER_KEY).and_then(|tracer| tracer.downcast_ref::<MutationTracer>());
+
+    if let Some(tracer) = tracer {
+        let objective = &refinement_ctx.problem.objective;
+        tracer(&MutationTrace {
+            generation: refinement_ctx.statistics.generation,
+            individual,
+            mutation: mutation.name().to_string(),
+            cost_before: objective.fitness(original),
+            cost_after: objective.fitness(mutated),
+            is_improved: objective.total_order(original, mutated) == Ordering::Greater,
+        });
+    }
+}
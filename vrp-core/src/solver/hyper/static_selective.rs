@@ -1,7 +1,10 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/hyper/static_selective_test.rs"]
+mod static_selective_test;
+
 use super::*;
 use crate::algorithms::nsga2::Objective;
 use crate::construction::heuristics::InsertionContext;
-use crate::models::common::SingleDimLoad;
 use crate::models::Problem;
 use crate::solver::mutation::*;
 use crate::solver::population::{Individual, SelectionPhase};
@@ -16,18 +19,27 @@ pub type MutationProbability = Box<dyn Fn(&RefinementContext, &InsertionContext)
 /// A type which specifies a group of multiple mutation strategies with their probability.
 pub type MutationGroup = Vec<(Arc<dyn Mutation + Send + Sync>, MutationProbability)>;
 
+/// A type which decides whether a mutation result (third argument) is accepted instead of the
+/// individual it was produced from (second argument) within the mutation chain.
+pub type MutationAcceptance =
+    Box<dyn Fn(&RefinementContext, &InsertionContext, &InsertionContext) -> bool + Send + Sync>;
+
 /// A simple hyper-heuristic which selects mutation operator from the list with fixed (static) probabilities.
 pub struct StaticSelective {
     mutation_group: MutationGroup,
+    acceptance: Option<MutationAcceptance>,
 }
 
 impl HyperHeuristic for StaticSelective {
     fn search(&mut self, refinement_ctx: &RefinementContext, individuals: Vec<&Individual>) -> Vec<Individual> {
+        let environment = refinement_ctx.environment.as_ref();
+        let generation = refinement_ctx.statistics.generation as u64;
+
         parallel_into_collect(individuals.iter().enumerate().collect(), |(idx, insertion_ctx)| {
-            refinement_ctx
-                .environment
-                .parallelism
-                .thread_pool_execute(idx, || self.mutate(refinement_ctx, insertion_ctx))
+            environment.parallelism.thread_pool_execute(idx, || {
+                environment
+                    .stream_execute((generation << 32) | idx as u64, || self.mutate(refinement_ctx, insertion_ctx, idx))
+            })
         })
     }
 }
@@ -35,23 +47,30 @@ impl HyperHeuristic for StaticSelective {
 impl StaticSelective {
     /// Creates an instance of `StaticSelective` from mutation groups.
     pub fn new(mutation_group: MutationGroup) -> Self {
-        Self { mutation_group }
+        Self { mutation_group, acceptance: None }
+    }
+
+    /// Sets an acceptance criterion for results of mutations applied one after another. By default,
+    /// each mutation result is passed to the next mutation regardless of its cost.
+    pub fn with_acceptance(mut self, acceptance: MutationAcceptance) -> Self {
+        self.acceptance = Some(acceptance);
+        self
     }
 
     /// Creates an instance of `StaticSelective` with default parameters.
     pub fn new_with_defaults(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
-        let default_mutation = Self::create_default_mutation(problem);
-        let local_search = Arc::new(LocalSearch::new(Box::new(CompositeLocalOperator::new(
-            vec![
-                (Box::new(ExchangeInterRouteBest::default()), 100),
-                (Box::new(ExchangeInterRouteRandom::default()), 30),
-                (Box::new(ExchangeIntraRouteRandom::default()), 30),
-            ],
-            1,
-            2,
-        ))));
+        Self::new(Self::create_default_mutation_group(Self::create_default_mutation(problem), environment))
+    }
+
+    /// Creates default mutation group which applies given ruin and recreate mutation together with
+    /// decomposition and local search.
+    pub fn create_default_mutation_group(
+        default_mutation: Arc<dyn Mutation + Send + Sync>,
+        environment: Arc<Environment>,
+    ) -> MutationGroup {
+        let local_search = Self::create_default_local_search();
 
-        Self::new(vec![
+        vec![
             (
                 Arc::new(DecomposeSearch::new(default_mutation.clone(), (2, 4), 4)),
                 create_context_mutation_probability(
@@ -64,21 +83,30 @@ impl StaticSelective {
             (local_search.clone(), create_scalar_mutation_probability(0.05, environment.random.clone())),
             (default_mutation, create_scalar_mutation_probability(1., environment.random.clone())),
             (local_search, create_scalar_mutation_probability(0.05, environment.random.clone())),
-        ])
+        ]
     }
 
-    fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
+    fn mutate(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+        individual: usize,
+    ) -> InsertionContext {
         unwrap_from_result(
             self.mutation_group.iter().filter(|(_, probability)| probability(refinement_ctx, insertion_ctx)).try_fold(
                 insertion_ctx.deep_copy(),
                 |ctx, (mutation, _)| {
                     let new_insertion_ctx = mutation.mutate(refinement_ctx, &ctx);
 
+                    trace_mutation(refinement_ctx, individual, mutation.as_ref(), &ctx, &new_insertion_ctx);
+
                     if refinement_ctx.problem.objective.total_order(&insertion_ctx, &new_insertion_ctx)
                         == Ordering::Greater
                     {
                         // NOTE exit immediately as we don't want to lose improvement from original individual
                         Err(new_insertion_ctx)
+                    } else if let Some(acceptance) = &self.acceptance {
+                        Ok(if acceptance(refinement_ctx, &ctx, &new_insertion_ctx) { new_insertion_ctx } else { ctx })
                     } else {
                         Ok(new_insertion_ctx)
                     }
@@ -87,27 +115,60 @@ impl StaticSelective {
         )
     }
 
+    /// Creates default local search mutation with default parameters.
+    pub fn create_default_local_search() -> Arc<dyn Mutation + Send + Sync> {
+        Arc::new(LocalSearch::new(Box::new(CompositeLocalOperator::new(
+            vec![
+                (Box::new(ExchangeInterRouteBest::default()), 100),
+                (Box::new(ExchangeInterRouteRandom::default()), 30),
+                (Box::new(ExchangeSequence::default()), 20),
+                (Box::new(ExchangeIntraRouteRandom::default()), 30),
+                (Box::new(TwoOptWithinRoute::default()), 20),
+                (Box::new(OrOptMove::default()), 20),
+            ],
+            1,
+            2,
+        ))))
+    }
+
     /// Creates default mutation (ruin and recreate) with default parameters.
     pub fn create_default_mutation(problem: Arc<Problem>) -> Arc<dyn Mutation + Send + Sync> {
-        // initialize recreate
-        let recreate = Box::new(CompositeRecreate::new(vec![
+        Self::create_ruin_recreate_mutation(
+            Self::create_default_ruins(problem.clone()),
+            Self::create_default_recreates(problem),
+        )
+    }
+
+    /// Creates ruin and recreate mutation from given ruin groups and recreate methods with their weights.
+    pub fn create_ruin_recreate_mutation(
+        ruins: Vec<RuinGroup>,
+        recreates: Vec<(Box<dyn Recreate + Send + Sync>, usize)>,
+    ) -> Arc<dyn Mutation + Send + Sync> {
+        Arc::new(RuinAndRecreate::new(Box::new(CompositeRecreate::new(recreates)), Box::new(CompositeRuin::new(ruins))))
+    }
+
+    /// Creates default recreate methods with their weights.
+    pub fn create_default_recreates(problem: Arc<Problem>) -> Vec<(Box<dyn Recreate + Send + Sync>, usize)> {
+        vec![
             (Box::new(RecreateWithSkipBest::new(1, 2)), 50),
             (Box::new(RecreateWithRegret::new(2, 3)), 20),
             (Box::new(RecreateWithCheapest::default()), 20),
             (Box::new(RecreateWithPerturbation::default()), 10),
             (Box::new(RecreateWithSkipBest::new(3, 4)), 5),
             (Box::new(RecreateWithGaps::default()), 5),
-            // TODO use dimension size from problem
-            (Box::new(RecreateWithBlinks::<SingleDimLoad>::default()), 5),
+            (create_default_blinks(problem.as_ref()), 5),
             (Box::new(RecreateWithFarthest::default()), 2),
             (Box::new(RecreateWithSkipBest::new(4, 8)), 2),
             (Box::new(RecreateWithNearestNeighbor::default()), 1),
-        ]));
+        ]
+    }
 
-        // initialize ruin
+    /// Creates default ruin groups with their weights.
+    pub fn create_default_ruins(problem: Arc<Problem>) -> Vec<RuinGroup> {
         let random_route = Arc::new(RandomRouteRemoval::default());
         let random_job = Arc::new(RandomJobRemoval::new(JobRemovalLimit::default()));
-        let ruin = Box::new(CompositeRuin::new(vec![
+
+        vec![
             (
                 vec![
                     (Arc::new(AdjustedStringRemoval::default()), 1.),
@@ -143,9 +204,7 @@ impl StaticSelective {
                 ],
                 1,
             ),
-        ]));
-
-        Arc::new(RuinAndRecreate::new(recreate, ruin))
+        ]
     }
 }
 
@@ -157,6 +216,33 @@ pub fn create_scalar_mutation_probability(
     Box::new(move |_, _| random.is_hit(scalar_probability))
 }
 
+/// Creates a simulated annealing acceptance: a result which is not worse than the current
+/// individual is always accepted, a worse one is accepted with probability `exp(-delta / t)`,
+/// where `delta` is a cost increase relative to the current cost and temperature `t` decreases
+/// with each generation: `t = initial_temperature * cooling_rate ^ generation`.
+pub fn create_simulated_annealing_acceptance(
+    initial_temperature: f64,
+    cooling_rate: f64,
+    random: Arc<dyn Random + Send + Sync>,
+) -> MutationAcceptance {
+    Box::new(move |refinement_ctx, current, candidate| {
+        let objective = &refinement_ctx.problem.objective;
+        if objective.total_order(current, candidate) != Ordering::Less {
+            return true;
+        }
+
+        let temperature = initial_temperature * cooling_rate.powi(refinement_ctx.statistics.generation as i32);
+        if temperature <= 0. {
+            return false;
+        }
+
+        let (current, candidate) = (objective.fitness(current), objective.fitness(candidate));
+        let delta = (candidate - current).max(0.) / current.abs().max(1.);
+
+        random.is_hit((-delta / temperature).exp())
+    })
+}
+
 /// Creates a mutation probability which uses context state.
 pub fn create_context_mutation_probability(
     jobs_threshold: usize,
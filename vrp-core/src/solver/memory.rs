@@ -0,0 +1,115 @@
+//! A module which contains logic to keep approximate memory usage of the solver under the limit.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/memory_test.rs"]
+mod memory_test;
+
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use crate::models::solution::Activity;
+use crate::solver::RefinementContext;
+use std::mem::size_of;
+use std::sync::Arc;
+
+const MEMORY_GUARD_KEY: &str = "memory_guard";
+const MEMORY_PRESSURE_KEY: &str = "memory_pressure";
+
+/// An approximate size of the state kept per activity in route context.
+const ACTIVITY_STATE_SIZE: usize = 64;
+
+/// Tracks approximate memory usage of the problem and the population. When usage is nearing the
+/// limit, applies graceful degradation: shrinks population and signals memory hungry operators to
+/// fall back to cheaper alternatives.
+pub struct MemoryGuard {
+    limit_in_bytes: usize,
+    threshold: f64,
+}
+
+impl MemoryGuard {
+    /// Creates a new instance of `MemoryGuard` with memory limit in bytes. Degradation is applied
+    /// when estimated usage exceeds 90% of the limit.
+    pub fn new(limit_in_bytes: usize) -> Self {
+        Self { limit_in_bytes, threshold: 0.9 }
+    }
+
+    /// Returns memory limit in bytes.
+    pub fn limit(&self) -> usize {
+        self.limit_in_bytes
+    }
+
+    /// Returns approximate memory usage of the problem and the population in bytes.
+    pub fn estimate(refinement_ctx: &RefinementContext) -> usize {
+        let problem = refinement_ctx.problem.as_ref();
+
+        // NOTE job index keeps neighbourhood list and map for each job per profile
+        let jobs = problem.jobs.size();
+        let index_size = 2 * jobs * jobs * size_of::<(Job, Cost)>() * problem.fleet.profiles.len().max(1);
+
+        let individual_size =
+            refinement_ctx.population.ranked().next().map_or(0, |(individual, _)| estimate_individual(individual));
+
+        problem.transport.memory_size() + index_size + refinement_ctx.population.total_size() * individual_size
+    }
+
+    /// Checks memory usage and, when it is close to the limit, shrinks population by half, disables
+    /// insertion cache and marks refinement context as being under memory pressure. Returns estimated usage if any degradation
+    /// is applied.
+    pub fn check(&self, refinement_ctx: &mut RefinementContext) -> Option<usize> {
+        let usage = Self::estimate(refinement_ctx);
+
+        if (usage as f64) < self.limit_in_bytes as f64 * self.threshold {
+            return None;
+        }
+
+        let was_pressure = is_memory_pressure(refinement_ctx);
+        let old_size = refinement_ctx.population.total_size();
+
+        refinement_ctx.population.shrink((refinement_ctx.population.size() / 2).max(1));
+        refinement_ctx.state.insert(MEMORY_PRESSURE_KEY.to_string(), Box::new(true));
+        refinement_ctx.problem.constraint.insertion_cache().set_capacity(0);
+
+        if was_pressure && old_size == refinement_ctx.population.total_size() {
+            None
+        } else {
+            Some(usage)
+        }
+    }
+}
+
+/// Attaches memory guard to refinement context.
+pub(crate) fn set_memory_guard(refinement_ctx: &mut RefinementContext, guard: Arc<MemoryGuard>) {
+    refinement_ctx.state.insert(MEMORY_GUARD_KEY.to_string(), Box::new(guard));
+}
+
+/// Checks memory usage using memory guard attached to refinement context, if any.
+pub(crate) fn check_memory_usage(refinement_ctx: &mut RefinementContext) -> Option<(usize, usize)> {
+    let guard = refinement_ctx
+        .state
+        .get(MEMORY_GUARD_KEY)
+        .and_then(|guard| guard.downcast_ref::<Arc<MemoryGuard>>())
+        .cloned()?;
+
+    guard.check(refinement_ctx).map(|usage| (usage, guard.limit()))
+}
+
+/// Returns true if memory usage is close to the limit, so memory hungry operators should be avoided.
+pub fn is_memory_pressure(refinement_ctx: &RefinementContext) -> bool {
+    refinement_ctx
+        .state
+        .get(MEMORY_PRESSURE_KEY)
+        .and_then(|value| value.downcast_ref::<bool>())
+        .cloned()
+        .unwrap_or(false)
+}
+
+fn estimate_individual(insertion_ctx: &InsertionContext) -> usize {
+    let solution = &insertion_ctx.solution;
+
+    let activities = solution.routes.iter().map(|route_ctx| route_ctx.route.tour.total()).sum::<usize>();
+    let jobs = solution.required.len() + solution.ignored.len() + solution.unassigned.len() + solution.locked.len();
+
+    activities * (size_of::<Activity>() + ACTIVITY_STATE_SIZE)
+        + jobs * size_of::<Job>()
+        + solution.routes.len() * size_of::<RouteContext>()
+}
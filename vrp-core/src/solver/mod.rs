@@ -90,30 +90,51 @@
 //! [`Solver`]: ./struct.Solver.html
 //!
 
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
+
 extern crate rand;
-use crate::algorithms::nsga2::Objective;
+use crate::algorithms::nsga2::{MultiObjective, Objective};
+use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
 use crate::models::common::Cost;
 use crate::models::{Problem, Solution};
 use crate::solver::population::Population;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
+pub mod analysis;
 pub mod hyper;
 pub mod mutation;
 pub mod objectives;
 pub mod population;
 pub mod termination;
 
+mod budget;
+pub use self::budget::{BudgetPhase, PhaseConsumption, TimeBudget};
+
 mod builder;
 pub use self::builder::Builder;
 
 mod evolution;
 use self::evolution::{EvolutionConfig, EvolutionSimulator};
 
+mod cancellation;
+
+mod memory;
+pub use self::memory::{is_memory_pressure, MemoryGuard};
+
+mod weights;
+pub use self::weights::{WeightSchedule, WeightScheduleFunc};
+
 mod telemetry;
-pub use self::telemetry::{Metrics, Telemetry, TelemetryMode};
+pub use self::telemetry::{
+    IndividualSnapshot, IterationCallback, IterationSnapshot, Metrics, PopulationDumper, PopulationSnapshot,
+    ProgressRecord, ProgressSink, Telemetry, TelemetryMode,
+};
 use crate::utils::Environment;
 
 /// A key to store solution order information.
@@ -125,6 +146,21 @@ const BALANCE_ACTIVITY_KEY: i32 = 21;
 const BALANCE_DISTANCE_KEY: i32 = 22;
 const BALANCE_DURATION_KEY: i32 = 23;
 
+/// A key to store lateness risk of the route.
+const LATENESS_RISK_KEY: i32 = 24;
+
+/// A key to store amount of jobs assigned to a different vehicle than in the previous solution.
+const DRIVER_CONSISTENCY_KEY: i32 = 25;
+
+/// A key to store convex hull area of the route.
+const ROUTE_COMPACTNESS_KEY: i32 = 26;
+
+/// A key to store amount of activities started outside of their preferred time windows.
+const PREFERRED_TIMES_KEY: i32 = 27;
+
+/// A key to store slack deficit of the route.
+const SLACK_RESERVATION_KEY: i32 = 28;
+
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
     /// Original problem definition.
@@ -226,4 +262,132 @@ impl Solver {
 
         Ok((solution, cost, metrics))
     }
+
+    /// Solves a Vehicle Routing Problem and returns a set of mutually non-dominated solutions found
+    /// in the final population, each within its cost and objective fitness values. Dominance is checked
+    /// using all problem objectives, so the set makes sense only for multi-objective problems. The first
+    /// solution is the same as returned by `solve` method.
+    #[allow(clippy::type_complexity)]
+    pub fn solve_pareto(self) -> Result<(Vec<(Solution, Cost, Vec<f64>)>, Option<Metrics>), String> {
+        let problem = self.problem;
+        let (population, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+
+        let individuals = population.ranked().map(|(insertion_ctx, _)| insertion_ctx).collect::<Vec<_>>();
+        if individuals.is_empty() {
+            return Err("cannot find any solution".to_string());
+        }
+
+        let solutions = get_non_dominated(individuals.as_slice())
+            .into_iter()
+            .map(|insertion_ctx| {
+                let solution = insertion_ctx.solution.to_solution(problem.extras.clone());
+                let cost = problem.objective.fitness(insertion_ctx);
+                let fitness = insertion_ctx.get_fitness_values().collect();
+
+                (solution, cost, fitness)
+            })
+            .collect();
+
+        Ok((solutions, metrics))
+    }
+
+    /// Solves a Vehicle Routing Problem and returns the best known solution within its cost together
+    /// with an alternative one: the best solution in the final population which differs from the best
+    /// known by at least `min_reassignments` job reassignments, if there is such solution. Please note
+    /// that only individuals of the final population are searched, so no alternative is returned when
+    /// population keeps similar solutions only (e.g. population with small selection size).
+    #[allow(clippy::type_complexity)]
+    pub fn solve_with_alternative(
+        self,
+        min_reassignments: usize,
+    ) -> Result<((Solution, Cost), Option<(Solution, Cost)>, Option<Metrics>), String> {
+        let problem = self.problem;
+        let (population, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+
+        let mut individuals = population.ranked().map(|(insertion_ctx, _)| insertion_ctx);
+        let best = individuals.next().ok_or_else(|| "cannot find any solution".to_string())?;
+        let alternative =
+            individuals.find(|insertion_ctx| get_reassignments(best, insertion_ctx) >= min_reassignments.max(1));
+
+        let to_result = |insertion_ctx: &InsertionContext| {
+            (insertion_ctx.solution.to_solution(problem.extras.clone()), problem.objective.fitness(insertion_ctx))
+        };
+
+        Ok((to_result(best), alternative.map(to_result), metrics))
+    }
+}
+
+/// Returns amount of jobs which are served together with different jobs in two individuals. Routes
+/// are compared by their job sets, so routes swapped between interchangeable vehicles (e.g. of the
+/// same type and shift) are not counted as reassignments. Each route of one individual is matched
+/// with at most one route of another one, starting from pairs which share the most jobs: a job is
+/// counted when it is not served by matched routes, including a job assigned only in one of them.
+fn get_reassignments(a: &InsertionContext, b: &InsertionContext) -> usize {
+    let get_assignments = |insertion_ctx: &InsertionContext| {
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| route_ctx.route.tour.jobs().map(move |job| (job, route_idx)))
+            .collect::<HashMap<_, _>>()
+    };
+
+    let assignments_a = get_assignments(a);
+    let assignments_b = get_assignments(b);
+
+    let mut shared = assignments_a
+        .iter()
+        .filter_map(|(job, &route_a)| assignments_b.get(job).map(|&route_b| (route_a, route_b)))
+        .fold(HashMap::<(usize, usize), usize>::new(), |mut acc, key| {
+            *acc.entry(key).or_insert(0) += 1;
+            acc
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+    shared.sort_by(|(key_a, count_a), (key_b, count_b)| count_b.cmp(count_a).then(key_a.cmp(key_b)));
+
+    let (mut matched_a, mut matched_b) = (HashSet::new(), HashSet::new());
+    let kept = shared
+        .into_iter()
+        .filter(|((route_a, route_b), _)| {
+            let is_matched = matched_a.contains(route_a) || matched_b.contains(route_b);
+            if !is_matched {
+                matched_a.insert(*route_a);
+                matched_b.insert(*route_b);
+            }
+            !is_matched
+        })
+        .map(|(_, count)| count)
+        .sum::<usize>();
+
+    let assigned = assignments_a.len() + assignments_b.keys().filter(|job| !assignments_a.contains_key(*job)).count();
+
+    assigned - kept
+}
+
+/// Returns individuals which are not dominated by any other individual keeping their original order.
+/// Individuals with the same objective values as some previous one are skipped.
+fn get_non_dominated<'a>(individuals: &[&'a InsertionContext]) -> Vec<&'a InsertionContext> {
+    let compare = |a: &InsertionContext, b: &InsertionContext| {
+        a.problem.objective.objectives().map(|objective| objective.total_order(a, b)).fold(
+            (false, false),
+            |(is_less, is_greater), order| {
+                (is_less || order == Ordering::Less, is_greater || order == Ordering::Greater)
+            },
+        )
+    };
+
+    individuals
+        .iter()
+        .enumerate()
+        .filter(|(idx, &individual)| {
+            individuals.iter().enumerate().all(|(other_idx, &other)| match compare(other, individual) {
+                (true, false) => false,
+                (false, false) => other_idx >= *idx,
+                _ => true,
+            })
+        })
+        .map(|(_, &individual)| individual)
+        .collect()
 }
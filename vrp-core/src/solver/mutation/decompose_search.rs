@@ -7,7 +7,7 @@ use crate::algorithms::nsga2::Objective;
 use crate::construction::heuristics::{get_medoid, InsertionContext, SolutionContext};
 use crate::solver::mutation::Mutation;
 use crate::solver::population::{Greedy, Individual, Population};
-use crate::solver::RefinementContext;
+use crate::solver::{is_memory_pressure, RefinementContext};
 use crate::utils::{compare_floats, parallel_into_collect};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
@@ -37,10 +37,19 @@ impl DecomposeSearch {
 
 impl Mutation for DecomposeSearch {
     fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
+        // NOTE decomposition creates multiple sub-problems with their own populations
+        if is_memory_pressure(refinement_ctx) {
+            return self.inner_mutation.mutate(refinement_ctx, insertion_ctx);
+        }
+
         decompose_individual(&refinement_ctx, insertion_ctx, self.max_routes_range)
             .map(|contexts| self.refine_decomposed(refinement_ctx, insertion_ctx, contexts))
             .unwrap_or_else(|| self.inner_mutation.mutate(refinement_ctx, insertion_ctx))
     }
+
+    fn name(&self) -> &str {
+        "decomposition"
+    }
 }
 
 const GREEDY_ERROR: &str = "greedy population has no individuals";
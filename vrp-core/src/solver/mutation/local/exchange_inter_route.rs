@@ -4,7 +4,7 @@ mod exchange_inter_route_test;
 
 use crate::construction::heuristics::*;
 use crate::models::problem::Job;
-use crate::solver::mutation::{select_seed_job, LocalOperator};
+use crate::solver::mutation::{select_seed_job, GranularNeighborhood, LocalOperator};
 use crate::solver::RefinementContext;
 use crate::utils::{map_reduce, Noise};
 
@@ -12,18 +12,26 @@ use crate::utils::{map_reduce, Noise};
 pub struct ExchangeInterRouteBest {
     noise_probability: f64,
     noise_range: (f64, f64),
+    neighborhood: Option<GranularNeighborhood>,
 }
 
 /// A local search operator which tries to exchange random jobs between different routes.
 pub struct ExchangeInterRouteRandom {
     noise_probability: f64,
     noise_range: (f64, f64),
+    neighborhood: Option<GranularNeighborhood>,
 }
 
 impl ExchangeInterRouteBest {
     /// Creates a new instance of `ExchangeInterRouteBest`.
     pub fn new(noise_probability: f64, min: f64, max: f64) -> Self {
-        Self { noise_probability, noise_range: (min, max) }
+        Self { noise_probability, noise_range: (min, max), neighborhood: None }
+    }
+
+    /// Restricts exchanges to jobs from granular neighborhood of the seed job.
+    pub fn with_neighborhood(mut self, neighborhood: GranularNeighborhood) -> Self {
+        self.neighborhood = Some(neighborhood);
+        self
     }
 }
 
@@ -38,6 +46,7 @@ impl LocalOperator for ExchangeInterRouteBest {
         find_best_insertion_pair(
             insertion_ctx,
             Noise::new(self.noise_probability, self.noise_range, insertion_ctx.environment.random.clone()),
+            self.neighborhood.as_ref(),
             Box::new(|_| true),
             Box::new(|_| true),
         )
@@ -47,7 +56,13 @@ impl LocalOperator for ExchangeInterRouteBest {
 impl ExchangeInterRouteRandom {
     /// Creates a new instance of `ExchangeInterRouteRandom`.
     pub fn new(noise_probability: f64, min: f64, max: f64) -> Self {
-        Self { noise_probability, noise_range: (min, max) }
+        Self { noise_probability, noise_range: (min, max), neighborhood: None }
+    }
+
+    /// Restricts exchanges to jobs from granular neighborhood of the seed job.
+    pub fn with_neighborhood(mut self, neighborhood: GranularNeighborhood) -> Self {
+        self.neighborhood = Some(neighborhood);
+        self
     }
 }
 
@@ -63,6 +78,7 @@ impl LocalOperator for ExchangeInterRouteRandom {
         find_best_insertion_pair(
             insertion_ctx,
             Noise::new(self.noise_probability, self.noise_range, random.clone()),
+            self.neighborhood.as_ref(),
             {
                 let random = random.clone();
                 Box::new(move |_idx| random.is_head_not_tails())
@@ -80,6 +96,7 @@ type InsertionSuccessPair = (InsertionSuccess, InsertionSuccess);
 fn find_best_insertion_pair(
     insertion_ctx: &InsertionContext,
     noise: Noise,
+    neighborhood: Option<&GranularNeighborhood>,
     filter_route_indices: Box<dyn Fn(usize) -> bool + Send + Sync>,
     filter_jobs_indices: Box<dyn Fn(usize) -> bool + Send + Sync>,
 ) -> Option<InsertionContext> {
@@ -95,6 +112,9 @@ fn find_best_insertion_pair(
 
         let new_insertion_ctx = get_new_insertion_ctx(insertion_ctx, &seed_job, seed_route_idx).unwrap();
         let seed_route = new_insertion_ctx.solution.routes.get(seed_route_idx).unwrap();
+        let neighbors = neighborhood.map(|neighborhood| {
+            neighborhood.get_neighbors(&new_insertion_ctx, seed_route.route.actor.vehicle.profile, &seed_job)
+        });
         let result_selector = NoiseResultSelector::new(noise.clone());
 
         let insertion_pair = new_insertion_ctx
@@ -110,7 +130,11 @@ fn find_best_insertion_pair(
                         .tour
                         .jobs()
                         .enumerate()
-                        .filter(|(idx, job)| !locked.contains(&job) && filter_jobs_indices(*idx))
+                        .filter(|(idx, job)| {
+                            !locked.contains(&job)
+                                && neighbors.as_ref().map_or(true, |neighbors| neighbors.contains(job))
+                                && filter_jobs_indices(*idx)
+                        })
                         .collect::<Vec<_>>()
                         .as_slice(),
                     |(_, test_job)| {
@@ -1,4 +1,5 @@
 use super::super::super::rand::prelude::SliceRandom;
+use super::get_random_route_idx;
 use crate::construction::heuristics::*;
 use crate::models::problem::Job;
 use crate::solver::mutation::LocalOperator;
@@ -73,19 +74,3 @@ fn get_shuffled_jobs(insertion_ctx: &InsertionContext, route_ctx: &RouteContext)
 
     jobs
 }
-
-fn get_random_route_idx(insertion_ctx: &InsertionContext) -> Option<usize> {
-    let routes = insertion_ctx
-        .solution
-        .routes
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, rc)| if rc.route.tour.job_count() > 1 { Some(idx) } else { None })
-        .collect::<Vec<_>>();
-
-    if routes.is_empty() {
-        None
-    } else {
-        Some(routes[insertion_ctx.environment.random.uniform_int(0, (routes.len() - 1) as i32) as usize])
-    }
-}
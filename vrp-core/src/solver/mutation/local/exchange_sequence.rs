@@ -0,0 +1,110 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/local/exchange_sequence_test.rs"]
+mod exchange_sequence_test;
+
+use super::{apply_routes, get_random_segment, insert_segment, remove_segment};
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::mutation::LocalOperator;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+
+/// A local search operator which swaps sequences of consecutive jobs between two routes (CROSS
+/// exchange). Each sequence is inserted at the place of the other one as it is or reversed,
+/// whichever is cheaper. The move is discarded if any job cannot be inserted.
+pub struct ExchangeSequence {
+    min_size: usize,
+    max_size: usize,
+}
+
+impl ExchangeSequence {
+    /// Creates a new instance of `ExchangeSequence` which swaps sequences of `min_size` up to
+    /// `max_size` jobs.
+    pub fn new(min_size: usize, max_size: usize) -> Self {
+        let min_size = min_size.max(1);
+
+        Self { min_size, max_size: max_size.max(min_size) }
+    }
+
+    fn get_sequence(&self, insertion_ctx: &InsertionContext, route_idx: usize) -> Option<(usize, Vec<Job>)> {
+        let route_ctx = insertion_ctx.solution.routes.get(route_idx).unwrap();
+        let (start, mut jobs) = get_random_segment(insertion_ctx, route_ctx, self.max_size)?;
+
+        if jobs.len() < self.min_size {
+            return None;
+        }
+
+        let size = insertion_ctx.environment.random.uniform_int(self.min_size as i32, jobs.len() as i32) as usize;
+        jobs.truncate(size);
+
+        Some((start, jobs))
+    }
+}
+
+impl Default for ExchangeSequence {
+    fn default() -> Self {
+        Self::new(2, 4)
+    }
+}
+
+impl LocalOperator for ExchangeSequence {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let random = &insertion_ctx.environment.random;
+
+        let routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route_ctx)| route_ctx.route.tour.job_count() >= self.min_size)
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        if routes.len() < 2 {
+            return None;
+        }
+
+        let first_idx = random.uniform_int(0, routes.len() as i32 - 1) as usize;
+        let second_idx = (first_idx + random.uniform_int(1, routes.len() as i32 - 1) as usize) % routes.len();
+        let (first_route_idx, second_route_idx) = (routes[first_idx], routes[second_idx]);
+
+        let (first_start, first_jobs) = self.get_sequence(insertion_ctx, first_route_idx)?;
+        let (second_start, second_jobs) = self.get_sequence(insertion_ctx, second_route_idx)?;
+
+        let first_route_ctx =
+            exchange_sequence(insertion_ctx, first_route_idx, &first_jobs, &second_jobs, first_start)?;
+        let second_route_ctx =
+            exchange_sequence(insertion_ctx, second_route_idx, &second_jobs, &first_jobs, second_start)?;
+
+        Some(apply_routes(
+            insertion_ctx,
+            vec![(first_route_idx, first_route_ctx, second_jobs), (second_route_idx, second_route_ctx, first_jobs)],
+        ))
+    }
+}
+
+/// Replaces `removed` jobs in the route by `inserted` ones trying them in both directions.
+fn exchange_sequence(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    removed: &[Job],
+    inserted: &[Job],
+    start: usize,
+) -> Option<RouteContext> {
+    let mut route_ctx = insertion_ctx.solution.routes.get(route_idx).unwrap().deep_copy();
+    remove_segment(insertion_ctx, &mut route_ctx, removed);
+
+    let reversed = inserted.iter().rev().cloned().collect::<Vec<_>>();
+
+    vec![inserted, reversed.as_slice()]
+        .into_iter()
+        .filter_map(|jobs| {
+            let mut new_route_ctx = route_ctx.deep_copy();
+            if insert_segment(insertion_ctx, &mut new_route_ctx, jobs, start) {
+                Some(new_route_ctx)
+            } else {
+                None
+            }
+        })
+        .min_by(|a, b| compare_floats(a.get_route_cost(), b.get_route_cost()))
+}
@@ -1,8 +1,11 @@
 //! This module contains various Local Search operators.
 
 use crate::algorithms::nsga2::Objective;
-use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Profile};
+use crate::models::problem::Job;
 use crate::solver::RefinementContext;
+use hashbrown::HashSet;
 use std::cmp::Ordering;
 
 mod exchange_inter_route;
@@ -11,6 +14,15 @@ pub use self::exchange_inter_route::*;
 mod exchange_intra_route;
 pub use self::exchange_intra_route::*;
 
+mod exchange_sequence;
+pub use self::exchange_sequence::*;
+
+mod segment_intra_route;
+pub use self::segment_intra_route::*;
+
+mod split_merge_routes;
+pub use self::split_merge_routes::*;
+
 /// Specifies behavior of a local search operator.
 pub trait LocalOperator {
     /// Applies local search operator to passed solution in order to explore possible
@@ -19,6 +31,34 @@ pub trait LocalOperator {
         -> Option<InsertionContext>;
 }
 
+/// Specifies granular neighborhood which restricts local search moves to pairs of jobs which are
+/// close to each other. Neighbors are taken from the jobs index which keeps them sorted by distance.
+#[derive(Clone)]
+pub struct GranularNeighborhood {
+    size: usize,
+    threshold: Cost,
+}
+
+impl GranularNeighborhood {
+    /// Creates a new instance of `GranularNeighborhood` which keeps up to `size` nearest neighbors
+    /// with distance not exceeding `threshold`.
+    pub fn new(size: usize, threshold: Option<Cost>) -> Self {
+        Self { size, threshold: threshold.unwrap_or(std::f64::MAX) }
+    }
+
+    /// Returns neighbors of given job which are allowed to be used in a move.
+    pub fn get_neighbors(&self, insertion_ctx: &InsertionContext, profile: Profile, job: &Job) -> HashSet<Job> {
+        insertion_ctx
+            .problem
+            .jobs
+            .neighbors(profile, job, 0.)
+            .take(self.size)
+            .take_while(|(_, distance)| *distance <= self.threshold)
+            .map(|(job, _)| job.clone())
+            .collect()
+    }
+}
+
 /// Provides the way to run multiple local search operators with different probability.
 pub struct CompositeLocalOperator {
     operators: Vec<Box<dyn LocalOperator + Send + Sync>>,
@@ -63,3 +103,114 @@ impl LocalOperator for CompositeLocalOperator {
         Some(old_result)
     }
 }
+
+/// Returns index of a random route which has more than one job.
+fn get_random_route_idx(insertion_ctx: &InsertionContext) -> Option<usize> {
+    let routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, rc)| if rc.route.tour.job_count() > 1 { Some(idx) } else { None })
+        .collect::<Vec<_>>();
+
+    if routes.is_empty() {
+        None
+    } else {
+        Some(routes[insertion_ctx.environment.random.uniform_int(0, (routes.len() - 1) as i32) as usize])
+    }
+}
+
+/// Returns tour index of a randomly selected job and up to `max_size` consecutive jobs starting from
+/// it which can be moved: they are single jobs and they are not locked.
+fn get_random_segment(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    max_size: usize,
+) -> Option<(usize, Vec<Job>)> {
+    let is_movable = |job: &Job| job.as_single().is_some() && !insertion_ctx.solution.locked.contains(job);
+
+    let candidates = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter_map(|(idx, activity)| activity.retrieve_job().filter(|job| is_movable(job)).map(|_| idx))
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let start = candidates[insertion_ctx.environment.random.uniform_int(0, candidates.len() as i32 - 1) as usize];
+    let jobs = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .skip(start)
+        .take(max_size)
+        .map(|activity| activity.retrieve_job())
+        .take_while(|job| matches!(job, Some(job) if is_movable(job)))
+        .flatten()
+        .collect::<Vec<_>>();
+
+    Some((start, jobs))
+}
+
+fn remove_segment(insertion_ctx: &InsertionContext, route_ctx: &mut RouteContext, jobs: &[Job]) {
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+}
+
+/// Inserts jobs one by one starting from given tour index, so they are served in the given order.
+/// Returns false if any of the jobs cannot be inserted there.
+fn insert_segment(insertion_ctx: &InsertionContext, route_ctx: &mut RouteContext, jobs: &[Job], index: usize) -> bool {
+    let result_selector = BestResultSelector::default();
+
+    jobs.iter().enumerate().all(|(offset, job)| {
+        let insertion = evaluate_job_insertion_in_route(
+            job,
+            insertion_ctx,
+            route_ctx,
+            InsertionPosition::Concrete(index + offset - 1),
+            InsertionResult::make_failure(),
+            &result_selector,
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => {
+                let route = route_ctx.route_mut();
+                success.activities.into_iter().for_each(|(activity, idx)| {
+                    route.tour.insert_at(activity, idx + 1);
+                });
+                insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+                true
+            }
+            InsertionResult::Failure(_) => false,
+        }
+    })
+}
+
+/// Replaces routes with given indices by the new ones where the given jobs were inserted.
+fn apply_routes(insertion_ctx: &InsertionContext, routes: Vec<(usize, RouteContext, Vec<Job>)>) -> InsertionContext {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+    let routes = routes
+        .into_iter()
+        .map(|(route_idx, route_ctx, jobs)| {
+            new_insertion_ctx.solution.routes[route_idx] = route_ctx;
+            (route_idx, jobs)
+        })
+        .collect::<Vec<_>>();
+
+    routes.into_iter().for_each(|(route_idx, jobs)| {
+        jobs.iter().for_each(|job| {
+            new_insertion_ctx.problem.constraint.accept_insertion(&mut new_insertion_ctx.solution, route_idx, job)
+        });
+    });
+    finalize_insertion_ctx(&mut new_insertion_ctx);
+
+    new_insertion_ctx
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/local/segment_intra_route_test.rs"]
+mod segment_intra_route_test;
+
+use super::{apply_routes, get_random_route_idx, get_random_segment, insert_segment, remove_segment};
+use crate::construction::heuristics::*;
+use crate::solver::mutation::LocalOperator;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+
+/// A local search operator which reverses a segment of consecutive jobs inside one route (2-opt move).
+/// Segments of different length starting at a random job are evaluated, the cheapest feasible
+/// reversal is returned.
+pub struct TwoOptWithinRoute {
+    max_segment: usize,
+}
+
+impl TwoOptWithinRoute {
+    /// Creates a new instance of `TwoOptWithinRoute` which reverses up to `max_segment` jobs.
+    pub fn new(max_segment: usize) -> Self {
+        Self { max_segment: max_segment.max(2) }
+    }
+}
+
+impl Default for TwoOptWithinRoute {
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
+impl LocalOperator for TwoOptWithinRoute {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let route_idx = get_random_route_idx(insertion_ctx)?;
+        let route_ctx = insertion_ctx.solution.routes.get(route_idx).unwrap();
+        let (start, jobs) = get_random_segment(insertion_ctx, route_ctx, self.max_segment)?;
+
+        let best = (2..=jobs.len())
+            .filter_map(|size| {
+                let segment = &jobs[..size];
+                let mut new_route_ctx = route_ctx.deep_copy();
+                remove_segment(insertion_ctx, &mut new_route_ctx, segment);
+
+                let reversed = segment.iter().rev().cloned().collect::<Vec<_>>();
+                if insert_segment(insertion_ctx, &mut new_route_ctx, reversed.as_slice(), start) {
+                    Some((new_route_ctx, reversed))
+                } else {
+                    None
+                }
+            })
+            .min_by(|(a, _), (b, _)| compare_floats(a.get_route_cost(), b.get_route_cost()))?;
+
+        let (route_ctx, jobs) = best;
+
+        Some(apply_routes(insertion_ctx, vec![(route_idx, route_ctx, jobs)]))
+    }
+}
+
+/// A local search operator which relocates a segment of one up to three consecutive jobs to the
+/// cheapest feasible position inside the same route (Or-opt move).
+pub struct OrOptMove {
+    max_segment: usize,
+}
+
+impl OrOptMove {
+    /// Creates a new instance of `OrOptMove` which relocates up to `max_segment` jobs.
+    pub fn new(max_segment: usize) -> Self {
+        Self { max_segment: max_segment.max(1) }
+    }
+}
+
+impl Default for OrOptMove {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl LocalOperator for OrOptMove {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let route_idx = get_random_route_idx(insertion_ctx)?;
+        let route_ctx = insertion_ctx.solution.routes.get(route_idx).unwrap();
+        let (start, jobs) = get_random_segment(insertion_ctx, route_ctx, self.max_segment)?;
+
+        let size = insertion_ctx.environment.random.uniform_int(1, jobs.len() as i32) as usize;
+        let segment = &jobs[..size];
+
+        let mut removed_route_ctx = route_ctx.deep_copy();
+        remove_segment(insertion_ctx, &mut removed_route_ctx, segment);
+
+        let best = (1..=removed_route_ctx.route.tour.legs().count())
+            .filter(|index| *index != start)
+            .filter_map(|index| {
+                let mut new_route_ctx = removed_route_ctx.deep_copy();
+                if insert_segment(insertion_ctx, &mut new_route_ctx, segment, index) {
+                    Some(new_route_ctx)
+                } else {
+                    None
+                }
+            })
+            .min_by(|a, b| compare_floats(a.get_route_cost(), b.get_route_cost()))?;
+
+        Some(apply_routes(insertion_ctx, vec![(route_idx, best, segment.to_vec())]))
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/local/split_merge_routes_test.rs"]
+mod split_merge_routes_test;
+
+use crate::construction::constraints::TOTAL_DURATION_KEY;
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::mutation::{LocalOperator, Recreate, RecreateWithCheapest};
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+
+/// A local search operator which splits the longest route into two: the second half of its jobs
+/// is moved to a new route served by a spare vehicle.
+#[derive(Default)]
+pub struct SplitRoute {
+    recreate: RecreateWithCheapest,
+}
+
+impl LocalOperator for SplitRoute {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        if !insertion_ctx.solution.required.is_empty() {
+            return None;
+        }
+
+        let route_idx = get_longest_route_idx(insertion_ctx)?;
+        let jobs = get_unlocked_jobs(insertion_ctx, route_idx);
+        let tail = jobs.iter().skip(jobs.len() / 2).cloned().collect::<Vec<_>>();
+
+        if tail.is_empty() || tail.len() == jobs.len() {
+            return None;
+        }
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        let solution = &mut new_insertion_ctx.solution;
+
+        let spare_route_ctx = solution.registry.next().next()?.deep_copy();
+        solution.registry.use_route(&spare_route_ctx);
+        solution.routes.push(spare_route_ctx);
+        let spare_route_idx = solution.routes.len() - 1;
+
+        remove_jobs(&mut new_insertion_ctx, route_idx, tail.as_slice());
+        insert_jobs(&mut new_insertion_ctx, spare_route_idx, tail);
+
+        Some(self.recreate.run(refinement_ctx, new_insertion_ctx))
+    }
+}
+
+/// A local search operator which merges two routes with the least amount of jobs: jobs of the
+/// smallest route are moved to the other one, jobs which cannot be moved are reinserted elsewhere.
+#[derive(Default)]
+pub struct MergeRoutes {
+    recreate: RecreateWithCheapest,
+}
+
+impl LocalOperator for MergeRoutes {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        if !insertion_ctx.solution.required.is_empty() {
+            return None;
+        }
+
+        let mut candidates = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(idx, route_ctx)| get_unlocked_jobs(insertion_ctx, *idx).len() == route_ctx.route.tour.job_count())
+            .map(|(idx, route_ctx)| (idx, route_ctx.route.tour.job_count()))
+            .collect::<Vec<_>>();
+
+        if candidates.len() < 2 {
+            return None;
+        }
+
+        candidates.sort_by_key(|(_, job_count)| *job_count);
+        let (source_idx, target_idx) = (candidates[0].0, candidates[1].0);
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        let jobs = get_unlocked_jobs(&new_insertion_ctx, source_idx);
+
+        remove_jobs(&mut new_insertion_ctx, source_idx, jobs.as_slice());
+        insert_jobs(&mut new_insertion_ctx, target_idx, jobs);
+        new_insertion_ctx.restore();
+
+        Some(self.recreate.run(refinement_ctx, new_insertion_ctx))
+    }
+}
+
+fn get_longest_route_idx(insertion_ctx: &InsertionContext) -> Option<usize> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.job_count() > 1)
+        .map(|(idx, route_ctx)| {
+            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+            (idx, duration, route_ctx.route.tour.job_count())
+        })
+        .max_by(|(_, a_duration, a_jobs), (_, b_duration, b_jobs)| {
+            compare_floats(*a_duration, *b_duration).then_with(|| a_jobs.cmp(b_jobs))
+        })
+        .map(|(idx, _, _)| idx)
+}
+
+/// Returns unlocked jobs of the route in their visit order.
+fn get_unlocked_jobs(insertion_ctx: &InsertionContext, route_idx: usize) -> Vec<Job> {
+    let locked = &insertion_ctx.solution.locked;
+
+    insertion_ctx.solution.routes[route_idx].route.tour.jobs().filter(|job| !locked.contains(job)).collect()
+}
+
+fn remove_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) {
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+
+    constraint.insertion_cache().invalidate(route_ctx);
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    constraint.accept_route_state(route_ctx);
+}
+
+/// Inserts jobs into the route with given index, jobs which cannot be inserted are marked as required.
+fn insert_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: Vec<Job>) {
+    jobs.into_iter().for_each(|job| {
+        let result = evaluate_job_insertion_in_route(
+            &job,
+            insertion_ctx,
+            &insertion_ctx.solution.routes[route_idx],
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+            &BestResultSelector::default(),
+        );
+
+        match result {
+            InsertionResult::Success(_) => apply_insertion_result(insertion_ctx, result),
+            InsertionResult::Failure(_) => insertion_ctx.solution.required.push(job),
+        }
+    });
+}
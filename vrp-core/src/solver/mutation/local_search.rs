@@ -1,25 +1,117 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/local_search_test.rs"]
+mod local_search_test;
+
+use crate::algorithms::nsga2::Objective;
 use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::{Actor, Job};
 use crate::solver::mutation::{LocalOperator, Mutation};
 use crate::solver::RefinementContext;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::sync::{Arc, RwLock};
 
 /// A mutation operator which applies local search principles.
 pub struct LocalSearch {
     operator: Box<dyn LocalOperator + Send + Sync>,
+    tabu: Option<TabuList>,
 }
 
 impl LocalSearch {
     /// Creates a new instance of `LocalSearch`.
     pub fn new(operator: Box<dyn LocalOperator + Send + Sync>) -> Self {
-        Self { operator }
+        Self { operator, tabu: None }
+    }
+
+    /// Enables tabu memory: a job moved to another route cannot be moved back to its original
+    /// route during `tenure` generations unless such move leads to a new best known solution.
+    pub fn with_tabu(mut self, tenure: usize) -> Self {
+        self.tabu = Some(TabuList { tenure, moves: RwLock::new(HashMap::default()) });
+        self
     }
 }
 
 impl Mutation for LocalSearch {
     fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
-        if let Some(new_insertion_ctx) = self.operator.explore(refinement_ctx, insertion_ctx) {
-            new_insertion_ctx
-        } else {
-            insertion_ctx.deep_copy()
+        let new_insertion_ctx = self.operator.explore(refinement_ctx, insertion_ctx);
+
+        match (new_insertion_ctx, self.tabu.as_ref()) {
+            (Some(new_insertion_ctx), Some(tabu)) => {
+                if tabu.try_accept(refinement_ctx, insertion_ctx, &new_insertion_ctx) {
+                    new_insertion_ctx
+                } else {
+                    insertion_ctx.deep_copy()
+                }
+            }
+            (Some(new_insertion_ctx), None) => new_insertion_ctx,
+            _ => insertion_ctx.deep_copy(),
         }
     }
+
+    fn name(&self) -> &str {
+        "local-search"
+    }
+}
+
+/// Keeps recently moved jobs with the route they were moved from and the generation of the move.
+struct TabuList {
+    tenure: usize,
+    moves: RwLock<HashMap<Job, (Arc<Actor>, usize)>>,
+}
+
+impl TabuList {
+    /// Checks whether the new solution reverses any recent move. If not, remembers its moves
+    /// and returns true.
+    fn try_accept(
+        &self,
+        refinement_ctx: &RefinementContext,
+        old_insertion_ctx: &InsertionContext,
+        new_insertion_ctx: &InsertionContext,
+    ) -> bool {
+        let generation = refinement_ctx.statistics.generation;
+        let old_actors = get_job_actors(old_insertion_ctx);
+        let moved = get_job_actors(new_insertion_ctx)
+            .into_iter()
+            .filter_map(|(job, actor)| match old_actors.get(&job) {
+                Some(old_actor) if *old_actor != actor => Some((job, old_actor.clone(), actor)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if moved.is_empty() {
+            return true;
+        }
+
+        let is_tabu = {
+            let moves = self.moves.read().unwrap();
+            moved.iter().any(|(job, _, actor)| {
+                matches!(moves.get(job),
+                    Some((from, move_generation)) if from == actor && generation < move_generation + self.tenure)
+            })
+        };
+
+        // NOTE aspiration criteria: tabu move is allowed if it improves the best known solution
+        let objective = &refinement_ctx.problem.objective;
+        let is_best_known = !matches!(refinement_ctx.population.ranked().next(),
+            Some((best_known, _)) if objective.total_order(best_known, new_insertion_ctx) != Ordering::Greater);
+
+        if is_tabu && !is_best_known {
+            return false;
+        }
+
+        let mut moves = self.moves.write().unwrap();
+        moves.retain(|_, (_, move_generation)| generation < *move_generation + self.tenure);
+        moves.extend(moved.into_iter().map(|(job, from, _)| (job, (from, generation))));
+
+        true
+    }
+}
+
+fn get_job_actors(insertion_ctx: &InsertionContext) -> HashMap<Job, Arc<Actor>> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs().map(move |job| (job, route_ctx.route.actor.clone())))
+        .collect()
 }
@@ -27,11 +27,20 @@ pub use self::decompose_search::DecomposeSearch;
 mod local_search;
 pub use self::local_search::LocalSearch;
 
+mod route_reduction;
+pub use self::route_reduction::RouteReduction;
+
 mod ruin_recreate;
 pub use self::ruin_recreate::RuinAndRecreate;
 
+mod temporal_decompose_search;
+pub use self::temporal_decompose_search::TemporalDecomposeSearch;
+
 /// A trait which defines mutation behavior.
 pub trait Mutation {
     /// Mutates passed insertion context.
     fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext;
+
+    /// Returns a name of the mutation operator which is used to identify it, e.g. in mutation trace.
+    fn name(&self) -> &str;
 }
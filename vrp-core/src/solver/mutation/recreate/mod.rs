@@ -10,7 +10,7 @@ pub trait Recreate {
 }
 
 mod recreate_with_blinks;
-pub use self::recreate_with_blinks::RecreateWithBlinks;
+pub use self::recreate_with_blinks::{create_default_blinks, RecreateWithBlinks};
 
 mod recreate_with_cheapest;
 pub use self::recreate_with_cheapest::RecreateWithCheapest;
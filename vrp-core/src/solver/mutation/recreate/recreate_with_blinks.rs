@@ -7,6 +7,7 @@ use crate::construction::heuristics::{InsertionContext, InsertionResult};
 use crate::models::common::*;
 use crate::models::problem::Job;
 use crate::models::Problem;
+use crate::solver::mutation::has_multi_dim_demand;
 use crate::solver::mutation::recreate::Recreate;
 use crate::solver::RefinementContext;
 use crate::utils::compare_floats;
@@ -124,11 +125,13 @@ impl Default for BlinkResultSelector {
 
 impl ResultSelector for BlinkResultSelector {
     fn select(&self, ctx: &InsertionContext, left: InsertionResult, right: InsertionResult) -> InsertionResult {
-        let is_blink = ctx.environment.random.is_hit(self.ratio);
-        let is_locked = match &right {
-            InsertionResult::Success(success) => ctx.solution.locked.contains(&success.job),
-            _ => false,
+        let (is_locked, priority) = match &right {
+            InsertionResult::Success(success) => {
+                (ctx.solution.locked.contains(&success.job), get_job_priority(&success.job))
+            }
+            _ => (false, DEFAULT_PRIORITY),
         };
+        let is_blink = ctx.environment.random.is_hit((self.ratio * priority as f64).min(1.));
         match (&left, is_blink, is_locked) {
             (InsertionResult::Success(_), true, false) => left,
             _ => InsertionResult::choose_best_result(left, right),
@@ -136,8 +139,18 @@ impl ResultSelector for BlinkResultSelector {
     }
 }
 
+/// A default job priority.
+const DEFAULT_PRIORITY: i32 = 1;
+
+/// Returns job priority specified by priority dimension: bigger value - less important.
+fn get_job_priority(job: &Job) -> i32 {
+    job.dimens().get_priority().unwrap_or(DEFAULT_PRIORITY).max(DEFAULT_PRIORITY)
+}
+
 /// A recreate method as described in "Slack Induction by String Removals for
 /// Vehicle Routing Problems" (aka SISR) paper by Jan Christiaens, Greet Vanden Berghe.
+/// Jobs with higher priority, if it is specified, blink less often, so they are more likely to
+/// get their best positions.
 pub struct RecreateWithBlinks<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
     job_selectors: Vec<Box<dyn JobSelector + Send + Sync>>,
     job_reducer: Box<dyn JobMapReducer + Send + Sync>,
@@ -186,3 +199,13 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> Recreate for Recreat
         )
     }
 }
+
+/// Creates `RecreateWithBlinks` with default parameters and load type which is used by the problem:
+/// `MultiDimLoad` when jobs have multi dimensional demand, `SingleDimLoad` otherwise.
+pub fn create_default_blinks(problem: &Problem) -> Box<dyn Recreate + Send + Sync> {
+    if has_multi_dim_demand(problem) {
+        Box::new(RecreateWithBlinks::<MultiDimLoad>::default())
+    } else {
+        Box::new(RecreateWithBlinks::<SingleDimLoad>::default())
+    }
+}
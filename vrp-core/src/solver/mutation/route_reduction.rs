@@ -0,0 +1,96 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/route_reduction_test.rs"]
+mod route_reduction_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::solver::mutation::{Mutation, Recreate};
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// A mutation which tries to reduce fleet usage: it removes the route with the smallest amount of
+/// jobs and inserts its jobs into the remaining routes without opening new ones. Jobs which cannot
+/// be inserted are left unassigned, so the result is not necessary better than original solution.
+pub struct RouteReduction {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    attempts: usize,
+}
+
+impl RouteReduction {
+    /// Creates a new instance of `RouteReduction` which uses given recreate method and makes up to
+    /// `attempts` tries to insert all jobs of the removed route.
+    pub fn new(recreate: Arc<dyn Recreate + Send + Sync>, attempts: usize) -> Self {
+        Self { recreate, attempts: attempts.max(1) }
+    }
+}
+
+impl Mutation for RouteReduction {
+    fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
+        let route_idx = match get_smallest_route_idx(insertion_ctx) {
+            Some(route_idx) => route_idx,
+            _ => return insertion_ctx.deep_copy(),
+        };
+
+        let mut last_insertion_ctx = None;
+        for _ in 0..self.attempts {
+            let new_insertion_ctx = self.reduce(refinement_ctx, insertion_ctx, route_idx);
+            let is_assigned = new_insertion_ctx.solution.unassigned.len() <= insertion_ctx.solution.unassigned.len();
+
+            last_insertion_ctx = Some(new_insertion_ctx);
+
+            if is_assigned {
+                break;
+            }
+        }
+
+        last_insertion_ctx.unwrap()
+    }
+
+    fn name(&self) -> &str {
+        "route-reduction"
+    }
+}
+
+impl RouteReduction {
+    fn reduce(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+        route_idx: usize,
+    ) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+        let solution = &mut insertion_ctx.solution;
+
+        let route_ctx = solution.routes.remove(route_idx);
+        solution.registry.free_route(&route_ctx);
+        solution.required.extend(route_ctx.route.tour.jobs());
+
+        // NOTE keep only actors of remaining routes in registry, so no new route can be opened
+        let actors = solution.routes.iter().map(|route_ctx| route_ctx.route.actor.clone()).collect::<HashSet<_>>();
+        let registry = solution.registry.deep_slice(|actor| actors.contains(actor));
+        let registry = std::mem::replace(&mut solution.registry, registry);
+
+        insertion_ctx.restore();
+        let mut insertion_ctx = self.recreate.run(refinement_ctx, insertion_ctx);
+
+        insertion_ctx.solution.registry = registry;
+
+        insertion_ctx
+    }
+}
+
+fn get_smallest_route_idx(insertion_ctx: &InsertionContext) -> Option<usize> {
+    let solution = &insertion_ctx.solution;
+
+    if solution.routes.len() < 2 {
+        return None;
+    }
+
+    solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.jobs().all(|job| !solution.locked.contains(&job)))
+        .min_by_key(|(_, route_ctx)| route_ctx.route.tour.job_count())
+        .map(|(idx, _)| idx)
+}
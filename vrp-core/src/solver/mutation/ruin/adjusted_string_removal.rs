@@ -26,12 +26,16 @@ pub struct AdjustedStringRemoval {
     cavg: usize,
     /// Preserved customers ratio.
     alpha: f64,
+    /// Probability to remove a split string (with preserved customers) instead of a sequential one.
+    split_rate: f64,
 }
 
 impl AdjustedStringRemoval {
-    /// Creates a new instance of [`AdjustedStringRemoval`].
-    pub fn new(lmax: usize, cavg: usize, alpha: f64) -> Self {
-        Self { lmax, cavg, alpha }
+    /// Creates a new instance of [`AdjustedStringRemoval`] with given max removed string
+    /// cardinality, average number of removed customers, preserved customers ratio and split
+    /// string probability.
+    pub fn new(lmax: usize, cavg: usize, alpha: f64, split_rate: f64) -> Self {
+        Self { lmax, cavg, alpha, split_rate }
     }
 
     /// Calculates initial parameters from paper using 5,6,7 equations.
@@ -51,7 +55,7 @@ impl AdjustedStringRemoval {
 
 impl Default for AdjustedStringRemoval {
     fn default() -> Self {
-        Self::new(10, 10, 0.01)
+        Self::new(10, 10, 0.01, 0.5)
     }
 }
 
@@ -84,7 +88,7 @@ impl Ruin for AdjustedStringRemoval {
 
                         if let Some(index) = rc.route.tour.index(&job) {
                             actors.write().unwrap().insert(rc.route.actor.clone());
-                            select_string((&rc.route.tour, index), lt, self.alpha, &random)
+                            select_string((&rc.route.tour, index), lt, self.alpha, self.split_rate, &random)
                                 .filter(|job| !locked.contains(job))
                                 .collect::<Vec<Job>>()
                                 .into_iter()
@@ -114,12 +118,13 @@ fn select_string<'a>(
     seed_tour: (&'a Tour, usize),
     cardinality: usize,
     alpha: f64,
+    split_rate: f64,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> JobIter<'a> {
-    if random.is_head_not_tails() {
-        sequential_string(seed_tour, cardinality, random)
-    } else {
+    if random.is_hit(split_rate) {
         preserved_string(seed_tour, cardinality, alpha, random)
+    } else {
+        sequential_string(seed_tour, cardinality, random)
     }
 }
 
@@ -3,15 +3,14 @@
 mod cluster_removal_test;
 
 use super::*;
-use crate::algorithms::dbscan::{create_clusters, Cluster, NeighborhoodFn};
-use crate::algorithms::geometry::Point;
+use crate::algorithms::dbscan::{create_clusters, estimate_epsilon_from_distances, Cluster, NeighborhoodFn};
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::Timestamp;
 use crate::models::problem::Job;
 use crate::models::Problem;
 use crate::solver::mutation::{get_route_jobs, get_selection_chunk_size};
 use crate::solver::RefinementContext;
-use crate::utils::{compare_floats, Random};
+use crate::utils::Random;
 use hashbrown::HashSet;
 use rand::prelude::*;
 use std::ops::Range;
@@ -109,14 +108,7 @@ fn create_job_clusters<'a>(
 /// Estimates DBSCAN epsilon parameter.
 fn estimate_epsilon(problem: &Problem, min_points: usize) -> f64 {
     // for each job get distance to its nth neighbor
-    let mut costs = get_average_costs(problem, min_points);
-
-    // sort all distances in ascending order and form the curve
-    costs.sort_by(|&a, &b| compare_floats(a, b));
-    let curve = costs.into_iter().enumerate().map(|(idx, cost)| Point::new(idx as f64, cost)).collect::<Vec<_>>();
-
-    // get max curvature approximation and return it as a guess for optimal epsilon value
-    get_max_curvature(curve.as_slice())
+    estimate_epsilon_from_distances(get_average_costs(problem, min_points))
 }
 
 /// Gets average costs across all profiles.
@@ -139,27 +131,3 @@ fn get_average_costs(problem: &Problem, min_points: usize) -> Vec<f64> {
 
     costs
 }
-
-/// Gets max curvature approximation: for each point p on the curve, find the one with the maximum
-/// distance d to a line drawn from the first to the last point of the curves.
-fn get_max_curvature(values: &[Point]) -> f64 {
-    if values.is_empty() {
-        return 0.;
-    }
-
-    let first = values.first().unwrap();
-    let last = values.last().unwrap();
-
-    values
-        .iter()
-        .fold((0., std::f64::MIN), |acc, p| {
-            let distance = p.distance_to_line(&first, &last);
-
-            if distance > acc.1 {
-                (p.y, distance)
-            } else {
-                acc
-            }
-        })
-        .0
-}
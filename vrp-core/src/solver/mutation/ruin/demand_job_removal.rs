@@ -0,0 +1,64 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/demand_job_removal_test.rs"]
+mod demand_job_removal_test;
+
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::solver::mutation::{get_job_demand_value, get_selection_chunk_size};
+use crate::solver::RefinementContext;
+
+/// A ruin strategy which removes random jobs from solution with probability proportional to their
+/// demand, so the biggest jobs are more likely to be repacked by recreate. Jobs without demand are
+/// removed as ones with unit demand.
+pub struct DemandJobRemoval {
+    /// Specifies limitation for job removal.
+    limit: JobRemovalLimit,
+}
+
+impl DemandJobRemoval {
+    /// Creates a new instance of `DemandJobRemoval`.
+    pub fn new(limit: JobRemovalLimit) -> Self {
+        Self { limit }
+    }
+}
+
+impl Default for DemandJobRemoval {
+    fn default() -> Self {
+        Self::new(JobRemovalLimit::default())
+    }
+}
+
+impl Ruin for DemandJobRemoval {
+    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        if insertion_ctx.solution.routes.is_empty() {
+            return insertion_ctx;
+        }
+
+        let affected = get_selection_chunk_size(&insertion_ctx, self.limit.min, self.limit.max, self.limit.threshold);
+
+        let solution = &mut insertion_ctx.solution;
+        let random = &insertion_ctx.environment.random;
+
+        let mut candidates = solution
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_index, route_ctx)| route_ctx.route.tour.jobs().map(move |job| (route_index, job)))
+            .filter(|(_, job)| !solution.locked.contains(job))
+            .map(|(route_index, job)| {
+                let weight = get_job_demand_value(&job).unwrap_or(1).max(1) as usize;
+                (route_index, job, weight)
+            })
+            .collect::<Vec<_>>();
+
+        (0..affected.min(candidates.len())).for_each(|_| {
+            let weights = candidates.iter().map(|(_, _, weight)| *weight).collect::<Vec<_>>();
+            let (route_index, job, _) = candidates.swap_remove(random.weighted(weights.as_slice()));
+
+            solution.routes.get_mut(route_index).unwrap().route_mut().tour.remove(&job);
+            solution.required.push(job);
+        });
+
+        insertion_ctx
+    }
+}
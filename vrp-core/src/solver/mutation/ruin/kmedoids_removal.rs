@@ -0,0 +1,135 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/kmedoids_removal_test.rs"]
+mod kmedoids_removal_test;
+
+use super::*;
+use crate::algorithms::clustering::{create_k_medoids, KMedoidsParams};
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Profile, Timestamp};
+use crate::models::problem::Job;
+use crate::models::Problem;
+use crate::solver::mutation::{get_route_jobs, get_selection_chunk_size};
+use crate::solver::RefinementContext;
+use crate::utils::Random;
+use rand::prelude::*;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A ruin strategy which splits all jobs into regions using [`k-medoids`] algorithm and removes
+/// jobs of randomly selected regions. Unlike [`ClusterRemoval`], every job belongs to some region,
+/// so sparse areas are ruined as well.
+///
+/// Regions are created once per transport profile and amount of regions, so clustering cost is
+/// paid on creation.
+///
+/// [`k-medoids`]: ../../algorithms/clustering/fn.create_k_medoids.html
+/// [`ClusterRemoval`]: struct.ClusterRemoval.html
+///
+pub struct KMedoidsRemoval {
+    /// Stores job regions for each profile and amount of regions.
+    regions: Vec<Vec<Vec<Job>>>,
+    /// Specifies limitation for job removal.
+    limit: JobRemovalLimit,
+}
+
+impl KMedoidsRemoval {
+    /// Creates a new instance of `KMedoidsRemoval` which splits jobs into amount of regions from
+    /// given range.
+    pub fn new(
+        problem: Arc<Problem>,
+        random: Arc<dyn Random + Send + Sync>,
+        regions: Range<usize>,
+        limit: JobRemovalLimit,
+    ) -> Self {
+        let min = regions.start.max(2);
+        let max = regions.end.min(problem.jobs.size()).max(min + 1);
+
+        let regions = problem
+            .fleet
+            .profiles
+            .iter()
+            .flat_map(|&profile| (min..max).map(move |k| (profile, k)))
+            .map(|(profile, k)| create_job_regions(problem.as_ref(), profile, k, random.as_ref()))
+            .filter(|regions| !regions.is_empty())
+            .collect();
+
+        Self { regions, limit }
+    }
+
+    /// Creates a new instance of `KMedoidsRemoval` with default parameters.
+    pub fn new_with_defaults(problem: Arc<Problem>, random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::new(problem, random, 4..9, JobRemovalLimit::default())
+    }
+}
+
+impl Ruin for KMedoidsRemoval {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        if self.regions.is_empty() {
+            return insertion_ctx;
+        }
+
+        let random = insertion_ctx.environment.random.clone();
+        let regions = &self.regions[random.uniform_int(0, self.regions.len() as i32 - 1) as usize];
+
+        let mut region_indices = (0..regions.len()).collect::<Vec<_>>();
+        region_indices.shuffle(&mut random.get_rng());
+
+        let mut route_jobs = get_route_jobs(&insertion_ctx.solution);
+        let locked = insertion_ctx.solution.locked.clone();
+        let max_affected =
+            get_selection_chunk_size(&insertion_ctx, self.limit.min, self.limit.max, self.limit.threshold);
+        let mut removed_jobs = Vec::with_capacity(max_affected);
+
+        for region_idx in region_indices {
+            if removed_jobs.len() >= max_affected {
+                break;
+            }
+
+            let mut jobs = regions[region_idx].iter().filter(|job| !locked.contains(*job)).collect::<Vec<_>>();
+            if jobs.len() > max_affected - removed_jobs.len() {
+                jobs.shuffle(&mut random.get_rng());
+            }
+
+            jobs.into_iter().for_each(|job| {
+                if removed_jobs.len() >= max_affected {
+                    return;
+                }
+
+                if let Some(rc) = route_jobs.get_mut(job) {
+                    // NOTE actual insertion context modification via route mut
+                    if rc.route.tour.contains(job) {
+                        rc.route_mut().tour.remove(job);
+                        removed_jobs.push(job.clone());
+                    }
+                }
+            });
+        }
+
+        insertion_ctx.solution.required.extend(removed_jobs);
+
+        insertion_ctx
+    }
+}
+
+/// Splits all jobs into regions using k-medoids algorithm with routing costs as distance.
+fn create_job_regions(
+    problem: &Problem,
+    profile: Profile,
+    k: usize,
+    random: &(dyn Random + Send + Sync),
+) -> Vec<Vec<Job>> {
+    let jobs = problem.jobs.all_as_slice();
+    let distance_fn = |from: &Job, to: &Job| {
+        if from == to {
+            0.
+        } else {
+            problem.jobs.distance(profile, from, to, Timestamp::default())
+        }
+    };
+
+    create_k_medoids(jobs, &KMedoidsParams::new(k), distance_fn, random)
+        .into_iter()
+        .map(|cluster| cluster.items.into_iter().cloned().collect::<Vec<_>>())
+        .filter(|region| !region.is_empty())
+        .collect()
+}
@@ -18,6 +18,12 @@ pub use self::adjusted_string_removal::AdjustedStringRemoval;
 mod cluster_removal;
 pub use self::cluster_removal::ClusterRemoval;
 
+mod demand_job_removal;
+pub use self::demand_job_removal::DemandJobRemoval;
+
+mod kmedoids_removal;
+pub use self::kmedoids_removal::KMedoidsRemoval;
+
 mod neighbour_removal;
 pub use self::neighbour_removal::NeighbourRemoval;
 
@@ -30,6 +36,9 @@ pub use self::random_job_removal::RandomJobRemoval;
 mod worst_jobs_removal;
 pub use self::worst_jobs_removal::WorstJobRemoval;
 
+mod worst_segment_removal;
+pub use self::worst_segment_removal::WorstSegmentRemoval;
+
 /// A type which specifies a group of multiple ruin strategies with their probability.
 pub type RuinGroup = (Vec<(Arc<dyn Ruin + Send + Sync>, f64)>, usize);
 
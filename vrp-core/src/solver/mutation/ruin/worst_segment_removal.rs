@@ -0,0 +1,131 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/worst_segment_removal_test.rs"]
+mod worst_segment_removal_test;
+
+use super::*;
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job, TransportCost};
+use crate::models::solution::Activity;
+use crate::solver::mutation::{get_demand_value, get_selection_chunk_size};
+use crate::solver::RefinementContext;
+use crate::utils::{compare_floats, parallel_collect};
+use hashbrown::HashSet;
+
+/// A ruin strategy which detects contiguous route segments with the highest detour cost per served
+/// demand (long detours for little load) and removes them.
+pub struct WorstSegmentRemoval {
+    /// Specifies limitation for job removal.
+    limit: JobRemovalLimit,
+    /// Specifies min and max amount of activities in segment.
+    segment_range: (usize, usize),
+    /// Amount of segments to skip.
+    worst_skip: usize,
+}
+
+impl WorstSegmentRemoval {
+    /// Creates a new instance of `WorstSegmentRemoval`.
+    pub fn new(segment_range: (usize, usize), worst_skip: usize, limit: JobRemovalLimit) -> Self {
+        let segment_range = (segment_range.0.max(1), segment_range.1.max(segment_range.0.max(1)));
+
+        Self { limit, segment_range, worst_skip }
+    }
+}
+
+impl Default for WorstSegmentRemoval {
+    fn default() -> Self {
+        Self::new((2, 4), 2, JobRemovalLimit::default())
+    }
+}
+
+impl Ruin for WorstSegmentRemoval {
+    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = insertion_ctx.environment.random.clone();
+        let affected = get_selection_chunk_size(&insertion_ctx, self.limit.min, self.limit.max, self.limit.threshold);
+
+        let mut segments = get_segments(&insertion_ctx, self.segment_range);
+        segments.sort_by(|(_, _, a), (_, _, b)| compare_floats(*b, *a));
+
+        let skip = segments.len().min(random.uniform_int(0, self.worst_skip as i32) as usize);
+        let mut removed_jobs = HashSet::<Job>::default();
+
+        segments.into_iter().skip(skip).for_each(|(route_ctx, jobs, _)| {
+            if removed_jobs.len() >= affected || jobs.iter().any(|job| removed_jobs.contains(job)) {
+                return;
+            }
+
+            let mut route_ctx = route_ctx;
+            jobs.into_iter().take(affected - removed_jobs.len()).for_each(|job| {
+                // NOTE actual insertion context modification via route mut
+                if route_ctx.route.tour.contains(&job) {
+                    route_ctx.route_mut().tour.remove(&job);
+                    removed_jobs.insert(job);
+                }
+            });
+        });
+
+        insertion_ctx.solution.required.extend(removed_jobs);
+
+        insertion_ctx
+    }
+}
+
+/// Returns route segments with their jobs and detour cost per served demand.
+fn get_segments(
+    insertion_ctx: &InsertionContext,
+    segment_range: (usize, usize),
+) -> Vec<(RouteContext, Vec<Job>, Cost)> {
+    let solution = &insertion_ctx.solution;
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    let can_remove_job = |job: &Job| !solution.locked.contains(job) && !solution.unassigned.contains_key(job);
+
+    parallel_collect(&solution.routes, |route_ctx| {
+        let actor = route_ctx.route.actor.as_ref();
+        let activities = route_ctx.route.tour.all_activities().collect::<Vec<_>>();
+
+        // NOTE first and last activities are kept as segment boundaries
+        (1..activities.len().saturating_sub(1))
+            .flat_map(|start| (segment_range.0..=segment_range.1).map(move |size| (start, start + size)))
+            .filter(|(_, end)| *end < activities.len())
+            .filter_map(|(start, end)| {
+                let segment = &activities[start..end];
+                let jobs = segment.iter().filter_map(|activity| activity.retrieve_job()).fold(
+                    Vec::<Job>::new(),
+                    |mut jobs, job| {
+                        if !jobs.contains(&job) {
+                            jobs.push(job);
+                        }
+                        jobs
+                    },
+                );
+
+                if jobs.is_empty() || !jobs.iter().all(&can_remove_job) {
+                    return None;
+                }
+
+                let (prev, next) = (activities[start - 1], activities[end]);
+                let detour = get_cost(actor, prev, segment[0], transport)
+                    + segment.windows(2).map(|pair| get_cost(actor, pair[0], pair[1], transport)).sum::<Cost>()
+                    + get_cost(actor, segment[segment.len() - 1], next, transport)
+                    - get_cost(actor, prev, next, transport);
+                let demand = segment.iter().map(|activity| get_demand(activity)).sum::<i32>().max(1);
+
+                Some((route_ctx.clone(), jobs, detour / demand as f64))
+            })
+            .collect::<Vec<_>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns served demand of the activity or one if activity has no demand.
+fn get_demand(activity: &Activity) -> i32 {
+    activity.job.as_ref().and_then(|single| get_demand_value(&single.dimens)).unwrap_or(1).max(1)
+}
+
+#[inline(always)]
+fn get_cost(actor: &Actor, from: &Activity, to: &Activity, transport: &(dyn TransportCost + Send + Sync)) -> Cost {
+    transport.cost(actor, from.place.location, to.place.location, from.schedule.departure)
+}
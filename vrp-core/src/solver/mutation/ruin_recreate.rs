@@ -18,4 +18,8 @@ impl Mutation for RuinAndRecreate {
     fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
         self.recreate.run(refinement_ctx, self.ruin.run(refinement_ctx, insertion_ctx.deep_copy()))
     }
+
+    fn name(&self) -> &str {
+        "ruin-recreate"
+    }
 }
@@ -0,0 +1,135 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/temporal_decompose_search_test.rs"]
+mod temporal_decompose_search_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::problem::Job;
+use crate::solver::mutation::Mutation;
+use crate::solver::population::{Greedy, Individual};
+use crate::solver::{is_memory_pressure, RefinementContext};
+use crate::utils::compare_floats;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A mutation which decomposes original solution into temporal slices using activity arrival
+/// times, e.g. morning and afternoon jobs, and performs search within each slice while jobs of
+/// other slices are kept in place. Slices are refined one after another, so each next slice is
+/// refined on top of the best result of the previous one.
+pub struct TemporalDecomposeSearch {
+    inner_mutation: Arc<dyn Mutation + Send + Sync>,
+    slices_range: (i32, i32),
+    repeat_count: usize,
+}
+
+impl TemporalDecomposeSearch {
+    /// Create a new instance of `TemporalDecomposeSearch`.
+    pub fn new(
+        inner_mutation: Arc<dyn Mutation + Send + Sync>,
+        slices_range: (usize, usize),
+        repeat_count: usize,
+    ) -> Self {
+        let slices_range = (slices_range.0 as i32, slices_range.1 as i32);
+
+        Self { inner_mutation, slices_range, repeat_count }
+    }
+}
+
+impl Mutation for TemporalDecomposeSearch {
+    fn mutate(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
+        if is_memory_pressure(refinement_ctx) {
+            return self.inner_mutation.mutate(refinement_ctx, insertion_ctx);
+        }
+
+        let slices_count = insertion_ctx.environment.random.uniform_int(self.slices_range.0, self.slices_range.1);
+        let slices = create_temporal_slices(insertion_ctx, slices_count.max(2) as usize);
+
+        if slices.len() < 2 {
+            return self.inner_mutation.mutate(refinement_ctx, insertion_ctx);
+        }
+
+        slices
+            .iter()
+            .fold(insertion_ctx.deep_copy(), |individual, slice| self.refine_slice(refinement_ctx, individual, slice))
+    }
+
+    fn name(&self) -> &str {
+        "temporal-decomposition"
+    }
+}
+
+impl TemporalDecomposeSearch {
+    fn refine_slice(
+        &self,
+        refinement_ctx: &RefinementContext,
+        individual: Individual,
+        slice: &HashSet<Job>,
+    ) -> Individual {
+        let original_locked = individual.solution.locked.clone();
+
+        let mut sliced = individual.deep_copy();
+        sliced.solution.locked.extend(
+            sliced
+                .solution
+                .routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+                .filter(|job| !slice.contains(job)),
+        );
+
+        let mut slice_ctx = RefinementContext {
+            problem: refinement_ctx.problem.clone(),
+            population: Box::new(Greedy::new(refinement_ctx.problem.clone(), Some(sliced))),
+            state: Default::default(),
+            quota: refinement_ctx.quota.clone(),
+            environment: refinement_ctx.environment.clone(),
+            statistics: Default::default(),
+        };
+
+        (0..self.repeat_count).for_each(|_| {
+            let insertion_ctx = slice_ctx.population.select().next().expect(GREEDY_ERROR);
+            let insertion_ctx = self.inner_mutation.mutate(&slice_ctx, insertion_ctx);
+            slice_ctx.population.add(insertion_ctx);
+        });
+
+        let (best, _) = slice_ctx.population.ranked().next().expect(GREEDY_ERROR);
+        let mut best = best.deep_copy();
+        best.solution.locked = original_locked;
+
+        if refinement_ctx.problem.objective.total_order(&best, &individual) == Ordering::Less {
+            best
+        } else {
+            individual
+        }
+    }
+}
+
+const GREEDY_ERROR: &str = "greedy population has no individuals";
+
+/// Splits assigned jobs into slices with approximately the same amount of jobs using the earliest
+/// arrival time of job activities.
+fn create_temporal_slices(insertion_ctx: &InsertionContext, slices_count: usize) -> Vec<HashSet<Job>> {
+    let mut arrivals = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+        .filter_map(|activity| activity.retrieve_job().map(|job| (job, activity.schedule.arrival)))
+        .fold(HashMap::<Job, f64>::new(), |mut acc, (job, arrival)| {
+            let earliest = acc.entry(job).or_insert(arrival);
+            *earliest = earliest.min(arrival);
+            acc
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let slice_size = (arrivals.len() as f64 / slices_count as f64).ceil() as usize;
+    if slice_size == 0 {
+        return vec![];
+    }
+
+    arrivals.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+    arrivals.chunks(slice_size).map(|chunk| chunk.iter().map(|(job, _)| job.clone()).collect()).collect()
+}
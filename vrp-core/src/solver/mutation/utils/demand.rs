@@ -0,0 +1,42 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/utils/demand_test.rs"]
+mod demand_test;
+
+use crate::models::common::{DemandDimension, Dimensions, MultiDimLoad, SingleDimLoad};
+use crate::models::problem::Job;
+use crate::models::Problem;
+
+/// Returns total absolute demand value (static and dynamic, pickup and delivery) of single or
+/// multi dimensional load, if demand is defined.
+pub(crate) fn get_demand_value(dimens: &Dimensions) -> Option<i32> {
+    DemandDimension::<SingleDimLoad>::get_demand(dimens)
+        .map(|demand| (demand.pickup.0 + demand.pickup.1 + demand.delivery.0 + demand.delivery.1).value.abs())
+        .or_else(|| {
+            DemandDimension::<MultiDimLoad>::get_demand(dimens).map(|demand| {
+                let load = demand.pickup.0 + demand.pickup.1 + demand.delivery.0 + demand.delivery.1;
+                load.load.iter().take(load.size).map(|value| value.abs()).sum()
+            })
+        })
+}
+
+/// Returns total demand value of all job's activities.
+pub(crate) fn get_job_demand_value(job: &Job) -> Option<i32> {
+    match job {
+        Job::Single(single) => get_demand_value(&single.dimens),
+        Job::Multi(multi) => multi
+            .jobs
+            .iter()
+            .filter_map(|single| get_demand_value(&single.dimens))
+            .fold(None, |acc, value| Some(acc.unwrap_or(0) + value)),
+    }
+}
+
+/// Checks whether problem has jobs with multi dimensional demand.
+pub(crate) fn has_multi_dim_demand(problem: &Problem) -> bool {
+    problem.jobs.all().any(|job| match &job {
+        Job::Single(single) => DemandDimension::<MultiDimLoad>::get_demand(&single.dimens).is_some(),
+        Job::Multi(multi) => {
+            multi.jobs.iter().any(|single| DemandDimension::<MultiDimLoad>::get_demand(&single.dimens).is_some())
+        }
+    })
+}
@@ -1,2 +1,5 @@
+mod demand;
+pub(crate) use self::demand::*;
+
 mod selection;
 pub(crate) use self::selection::*;
@@ -0,0 +1,66 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/driver_consistency_test.rs"]
+mod driver_consistency_test;
+
+use super::route_value::create_route_value_objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::{Cost, IdDimension};
+use crate::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
+use crate::models::solution::Route;
+use crate::solver::DRIVER_CONSISTENCY_KEY;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A function which returns id of the vehicle the job was assigned to in the previous solution.
+pub type PreviousAssignmentFunc = Arc<dyn Fn(&Job) -> Option<String> + Send + Sync>;
+
+/// An objective function which minimizes amount of jobs assigned to a different vehicle (driver)
+/// than in the previous solution. Useful when re-planning is done periodically, e.g. daily,
+/// and customers prefer to be served by the same driver.
+pub struct DriverConsistency {
+    previous_func: PreviousAssignmentFunc,
+}
+
+impl DriverConsistency {
+    /// Creates _(constraint, objective)_ type pair which keeps driver consistency. Weight specifies
+    /// an insertion penalty for each job assigned to a different vehicle.
+    pub fn new_with_constraint(
+        previous_func: PreviousAssignmentFunc,
+        weight: Cost,
+    ) -> (TargetConstraint, TargetObjective) {
+        let consistency = Arc::new(Self { previous_func });
+        let constraint = DriverConsistencySoftRouteConstraint { consistency: consistency.clone(), weight };
+
+        create_route_value_objective(
+            DRIVER_CONSISTENCY_KEY,
+            Arc::new(move |route_ctx| consistency.get_route_changes(&route_ctx.route)),
+            vec![ConstraintVariant::SoftRoute(Arc::new(constraint))],
+        )
+    }
+
+    /// Checks whether job assignment to the actor differs from the previous one.
+    pub fn is_changed(&self, actor: &Actor, job: &Job) -> bool {
+        self.previous_func.deref()(job)
+            .map_or(false, |vehicle_id| actor.vehicle.dimens.get_id().map_or(true, |id| *id != vehicle_id))
+    }
+
+    fn get_route_changes(&self, route: &Route) -> f64 {
+        route.tour.jobs().filter(|job| self.is_changed(route.actor.as_ref(), job)).count() as f64
+    }
+}
+
+struct DriverConsistencySoftRouteConstraint {
+    consistency: Arc<DriverConsistency>,
+    weight: Cost,
+}
+
+impl SoftRouteConstraint for DriverConsistencySoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        if self.consistency.is_changed(ctx.route.actor.as_ref(), job) {
+            self.weight
+        } else {
+            0.
+        }
+    }
+}
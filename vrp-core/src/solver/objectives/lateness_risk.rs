@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/lateness_risk_test.rs"]
+mod lateness_risk_test;
+
+use super::route_value::create_route_value_objective;
+use crate::algorithms::statistics::{get_normal_cdf, get_normal_quantile};
+use crate::models::common::{Duration, Location, Profile};
+use crate::models::problem::{TargetConstraint, TargetObjective};
+use crate::models::solution::Route;
+use crate::solver::LATENESS_RISK_KEY;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A function which returns standard deviation of travel time between two locations.
+pub type TravelDeviationFunc = Arc<dyn Fn(Profile, Location, Location) -> Duration + Send + Sync>;
+
+/// An objective function which minimizes risk to be late at activities when travel times are
+/// uncertain. Travel times are expected to be planned using given percentile of their distribution,
+/// so risk is estimated as a sum of probabilities to miss activity's time window.
+pub struct LatenessRisk {
+    deviation_func: TravelDeviationFunc,
+    percentile: f64,
+}
+
+impl LatenessRisk {
+    /// Creates a new instance of `LatenessRisk`. Percentile is the one used to plan travel times.
+    pub fn new(deviation_func: TravelDeviationFunc, percentile: f64) -> Self {
+        Self { deviation_func, percentile }
+    }
+
+    /// Creates _(constraint, objective)_ type pair which minimizes lateness risk. Percentile is
+    /// the one used to plan travel times, e.g. 0.5 when travel times are mean values.
+    pub fn new_with_constraint(
+        deviation_func: TravelDeviationFunc,
+        percentile: f64,
+    ) -> (TargetConstraint, TargetObjective) {
+        let risk = Self::new(deviation_func, percentile);
+
+        create_route_value_objective(
+            LATENESS_RISK_KEY,
+            Arc::new(move |route_ctx| risk.get_route_risk(&route_ctx.route)),
+            vec![],
+        )
+    }
+
+    /// Returns probabilities to start each route activity, except the first one, within its
+    /// time window. Delays accumulate along the route: travel times of legs are assumed to be
+    /// independent and normally distributed, so variance of arrival time is a sum of variances
+    /// of all previous legs. Waiting time is not used to absorb delays.
+    pub fn get_on_time_probabilities(&self, route: &Route) -> Vec<f64> {
+        let profile = route.actor.vehicle.profile;
+        let z = get_normal_quantile(self.percentile);
+
+        route
+            .tour
+            .all_activities()
+            .zip(route.tour.all_activities().skip(1))
+            .scan((0., 0.), |(variance, shift), (prev, next)| {
+                let deviation = self.deviation_func.deref()(profile, prev.place.location, next.place.location);
+
+                *variance += deviation * deviation;
+                *shift += z * deviation;
+
+                let slack = next.place.time.end - next.schedule.arrival;
+
+                Some(if compare_floats(*variance, 0.) == Ordering::Greater {
+                    get_normal_cdf((slack + *shift) / variance.sqrt())
+                } else if slack >= 0. {
+                    1.
+                } else {
+                    0.
+                })
+            })
+            .collect()
+    }
+
+    fn get_route_risk(&self, route: &Route) -> f64 {
+        self.get_on_time_probabilities(route).into_iter().map(|probability| 1. - probability).sum()
+    }
+}
@@ -3,6 +3,23 @@
 use crate::construction::heuristics::InsertionContext;
 use std::cmp::Ordering;
 
+mod driver_consistency;
+pub use self::driver_consistency::{DriverConsistency, PreviousAssignmentFunc};
+
+mod lateness_risk;
+pub use self::lateness_risk::{LatenessRisk, TravelDeviationFunc};
+
+mod preferred_times;
+pub use self::preferred_times::{PreferredTimes, PreferredTimesFunc};
+
+mod route_value;
+
+mod route_compactness;
+pub use self::route_compactness::{LocationPointFunc, RouteCompactness};
+
+mod slack_reservation;
+pub use self::slack_reservation::SlackReservation;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
@@ -10,7 +27,13 @@ mod total_transport_cost;
 pub use self::total_transport_cost::TotalTransportCost;
 
 mod total_unassigned_jobs;
-pub use self::total_unassigned_jobs::TotalUnassignedJobs;
+pub use self::total_unassigned_jobs::{get_priority_weight, TotalUnassignedJobs};
+
+mod total_value;
+pub use self::total_value::{JobValueFunc, TotalValue};
+
+mod total_violations;
+pub use self::total_violations::{RelaxableConstraint, TotalViolations, ViolationCountFunc};
 
 mod work_balance;
 pub use self::work_balance::WorkBalance;
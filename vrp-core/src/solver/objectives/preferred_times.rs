@@ -0,0 +1,109 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/preferred_times_test.rs"]
+mod preferred_times_test;
+
+use super::route_value::create_route_value_objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext};
+use crate::models::common::{Cost, TimeWindow, Timestamp};
+use crate::models::problem::{TargetConstraint, TargetObjective, TransportCost};
+use crate::models::solution::{Activity, Route};
+use crate::solver::PREFERRED_TIMES_KEY;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A function which returns time windows preferred by customer for given activity, if they are specified.
+pub type PreferredTimesFunc = Arc<dyn Fn(&Activity) -> Option<&[TimeWindow]> + Send + Sync>;
+
+/// An objective function which minimizes amount of activities started outside of time windows
+/// preferred by customers. Unlike regular time windows, preferred ones are not enforced.
+pub struct PreferredTimes {
+    preferred_func: PreferredTimesFunc,
+}
+
+impl PreferredTimes {
+    /// Creates a new instance of `PreferredTimes`.
+    pub fn new(preferred_func: PreferredTimesFunc) -> Self {
+        Self { preferred_func }
+    }
+
+    /// Creates _(constraint, objective)_ type pair which prefers serving customers within their
+    /// preferred time windows. Weight specifies an insertion penalty for each missed preferred time window.
+    pub fn new_with_constraint(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        preferred_func: PreferredTimesFunc,
+        weight: Cost,
+    ) -> (TargetConstraint, TargetObjective) {
+        let preferences = Arc::new(Self::new(preferred_func));
+        let constraint = PreferredTimesSoftActivityConstraint { preferences: preferences.clone(), transport, weight };
+
+        create_route_value_objective(
+            PREFERRED_TIMES_KEY,
+            Arc::new(move |route_ctx| preferences.get_route_misses(&route_ctx.route)),
+            vec![ConstraintVariant::SoftActivity(Arc::new(constraint))],
+        )
+    }
+
+    /// Checks whether service of the activity started at given time is within its preferred time
+    /// windows. Returns None if activity has no preferred time windows.
+    pub fn is_hit(&self, activity: &Activity, arrival: Timestamp) -> Option<bool> {
+        let start = arrival.max(activity.place.time.start);
+
+        self.preferred_func.deref()(activity)
+            .map(|times| times.iter().any(|time| time.start <= start && start <= time.end))
+    }
+
+    /// Returns amount of route activities with preferred time windows and amount of them started
+    /// within these windows.
+    pub fn get_route_hits(&self, route: &Route) -> (usize, usize) {
+        route
+            .tour
+            .all_activities()
+            .filter_map(|activity| self.is_hit(activity, activity.schedule.arrival))
+            .fold((0, 0), |(total, hits), is_hit| (total + 1, if is_hit { hits + 1 } else { hits }))
+    }
+
+    fn get_route_misses(&self, route: &Route) -> f64 {
+        let (total, hits) = self.get_route_hits(route);
+
+        (total - hits) as f64
+    }
+
+    fn get_miss(&self, activity: &Activity, arrival: Timestamp) -> f64 {
+        match self.is_hit(activity, arrival) {
+            Some(false) => 1.,
+            _ => 0.,
+        }
+    }
+}
+
+/// Penalizes activity insertions which start target activity outside of its preferred time windows
+/// or move the next activity out of them. Further schedule shift is not considered.
+struct PreferredTimesSoftActivityConstraint {
+    preferences: Arc<PreferredTimes>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    weight: Cost,
+}
+
+impl SoftActivityConstraint for PreferredTimesSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let departure = prev.schedule.departure;
+        let arrival =
+            departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+        let target_miss = self.preferences.get_miss(target, arrival);
+
+        let next_miss = activity_ctx.next.map_or(0., |next| {
+            let departure = arrival.max(target.place.time.start) + target.place.duration;
+            let arrival =
+                departure + self.transport.duration(profile, target.place.location, next.place.location, departure);
+
+            self.preferences.get_miss(next, arrival) - self.preferences.get_miss(next, next.schedule.arrival)
+        });
+
+        (target_miss + next_miss) * self.weight
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/route_compactness_test.rs"]
+mod route_compactness_test;
+
+use super::route_value::{create_route_value_objective, get_route_value};
+use crate::algorithms::geometry::{get_convex_hull, get_polygon_area, Point};
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::{Cost, Location};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::models::solution::Route;
+use crate::solver::ROUTE_COMPACTNESS_KEY;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A function which returns a point on a plane for given location, if it is known.
+pub type LocationPointFunc = Arc<dyn Fn(Location) -> Option<Point> + Send + Sync>;
+
+/// An objective function which minimizes total area of routes' convex hulls, so resulting routes
+/// look geographically compact.
+pub struct RouteCompactness {
+    point_func: LocationPointFunc,
+}
+
+impl RouteCompactness {
+    /// Creates _(constraint, objective)_ type pair which keeps routes compact. Weight specifies
+    /// an insertion penalty per unit of route area increase.
+    pub fn new_with_constraint(point_func: LocationPointFunc, weight: Cost) -> (TargetConstraint, TargetObjective) {
+        let compactness = Arc::new(Self { point_func });
+        let constraint = RouteCompactnessSoftRouteConstraint { compactness: compactness.clone(), weight };
+
+        create_route_value_objective(
+            ROUTE_COMPACTNESS_KEY,
+            Arc::new(move |route_ctx| compactness.get_route_area(&route_ctx.route)),
+            vec![ConstraintVariant::SoftRoute(Arc::new(constraint))],
+        )
+    }
+
+    /// Returns convex hull area of all route locations.
+    pub fn get_route_area(&self, route: &Route) -> f64 {
+        self.get_area(route.tour.all_activities().map(|activity| activity.place.location))
+    }
+
+    fn get_area<I: Iterator<Item = Location>>(&self, locations: I) -> f64 {
+        let points = locations.filter_map(|location| self.point_func.deref()(location)).collect::<Vec<_>>();
+
+        get_polygon_area(get_convex_hull(points.as_slice()).as_slice())
+    }
+}
+
+/// Penalizes job insertions which increase route area. As exact insertion place is not known on
+/// route level, the first location of each job task is used.
+struct RouteCompactnessSoftRouteConstraint {
+    compactness: Arc<RouteCompactness>,
+    weight: Cost,
+}
+
+impl SoftRouteConstraint for RouteCompactnessSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        let singles = match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.iter().collect(),
+        };
+        let job_locations = singles
+            .into_iter()
+            .filter_map(|single| single.places.iter().filter_map(|place| place.location).next())
+            .collect::<Vec<_>>();
+
+        if job_locations.is_empty() {
+            return 0.;
+        }
+
+        let current_area = get_route_value(ctx, ROUTE_COMPACTNESS_KEY, || self.compactness.get_route_area(&ctx.route));
+        let new_area = self
+            .compactness
+            .get_area(ctx.route.tour.all_activities().map(|activity| activity.place.location).chain(job_locations));
+
+        (new_area - current_area).max(0.) * self.weight
+    }
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::utils::compare_floats;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which calculates a value of the route to be minimized.
+pub(crate) type RouteValueFunc = Arc<dyn Fn(&RouteContext) -> f64 + Send + Sync>;
+
+/// Creates _(constraint, objective)_ type pair which minimizes a sum of route values. Route value is
+/// kept in route state under given key, insertions are estimated by given constraints.
+pub(crate) fn create_route_value_objective(
+    key: i32,
+    value_func: RouteValueFunc,
+    constraints: Vec<ConstraintVariant>,
+) -> (TargetConstraint, TargetObjective) {
+    let constraint = RouteValueModule { key, value_func: value_func.clone(), constraints, keys: vec![key] };
+
+    (Box::new(constraint), Box::new(RouteValueObjective { key, value_func }))
+}
+
+/// Returns route value kept in route state under given key or calculates it if state is not set.
+pub(crate) fn get_route_value<F: FnOnce() -> f64>(route_ctx: &RouteContext, key: i32, value_func: F) -> f64 {
+    route_ctx.state.get_route_state::<f64>(key).cloned().unwrap_or_else(value_func)
+}
+
+struct RouteValueObjective {
+    key: i32,
+    value_func: RouteValueFunc,
+}
+
+impl Objective for RouteValueObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| get_route_value(route_ctx, self.key, || self.value_func.deref()(route_ctx)))
+            .sum()
+    }
+}
+
+/// A module which keeps route value in its state.
+struct RouteValueModule {
+    key: i32,
+    value_func: RouteValueFunc,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for RouteValueModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let value = self.value_func.deref()(ctx);
+
+        ctx.state_mut().put_route_state(self.key, value);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/slack_reservation_test.rs"]
+mod slack_reservation_test;
+
+use super::route_value::{create_route_value_objective, get_route_value};
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::{CapacityDimension, Cost, Demand, DemandDimension, Load};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::solver::SLACK_RESERVATION_KEY;
+use std::ops::{Add, Deref, Sub};
+use std::sync::Arc;
+
+/// A function which returns max load ratio of the route, optionally, with given job inserted.
+type LoadRatioFunc = Arc<dyn Fn(&RouteContext, Option<&Job>) -> f64 + Send + Sync>;
+
+/// An objective function which rewards leaving some capacity and time slack on each route, so
+/// the plan can absorb late orders without rebuilding everything. A route is penalized by the
+/// amount its max load and duration ratios exceed desired ones.
+pub struct SlackReservation {
+    capacity_slack: f64,
+    time_slack: f64,
+    load_ratio_func: LoadRatioFunc,
+}
+
+impl SlackReservation {
+    /// Creates _(constraint, objective)_ type pair which reserves given ratios of vehicle capacity
+    /// and shift time on each route. Weight specifies an insertion penalty per unit of slack deficit.
+    pub fn new_with_constraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+        capacity_slack: f64,
+        time_slack: f64,
+        weight: Cost,
+    ) -> (TargetConstraint, TargetObjective) {
+        let reservation = Arc::new(Self::new::<T>(capacity_slack, time_slack));
+        let constraint = SlackReservationSoftRouteConstraint { reservation: reservation.clone(), weight };
+
+        create_route_value_objective(
+            SLACK_RESERVATION_KEY,
+            Arc::new(move |route_ctx| reservation.get_route_deficit(route_ctx)),
+            vec![ConstraintVariant::SoftRoute(Arc::new(constraint))],
+        )
+    }
+
+    fn new<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(capacity_slack: f64, time_slack: f64) -> Self {
+        let default_intervals = vec![(0_usize, 0_usize)];
+
+        let load_ratio_func = Arc::new(move |route_ctx: &RouteContext, job: Option<&Job>| {
+            let capacity = match route_ctx.route.actor.vehicle.dimens.get_capacity() {
+                Some(capacity) => capacity,
+                None => return 0.,
+            };
+
+            let job_load = job.map_or(T::default(), |job| {
+                let singles = match job {
+                    Job::Single(single) => vec![single],
+                    Job::Multi(multi) => multi.jobs.iter().collect(),
+                };
+
+                singles
+                    .into_iter()
+                    .filter_map(|single| single.dimens.get_demand())
+                    .fold(T::default(), |acc, demand: &Demand<T>| {
+                        acc + demand.pickup.0.max_load(demand.delivery.0) + demand.pickup.1
+                    })
+            });
+
+            let intervals = route_ctx
+                .state
+                .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+                .unwrap_or(&default_intervals);
+
+            intervals
+                .iter()
+                .filter_map(|(start, _)| route_ctx.route.tour.get(*start))
+                .map(|activity| {
+                    route_ctx
+                        .state
+                        .get_activity_state::<T>(MAX_FUTURE_CAPACITY_KEY, activity)
+                        .cloned()
+                        .unwrap_or_default()
+                })
+                .map(|max_load| (max_load + job_load).ratio(capacity))
+                .fold(0_f64, |acc, ratio| acc.max(ratio))
+        });
+
+        Self { capacity_slack, time_slack, load_ratio_func }
+    }
+
+    /// Returns slack deficit of the route: a sum of capacity and time ratios which exceed desired ones.
+    pub fn get_route_deficit(&self, route_ctx: &RouteContext) -> f64 {
+        self.get_deficit(route_ctx, None)
+    }
+
+    fn get_deficit(&self, route_ctx: &RouteContext, job: Option<&Job>) -> f64 {
+        let load_ratio = self.load_ratio_func.deref()(route_ctx, job);
+
+        let shift = &route_ctx.route.actor.detail.time;
+        let tour = &route_ctx.route.tour;
+        let time_ratio = match (tour.start(), tour.end()) {
+            (Some(start), Some(end)) if shift.end < std::f64::MAX && shift.end > shift.start => {
+                (end.schedule.arrival - start.schedule.departure) / (shift.end - shift.start)
+            }
+            _ => 0.,
+        };
+
+        (load_ratio - (1. - self.capacity_slack)).max(0.) + (time_ratio - (1. - self.time_slack)).max(0.)
+    }
+}
+
+/// Penalizes job insertions into routes which do not keep desired slack by an increase of the route's
+/// slack deficit. As exact insertion place is not known on route level, job demand is added to the
+/// route's max load and time ratio is taken as it is.
+struct SlackReservationSoftRouteConstraint {
+    reservation: Arc<SlackReservation>,
+    weight: Cost,
+}
+
+impl SoftRouteConstraint for SlackReservationSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        let route_deficit = get_route_value(ctx, SLACK_RESERVATION_KEY, || self.reservation.get_route_deficit(ctx));
+
+        (self.reservation.get_deficit(ctx, Some(job)) - route_deficit).max(0.) * self.weight
+    }
+}
@@ -44,6 +44,12 @@ impl Objective for TotalRoutes {
     }
 
     fn fitness(&self, solution: &Self::Solution) -> f64 {
-        solution.solution.routes.len() as f64
+        let fitness = solution.solution.routes.len() as f64;
+
+        if self.is_minimization {
+            fitness
+        } else {
+            -fitness
+        }
     }
 }
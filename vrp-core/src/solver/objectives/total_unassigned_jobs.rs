@@ -1,5 +1,10 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_unassigned_jobs_test.rs"]
+mod total_unassigned_jobs_test;
+
 use super::*;
 use crate::algorithms::nsga2::Objective;
+use crate::models::common::PriorityDimension;
 use crate::models::problem::Job;
 use crate::utils::compare_floats;
 use std::ops::Deref;
@@ -22,7 +27,21 @@ impl TotalUnassignedJobs {
 
 impl Default for TotalUnassignedJobs {
     fn default() -> Self {
-        Self::new(Arc::new(|_, _, _| 1.))
+        Self::new(Arc::new(|ctx, job, _| get_priority_weight(ctx, job)))
+    }
+}
+
+/// Returns a weight of unassigned job based on its priority. Weights are chosen in a way that
+/// a single unassigned job with higher priority is always worse than any amount of unassigned
+/// jobs with lower priority: a job with priority `p` has weight `(n + 1)^(1 - p)`, where `n`
+/// is total amount of jobs in the problem. Jobs without priority have weight `1`.
+pub fn get_priority_weight(insertion_ctx: &InsertionContext, job: &Job) -> f64 {
+    match job.dimens().get_priority() {
+        Some(priority) if priority > 1 => {
+            let base = (insertion_ctx.problem.jobs.size() + 1) as f64;
+            base.powi(1 - priority)
+        }
+        _ => 1.,
     }
 }
 
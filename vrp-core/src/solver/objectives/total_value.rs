@@ -0,0 +1,172 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_value_test.rs"]
+mod total_value_test;
+
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::{ActivityCost, Job, TargetConstraint, TargetObjective, TransportCost};
+use crate::models::solution::Activity;
+use crate::utils::compare_floats;
+use hashbrown::HashMap;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns value collected when the job is served.
+pub type JobValueFunc = Arc<dyn Fn(&Job) -> f64 + Send + Sync>;
+
+/// An objective function which maximizes total value of served jobs (aka prize collecting).
+pub struct TotalValue {
+    value_func: JobValueFunc,
+}
+
+impl TotalValue {
+    /// Creates _(constraint, objective)_ type pair which maximizes total value of served jobs.
+    /// Jobs with positive value are considered as optional: a job is removed from its tour and
+    /// left unassigned with given code when its marginal cost exceeds its value.
+    pub fn maximize(
+        value_func: JobValueFunc,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = TotalValueModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(TotalValueSoftRouteConstraint {
+                value_func: value_func.clone(),
+            }))],
+            value_func: value_func.clone(),
+            transport,
+            activity,
+            keys: vec![],
+            codes: vec![code],
+            code,
+        };
+
+        (Box::new(constraint), Box::new(TotalValue { value_func }))
+    }
+}
+
+impl Objective for TotalValue {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        -solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+            .map(|job| self.value_func.deref()(&job))
+            .sum::<f64>()
+    }
+}
+
+struct TotalValueModule {
+    constraints: Vec<ConstraintVariant>,
+    value_func: JobValueFunc,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+    code: i32,
+}
+
+impl TotalValueModule {
+    /// Returns the most unprofitable job of the route: the job which marginal cost exceeds its
+    /// value the most.
+    fn get_unprofitable_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> Option<Job> {
+        let route = route_ctx.route.as_ref();
+        let actor = route.actor.as_ref();
+
+        // NOTE removing the last job from the tour saves also fixed costs of the actor
+        let fixed = if route.tour.job_count() == 1 { actor.vehicle.costs.fixed + actor.driver.costs.fixed } else { 0. };
+
+        let get_cost = |from: &Activity, to: &Activity| {
+            self.transport.cost(actor, from.place.location, to.place.location, from.schedule.departure)
+        };
+
+        let savings = route.tour.all_activities().enumerate().filter_map(|(idx, activity)| {
+            let job = activity.retrieve_job()?;
+            let prev = route.tour.get(idx.checked_sub(1)?)?;
+
+            let cost = get_cost(prev, activity)
+                + self.activity.cost(actor, activity, activity.schedule.arrival)
+                + route.tour.get(idx + 1).map_or(0., |next| get_cost(activity, next) - get_cost(prev, next));
+
+            Some((job, cost))
+        });
+
+        savings
+            .fold(HashMap::<Job, Cost>::default(), |mut acc, (job, cost)| {
+                *acc.entry(job).or_insert(fixed) += cost;
+                acc
+            })
+            .into_iter()
+            .filter(|(job, _)| !solution_ctx.locked.contains(job))
+            .filter_map(|(job, cost)| {
+                let value = self.value_func.deref()(&job);
+                if value > 0. && cost > value {
+                    Some((job, cost - value))
+                } else {
+                    None
+                }
+            })
+            .max_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .map(|(job, _)| job)
+    }
+}
+
+impl ConstraintModule for TotalValueModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE remove at most one job per route as removal changes marginal costs of the others:
+        // the pipeline calls this method again when unassigned jobs are changed
+        let unprofitable = ctx
+            .routes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, route_ctx)| self.get_unprofitable_job(ctx, route_ctx).map(|job| (idx, job)))
+            .collect::<Vec<_>>();
+
+        unprofitable.into_iter().for_each(|(idx, job)| {
+            ctx.routes.get_mut(idx).unwrap().route_mut().tour.remove(&job);
+            ctx.unassigned.insert(job, self.code);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<'_, i32> {
+        self.codes.iter()
+    }
+}
+
+/// Reduces insertion cost of the job by its value.
+struct TotalValueSoftRouteConstraint {
+    value_func: JobValueFunc,
+}
+
+impl SoftRouteConstraint for TotalValueSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> f64 {
+        -self.value_func.deref()(job)
+    }
+}
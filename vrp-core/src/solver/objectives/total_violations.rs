@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_violations_test.rs"]
+mod total_violations_test;
+
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::{ConstraintPipeline, ConstraintWeights};
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::Cost;
+use crate::utils::compare_floats;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A function which returns amount of constraint violations in the route.
+pub type ViolationCountFunc = Arc<dyn Fn(&RouteContext) -> usize + Send + Sync>;
+
+/// Specifies a hard constraint which can be relaxed: its violations are penalized instead of
+/// being rejected.
+#[derive(Clone)]
+pub struct RelaxableConstraint {
+    /// A code of the constraint.
+    pub code: i32,
+    /// A penalty for each violation.
+    pub weight: Cost,
+    /// A function which counts constraint violations in the route.
+    pub violation_func: ViolationCountFunc,
+}
+
+/// An objective function which minimizes total weighted violation of relaxable hard constraints.
+/// It is used to get the least violating solution when no feasible one exists. Weights are shared
+/// with the relaxed constraint pipeline, so they can be adjusted at runtime, e.g. to ramp penalties.
+#[derive(Clone)]
+pub struct TotalViolations {
+    constraints: Vec<RelaxableConstraint>,
+    weights: Arc<ConstraintWeights>,
+}
+
+impl TotalViolations {
+    /// Creates a new instance of `TotalViolations`.
+    pub fn new(constraints: Vec<RelaxableConstraint>) -> Self {
+        let weights = constraints.iter().map(|c| (c.code, c.weight)).collect::<Vec<_>>();
+
+        Self { constraints, weights: Arc::new(ConstraintWeights::new(weights.as_slice())) }
+    }
+
+    /// Returns current weights of relaxable constraints.
+    pub fn weights(&self) -> Arc<ConstraintWeights> {
+        self.weights.clone()
+    }
+
+    /// Creates a constraint pipeline which penalizes violations of relaxable constraints instead
+    /// of rejecting them.
+    pub fn relax(&self, constraint: ConstraintPipeline) -> ConstraintPipeline {
+        ConstraintPipeline::new_weighted(Arc::new(constraint), self.weights.clone())
+    }
+
+    /// Returns amount of violations in the route per constraint code. Constraints without
+    /// violations are omitted.
+    pub fn get_route_violations(&self, route_ctx: &RouteContext) -> Vec<(i32, usize)> {
+        self.constraints
+            .iter()
+            .map(|c| (c.code, c.violation_func.deref()(route_ctx)))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
+    fn get_route_penalty(&self, route_ctx: &RouteContext) -> Cost {
+        self.constraints
+            .iter()
+            .map(|c| self.weights.get(c.code).unwrap_or(c.weight) * c.violation_func.deref()(route_ctx) as f64)
+            .sum()
+    }
+}
+
+impl Objective for TotalViolations {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_penalty(route_ctx)).sum()
+    }
+}
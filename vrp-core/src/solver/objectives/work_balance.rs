@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/work_balance_test.rs"]
+mod work_balance_test;
+
 use crate::algorithms::nsga2::Objective;
 use crate::algorithms::statistics::get_cv;
 use crate::construction::constraints::*;
@@ -234,6 +238,12 @@ impl Objective for WorkBalanceObjectives {
             }
         }
 
+        if let Some(tolerance) = self.tolerance {
+            if (fitness_a - fitness_b).abs() <= tolerance {
+                return Equal;
+            }
+        }
+
         compare_floats(fitness_a, fitness_b)
     }
 
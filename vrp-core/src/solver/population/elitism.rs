@@ -61,6 +61,11 @@ impl Population for Elitism {
         self.is_improved(was_empty)
     }
 
+    fn replace_all(&mut self, individuals: Vec<Individual>) {
+        self.individuals.clear();
+        self.add_all(individuals);
+    }
+
     fn on_generation(&mut self, _: &Statistics) {}
 
     fn cmp(&self, a: &Individual, b: &Individual) -> Ordering {
@@ -91,6 +96,15 @@ impl Population for Elitism {
         self.individuals.len()
     }
 
+    fn total_size(&self) -> usize {
+        self.size()
+    }
+
+    fn shrink(&mut self, max_size: usize) {
+        self.max_population_size = self.max_population_size.min(max_size.max(1));
+        self.ensure_max_population_size();
+    }
+
     fn selection_phase(&self) -> SelectionPhase {
         SelectionPhase::Exploitation
     }
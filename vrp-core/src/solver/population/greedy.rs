@@ -35,6 +35,11 @@ impl Population for Greedy {
         true
     }
 
+    fn replace_all(&mut self, individuals: Vec<Individual>) {
+        self.best_known = None;
+        self.add_all(individuals);
+    }
+
     fn on_generation(&mut self, _: &Statistics) {}
 
     fn cmp(&self, a: &Individual, b: &Individual) -> Ordering {
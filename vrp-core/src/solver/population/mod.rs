@@ -1,5 +1,9 @@
 //! Specifies population types.
 
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/population/population_test.rs"]
+mod population_test;
+
 mod elitism;
 pub use self::elitism::Elitism;
 
@@ -8,7 +12,7 @@ pub use self::greedy::Greedy;
 
 mod rosomaxa;
 pub use self::rosomaxa::Rosomaxa;
-pub use self::rosomaxa::RosomaxaConfig;
+pub use self::rosomaxa::{NetworkStateExporter, RosomaxaConfig};
 
 use crate::construction::heuristics::InsertionContext;
 use crate::models::Problem;
@@ -22,7 +26,7 @@ use std::sync::Arc;
 pub type Individual = InsertionContext;
 
 /// Specifies a selection phase.
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SelectionPhase {
     /// A phase of building an initial solution(-s).
     Initial,
@@ -42,6 +46,10 @@ pub trait Population: Display {
     /// Returns true if newly added individual is considered as best known.
     fn add(&mut self, individual: Individual) -> bool;
 
+    /// Removes all individuals from the population and adds given ones instead. Used when known
+    /// individuals become outdated, e.g. when some of their jobs are cancelled.
+    fn replace_all(&mut self, individuals: Vec<Individual>);
+
     /// Informs population about new generation event. This is time for the population
     /// to decide whether selection phase has to be changed.
     fn on_generation(&mut self, statistics: &Statistics);
@@ -58,6 +66,16 @@ pub trait Population: Display {
     /// Returns population size.
     fn size(&self) -> usize;
 
+    /// Returns total amount of individuals kept by the population including ones stored in its
+    /// internal structures. Default is population size.
+    fn total_size(&self) -> usize {
+        self.size()
+    }
+
+    /// Shrinks population to keep at most `max_size` individuals and releases internal structures
+    /// which are not essential, if any, in order to reduce memory usage. Default does nothing.
+    fn shrink(&mut self, _max_size: usize) {}
+
     /// Returns a current selection phase.
     fn selection_phase(&self) -> SelectionPhase;
 }
@@ -75,7 +93,11 @@ pub fn get_default_selection_size(environment: &Environment) -> usize {
     environment.parallelism.available_cpus()
 }
 
-/// Gets default population algorithm.
+/// Specifies amount of jobs below which problem is considered as small: for such problems,
+/// overhead of maintaining GSOM network used by rosomaxa dominates over its benefits.
+const SMALL_PROBLEM_SIZE: usize = 50;
+
+/// Gets default population algorithm: rosomaxa or elitism for small problems and single thread.
 pub fn get_default_population(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
@@ -84,6 +106,8 @@ pub fn get_default_population(
     if selection_size == 1 {
         // TODO use greedy instead
         Box::new(Elitism::new(problem, environment.random.clone(), 1, 1))
+    } else if problem.jobs.size() < SMALL_PROBLEM_SIZE {
+        create_elitism_population(problem, environment)
     } else {
         let config = RosomaxaConfig::new_with_defaults(selection_size);
         let population =
@@ -4,7 +4,7 @@ mod rosomaxa_test;
 
 use super::super::rand::prelude::SliceRandom;
 use super::*;
-use crate::algorithms::gsom::{get_network_state, Input, Network, NetworkConfig, NodeLink, Storage};
+use crate::algorithms::gsom::*;
 use crate::algorithms::statistics::relative_distance;
 use crate::construction::heuristics::*;
 use crate::models::Problem;
@@ -36,8 +36,14 @@ pub struct RosomaxaConfig {
     pub rebalance_count: usize,
     /// A ratio of exploration phase.
     pub exploration_ratio: f64,
+    /// An optional GSOM network state exporter which is called with specified frequency (in generations)
+    /// during exploration phase.
+    pub network_export: Option<(usize, NetworkStateExporter)>,
 }
 
+/// A function which receives generation number and GSOM network state.
+pub type NetworkStateExporter = Arc<dyn Fn(usize, &NetworkState) + Send + Sync>;
+
 impl RosomaxaConfig {
     /// Creates an instance of `RosomaxaConfig` using default parameters, but taking into
     /// account data parallelism settings.
@@ -53,6 +59,7 @@ impl RosomaxaConfig {
             rebalance_memory: 500,
             rebalance_count: 4,
             exploration_ratio: 0.9,
+            network_export: None,
         }
     }
 }
@@ -96,6 +103,19 @@ impl Population for Rosomaxa {
         self.elite.add(individual)
     }
 
+    fn replace_all(&mut self, individuals: Vec<Individual>) {
+        let copies = || individuals.iter().map(|individual| individual.deep_copy()).collect::<Vec<_>>();
+
+        match &mut self.phase {
+            RosomaxaPhases::Initial { individuals: known_individuals } => *known_individuals = copies(),
+            // NOTE network is trained on outdated individuals, so exploration is started from scratch
+            RosomaxaPhases::Exploration { .. } => self.phase = RosomaxaPhases::Initial { individuals: copies() },
+            RosomaxaPhases::Exploitation => {}
+        };
+
+        self.elite.replace_all(individuals)
+    }
+
     fn on_generation(&mut self, statistics: &Statistics) {
         self.update_phase(statistics)
     }
@@ -127,6 +147,24 @@ impl Population for Rosomaxa {
         self.elite.size()
     }
 
+    fn total_size(&self) -> usize {
+        let phase_size = match &self.phase {
+            RosomaxaPhases::Initial { individuals } => individuals.len(),
+            RosomaxaPhases::Exploration { network, .. } => {
+                network.get_nodes().map(|node| node.read().unwrap().storage.population.size()).sum()
+            }
+            RosomaxaPhases::Exploitation => 0,
+        };
+
+        self.elite.size() + phase_size
+    }
+
+    fn shrink(&mut self, max_size: usize) {
+        // NOTE network keeps the most of individuals, so exploration is stopped
+        self.phase = RosomaxaPhases::Exploitation;
+        self.elite.shrink(max_size);
+    }
+
     fn selection_phase(&self) -> SelectionPhase {
         match &self.phase {
             RosomaxaPhases::Initial { .. } => SelectionPhase::Initial,
@@ -190,6 +228,12 @@ impl Rosomaxa {
                         best_fitness.as_slice(),
                         self.environment.random.as_ref(),
                     );
+
+                    if let Some((frequency, exporter)) = &self.config.network_export {
+                        if *frequency > 0 && statistics.generation % *frequency == 0 {
+                            (exporter)(statistics.generation, &get_network_state(network));
+                        }
+                    }
                 } else {
                     self.phase = RosomaxaPhases::Exploitation
                 }
@@ -6,6 +6,8 @@ mod telemetry_test;
 
 use crate::algorithms::nsga2::Objective;
 use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Dimensions, IdDimension};
+use crate::models::Solution;
 use crate::solver::population::SelectionPhase;
 use crate::solver::{RefinementContext, Statistics};
 use crate::utils::Timer;
@@ -16,6 +18,67 @@ use std::sync::Arc;
 /// A logger type which is called with various information regarding the work done by the VRP solver.
 pub type InfoLogger = Arc<dyn Fn(&str)>;
 
+/// A sink type which is called with search progress record on each generation.
+pub type ProgressSink = Arc<dyn Fn(&ProgressRecord)>;
+
+/// Represents search progress at specific generation.
+pub struct ProgressRecord {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Time since evolution started in seconds.
+    pub elapsed: f64,
+    /// Cost of the best individual.
+    pub best_cost: f64,
+    /// Mean cost of all individuals in population.
+    pub mean_cost: f64,
+    /// Amount of unassigned jobs in the best individual.
+    pub unassigned: usize,
+}
+
+/// A callback type which is called with a snapshot of the best individual each time a new best
+/// individual is found.
+pub type IterationCallback = Arc<dyn Fn(&IterationSnapshot)>;
+
+/// Represents the best individual found at specific generation.
+pub struct IterationSnapshot<'a> {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Time since evolution started in seconds.
+    pub elapsed: f64,
+    /// Cost of the best individual.
+    pub cost: f64,
+    /// Amount of unassigned jobs in the best individual.
+    pub unassigned: usize,
+    insertion_ctx: &'a InsertionContext,
+}
+
+impl IterationSnapshot<'_> {
+    /// Creates a solution of the best individual. It is not part of the snapshot as it requires
+    /// copying all routes, so it should be called only when the solution is needed.
+    pub fn to_solution(&self) -> Solution {
+        self.insertion_ctx.solution.to_solution(self.insertion_ctx.problem.extras.clone())
+    }
+}
+
+/// A dumper type which is called with population snapshot with specified frequency (in generations).
+pub type PopulationDumper = Arc<dyn Fn(&PopulationSnapshot)>;
+
+/// Represents population state at specific generation.
+pub struct PopulationSnapshot {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Population individuals in rank order.
+    pub individuals: Vec<IndividualSnapshot>,
+}
+
+/// Represents individual state in population snapshot.
+pub struct IndividualSnapshot {
+    /// Objectives fitness values.
+    pub fitness: Vec<f64>,
+    /// Routes as vehicle id and ids of jobs in visit order.
+    pub routes: Vec<(String, Vec<String>)>,
+}
+
 /// Encapsulates different measurements regarding algorithm evaluation.
 pub struct Metrics {
     /// Algorithm duration.
@@ -108,6 +171,9 @@ pub struct Telemetry {
     mode: TelemetryMode,
     improvement_tracker: ImprovementTracker,
     next_generation: Option<usize>,
+    progress_sink: Option<ProgressSink>,
+    iteration_callback: Option<IterationCallback>,
+    population_dumper: Option<(usize, PopulationDumper)>,
 }
 
 impl Telemetry {
@@ -119,9 +185,25 @@ impl Telemetry {
             mode,
             improvement_tracker: ImprovementTracker::new(1000),
             next_generation: None,
+            progress_sink: None,
+            iteration_callback: None,
+            population_dumper: None,
         }
     }
 
+    /// Sets a sink which receives search progress on each generation regardless of telemetry mode.
+    pub fn with_progress_sink(mut self, progress_sink: ProgressSink) -> Self {
+        self.progress_sink = Some(progress_sink);
+        self
+    }
+
+    /// Sets a callback which receives a snapshot of the best individual each time a new best
+    /// individual is found regardless of telemetry mode.
+    pub fn with_iteration_callback(mut self, iteration_callback: IterationCallback) -> Self {
+        self.iteration_callback = Some(iteration_callback);
+        self
+    }
+
     /// Starts telemetry reporting.
     pub fn start(&mut self) {
         self.time = Timer::start();
@@ -166,6 +248,10 @@ impl Telemetry {
 
         self.next_generation = Some(generation + 1);
 
+        self.on_progress(refinement_ctx);
+        self.on_iteration(refinement_ctx, is_improved);
+        self.on_population_dump(refinement_ctx);
+
         let (log_best, log_population, track_population, should_dump_population) = match &self.mode {
             TelemetryMode::None => return,
             TelemetryMode::OnlyLogging { log_best, log_population, dump_population, .. } => {
@@ -195,6 +281,93 @@ impl Telemetry {
         }
     }
 
+    /// Sets a dumper which receives population snapshot with given frequency (in generations)
+    /// regardless of telemetry mode.
+    pub fn with_population_dumper(mut self, frequency: usize, population_dumper: PopulationDumper) -> Self {
+        self.population_dumper = Some((frequency.max(1), population_dumper));
+        self
+    }
+
+    /// Reports search progress to the sink, if it is set.
+    fn on_progress(&self, refinement_ctx: &RefinementContext) {
+        let progress_sink = match &self.progress_sink {
+            Some(progress_sink) => progress_sink,
+            None => return,
+        };
+
+        let (unassigned, costs) = refinement_ctx.population.ranked().fold(
+            (None, Vec::new()),
+            |(unassigned, mut costs), (insertion_ctx, _)| {
+                costs.push(refinement_ctx.problem.objective.fitness(insertion_ctx));
+                (unassigned.or(Some(insertion_ctx.solution.unassigned.len())), costs)
+            },
+        );
+
+        if let (Some(unassigned), Some(best_cost)) = (unassigned, costs.first().cloned()) {
+            progress_sink.deref()(&ProgressRecord {
+                generation: refinement_ctx.statistics.generation,
+                elapsed: self.time.elapsed_secs_as_f64(),
+                best_cost,
+                mean_cost: costs.iter().sum::<f64>() / costs.len() as f64,
+                unassigned,
+            });
+        }
+    }
+
+    /// Reports the best individual to the callback, if it is set and the best individual is improved.
+    fn on_iteration(&self, refinement_ctx: &RefinementContext, is_improved: bool) {
+        let iteration_callback = match &self.iteration_callback {
+            Some(iteration_callback) if is_improved => iteration_callback,
+            _ => return,
+        };
+
+        if let Some((insertion_ctx, _)) = refinement_ctx.population.ranked().next() {
+            iteration_callback.deref()(&IterationSnapshot {
+                generation: refinement_ctx.statistics.generation,
+                elapsed: self.time.elapsed_secs_as_f64(),
+                cost: refinement_ctx.problem.objective.fitness(insertion_ctx),
+                unassigned: insertion_ctx.solution.unassigned.len(),
+                insertion_ctx,
+            });
+        }
+    }
+
+    /// Dumps population snapshot, if dumper is set and it is time to dump.
+    fn on_population_dump(&self, refinement_ctx: &RefinementContext) {
+        let generation = refinement_ctx.statistics.generation;
+        let population_dumper = match &self.population_dumper {
+            Some((frequency, population_dumper)) if generation % *frequency == 0 => population_dumper,
+            _ => return,
+        };
+
+        let get_id = |dimens: &Dimensions| dimens.get_id().cloned().unwrap_or_default();
+        let individuals = refinement_ctx
+            .population
+            .ranked()
+            .map(|(insertion_ctx, _)| IndividualSnapshot {
+                fitness: insertion_ctx.get_fitness_values().collect(),
+                routes: insertion_ctx
+                    .solution
+                    .routes
+                    .iter()
+                    .map(|route_ctx| {
+                        let route = &route_ctx.route;
+                        let jobs = route
+                            .tour
+                            .all_activities()
+                            .filter_map(|activity| activity.retrieve_job())
+                            .map(|job| get_id(job.dimens()))
+                            .collect();
+
+                        (get_id(&route.actor.vehicle.dimens), jobs)
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        population_dumper.deref()(&PopulationSnapshot { generation, individuals });
+    }
+
     /// Reports population state.
     fn on_population(
         &mut self,
@@ -280,6 +453,14 @@ impl Telemetry {
         }
     }
 
+    /// Returns logger if logging is enabled.
+    pub fn get_logger(&self) -> Option<InfoLogger> {
+        match &self.mode {
+            TelemetryMode::OnlyLogging { logger, .. } | TelemetryMode::All { logger, .. } => Some(logger.clone()),
+            _ => None,
+        }
+    }
+
     /// Writes log message.
     pub fn log(&self, message: &str) {
         match &self.mode {
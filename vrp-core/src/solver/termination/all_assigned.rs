@@ -0,0 +1,17 @@
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+
+/// A termination criteria which is in terminated state when the best individual has no unassigned
+/// jobs.
+#[derive(Default)]
+pub struct AllAssigned {}
+
+impl Termination for AllAssigned {
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        matches!(refinement_ctx.population.ranked().next(), Some((best, _)) if best.solution.unassigned.is_empty())
+    }
+
+    fn estimate(&self, _: &RefinementContext) -> f64 {
+        0.
+    }
+}
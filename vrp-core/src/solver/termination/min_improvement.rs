@@ -0,0 +1,62 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/min_improvement_test.rs"]
+mod min_improvement_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::models::common::Cost;
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+
+/// A termination criteria which is in terminated state when relative change of the best individual's
+/// cost over the last `generations` generations is below `epsilon`.
+pub struct MinImprovement {
+    generations: usize,
+    epsilon: f64,
+    key: String,
+}
+
+impl MinImprovement {
+    /// Creates a new instance of `MinImprovement`.
+    pub fn new(generations: usize, epsilon: f64) -> Self {
+        Self { generations: generations.max(1), epsilon, key: "min_improvement".to_string() }
+    }
+
+    fn update_and_check(&self, refinement_ctx: &mut RefinementContext, cost: Cost) -> bool {
+        let size = self.generations + 1;
+        let generation = refinement_ctx.statistics.generation;
+
+        let costs = refinement_ctx
+            .state
+            .entry(self.key.clone())
+            .or_insert_with(|| Box::new(vec![0.; size]))
+            .downcast_mut::<Vec<f64>>()
+            .unwrap();
+
+        costs[generation % size] = cost;
+
+        if generation < self.generations {
+            return false;
+        }
+
+        let old_cost = costs[(generation + 1) % size];
+        let improvement = if old_cost == 0. { (old_cost - cost).abs() } else { ((old_cost - cost) / old_cost).abs() };
+
+        improvement < self.epsilon
+    }
+}
+
+impl Termination for MinImprovement {
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        let cost =
+            refinement_ctx.population.ranked().next().map(|(best, _)| refinement_ctx.problem.objective.fitness(best));
+
+        match cost {
+            Some(cost) => self.update_and_check(refinement_ctx, cost),
+            None => false,
+        }
+    }
+
+    fn estimate(&self, _: &RefinementContext) -> f64 {
+        0.
+    }
+}
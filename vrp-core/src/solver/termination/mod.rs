@@ -12,6 +12,9 @@ pub trait Termination {
     fn estimate(&self, refinement_ctx: &RefinementContext) -> f64;
 }
 
+mod all_assigned;
+pub use self::all_assigned::AllAssigned;
+
 mod cost_variation;
 pub use self::cost_variation::CostVariation;
 
@@ -20,26 +23,55 @@ pub use self::max_generation::MaxGeneration;
 
 mod max_time;
 pub use self::max_time::MaxTime;
+
+mod min_improvement;
+pub use self::min_improvement::MinImprovement;
+
+mod search_budget;
+pub use self::search_budget::SearchBudget;
+
+mod target_cost;
+pub use self::target_cost::TargetCost;
 use crate::utils::compare_floats;
 
 /// A trait which encapsulates multiple termination criteria.
 pub struct CompositeTermination {
     terminations: Vec<Box<dyn Termination + Send + Sync>>,
+    is_all: bool,
 }
 
 impl CompositeTermination {
-    /// Creates a new instance of `CompositeTermination`.
+    /// Creates a new instance of `CompositeTermination` which is in terminated state when any of
+    /// its criteria is met.
     pub fn new(terminations: Vec<Box<dyn Termination + Send + Sync>>) -> Self {
-        Self { terminations }
+        Self { terminations, is_all: false }
+    }
+
+    /// Creates a new instance of `CompositeTermination` which is in terminated state when all of
+    /// its criteria are met.
+    pub fn new_all(terminations: Vec<Box<dyn Termination + Send + Sync>>) -> Self {
+        Self { terminations, is_all: true }
     }
 }
 
 impl Termination for CompositeTermination {
     fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
-        self.terminations.iter().any(|t| t.is_termination(refinement_ctx))
+        if self.is_all {
+            // NOTE all criteria are checked as some of them keep their state in refinement context
+            let results = self.terminations.iter().map(|t| t.is_termination(refinement_ctx)).collect::<Vec<_>>();
+            !results.is_empty() && results.into_iter().all(|result| result)
+        } else {
+            self.terminations.iter().any(|t| t.is_termination(refinement_ctx))
+        }
     }
 
     fn estimate(&self, refinement_ctx: &RefinementContext) -> f64 {
-        self.terminations.iter().map(|t| t.estimate(refinement_ctx)).max_by(|a, b| compare_floats(*a, *b)).unwrap_or(0.)
+        let estimates = self.terminations.iter().map(|t| t.estimate(refinement_ctx));
+
+        if self.is_all {
+            estimates.min_by(|a, b| compare_floats(*a, *b)).unwrap_or(0.)
+        } else {
+            estimates.max_by(|a, b| compare_floats(*a, *b)).unwrap_or(0.)
+        }
     }
 }
@@ -0,0 +1,26 @@
+use crate::solver::termination::Termination;
+use crate::solver::{BudgetPhase, RefinementContext, TimeBudget};
+use std::sync::Arc;
+
+/// A termination criteria which is in terminated state when search phase has consumed its time
+/// allocated by time budget.
+pub struct SearchBudget {
+    budget: Arc<TimeBudget>,
+}
+
+impl SearchBudget {
+    /// Creates a new instance of `SearchBudget`.
+    pub fn new(budget: Arc<TimeBudget>) -> Self {
+        Self { budget }
+    }
+}
+
+impl Termination for SearchBudget {
+    fn is_termination(&self, _: &mut RefinementContext) -> bool {
+        self.budget.is_exhausted(BudgetPhase::Search)
+    }
+
+    fn estimate(&self, _: &RefinementContext) -> f64 {
+        self.budget.estimate(BudgetPhase::Search)
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/termination/target_cost_test.rs"]
+mod target_cost_test;
+
+use crate::algorithms::nsga2::Objective;
+use crate::solver::termination::Termination;
+use crate::solver::RefinementContext;
+
+/// A termination criteria which is in terminated state when cost of the best individual is not
+/// higher than target cost.
+pub struct TargetCost {
+    target: f64,
+}
+
+impl TargetCost {
+    /// Creates a new instance of `TargetCost`.
+    pub fn new(target: f64) -> Self {
+        Self { target }
+    }
+}
+
+impl Termination for TargetCost {
+    fn is_termination(&self, refinement_ctx: &mut RefinementContext) -> bool {
+        match refinement_ctx.population.ranked().next() {
+            Some((best, _)) => refinement_ctx.problem.objective.fitness(best) <= self.target,
+            None => false,
+        }
+    }
+
+    fn estimate(&self, _: &RefinementContext) -> f64 {
+        0.
+    }
+}
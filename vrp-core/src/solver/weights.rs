@@ -0,0 +1,81 @@
+//! A module which contains logic to adjust constraint weights between generations.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/weights_test.rs"]
+mod weights_test;
+
+use crate::construction::constraints::ConstraintWeights;
+use crate::models::common::Cost;
+use crate::solver::{RefinementContext, Statistics};
+use hashbrown::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+const WEIGHT_SCHEDULE_KEY: &str = "weight_schedule";
+
+/// A function which adjusts constraint weights using refinement statistics.
+pub type WeightScheduleFunc = Arc<dyn Fn(&Statistics, &ConstraintWeights) + Send + Sync>;
+
+/// Adjusts constraint weights between generations, so penalty ramping strategies can be used
+/// without restarting the solver. Weights are expected to be shared with problem's constraint
+/// pipeline and objective, e.g. the ones returned by `TotalViolations::weights`.
+pub struct WeightSchedule {
+    weights: Arc<ConstraintWeights>,
+    schedule_func: WeightScheduleFunc,
+}
+
+impl WeightSchedule {
+    /// Creates a new instance of `WeightSchedule` which calls given function on each generation.
+    pub fn new(weights: Arc<ConstraintWeights>, schedule_func: WeightScheduleFunc) -> Self {
+        Self { weights, schedule_func }
+    }
+
+    /// Creates a new instance of `WeightSchedule` which changes weights linearly from their initial
+    /// values to target ones according to termination estimate.
+    pub fn new_linear(weights: Arc<ConstraintWeights>, targets: &[(i32, Cost)]) -> Self {
+        let initial = weights.all().into_iter().collect::<HashMap<_, _>>();
+        let targets = targets.to_vec();
+
+        Self::new(
+            weights,
+            Arc::new(move |statistics, weights| {
+                let ratio = statistics.termination_estimate.min(1.);
+
+                targets
+                    .iter()
+                    .filter_map(|(code, target)| initial.get(code).map(|start| (code, start, target)))
+                    .for_each(|(code, start, target)| {
+                        let _ = weights.set(*code, start + (target - start) * ratio);
+                    });
+            }),
+        )
+    }
+
+    /// Applies schedule to constraint weights. Returns true if any weight is changed: in this case
+    /// cached insertion costs are discarded as they are outdated.
+    pub fn apply(&self, refinement_ctx: &RefinementContext) -> bool {
+        let old_weights = self.weights.all();
+        self.schedule_func.deref()(&refinement_ctx.statistics, self.weights.as_ref());
+
+        let is_changed = old_weights != self.weights.all();
+        if is_changed {
+            refinement_ctx.problem.constraint.insertion_cache().clear();
+        }
+
+        is_changed
+    }
+}
+
+/// Attaches weight schedule to refinement context.
+pub(crate) fn set_weight_schedule(refinement_ctx: &mut RefinementContext, schedule: Arc<WeightSchedule>) {
+    refinement_ctx.state.insert(WEIGHT_SCHEDULE_KEY.to_string(), Box::new(schedule));
+}
+
+/// Applies weight schedule attached to refinement context, if any.
+pub(crate) fn apply_weight_schedule(refinement_ctx: &RefinementContext) -> bool {
+    refinement_ctx
+        .state
+        .get(WEIGHT_SCHEDULE_KEY)
+        .and_then(|schedule| schedule.downcast_ref::<Arc<WeightSchedule>>())
+        .map_or(false, |schedule| schedule.apply(refinement_ctx))
+}
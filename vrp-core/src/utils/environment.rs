@@ -1,6 +1,10 @@
 //! Contains environment specific logic.
 
-use crate::utils::{DefaultRandom, Random, ThreadPool};
+#[cfg(test)]
+#[path = "../../tests/unit/utils/environment_test.rs"]
+mod environment_test;
+
+use crate::utils::{with_random_stream, DefaultRandom, Random, ThreadPool};
 use std::sync::Arc;
 
 /// Keeps track of environment specific information which influences algorithm behavior.
@@ -11,12 +15,36 @@ pub struct Environment {
 
     /// Keeps data parallelism settings.
     pub parallelism: Parallelism,
+
+    /// Specifies whether the search is reproducible for the same master seed of random generator
+    /// regardless of thread scheduling. In this mode, each individual is mutated within its own
+    /// random stream and without nested parallelism.
+    pub is_deterministic: bool,
 }
 
 impl Environment {
     /// Creates an instance of `Environment`.
     pub fn new(random: Arc<dyn Random + Send + Sync>, parallelism: Parallelism) -> Self {
-        Self { random, parallelism }
+        Self { random, parallelism, is_deterministic: false }
+    }
+
+    /// Creates an instance of `Environment` which runs in deterministic mode using default random
+    /// generator with given master seed.
+    pub fn new_deterministic(seed: u64, parallelism: Parallelism) -> Self {
+        Self { random: Arc::new(DefaultRandom::new_with_seed(seed)), parallelism, is_deterministic: true }
+    }
+
+    /// Executes operation within a random stream with given id, if environment is deterministic.
+    /// Otherwise, just executes operation.
+    pub fn stream_execute<OP, R>(&self, stream: u64, op: OP) -> R
+    where
+        OP: FnOnce() -> R,
+    {
+        if self.is_deterministic {
+            with_random_stream(stream, op)
+        } else {
+            op()
+        }
     }
 }
 
@@ -26,6 +54,17 @@ impl Default for Environment {
     }
 }
 
+/// Specifies a solver phase which can be executed on its own thread pool.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParallelismPhase {
+    /// Building of initial solutions.
+    Construction,
+    /// Search: applying mutation operators to selected individuals.
+    Mutation,
+    /// Evaluation of new individuals when they are added to the population.
+    Evaluation,
+}
+
 /// Specifies data parallelism settings.
 #[derive(Clone)]
 pub struct Parallelism {
@@ -33,11 +72,13 @@ pub struct Parallelism {
     // NOTE seems falls positive.
     #[allow(clippy::rc_buffer)]
     thread_pools: Option<Arc<Vec<ThreadPool>>>,
+    phase_pools: [Option<Arc<ThreadPool>>; 3],
+    solver_pool: Option<Arc<ThreadPool>>,
 }
 
 impl Default for Parallelism {
     fn default() -> Self {
-        Self { available_cpus: get_cpus(), thread_pools: None }
+        Self { available_cpus: get_cpus(), thread_pools: None, phase_pools: Default::default(), solver_pool: None }
     }
 }
 
@@ -45,7 +86,58 @@ impl Parallelism {
     /// Creates an instance of `Parallelism`.
     pub fn new(num_thread_pools: usize, threads_per_pool: usize) -> Self {
         let thread_pools = (0..num_thread_pools).map(|_| ThreadPool::new(threads_per_pool)).collect();
-        Self { available_cpus: get_cpus(), thread_pools: Some(Arc::new(thread_pools)) }
+        Self {
+            available_cpus: get_cpus(),
+            thread_pools: Some(Arc::new(thread_pools)),
+            phase_pools: Default::default(),
+            solver_pool: None,
+        }
+    }
+
+    /// Creates an instance of `Parallelism` which runs all solver phases on a single thread.
+    pub fn new_single_thread() -> Self {
+        Self::default().with_solver_threads(1)
+    }
+
+    /// Pins solver to a dedicated thread pool with fixed amount of threads instead of the global one
+    /// shared with host application. The pool is used by all solver phases which have no dedicated
+    /// thread pool. Amount of available CPUs, which is used to size search, is limited accordingly.
+    pub fn with_solver_threads(mut self, num_threads: usize) -> Self {
+        let thread_pool = ThreadPool::new(num_threads);
+        self.available_cpus = thread_pool.num_threads();
+        self.solver_pool = Some(Arc::new(thread_pool));
+        self
+    }
+
+    /// Returns amount of threads in dedicated solver thread pool, if it is configured.
+    pub fn solver_threads(&self) -> Option<usize> {
+        self.solver_pool.as_ref().map(|thread_pool| thread_pool.num_threads())
+    }
+
+    /// Sets a dedicated thread pool with fixed amount of threads for given solver phase, so the phase
+    /// does not compete for threads with other phases or with the global pool shared with host application.
+    pub fn with_phase_threads(mut self, phase: ParallelismPhase, num_threads: usize) -> Self {
+        self.phase_pools[phase as usize] = Some(Arc::new(ThreadPool::new(num_threads)));
+        self
+    }
+
+    /// Returns amount of threads in dedicated thread pool of given phase, if it is configured.
+    pub fn phase_threads(&self, phase: ParallelismPhase) -> Option<usize> {
+        self.phase_pools[phase as usize].as_ref().map(|thread_pool| thread_pool.num_threads())
+    }
+
+    /// Executes operation on dedicated thread pool of given phase. If there is no such thread
+    /// pool, then executes it on solver thread pool or without using any of thread pools.
+    pub fn phase_execute<OP, R>(&self, phase: ParallelismPhase, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        if let Some(thread_pool) = self.phase_pools[phase as usize].as_ref().or(self.solver_pool.as_ref()) {
+            thread_pool.execute(op)
+        } else {
+            op()
+        }
     }
 
     /// Amount of total available CPUs.
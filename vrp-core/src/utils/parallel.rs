@@ -7,6 +7,7 @@ pub use self::actual::ThreadPool;
 mod actual {
     extern crate rayon;
     use self::rayon::{ThreadPool as RayonThreadPool, ThreadPoolBuilder};
+    use crate::utils::is_random_stream;
     use rayon::prelude::*;
 
     /// Represents a thread pool wrapper.
@@ -30,29 +31,44 @@ mod actual {
         {
             self.inner.install(op)
         }
+
+        /// Returns amount of threads in thread pool.
+        pub fn num_threads(&self) -> usize {
+            self.inner.current_num_threads()
+        }
     }
 
-    /// Maps collection and collects results into vector in parallel.
+    /// Maps collection and collects results into vector in parallel. Within a random stream,
+    /// it is done synchronously.
     pub fn parallel_collect<T, F, R>(source: &[T], map_op: F) -> Vec<R>
     where
         T: Send + Sync,
         F: Fn(&T) -> R + Sync + Send,
         R: Send,
     {
-        source.par_iter().map(map_op).collect()
+        if is_random_stream() {
+            source.iter().map(map_op).collect()
+        } else {
+            source.par_iter().map(map_op).collect()
+        }
     }
 
-    /// Maps collection and collects results into vector in parallel.
+    /// Maps collection and collects results into vector in parallel. Within a random stream,
+    /// it is done synchronously.
     pub fn parallel_into_collect<T, F, R>(source: Vec<T>, map_op: F) -> Vec<R>
     where
         T: Send + Sync,
         F: Fn(T) -> R + Sync + Send,
         R: Send,
     {
-        source.into_par_iter().map(map_op).collect()
+        if is_random_stream() {
+            source.into_iter().map(map_op).collect()
+        } else {
+            source.into_par_iter().map(map_op).collect()
+        }
     }
 
-    /// Performs map reduce operations in parallel.
+    /// Performs map reduce operations in parallel. Within a random stream, it is done synchronously.
     pub fn map_reduce<T, FM, FR, FD, R>(source: &[T], map_op: FM, default_op: FD, reduce_op: FR) -> R
     where
         T: Send + Sync,
@@ -61,7 +77,11 @@ mod actual {
         FD: Fn() -> R + Sync + Send,
         R: Send,
     {
-        source.par_iter().map(map_op).reduce(default_op, reduce_op)
+        if is_random_stream() {
+            source.iter().map(map_op).fold(default_op(), reduce_op)
+        } else {
+            source.par_iter().map(map_op).reduce(default_op, reduce_op)
+        }
     }
 }
 
@@ -84,6 +104,11 @@ mod actual {
         {
             op()
         }
+
+        /// Returns amount of threads in thread pool (dummy).
+        pub fn num_threads(&self) -> usize {
+            1
+        }
     }
 
     /// Map collections and collects results into vector synchronously.
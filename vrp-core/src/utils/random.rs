@@ -3,6 +3,12 @@
 mod random_test;
 
 use rand::prelude::*;
+use rand::rngs::SmallRng;
+use rand::Error;
+use rand_chacha::ChaCha20Rng;
+use rand_pcg::Pcg32;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 /// Provides the way to use randomized values in generic way.
@@ -51,35 +57,218 @@ pub trait Random {
     }
 
     /// Returns RNG.
-    fn get_rng(&self) -> StdRng;
+    fn get_rng(&self) -> RandomGen;
+
+    /// Returns master seed, if it is specified.
+    fn seed(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Specifies an algorithm of pseudo random number generator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RandomAlgorithm {
+    /// A standard generator of `rand` crate. Its algorithm might change in future versions of the crate.
+    Std,
+    /// ChaCha with 20 rounds: high quality generator with stable output across platforms and versions.
+    ChaCha,
+    /// PCG32 (XSH RR variant): fast generator with small state and good statistical quality.
+    Pcg,
+    /// A small and fast generator which is not portable: its output depends on platform.
+    Small,
+}
+
+impl Default for RandomAlgorithm {
+    fn default() -> Self {
+        RandomAlgorithm::Std
+    }
+}
+
+/// A pseudo random number generator which uses one of supported algorithms.
+pub struct RandomGen {
+    inner: RandomGenInner,
+}
+
+enum RandomGenInner {
+    Std(StdRng),
+    ChaCha(ChaCha20Rng),
+    Pcg(Pcg32),
+    Small(SmallRng),
+}
+
+impl RandomGen {
+    /// Creates a new instance of `RandomGen` using given algorithm and seed.
+    pub fn new(algorithm: RandomAlgorithm, seed: u64) -> Self {
+        let inner = match algorithm {
+            RandomAlgorithm::Std => RandomGenInner::Std(StdRng::seed_from_u64(seed)),
+            RandomAlgorithm::ChaCha => RandomGenInner::ChaCha(ChaCha20Rng::seed_from_u64(seed)),
+            RandomAlgorithm::Pcg => RandomGenInner::Pcg(Pcg32::seed_from_u64(seed)),
+            RandomAlgorithm::Small => RandomGenInner::Small(SmallRng::seed_from_u64(seed)),
+        };
+
+        Self { inner }
+    }
+
+    /// Creates a new instance of `RandomGen` using given algorithm seeded from thread local generator.
+    pub fn new_from_entropy(algorithm: RandomAlgorithm) -> Self {
+        Self::new(algorithm, thread_rng().next_u64())
+    }
+}
+
+impl RngCore for RandomGen {
+    fn next_u32(&mut self) -> u32 {
+        match &mut self.inner {
+            RandomGenInner::Std(rng) => rng.next_u32(),
+            RandomGenInner::ChaCha(rng) => rng.next_u32(),
+            RandomGenInner::Pcg(rng) => rng.next_u32(),
+            RandomGenInner::Small(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match &mut self.inner {
+            RandomGenInner::Std(rng) => rng.next_u64(),
+            RandomGenInner::ChaCha(rng) => rng.next_u64(),
+            RandomGenInner::Pcg(rng) => rng.next_u64(),
+            RandomGenInner::Small(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match &mut self.inner {
+            RandomGenInner::Std(rng) => rng.fill_bytes(dest),
+            RandomGenInner::ChaCha(rng) => rng.fill_bytes(dest),
+            RandomGenInner::Pcg(rng) => rng.fill_bytes(dest),
+            RandomGenInner::Small(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
 }
 
 /// A default random implementation.
 pub struct DefaultRandom {
     seed: Option<u64>,
+    algorithm: RandomAlgorithm,
+    counter: AtomicU64,
 }
 
 impl DefaultRandom {
     /// Creates a new instance `DefaultRandom` with seed.
     pub fn new_with_seed(seed: u64) -> Self {
-        Self { seed: Some(seed) }
+        Self::new(RandomAlgorithm::default(), Some(seed))
+    }
+
+    /// Creates a new instance `DefaultRandom` with given algorithm and optional master seed.
+    /// When seed is specified, each thread gets its own stream of random numbers derived from
+    /// the master seed and the thread index, so results are reproducible as long as work is
+    /// distributed among threads in the same way.
+    pub fn new(algorithm: RandomAlgorithm, seed: Option<u64>) -> Self {
+        Self { seed, algorithm, counter: AtomicU64::new(0) }
+    }
+
+    /// Creates a new instance `DefaultRandom` with given algorithm and master seed. When seed is not
+    /// specified, it is generated from entropy, so it is always known and can be used to reproduce results.
+    pub fn new_with_known_seed(algorithm: RandomAlgorithm, seed: Option<u64>) -> Self {
+        Self::new(algorithm, Some(seed.unwrap_or_else(|| thread_rng().next_u64())))
+    }
+
+    /// Returns used algorithm.
+    pub fn algorithm(&self) -> RandomAlgorithm {
+        self.algorithm
     }
 }
 
 impl Random for DefaultRandom {
-    fn get_rng(&self) -> StdRng {
-        if let Some(ref seed) = self.seed {
-            StdRng::seed_from_u64(*seed)
+    fn get_rng(&self) -> RandomGen {
+        if let Some(seed) = self.seed {
+            if let Some((stream, sequence)) = next_random_stream_sequence() {
+                // NOTE explicit streams use their own domain to not overlap with thread streams
+                let seed = derive_seed(derive_seed(seed, u64::MAX), stream);
+                return RandomGen::new(self.algorithm, derive_seed(seed, sequence));
+            }
+
+            let stream = get_thread_stream();
+            let sequence = self.counter.fetch_add(1, Ordering::Relaxed);
+
+            RandomGen::new(self.algorithm, derive_seed(derive_seed(seed, stream), sequence))
         } else {
-            StdRng::from_rng(thread_rng()).expect("cannot get RNG")
+            RandomGen::new_from_entropy(self.algorithm)
         }
     }
+
+    fn seed(&self) -> Option<u64> {
+        self.seed
+    }
 }
 
 impl Default for DefaultRandom {
     fn default() -> Self {
-        Self { seed: None }
+        Self::new(RandomAlgorithm::default(), None)
+    }
+}
+
+thread_local! {
+    static RANDOM_STREAM: Cell<Option<(u64, u64)>> = Cell::new(None);
+}
+
+/// Executes operation within a random stream with given id. Within the stream, `DefaultRandom` with
+/// master seed derives generators from the seed, the stream id and amount of generators created
+/// within the stream so far, so random values do not depend on how work is distributed among
+/// threads. As other threads do not share the stream, parallel operations are run sequentially.
+pub fn with_random_stream<OP, R>(stream: u64, op: OP) -> R
+where
+    OP: FnOnce() -> R,
+{
+    struct StreamGuard(Option<(u64, u64)>);
+
+    impl Drop for StreamGuard {
+        fn drop(&mut self) {
+            RANDOM_STREAM.with(|state| state.set(self.0));
+        }
     }
+
+    let _guard = StreamGuard(RANDOM_STREAM.with(|state| state.replace(Some((stream, 0)))));
+
+    op()
+}
+
+/// Returns true if current thread runs within a random stream.
+pub(crate) fn is_random_stream() -> bool {
+    RANDOM_STREAM.with(|state| state.get().is_some())
+}
+
+/// Returns the current random stream id and next sequence number within it, if there is a stream.
+fn next_random_stream_sequence() -> Option<(u64, u64)> {
+    RANDOM_STREAM.with(|state| {
+        state.get().map(|(stream, sequence)| {
+            state.set(Some((stream, sequence + 1)));
+            (stream, sequence)
+        })
+    })
+}
+
+/// Derives a new seed from given one and stream index using splitmix64 finalizer.
+fn derive_seed(seed: u64, stream: u64) -> u64 {
+    let mut value = seed ^ stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    value ^ (value >> 31)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn get_thread_stream() -> u64 {
+    // NOTE zero is reserved for threads outside of thread pools
+    rayon::current_thread_index().map_or(0, |idx| idx as u64 + 1)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn get_thread_stream() -> u64 {
+    0
 }
 
 /// Provides way to generate some noise to floating point value.
@@ -20,6 +20,7 @@ pub fn test_actor() -> Arc<Actor> {
                 time: TimeInterval { earliest: None, latest: Some(DEFAULT_ACTOR_TIME_WINDOW.end) },
             }),
             time: DEFAULT_ACTOR_TIME_WINDOW,
+            driver: None,
         },
     })
 }
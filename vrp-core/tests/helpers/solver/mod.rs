@@ -1,11 +1,13 @@
 use crate::algorithms::geometry::Point;
+use crate::construction::constraints::ConstraintVariant;
+use crate::construction::heuristics::RouteContext;
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
-use crate::helpers::models::domain::test_random;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context, test_random};
 use crate::helpers::models::problem::*;
-use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
-use crate::models::common::Location;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Cost, Location};
 use crate::models::problem::*;
-use crate::models::solution::{Registry, Route};
+use crate::models::solution::{Activity, Registry, Route};
 use crate::models::{Problem, Solution};
 use crate::solver::population::create_elitism_population;
 use crate::solver::RefinementContext;
@@ -22,6 +24,34 @@ pub fn create_default_refinement_ctx(problem: Arc<Problem>) -> RefinementContext
     )
 }
 
+/// Creates route context with given activities for a test vehicle with given id.
+pub fn create_test_route_ctx(vehicle_id: &str, activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id(vehicle_id)).build();
+
+    create_route_context_with_activities(&fleet, vehicle_id, activities)
+}
+
+/// Estimates job insertion into the route using soft route constraints of given module.
+pub fn estimate_soft_route_job(module: &TargetConstraint, route_ctx: &RouteContext, job: &Job) -> Cost {
+    let solution_ctx = create_empty_solution_context();
+
+    module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::SoftRoute(constraint) => Some(constraint.estimate_job(&solution_ctx, route_ctx, job)),
+            _ => None,
+        })
+        .sum()
+}
+
+/// Returns fitness of the solution which consists of given routes.
+pub fn get_fitness_with_routes(objective: &TargetObjective, routes: Vec<RouteContext>) -> f64 {
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.extend(routes);
+
+    objective.fitness(&insertion_ctx)
+}
+
 /// Generates matrix routes. See `generate_matrix_routes`.
 pub fn generate_matrix_routes_with_defaults(rows: usize, cols: usize, is_open_vrp: bool) -> (Problem, Solution) {
     generate_matrix_routes(
@@ -1,5 +1,4 @@
-use crate::utils::Random;
-use rand::prelude::*;
+use crate::utils::{Random, RandomAlgorithm, RandomGen};
 
 struct FakeDistribution<T> {
     values: Vec<T>,
@@ -45,8 +44,8 @@ impl Random for FakeRandom {
         unsafe { self.const_cast().reals.next() }
     }
 
-    fn get_rng(&self) -> StdRng {
-        StdRng::seed_from_u64(0)
+    fn get_rng(&self) -> RandomGen {
+        RandomGen::new(RandomAlgorithm::default(), 0)
     }
 }
 
@@ -77,7 +76,7 @@ impl Random for EchoRandom {
         }
     }
 
-    fn get_rng(&self) -> StdRng {
-        StdRng::seed_from_u64(0)
+    fn get_rng(&self) -> RandomGen {
+        RandomGen::new(RandomAlgorithm::default(), 0)
     }
 }
@@ -0,0 +1,64 @@
+use super::*;
+use crate::algorithms::geometry::Point;
+use crate::helpers::algorithms::p;
+use crate::helpers::utils::random::FakeRandom;
+
+fn distance_fn(a: &Point, b: &Point) -> f64 {
+    a.distance_to_point(b)
+}
+
+fn get_indices(points: &[Point], items: &[&Point]) -> Vec<usize> {
+    let mut indices = items
+        .iter()
+        .map(|item| points.iter().position(|point| std::ptr::eq(point, *item)).unwrap())
+        .collect::<Vec<_>>();
+    indices.sort();
+    indices
+}
+
+#[test]
+fn can_create_clusters_for_separated_points() {
+    let points = vec![p(0., 0.), p(1., 0.), p(0., 1.), p(10., 10.), p(11., 10.), p(10., 11.)];
+    let random = FakeRandom::new(vec![0], vec![300.]);
+
+    let clusters = create_k_medoids(points.as_slice(), &KMedoidsParams::new(2), distance_fn, &random);
+
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(get_indices(points.as_slice(), &[clusters[0].medoid, clusters[1].medoid]), vec![0, 3]);
+    assert_eq!(get_indices(points.as_slice(), clusters[0].items.as_slice()), vec![0, 1, 2]);
+    assert_eq!(get_indices(points.as_slice(), clusters[1].items.as_slice()), vec![3, 4, 5]);
+}
+
+parameterized_test! {can_create_clusters_with_balance_option, (is_balanced, expected), {
+    can_create_clusters_with_balance_option_impl(is_balanced, expected);
+}}
+
+can_create_clusters_with_balance_option! {
+    case01_not_balanced: (false, (vec![0, 1, 2, 3], vec![4, 5])),
+    case02_balanced: (true, (vec![0, 1, 2], vec![3, 4, 5])),
+}
+
+fn can_create_clusters_with_balance_option_impl(is_balanced: bool, expected: (Vec<usize>, Vec<usize>)) {
+    let points = vec![p(0., 0.), p(1., 0.), p(2., 0.), p(3., 0.), p(10., 0.), p(11., 0.)];
+    let random = FakeRandom::new(vec![0], vec![200.]);
+    let params = KMedoidsParams { is_balanced, ..KMedoidsParams::new(2) };
+
+    let clusters = create_k_medoids(points.as_slice(), &params, distance_fn, &random);
+
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(get_indices(points.as_slice(), clusters[0].items.as_slice()), expected.0);
+    assert_eq!(get_indices(points.as_slice(), clusters[1].items.as_slice()), expected.1);
+}
+
+#[test]
+fn can_handle_edge_cases() {
+    let points = vec![p(0., 0.), p(1., 0.)];
+    let random = FakeRandom::new(vec![0], vec![0.5]);
+
+    assert!(create_k_medoids(&[], &KMedoidsParams::new(2), distance_fn, &random).is_empty());
+    assert!(create_k_medoids(points.as_slice(), &KMedoidsParams::new(0), distance_fn, &random).is_empty());
+
+    let clusters = create_k_medoids(points.as_slice(), &KMedoidsParams::new(3), distance_fn, &random);
+    assert_eq!(clusters.len(), 2);
+    assert!(clusters.iter().all(|cluster| cluster.items.len() == 1));
+}
@@ -158,3 +158,32 @@ fn can_create_clusters_with_single_link() {
 
     assert_non_ordered(clusters[0].clone(), vec![&ps[0], &ps[1], &ps[2], &ps[3], &ps[4], &ps[5], &ps[6], &ps[7]]);
 }
+
+#[test]
+fn can_get_max_curvature() {
+    let values =
+        &[p(0., 0.), p(1., 0.25), p(2., 0.5), p(3., 0.75), p(4., 1.), p(6., 2.), p(7., 4.), p(8., 6.), p(9., 8.)];
+
+    assert_eq!(get_max_curvature(values), 2.);
+}
+
+#[test]
+fn can_create_clusters_with_distance_based_neighborhood() {
+    let ps = vec![p(0., 0.), p(0., 1.), p(1., 0.), p(1., 1.), p(10., 10.), p(10., 11.), p(11., 10.), p(30., 30.)];
+    let neighborhood_fn = create_neighborhood_fn(ps.as_slice(), |a: &Point, b: &Point| a.distance_to_point(b));
+
+    let clusters = create_clusters(ps.as_slice(), 1.5, 3, &neighborhood_fn);
+
+    assert_eq!(clusters.len(), 2);
+    assert_non_ordered(clusters[0].clone(), vec![&ps[0], &ps[1], &ps[2], &ps[3]]);
+    assert_non_ordered(clusters[1].clone(), vec![&ps[4], &ps[5], &ps[6]]);
+}
+
+#[test]
+fn can_estimate_epsilon_with_distance_function() {
+    let ps = vec![p(0., 0.), p(0., 1.), p(0., 2.), p(0., 3.), p(0., 4.), p(0., 5.), p(0., 20.)];
+
+    let eps = estimate_epsilon(ps.as_slice(), 1, |a: &Point, b: &Point| a.distance_to_point(b));
+
+    assert_eq!(eps, 1.);
+}
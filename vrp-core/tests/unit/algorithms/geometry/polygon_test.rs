@@ -0,0 +1,81 @@
+use super::*;
+use crate::helpers::algorithms::p;
+
+fn round(value: f64) -> f64 {
+    (value * 1000.).round() / 1000.
+}
+
+fn square(x: f64, y: f64, size: f64) -> Vec<Point> {
+    vec![p(x, y), p(x + size, y), p(x + size, y + size), p(x, y + size)]
+}
+
+#[test]
+fn can_build_convex_hull() {
+    let points = vec![p(0., 0.), p(2., 0.), p(1., 1.), p(2., 2.), p(0., 2.), p(1., 0.), p(0., 2.)];
+
+    let hull = get_convex_hull(points.as_slice());
+
+    assert_eq!(hull, vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.)]);
+}
+
+parameterized_test! {can_handle_degenerate_convex_hull, (points, expected), {
+    assert_eq!(get_convex_hull(points.as_slice()), expected);
+}}
+
+can_handle_degenerate_convex_hull! {
+    case01: (vec![], vec![]),
+    case02: (vec![p(1., 1.), p(1., 1.)], vec![p(1., 1.)]),
+    case03: (vec![p(0., 0.), p(1., 1.), p(2., 2.)], vec![p(0., 0.), p(2., 2.)]),
+}
+
+#[test]
+fn can_calculate_polygon_area() {
+    assert_eq!(get_polygon_area(square(0., 0., 2.).as_slice()), 4.);
+    assert_eq!(get_polygon_area(vec![p(0., 0.), p(4., 0.), p(0., 3.)].as_slice()), 6.);
+    assert_eq!(get_polygon_area(vec![p(0., 0.), p(4., 0.)].as_slice()), 0.);
+}
+
+parameterized_test! {can_calculate_intersection_area, (subject, clip, expected), {
+    assert_eq!(round(get_intersection_area(subject.as_slice(), clip.as_slice())), expected);
+}}
+
+can_calculate_intersection_area! {
+    case01_partial: (square(0., 0., 2.), square(1., 1., 2.), 1.),
+    case02_inner: (square(0., 0., 4.), square(1., 1., 1.), 1.),
+    case03_disjoint: (square(0., 0., 1.), square(2., 2., 1.), 0.),
+    case04_same: (square(0., 0., 2.), square(0., 0., 2.), 4.),
+    case05_triangle: (vec![p(0., 0.), p(2., 0.), p(0., 2.)], square(0., 0., 1.), 1.),
+}
+
+parameterized_test! {can_detect_segments_cross, (a1, a2, b1, b2, expected), {
+    assert_eq!(is_segments_cross(&a1, &a2, &b1, &b2), expected);
+}}
+
+can_detect_segments_cross! {
+    case01_cross: (p(0., 0.), p(2., 2.), p(0., 2.), p(2., 0.), true),
+    case02_parallel: (p(0., 0.), p(2., 0.), p(0., 1.), p(2., 1.), false),
+    case03_touch_end: (p(0., 0.), p(2., 2.), p(2., 2.), p(3., 0.), false),
+    case04_collinear: (p(0., 0.), p(2., 0.), p(1., 0.), p(3., 0.), false),
+    case05_apart: (p(0., 0.), p(1., 1.), p(2., 0.), p(3., -1.), false),
+}
+
+#[test]
+fn can_count_route_crossings() {
+    let routes = vec![
+        vec![p(0., 0.), p(2., 2.), p(4., 0.), p(0., 0.)],
+        vec![p(0., 0.), p(0., 2.), p(4., 2.), p(0., 0.)],
+        vec![p(0., 0.), p(-2., -2.), p(0., 0.)],
+    ];
+
+    assert_eq!(count_route_crossings(routes.as_slice()), 1);
+}
+
+#[test]
+fn can_calculate_route_overlap() {
+    let separated = vec![square(0., 0., 2.), square(3., 0., 2.)];
+    let overlapped = vec![square(0., 0., 2.), square(1., 0., 2.)];
+
+    assert_eq!(get_route_overlap(separated.as_slice()), 0.);
+    assert_eq!(round(get_route_overlap(overlapped.as_slice())), 0.25);
+    assert_eq!(get_route_overlap(&[]), 0.);
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::algorithms::gsom::{get_network_state, NodeState};
+use crate::helpers::algorithms::gsom::create_test_network;
+
+fn create_test_state() -> NetworkState {
+    NetworkState {
+        shape: (0..1, -1..0, 2),
+        nodes: vec![
+            NodeState {
+                coordinate: (0, 0),
+                unified_distance: 0.5,
+                weights: vec![1., 2.],
+                total_hits: 3,
+                last_hits: 1,
+                dump: "[[1.0,2.0],]".to_string(),
+            },
+            NodeState {
+                coordinate: (1, -1),
+                unified_distance: 0.25,
+                weights: vec![3., 4.],
+                total_hits: 0,
+                last_hits: 0,
+                dump: "\"quoted\"".to_string(),
+            },
+        ],
+    }
+}
+
+fn write_to_string(write_func: impl Fn(&NetworkState, &mut Vec<u8>) -> Result<(), String>) -> String {
+    let mut buffer = Vec::new();
+    write_func(&create_test_state(), &mut buffer).unwrap();
+
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn can_write_network_state_json() {
+    let result = write_to_string(write_network_state_json);
+
+    assert_eq!(
+        result,
+        concat!(
+            r#"{"shape":{"rows":[0,1],"cols":[-1,0],"weights":2},"nodes":["#,
+            r#"{"x":0,"y":0,"unifiedDistance":0.5000000,"totalHits":3,"lastHits":1,"#,
+            r#""weights":[1.0000000,2.0000000],"storage":"[[1.0,2.0],]"},"#,
+            r#"{"x":1,"y":-1,"unifiedDistance":0.2500000,"totalHits":0,"lastHits":0,"#,
+            r#""weights":[3.0000000,4.0000000],"storage":"\"quoted\""}]}"#
+        )
+    );
+}
+
+#[test]
+fn can_write_network_state_csv() {
+    let result = write_to_string(write_network_state_csv);
+
+    assert_eq!(
+        result,
+        "x,y,unified_distance,total_hits,last_hits,w0,w1,storage\n\
+         0,0,0.5000000,3,1,1.0000000,2.0000000,\"[[1.0,2.0],]\"\n\
+         1,-1,0.2500000,0,0,3.0000000,4.0000000,\"\"\"quoted\"\"\"\n"
+    );
+}
+
+#[test]
+fn can_write_test_network_state() {
+    let state = get_network_state(&create_test_network());
+    let mut buffer = Vec::new();
+
+    write_network_state_csv(&state, &mut buffer).unwrap();
+
+    let result = String::from_utf8(buffer).unwrap();
+    assert_eq!(result.lines().count(), 5);
+    assert!(result.starts_with("x,y,unified_distance,total_hits,last_hits,w0,w1,w2,storage\n"));
+}
+
+parameterized_test! {can_escape_json, (value, expected), {
+    assert_eq!(escape_json(value), expected);
+}}
+
+can_escape_json! {
+    case01_plain: ("plain", "plain"),
+    case02_quote_and_backslash: ("a\"b\\c", r#"a\"b\\c"#),
+    case03_whitespace: ("a\nb\r\tc", r#"a\nb\r\tc"#),
+    case04_control: ("a\u{0}b\u{1f}c\u{8}", r#"a\u0000b\u001fc\u0008"#),
+    case05_unicode: ("ä\u{7f}", "ä\u{7f}"),
+}
@@ -5,13 +5,13 @@ use std::sync::{Arc, RwLock};
 
 type ActionCounter = Arc<RwLock<Vec<GridAction>>>;
 
-#[derive(PartialEq, Eq, Hash, Clone)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 enum GridState {
     OnGrid { x: i32, y: i32 },
     Terminal,
 }
 
-#[derive(PartialEq, Eq, Hash, Clone)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 enum GridAction {
     Move { dx: i32, dy: i32 },
 }
@@ -189,3 +189,100 @@ fn can_run_grid_episodes_impl(
         assert_eq!((dx, dy), (e_dx, e_dy));
     }
 }
+
+fn encode_state(state: &GridState) -> String {
+    match state {
+        GridState::OnGrid { x, y } => format!("{},{}", x, y),
+        GridState::Terminal => "terminal".to_string(),
+    }
+}
+
+fn decode_state(value: &str) -> Result<GridState, String> {
+    match value {
+        "terminal" => Ok(GridState::Terminal),
+        _ => decode_pair(value).map(|(x, y)| GridState::OnGrid { x, y }),
+    }
+}
+
+fn encode_action(action: &GridAction) -> String {
+    match action {
+        GridAction::Move { dx, dy } => format!("{},{}", dx, dy),
+    }
+}
+
+fn decode_action(value: &str) -> Result<GridAction, String> {
+    decode_pair(value).map(|(dx, dy)| GridAction::Move { dx, dy })
+}
+
+fn decode_pair(value: &str) -> Result<(i32, i32), String> {
+    let values =
+        value.split(',').map(|v| v.parse::<i32>().map_err(|err| err.to_string())).collect::<Result<Vec<_>, _>>()?;
+
+    match values.as_slice() {
+        [first, second] => Ok((*first, *second)),
+        _ => Err(format!("unexpected value: '{}'", value)),
+    }
+}
+
+fn create_simulator() -> Simulator<GridState> {
+    Simulator::new(Box::new(QLearning::new(0.2, 0.01)), Box::new(Greedy::default()))
+}
+
+#[test]
+fn can_write_and_read_state_estimates() {
+    let state = GridState::OnGrid { x: 0, y: 0 };
+    let mut simulator = create_simulator();
+    run_simulator(&mut simulator, 100, 1, false, |counter| create_agent(state.clone(), counter));
+
+    let mut buffer = Vec::new();
+    simulator.write_state_estimates(&mut buffer, encode_state, encode_action).unwrap();
+    let mut restored = create_simulator();
+    restored.read_state_estimates(BufReader::new(buffer.as_slice()), decode_state, decode_action).unwrap();
+
+    // NOTE terminal states have no actions, so they are not written
+    let expected = simulator
+        .get_state_estimates()
+        .iter()
+        .filter(|(_, estimates)| !estimates.is_empty())
+        .map(|(state, estimates)| (state.clone(), estimates.clone()))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(restored.get_state_estimates(), &expected);
+    (0..4).flat_map(|x| (0..4).map(move |y| GridState::OnGrid { x, y })).for_each(|state| {
+        // NOTE actions with the same estimate are selected in arbitrary order, so compare values only
+        let get_value = |simulator: &Simulator<GridState>| simulator.get_optimal_policy(&state).map(|(_, v)| v);
+        assert_eq!(get_value(&restored), get_value(&simulator));
+    });
+}
+
+#[test]
+fn can_bootstrap_simulator_with_state_estimates() {
+    let state = GridState::OnGrid { x: 0, y: 0 };
+    let mut simulator = create_simulator();
+    run_simulator(&mut simulator, 1000, 1, false, |counter| create_agent(state.clone(), counter));
+    let mut bootstrapped = create_simulator();
+    bootstrapped.set_state_estimates(simulator.get_state_estimates().clone());
+
+    let actions_taken = run_simulator(&mut bootstrapped, 1, 1, false, |counter| create_agent(state.clone(), counter));
+
+    assert_eq!(actions_taken[0][0].len(), 6);
+}
+
+parameterized_test! {can_detect_invalid_state_estimates, (data, expected), {
+    can_detect_invalid_state_estimates_impl(data, expected);
+}}
+
+can_detect_invalid_state_estimates! {
+    case01: ("0,0\t1,0\tabc\n", "cannot parse estimate value: 'abc'"),
+    case02: ("0,0\t1,0\n", "cannot read state estimate line: '0,0\t1,0'"),
+    case03: ("0,0\t1,0\t1\t2\n", "cannot read state estimate line: '0,0\t1,0\t1\t2'"),
+    case04: ("a,0\t1,0\t1\n", "invalid digit found in string"),
+}
+
+fn can_detect_invalid_state_estimates_impl(data: &str, expected: &str) {
+    let mut simulator = create_simulator();
+
+    let result = simulator.read_state_estimates(BufReader::new(data.as_bytes()), decode_state, decode_action);
+
+    assert_eq!(result, Err(expected.to_string()));
+    assert!(simulator.get_state_estimates().is_empty());
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+fn round(value: f64) -> f64 {
+    (value * 1000.).round() / 1000.
+}
+
+parameterized_test! {can_get_normal_quantile, (p, expected), {
+    assert_eq!(round(get_normal_quantile(p)), expected);
+}}
+
+can_get_normal_quantile! {
+    case01: (0.5, 0.),
+    case02: (0.9, 1.282),
+    case03: (0.95, 1.645),
+    case04: (0.99, 2.326),
+    case05: (0.01, -2.326),
+    case06: (0.1, -1.282),
+}
+
+#[test]
+fn can_invert_normal_cdf() {
+    (1..100).map(|idx| idx as f64 / 100.).for_each(|p| {
+        assert!((get_normal_cdf(get_normal_quantile(p)) - p).abs() < 1E-5);
+    });
+}
+
+#[test]
+fn can_handle_normal_quantile_bounds() {
+    assert_eq!(get_normal_quantile(0.), f64::NEG_INFINITY);
+    assert_eq!(get_normal_quantile(1.), f64::INFINITY);
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+fn assert_result(result: Option<SignificanceResult>, expected: (f64, f64)) {
+    let result = result.expect("result is expected");
+
+    assert_eq!(result.statistic, expected.0);
+    assert!((result.p_value - expected.1).abs() < 1E-4, "unexpected p-value: {}", result.p_value);
+}
+
+parameterized_test! {can_run_mann_whitney_u_test, (a, b, expected), {
+    assert_result(mann_whitney_u_test(a.as_slice(), b.as_slice()), expected);
+}}
+
+can_run_mann_whitney_u_test! {
+    case01_no_overlap: (vec![1., 2., 3., 4., 5.], vec![6., 7., 8., 9., 10.], (0., 0.012186)),
+    case02_with_ties: (vec![1., 2., 2., 3., 7.], vec![2., 4., 5., 6., 6.], (7., 0.288844)),
+    case03_same: (vec![1., 1., 1.], vec![1., 1., 1.], (4.5, 1.)),
+}
+
+parameterized_test! {can_run_wilcoxon_signed_rank_test, (a, b, expected), {
+    assert_result(wilcoxon_signed_rank_test(a.as_slice(), b.as_slice()), expected);
+}}
+
+can_run_wilcoxon_signed_rank_test! {
+    case01_all_better: (vec![10., 12., 14., 16., 18., 20., 22., 24.], vec![9., 10., 11., 12., 13., 14., 15., 16.], (0., 0.014266)),
+    case02_mixed: (vec![1., 2., 3., 4.], vec![2., 1., 4., 3.], (5., 1.)),
+}
+
+#[test]
+fn can_handle_invalid_input() {
+    assert!(mann_whitney_u_test(&[], &[1.]).is_none());
+    assert!(wilcoxon_signed_rank_test(&[1., 2.], &[1.]).is_none());
+    assert!(wilcoxon_signed_rank_test(&[1., 2.], &[1., 2.]).is_none());
+}
+
+#[test]
+fn can_check_significance() {
+    let result = SignificanceResult { statistic: 0., p_value: 0.01 };
+
+    assert!(result.is_significant(0.05));
+    assert!(!result.is_significant(0.01));
+}
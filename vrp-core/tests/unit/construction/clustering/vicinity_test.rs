@@ -0,0 +1,140 @@
+use super::*;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::problem::*;
+use crate::models::common::{IdDimension, SingleDimLoad};
+
+fn create_config(duration: Duration, max_jobs: Option<usize>, visiting: VisitPolicy) -> ClusterConfig {
+    ClusterConfig {
+        profile: 0,
+        threshold: ThresholdPolicy {
+            moving_duration: duration,
+            moving_distance: duration,
+            max_jobs_per_cluster: max_jobs,
+        },
+        visiting,
+        filtering: FilterPolicy {
+            job_filter: Arc::new(|job| !matches!(job.dimens().get_id(), Some(id) if id == "skip")),
+        },
+    }
+}
+
+fn create_jobs(locations: Vec<(&str, Location)>) -> Vec<Job> {
+    locations
+        .into_iter()
+        .map(|(id, location)| SingleBuilder::default().id(id).location(Some(location)).duration(1.).build_as_job_ref())
+        .collect()
+}
+
+fn get_ids(jobs: &[Job]) -> Vec<Vec<String>> {
+    jobs.iter()
+        .map(|job| match job.dimens().get_cluster_info() {
+            Some(info) => info.iter().map(|info| info.job.dimens().get_id().unwrap().clone()).collect(),
+            None => vec![job.dimens().get_id().unwrap().clone()],
+        })
+        .collect()
+}
+
+fn create_clusters(jobs: Vec<Job>, config: &ClusterConfig) -> Vec<Job> {
+    create_job_clusters::<SingleDimLoad>(jobs, &TestTransportCost {}, config)
+}
+
+parameterized_test! {can_create_clusters, (locations, threshold, max_jobs, expected), {
+    can_create_clusters_impl(locations, threshold, max_jobs, expected);
+}}
+
+can_create_clusters! {
+    case01_all_in_one: (vec![("job1", 1), ("job2", 2), ("job3", 3)], 2., None, vec![vec!["job1", "job2", "job3"]]),
+    case02_too_far: (vec![("job1", 1), ("job2", 5), ("job3", 9)], 2., None, vec![vec!["job1"], vec!["job2"], vec!["job3"]]),
+    case03_two_clusters: (vec![("job1", 1), ("job2", 2), ("job3", 10), ("job4", 11)], 2.,  None,
+                          vec![vec!["job1", "job2"], vec!["job3", "job4"]]),
+    case04_max_jobs: (vec![("job1", 1), ("job2", 2), ("job3", 3)], 2., Some(2), vec![vec!["job1", "job2"], vec!["job3"]]),
+    case05_filtered: (vec![("job1", 1), ("skip", 2), ("job3", 3)], 2., None, vec![vec!["job1", "job3"], vec!["skip"]]),
+}
+
+fn can_create_clusters_impl(
+    locations: Vec<(&str, Location)>,
+    threshold: Duration,
+    max_jobs: Option<usize>,
+    expected: Vec<Vec<&str>>,
+) {
+    let config = create_config(threshold, max_jobs, VisitPolicy::ServeInPlace);
+
+    let jobs = create_clusters(create_jobs(locations), &config);
+
+    assert_eq!(get_ids(jobs.as_slice()), expected);
+}
+
+#[test]
+fn can_create_cluster_with_walking_commute() {
+    let mut jobs = create_jobs(vec![("job1", 1), ("job2", 3)]);
+    jobs.push(
+        SingleBuilder::default()
+            .id("job3")
+            .location(Some(2))
+            .duration(1.)
+            .demand(create_simple_demand(-2))
+            .build_as_job_ref(),
+    );
+    let config = create_config(1.5, None, VisitPolicy::WalkFromParking);
+
+    let jobs = create_clusters(jobs, &config);
+
+    assert_eq!(jobs.len(), 1);
+    let cluster = jobs.first().unwrap().to_single();
+    assert_eq!(get_ids(jobs.as_slice()), vec![vec!["job3", "job1", "job2"]]);
+    assert_eq!(cluster.places.len(), 1);
+    assert_eq!(cluster.places[0].location, Some(2));
+    assert_eq!(cluster.places[0].duration, 7.);
+    assert_eq!(
+        cluster.dimens.get_demand().map(|demand: &Demand<SingleDimLoad>| demand.delivery.0),
+        Some(SingleDimLoad::new(2))
+    );
+    let commutes = cluster
+        .dimens
+        .get_cluster_info()
+        .unwrap()
+        .iter()
+        .map(|info| (info.commute.forward.duration, info.service_time, info.commute.backward.duration))
+        .collect::<Vec<_>>();
+    assert_eq!(commutes, vec![(0., 1., 0.), (1., 1., 1.), (1., 1., 1.)]);
+}
+
+parameterized_test! {can_respect_time_windows, (times, expected_ids, expected_time), {
+    can_respect_time_windows_impl(times, expected_ids, expected_time);
+}}
+
+can_respect_time_windows! {
+    case01_shifted_by_offset: (vec![Some((0., 10.)), Some((2., 12.))], vec![vec!["job1", "job2"]], Some((0., 10.))),
+    case02_partially_open: (vec![None, Some((5., 12.))], vec![vec!["job1", "job2"]], Some((3., 10.))),
+    case03_no_times: (vec![None, None], vec![vec!["job1", "job2"]], None),
+    case04_not_intersecting: (vec![Some((0., 1.)), Some((10., 12.))], vec![vec!["job1"], vec!["job2"]], None),
+}
+
+fn can_respect_time_windows_impl(
+    times: Vec<Option<(f64, f64)>>,
+    expected_ids: Vec<Vec<&str>>,
+    expected_time: Option<(f64, f64)>,
+) {
+    let jobs = times
+        .into_iter()
+        .enumerate()
+        .map(|(idx, time)| {
+            SingleBuilder::default()
+                .id(format!("job{}", idx + 1).as_str())
+                .location(Some(idx))
+                .duration(1.)
+                .times(time.map(|(start, end)| vec![TimeWindow::new(start, end)]).unwrap_or_default())
+                .build_as_job_ref()
+        })
+        .collect();
+    let config = create_config(1., None, VisitPolicy::WalkFromParking);
+
+    let jobs = create_clusters(jobs, &config);
+
+    assert_eq!(get_ids(jobs.as_slice()), expected_ids);
+    if expected_ids.len() == 1 {
+        let times = &jobs.first().unwrap().to_single().places[0].times;
+        let times = times.iter().map(|time| time.as_time_window().unwrap()).collect::<Vec<_>>();
+        assert_eq!(times, expected_time.map(|(start, end)| vec![TimeWindow::new(start, end)]).unwrap_or_default());
+    }
+}
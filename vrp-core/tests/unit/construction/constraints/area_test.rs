@@ -46,7 +46,7 @@ fn can_check_single_job_impl(
     activity_location: Location,
     expected: (Option<()>, Option<()>),
 ) {
-    let areas = vec![Area { priority: None, outer_shape: vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)] }];
+    let areas = vec![Area::new(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)])];
     let solution_ctx = create_empty_solution_context();
     let route_ctx = create_route_context_with_activities(&create_fleet(areas), vehicle_id, vec![]);
     let activity_ctx = ActivityContext {
@@ -82,7 +82,7 @@ can_check_multi_job! {
 }
 
 fn can_check_multi_job_impl(job_locations: Vec<Option<Location>>, expected: Option<()>) {
-    let areas = vec![Area { priority: None, outer_shape: vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)] }];
+    let areas = vec![Area::new(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)])];
     let solution_ctx = create_empty_solution_context();
     let route_ctx = create_route_context_with_activities(&create_fleet(areas), "v1", vec![]);
     let pipeline = create_area_constraint_pipeline();
@@ -131,6 +131,19 @@ fn can_check_location_in_area() {
     assert_eq!(is_location_in_area(&(52.480890, 13.4081030), &polygon), true);
 }
 
+parameterized_test! {can_check_location_in_area_with_bounding_box, (location, expected), {
+    let area = Area::new(None, vec![(1., 3.), (2., 8.), (5., 4.), (5., 9.), (7., 5.), (13., 1.), (3., 1.)]);
+
+    assert_eq!(area.contains(&location), expected);
+}}
+
+can_check_location_in_area_with_bounding_box! {
+    case01_inside: ((5.5, 7.), true),
+    case02_inside_box_outside_polygon: ((4.5, 7.), false),
+    case03_outside_box: ((14., 2.), false),
+    case04_outside_box_negative: ((-1., -1.), false),
+}
+
 parameterized_test! {can_estimate_activity_with_penalty, (priority, route_cost, expected), {
     can_estimate_activity_with_penalty_impl(priority, route_cost, expected);
 }}
@@ -152,7 +165,7 @@ can_estimate_activity_with_penalty! {
 }
 
 fn can_estimate_activity_with_penalty_impl(priority: Option<usize>, route_cost: Option<Cost>, expected_cost: Cost) {
-    let areas = vec![Area { priority, outer_shape: vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)] }];
+    let areas = vec![Area::new(priority, vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)])];
     let area_constraint = AreaSoftActivityConstraint {
         area_resolver: Arc::new(move |actor| actor.vehicle.dimens.get_value::<Vec<Area>>("areas")),
         location_resolver: Arc::new(|location| (location as f64, 0.)),
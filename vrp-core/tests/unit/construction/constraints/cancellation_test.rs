@@ -0,0 +1,78 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::{test_fleet, test_single_with_id};
+use crate::helpers::models::solution::{create_route_context_with_activities, test_activity_with_job};
+use crate::models::solution::Activity;
+
+const CANCELLATION_CODE: i32 = 1;
+
+fn create_jobs(size: usize) -> Vec<Job> {
+    (0..size).map(|idx| Job::Single(test_single_with_id(format!("job{}", idx).as_str()))).collect()
+}
+
+fn create_activity(job: &Job) -> Activity {
+    test_activity_with_job(job.to_single().clone())
+}
+
+#[test]
+fn can_cancel_jobs() {
+    let jobs = create_jobs(2);
+    let cancellation = JobCancellation::default();
+
+    assert!(cancellation.cancel(jobs[0].clone()));
+    assert!(!cancellation.cancel(jobs[0].clone()));
+
+    assert!(cancellation.is_cancelled(&jobs[0]));
+    assert!(!cancellation.is_cancelled(&jobs[1]));
+    assert_eq!(cancellation.size(), 1);
+}
+
+#[test]
+fn can_reject_cancelled_job_insertion() {
+    let jobs = create_jobs(2);
+    let cancellation = Arc::new(JobCancellation::default());
+    cancellation.cancel(jobs[0].clone());
+    let pipeline =
+        create_constraint_pipeline_with_module(Box::new(JobCancellationModule::new(cancellation, CANCELLATION_CODE)));
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&test_fleet(), "v1", vec![]);
+
+    let results = jobs
+        .iter()
+        .map(|job| pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, job).map(|violation| violation.code))
+        .collect::<Vec<_>>();
+
+    assert_eq!(results, vec![Some(CANCELLATION_CODE), None]);
+}
+
+#[test]
+fn can_withdraw_cancelled_jobs_from_solution() {
+    let jobs = create_jobs(5);
+    let cancellation = Arc::new(JobCancellation::default());
+    vec![0, 2, 3].into_iter().for_each(|idx| {
+        cancellation.cancel(jobs[idx].clone());
+    });
+    let module = JobCancellationModule::new(cancellation, CANCELLATION_CODE);
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        vec![create_activity(&jobs[0]), create_activity(&jobs[1])],
+    ));
+    solution_ctx.locked.insert(jobs[0].clone());
+    solution_ctx.required.extend(vec![jobs[2].clone(), jobs[4].clone()]);
+    solution_ctx.unassigned.insert(jobs[3].clone(), 1);
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    let route_ctx = solution_ctx.routes.first().unwrap();
+    assert!(route_ctx.is_stale());
+    assert_eq!(route_ctx.route.tour.job_count(), 1);
+    assert!(route_ctx.route.tour.contains(&jobs[1]));
+    assert!(solution_ctx.required == vec![jobs[4].clone()]);
+    assert!(solution_ctx.unassigned.is_empty());
+    assert!(solution_ctx.locked.is_empty());
+    assert_eq!(solution_ctx.ignored.len(), 3);
+    assert!(vec![0, 2, 3].into_iter().all(|idx| solution_ctx.ignored.contains(&jobs[idx])));
+}
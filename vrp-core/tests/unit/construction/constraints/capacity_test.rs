@@ -1,12 +1,13 @@
 use crate::construction::constraints::*;
-use crate::construction::heuristics::{ActivityContext, RouteState};
+use crate::construction::heuristics::{ActivityContext, RouteContext, RouteState};
 use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::SingleDimLoad;
-use crate::models::problem::{Job, Vehicle};
-use crate::models::solution::Activity;
+use crate::models::common::{CapacityOverload, CapacityOverloadDimension, SingleDimLoad, ValueDimension};
+use crate::models::problem::{Job, Single, Vehicle};
+use crate::models::solution::{Activity, Route};
+use std::sync::Arc;
 
 fn create_test_vehicle(capacity: i32) -> Vehicle {
     VehicleBuilder::default().id("v1").capacity(capacity).build()
@@ -130,3 +131,158 @@ fn can_evaluate_demand_on_activity_impl(
 
     assert_eq!(result, expected);
 }
+
+fn create_overload_vehicle(capacity: i32) -> Vehicle {
+    let mut vehicle = create_test_vehicle(capacity);
+    vehicle.dimens.set_capacity_overload(CapacityOverload { max_ratio: 0.2, penalty: 100. });
+
+    vehicle
+}
+
+parameterized_test! {can_allow_capacity_overload, (size, expected_violation, expected_cost), {
+    can_allow_capacity_overload_impl(size, expected_violation, expected_cost);
+}}
+
+can_allow_capacity_overload! {
+    case01: (0, None, 0.),
+    case02: (2, None, 200.),
+    case03: (3, create_activity_violation(true), 300.),
+}
+
+fn can_allow_capacity_overload_impl(
+    size: i32,
+    expected_violation: Option<ActivityConstraintViolation>,
+    expected_cost: f64,
+) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_overload_vehicle(10)).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(5))),
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(5))),
+        ],
+    );
+    let pipeline = create_constraint_pipeline_with_simple_capacity();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size)));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    assert_eq!(pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx), expected_violation);
+    assert_eq!(pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx), expected_cost);
+}
+
+#[test]
+fn can_calculate_capacity_overload_state() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_overload_vehicle(10)).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(6))),
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(6))),
+        ],
+    );
+
+    create_constraint_pipeline_with_simple_capacity().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<i32>(CAPACITY_OVERLOAD_KEY).cloned(), Some(2));
+    assert_eq!(route_ctx.get_route_cost(), 200.);
+}
+
+parameterized_test! {can_count_capacity_violations, (demands, expected), {
+    can_count_capacity_violations_impl(demands, expected);
+}}
+
+can_count_capacity_violations! {
+    case01_no_violations: (vec![-4, 6], 0),
+    case02_deliveries: (vec![-6, -6], 1),
+    case03_pickups: (vec![6, 6], 2),
+}
+
+fn can_count_capacity_violations_impl(demands: Vec<i32>, expected: usize) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        demands
+            .into_iter()
+            .map(|demand| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(demand))))
+            .collect(),
+    );
+
+    create_constraint_pipeline_with_simple_capacity().accept_route_state(&mut route_ctx);
+
+    assert_eq!(count_capacity_violations::<SingleDimLoad>(&route_ctx), expected);
+}
+
+struct TestMultiTrip {}
+
+impl MultiTrip<SingleDimLoad> for TestMultiTrip {
+    fn is_reload_job(&self, job: &Job) -> bool {
+        matches!(job.as_single(), Some(single) if self.is_reload_single(single))
+    }
+
+    fn is_reload_single(&self, single: &Single) -> bool {
+        matches!(single.dimens.get_value::<String>("type"), Some(job_type) if job_type == "reload")
+    }
+
+    fn is_assignable(&self, _: &Route, job: &Job) -> bool {
+        self.is_reload_job(job)
+    }
+
+    fn is_reload_needed(&self, current: &SingleDimLoad, max_capacity: &SingleDimLoad) -> bool {
+        current.value >= max_capacity.value
+    }
+
+    fn has_reloads(&self, route_ctx: &RouteContext) -> bool {
+        route_ctx.route.tour.all_activities().any(|activity| self.get_reload(activity).is_some())
+    }
+
+    fn get_reload<'a>(&self, activity: &'a Activity) -> Option<&'a Arc<Single>> {
+        activity.job.as_ref().filter(|single| self.is_reload_single(single))
+    }
+
+    fn get_reloads<'a>(&'a self, _: &'a Route, jobs: &'a [Job]) -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync> {
+        Box::new(jobs.iter().filter(move |job| self.is_reload_job(job)).cloned())
+    }
+}
+
+parameterized_test! {can_reset_load_at_reload, (has_reload, expected_start, expected_overload), {
+    can_reset_load_at_reload_impl(has_reload, expected_start, expected_overload);
+}}
+
+can_reset_load_at_reload! {
+    case01_with_reload: (true, 8, 0),
+    case02_without_reload: (false, 16, 6),
+}
+
+fn can_reset_load_at_reload_impl(has_reload: bool, expected_start: i32, expected_overload: i32) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_overload_vehicle(10)).build();
+    let create_delivery = || test_activity_with_job(test_single_with_simple_demand(create_simple_demand(-8)));
+    let create_reload = || {
+        let mut single = test_single();
+        single.dimens.set_value("type", "reload".to_string());
+        test_activity_with_job(Arc::new(single))
+    };
+    let activities = if has_reload {
+        vec![create_delivery(), create_reload(), create_delivery()]
+    } else {
+        vec![create_delivery(), create_delivery()]
+    };
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let pipeline = create_constraint_pipeline_with_module(Box::new(
+        CapacityConstraintModule::<SingleDimLoad>::new_with_multi_trip(2, Arc::new(TestMultiTrip {})),
+    ));
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    let start = route_ctx.route.tour.start();
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, &route_ctx.state, start), expected_start);
+    assert_eq!(route_ctx.state.get_route_state::<i32>(CAPACITY_OVERLOAD_KEY).cloned(), Some(expected_overload));
+}
@@ -0,0 +1,138 @@
+use super::*;
+use crate::helpers::construction::constraints::{create_constraint_pipeline_with_module, create_simple_demand};
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Dimensions, IdDimension, SingleDimLoad, ValueDimension};
+use crate::models::problem::{Multi, Single};
+
+const NEVER_SAME_TOUR: CompatibilityPolicy = CompatibilityPolicy::NeverSameTour;
+const NEVER_LOADED: CompatibilityPolicy = CompatibilityPolicy::NeverSimultaneouslyLoaded;
+
+fn create_compatibility_pipeline() -> ConstraintPipeline {
+    create_constraint_pipeline_with_module(Box::new(CompatibilityConstraintModule::<SingleDimLoad>::new(
+        Arc::new(|job| job.dimens().get_value::<Compatibility>("compatibility")),
+        2,
+    )))
+}
+
+fn create_single(
+    id: &str,
+    group: &str,
+    incompatible: Vec<&str>,
+    policy: CompatibilityPolicy,
+    demand: i32,
+) -> Arc<Single> {
+    let mut single = SingleBuilder::default().id(id).demand(create_simple_demand(demand)).build();
+    single.dimens.set_value(
+        "compatibility",
+        Compatibility {
+            group: group.to_string(),
+            incompatible: incompatible.into_iter().map(|group| group.to_string()).collect(),
+            policy,
+        },
+    );
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(singles: Vec<Arc<Single>>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = singles.into_iter().map(test_activity_with_job).collect();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+parameterized_test! {can_check_same_tour, (incompatible, policy, other_incompatible, other_policy, expected), {
+    can_check_same_tour_impl(incompatible, policy, other_incompatible, other_policy, expected);
+}}
+
+can_check_same_tour! {
+    case01_never_same_tour: (vec!["chemicals"], NEVER_SAME_TOUR, vec![], NEVER_LOADED, Some(2)),
+    case02_never_same_tour_declared_by_other: (vec![], NEVER_LOADED, vec!["food"], NEVER_SAME_TOUR, Some(2)),
+    case03_stricter_policy_wins: (vec!["chemicals"], NEVER_LOADED, vec!["food"], NEVER_SAME_TOUR, Some(2)),
+    case04_never_loaded: (vec!["chemicals"], NEVER_LOADED, vec![], NEVER_SAME_TOUR, None),
+    case05_compatible: (vec!["other"], NEVER_SAME_TOUR, vec![], NEVER_SAME_TOUR, None),
+}
+
+fn can_check_same_tour_impl(
+    incompatible: Vec<&str>,
+    policy: CompatibilityPolicy,
+    other_incompatible: Vec<&str>,
+    other_policy: CompatibilityPolicy,
+    expected: Option<i32>,
+) {
+    let pipeline = create_compatibility_pipeline();
+    let route_ctx = create_route_ctx(vec![create_single("job1", "chemicals", other_incompatible, other_policy, -1)]);
+    let job = Job::Single(create_single("job2", "food", incompatible, policy, -1));
+
+    let result = pipeline.evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_check_simultaneous_loading, (demands, demand, index, expected), {
+    can_check_simultaneous_loading_impl(demands, demand, index, expected);
+}}
+
+can_check_simultaneous_loading! {
+    case01_both_deliveries: (vec![-1], -1, 0, Some(2)),
+    case02_delivery_before_pickup: (vec![-1], 1, 1, None),
+    case03_pickup_before_delivery: (vec![-1], 1, 0, Some(2)),
+    case04_both_pickups: (vec![1], 1, 0, Some(2)),
+    case05_delivery_after_other_delivery: (vec![-1, 1], -1, 2, Some(2)),
+    case06_delivery_before_other_pickup: (vec![1, 0], -1, 0, None),
+}
+
+fn can_check_simultaneous_loading_impl(demands: Vec<i32>, demand: i32, index: usize, expected: Option<i32>) {
+    let pipeline = create_compatibility_pipeline();
+    let route_ctx = create_route_ctx(
+        demands
+            .into_iter()
+            .enumerate()
+            .map(|(idx, demand)| match demand {
+                0 => test_single_with_id("service"),
+                _ => create_single(format!("job{}", idx).as_str(), "chemicals", vec![], NEVER_LOADED, demand),
+            })
+            .collect(),
+    );
+    let target = test_activity_with_job(create_single("target", "food", vec!["chemicals"], NEVER_LOADED, demand));
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_check_simultaneous_loading_of_multi_job() {
+    let pipeline = create_compatibility_pipeline();
+    let mut dimens = Dimensions::default();
+    dimens.set_id("food").set_value(
+        "compatibility",
+        Compatibility { group: "food".to_string(), incompatible: vec!["chemicals".to_string()], policy: NEVER_LOADED },
+    );
+    let multi = Multi::bind(Multi::new(vec![test_single_with_id("pickup"), test_single_with_id("delivery")], dimens));
+    let chemicals = create_single("chemicals", "chemicals", vec![], NEVER_LOADED, 1);
+    let route_ctx = create_route_ctx(vec![multi.jobs[0].clone(), chemicals]);
+    let target = test_activity_with_job(multi.jobs[1].clone());
+
+    let results = (1..3)
+        .map(|index| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route.tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route.tour.get(index + 1),
+            };
+            pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx).map(|violation| violation.code)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(results, vec![None, Some(2)]);
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{IdDimension, Location};
+
+fn create_driving_rest_pipeline(max_driving_time: Duration, rests: usize) -> ConstraintPipeline {
+    create_constraint_pipeline_with_module(Box::new(DrivingRestModule::new(
+        Arc::new(move |_| Some(DrivingLimit { max_driving_time, rests })),
+        Arc::new(|single| matches!(single.dimens.get_id(), Some(id) if id == "rest")),
+        TestTransportCost::new_shared(),
+        2,
+    )))
+}
+
+fn create_activity(location: Location, is_rest: bool) -> Activity {
+    let mut activity = test_activity_with_location(location);
+    if is_rest {
+        activity.job = Some(test_single_with_id_and_location("rest", Some(location)));
+    }
+
+    activity
+}
+
+fn create_route_ctx(pipeline: &ConstraintPipeline, activities: Vec<(Location, bool)>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = activities.into_iter().map(|(location, is_rest)| create_activity(location, is_rest)).collect();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_accumulate_continuous_driving_time, (activities, expected, total, rests), {
+    let pipeline = create_driving_rest_pipeline(1000., 1);
+
+    let route_ctx = create_route_ctx(&pipeline, activities);
+
+    let result = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| {
+            *route_ctx.state.get_activity_state::<(Duration, Duration)>(CONTINUOUS_DRIVING_KEY, activity).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    assert_eq!(route_ctx.state.get_route_state::<Duration>(CONTINUOUS_DRIVING_KEY), Some(&total));
+    assert_eq!(route_ctx.state.get_route_state::<RestState>(DRIVING_REST_KEY).map(|state| state.rests), Some(rests));
+}}
+
+can_accumulate_continuous_driving_time! {
+    case01_no_rest: (vec![(10, false), (30, false)], vec![(0., 60.), (10., 50.), (30., 30.), (60., 0.)], 60., 0),
+    case02_rest_in_the_middle: (
+        vec![(10, false), (10, true), (30, false)],
+        vec![(0., 10.), (10., 0.), (0., 50.), (20., 30.), (50., 0.)],
+        60.,
+        1
+    ),
+    case03_rest_with_travel: (vec![(10, false), (20, true)], vec![(0., 20.), (10., 10.), (0., 20.), (20., 0.)], 40., 1),
+}
+
+parameterized_test! {can_check_continuous_driving_time_on_insertion, (activities, max_driving_time, rests, index, location, is_rest, expected), {
+    let pipeline = create_driving_rest_pipeline(max_driving_time, rests);
+    let route_ctx = create_route_ctx(&pipeline, activities);
+    let target = create_activity(location, is_rest);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}}
+
+can_check_continuous_driving_time_on_insertion! {
+    case01_job_fits: (vec![(10, false), (30, false)], 70., 0, 0, 5, false, None),
+    case02_job_exceeds: (vec![(10, false), (30, false)], 50., 0, 0, 5, false, Some(2)),
+    case03_rest_splits_driving: (vec![(10, false), (30, false)], 50., 0, 2, 30, true, None),
+    case04_rest_too_late: (vec![(10, false), (30, false)], 25., 0, 2, 30, true, Some(2)),
+    case05_rest_too_early: (vec![(10, false), (30, false)], 40., 0, 1, 10, true, Some(2)),
+    case06_rest_in_the_middle: (vec![(10, false), (30, false)], 40., 0, 1, 20, true, None),
+    case07_job_before_rest_fits: (vec![(10, true), (40, false)], 30., 0, 0, 5, false, None),
+    case08_job_after_rest_exceeds: (vec![(10, true), (40, false)], 30., 0, 1, 5, false, Some(2)),
+    case09_job_exceeds_with_available_rest: (vec![(10, false)], 25., 1, 1, 20, false, None),
+    case10_job_exceeds_with_used_rest: (vec![(10, true), (30, false)], 30., 1, 1, 20, false, Some(2)),
+    case11_job_exceeds_without_split: (vec![(10, false), (30, false)], 40., 1, 0, 5, false, Some(2)),
+    case12_job_exceeds_already_exceeded: (vec![(10, false), (30, false)], 40., 1, 1, 20, false, Some(2)),
+}
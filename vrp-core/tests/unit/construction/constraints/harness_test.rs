@@ -0,0 +1,86 @@
+use super::*;
+use crate::construction::heuristics::SolutionContext;
+use std::slice::Iter;
+
+const TIME_CONSTRAINT_CODE: i32 = 1;
+const DISTANCE_LIMIT_CODE: i32 = 2;
+const DURATION_LIMIT_CODE: i32 = 3;
+const CAPACITY_CONSTRAINT_CODE: i32 = 4;
+
+/// Corrupts latest arrival state of the last job activity in each route.
+struct BrokenStateModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for BrokenStateModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let (route, state) = ctx.as_mut();
+        if let Some(activity) = route.tour.all_activities().rfind(|activity| activity.job.is_some()) {
+            let latest = state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, activity).cloned().unwrap_or(0.);
+            state.put_activity_state(LATEST_ARRIVAL_KEY, activity, latest - 1.);
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<'_, i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<'_, ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+fn create_module_factory(is_broken: bool) -> HarnessModuleFactory {
+    Arc::new(move |activity, transport| {
+        let mut modules: Vec<Box<dyn ConstraintModule + Send + Sync>> = vec![
+            Box::new(TransportConstraintModule::new(
+                activity,
+                transport,
+                Arc::new(|_| (None, None)),
+                TIME_CONSTRAINT_CODE,
+                DISTANCE_LIMIT_CODE,
+                DURATION_LIMIT_CODE,
+            )),
+            Box::new(CapacityConstraintModule::<SingleDimLoad>::new(CAPACITY_CONSTRAINT_CODE)),
+        ];
+
+        if is_broken {
+            modules.push(Box::new(BrokenStateModule { constraints: vec![], keys: vec![] }));
+        }
+
+        modules
+    })
+}
+
+fn create_harness(is_broken: bool) -> ConstraintHarness {
+    let settings = HarnessSettings { problems: 5, jobs: 15, vehicles: 2, locations: 10, capacity: 10, seed: 42 };
+
+    ConstraintHarness::new(settings, create_module_factory(is_broken))
+        .with_checker(create_schedule_checker())
+        .with_checker(create_latest_arrival_checker())
+        .with_checker(create_capacity_checker::<SingleDimLoad>())
+}
+
+#[test]
+fn can_validate_transport_and_capacity_modules() {
+    let result = create_harness(false).run();
+
+    assert!(result.is_ok(), "{:?}", result);
+    assert!(result.unwrap() > 0);
+}
+
+#[test]
+fn can_detect_broken_route_state() {
+    let result = create_harness(true).run();
+
+    let err = result.expect_err("broken state should be detected");
+    assert!(err.contains("latest arrival"), "{}", err);
+    assert!(err.starts_with("problem with seed 42"), "{}", err);
+}
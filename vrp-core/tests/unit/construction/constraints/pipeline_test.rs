@@ -111,3 +111,169 @@ fn can_estimate_hard_activity_constraints() {
 
     assert_eq!(result, 12.0);
 }
+
+parameterized_test! {can_relax_hard_activity_constraints, (codes, expected), {
+    can_relax_hard_activity_constraints_impl(codes, expected);
+}}
+
+can_relax_hard_activity_constraints! {
+    case01_no_relaxation: (vec![], Some(5)),
+    case02_relax_first: (vec![5], Some(6)),
+    case03_relax_all: (vec![5, 6], None),
+    case04_relax_unknown: (vec![7], Some(5)),
+}
+
+fn can_relax_hard_activity_constraints_impl(codes: Vec<i32>, expected: Option<i32>) {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1, 2],
+        constraints: vec![
+            ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+                violation: Some(ActivityConstraintViolation { code: 5, stopped: true }),
+            })),
+            ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+                violation: Some(ActivityConstraintViolation { code: 6, stopped: false }),
+            })),
+        ],
+    }));
+    let pipeline = ConstraintPipeline::new_relaxed(Arc::new(pipeline), codes.as_slice());
+
+    let result = pipeline.evaluate_hard_activity(
+        &RouteContext::new(test_actor()),
+        &ActivityContext {
+            index: 0,
+            prev: &test_activity_without_job(),
+            target: &test_activity_without_job(),
+            next: None,
+        },
+    );
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_penalize_hard_activity_constraints, (penalties, expected), {
+    can_penalize_hard_activity_constraints_impl(penalties, expected);
+}}
+
+can_penalize_hard_activity_constraints! {
+    case01_penalize_first: (vec![(5, 10.)], (Some(6), 10.)),
+    case02_penalize_all: (vec![(5, 10.), (6, 20.)], (None, 30.)),
+    case03_penalize_unknown: (vec![(7, 10.)], (Some(5), 0.)),
+}
+
+fn can_penalize_hard_activity_constraints_impl(penalties: Vec<(i32, Cost)>, expected: (Option<i32>, Cost)) {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1, 2],
+        constraints: vec![
+            ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+                violation: Some(ActivityConstraintViolation { code: 5, stopped: true }),
+            })),
+            ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+                violation: Some(ActivityConstraintViolation { code: 6, stopped: false }),
+            })),
+        ],
+    }));
+    let pipeline = ConstraintPipeline::new_penalized(Arc::new(pipeline), penalties.as_slice());
+    let route_ctx = RouteContext::new(test_actor());
+    let (prev, target) = (test_activity_without_job(), test_activity_without_job());
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let violation = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+    let penalty = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!((violation.map(|violation| violation.code), penalty), expected);
+}
+
+#[test]
+fn can_change_penalties_of_weighted_pipeline_at_runtime() {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1],
+        constraints: vec![ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+            violation: Some(ActivityConstraintViolation { code: 5, stopped: true }),
+        }))],
+    }));
+    let weights = Arc::new(ConstraintWeights::new(&[(5, 0.)]));
+    let pipeline = ConstraintPipeline::new_weighted(Arc::new(pipeline), weights.clone());
+    let route_ctx = RouteContext::new(test_actor());
+    let (prev, target) = (test_activity_without_job(), test_activity_without_job());
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    assert!(pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx).is_none());
+    assert_eq!(pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx), 0.);
+
+    weights.set(5, 10.).unwrap();
+    assert_eq!(pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx), 10.);
+}
+
+parameterized_test! {can_evaluate_hard_activity_constraints_in_priority_order, (priorities, expected), {
+    can_evaluate_hard_activity_constraints_in_priority_order_impl(priorities, expected);
+}}
+
+can_evaluate_hard_activity_constraints_in_priority_order! {
+    case01_same_priority: ((0, 0), 5),
+    case02_first_later: ((1, 0), 6),
+    case03_second_earlier: ((0, -1), 6),
+    case04_same_non_default: ((1, 1), 5),
+}
+
+fn can_evaluate_hard_activity_constraints_in_priority_order_impl(priorities: (i32, i32), expected: i32) {
+    let mut pipeline = ConstraintPipeline::default();
+    vec![(vec![1], 5, priorities.0), (vec![2], 6, priorities.1)].into_iter().for_each(
+        |(state_keys, code, priority)| {
+            pipeline.add_module_with_priority(
+                Box::new(TestConstraintModule {
+                    state_keys,
+                    constraints: vec![ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint {
+                        violation: Some(ActivityConstraintViolation { code, stopped: false }),
+                    }))],
+                }),
+                priority,
+            );
+        },
+    );
+    let route_ctx = RouteContext::new(test_actor());
+    let (prev, target) = (test_activity_without_job(), test_activity_without_job());
+    let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: None };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), Some(expected));
+}
+
+#[test]
+fn can_get_module_infos() {
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1, 2],
+        constraints: vec![
+            ConstraintVariant::HardActivity(Arc::new(TestHardActivityConstraint { violation: None })),
+            ConstraintVariant::SoftActivity(Arc::new(TestSoftActivityConstraint { cost: 5.0 })),
+        ],
+    }));
+    pipeline.add_module_with_priority(
+        Box::new(TestConstraintModule {
+            state_keys: vec![3],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(TestSoftActivityConstraint { cost: 7.0 }))],
+        }),
+        2,
+    );
+
+    let infos = pipeline.get_module_infos();
+
+    assert_eq!(infos.len(), 2);
+    assert_eq!(infos[0].priority, 0);
+    assert_eq!(infos[0].state_keys, vec![1, 2]);
+    assert!(infos[0].violation_codes.is_empty());
+    assert_eq!(
+        (infos[0].hard_route, infos[0].hard_activity, infos[0].soft_route, infos[0].soft_activity),
+        (0, 1, 0, 1)
+    );
+    assert_eq!(infos[1].priority, 2);
+    assert_eq!(infos[1].state_keys, vec![3]);
+    assert_eq!(
+        (infos[1].hard_route, infos[1].hard_activity, infos[1].soft_route, infos[1].soft_activity),
+        (0, 0, 0, 1)
+    );
+}
@@ -0,0 +1,96 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+
+fn create_precedence_pipeline() -> ConstraintPipeline {
+    create_constraint_pipeline_with_module(Box::new(PrecedenceConstraintModule::new(
+        Arc::new(|multi| match multi.dimens.get_id().map(|id| id.as_str()) {
+            Some("lifo") => Some(LoadingPolicy::Lifo),
+            Some("fifo") => Some(LoadingPolicy::Fifo),
+            _ => None,
+        }),
+        2,
+    )))
+}
+
+fn create_multi_job(id: &str) -> Arc<Multi> {
+    let job = MultiBuilder::default()
+        .id(id)
+        .job(SingleBuilder::default().id("pickup").build())
+        .job(SingleBuilder::default().id("delivery").build())
+        .build();
+
+    job.as_multi().unwrap().clone()
+}
+
+parameterized_test! {can_check_loading_policy, (outer_id, inner_id, sequence, index, expected), {
+    can_check_loading_policy_impl(outer_id, inner_id, sequence, index, expected);
+}}
+
+can_check_loading_policy! {
+    case01_lifo_nested: ("lifo", "none", vec![(0, 0), (1, 0), (0, 1)], 2, None),
+    case02_lifo_crossed: ("lifo", "none", vec![(0, 0), (1, 0), (0, 1)], 3, Some(2)),
+    case03_fifo_nested: ("fifo", "none", vec![(0, 0), (1, 0), (0, 1)], 2, Some(2)),
+    case04_fifo_crossed: ("fifo", "none", vec![(0, 0), (1, 0), (0, 1)], 3, None),
+    case05_no_policy: ("none", "none", vec![(0, 0), (1, 0), (0, 1)], 3, None),
+    case06_inner_lifo_crossed: ("none", "lifo", vec![(0, 0), (1, 0), (0, 1)], 3, None),
+    case07_target_lifo_crossed: ("none", "lifo", vec![(1, 0), (0, 0), (0, 1)], 2, Some(2)),
+    case08_target_lifo_nested: ("none", "lifo", vec![(1, 0), (0, 0), (0, 1)], 3, None),
+    case09_no_overlap: ("lifo", "none", vec![(0, 0), (0, 1), (1, 0)], 3, None),
+}
+
+/// Checks insertion of the inner job's delivery into the tour defined by sequence of
+/// (job index, activity index) pairs, where job index is 0 for outer and 1 for inner job.
+fn can_check_loading_policy_impl(
+    outer_id: &str,
+    inner_id: &str,
+    sequence: Vec<(usize, usize)>,
+    index: usize,
+    expected: Option<i32>,
+) {
+    let pipeline = create_precedence_pipeline();
+    let jobs = [create_multi_job(outer_id), create_multi_job(inner_id)];
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = sequence
+        .into_iter()
+        .map(|(job_idx, activity_idx)| test_activity_with_job(jobs[job_idx].jobs[activity_idx].clone()))
+        .collect();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let target = test_activity_with_job(jobs[1].jobs[1].clone());
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_skip_check_when_job_is_not_fully_inserted() {
+    let pipeline = create_precedence_pipeline();
+    let outer = create_multi_job("lifo");
+    let inner = create_multi_job("none");
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_job(outer.jobs[0].clone()), test_activity_with_job(outer.jobs[1].clone())],
+    );
+    let target = test_activity_with_job(inner.jobs[0].clone());
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert!(result.is_none());
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::ValueDimension;
+use crate::models::problem::Fleet;
+
+fn create_fleet(radius: f64) -> Fleet {
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_value("radius", radius);
+
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(vehicle)
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build()
+}
+
+fn create_radius_constraint_pipeline() -> ConstraintPipeline {
+    create_constraint_pipeline_with_module(Box::new(RadiusModule::new(
+        Arc::new(|actor| actor.vehicle.dimens.get_value::<f64>("radius").cloned()),
+        Arc::new(|_, from, to| (to as f64 - from as f64).abs()),
+        2,
+    )))
+}
+
+parameterized_test! {can_check_single_job, (vehicle_id, job_locations, activity_location, expected), {
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&create_fleet(10.), vehicle_id, vec![]);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &test_activity_without_job(),
+        target: &test_activity_with_location(activity_location),
+        next: None,
+    };
+    let pipeline = create_radius_constraint_pipeline();
+
+    let route_result = pipeline.evaluate_hard_route(
+        &solution_ctx,
+        &route_ctx,
+        &SingleBuilder::default()
+            .places(job_locations.into_iter().map(|l| (l, 10., vec![(0., 100.)])).collect())
+            .build_as_job_ref(),
+    );
+    let activity_result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!((route_result.map(|_| ()), activity_result.map(|_| ())), expected);
+}}
+
+can_check_single_job! {
+    case01_inside: ("v1", vec![Some(5)], 5, (None, None)),
+    case02_on_border: ("v1", vec![Some(10)], 10, (None, None)),
+    case03_outside: ("v1", vec![Some(15)], 15, (Some(()), Some(()))),
+    case04_one_of_places_inside: ("v1", vec![Some(15), Some(5)], 15, (None, Some(()))),
+    case05_no_limit: ("v2", vec![Some(15)], 15, (None, None)),
+    case06_no_location: ("v1", vec![None], 5, (None, None)),
+    case07_one_of_places_without_location: ("v1", vec![Some(15), None], 5, (None, None)),
+}
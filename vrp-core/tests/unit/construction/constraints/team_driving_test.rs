@@ -0,0 +1,66 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_team_driving_pipeline(drivers: usize, max_driving_time: Duration) -> ConstraintPipeline {
+    create_constraint_pipeline_with_module(Box::new(TeamDrivingModule::new(
+        Arc::new(move |_| Some(DrivingTeam { drivers, max_driving_time })),
+        TestTransportCost::new_shared(),
+        2,
+    )))
+}
+
+fn create_route_ctx(pipeline: &ConstraintPipeline, locations: Vec<usize>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = locations.into_iter().map(test_activity_with_location).collect();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_accumulate_driving_time_per_driver, (drivers, locations, expected), {
+    let pipeline = create_team_driving_pipeline(drivers, 1000.);
+
+    let route_ctx = create_route_ctx(&pipeline, locations);
+
+    assert_eq!(route_ctx.state.get_route_state::<Vec<Duration>>(DRIVING_TIME_KEY), Some(&expected));
+}}
+
+can_accumulate_driving_time_per_driver! {
+    case01_single_driver: (1, vec![10, 30], vec![60.]),
+    case02_two_drivers: (2, vec![10, 30], vec![40., 20.]),
+    case03_three_drivers: (3, vec![10, 30], vec![10., 20., 30.]),
+    case04_two_drivers_more_legs: (2, vec![10, 30, 40], vec![20., 60.]),
+    case05_two_drivers_same_location: (2, vec![10, 10, 30], vec![40., 20.]),
+    case06_three_drivers_same_location: (3, vec![10, 30, 30, 30], vec![10., 20., 30.]),
+}
+
+parameterized_test! {can_check_driving_time_on_insertion, (drivers, max_driving_time, index, location, expected), {
+    let pipeline = create_team_driving_pipeline(drivers, max_driving_time);
+    let route_ctx = create_route_ctx(&pipeline, vec![10]);
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}}
+
+can_check_driving_time_on_insertion! {
+    case01_single_driver_fits: (1, 25., 0, 5, None),
+    case02_single_driver_exceeds: (1, 25., 1, 20, Some(2)),
+    case03_two_drivers_fit: (2, 25., 0, 5, None),
+    case04_two_drivers_one_exceeds: (2, 25., 1, 20, Some(2)),
+    case05_two_drivers_fit_with_rest: (2, 30., 1, 20, None),
+    case06_two_drivers_same_location_after: (2, 10., 1, 10, None),
+    case07_two_drivers_same_location_before: (2, 10., 0, 10, None),
+    case08_two_drivers_same_location_exceeds: (2, 9., 0, 10, Some(2)),
+}
@@ -1,15 +1,18 @@
 mod timing {
     use crate::construction::constraints::*;
     use crate::construction::heuristics::*;
-    use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+    use crate::helpers::construction::constraints::{
+        create_constraint_pipeline_with_module, create_constraint_pipeline_with_transport,
+    };
     use crate::helpers::models::domain::{create_empty_solution_context, test_random};
     use crate::helpers::models::problem::*;
     use crate::helpers::models::solution::*;
-    use crate::models::common::{Location, Schedule, TimeInterval, TimeWindow, Timestamp};
-    use crate::models::problem::{VehicleDetail, VehiclePlace};
+    use crate::models::common::{Duration, Location, Schedule, TimeInterval, TimeWindow, Timestamp};
+    use crate::models::problem::{ActivityCost, Actor, VehicleDetail, VehiclePlace};
     use crate::models::solution::{Activity, Place, Registry};
     use crate::utils::compare_floats;
     use std::cmp::Ordering;
+    use std::sync::Arc;
 
     fn create_detail(
         locations: (Option<Location>, Option<Location>),
@@ -26,6 +29,7 @@ mod timing {
                 location,
                 time: time.map_or(Default::default(), |(_, end)| TimeInterval { earliest: None, latest: Some(end) }),
             }),
+            driver: None,
         }
     }
 
@@ -83,6 +87,33 @@ mod timing {
         assert_eq!(result, time);
     }
 
+    #[test]
+    fn can_calculate_latest_arrival_for_open_tour() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), None), Some((0., 100.)))])
+                .build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![test_activity_with_location(10), test_activity_with_location(20), test_activity_with_location(30)],
+        );
+
+        create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+
+        let tour = &route_ctx.route.tour;
+        assert!(tour.end().unwrap().job.is_some());
+        let result = (1..4)
+            .map(|idx| {
+                *route_ctx.state.get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, tour.get(idx).unwrap()).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![980., 990., 1000.]);
+    }
+
     parameterized_test! {can_detect_activity_constraint_violation, (vehicle_detail_data, location, prev_index, next_index, expected), {
         can_detect_activity_constraint_violation_impl(vehicle_detail_data, location, prev_index, next_index, expected);
     }}
@@ -120,13 +151,12 @@ mod timing {
         assert_eq!(result, expected);
     }
 
-    #[test]
-    fn can_update_activity_schedule() {
+    fn create_solution_context_for_schedule_update() -> SolutionContext {
         let fleet = FleetBuilder::default()
             .add_driver(test_driver())
             .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
             .build();
-        let mut solution_ctx = SolutionContext {
+        SolutionContext {
             routes: vec![create_route_context_with_activities(
                 &fleet,
                 "v1",
@@ -141,15 +171,140 @@ mod timing {
             )],
             registry: RegistryContext::new(Registry::new(&fleet, test_random())),
             ..create_empty_solution_context()
-        };
+        }
+    }
+
+    #[test]
+    fn can_update_activity_schedule() {
+        let mut solution_ctx = create_solution_context_for_schedule_update();
 
         create_constraint_pipeline_with_transport().accept_solution_state(&mut solution_ctx);
 
         let route_ctx = solution_ctx.routes.first().unwrap();
+        assert_eq!(route_ctx.route.tour.get(0).unwrap().schedule.departure, 10.0);
         assert_eq!(route_ctx.route.tour.get(1).unwrap().schedule, Schedule { arrival: 20.0, departure: 25.0 });
         assert_eq!(route_ctx.route.tour.get(2).unwrap().schedule, Schedule { arrival: 35.0, departure: 60.0 });
     }
 
+    #[test]
+    fn can_keep_earliest_departure_without_rescheduling() {
+        let mut solution_ctx = create_solution_context_for_schedule_update();
+        let pipeline = create_constraint_pipeline_with_module(Box::new(
+            TransportConstraintModule::new(
+                Arc::new(TestActivityCost::default()),
+                TestTransportCost::new_shared(),
+                Arc::new(|_| (None, None)),
+                1,
+                2,
+                3,
+            )
+            .with_departure_rescheduling(false),
+        ));
+
+        pipeline.accept_solution_state(&mut solution_ctx);
+
+        let route_ctx = solution_ctx.routes.first().unwrap();
+        assert_eq!(route_ctx.route.tour.get(0).unwrap().schedule.departure, 0.0);
+        assert_eq!(route_ctx.route.tour.get(1).unwrap().schedule, Schedule { arrival: 10.0, departure: 25.0 });
+        assert_eq!(route_ctx.route.tour.get(2).unwrap().schedule, Schedule { arrival: 35.0, departure: 60.0 });
+    }
+
+    struct ParallelActivityCost {}
+
+    impl ActivityCost for ParallelActivityCost {
+        fn duration_after(
+            &self,
+            _: &Actor,
+            previous: &mut dyn Iterator<Item = &Activity>,
+            activity: &Activity,
+            _: Timestamp,
+        ) -> Duration {
+            match previous.next() {
+                Some(prev) if prev.place.location == activity.place.location => activity.place.duration / 2.,
+                _ => activity.place.duration,
+            }
+        }
+    }
+
+    fn create_parallel_pipeline_and_route(end_time: Timestamp) -> (ConstraintPipeline, RouteContext) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((0., end_time)))])
+                .build()])
+            .build();
+        let create_activity = |location| {
+            ActivityBuilder::default()
+                .place(Place { location, duration: 10.0, time: TimeWindow { start: 0.0, end: 1000.0 } })
+                .build()
+        };
+        let route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity(10), create_activity(10), create_activity(20)],
+        );
+        let pipeline = create_constraint_pipeline_with_module(Box::new(TransportConstraintModule::new(
+            Arc::new(ParallelActivityCost {}),
+            TestTransportCost::new_shared(),
+            Arc::new(|_| (None, None)),
+            1,
+            2,
+            3,
+        )));
+
+        (pipeline, route_ctx)
+    }
+
+    #[test]
+    fn can_update_schedule_using_previous_activity() {
+        let (pipeline, mut route_ctx) = create_parallel_pipeline_and_route(100.);
+
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let schedules = route_ctx.route.tour.all_activities().map(|a| a.schedule.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            schedules,
+            vec![
+                Schedule { arrival: 0., departure: 0. },
+                Schedule { arrival: 10., departure: 20. },
+                Schedule { arrival: 20., departure: 25. },
+                Schedule { arrival: 35., departure: 45. },
+                Schedule { arrival: 65., departure: 65. },
+            ]
+        );
+    }
+
+    parameterized_test! {can_consider_next_activity_duration_change, (end_time, expected), {
+        can_consider_next_activity_duration_change_impl(end_time, expected);
+    }}
+
+    can_consider_next_activity_duration_change! {
+        case01_enough_time: (90., None),
+        case02_not_enough_time: (85., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    }
+
+    fn can_consider_next_activity_duration_change_impl(
+        end_time: Timestamp,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        let (pipeline, mut route_ctx) = create_parallel_pipeline_and_route(end_time);
+        pipeline.accept_route_state(&mut route_ctx);
+        let target = ActivityBuilder::default()
+            .place(Place { location: 15, duration: 10.0, time: TimeWindow { start: 0.0, end: 1000.0 } })
+            .build();
+        let activity_ctx = ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn can_calculate_soft_activity_cost_for_empty_tour() {
         let fleet = FleetBuilder::default()
@@ -227,13 +382,16 @@ mod timing {
 }
 
 mod traveling {
-    use super::super::stop;
+    use super::super::{stop, OvertimeSoftActivityConstraint};
     use crate::construction::constraints::*;
     use crate::construction::heuristics::{ActivityContext, RouteContext, RouteState};
     use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
     use crate::helpers::models::problem::*;
     use crate::helpers::models::solution::*;
-    use crate::models::common::{Distance, Duration, Location, TimeWindow};
+    use crate::models::common::{
+        Cost, Distance, Duration, Location, ShiftOvertime, ShiftOvertimeDimension, TimeWindow,
+    };
+    use crate::models::problem::{create_toll_transport_cost, TollFunc, TransportCost};
     use std::sync::Arc;
 
     fn create_test_data(
@@ -322,4 +480,88 @@ mod traveling {
 
         assert_eq!(result, stop(3));
     }
+
+    fn create_route_ctx_with_overtime(overtime: Option<ShiftOvertime>) -> RouteContext {
+        let mut vehicle = test_vehicle_with_id("v1");
+        if let Some(overtime) = overtime {
+            vehicle.dimens.set_shift_overtime(overtime);
+        }
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+        let mut state = RouteState::default();
+        state.put_route_state(TOTAL_DISTANCE_KEY, 50.);
+        state.put_route_state(TOTAL_DURATION_KEY, 50.);
+
+        RouteContext::new_with_state(Arc::new(create_route_with_activities(&fleet, "v1", vec![])), Arc::new(state))
+    }
+
+    parameterized_test! {can_estimate_overtime_penalty, (shift_time, expected), {
+        can_estimate_overtime_penalty_impl(shift_time, expected);
+    }}
+
+    can_estimate_overtime_penalty! {
+        case01_no_overtime: (None, 0.),
+        case02_below_threshold: (Some(100.), 0.),
+        case03_crosses_threshold: (Some(60.), 20.),
+        case04_above_threshold: (Some(40.), 40.),
+    }
+
+    fn can_estimate_overtime_penalty_impl(shift_time: Option<Duration>, expected: Cost) {
+        let route_ctx =
+            create_route_ctx_with_overtime(shift_time.map(|shift_time| ShiftOvertime { shift_time, penalty: 2. }));
+        let constraint = OvertimeSoftActivityConstraint { transport: TestTransportCost::new_shared() };
+
+        let result = constraint.estimate_activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &test_activity_with_location(50),
+                target: &test_activity_with_location(60),
+                next: Some(&test_activity_with_location(50)),
+            },
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_add_overtime_penalty_to_route_cost() {
+        let route_ctx = create_route_ctx_with_overtime(None);
+        let cost_without_overtime = route_ctx.get_route_cost();
+        let route_ctx = create_route_ctx_with_overtime(Some(ShiftOvertime { shift_time: 40., penalty: 2. }));
+
+        assert_eq!(route_ctx.get_route_cost() - cost_without_overtime, 20.);
+    }
+
+    #[test]
+    fn can_add_tolls_to_route_cost() {
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let create_route_ctx = || {
+            create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![test_activity_with_location(10), test_activity_with_location(20)],
+            )
+        };
+        let create_pipeline = |transport: Arc<dyn TransportCost + Send + Sync>| {
+            create_constraint_pipeline_with_module(Box::new(TransportConstraintModule::new(
+                Arc::new(TestActivityCost::default()),
+                transport,
+                Arc::new(|_| (None, None)),
+                1,
+                2,
+                3,
+            )))
+        };
+        let toll_func: TollFunc = Arc::new(|_, from, to| if from == 10 && to == 20 { 7. } else { 0. });
+        let mut route_ctx = create_route_ctx();
+        create_pipeline(TestTransportCost::new_shared()).accept_route_state(&mut route_ctx);
+        let cost_without_tolls = route_ctx.get_route_cost();
+        let mut route_ctx = create_route_ctx();
+
+        create_pipeline(create_toll_transport_cost(TestTransportCost::new_shared(), toll_func))
+            .accept_route_state(&mut route_ctx);
+
+        assert_eq!(route_ctx.state.get_route_state::<Cost>(TOTAL_TOLL_KEY).cloned(), Some(7.));
+        assert_eq!(route_ctx.get_route_cost() - cost_without_tolls, 7.);
+    }
 }
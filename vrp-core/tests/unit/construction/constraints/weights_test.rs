@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn can_get_and_set_weights() {
+    let weights = ConstraintWeights::new(&[(2, 20.), (1, 10.)]);
+
+    weights.set(1, 15.).unwrap();
+
+    assert_eq!(weights.get(1), Some(15.));
+    assert_eq!(weights.get(3), None);
+    assert_eq!(weights.all(), vec![(1, 15.), (2, 20.)]);
+}
+
+#[test]
+fn can_reject_unknown_code() {
+    let weights = ConstraintWeights::new(&[(1, 10.)]);
+
+    let result = weights.set(2, 15.);
+
+    assert_eq!(result, Err("unknown constraint code: 2".to_string()));
+    assert_eq!(weights.all(), vec![(1, 10.)]);
+}
@@ -0,0 +1,153 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn create_route_ctx() -> RouteContext {
+    create_route_context_with_activities(&test_fleet(), "v1", vec![test_activity_with_location(10)])
+}
+
+fn evaluate_with_counter(
+    cache: &InsertionCache,
+    job: &Job,
+    route_ctx: &RouteContext,
+    best_known_cost: Option<Cost>,
+    counter: &AtomicUsize,
+) -> InsertionResult {
+    cache.evaluate(job, route_ctx, InsertionPosition::Any, 5., best_known_cost, || {
+        counter.fetch_add(1, Ordering::Relaxed);
+        InsertionResult::make_success(10., job.clone(), vec![(test_activity_with_location(5), 0)], route_ctx.clone())
+    })
+}
+
+fn get_cost(result: &InsertionResult) -> Option<Cost> {
+    match result {
+        InsertionResult::Success(success) => Some(success.cost),
+        InsertionResult::Failure(_) => None,
+    }
+}
+
+#[test]
+fn can_reuse_evaluation_for_same_route_version() {
+    let cache = InsertionCache::new(100, 1);
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let route_ctx = create_route_ctx();
+
+    let first = evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+    let second = evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+    let third = evaluate_with_counter(&cache, &job, &route_ctx.deep_copy(), None, &counter);
+
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    assert_eq!(cache.size(), 1);
+    assert_eq!(get_cost(&first), Some(15.));
+    assert_eq!(get_cost(&second), Some(15.));
+    assert_eq!(get_cost(&third), Some(15.));
+}
+
+#[test]
+fn can_evaluate_again_when_route_is_changed() {
+    let cache = InsertionCache::new(100, 1);
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let mut route_ctx = create_route_ctx();
+
+    evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+    let version = route_ctx.version();
+    route_ctx.route_mut().tour.insert_last(test_activity_with_location(20));
+    evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+
+    assert_ne!(route_ctx.version(), version);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
+parameterized_test! {can_apply_best_known_cost, (best_known_cost, expected), {
+    let cache = InsertionCache::new(100, 1);
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let route_ctx = create_route_ctx();
+    evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+
+    let result = evaluate_with_counter(&cache, &job, &route_ctx, best_known_cost, &counter);
+
+    assert_eq!(get_cost(&result), expected);
+}}
+
+can_apply_best_known_cost! {
+    case01_no_best_known: (None, Some(15.)),
+    case02_worse_than_best_known: (Some(8.), None),
+    case03_better_than_best_known: (Some(12.), Some(15.)),
+}
+
+#[test]
+fn can_invalidate_route_results() {
+    let cache = InsertionCache::new(100, 1);
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let route_ctx = create_route_ctx();
+    evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+
+    cache.invalidate(&route_ctx);
+    evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn can_keep_results_within_capacity() {
+    let cache = InsertionCache::new(2, 1);
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let routes = (0..3).map(|_| create_route_ctx()).collect::<Vec<_>>();
+
+    routes.iter().for_each(|route_ctx| {
+        evaluate_with_counter(&cache, &job, route_ctx, None, &counter);
+    });
+    evaluate_with_counter(&cache, &job, routes.first().unwrap(), None, &counter);
+
+    assert_eq!(cache.size(), 2);
+    assert_eq!(counter.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn can_keep_results_in_shards() {
+    let cache = InsertionCache::new(100, 4);
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let routes = (0..8).map(|_| create_route_ctx()).collect::<Vec<_>>();
+
+    routes.iter().chain(routes.iter()).for_each(|route_ctx| {
+        evaluate_with_counter(&cache, &job, route_ctx, None, &counter);
+    });
+    cache.invalidate(routes.first().unwrap());
+
+    assert_eq!(counter.load(Ordering::Relaxed), 8);
+    assert_eq!(cache.size(), 7);
+}
+
+#[test]
+fn can_change_capacity() {
+    let cache = InsertionCache::default();
+    let counter = AtomicUsize::new(0);
+    let job = Job::Single(Arc::new(test_single()));
+    let route_ctx = create_route_ctx();
+
+    cache.set_capacity(100);
+    evaluate_with_counter(&cache, &job, &route_ctx, None, &counter);
+    assert!(cache.is_enabled());
+    assert_eq!(cache.size(), 1);
+
+    cache.set_capacity(0);
+    assert!(!cache.is_enabled());
+    assert_eq!(cache.size(), 0);
+}
+
+#[test]
+fn can_disable_cache_by_default() {
+    let constraint = ConstraintPipeline::default();
+
+    assert!(!InsertionCache::default().is_enabled());
+    assert!(!constraint.insertion_cache().is_enabled());
+}
@@ -1,5 +1,7 @@
+use crate::construction::constraints::TOTAL_DISTANCE_KEY;
 use crate::construction::heuristics::RouteState;
-use crate::helpers::models::solution::test_activity;
+use crate::helpers::models::problem::test_fleet;
+use crate::helpers::models::solution::*;
 
 #[test]
 fn can_put_and_get_activity_state() {
@@ -77,3 +79,43 @@ fn can_remove_activity_states() {
     assert!(result1.is_none());
     assert!(result2.is_none());
 }
+
+#[test]
+fn can_rollback_route_context_to_snapshot() {
+    let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", vec![test_activity_with_location(1)]);
+    let (route, state) = route_ctx.as_mut();
+    state.put_activity_state(1, route.tour.get(1).unwrap(), "original".to_string());
+    state.put_route_state(2, "original".to_string());
+    let shared_ctx = route_ctx.clone();
+    let version = route_ctx.version();
+
+    let snapshot = route_ctx.snapshot();
+    let (route, state) = route_ctx.as_mut();
+    route.tour.insert_at(test_activity_with_location(2), 1);
+    state.put_activity_state(1, route.tour.get(1).unwrap(), "changed".to_string());
+    state.put_route_state(2, "changed".to_string());
+    route_ctx.rollback(snapshot);
+
+    assert!(route_ctx == shared_ctx);
+    assert_eq!(route_ctx.version(), version);
+    assert_eq!(shared_ctx.route.tour.activity_count(), 1);
+    let activity = shared_ctx.route.tour.get(1).unwrap();
+    assert_eq!(activity.place.location, 1);
+    assert_eq!(shared_ctx.state.get_activity_state::<String>(1, activity).unwrap(), "original");
+    assert_eq!(shared_ctx.state.get_route_state::<String>(2).unwrap(), "original");
+}
+
+#[test]
+fn can_recalculate_cached_route_cost_only_after_change() {
+    let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", vec![test_activity_with_location(1)]);
+    route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, 10.);
+    let shared_ctx = route_ctx.clone();
+
+    let original_cost = shared_ctx.get_route_cost();
+    let copied_ctx = route_ctx.deep_copy();
+    route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, 20.);
+
+    assert_eq!(copied_ctx.get_route_cost(), original_cost);
+    assert!(shared_ctx.get_route_cost() > original_cost);
+    assert_eq!(route_ctx.get_route_cost(), shared_ctx.get_route_cost());
+}
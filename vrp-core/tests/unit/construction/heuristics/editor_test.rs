@@ -0,0 +1,173 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_id, get_customer_ids_from_routes_sorted};
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::{Problem, Solution};
+use crate::utils::Environment;
+
+fn create_insertion_ctx(problem: Problem, solution: Solution) -> InsertionContext {
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+fn get_job(insertion_ctx: &InsertionContext, id: &str) -> Job {
+    insertion_ctx.problem.jobs.all().find(|job| get_customer_id(job) == id).unwrap()
+}
+
+fn get_actor(insertion_ctx: &InsertionContext, job_id: &str) -> Arc<Actor> {
+    let job = get_job(insertion_ctx, job_id);
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route.tour.contains(&job))
+        .unwrap()
+        .route
+        .actor
+        .clone()
+}
+
+fn to_ids(ids: Vec<Vec<&str>>) -> Vec<Vec<String>> {
+    ids.into_iter().map(|ids| ids.into_iter().map(|id| id.to_string()).collect()).collect()
+}
+
+parameterized_test! {can_apply_edits, (edits, expected), {
+    can_apply_edits_impl(edits, expected);
+}}
+
+can_apply_edits! {
+    case01_move_to_another_route: (vec![("c0", Some(("c4", 0)))], vec![vec!["c0", "c4", "c5", "c6", "c7"], vec!["c1", "c2", "c3"]]),
+    case02_move_within_route: (vec![("c0", Some(("c1", 3)))], vec![vec!["c1", "c2", "c3", "c0"], vec!["c4", "c5", "c6", "c7"]]),
+    case03_remove: (vec![("c5", None)], vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c6", "c7"]]),
+    case04_remove_and_move: (vec![("c5", None), ("c1", Some(("c6", 2)))], vec![vec!["c0", "c2", "c3"], vec!["c4", "c6", "c1", "c7"]]),
+}
+
+fn can_apply_edits_impl(edits: Vec<(&str, Option<(&str, usize)>)>, expected: Vec<Vec<&str>>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 2, false);
+    let mut insertion_ctx = create_insertion_ctx(problem, solution);
+    let edits = edits
+        .into_iter()
+        .map(|(job_id, target)| {
+            let job = get_job(&insertion_ctx, job_id);
+            match target {
+                Some((route_job_id, position)) => {
+                    SolutionEdit::MoveJob { job, actor: get_actor(&insertion_ctx, route_job_id), position }
+                }
+                None => SolutionEdit::RemoveJob { job },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    apply_solution_edits(&mut insertion_ctx, edits.as_slice()).expect("cannot apply edits");
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), to_ids(expected));
+}
+
+#[test]
+fn can_return_error_for_invalid_edits() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let mut insertion_ctx = create_insertion_ctx(problem, solution);
+    let job = get_job(&insertion_ctx, "c0");
+    let actor = get_actor(&insertion_ctx, "c2");
+
+    let position_result =
+        apply_solution_edits(&mut insertion_ctx, &[SolutionEdit::MoveJob { job: job.clone(), actor, position: 4 }]);
+    let remove_result = apply_solution_edits(
+        &mut insertion_ctx,
+        &[SolutionEdit::RemoveJob { job: job.clone() }, SolutionEdit::RemoveJob { job }],
+    );
+
+    assert!(position_result.is_err());
+    assert!(remove_result.is_err());
+}
+
+parameterized_test! {can_keep_solution_on_invalid_move, (use_unknown_actor, position), {
+    can_keep_solution_on_invalid_move_impl(use_unknown_actor, position);
+}}
+
+can_keep_solution_on_invalid_move! {
+    case01_invalid_position: (false, 3),
+    case02_unknown_actor: (true, 0),
+}
+
+fn can_keep_solution_on_invalid_move_impl(use_unknown_actor: bool, position: usize) {
+    let create_insertion_ctx = || {
+        let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+        create_insertion_ctx(problem, solution)
+    };
+    let mut insertion_ctx = create_insertion_ctx();
+    let job = get_job(&insertion_ctx, "c0");
+    let actor =
+        if use_unknown_actor { get_actor(&create_insertion_ctx(), "c2") } else { get_actor(&insertion_ctx, "c2") };
+
+    let result =
+        apply_solution_edits(&mut insertion_ctx, &[SolutionEdit::MoveJob { job: job.clone(), actor, position }]);
+
+    assert!(result.is_err());
+    assert_eq!(insertion_ctx.solution.routes.len(), 2);
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), to_ids(vec![vec!["c0", "c1"], vec!["c2", "c3"]]));
+    assert!(!insertion_ctx.solution.ignored.contains(&job));
+}
+
+#[test]
+fn can_repair_solution_after_infeasible_edit() {
+    let (problem, mut solution) = generate_matrix_routes(
+        4,
+        2,
+        false,
+        |id, location| {
+            let times = if id == "c5" { vec![TimeWindow::new(0., 5.)] } else { vec![TimeWindow::new(0., 1000.)] };
+            Arc::new(SingleBuilder::default().id(id).location(location).times(times).build())
+        },
+        |v| v,
+        |data| (data.clone(), data),
+    );
+    solution.routes.get_mut(1).unwrap().tour.get_mut(2).unwrap().place.time = TimeWindow::new(0., 5.);
+    let mut insertion_ctx = create_insertion_ctx(problem, solution);
+    let job = get_job(&insertion_ctx, "c0");
+    let actor = get_actor(&insertion_ctx, "c4");
+    apply_solution_edits(&mut insertion_ctx, &[SolutionEdit::MoveJob { job, actor, position: 1 }])
+        .expect("cannot apply edits");
+    let verifier = SolutionVerifier::new(insertion_ctx.problem.clone(), insertion_ctx.environment.clone());
+    let extras = insertion_ctx.problem.extras.clone();
+    assert!(verifier.verify(&insertion_ctx.solution.to_solution(extras.clone())).is_err());
+
+    let insertion_ctx = repair_solution(insertion_ctx);
+
+    assert!(verifier.verify(&insertion_ctx.solution.to_solution(extras)).is_ok());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert!(get_actor(&insertion_ctx, "c0") == get_actor(&insertion_ctx, "c4"));
+    assert_eq!(
+        get_customer_ids_from_routes_sorted(&insertion_ctx).first(),
+        to_ids(vec![vec!["c1", "c2", "c3"]]).first()
+    );
+}
+
+parameterized_test! {can_replan_vehicle_breakdown, (time, expected_changes, expected_routes), {
+    can_replan_vehicle_breakdown_impl(time, expected_changes, expected_routes);
+}}
+
+can_replan_vehicle_breakdown! {
+    case01_before_start: (0., vec!["c0", "c1", "c2", "c3"], vec![vec!["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7"]]),
+    case02_in_the_middle: (2., vec!["c2", "c3"], vec![vec!["c0", "c1"], vec!["c2", "c3", "c4", "c5", "c6", "c7"]]),
+    case03_after_end: (5., vec![], vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c5", "c6", "c7"]]),
+}
+
+fn can_replan_vehicle_breakdown_impl(time: f64, expected_changes: Vec<&str>, expected_routes: Vec<Vec<&str>>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 2, false);
+    let insertion_ctx = create_insertion_ctx(problem, solution);
+    let actor = get_actor(&insertion_ctx, "c0");
+
+    let (insertion_ctx, changes) = replan_vehicle_breakdown(insertion_ctx, &actor, time).expect("cannot replan");
+
+    let mut changes = changes
+        .into_iter()
+        .map(|change| {
+            assert!(change.from == actor);
+            assert!(change.to.map_or(false, |to| to != actor));
+            get_customer_id(&change.job)
+        })
+        .collect::<Vec<_>>();
+    changes.sort();
+    assert_eq!(changes, expected_changes.into_iter().map(|id| id.to_string()).collect::<Vec<_>>());
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), to_ids(expected_routes));
+}
@@ -87,6 +87,10 @@ mod single {
         // vary insertion position
         case09: (vec![JobPlace { location: Some(3), duration: 0.0, times: vec![DEFAULT_JOB_TIME_SPAN] }], InsertionPosition::Last, 3, 2),
         case10: (vec![JobPlace { location: Some(3), duration: 0.0, times: vec![DEFAULT_JOB_TIME_SPAN] }], InsertionPosition::Concrete(1), 3, 1),
+
+        // vary frozen horizon
+        case11: (vec![JobPlace { location: Some(3), duration: 0.0, times: vec![DEFAULT_JOB_TIME_SPAN] }], InsertionPosition::Horizon(0.), 3, 0),
+        case12: (vec![JobPlace { location: Some(3), duration: 0.0, times: vec![DEFAULT_JOB_TIME_SPAN] }], InsertionPosition::Horizon(6.), 3, 2),
     }
 
     fn can_insert_job_with_location_into_tour_with_two_activities_and_variations_impl(
@@ -114,6 +118,36 @@ mod single {
         }
     }
 
+    #[test]
+    fn can_keep_chosen_time_window_in_activity_place() {
+        let job = Job::Single(Arc::new(Single {
+            places: vec![JobPlace {
+                location: Some(7),
+                duration: 0.0,
+                times: vec![TimeSpan::Window(TimeWindow::new(15.0, 20.0)), TimeSpan::Window(TimeWindow::new(7.0, 8.0))],
+            }],
+            dimens: Default::default(),
+        }));
+        let registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1).insert_at(create_activity_at(10), 2);
+        let ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+
+        let result = evaluate_job_insertion(
+            &job,
+            &ctx,
+            &AllRouteSelector::default(),
+            &BestResultSelector::default(),
+            InsertionPosition::Any,
+        );
+
+        if let InsertionResult::Success(success) = result {
+            assert_eq!(success.activities.first().unwrap().0.place.time, TimeWindow::new(7.0, 8.0));
+        } else {
+            unreachable!()
+        }
+    }
+
     parameterized_test! {can_insert_job_with_two_vehicles_and_various_time_constraints, (job_location, v1_end_location, v2_end_location, expected_used_vehicle, cost), {
         can_insert_job_with_two_vehicles_and_various_time_constraints_impl(job_location, v1_end_location, v2_end_location, expected_used_vehicle, cost);
     }}
@@ -146,6 +180,7 @@ mod single {
                                 location: v1_end_location,
                                 time: TimeInterval { earliest: None, latest: Some(100.) },
                             }),
+                            driver: None,
                         }])
                         .build(),
                     VehicleBuilder::default()
@@ -159,6 +194,7 @@ mod single {
                                 location: v2_end_location,
                                 time: TimeInterval { earliest: None, latest: Some(100.) },
                             }),
+                            driver: None,
                         }])
                         .build(),
                 ])
@@ -295,6 +331,10 @@ mod multi {
         // concrete position
         case09: (vec![(1, 5)], InsertionPosition::Concrete(1), vec![(1, 3), (2, 7)], 16.),          // s [5] 3 7 e
         case10: (vec![(1, 7), (2, 3)], InsertionPosition::Concrete(1), vec![(1, 9), (2, 5)], 8.),   // s [7] 9 5 [3] e
+
+        // frozen horizon
+        case11: (vec![(1, 5)], InsertionPosition::Horizon(6.), vec![(1, 3), (2, 7)], 16.),          // s [5] 3 7 e
+        case12: (vec![(1, 7), (2, 3)], InsertionPosition::Horizon(10.), vec![(2, 9), (3, 5)], 24.), // s [7] [3] 9 5 e
     }
 
     fn can_insert_job_with_singles_into_tour_with_activities_impl(
@@ -0,0 +1,126 @@
+use super::*;
+use crate::construction::constraints::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::models::common::IdDimension;
+use crate::utils::Environment;
+use std::slice::Iter;
+
+struct RejectJobModule {
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for RejectJobModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        [].iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct RejectJobConstraint {
+    code: i32,
+    job_ids: Vec<String>,
+}
+
+impl HardRouteConstraint for RejectJobConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if self.job_ids.contains(job.dimens().get_id().unwrap()) {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+struct RejectActivityConstraint {
+    code: i32,
+    job_ids: Vec<String>,
+}
+
+impl HardActivityConstraint for RejectActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        _: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let job_id = activity_ctx.target.job.as_ref().and_then(|single| single.dimens.get_id());
+
+        if matches!(job_id, Some(job_id) if self.job_ids.contains(job_id)) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+fn create_reject_constraint(code: i32, job_ids: Vec<&str>) -> ConstraintVariant {
+    ConstraintVariant::HardRoute(Arc::new(RejectJobConstraint {
+        code,
+        job_ids: job_ids.into_iter().map(|id| id.to_string()).collect(),
+    }))
+}
+
+fn create_test_insertion_ctx() -> InsertionContext {
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(Box::new(RejectJobModule {
+        constraints: vec![
+            create_reject_constraint(1, vec!["job1", "job2"]),
+            create_reject_constraint(2, vec!["job2", "job3"]),
+            create_reject_constraint(3, vec!["job3", "job4"]),
+            create_reject_constraint(4, vec!["job5"]),
+            ConstraintVariant::HardActivity(Arc::new(RejectActivityConstraint {
+                code: 2,
+                job_ids: vec!["job6".to_string(), "job7".to_string()],
+            })),
+            ConstraintVariant::HardActivity(Arc::new(RejectActivityConstraint {
+                code: 4,
+                job_ids: vec!["job7".to_string()],
+            })),
+        ],
+    }));
+
+    InsertionContext {
+        problem: create_empty_problem_with_constraint(constraint),
+        solution: create_empty_solution_context(),
+        environment: Arc::new(Environment::default()),
+    }
+}
+
+parameterized_test! {can_find_job_relaxation, (job_id, expected), {
+    let insertion_ctx = create_test_insertion_ctx();
+    let job = Job::Single(test_single_with_id(job_id));
+
+    let result = find_job_relaxation(&insertion_ctx, &job, &[1, 2, 3]);
+
+    assert_eq!(result.map(|relaxation| relaxation.codes), expected);
+}}
+
+can_find_job_relaxation! {
+    case01_no_relaxation: ("job0", Some(vec![])),
+    case02_single_relaxation: ("job1", Some(vec![1])),
+    case03_double_relaxation: ("job2", Some(vec![1, 2])),
+    case04_double_relaxation: ("job3", Some(vec![2, 3])),
+    case05_single_relaxation: ("job4", Some(vec![3])),
+    case06_not_relaxable: ("job5", None),
+    case07_activity_relaxation: ("job6", Some(vec![2])),
+    case08_activity_not_relaxable: ("job7", None),
+}
+
+#[test]
+fn can_return_actor_of_relaxation() {
+    let insertion_ctx = create_test_insertion_ctx();
+    let job = Job::Single(test_single_with_id("job1"));
+
+    let result = find_job_relaxation(&insertion_ctx, &job, &[1]).expect("relaxation is expected");
+
+    assert_eq!(result.actor.vehicle.dimens.get_id().cloned(), Some("v1".to_string()));
+}
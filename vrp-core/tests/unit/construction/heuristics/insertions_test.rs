@@ -0,0 +1,92 @@
+use super::*;
+use crate::construction::constraints::{CapacityConstraintModule, ConstraintPipeline, TransportConstraintModule};
+use crate::construction::heuristics::{AllJobSelector, AllRouteSelector, BestResultSelector, PairJobMapReducer};
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::domain::test_random;
+use crate::helpers::models::problem::*;
+use crate::models::common::{IdDimension, Location, SingleDimLoad};
+use crate::models::solution::Registry;
+
+fn create_job_with_priority(id: &str, location: Location, priority: Option<i32>) -> Job {
+    let mut single = SingleBuilder::default().id(id).location(Some(location)).demand(create_simple_demand(-1)).build();
+    if let Some(priority) = priority {
+        single.dimens.set_priority(priority);
+    }
+
+    Job::Single(Arc::new(single))
+}
+
+fn get_job_ids(jobs: &[Job]) -> Vec<String> {
+    let mut ids = jobs.iter().map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn can_take_jobs_with_lower_priority() {
+    let mut required = vec![
+        create_job_with_priority("job1", 1, Some(2)),
+        create_job_with_priority("job2", 1, Some(1)),
+        create_job_with_priority("job3", 1, None),
+        create_job_with_priority("job4", 1, Some(3)),
+    ];
+
+    let postponed = take_low_priority_jobs(&mut required);
+
+    assert_eq!(get_job_ids(&required), vec!["job2", "job3"]);
+    assert_eq!(get_job_ids(&postponed), vec!["job1", "job4"]);
+}
+
+#[test]
+fn can_keep_jobs_with_the_same_priority() {
+    let mut required = vec![create_job_with_priority("job1", 1, Some(2)), create_job_with_priority("job2", 1, Some(2))];
+
+    let postponed = take_low_priority_jobs(&mut required);
+
+    assert_eq!(get_job_ids(&required), vec!["job1", "job2"]);
+    assert!(postponed.is_empty());
+}
+
+parameterized_test! {can_insert_jobs_with_higher_priority_first, (priority, expected_assigned, expected_unassigned), {
+    can_insert_jobs_with_higher_priority_first_impl(priority, expected_assigned, expected_unassigned);
+}}
+
+can_insert_jobs_with_higher_priority_first! {
+    case01_no_priority: (None, "cheap", "expensive"),
+    case02_lower_priority: (Some(2), "expensive", "cheap"),
+}
+
+fn can_insert_jobs_with_higher_priority_first_impl(
+    priority: Option<i32>,
+    expected_assigned: &str,
+    expected_unassigned: &str,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(1).build())
+        .build();
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(Box::new(TransportConstraintModule::new(
+        Arc::new(TestActivityCost::default()),
+        TestTransportCost::new_shared(),
+        Arc::new(|_| (None, None)),
+        1,
+        2,
+        3,
+    )));
+    constraint.add_module(Box::new(CapacityConstraintModule::<SingleDimLoad>::new(4)));
+    let mut insertion_ctx = create_insertion_context(Registry::new(&fleet, test_random()), constraint, vec![]);
+    insertion_ctx.solution.required =
+        vec![create_job_with_priority("cheap", 5, priority), create_job_with_priority("expensive", 50, None)];
+    let job_reducer =
+        PairJobMapReducer::new(Box::new(AllRouteSelector::default()), Box::new(BestResultSelector::default()));
+
+    let result = InsertionHeuristic::default().process(&AllJobSelector::default(), &job_reducer, insertion_ctx, &None);
+
+    assert_eq!(result.solution.routes.len(), 1);
+    let assigned = result.solution.routes[0].route.tour.jobs().collect::<Vec<_>>();
+    assert_eq!(get_job_ids(&assigned), vec![expected_assigned]);
+    let unassigned = result.solution.unassigned.keys().cloned().collect::<Vec<_>>();
+    assert_eq!(get_job_ids(&unassigned), vec![expected_unassigned]);
+}
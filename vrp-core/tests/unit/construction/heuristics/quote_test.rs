@@ -0,0 +1,37 @@
+use super::*;
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::common::{IdDimension, TimeWindow};
+use crate::utils::Environment;
+
+fn create_insertion_ctx() -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+#[test]
+fn can_quote_job_insertion() {
+    let insertion_ctx = create_insertion_ctx();
+    let job = SingleBuilder::default().id("new").location(Some(5)).build_as_job_ref();
+
+    let quote = quote_job_insertion(&insertion_ctx, &job).expect("should quote insertion");
+
+    assert_eq!(quote.actor.vehicle.dimens.get_id().unwrap(), "1");
+    assert_eq!(quote.positions, vec![2]);
+    assert_eq!(quote.schedules, vec![Schedule::new(5., 5.)]);
+    assert_eq!(quote.cost, 8.);
+    assert_eq!(quote.distance_delta, 4.);
+    assert_eq!(quote.duration_delta, 4.);
+    assert!(insertion_ctx.solution.routes.iter().all(|route_ctx| !route_ctx.route.tour.contains(&job)));
+}
+
+#[test]
+fn can_return_violation_code_when_job_cannot_be_inserted() {
+    let insertion_ctx = create_insertion_ctx();
+    let job =
+        SingleBuilder::default().id("new").location(Some(5)).times(vec![TimeWindow::new(0., 1.)]).build_as_job_ref();
+
+    let result = quote_job_insertion(&insertion_ctx, &job);
+
+    assert_eq!(result.err(), Some(1));
+}
@@ -0,0 +1,53 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::common::TimeWindow;
+
+fn verify(problem: Problem, solution: &Solution) -> Result<(), Vec<SolutionViolation>> {
+    SolutionVerifier::new(Arc::new(problem), Arc::new(Environment::default())).verify(solution)
+}
+
+fn get_activity_violations(violations: &[SolutionViolation]) -> Vec<(usize, usize, i32)> {
+    violations
+        .iter()
+        .filter_map(|violation| match violation {
+            SolutionViolation::Activity { route_index, activity_index, code } => {
+                Some((*route_index, *activity_index, *code))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn can_verify_feasible_solution() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 2, false);
+
+    let result = verify(problem, &solution);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn can_detect_activity_violation() {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(4, 2, false);
+    solution.routes.get_mut(1).unwrap().tour.get_mut(3).unwrap().place.time = TimeWindow::new(0., 1.);
+
+    let violations = verify(problem, &solution).expect_err("should have violations");
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(get_activity_violations(violations.as_slice()), vec![(1, 3, 1)]);
+}
+
+#[test]
+fn can_detect_duplicated_job() {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let job = solution.routes.first().unwrap().tour.jobs().next().unwrap();
+    solution.unassigned.push((job.clone(), 0));
+
+    let violations = verify(problem, &solution).expect_err("should have violations");
+
+    assert_eq!(violations.len(), 1);
+    assert!(
+        matches!(violations.first(), Some(SolutionViolation::DuplicatedJob { job: duplicated }) if *duplicated == job)
+    );
+}
@@ -45,6 +45,9 @@ mod single {
 
         assert!(from_value(10).can_fit(&from_value(5)));
         assert!(!from_value(5).can_fit(&from_value(10)));
+
+        assert_eq!(from_value(12).overload(&from_value(10)), 2);
+        assert_eq!(from_value(8).overload(&from_value(10)), 0);
     }
 }
 
@@ -101,5 +104,8 @@ mod multi {
 
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
+
+        assert_eq!(from_vec(vec![3, 0, 6]).overload(&from_vec(vec![1, 1, 4])), 4);
+        assert_eq!(from_vec(vec![1, 1]).overload(&from_vec(vec![1, 1])), 0);
     }
 }
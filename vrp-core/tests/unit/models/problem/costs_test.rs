@@ -1,4 +1,7 @@
 use super::*;
+use crate::helpers::models::solution::test_actor;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::utils::Environment;
 
 fn create_matrix_data(
     profile: Profile,
@@ -80,3 +83,287 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance(0, 0, 1, 0.), 1.);
     assert_eq!(costs.distance(1, 0, 1, 0.), 5.);
 }
+
+parameterized_test! {can_use_time_buckets, (timestamp, expected), {
+    let costs = create_matrix_transport_cost_with_time_buckets(
+        vec![
+            create_matrix_data(0, Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(0, Some(10.), (200., 4), (2., 4)),
+            create_matrix_data(1, Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(1, Some(10.), (200., 4), (2., 4)),
+        ],
+        vec![(0, 4.)].into_iter().collect(),
+    )
+    .unwrap();
+
+    assert_eq!((costs.duration(0, 0, 1, timestamp), costs.distance(0, 0, 1, timestamp)), expected);
+}}
+
+can_use_time_buckets! {
+    case01_before_start: (-5., (100., 1.)),
+    case02_at_start: (0., (100., 1.)),
+    case03_inside_first_bucket: (3., (100., 1.)),
+    case04_second_bucket: (5., (140., 1.)),
+    case05_last_bucket: (9., (180., 1.)),
+    case06_after_end: (20., (200., 2.)),
+}
+
+#[test]
+fn can_interpolate_profiles_without_time_buckets() {
+    let costs = create_matrix_transport_cost_with_time_buckets(
+        vec![
+            create_matrix_data(0, Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(0, Some(10.), (200., 4), (2., 4)),
+            create_matrix_data(1, Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(1, Some(10.), (200., 4), (2., 4)),
+        ],
+        vec![(0, 4.)].into_iter().collect(),
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration(0, 0, 1, 3.), 100.);
+    assert_eq!(costs.duration(1, 0, 1, 3.), 130.);
+}
+
+#[test]
+fn can_validate_time_bucket_size() {
+    let result = create_matrix_transport_cost_with_time_buckets(
+        vec![create_matrix_data(0, Some(0.), (1., 1), (1., 1))],
+        vec![(0, 0.)].into_iter().collect(),
+    );
+
+    assert_eq!(result.err(), Some("time bucket size should be positive".to_string()));
+}
+
+parameterized_test! {can_limit_time_bucket_count, (bucket_size, expected), {
+    let result = create_matrix_transport_cost_with_time_buckets(
+        vec![
+            create_matrix_data(0, Some(0.), (100., 4), (1., 4)),
+            create_matrix_data(0, Some(1023.), (200., 4), (2., 4)),
+        ],
+        vec![(0, bucket_size)].into_iter().collect(),
+    );
+
+    assert_eq!(result.err(), expected);
+}}
+
+can_limit_time_bucket_count! {
+    case01_at_limit: (1., None),
+    case02_above_limit: (0.5, Some("too many time buckets for profile 0: 2047, max is 1024".to_string())),
+}
+
+#[test]
+fn can_estimate_matrix_memory_size() {
+    let agnostic = create_matrix_transport_cost(vec![
+        create_matrix_data(0, None, (1., 4), (1., 4)),
+        create_matrix_data(1, None, (1., 4), (1., 4)),
+    ])
+    .unwrap();
+    let aware = create_matrix_transport_cost(vec![
+        create_matrix_data(0, Some(0.), (1., 4), (1., 4)),
+        create_matrix_data(0, Some(10.), (1., 4), (1., 4)),
+        create_matrix_data(0, Some(20.), (1., 4), (1., 4)),
+    ])
+    .unwrap();
+
+    assert_eq!(agnostic.memory_size(), 16 * 8);
+    assert_eq!(aware.memory_size(), 24 * 8);
+}
+
+#[test]
+fn can_add_tolls_to_transport_cost() {
+    let transport = create_matrix_transport_cost(vec![create_matrix_data(0, None, (10., 4), (5., 4))]).unwrap();
+    let toll_func: TollFunc = Arc::new(|_, from, to| if from == 0 && to == 1 { 100. } else { 0. });
+    let actor = test_actor();
+
+    let tolled = create_toll_transport_cost(transport.clone(), toll_func);
+
+    assert_eq!(tolled.toll(&actor, 0, 1, 0.), 100.);
+    assert_eq!(tolled.toll(&actor, 1, 0, 0.), 0.);
+    assert_eq!(tolled.cost(&actor, 0, 1, 0.), transport.cost(&actor, 0, 1, 0.) + 100.);
+    assert_eq!(tolled.cost(&actor, 1, 0, 0.), transport.cost(&actor, 1, 0, 0.));
+    assert_eq!(tolled.duration(0, 0, 1, 0.), 10.);
+    assert_eq!(tolled.distance(0, 0, 1, 0.), 5.);
+    assert_eq!(tolled.memory_size(), transport.memory_size());
+}
+
+fn create_sparse_matrix_data(profile: Profile, size: usize) -> SparseMatrixData {
+    // NOTE each location knows only the next one
+    SparseMatrixData::new(profile, (0..size).map(|from| vec![((from + 1) % size, 10., 100.)]).collect())
+}
+
+#[test]
+fn can_use_sparse_transport_cost() {
+    let fallback: TransportFallbackFunc = Arc::new(|_, from, to| (1000. + (from * 10 + to) as f64, 2000.));
+
+    let transport = create_sparse_transport_cost(vec![create_sparse_matrix_data(0, 3)], fallback).unwrap();
+
+    assert_eq!(transport.duration(0, 0, 1, 0.), 10.);
+    assert_eq!(transport.distance(0, 2, 0, 0.), 100.);
+    assert_eq!(transport.duration(0, 1, 0, 0.), 1010.);
+    assert_eq!(transport.distance(0, 1, 0, 0.), 2000.);
+    assert_eq!(transport.distance(0, 1, 1, 0.), 0.);
+    assert_eq!(transport.sparse_neighbors(0, 1), Some(vec![2]));
+    assert_eq!(transport.memory_size(), 3 * std::mem::size_of::<(Location, Duration, Distance)>());
+}
+
+parameterized_test! {can_detect_invalid_sparse_matrix_data, (costs, expected), {
+    let fallback: TransportFallbackFunc = Arc::new(|_, _, _| (0., 0.));
+
+    let result = create_sparse_transport_cost(costs, fallback).map(|_| ());
+
+    assert_eq!(result, Err(expected.to_string()));
+}}
+
+can_detect_invalid_sparse_matrix_data! {
+    case01_empty: (vec![], "no sparse matrix data found"),
+    case02_duplicate_profiles: (vec![create_sparse_matrix_data(0, 2), create_sparse_matrix_data(0, 2)],
+                                "sparse matrix data should have unique profiles"),
+    case03_different_sizes: (vec![create_sparse_matrix_data(0, 2), create_sparse_matrix_data(1, 3)],
+                             "sparse matrix data should have the same amount of locations"),
+    case04_unknown_location: (vec![SparseMatrixData::new(0, vec![vec![(1, 1., 1.)]])],
+                              "sparse matrix data has unknown location"),
+}
+
+#[test]
+fn can_estimate_with_great_circle_fallback() {
+    let fallback = create_great_circle_fallback(vec![(52.52, 13.405), (48.8566, 2.3522)], 10., 1.5).unwrap();
+
+    let (duration, distance) = fallback(0, 0, 1);
+
+    assert!((distance - 877_464.5 * 1.5).abs() < 1.);
+    assert!((duration - distance / 10.).abs() < 1E-6);
+    assert_eq!(fallback(0, 1, 1), (0., 0.));
+    assert!(create_great_circle_fallback(vec![], 0., 1.).is_err());
+}
+
+parameterized_test! {can_validate_great_circle_coordinates, (coordinates, expected), {
+    let result = create_great_circle_sparse_transport_cost(
+        vec![create_sparse_matrix_data(0, 3)], coordinates, 10., 1.5).map(|_| ());
+
+    assert_eq!(result, expected.map_or(Ok(()), |err: &str| Err(err.to_string())));
+}}
+
+can_validate_great_circle_coordinates! {
+    case01_same_size: (vec![(52.52, 13.405), (48.8566, 2.3522), (50.0755, 14.4378)], None),
+    case02_less: (vec![(52.52, 13.405), (48.8566, 2.3522)],
+                  Some("amount of coordinates should match amount of locations in sparse matrix data")),
+    case03_more: (vec![(52.52, 13.405); 4],
+                  Some("amount of coordinates should match amount of locations in sparse matrix data")),
+}
+
+#[test]
+fn can_get_percentile_durations() {
+    let durations = get_percentile_durations(&[10., 10., -1., 0.], &[0., 10., 5., 10.], 0.9).unwrap();
+
+    assert_eq!(durations.iter().map(|d| d.round()).collect::<Vec<_>>(), vec![10., 23., -1., 13.]);
+    assert_eq!(get_percentile_durations(&[10., 10.], &[2., 2.], 0.5).unwrap(), vec![10., 10.]);
+}
+
+parameterized_test! {can_detect_invalid_percentile_durations, (deviations, percentile, expected), {
+    let result = get_percentile_durations(&[10., 10.], deviations.as_slice(), percentile).err();
+
+    assert_eq!(result, Some(expected.to_string()));
+}}
+
+can_detect_invalid_percentile_durations! {
+    case01: (vec![1.], 0.9, "travel time deviations should have the same size as durations"),
+    case02: (vec![1., 1.], 1., "percentile should be in (0, 1) range"),
+    case03: (vec![1., 1.], 0., "percentile should be in (0, 1) range"),
+    case04: (vec![1., -1.], 0.9, "travel time deviations should be non-negative"),
+}
+
+/// An objective which minimizes given fitness function.
+struct FitnessObjective {
+    fitness_fn: fn(&InsertionContext) -> f64,
+}
+
+impl Objective for FitnessObjective {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        (self.fitness_fn)(solution)
+    }
+}
+
+fn create_routes_objective() -> TargetObjective {
+    Box::new(FitnessObjective { fitness_fn: |ctx| ctx.solution.routes.len() as f64 })
+}
+
+fn create_route_size_objective() -> TargetObjective {
+    Box::new(FitnessObjective {
+        fitness_fn: |ctx| ctx.solution.routes.iter().map(|rc| rc.route.tour.job_count()).max().unwrap_or(0) as f64,
+    })
+}
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, true);
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+parameterized_test! {can_compose_objective_layers, (builder, expected), {
+    can_compose_objective_layers_impl(builder, expected);
+}}
+
+can_compose_objective_layers! {
+    case01_dominance: (ObjectiveBuilder::default()
+        .add_layer(vec![create_routes_objective(), create_route_size_objective()]), Ordering::Equal),
+    case02_lexicographic_routes: (ObjectiveBuilder::default()
+        .add_layer(vec![create_routes_objective()])
+        .add_layer(vec![create_route_size_objective()]), Ordering::Greater),
+    case03_lexicographic_route_size: (ObjectiveBuilder::default()
+        .add_layer(vec![create_route_size_objective()])
+        .add_layer(vec![create_routes_objective()]), Ordering::Less),
+    case04_weighted_routes: (ObjectiveBuilder::default()
+        .add_weighted_layer(vec![(create_routes_objective(), 2.), (create_route_size_objective(), 1.)]),
+        Ordering::Greater),
+    case05_weighted_route_size: (ObjectiveBuilder::default()
+        .add_weighted_layer(vec![(create_routes_objective(), 1.), (create_route_size_objective(), 2.)]),
+        Ordering::Less),
+    case06_weighted_then_lexicographic: (ObjectiveBuilder::default()
+        .add_weighted_layer(vec![(create_routes_objective(), 1.), (create_route_size_objective(), 1.)])
+        .add_layer(vec![create_routes_objective()]), Ordering::Greater),
+}
+
+fn can_compose_objective_layers_impl(builder: ObjectiveBuilder, expected: Ordering) {
+    // NOTE first solution has 3 routes with 2 jobs, second one has 2 routes with 3 jobs
+    let (a, b) = (create_insertion_ctx(2, 3), create_insertion_ctx(3, 2));
+
+    let objective = builder.build().unwrap();
+
+    assert_eq!(objective.total_order(&a, &b), expected);
+    assert_eq!(objective.total_order(&b, &a), expected.reverse());
+    assert_eq!(objective.total_order(&a, &a), Ordering::Equal);
+}
+
+parameterized_test! {can_detect_invalid_objective_layers, (builder, expected), {
+    assert_eq!(builder.build().err(), Some(expected.to_string()));
+}}
+
+can_detect_invalid_objective_layers! {
+    case01_no_layers: (ObjectiveBuilder::default(), "no objective layers specified"),
+    case02_empty_layer: (ObjectiveBuilder::default().add_layer(vec![create_routes_objective()]).add_layer(vec![]),
+                         "objective layer cannot be empty"),
+    case03_negative_weight: (ObjectiveBuilder::default().add_weighted_layer(vec![(create_routes_objective(), -1.)]),
+                             "objective weight should be non-negative finite number"),
+}
+
+#[test]
+fn can_return_all_objectives_from_layers() {
+    let objective = ObjectiveBuilder::default()
+        .add_layer(vec![create_routes_objective()])
+        .add_weighted_layer(vec![(create_routes_objective(), 1.), (create_route_size_objective(), 1.)])
+        .build()
+        .unwrap();
+
+    assert_eq!(objective.objectives().count(), 3);
+}
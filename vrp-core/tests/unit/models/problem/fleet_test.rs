@@ -1,4 +1,6 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle, FleetBuilder};
+use crate::helpers::models::problem::{test_driver, test_vehicle, test_vehicle_detail, FleetBuilder};
+use crate::models::common::TimeInterval;
+use crate::models::problem::{DriverDetail, Vehicle, VehicleDetail, VehiclePlace};
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -16,3 +18,73 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1.to_owned(), profile2.to_owned()]
     )
 }
+
+parameterized_test! {can_delay_actor_start_until_driver_takes_vehicle, (vehicle_earliest, driver_earliest, expected), {
+    can_delay_actor_start_until_driver_takes_vehicle_impl(vehicle_earliest, driver_earliest, expected);
+}}
+
+can_delay_actor_start_until_driver_takes_vehicle! {
+    case01: (Some(10.), None, 10.),
+    case02: (Some(10.), Some(5.), 10.),
+    case03: (Some(10.), Some(15.), 15.),
+    case04: (None, Some(15.), 15.),
+}
+
+fn can_delay_actor_start_until_driver_takes_vehicle_impl(
+    vehicle_earliest: Option<f64>,
+    driver_earliest: Option<f64>,
+    expected: f64,
+) {
+    let detail = VehicleDetail {
+        start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: vehicle_earliest, latest: None } }),
+        driver: driver_earliest.map(|earliest| DriverDetail {
+            start: Some(VehiclePlace { location: 1, time: TimeInterval { earliest: Some(0.), latest: None } }),
+            time: TimeInterval { earliest: Some(earliest), latest: None },
+        }),
+        ..test_vehicle_detail()
+    };
+
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle { details: vec![detail], ..test_vehicle(0) })
+        .build();
+
+    let actor = fleet.actors.first().unwrap();
+    assert_eq!(actor.detail.time.start, expected);
+    assert_eq!(actor.detail.start.as_ref().and_then(|start| start.time.earliest), Some(expected));
+    assert_eq!(actor.detail.driver.is_some(), driver_earliest.is_some());
+}
+
+parameterized_test! {can_clamp_actor_start_when_driver_is_late, (start_latest, end_latest, expected), {
+    can_clamp_actor_start_when_driver_is_late_impl(start_latest, end_latest, expected);
+}}
+
+can_clamp_actor_start_when_driver_is_late! {
+    case01_no_latest: (None, None, 15.),
+    case02_start_latest: (Some(12.), None, 12.),
+    case03_end_latest: (None, Some(11.), 11.),
+    case04_both_latest: (Some(12.), Some(11.), 11.),
+    case05_driver_in_time: (Some(20.), Some(30.), 15.),
+}
+
+fn can_clamp_actor_start_when_driver_is_late_impl(start_latest: Option<f64>, end_latest: Option<f64>, expected: f64) {
+    let detail = VehicleDetail {
+        start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(10.), latest: start_latest } }),
+        end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: end_latest } }),
+        driver: Some(DriverDetail {
+            start: Some(VehiclePlace { location: 1, time: TimeInterval { earliest: Some(0.), latest: None } }),
+            time: TimeInterval { earliest: Some(15.), latest: None },
+        }),
+    };
+
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle { details: vec![detail], ..test_vehicle(0) })
+        .build();
+
+    let actor = fleet.actors.first().unwrap();
+    assert_eq!(actor.detail.time.start, expected);
+    assert!(actor.detail.time.start <= actor.detail.time.end);
+    let start_time = &actor.detail.start.as_ref().unwrap().time;
+    assert!(start_time.earliest.unwrap() <= start_time.latest.unwrap_or(f64::MAX));
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::helpers::models::common::DEFAULT_PROFILE;
 use crate::helpers::models::problem::*;
-use crate::models::problem::{VehicleDetail, VehiclePlace};
+use crate::models::problem::{create_sparse_transport_cost, SparseMatrixData, VehicleDetail, VehiclePlace};
 
 struct OnlyDistanceCost {}
 
@@ -148,6 +148,29 @@ fn returns_proper_job_neighbours_impl(index: usize, expected: Vec<String>) {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn can_use_sparse_routing_data_for_neighbors() {
+    let neighbors = (0..5_usize).map(|from| vec![(from.max(1) - 1, 1., 1.), ((from + 1).min(4), 1., 1.)]).collect();
+    let transport = create_sparse_transport_cost(
+        vec![SparseMatrixData::new(DEFAULT_PROFILE, neighbors)],
+        Arc::new(|_, from, to| (0., (from as f64 - to as f64).abs() * 100.)),
+    )
+    .unwrap();
+    let species = (0..5)
+        .map(|idx| SingleBuilder::default().id(format!("s{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect::<Vec<_>>();
+    let jobs = Jobs::new(&test_fleet(), species.clone(), &transport);
+    let get_neighbors = |index: usize| {
+        jobs.neighbors(DEFAULT_PROFILE, species.get(index).unwrap(), 0.)
+            .map(|(j, _)| get_job_id(j).clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(get_neighbors(0), vec!["s1"]);
+    assert_eq!(get_neighbors(2), vec!["s1", "s3"]);
+    assert_eq!(jobs.distance(DEFAULT_PROFILE, &species[0], &species[4], 0.), UNREACHABLE_COST);
+}
+
 parameterized_test! {returns_proper_job_ranks, (index, profile, expected), {
     returns_proper_job_ranks_impl(index, profile, expected);
 }}
@@ -167,6 +190,7 @@ fn returns_proper_job_ranks_impl(index: usize, profile: Profile, expected: Dista
     let create_vehicle_detail = |start_location: usize| VehicleDetail {
         start: Some(VehiclePlace { location: start_location, time: TimeInterval::default() }),
         end: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
+        driver: None,
     };
     let fleet = FleetBuilder::default()
         .add_driver(test_driver())
@@ -0,0 +1,62 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::models::problem::Fleet;
+
+fn create_job(location: usize, time: (f64, f64), demand: i32) -> Job {
+    SingleBuilder::default()
+        .location(Some(location))
+        .times(vec![TimeWindow::new(time.0, time.1)])
+        .demand(create_simple_demand(demand))
+        .build_as_job_ref()
+}
+
+fn create_fleet(vehicles: Vec<(&str, Profile, i32)>) -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            vehicles
+                .into_iter()
+                .map(|(id, profile, capacity)| {
+                    VehicleBuilder::default().id(id).profile(profile).capacity(capacity).build()
+                })
+                .collect(),
+        )
+        .build()
+}
+
+#[test]
+fn can_calculate_problem_statistics() {
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        ConstraintPipeline::default(),
+        vec![create_job(0, (0., 1000.), 5), create_job(10, (0., 100.), -3), create_job(20, (500., 700.), 2)],
+        create_fleet(vec![("v1", 0, 10), ("v2", 0, 10), ("v3", 1, 5), ("v4", 0, 5)]),
+    );
+
+    let statistics = get_problem_statistics(problem.as_ref());
+
+    assert_eq!(statistics.jobs, 3);
+    assert_eq!(statistics.vehicles, 4);
+    assert!((statistics.average_distance - 40. / 3.).abs() < 1E-6);
+    assert!((statistics.dispersion - 0.75).abs() < 1E-6);
+    assert!((statistics.time_window_tightness - 1.7 / 3.).abs() < 1E-6);
+    assert_eq!(statistics.demand_ratio, Some(10. / 30.));
+    assert_eq!(statistics.vehicle_types, 3);
+    assert!((statistics.fleet_heterogeneity - 2. / 3.).abs() < 1E-6);
+}
+
+#[test]
+fn can_calculate_statistics_for_empty_problem() {
+    let problem = create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), vec![], test_fleet());
+
+    let statistics = get_problem_statistics(problem.as_ref());
+
+    assert_eq!(statistics.jobs, 0);
+    assert_eq!(statistics.average_distance, 0.);
+    assert_eq!(statistics.time_window_tightness, 0.);
+    assert_eq!(statistics.demand_ratio, None);
+    assert_eq!(statistics.vehicle_types, 1);
+    assert_eq!(statistics.fleet_heterogeneity, 0.);
+}
@@ -1,6 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, VehicleBuilder};
-use crate::models::common::TimeInterval;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::{IdDimension, TimeInterval};
 use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
 use std::cmp::Ordering::Less;
@@ -57,12 +58,36 @@ fn can_provide_next_actors_from_registry() {
     assert_eq!(actors.last().unwrap().detail.start.as_ref().map(|s| s.location), Some(1));
 }
 
+#[test]
+fn can_provide_next_actors_in_fleet_order() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            (1..=4)
+                .map(|idx| {
+                    VehicleBuilder::default().id(&format!("v{}", idx)).details(vec![test_vehicle_detail()]).build()
+                })
+                .collect(),
+        )
+        .build();
+    let mut registry = Registry::new(&fleet, Arc::new(FakeRandom::new(vec![1, 1, 2], vec![])));
+    let get_next_id = |registry: &Registry| {
+        registry.next().map(|actor| actor.vehicle.dimens.get_id().unwrap().clone()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(get_next_id(&registry), vec!["v2"]);
+    registry.use_actor(&fleet.actors[1]);
+    assert_eq!(get_next_id(&registry), vec!["v3"]);
+    assert_eq!(get_next_id(&registry), vec!["v4"]);
+}
+
 fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
     vec![
         test_vehicle_detail(),
         VehicleDetail {
             start: Some(VehiclePlace { location: 1, time: TimeInterval { earliest: Some(0.), latest: None } }),
             end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(50.) } }),
+            driver: None,
         },
     ]
 }
@@ -0,0 +1,50 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::solver::mutation::ExchangeInterRouteRandom;
+use crate::utils::Environment;
+use std::sync::Arc;
+
+parameterized_test! {can_estimate_autocorrelation, (series, lag, expected), {
+    can_estimate_autocorrelation_impl(series, lag, expected);
+}}
+
+can_estimate_autocorrelation! {
+    case01_alternating: (vec![1., -1., 1., -1., 1., -1.], 1, Some(-1.)),
+    case02_alternating_even_lag: (vec![1., -1., 1., -1., 1., -1.], 2, Some(1.)),
+    case03_constant: (vec![2., 2., 2.], 1, None),
+    case04_too_short: (vec![1., 2.], 2, None),
+    case05_zero_lag: (vec![1., 2., 3.], 0, None),
+}
+
+fn can_estimate_autocorrelation_impl(series: Vec<f64>, lag: usize, expected: Option<f64>) {
+    let result = estimate_autocorrelation(series.as_slice(), lag);
+
+    match (result, expected) {
+        (Some(result), Some(expected)) => assert!((result - expected).abs() < 1E-9),
+        (result, expected) => assert_eq!(result, expected),
+    }
+}
+
+#[test]
+fn can_analyze_landscape_with_random_walk() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let problem = Arc::new(problem);
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    let analysis = analyze_landscape(&refinement_ctx, &insertion_ctx, &ExchangeInterRouteRandom::default(), 50, 5);
+
+    assert!(!analysis.walk.is_empty() && analysis.walk.len() <= 51);
+    assert!(analysis.autocorrelation.len() <= 5);
+    assert!(analysis.autocorrelation.iter().all(|value| value.is_finite()));
+    assert!(analysis.correlation_length >= 0.);
+    assert!((0. ..=1.).contains(&analysis.ruggedness));
+}
+
+#[test]
+fn can_estimate_ruggedness() {
+    assert_eq!(get_ruggedness(&[1., 2., 1., 2., 1.]), 1.);
+    assert_eq!(get_ruggedness(&[1., 2., 3., 4.]), 0.);
+    assert_eq!(get_ruggedness(&[1., 2., 2., 3., 1.]), 0.5);
+}
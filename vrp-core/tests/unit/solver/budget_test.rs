@@ -0,0 +1,77 @@
+use super::*;
+
+fn round(value: f64) -> f64 {
+    (value * 10.).round() / 10.
+}
+
+#[test]
+fn can_split_total_time_by_shares() {
+    let budget = TimeBudget::new(10., (1., 3., 1.)).unwrap();
+
+    assert_eq!(budget.get_allocated(BudgetPhase::Construction), 2.);
+    assert_eq!(budget.get_allocated(BudgetPhase::Search), 6.);
+    assert_eq!(budget.get_allocated(BudgetPhase::PostOptimization), 2.);
+}
+
+#[test]
+fn can_reallocate_unused_time() {
+    let budget = TimeBudget::new(10., (1., 3., 1.)).unwrap();
+
+    budget.start(BudgetPhase::Construction);
+    budget.start(BudgetPhase::Search);
+
+    assert!(budget.is_exhausted(BudgetPhase::Construction));
+    assert!(!budget.is_exhausted(BudgetPhase::Search));
+    assert_eq!(round(budget.get_allocated(BudgetPhase::Construction)), 2.);
+    assert_eq!(round(budget.get_allocated(BudgetPhase::Search)), 7.5);
+    assert_eq!(round(budget.get_allocated(BudgetPhase::PostOptimization)), 2.5);
+
+    budget.finish();
+
+    assert_eq!(round(budget.get_allocated(BudgetPhase::PostOptimization)), 10.);
+}
+
+#[test]
+fn can_skip_phase() {
+    let budget = TimeBudget::new(10., (1., 3., 1.)).unwrap();
+
+    budget.skip(BudgetPhase::PostOptimization);
+
+    assert!(budget.is_exhausted(BudgetPhase::PostOptimization));
+    assert_eq!(budget.get_allocated(BudgetPhase::PostOptimization), 0.);
+    assert_eq!(budget.get_allocated(BudgetPhase::Construction), 2.5);
+    assert_eq!(budget.get_allocated(BudgetPhase::Search), 7.5);
+}
+
+#[test]
+fn can_track_consumption() {
+    let budget = TimeBudget::new(10., (0., 1., 0.)).unwrap();
+
+    budget.start(BudgetPhase::Construction);
+    assert!(budget.is_exhausted(BudgetPhase::Construction));
+    assert_eq!(budget.estimate(BudgetPhase::Construction), 1.);
+
+    budget.start(BudgetPhase::Search);
+    budget.finish();
+
+    let consumption = budget.get_consumption();
+    assert_eq!(consumption.len(), 3);
+    assert_eq!(
+        consumption.iter().map(|phase| phase.phase).collect::<Vec<_>>(),
+        vec![BudgetPhase::Construction, BudgetPhase::Search, BudgetPhase::PostOptimization]
+    );
+    assert!(consumption.iter().all(|phase| phase.consumed < 1.));
+    assert_eq!(consumption[0].allocated, 0.);
+    assert_eq!(round(consumption[1].allocated), 10.);
+}
+
+parameterized_test! {can_validate_budget, (total, shares, expected), {
+    assert_eq!(TimeBudget::new(total, shares).err(), expected.map(|err: &str| err.to_string()));
+}}
+
+can_validate_budget! {
+    case01: (10., (1., 1., 1.), None),
+    case02: (0., (1., 1., 1.), Some("time budget should be positive")),
+    case03: (10., (-1., 1., 1.), Some("time budget shares should be non-negative")),
+    case04: (10., (0., 0., 0.), Some("at least one time budget share should be positive")),
+}
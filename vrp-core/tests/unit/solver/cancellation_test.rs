@@ -0,0 +1,51 @@
+use super::*;
+use crate::construction::constraints::JobCancellationModule;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::{test_fleet, SingleBuilder};
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::problem::Job;
+use crate::solver::mutation::{Recreate, RecreateWithCheapest};
+
+fn create_refinement_ctx_with_cancellation() -> (RefinementContext, Arc<JobCancellation>, Vec<Job>) {
+    let jobs = (1..4)
+        .map(|idx| {
+            SingleBuilder::default().id(format!("job{}", idx).as_str()).location(Some(idx * 10)).build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+    let cancellation = Arc::new(JobCancellation::default());
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_module(Box::new(JobCancellationModule::new(cancellation.clone(), 4)));
+    let problem = create_problem_with_constraint_jobs_and_fleet(constraint, jobs.clone(), test_fleet());
+
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let insertion_ctx = RecreateWithCheapest::default()
+        .run(&refinement_ctx, InsertionContext::new(problem, refinement_ctx.environment.clone()));
+    refinement_ctx.population.add(insertion_ctx);
+    set_job_cancellation(&mut refinement_ctx, cancellation.clone());
+
+    (refinement_ctx, cancellation, jobs)
+}
+
+#[test]
+fn can_withdraw_cancelled_jobs_from_population() {
+    let (mut refinement_ctx, cancellation, jobs) = create_refinement_ctx_with_cancellation();
+    assert_eq!(apply_job_cancellation(&mut refinement_ctx), None);
+
+    cancellation.cancel(jobs[2].clone());
+    let result = apply_job_cancellation(&mut refinement_ctx);
+
+    assert_eq!(result, Some(1));
+    let (best, _) = refinement_ctx.population.ranked().next().unwrap();
+    assert!(best.solution.routes.iter().all(|route_ctx| !route_ctx.route.tour.contains(&jobs[2])));
+    assert_eq!(best.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 2);
+    assert!(best.solution.ignored == vec![jobs[2].clone()]);
+    assert_eq!(refinement_ctx.population.size(), 1);
+    assert!(refinement_ctx.population.ranked().all(|(individual, _)| individual
+        .solution
+        .routes
+        .iter()
+        .all(|route_ctx| !route_ctx.route.tour.contains(&jobs[2]))));
+    assert_eq!(apply_job_cancellation(&mut refinement_ctx), None);
+}
@@ -1,8 +1,12 @@
 use super::*;
 use crate::construction::Quota;
 use crate::models::examples::create_example_problem;
-use crate::solver::TelemetryMode;
+use crate::models::solution::Registry;
+use crate::models::Solution;
+use crate::solver::mutation::Mutation;
+use crate::solver::{TelemetryMode, TimeBudget};
 use crate::utils::Environment;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 parameterized_test! {can_enable_telemetry_metrics, mode, {
@@ -79,3 +83,69 @@ fn can_use_quota() {
     assert_eq!(metrics.generations, 0);
     assert_eq!(metrics.evolution.len(), 1);
 }
+
+#[test]
+fn can_use_time_budget_with_post_optimization() {
+    struct CountMutation {
+        calls: AtomicUsize,
+    }
+
+    impl Mutation for CountMutation {
+        fn mutate(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            insertion_ctx.deep_copy()
+        }
+
+        fn name(&self) -> &str {
+            "count"
+        }
+    }
+
+    let mutation = Arc::new(CountMutation { calls: AtomicUsize::new(0) });
+    let budget = Arc::new(TimeBudget::new(0.3, (1., 1., 1.)).unwrap());
+    let config = EvolutionConfig {
+        termination: Arc::new(MaxGeneration::new(10)),
+        budget: Some(budget.clone()),
+        post_optimization: Some(mutation.clone()),
+        ..EvolutionConfig::new(create_example_problem(), Arc::new(Environment::default()))
+    };
+    let evolution = EvolutionSimulator::new(config).unwrap();
+
+    let (population, _) = evolution.run().unwrap();
+
+    assert!(population.size() > 0);
+    assert!(mutation.calls.load(Ordering::Relaxed) > 0);
+    let consumption = budget.get_consumption();
+    assert!(consumption.iter().all(|phase| phase.consumed > 0.));
+    assert!(consumption[2].consumed >= consumption[2].allocated);
+}
+
+#[test]
+fn can_complete_partial_init_solution() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let solution = Solution {
+        registry: Registry::new(&problem.fleet, environment.random.clone()),
+        routes: vec![],
+        unassigned: problem.jobs.all().map(|job| (job, 0)).collect(),
+        extras: problem.extras.clone(),
+    };
+    let mut config = EvolutionConfig {
+        termination: Arc::new(MaxGeneration::new(0)),
+        ..EvolutionConfig::new(problem.clone(), environment.clone())
+    };
+    config.population.initial.size = 1;
+    config.population.initial.individuals =
+        vec![InsertionContext::new_from_solution(problem.clone(), (solution, None), environment)];
+    let evolution = EvolutionSimulator::new(config).unwrap();
+
+    let (population, _) = evolution.run().unwrap();
+
+    let (best, _) = population.ranked().next().expect("no individuals in population");
+    assert!(best.solution.required.is_empty());
+    assert!(best.solution.unassigned.is_empty());
+    assert_eq!(
+        best.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        problem.jobs.size()
+    );
+}
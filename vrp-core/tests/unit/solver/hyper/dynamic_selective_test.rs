@@ -0,0 +1,70 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::population::Greedy;
+
+#[test]
+fn can_search_and_learn_mutation_estimates() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(Greedy::new(problem.clone(), None));
+    let mut refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone(), None);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let traces = Arc::new(Mutex::new(Vec::<(usize, String)>::new()));
+    let mut hyper = DynamicSelective::new(
+        vec![StaticSelective::create_default_mutation(problem), StaticSelective::create_default_local_search()],
+        environment,
+    );
+    set_mutation_tracer(&mut refinement_ctx, {
+        let traces = traces.clone();
+        Arc::new(move |trace: &MutationTrace| traces.lock().unwrap().push((trace.individual, trace.mutation.clone())))
+    });
+
+    let individuals = hyper.search(&refinement_ctx, vec![&insertion_ctx, &insertion_ctx]);
+
+    assert_eq!(individuals.len(), 2);
+    let traces = traces.lock().unwrap();
+    let mut individuals = traces.iter().map(|(individual, _)| *individual).collect::<Vec<_>>();
+    individuals.sort();
+    assert_eq!(individuals, vec![0, 1]);
+    assert!(traces.iter().all(|(_, mutation)| mutation == "ruin-recreate" || mutation == "local-search"));
+    let estimates = hyper.simulator.get_state_estimates();
+    let phase = refinement_ctx.population.selection_phase();
+    assert_eq!(estimates.get(&SearchState::BestKnown(phase)).map(|estimates| estimates.len()), Some(2));
+}
+
+parameterized_test! {can_get_actions_for_state, (state, expected), {
+    can_get_actions_for_state_impl(state, expected);
+}}
+
+can_get_actions_for_state! {
+    case01_best_known: (SearchState::BestKnown(SelectionPhase::Exploration), 3),
+    case02_diverse: (SearchState::Diverse(SelectionPhase::Exploitation), 3),
+    case03_best_improvement: (SearchState::BestImprovement, 0),
+    case04_diverse_improvement: (SearchState::DiverseImprovement, 0),
+    case05_stagnated: (SearchState::Stagnated, 0),
+}
+
+fn can_get_actions_for_state_impl(state: SearchState, expected: usize) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(Greedy::new(problem.clone(), None));
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone(), None);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+    let mutations = vec![StaticSelective::create_default_local_search(); 3];
+    let result = Mutex::new(None);
+    let agent = SearchAgent {
+        refinement_ctx: &refinement_ctx,
+        mutations: mutations.as_slice(),
+        individual_idx: 0,
+        individual: &insertion_ctx,
+        state: state.clone(),
+        result: &result,
+    };
+
+    let actions = agent.get_actions(&state);
+
+    assert_eq!(actions.len(), expected);
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::construction::heuristics::finalize_insertion_ctx;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::random::FakeRandom;
+use crate::solver::population::Greedy;
+use std::sync::Mutex;
+
+#[test]
+fn can_trace_applied_mutations() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(Greedy::new(problem.clone(), None));
+    let mut refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone(), None);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let traces = Arc::new(Mutex::new(Vec::<(usize, String, f64)>::new()));
+    let mut hyper = StaticSelective::new(vec![
+        (
+            StaticSelective::create_default_mutation(problem.clone()),
+            create_scalar_mutation_probability(1., environment.random.clone()),
+        ),
+        (
+            StaticSelective::create_default_local_search(),
+            create_scalar_mutation_probability(0., environment.random.clone()),
+        ),
+    ]);
+    set_mutation_tracer(&mut refinement_ctx, {
+        let traces = traces.clone();
+        Arc::new(move |trace: &MutationTrace| {
+            traces.lock().unwrap().push((trace.individual, trace.mutation.clone(), trace.cost_before))
+        })
+    });
+
+    let individuals = hyper.search(&refinement_ctx, vec![&insertion_ctx, &insertion_ctx]);
+
+    assert_eq!(individuals.len(), 2);
+    let mut traces = traces.lock().unwrap().clone();
+    traces.sort_by_key(|(individual, ..)| *individual);
+    assert_eq!(traces.len(), 2);
+    assert_eq!(
+        traces.iter().map(|(individual, mutation, ..)| (*individual, mutation.as_str())).collect::<Vec<_>>(),
+        vec![(0, "ruin-recreate"), (1, "ruin-recreate")]
+    );
+    assert_eq!(traces[0].2, traces[1].2);
+}
+
+fn create_worse_insertion_ctx(insertion_ctx: &InsertionContext) -> InsertionContext {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+    let activity = new_insertion_ctx.solution.routes[0].route_mut().tour.all_activities().last().unwrap().deep_copy();
+    let job = activity.retrieve_job().unwrap();
+    new_insertion_ctx.solution.routes[0].route_mut().tour.remove(&job);
+    new_insertion_ctx.solution.routes[1].route_mut().tour.insert_last(activity);
+    finalize_insertion_ctx(&mut new_insertion_ctx);
+
+    new_insertion_ctx
+}
+
+parameterized_test! {can_use_simulated_annealing_acceptance, (temperature, generation, random_value, is_worse, expected), {
+    can_use_simulated_annealing_acceptance_impl(temperature, generation, random_value, is_worse, expected);
+}}
+
+can_use_simulated_annealing_acceptance! {
+    case01_hot_worse: (1., 0, 0.5, true, true),
+    case02_cold_worse: (1., 1000, 0.5, true, false),
+    case03_zero_temperature_worse: (0., 0, 0., true, false),
+    case04_cold_better: (0., 1000, 1., false, true),
+}
+
+fn can_use_simulated_annealing_acceptance_impl(
+    temperature: f64,
+    generation: usize,
+    random_value: f64,
+    is_worse: bool,
+    expected: bool,
+) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, true);
+    let problem = Arc::new(problem);
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    refinement_ctx.statistics.generation = generation;
+    let original = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+    let worse = create_worse_insertion_ctx(&original);
+    assert!(problem.objective.fitness(&worse) > problem.objective.fitness(&original));
+    let (current, candidate) = if is_worse { (original, worse) } else { (worse, original) };
+    let acceptance =
+        create_simulated_annealing_acceptance(temperature, 0.99, Arc::new(FakeRandom::new(vec![], vec![random_value])));
+
+    let result = acceptance(&refinement_ctx, &current, &candidate);
+
+    assert_eq!(result, expected);
+}
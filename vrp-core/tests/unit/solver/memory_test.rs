@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::models::domain::create_simple_insertion_ctx;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::examples::create_example_problem;
+
+fn create_refinement_ctx_with_individuals(size: usize) -> RefinementContext {
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    (0..size).for_each(|idx| {
+        refinement_ctx.population.add(create_simple_insertion_ctx(100. + idx as f64, 0));
+    });
+
+    refinement_ctx
+}
+
+#[test]
+fn can_estimate_memory_usage() {
+    let empty = create_refinement_ctx_with_individuals(0);
+    let filled = create_refinement_ctx_with_individuals(4);
+
+    assert!(MemoryGuard::estimate(&empty) > 0);
+    assert!(MemoryGuard::estimate(&filled) > MemoryGuard::estimate(&empty));
+}
+
+#[test]
+fn can_keep_population_when_limit_is_not_reached() {
+    let mut refinement_ctx = create_refinement_ctx_with_individuals(4);
+    let guard = MemoryGuard::new(usize::MAX / 2);
+
+    assert!(guard.check(&mut refinement_ctx).is_none());
+    assert_eq!(refinement_ctx.population.size(), 4);
+    assert!(!is_memory_pressure(&refinement_ctx));
+}
+
+#[test]
+fn can_degrade_when_limit_is_reached() {
+    let mut refinement_ctx = create_refinement_ctx_with_individuals(4);
+    let guard = Arc::new(MemoryGuard::new(1));
+    set_memory_guard(&mut refinement_ctx, guard);
+
+    assert!(check_memory_usage(&mut refinement_ctx).is_some());
+    assert_eq!(refinement_ctx.population.size(), 2);
+    assert!(is_memory_pressure(&refinement_ctx));
+
+    assert!(check_memory_usage(&mut refinement_ctx).is_some());
+    assert_eq!(refinement_ctx.population.size(), 1);
+
+    assert!(check_memory_usage(&mut refinement_ctx).is_none());
+    assert_eq!(refinement_ctx.population.size(), 1);
+}
@@ -72,3 +72,32 @@ fn can_use_exchange_inter_route_best_operator_impl(
 
     compare_ids_with_ignore(get_customer_ids_from_routes(&new_insertion_ctx), expected_ids);
 }
+
+parameterized_test! {can_use_granular_neighborhood_in_exchange_inter_route_best_operator, (size, threshold, has_result), {
+    can_use_granular_neighborhood_in_exchange_inter_route_best_operator_impl(size, threshold, has_result);
+}}
+
+can_use_granular_neighborhood_in_exchange_inter_route_best_operator! {
+    case_01_no_neighbors: (0, None, false),
+    case_02_all_neighbors: (8, None, true),
+    case_03_too_far_neighbors: (8, Some(0.5), false),
+}
+
+fn can_use_granular_neighborhood_in_exchange_inter_route_best_operator_impl(
+    size: usize,
+    threshold: Option<f64>,
+    has_result: bool,
+) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 3, true);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![0, 2], vec![1.; 128]))),
+    );
+
+    let new_insertion_ctx = ExchangeInterRouteBest::default()
+        .with_neighborhood(GranularNeighborhood::new(size, threshold))
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx);
+
+    assert_eq!(new_insertion_ctx.is_some(), has_result);
+}
@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use std::sync::Arc;
+
+fn create_insertion_ctx(layout: &[Vec<usize>], ints: Vec<i32>) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(layout[0].len(), layout.len(), true);
+
+    let activities = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.all_activities().skip(1).map(|activity| activity.deep_copy()))
+        .collect::<Vec<_>>();
+
+    solution.routes.iter_mut().zip(layout.iter()).for_each(|(route, indices)| {
+        activities.iter().for_each(|activity| {
+            route.tour.remove(&activity.retrieve_job().unwrap());
+        });
+        indices.iter().for_each(|idx| {
+            route.tour.insert_last(activities[*idx].deep_copy());
+        });
+    });
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, vec![]))),
+    )
+}
+
+parameterized_test! {can_exchange_sequences_between_routes, (layout, ints, expected), {
+    can_exchange_sequences_between_routes_impl(layout, ints, expected);
+}}
+
+can_exchange_sequences_between_routes! {
+    case01_same_direction: (vec![vec![0, 1, 6, 7], vec![4, 5, 2, 3]], vec![0, 1, 2, 2, 2, 2],
+                            Some(vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c5", "c6", "c7"]])),
+    case02_reversed: (vec![vec![0, 1, 7, 6], vec![4, 5, 2, 3]], vec![0, 1, 2, 2, 2, 2],
+                      Some(vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c5", "c6", "c7"]])),
+    case03_different_sizes: (vec![vec![0, 1, 6, 7], vec![4, 5, 2, 3]], vec![1, 1, 1, 3, 2, 2],
+                             Some(vec![vec!["c0", "c1", "c3", "c2", "c5"], vec!["c4", "c6", "c7"]])),
+    case04_too_short: (vec![vec![0, 1, 6, 7], vec![4, 5, 2, 3]], vec![0, 1, 3], None),
+}
+
+fn can_exchange_sequences_between_routes_impl(
+    layout: Vec<Vec<usize>>,
+    ints: Vec<i32>,
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let insertion_ctx = create_insertion_ctx(layout.as_slice(), ints);
+
+    let result = ExchangeSequence::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .map(|new_insertion_ctx| get_customer_ids_from_routes(&new_insertion_ctx));
+
+    assert_eq!(
+        result,
+        expected.map(|routes| routes.iter().map(|ids| ids.iter().map(|id| id.to_string()).collect()).collect())
+    );
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::IdDimension;
+use std::sync::Arc;
+
+fn create_insertion_ctx(order: &[usize], ints: Vec<i32>) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(order.len(), 1, true);
+
+    let tour = &mut solution.routes[0].tour;
+    let activities = tour.all_activities().skip(1).map(|activity| activity.deep_copy()).collect::<Vec<_>>();
+    activities.iter().for_each(|activity| {
+        tour.remove(&activity.retrieve_job().unwrap());
+    });
+    order.iter().for_each(|idx| {
+        tour.insert_last(activities[*idx].deep_copy());
+    });
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, vec![]))),
+    )
+}
+
+fn explore(operator: &dyn LocalOperator, insertion_ctx: &InsertionContext) -> Option<Vec<Vec<String>>> {
+    operator
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx)
+        .map(|new_insertion_ctx| get_customer_ids_from_routes(&new_insertion_ctx))
+}
+
+parameterized_test! {can_reverse_segment_with_two_opt, (order, seed, max_segment, expected), {
+    can_reverse_segment_with_two_opt_impl(order, seed, max_segment, expected);
+}}
+
+can_reverse_segment_with_two_opt! {
+    case01_two_jobs: (&[0, 2, 1, 3], 1, 2, Some(vec!["c0", "c1", "c2", "c3"])),
+    case02_three_jobs: (&[0, 3, 2, 1], 1, 3, Some(vec!["c0", "c1", "c2", "c3"])),
+    case03_best_of_segments: (&[0, 2, 1, 3], 1, 3, Some(vec!["c0", "c1", "c2", "c3"])),
+    case04_last_job: (&[0, 1, 2, 3], 3, 4, None),
+}
+
+fn can_reverse_segment_with_two_opt_impl(order: &[usize], seed: i32, max_segment: usize, expected: Option<Vec<&str>>) {
+    let insertion_ctx = create_insertion_ctx(order, vec![0, seed]);
+
+    let result = explore(&TwoOptWithinRoute::new(max_segment), &insertion_ctx);
+
+    assert_eq!(result, expected.map(|ids| vec![ids.iter().map(|id| id.to_string()).collect()]));
+}
+
+parameterized_test! {can_relocate_segment_with_or_opt, (order, seed, size, expected), {
+    can_relocate_segment_with_or_opt_impl(order, seed, size, expected);
+}}
+
+can_relocate_segment_with_or_opt! {
+    case01_one_job: (&[0, 3, 1, 2], 1, 1, vec!["c0", "c1", "c2", "c3"]),
+    case02_two_jobs: (&[2, 3, 0, 1], 0, 2, vec!["c0", "c1", "c2", "c3"]),
+    case03_first_job: (&[1, 0, 2, 3], 0, 1, vec!["c0", "c1", "c2", "c3"]),
+}
+
+fn can_relocate_segment_with_or_opt_impl(order: &[usize], seed: i32, size: i32, expected: Vec<&str>) {
+    let insertion_ctx = create_insertion_ctx(order, vec![0, seed, size]);
+
+    let result = explore(&OrOptMove::default(), &insertion_ctx);
+
+    assert_eq!(result, Some(vec![expected.iter().map(|id| id.to_string()).collect()]));
+}
+
+#[test]
+fn can_skip_locked_jobs() {
+    let mut insertion_ctx = create_insertion_ctx(&[0, 2, 1, 3], vec![0, 0, 0, 0, 1]);
+    let locked = insertion_ctx
+        .problem
+        .jobs
+        .all()
+        .filter(|job| ["c0", "c2", "c1"].contains(&job.dimens().get_id().unwrap().as_str()))
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked);
+
+    assert_eq!(explore(&TwoOptWithinRoute::default(), &insertion_ctx), None);
+    assert_eq!(
+        explore(&OrOptMove::default(), &insertion_ctx),
+        Some(vec![vec!["c0".to_string(), "c3".to_string(), "c2".to_string(), "c1".to_string()]])
+    );
+}
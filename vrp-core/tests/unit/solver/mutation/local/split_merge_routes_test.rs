@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::utils::Environment;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+fn create_insertion_ctx(rows: usize, cols: usize, spare_routes: usize) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+
+    let spare = solution.routes.split_off(cols - spare_routes);
+    solution.unassigned.extend(spare.iter().flat_map(|route| route.tour.jobs()).map(|job| (job, 0)));
+    let registry = &mut solution.registry;
+    solution.routes.iter().for_each(|route| {
+        registry.use_actor(&route.actor);
+    });
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment)
+}
+
+fn get_assigned_jobs(insertion_ctx: &InsertionContext) -> HashSet<Job> {
+    insertion_ctx.solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect()
+}
+
+#[test]
+fn can_split_route_using_spare_vehicle() {
+    let insertion_ctx = create_insertion_ctx(4, 2, 1);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = SplitRoute::default().explore(&refinement_ctx, &insertion_ctx).expect("cannot split route");
+
+    assert_eq!(result.solution.routes.len(), 2);
+    assert!(result.solution.routes.iter().all(|route_ctx| route_ctx.route.tour.job_count() >= 2));
+    assert!(get_assigned_jobs(&result).is_superset(&get_assigned_jobs(&insertion_ctx)));
+}
+
+#[test]
+fn cannot_split_route_without_spare_vehicle() {
+    let insertion_ctx = create_insertion_ctx(4, 2, 0);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = SplitRoute::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn can_merge_routes() {
+    let insertion_ctx = create_insertion_ctx(3, 3, 0);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = MergeRoutes::default().explore(&refinement_ctx, &insertion_ctx).expect("cannot merge routes");
+
+    assert_eq!(result.solution.routes.len(), 2);
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.solution.required.is_empty());
+    assert!(get_assigned_jobs(&result) == get_assigned_jobs(&insertion_ctx));
+}
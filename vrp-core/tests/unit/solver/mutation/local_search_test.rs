@@ -0,0 +1,95 @@
+use super::*;
+use crate::construction::heuristics::finalize_insertion_ctx;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::IdDimension;
+
+/// Moves job with given id to the end of the route with given index.
+struct MoveJobOperator {
+    job_id: String,
+    route_idx: usize,
+}
+
+impl LocalOperator for MoveJobOperator {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+        let activity = new_insertion_ctx.solution.routes.iter_mut().find_map(|route_ctx| {
+            let idx = route_ctx.route.tour.all_activities().position(
+                |activity| matches!(activity.retrieve_job(), Some(job) if job.dimens().get_id() == Some(&self.job_id)),
+            )?;
+            let activity = route_ctx.route.tour.get(idx).unwrap().deep_copy();
+            route_ctx.route_mut().tour.remove_activity_at(idx);
+
+            Some(activity)
+        })?;
+
+        new_insertion_ctx.solution.routes[self.route_idx].route_mut().tour.insert_last(activity);
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        Some(new_insertion_ctx)
+    }
+}
+
+fn create_local_search(job_id: &str, route_idx: usize, tenure: Option<usize>) -> LocalSearch {
+    let local_search = LocalSearch::new(Box::new(MoveJobOperator { job_id: job_id.to_string(), route_idx }));
+
+    match tenure {
+        Some(tenure) => local_search.with_tabu(tenure),
+        None => local_search,
+    }
+}
+
+parameterized_test! {can_prevent_reversal_moves_with_tabu, (tenure, generation, expected_ids), {
+    can_prevent_reversal_moves_with_tabu_impl(tenure, generation, expected_ids);
+}}
+
+can_prevent_reversal_moves_with_tabu! {
+    case01_no_tabu: (None, 1, vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]),
+    case02_tabu_move: (Some(2), 1, vec![vec!["c0", "c1"], vec!["c3", "c4", "c5", "c2"]]),
+    case03_tenure_expired: (Some(2), 2, vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]),
+}
+
+fn can_prevent_reversal_moves_with_tabu_impl(tenure: Option<usize>, generation: usize, expected_ids: Vec<Vec<&str>>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, true);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![]))),
+    );
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.population.add(insertion_ctx.deep_copy());
+
+    let forward = create_local_search("c2", 1, tenure);
+    let insertion_ctx = forward.mutate(&refinement_ctx, &insertion_ctx);
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["c0", "c1"], vec!["c3", "c4", "c5", "c2"]]);
+
+    // NOTE reuse the same tabu memory for the reversal move
+    let backward =
+        LocalSearch { operator: Box::new(MoveJobOperator { job_id: "c2".to_string(), route_idx: 0 }), ..forward };
+    refinement_ctx.statistics.generation = generation;
+    let insertion_ctx = backward.mutate(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), expected_ids);
+}
+
+#[test]
+fn can_accept_tabu_move_when_no_best_known() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, true);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![]))),
+    );
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let forward = create_local_search("c2", 1, Some(10));
+    let insertion_ctx = forward.mutate(&refinement_ctx, &insertion_ctx);
+    let backward =
+        LocalSearch { operator: Box::new(MoveJobOperator { job_id: "c2".to_string(), route_idx: 0 }), ..forward };
+    let insertion_ctx = backward.mutate(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]);
+}
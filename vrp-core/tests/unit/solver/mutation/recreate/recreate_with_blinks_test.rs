@@ -1,11 +1,10 @@
-use super::DemandJobSelector;
-use crate::construction::heuristics::JobSelector;
+use super::*;
 use crate::helpers::construction::constraints::create_simple_demand;
 use crate::helpers::construction::heuristics::create_test_insertion_context;
-use crate::helpers::models::problem::test_single_with_simple_demand;
-use crate::helpers::models::solution::create_test_registry;
-use crate::models::common::SingleDimLoad;
-use crate::models::problem::Job;
+use crate::helpers::models::problem::{test_single_with_id, test_single_with_simple_demand};
+use crate::helpers::models::solution::{create_empty_route_ctx, create_test_registry};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
 
 parameterized_test! {can_sort_jobs_by_demand, (demands, is_asc_order, expected), {
         can_sort_jobs_by_demand_impl(demands, is_asc_order, expected);
@@ -31,3 +30,37 @@ fn can_sort_jobs_by_demand_impl(demands: Vec<i32>, is_asc_order: bool, expected:
 
     assert_eq!(result, expected);
 }
+
+fn create_job_with_priority(id: &str, priority: Option<i32>) -> Job {
+    let mut single = test_single_with_id(id);
+    if let Some(priority) = priority {
+        Arc::get_mut(&mut single).unwrap().dimens.set_priority(priority);
+    }
+
+    Job::Single(single)
+}
+
+parameterized_test! {can_modulate_blink_ratio_by_priority, (priority, expected_cost), {
+        can_modulate_blink_ratio_by_priority_impl(priority, expected_cost);
+}}
+
+can_modulate_blink_ratio_by_priority! {
+        case01: (None, 1.),
+        case02: (Some(1), 1.),
+        case03: (Some(2), 2.),
+}
+
+fn can_modulate_blink_ratio_by_priority_impl(priority: Option<i32>, expected_cost: f64) {
+    let mut insertion_ctx = create_test_insertion_context(create_test_registry());
+    insertion_ctx.environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![0.015])));
+    let job = create_job_with_priority("job", priority);
+    let left = InsertionResult::make_success(2., job.clone(), vec![], create_empty_route_ctx());
+    let right = InsertionResult::make_success(1., job, vec![], create_empty_route_ctx());
+
+    let result = BlinkResultSelector::default().select(&insertion_ctx, left, right);
+
+    match result {
+        InsertionResult::Success(success) => assert_eq!(success.cost, expected_cost),
+        InsertionResult::Failure(_) => unreachable!(),
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_id;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::models::common::IdDimension;
+use crate::models::problem::Actor;
+use crate::solver::mutation::RecreateWithCheapest;
+use crate::utils::Environment;
+use std::sync::RwLock;
+
+/// Leaves all required jobs unassigned and counts how many times it was called.
+struct UnassignRecreate {
+    calls: RwLock<usize>,
+}
+
+impl Recreate for UnassignRecreate {
+    fn run(&self, _: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        *self.calls.write().unwrap() += 1;
+
+        let solution = &mut insertion_ctx.solution;
+        solution.unassigned.extend(solution.required.drain(0..).map(|job| (job, 0)));
+
+        insertion_ctx
+    }
+}
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, true);
+
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+
+    let (routes, registry) = (&insertion_ctx.solution.routes, &mut insertion_ctx.solution.registry);
+    routes.iter().for_each(|route_ctx| {
+        registry.use_route(route_ctx);
+    });
+
+    insertion_ctx
+}
+
+fn get_actor_ids(actors: impl Iterator<Item = Arc<Actor>>) -> Vec<String> {
+    let mut ids = actors.map(|actor| actor.vehicle.dimens.get_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_move_jobs_of_smallest_route_to_other_routes() {
+    let mut insertion_ctx = create_insertion_ctx(2, 3);
+    let job = insertion_ctx.solution.routes[1].route.tour.jobs().next().unwrap();
+    insertion_ctx.solution.routes[1].route_mut().tour.remove(&job);
+    insertion_ctx.solution.required.push(job);
+    insertion_ctx.restore();
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result =
+        RouteReduction::new(Arc::new(RecreateWithCheapest::default()), 1).mutate(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 2);
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 6);
+    assert_eq!(
+        get_actor_ids(result.solution.routes.iter().map(|route_ctx| route_ctx.route.actor.clone())),
+        vec!["0", "2"]
+    );
+    assert_eq!(get_actor_ids(result.solution.registry.resources().available()), vec!["1"]);
+}
+
+#[test]
+fn can_skip_routes_with_locked_jobs() {
+    let mut insertion_ctx = create_insertion_ctx(2, 2);
+    let locked = insertion_ctx.solution.routes[0].route.tour.jobs().collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result =
+        RouteReduction::new(Arc::new(RecreateWithCheapest::default()), 1).mutate(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 1);
+    let mut ids = result.solution.routes[0].route.tour.jobs().map(|job| get_customer_id(&job)).collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, vec!["c0", "c1", "c2", "c3"]);
+}
+
+#[test]
+fn can_keep_solution_with_one_route() {
+    let insertion_ctx = create_insertion_ctx(3, 1);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let recreate = Arc::new(UnassignRecreate { calls: RwLock::new(0) });
+
+    let result = RouteReduction::new(recreate.clone(), 3).mutate(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(*recreate.calls.read().unwrap(), 0);
+}
+
+#[test]
+fn can_leave_jobs_unassigned_after_all_attempts() {
+    let insertion_ctx = create_insertion_ctx(2, 2);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let recreate = Arc::new(UnassignRecreate { calls: RwLock::new(0) });
+
+    let result = RouteReduction::new(recreate.clone(), 3).mutate(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(result.solution.unassigned.len(), 2);
+    assert_eq!(*recreate.calls.read().unwrap(), 3);
+}
@@ -2,15 +2,15 @@
 int distribution values:
 1. route index in solution
 2*. job index in selected route tour
-3*. selected algorithm: 1: sequential algorithm(**)
-4*. string removal index(-ies)
+3*. string removal index(-ies)
 double distribution values:
 1. string count
 2*. string size(-s)
+3*. selected algorithm: less than split rate: preserved algorithm(**)
 (*) - specific for each route.
 (**) - calls more int and double distributions:
-    int 5. split start
-    dbl 3. alpha param
+    int 4. split start
+    dbl 4. alpha param
 */
 
 use super::{AdjustedStringRemoval, Ruin};
@@ -26,15 +26,15 @@ parameterized_test! {can_ruin_solution_with_matrix_routes, (matrix, ints, reals,
 }}
 
 can_ruin_solution_with_matrix_routes! {
-    case_01_sequential: ((10, 1), vec![0, 3, 1, 2], vec![1., 5.], vec!["c1", "c2", "c3", "c4", "c5"]),
-    case_02_preserved: ((10, 1), vec![0, 2, 2, 1, 4], vec![1., 5., 0.5, 0.005], vec!["c0", "c1", "c2", "c5", "c6"]),
-    case_03_preserved: ((10, 1), vec![0, 2, 2, 1, 4], vec![1., 5., 0.5, 0.5, 0.005], vec!["c0", "c1", "c2", "c6", "c7"]),
-    case_04_preserved: ((10, 1), vec![0, 2, 2, 3, 4], vec![1., 5., 0.5, 0.5, 0.005], vec!["c2", "c6", "c7", "c8", "c9"]),
-    case_05_sequential: ((5, 3), vec![1, 2, 1, 2], vec![1., 3.], vec!["c6", "c7", "c8"]),
-    case_06_sequential: ((5, 3), vec![0, 2, 1, 2, 1, 3, 2], vec![2., 3., 2.], vec!["c1", "c2", "c3", "c7", "c8"]),
-    case_07_sequential: ((5, 3), vec![1, 1, 1, 2, 1, 2, 1, 2, 1, 2], vec![3., 3., 3., 3.], vec!["c1", "c11", "c12", "c13", "c2", "c3", "c6", "c7", "c8"]),
-    case_08_preserved: ((5, 3), vec![1, 1, 2, 1, 3], vec![1., 3., 0.5], vec!["c5", "c6", "c9"]),
-    case_09_preserved: ((5, 3), vec![1, 3, 2, 1, 3], vec![1., 3., 0.5], vec!["c5", "c6", "c7"]),
+    case_01_sequential: ((10, 1), vec![0, 3, 2], vec![1., 5., 0.9], vec!["c1", "c2", "c3", "c4", "c5"]),
+    case_02_preserved: ((10, 1), vec![0, 2, 1, 4], vec![1., 5., 0.1, 0.5, 0.005], vec!["c0", "c1", "c2", "c5", "c6"]),
+    case_03_preserved: ((10, 1), vec![0, 2, 1, 4], vec![1., 5., 0.1, 0.5, 0.5, 0.005], vec!["c0", "c1", "c2", "c6", "c7"]),
+    case_04_preserved: ((10, 1), vec![0, 2, 3, 4], vec![1., 5., 0.1, 0.5, 0.5, 0.005], vec!["c2", "c6", "c7", "c8", "c9"]),
+    case_05_sequential: ((5, 3), vec![1, 2, 2], vec![1., 3., 0.9], vec!["c6", "c7", "c8"]),
+    case_06_sequential: ((5, 3), vec![0, 2, 2, 3, 2], vec![2., 3., 0.9, 2., 0.9], vec!["c1", "c2", "c3", "c7", "c8"]),
+    case_07_sequential: ((5, 3), vec![1, 1, 2, 2, 2, 1, 2], vec![3., 3., 0.9, 3., 0.9, 3., 0.9], vec!["c1", "c11", "c12", "c13", "c2", "c3", "c6", "c7", "c8"]),
+    case_08_preserved: ((5, 3), vec![1, 1, 1, 3], vec![1., 3., 0.1, 0.5], vec!["c5", "c6", "c9"]),
+    case_09_preserved: ((5, 3), vec![1, 3, 1, 3], vec![1., 3., 0.1, 0.5], vec!["c5", "c6", "c7"]),
 }
 
 fn can_ruin_solution_with_matrix_routes_impl(
@@ -55,3 +55,31 @@ fn can_ruin_solution_with_matrix_routes_impl(
 
     assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
 }
+
+parameterized_test! {can_use_split_rate, (split_rate, expected_ids), {
+    can_use_split_rate_impl(split_rate, expected_ids);
+}}
+
+can_use_split_rate! {
+    case_01_never_split: (0., vec!["c1", "c2", "c3", "c4", "c5"]),
+    case_02_always_split: (1., vec!["c0", "c1", "c2", "c5", "c6"]),
+}
+
+fn can_use_split_rate_impl(split_rate: f64, expected_ids: Vec<&str>) {
+    let (ints, reals) = if split_rate > 0. {
+        (vec![0, 2, 1, 4], vec![1., 5., 0.9, 0.5, 0.005])
+    } else {
+        (vec![0, 3, 2], vec![1., 5., 0.1])
+    };
+    let (problem, solution) = generate_matrix_routes_with_defaults(10, 1, false);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, reals))),
+    );
+
+    let insertion_ctx = AdjustedStringRemoval::new(10, 10, 0.01, split_rate)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
+}
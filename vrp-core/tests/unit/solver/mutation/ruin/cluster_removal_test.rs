@@ -21,14 +21,6 @@ fn create_test_distances() -> Vec<f64> {
     ])
 }
 
-#[test]
-fn can_get_max_curvature() {
-    let values =
-        &[p(0., 0.), p(1., 0.25), p(2., 0.5), p(3., 0.75), p(4., 1.), p(6., 2.), p(7., 4.), p(8., 6.), p(9., 8.)];
-
-    assert_eq!(get_max_curvature(values), 2.);
-}
-
 parameterized_test! {can_estimate_epsilon, (matrix, nth_neighbor, matrix_modify, expected), {
     can_estimate_epsilon_impl(matrix, nth_neighbor, matrix_modify, expected);
 }}
@@ -0,0 +1,67 @@
+use super::*;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::models::problem::test_single_with_id_and_location;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::{DemandDimension, IdDimension, Location};
+use crate::models::problem::{Job, Single};
+use std::sync::Arc;
+
+fn create_job_with_demand(id: &str, location: Option<Location>) -> Arc<Single> {
+    let mut single = test_single_with_id_and_location(id, location);
+    let demand = if id == "c5" { 10 } else { 1 };
+    Arc::get_mut(&mut single).unwrap().dimens.set_demand(create_simple_demand(demand));
+
+    single
+}
+
+fn create_insertion_ctx(ints: Vec<i32>, reals: Vec<f64>) -> InsertionContext {
+    let (problem, solution) =
+        generate_matrix_routes(5, 2, false, create_job_with_demand, |v| v, |data| (data.clone(), data));
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, reals))),
+    )
+}
+
+fn find_job(insertion_ctx: &InsertionContext, id: &str) -> Job {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+        .find(|job| job.dimens().get_id().unwrap() == id)
+        .unwrap()
+}
+
+#[test]
+fn can_remove_job_with_highest_demand() {
+    let insertion_ctx = create_insertion_ctx(vec![1], vec![0.5; 10]);
+
+    let insertion_ctx = DemandJobRemoval::new(JobRemovalLimit::new(1, 1, 1.))
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), vec!["c5"]);
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        9
+    );
+}
+
+#[test]
+fn can_skip_locked_jobs() {
+    let mut insertion_ctx = create_insertion_ctx(vec![2], vec![0.5; 9 + 8]);
+    let locked = find_job(&insertion_ctx, "c5");
+    insertion_ctx.solution.locked.insert(locked);
+
+    let insertion_ctx = DemandJobRemoval::new(JobRemovalLimit::new(2, 2, 1.))
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    let removed = get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required);
+    assert_eq!(removed.len(), 2);
+    assert!(!removed.contains(&"c5".to_string()));
+}
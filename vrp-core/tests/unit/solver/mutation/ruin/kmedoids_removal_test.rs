@@ -0,0 +1,111 @@
+use super::*;
+use crate::helpers::algorithms::p;
+use crate::helpers::models::domain::create_empty_problem;
+use crate::helpers::models::problem::test_single_with_id_and_location;
+use crate::helpers::solver::*;
+use crate::helpers::utils::random::FakeRandom;
+use crate::utils::Environment;
+use std::sync::Arc;
+
+fn create_test_problem() -> (Problem, crate::models::Solution) {
+    generate_matrix_routes(
+        8,
+        1,
+        false,
+        test_single_with_id_and_location,
+        |v| v,
+        |_| {
+            let distances = generate_matrix_distances_from_points(&[
+                p(0., 0.),
+                p(5., 5.),
+                p(0., 10.),
+                p(5., 15.),
+                p(100., 0.),
+                p(105., 5.),
+                p(105., 10.),
+                p(100., 15.),
+            ]);
+            (vec![0.; 64], distances)
+        },
+    )
+}
+
+parameterized_test! {can_create_job_regions, (regions, expected), {
+    can_create_job_regions_impl(regions, expected);
+}}
+
+can_create_job_regions! {
+    case_01: (2..3, vec![2]),
+    case_02: (2..4, vec![2, 3]),
+    case_03: (1..3, vec![2]),
+    case_04: (4..20, vec![4, 5, 6, 7]),
+}
+
+fn can_create_job_regions_impl(regions: Range<usize>, expected: Vec<usize>) {
+    let (problem, _) = create_test_problem();
+    let environment = Environment::default();
+
+    let removal = KMedoidsRemoval::new(Arc::new(problem), environment.random, regions, JobRemovalLimit::default());
+
+    assert_eq!(removal.regions.iter().map(|regions| regions.len()).collect::<Vec<_>>(), expected);
+    assert!(removal.regions.iter().all(|regions| regions.iter().map(|region| region.len()).sum::<usize>() == 8));
+}
+
+#[test]
+fn can_split_separated_jobs() {
+    let (problem, _) = create_test_problem();
+    let random = Arc::new(FakeRandom::new(vec![0], vec![1000.]));
+
+    let removal = KMedoidsRemoval::new(Arc::new(problem), random, 2..3, JobRemovalLimit::default());
+
+    let mut regions = removal.regions[0]
+        .iter()
+        .map(|region| {
+            let mut region =
+                region.iter().map(|job| job.as_single().unwrap().places[0].location.unwrap()).collect::<Vec<_>>();
+            region.sort();
+            region
+        })
+        .collect::<Vec<_>>();
+    regions.sort();
+
+    assert_eq!(regions, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+}
+
+#[test]
+fn can_handle_empty_problem() {
+    let problem = create_empty_problem();
+    let environment = Environment::default();
+
+    let removal = KMedoidsRemoval::new(problem, environment.random, 2..4, JobRemovalLimit::default());
+
+    assert!(removal.regions.is_empty());
+}
+
+parameterized_test! {can_ruin_jobs, (limit, expected), {
+    can_ruin_jobs_impl(limit, expected);
+}}
+
+can_ruin_jobs! {
+    case_01: (3, 3),
+    case_02: (4, 4),
+    case_03: (6, 6),
+}
+
+fn can_ruin_jobs_impl(limit: usize, expected: usize) {
+    let limit = JobRemovalLimit::new(limit, limit, 1.);
+    let (problem, solution) = create_test_problem();
+    let problem = Arc::new(problem);
+    let environment = Arc::new(Environment::default());
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+
+    let insertion_ctx = KMedoidsRemoval::new(problem, environment.random.clone(), 2..3, limit)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 0);
+    assert_eq!(insertion_ctx.solution.required.len(), expected);
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route| route.route.tour.job_count()).sum::<usize>(),
+        8 - expected
+    );
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use super::{Ruin, WorstJobRemoval};
 use crate::construction::heuristics::InsertionContext;
-use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::models::domain::{get_customer_id, get_sorted_customer_ids_from_jobs};
 use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
 use crate::helpers::utils::create_test_environment_with_random;
 use crate::helpers::utils::random::FakeRandom;
@@ -32,3 +32,29 @@ fn can_ruin_solution_with_matrix_routes_impl(matrix: (usize, usize), ints: Vec<i
 
     assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected_ids);
 }
+
+#[test]
+fn can_skip_locked_jobs() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let mut insertion_ctx: InsertionContext = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![32, 0, 2, 0, 2, 0, 2], vec![]))),
+    );
+    let locked = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+        .filter(|job| ["c3", "c4"].contains(&get_customer_id(job).as_str()))
+        .collect::<Vec<_>>();
+    insertion_ctx.solution.locked.extend(locked);
+
+    let insertion_ctx = WorstJobRemoval::new(4, JobRemovalLimit::new(1, 32, 1.))
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    let removed = get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required);
+    assert!(!removed.is_empty());
+    assert!(!removed.contains(&"c3".to_string()));
+    assert!(!removed.contains(&"c4".to_string()));
+}
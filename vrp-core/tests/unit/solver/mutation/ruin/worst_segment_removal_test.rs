@@ -0,0 +1,72 @@
+use super::*;
+use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::models::problem::test_single_with_id_and_location;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::IdDimension;
+use std::sync::Arc;
+
+fn create_insertion_ctx_with_detour(detour_location: usize, ints: Vec<i32>) -> InsertionContext {
+    let (rows, cols) = (5, 2);
+    let size = rows * cols;
+    let add_detour = |data: Vec<f64>| {
+        data.into_iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                let (from, to) = (idx / size, idx % size);
+                if from != to && (from == detour_location || to == detour_location) {
+                    value + 1000.
+                } else {
+                    value
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let (problem, solution) = generate_matrix_routes(
+        rows,
+        cols,
+        false,
+        test_single_with_id_and_location,
+        |v| v,
+        |data| (add_detour(data.clone()), add_detour(data)),
+    );
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, vec![]))),
+    )
+}
+
+#[test]
+fn can_remove_segment_with_worst_detour() {
+    let insertion_ctx = create_insertion_ctx_with_detour(2, vec![1, 0]);
+
+    let insertion_ctx = WorstSegmentRemoval::new((1, 1), 0, JobRemovalLimit::new(1, 1, 1.))
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), vec!["c2"]);
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.job_count(), 4);
+}
+
+#[test]
+fn can_skip_segments_with_locked_jobs() {
+    let mut insertion_ctx = create_insertion_ctx_with_detour(2, vec![1, 0]);
+    let locked = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+        .find(|job| job.dimens().get_id().unwrap() == "c2")
+        .unwrap();
+    insertion_ctx.solution.locked.insert(locked);
+
+    let insertion_ctx = WorstSegmentRemoval::new((1, 1), 0, JobRemovalLimit::new(1, 1, 1.))
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    let removed = get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required);
+    assert_eq!(removed.len(), 1);
+    assert_ne!(removed, vec!["c2"]);
+}
@@ -0,0 +1,61 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::solver::hyper::StaticSelective;
+use crate::utils::Environment;
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+    let mut insertion_ctx = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+#[test]
+fn can_create_temporal_slices() {
+    let insertion_ctx = create_insertion_ctx(5, 3);
+
+    let slices = create_temporal_slices(&insertion_ctx, 2);
+
+    assert_eq!(slices.len(), 2);
+    assert_eq!(slices[0].len(), 8);
+    assert_eq!(slices[1].len(), 7);
+    assert!(slices[0].intersection(&slices[1]).next().is_none());
+    let arrival = |job: &Job| {
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+            .filter(|activity| activity.retrieve_job().as_ref() == Some(job))
+            .map(|activity| activity.schedule.arrival)
+            .next()
+            .unwrap()
+    };
+    let latest_first = slices[0].iter().map(arrival).fold(f64::MIN, f64::max);
+    let earliest_second = slices[1].iter().map(arrival).fold(f64::MAX, f64::min);
+    assert!(latest_first <= earliest_second);
+}
+
+#[test]
+fn can_mutate_without_losing_jobs() {
+    let insertion_ctx = create_insertion_ctx(5, 3);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let mutation = TemporalDecomposeSearch::new(
+        StaticSelective::create_default_mutation(insertion_ctx.problem.clone()),
+        (2, 3),
+        5,
+    );
+
+    let result = mutation.mutate(&refinement_ctx, &insertion_ctx);
+
+    let solution = &result.solution;
+    assert!(solution.unassigned.is_empty());
+    assert!(solution.locked.is_empty());
+    assert_eq!(
+        solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<HashSet<_>>().len(),
+        15
+    );
+    assert_ne!(refinement_ctx.problem.objective.total_order(&result, &insertion_ctx), Ordering::Greater);
+}
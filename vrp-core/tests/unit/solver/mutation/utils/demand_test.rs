@@ -0,0 +1,51 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::models::common::{Demand, SingleDimLoad};
+use crate::models::problem::Single;
+use std::sync::Arc;
+
+fn create_single_with_demand(demand: Option<bool>) -> Single {
+    let mut single = test_single();
+    match demand {
+        Some(true) => {
+            let load = MultiDimLoad::new(vec![1, 2]);
+            single.dimens.set_demand(Demand::<MultiDimLoad> {
+                pickup: (MultiDimLoad::default(), MultiDimLoad::default()),
+                delivery: (load, MultiDimLoad::default()),
+            });
+        }
+        Some(false) => {
+            single.dimens.set_demand(Demand::<SingleDimLoad> {
+                pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+                delivery: (SingleDimLoad::new(1), SingleDimLoad::default()),
+            });
+        }
+        None => {}
+    }
+
+    single
+}
+
+parameterized_test! {can_detect_multi_dim_demand, (demands, expected), {
+    let jobs = demands.into_iter().map(|demand| Job::Single(Arc::new(create_single_with_demand(demand)))).collect();
+    let problem = create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), jobs, test_fleet());
+
+    assert_eq!(has_multi_dim_demand(problem.as_ref()), expected);
+}}
+
+can_detect_multi_dim_demand! {
+    case01_single_dim: (vec![Some(false), Some(false)], false),
+    case02_multi_dim: (vec![Some(true), Some(true)], true),
+    case03_no_demand: (vec![None], false),
+    case04_mixed_no_demand: (vec![None, Some(true)], true),
+}
+
+#[test]
+fn can_detect_multi_dim_demand_in_multi_job() {
+    let job = MultiBuilder::default().job(create_single_with_demand(Some(true))).build();
+    let problem = create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), vec![job], test_fleet());
+
+    assert!(has_multi_dim_demand(problem.as_ref()));
+}
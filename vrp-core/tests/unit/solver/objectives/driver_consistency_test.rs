@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::*;
+
+fn create_previous_func() -> PreviousAssignmentFunc {
+    Arc::new(|job| match get_job_id(job).as_str() {
+        "job1" | "job2" => Some("v1".to_string()),
+        "job3" => Some("v2".to_string()),
+        _ => None,
+    })
+}
+
+fn create_route_ctx(vehicle_id: &str, job_ids: Vec<&str>) -> RouteContext {
+    create_test_route_ctx(
+        vehicle_id,
+        job_ids.into_iter().map(|id| test_activity_with_job(test_single_with_id(id))).collect(),
+    )
+}
+
+fn get_job(id: &str) -> Job {
+    Job::Single(test_single_with_id(id))
+}
+
+parameterized_test! {can_detect_changed_assignment, (vehicle_id, job_id, expected), {
+    let route_ctx = create_route_ctx(vehicle_id, vec![]);
+    let consistency = DriverConsistency { previous_func: create_previous_func() };
+
+    assert_eq!(consistency.is_changed(route_ctx.route.actor.as_ref(), &get_job(job_id)), expected);
+}}
+
+can_detect_changed_assignment! {
+    case01_same_vehicle: ("v1", "job1", false),
+    case02_other_vehicle: ("v2", "job1", true),
+    case03_no_previous: ("v2", "job4", false),
+}
+
+#[test]
+fn can_estimate_job_insertion() {
+    let (module, _) = DriverConsistency::new_with_constraint(create_previous_func(), 10.);
+    let route_ctx = create_route_ctx("v1", vec![]);
+
+    let costs = vec!["job1", "job3", "job4"]
+        .into_iter()
+        .map(|id| estimate_soft_route_job(&module, &route_ctx, &get_job(id)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(costs, vec![0., 10., 0.]);
+}
+
+#[test]
+fn can_count_changed_assignments_in_route_state() {
+    let (module, _) = DriverConsistency::new_with_constraint(create_previous_func(), 10.);
+    let mut route_ctx = create_route_ctx("v2", vec!["job1", "job2", "job3", "job4"]);
+
+    module.accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(DRIVER_CONSISTENCY_KEY).cloned(), Some(2.));
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let (_, objective) = DriverConsistency::new_with_constraint(create_previous_func(), 10.);
+    let routes =
+        vec![create_route_ctx("v1", vec!["job1", "job3"]), create_route_ctx("v2", vec!["job2", "job3", "job4"])];
+
+    assert_eq!(get_fitness_with_routes(&objective, routes), 2.);
+}
@@ -0,0 +1,61 @@
+use super::*;
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeWindow};
+use crate::models::solution::Activity;
+
+fn round(value: f64) -> f64 {
+    (value * 1000.).round() / 1000.
+}
+
+fn create_terminal_activity(location: Location) -> Activity {
+    let mut activity = test_activity_with_location_and_tw(location, TimeWindow::new(0., 1000.));
+    activity.job = None;
+    activity
+}
+
+fn create_test_route_ctx() -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route = create_route_with_start_end_activities(
+        &fleet,
+        "v1",
+        create_terminal_activity(0),
+        create_terminal_activity(30),
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(0., 10.)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(0., 30.)),
+        ],
+    );
+
+    RouteContext::new_with_state(Arc::new(route), Arc::new(Default::default()))
+}
+
+fn create_lateness_risk(deviation: Duration, percentile: f64) -> LatenessRisk {
+    LatenessRisk { deviation_func: Arc::new(move |_, _, _| deviation), percentile }
+}
+
+parameterized_test! {can_get_on_time_probabilities, (deviation, percentile, expected), {
+    let route_ctx = create_test_route_ctx();
+
+    let probabilities = create_lateness_risk(deviation, percentile).get_on_time_probabilities(&route_ctx.route);
+
+    assert_eq!(probabilities.into_iter().map(round).collect::<Vec<_>>(), expected);
+}}
+
+can_get_on_time_probabilities! {
+    case01_no_deviation: (0., 0.5, vec![1., 1., 1.]),
+    case02_mean_durations: (5., 0.5, vec![0.5, 0.921, 1.]),
+    case03_percentile_durations: (5., 0.9, vec![0.9, 0.999, 1.]),
+}
+
+#[test]
+fn can_keep_route_lateness_risk_in_state() {
+    let (module, _) = LatenessRisk::new_with_constraint(Arc::new(|_, _, _| 5.), 0.5);
+    let mut route_ctx = create_test_route_ctx();
+
+    module.accept_route_state(&mut route_ctx);
+
+    let risk = route_ctx.state.get_route_state::<f64>(LATENESS_RISK_KEY).cloned().unwrap();
+    assert_eq!(round(risk), 0.579);
+}
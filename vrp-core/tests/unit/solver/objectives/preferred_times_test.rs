@@ -0,0 +1,82 @@
+use super::*;
+use crate::construction::heuristics::RouteContext;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::*;
+use crate::models::common::Location;
+
+const PREFERRED_TIMES: &[TimeWindow] = &[TimeWindow { start: 10., end: 20. }];
+
+fn get_preferred_times(activity: &Activity) -> Option<&[TimeWindow]> {
+    if activity.job.is_some() && activity.place.location < 100 {
+        Some(PREFERRED_TIMES)
+    } else {
+        None
+    }
+}
+
+fn create_route_ctx(locations: Vec<Location>) -> RouteContext {
+    create_test_route_ctx("v1", locations.into_iter().map(test_activity_with_location).collect())
+}
+
+parameterized_test! {can_detect_preferred_time_hit, (location, arrival, expected), {
+    let preferences = PreferredTimes { preferred_func: Arc::new(get_preferred_times) };
+
+    assert_eq!(preferences.is_hit(&test_activity_with_location(location), arrival), expected);
+}}
+
+can_detect_preferred_time_hit! {
+    case01_before: (5, 5., Some(false)),
+    case02_inside: (5, 15., Some(true)),
+    case03_after: (5, 25., Some(false)),
+    case04_no_preferences: (150, 5., None),
+}
+
+#[test]
+fn can_count_route_hits() {
+    let preferences = PreferredTimes { preferred_func: Arc::new(get_preferred_times) };
+
+    let hits = preferences.get_route_hits(&create_route_ctx(vec![5, 15, 150]).route);
+
+    assert_eq!(hits, (2, 1));
+}
+
+parameterized_test! {can_estimate_activity_insertion, (target, next, expected), {
+    let (module, _) =
+        PreferredTimes::new_with_constraint(TestTransportCost::new_shared(), Arc::new(get_preferred_times), 10.);
+    let route_ctx = create_route_ctx(vec![]);
+    let next = next.map(test_activity_with_location);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &test_activity_without_job(),
+        target: &test_activity_with_location(target),
+        next: next.as_ref(),
+    };
+
+    let cost = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::SoftActivity(constraint) => Some(constraint.estimate_activity(&route_ctx, &activity_ctx)),
+            _ => None,
+        })
+        .sum::<f64>();
+
+    assert_eq!(cost, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_target_hit: (15, None, 0.),
+    case02_target_miss: (5, None, 10.),
+    case03_no_preferences: (150, None, 0.),
+    case04_next_moved_out: (30, Some(20), 20.),
+    case05_next_moved_in: (10, Some(5), -10.),
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let (_, objective) =
+        PreferredTimes::new_with_constraint(TestTransportCost::new_shared(), Arc::new(get_preferred_times), 10.);
+    let routes = vec![create_route_ctx(vec![5, 15, 150]), create_route_ctx(vec![12, 25])];
+
+    assert_eq!(get_fitness_with_routes(&objective, routes), 2.);
+}
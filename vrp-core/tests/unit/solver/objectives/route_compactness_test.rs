@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::*;
+
+fn create_point_func() -> LocationPointFunc {
+    Arc::new(|location| Some(Point::new((location % 10) as f64, (location / 10) as f64)))
+}
+
+fn create_route_ctx(locations: Vec<Location>) -> RouteContext {
+    create_test_route_ctx("v1", locations.into_iter().map(test_activity_with_location).collect())
+}
+
+parameterized_test! {can_calculate_route_area, (locations, expected), {
+    let compactness = RouteCompactness { point_func: create_point_func() };
+
+    assert_eq!(compactness.get_route_area(&create_route_ctx(locations).route), expected);
+}}
+
+can_calculate_route_area! {
+    case01_empty: (vec![], 0.),
+    case02_line: (vec![5], 0.),
+    case03_square: (vec![5, 55, 50], 25.),
+    case04_triangle: (vec![5, 50], 12.5),
+}
+
+parameterized_test! {can_estimate_job_insertion, (location, expected), {
+    let (module, _) = RouteCompactness::new_with_constraint(create_point_func(), 2.);
+    let mut route_ctx = create_route_ctx(vec![5, 50]);
+    module.accept_route_state(&mut route_ctx);
+    let job = Job::Single(test_single_with_location(Some(location)));
+
+    let cost = estimate_soft_route_job(&module, &route_ctx, &job);
+
+    assert_eq!(cost, expected);
+}}
+
+can_estimate_job_insertion! {
+    case01_outside: (55, 25.),
+    case02_inside: (11, 0.),
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let (_, objective) = RouteCompactness::new_with_constraint(create_point_func(), 1.);
+    let routes = vec![create_route_ctx(vec![5, 55, 50]), create_route_ctx(vec![5, 50])];
+
+    assert_eq!(get_fitness_with_routes(&objective, routes), 37.5);
+}
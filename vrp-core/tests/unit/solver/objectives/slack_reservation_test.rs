@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::*;
+use crate::models::common::SingleDimLoad;
+
+fn create_route_ctx(demands: Vec<i32>, end_arrival: f64) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(10).build())
+        .build();
+    let activities = demands
+        .into_iter()
+        .map(|demand| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(demand))))
+        .collect();
+
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let last_idx = route_ctx.route.tour.total() - 1;
+    route_ctx.route_mut().tour.get_mut(last_idx).unwrap().schedule.arrival = end_arrival;
+    CapacityConstraintModule::<SingleDimLoad>::new(2).accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_calculate_route_deficit, (demands, end_arrival, capacity_slack, time_slack, expected), {
+    let reservation = SlackReservation::new::<SingleDimLoad>(capacity_slack, time_slack);
+
+    let deficit = reservation.get_route_deficit(&create_route_ctx(demands, end_arrival));
+
+    assert!((deficit - expected).abs() < 1E-6);
+}}
+
+can_calculate_route_deficit! {
+    case01_enough_slack: (vec![-3, -3], 500., 0.2, 0.2, 0.),
+    case02_capacity_deficit: (vec![-5, -4], 500., 0.2, 0.2, 0.1),
+    case03_time_deficit: (vec![-3, -3], 950., 0.2, 0.1, 0.05),
+    case04_both_deficits: (vec![-5, -4], 950., 0.2, 0.1, 0.15),
+    case05_no_slack: (vec![-5, -5], 1000., 0., 0., 0.),
+}
+
+parameterized_test! {can_estimate_job_insertion, (route_demands, demand, expected), {
+    let (module, _) = SlackReservation::new_with_constraint::<SingleDimLoad>(0.2, 0., 100.);
+    let route_ctx = create_route_ctx(route_demands, 500.);
+    let job = Job::Single(test_single_with_simple_demand(create_simple_demand(demand)));
+
+    let cost = estimate_soft_route_job(&module, &route_ctx, &job);
+
+    assert!((cost - expected).abs() < 1E-6);
+}}
+
+can_estimate_job_insertion! {
+    case01_fits: (vec![-5], -2, 0.),
+    case02_exceeds: (vec![-5], -4, 10.),
+    case03_increases_existing_deficit: (vec![-9], -1, 10.),
+    case04_keeps_existing_deficit: (vec![-9], 0, 0.),
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let (_, objective) = SlackReservation::new_with_constraint::<SingleDimLoad>(0.2, 0., 100.);
+    let routes = vec![create_route_ctx(vec![-5, -4], 500.), create_route_ctx(vec![-10], 500.)];
+
+    assert!((get_fitness_with_routes(&objective, routes) - 0.3).abs() < 1E-6);
+}
@@ -0,0 +1,57 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+
+fn create_job_with_priority(priority: Option<i32>) -> Job {
+    let mut single = test_single();
+    if let Some(priority) = priority {
+        single.dimens.set_priority(priority);
+    }
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_insertion_ctx_with_unassigned(jobs: &[Job], unassigned: &[usize]) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.problem =
+        create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), jobs.to_vec(), test_fleet());
+    unassigned.iter().for_each(|idx| {
+        insertion_ctx.solution.unassigned.insert(jobs[*idx].clone(), 0);
+    });
+
+    insertion_ctx
+}
+
+parameterized_test! {can_estimate_unassigned_job_by_priority, (priority, expected), {
+    let jobs = vec![create_job_with_priority(priority), create_job_with_priority(None), create_job_with_priority(None)];
+    let insertion_ctx = create_insertion_ctx_with_unassigned(jobs.as_slice(), &[]);
+
+    let result = get_priority_weight(&insertion_ctx, &jobs[0]);
+
+    assert_eq!(result, expected);
+}}
+
+can_estimate_unassigned_job_by_priority! {
+    case01_no_priority: (None, 1.),
+    case02_highest_priority: (Some(1), 1.),
+    case03_second_priority: (Some(2), 0.25),
+    case04_third_priority: (Some(3), 0.0625),
+}
+
+#[test]
+fn can_prefer_unassigned_jobs_with_lower_priority() {
+    let jobs = vec![
+        create_job_with_priority(Some(1)),
+        create_job_with_priority(Some(2)),
+        create_job_with_priority(Some(2)),
+        create_job_with_priority(Some(2)),
+        create_job_with_priority(Some(3)),
+    ];
+    let objective = TotalUnassignedJobs::default();
+
+    let high_unassigned = create_insertion_ctx_with_unassigned(jobs.as_slice(), &[0]);
+    let low_unassigned = create_insertion_ctx_with_unassigned(jobs.as_slice(), &[1, 2, 3, 4]);
+
+    assert_eq!(objective.total_order(&high_unassigned, &low_unassigned), Ordering::Greater);
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+use crate::models::common::Location;
+use crate::models::solution::Activity;
+
+fn create_value_pair(values: (f64, f64)) -> (TargetConstraint, TargetObjective) {
+    TotalValue::maximize(
+        Arc::new(move |job| match job.dimens().get_id().map(|id| id.as_str()) {
+            Some("job1") => values.0,
+            Some("job2") => values.1,
+            _ => 0.,
+        }),
+        TestTransportCost::new_shared(),
+        Arc::new(TestActivityCost::default()),
+        1,
+    )
+}
+
+fn create_activity(id: &str, location: Location) -> Activity {
+    let mut activity = test_activity_with_location(location);
+    activity.job = Some(test_single_with_id_and_location(id, Some(location)));
+
+    activity
+}
+
+fn create_route_ctx() -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    create_route_context_with_activities(&fleet, "v1", vec![create_activity("job1", 5), create_activity("job2", 10)])
+}
+
+parameterized_test! {can_remove_unprofitable_jobs, (values, expected), {
+    can_remove_unprofitable_jobs_impl(values, expected);
+}}
+
+can_remove_unprofitable_jobs! {
+    case01_all_profitable: ((10., 50.), vec![]),
+    case02_last_unprofitable: ((50., 30.), vec!["job2"]),
+    case03_all_unprofitable: ((10., 30.), vec!["job1", "job2"]),
+    case04_no_values: ((0., 0.), vec![]),
+}
+
+fn can_remove_unprofitable_jobs_impl(values: (f64, f64), expected: Vec<&str>) {
+    let (constraint, _) = create_value_pair(values);
+    let pipeline = create_constraint_pipeline_with_module(constraint);
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_ctx());
+
+    pipeline.accept_solution_state(&mut solution_ctx);
+
+    let mut unassigned = solution_ctx
+        .unassigned
+        .iter()
+        .map(|(job, code)| (job.dimens().get_id().unwrap().clone(), *code))
+        .collect::<Vec<_>>();
+    unassigned.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assert_eq!(unassigned, expected.iter().map(|id| (id.to_string(), 1)).collect::<Vec<_>>());
+    assert_eq!(solution_ctx.routes[0].route.tour.job_count(), 2 - expected.len());
+}
+
+#[test]
+fn can_estimate_job_insertion_by_its_value() {
+    let (constraint, _) = create_value_pair((10., 50.));
+    let pipeline = create_constraint_pipeline_with_module(constraint);
+    let job = Job::Single(test_single_with_id("job2"));
+
+    let result = pipeline.evaluate_soft_route(&create_empty_solution_context(), &create_route_ctx(), &job);
+
+    assert_eq!(result, -50.);
+}
+
+#[test]
+fn can_calculate_fitness_as_negative_total_value() {
+    let (_, objective) = create_value_pair((10., 50.));
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx());
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, -60.);
+}
@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(job_ids: Vec<&str>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = job_ids.into_iter().map(|id| test_activity_with_job(test_single_with_id(id))).collect::<Vec<_>>();
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", activities)),
+        Arc::new(Default::default()),
+    )
+}
+
+fn create_total_violations() -> TotalViolations {
+    TotalViolations::new(vec![
+        RelaxableConstraint {
+            code: 1,
+            weight: 10.,
+            violation_func: Arc::new(|route_ctx| route_ctx.route.tour.job_count()),
+        },
+        RelaxableConstraint {
+            code: 2,
+            weight: 100.,
+            violation_func: Arc::new(|route_ctx| if route_ctx.route.tour.job_count() > 2 { 1 } else { 0 }),
+        },
+    ])
+}
+
+parameterized_test! {can_get_route_violations, (job_ids, expected), {
+    let violations = create_total_violations().get_route_violations(&create_route_ctx(job_ids));
+
+    assert_eq!(violations, expected);
+}}
+
+can_get_route_violations! {
+    case01_no_violations: (vec![], vec![]),
+    case02_one_constraint: (vec!["job1", "job2"], vec![(1, 2)]),
+    case03_two_constraints: (vec!["job1", "job2", "job3"], vec![(1, 3), (2, 1)]),
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let objective = create_total_violations();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(vec!["job1"]));
+    insertion_ctx.solution.routes.push(create_route_ctx(vec!["job2", "job3", "job4"]));
+
+    assert_eq!(objective.fitness(&insertion_ctx), 10. + 30. + 100.);
+}
+
+#[test]
+fn can_change_weights_at_runtime() {
+    let objective = create_total_violations();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(vec!["job1", "job2", "job3"]));
+
+    objective.weights().set(2, 200.).unwrap();
+
+    assert_eq!(objective.clone().fitness(&insertion_ctx), 30. + 200.);
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::utils::Environment;
+
+fn create_insertion_ctx(is_balanced: bool) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, true);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+
+    if !is_balanced {
+        let activity = insertion_ctx.solution.routes[1].route.tour.get(1).unwrap().deep_copy();
+        insertion_ctx.solution.routes[1].route_mut().tour.remove(&activity.retrieve_job().unwrap());
+        insertion_ctx.solution.routes[0].route_mut().tour.insert_last(activity);
+    }
+
+    insertion_ctx
+}
+
+parameterized_test! {can_compare_activity_balance_with_threshold_and_tolerance, (threshold, tolerance, expected), {
+    can_compare_activity_balance_with_threshold_and_tolerance_impl(threshold, tolerance, expected);
+}}
+
+can_compare_activity_balance_with_threshold_and_tolerance! {
+    case01_no_options: (None, None, Less),
+    case02_below_threshold: (Some(0.6), None, Equal),
+    case03_above_threshold: (Some(0.4), None, Less),
+    case04_within_tolerance: (None, Some(0.6), Equal),
+    case05_outside_tolerance: (None, Some(0.4), Less),
+}
+
+fn can_compare_activity_balance_with_threshold_and_tolerance_impl(
+    threshold: Option<f64>,
+    tolerance: Option<f64>,
+    expected: Ordering,
+) {
+    let (_, objective) = WorkBalance::new_activity_balanced(threshold, tolerance);
+    let balanced = create_insertion_ctx(true);
+    let unbalanced = create_insertion_ctx(false);
+
+    assert_eq!(objective.fitness(&balanced), 0.);
+    assert_eq!(objective.fitness(&unbalanced), 0.5);
+    assert_eq!(objective.total_order(&balanced, &unbalanced), expected);
+    assert_eq!(objective.total_order(&unbalanced, &balanced), expected.reverse());
+}
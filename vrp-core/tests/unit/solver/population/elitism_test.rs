@@ -128,3 +128,35 @@ fn can_select_individuals() {
 
     assert_eq!(parents.len(), 3);
 }
+
+#[test]
+fn can_shrink_population() {
+    let problem = create_example_problem();
+    let mut population = Elitism::new(problem.clone(), create_random(), 4, 1);
+    population.add_all(vec![
+        create_simple_insertion_ctx(100., 0),
+        create_simple_insertion_ctx(200., 0),
+        create_simple_insertion_ctx(300., 0),
+        create_simple_insertion_ctx(50., 0),
+    ]);
+
+    population.shrink(2);
+    assert_eq!(get_all_fitness(&population), &[50., 100.]);
+
+    population.add(create_simple_insertion_ctx(70., 0));
+    assert_eq!(get_all_fitness(&population), &[50., 70.]);
+
+    population.shrink(0);
+    assert_eq!(get_all_fitness(&population), &[50.]);
+}
+
+#[test]
+fn can_replace_all_individuals() {
+    let problem = create_example_problem();
+    let mut population = Elitism::new(problem.clone(), create_random(), 4, 1);
+    population.add_all(vec![create_simple_insertion_ctx(100., 0), create_simple_insertion_ctx(200., 0)]);
+
+    population.replace_all(vec![create_simple_insertion_ctx(300., 0), create_simple_insertion_ctx(150., 0)]);
+
+    assert_eq!(get_all_fitness(&population), &[150., 300.]);
+}
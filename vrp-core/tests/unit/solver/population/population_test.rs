@@ -0,0 +1,25 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+
+parameterized_test! {can_get_default_population_depending_on_problem_size, (jobs, is_rosomaxa_expected), {
+    can_get_default_population_depending_on_problem_size_impl(jobs, is_rosomaxa_expected);
+}}
+
+can_get_default_population_depending_on_problem_size! {
+    case01_small: (10, false),
+    case02_below_threshold: (49, false),
+    case03_large: (50, true),
+}
+
+fn can_get_default_population_depending_on_problem_size_impl(jobs: usize, is_rosomaxa_expected: bool) {
+    let environment = Arc::new(Environment::default());
+    let is_single_thread = get_default_selection_size(environment.as_ref()) == 1;
+    let (problem, _) = generate_matrix_routes_with_defaults(jobs, 1, false);
+
+    let population = get_default_population(Arc::new(problem), environment);
+
+    // NOTE elitism population is always in exploitation phase, rosomaxa starts from initial one
+    let expected =
+        if is_rosomaxa_expected && !is_single_thread { SelectionPhase::Initial } else { SelectionPhase::Exploitation };
+    assert_eq!(population.selection_phase(), expected);
+}
@@ -58,3 +58,24 @@ fn can_optimize_network() {
 
     assert_eq!(get_network(&rosomaxa).get_nodes().count(), 1);
 }
+
+#[test]
+fn can_export_network_state() {
+    let exported = Arc::new(std::sync::RwLock::new(vec![]));
+    let mut config = RosomaxaConfig::new_with_defaults(4);
+    config.network_export = Some((
+        2,
+        Arc::new({
+            let exported = exported.clone();
+            move |generation, state| exported.write().unwrap().push((generation, state.nodes.len()))
+        }),
+    ));
+    let mut rosomaxa = Rosomaxa::new(create_empty_problem(), Arc::new(Environment::default()), config).unwrap();
+
+    (0..6).for_each(|idx| {
+        rosomaxa.add_all(vec![create_empty_insertion_context()]);
+        rosomaxa.update_phase(&create_statistics(0., idx))
+    });
+
+    assert_eq!(exported.read().unwrap().clone(), vec![(4, 4)]);
+}
@@ -0,0 +1,268 @@
+use super::*;
+use crate::construction::heuristics::{apply_solution_edits, SolutionEdit};
+use crate::helpers::models::domain::{get_customer_id, get_customer_ids_from_routes_sorted};
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::examples::create_example_problem;
+use crate::solver::hyper::{create_scalar_mutation_probability, HyperHeuristic, StaticSelective};
+use crate::solver::mutation::*;
+use crate::solver::population::{Elitism, Population};
+use crate::utils::Parallelism;
+
+fn create_insertion_ctx(unassigned_route: Option<usize>) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(2, 2, false);
+
+    if let Some(route_idx) = unassigned_route {
+        let route = solution.routes.remove(route_idx);
+        solution.unassigned = route.tour.jobs().map(|job| (job, 0)).collect();
+    }
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()))
+}
+
+#[test]
+fn can_get_non_dominated_individuals() {
+    let full = create_insertion_ctx(None);
+    let partial = create_insertion_ctx(Some(1));
+    let duplicate = create_insertion_ctx(None);
+    let dominated = create_insertion_ctx(Some(0));
+
+    let result = get_non_dominated(&[&full, &partial, &duplicate, &dominated]);
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(get_customer_ids_from_routes_sorted(result[0]), vec![vec!["c0", "c1"], vec!["c2", "c3"]]);
+    assert_eq!(get_customer_ids_from_routes_sorted(result[1]), vec![vec!["c0", "c1"]]);
+}
+
+#[test]
+fn can_solve_pareto() {
+    let environment = Arc::new(Environment::default());
+    let solver = Builder::new(create_example_problem(), environment).with_max_generations(Some(10)).build().unwrap();
+
+    let (solutions, _) = solver.solve_pareto().unwrap();
+
+    assert_eq!(solutions.len(), 1);
+    let (solution, cost, fitness) = solutions.first().unwrap();
+    assert_eq!(*cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(fitness, &vec![0., 1., 42.]);
+}
+
+parameterized_test! {can_get_reassignments, (moved, removed, expected), {
+    can_get_reassignments_impl(moved, removed, expected);
+}}
+
+can_get_reassignments! {
+    case01_same: (vec![], vec![], 0),
+    case02_moved: (vec!["c0"], vec![], 1),
+    case03_removed: (vec![], vec!["c3"], 1),
+    case04_moved_and_removed: (vec!["c0", "c1"], vec!["c3"], 2),
+    case05_moved_all: (vec!["c0", "c1"], vec![], 2),
+}
+
+fn can_get_reassignments_impl(moved: Vec<&str>, removed: Vec<&str>, expected: usize) {
+    let original = create_insertion_ctx(None);
+    let get_job = |id: &str| original.problem.jobs.all().find(|job| get_customer_id(job) == id).unwrap();
+    let actor = original.solution.routes.last().unwrap().route.actor.clone();
+    let edits = moved
+        .into_iter()
+        .map(|id| SolutionEdit::MoveJob { job: get_job(id), actor: actor.clone(), position: 0 })
+        .chain(removed.into_iter().map(|id| SolutionEdit::RemoveJob { job: get_job(id) }))
+        .collect::<Vec<_>>();
+    let mut changed = original.deep_copy();
+    apply_solution_edits(&mut changed, edits.as_slice()).unwrap();
+
+    assert_eq!(get_reassignments(&original, &changed), expected);
+    assert_eq!(get_reassignments(&changed, &original), expected);
+}
+
+#[test]
+fn can_ignore_swapped_actors_in_reassignments() {
+    let original = create_insertion_ctx(None);
+    let mut changed = original.deep_copy();
+    let actors = changed.solution.routes.iter().map(|route_ctx| route_ctx.route.actor.clone()).collect::<Vec<_>>();
+    changed.solution.routes.iter_mut().zip(actors.into_iter().rev()).for_each(|(route_ctx, actor)| {
+        route_ctx.route_mut().actor = actor;
+    });
+    assert!(!Arc::ptr_eq(&original.solution.routes[0].route.actor, &changed.solution.routes[0].route.actor));
+
+    assert_eq!(get_reassignments(&original, &changed), 0);
+    assert_eq!(get_reassignments(&changed, &original), 0);
+}
+
+#[test]
+fn can_solve_with_alternative() {
+    let environment = Arc::new(Environment::default());
+    let solver = Builder::new(create_example_problem(), environment).with_max_generations(Some(10)).build().unwrap();
+
+    let ((solution, cost), alternative, _) = solver.solve_with_alternative(10).unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+    assert!(alternative.is_none());
+}
+
+#[test]
+fn can_solve_with_iteration_callback() {
+    let snapshots = Arc::new(std::sync::Mutex::new(Vec::<(usize, f64, usize)>::new()));
+    let environment = Arc::new(Environment::default());
+    let solver = Builder::new(create_example_problem(), environment)
+        .with_max_generations(Some(10))
+        .with_iteration_callback({
+            let snapshots = snapshots.clone();
+            Arc::new(move |snapshot: &IterationSnapshot| {
+                let routes = snapshot.to_solution().routes.len();
+                snapshots.lock().unwrap().push((snapshot.generation, snapshot.cost, routes))
+            })
+        })
+        .with_telemetry(Telemetry::new(TelemetryMode::None))
+        .build()
+        .unwrap();
+
+    let (solution, cost, _) = solver.solve().unwrap();
+
+    let snapshots = snapshots.lock().unwrap();
+    assert_eq!(snapshots.first().map(|(generation, ..)| *generation), Some(0));
+    assert_eq!(snapshots.last().map(|(_, cost, routes)| (*cost, *routes)), Some((cost, solution.routes.len())));
+}
+
+#[test]
+fn can_solve_with_single_thread_parallelism() {
+    let environment = Arc::new(Environment::default());
+    let builder =
+        Builder::new(create_example_problem(), environment).with_parallelism(Parallelism::new_single_thread());
+
+    assert_eq!(builder.config.environment.parallelism.solver_threads(), Some(1));
+
+    let (solution, cost, _) = builder.with_max_generations(Some(10)).build().unwrap().solve().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+}
+
+#[test]
+fn can_keep_custom_population_and_hyper_with_parallelism() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let population: Box<dyn Population + Send + Sync> =
+        Box::new(Elitism::new(problem.clone(), environment.random.clone(), 2, 2));
+    let hyper: Box<dyn HyperHeuristic + Send + Sync> =
+        Box::new(StaticSelective::new_with_defaults(problem.clone(), environment.clone()));
+    let population_ptr = population.as_ref() as *const _ as *const u8;
+    let hyper_ptr = hyper.as_ref() as *const _ as *const u8;
+
+    let solver = Builder::new(problem, environment)
+        .with_population(population)
+        .with_hyper(hyper)
+        .with_parallelism(Parallelism::new_single_thread())
+        .build()
+        .unwrap();
+
+    assert_eq!(solver.config.environment.parallelism.solver_threads(), Some(1));
+    assert_eq!(solver.config.population.variation.as_ref().unwrap().as_ref() as *const _ as *const u8, population_ptr);
+    assert_eq!(solver.config.hyper.as_ref() as *const _ as *const u8, hyper_ptr);
+}
+
+#[test]
+fn can_use_default_post_optimization_with_time_budget() {
+    let create_builder = || Builder::new(create_example_problem(), Arc::new(Environment::default()));
+
+    let with_budget = create_builder().with_time_budget(Some((0.1, 0.8, 0.1))).build().unwrap();
+    let without_budget = create_builder().build().unwrap();
+
+    assert!(with_budget.config.post_optimization.is_some());
+    assert!(without_budget.config.post_optimization.is_none());
+}
+
+parameterized_test! {can_solve_with_goal_termination, (target_cost, all_assigned, all_goals), {
+    can_solve_with_goal_termination_impl(target_cost, all_assigned, all_goals);
+}}
+
+can_solve_with_goal_termination! {
+    case01_target_cost: (Some(100.), false, false),
+    case02_all_assigned: (None, true, false),
+    case03_all_goals: (Some(100.), true, true),
+}
+
+fn can_solve_with_goal_termination_impl(target_cost: Option<f64>, all_assigned: bool, all_goals: bool) {
+    let generations = Arc::new(std::sync::Mutex::new(0_usize));
+    let environment = Arc::new(Environment::default());
+    let solver = Builder::new(create_example_problem(), environment)
+        .with_target_cost(target_cost)
+        .with_all_assigned(all_assigned)
+        .with_all_goals(all_goals)
+        .with_telemetry(Telemetry::new(TelemetryMode::None).with_progress_sink({
+            let generations = generations.clone();
+            Arc::new(move |record: &ProgressRecord| *generations.lock().unwrap() = record.generation)
+        }))
+        .build()
+        .unwrap();
+
+    let (_, cost, _) = solver.solve().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(*generations.lock().unwrap(), 0);
+}
+
+#[test]
+fn can_solve_deterministically_with_the_same_seed() {
+    let (problem, _) = generate_matrix_routes_with_defaults(5, 4, false);
+    let problem = Arc::new(problem);
+    let solve = || {
+        let environment = Arc::new(Environment::new_deterministic(7, Parallelism::default()));
+        let (solution, cost, _) =
+            Builder::new(problem.clone(), environment).with_max_generations(Some(20)).build().unwrap().solve().unwrap();
+        let routes = solution
+            .routes
+            .iter()
+            .map(|route| {
+                route
+                    .tour
+                    .all_activities()
+                    .filter_map(|activity| activity.retrieve_job())
+                    .map(|job| get_customer_id(&job))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        (routes, cost)
+    };
+
+    let expected = solve();
+
+    (0..3).for_each(|_| assert_eq!(solve(), expected));
+}
+
+#[test]
+fn can_solve_with_custom_ruin_and_recreate_weights() {
+    let environment = Arc::new(Environment::default());
+    let solver = Builder::new(create_example_problem(), environment)
+        .with_max_generations(Some(10))
+        .with_ruin_weights(vec![(vec![(Arc::new(RandomJobRemoval::new(JobRemovalLimit::default())), 1.)], 1)])
+        .with_recreate_weights(vec![(Box::new(RecreateWithCheapest::default()), 1)])
+        .build()
+        .unwrap();
+
+    let (solution, cost, _) = solver.solve().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+}
+
+#[test]
+fn can_solve_with_custom_mutation_group() {
+    let environment = Arc::new(Environment::default());
+    let problem = create_example_problem();
+    let solver = Builder::new(problem.clone(), environment.clone())
+        .with_max_generations(Some(10))
+        .with_mutation_group(vec![(
+            StaticSelective::create_default_mutation(problem),
+            create_scalar_mutation_probability(1., environment.random.clone()),
+        )])
+        .build()
+        .unwrap();
+
+    let (solution, cost, _) = solver.solve().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::helpers::models::domain::create_simple_insertion_ctx;
 use crate::helpers::solver::create_default_refinement_ctx;
 use crate::models::examples::create_example_problem;
 use crate::utils::compare_floats;
@@ -35,3 +36,75 @@ fn can_update_statistic() {
     telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), true);
     compare_statistic(&refinement_ctx, (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_report_progress_to_sink() {
+    let records = Arc::new(std::sync::Mutex::new(Vec::<(usize, f64, f64, usize)>::new()));
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    let mut telemetry = Telemetry::new(TelemetryMode::None).with_progress_sink({
+        let records = records.clone();
+        Arc::new(move |record: &ProgressRecord| {
+            records.lock().unwrap().push((record.generation, record.best_cost, record.mean_cost, record.unassigned))
+        })
+    });
+
+    telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), false);
+    assert!(records.lock().unwrap().is_empty());
+
+    refinement_ctx.population.add(create_simple_insertion_ctx(100., 0));
+    refinement_ctx.population.add(create_simple_insertion_ctx(200., 0));
+    telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), true);
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 1);
+    let (generation, best_cost, mean_cost, unassigned) = records.first().cloned().unwrap();
+    assert_eq!(generation, 1);
+    assert!(best_cost <= mean_cost);
+    assert_eq!(unassigned, 0);
+}
+
+#[test]
+fn can_dump_population_with_given_frequency() {
+    let snapshots = Arc::new(std::sync::Mutex::new(Vec::<(usize, usize, Vec<String>)>::new()));
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    refinement_ctx.population.add(create_simple_insertion_ctx(100., 1));
+    let mut telemetry = Telemetry::new(TelemetryMode::None).with_population_dumper(2, {
+        let snapshots = snapshots.clone();
+        Arc::new(move |snapshot: &PopulationSnapshot| {
+            let individual = snapshot.individuals.first().unwrap();
+            snapshots.lock().unwrap().push((
+                snapshot.generation,
+                individual.fitness.len(),
+                individual.routes.iter().map(|(vehicle, _)| vehicle.clone()).collect(),
+            ))
+        })
+    });
+
+    (0..3).for_each(|_| telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), false));
+
+    let snapshots = snapshots.lock().unwrap();
+    assert_eq!(snapshots.iter().map(|(generation, ..)| *generation).collect::<Vec<_>>(), vec![0, 2]);
+    assert!(snapshots.iter().all(|(_, fitness, vehicles)| *fitness > 0 && vehicles == &vec!["v1".to_string()]));
+}
+
+#[test]
+fn can_notify_iteration_callback_only_on_improvement() {
+    let snapshots = Arc::new(std::sync::Mutex::new(Vec::<(usize, f64, usize, usize)>::new()));
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    refinement_ctx.population.add(create_simple_insertion_ctx(100., 1));
+    let mut telemetry = Telemetry::new(TelemetryMode::None).with_iteration_callback({
+        let snapshots = snapshots.clone();
+        Arc::new(move |snapshot: &IterationSnapshot| {
+            let routes = snapshot.to_solution().routes.len();
+            snapshots.lock().unwrap().push((snapshot.generation, snapshot.cost, snapshot.unassigned, routes))
+        })
+    });
+
+    telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), true);
+    telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), false);
+    telemetry.on_generation(&mut refinement_ctx, 0., Timer::start(), true);
+
+    let snapshots = snapshots.lock().unwrap();
+    assert_eq!(snapshots.iter().map(|(generation, ..)| *generation).collect::<Vec<_>>(), vec![0, 2]);
+    assert!(snapshots.iter().all(|(_, cost, unassigned, routes)| *cost > 0. && *unassigned == 1 && *routes == 1));
+}
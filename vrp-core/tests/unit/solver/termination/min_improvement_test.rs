@@ -0,0 +1,30 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_problem;
+use crate::helpers::solver::create_default_refinement_ctx;
+
+parameterized_test! {can_detect_termination, (generations, epsilon, costs, expected), {
+    can_detect_termination_impl(generations, epsilon, costs, expected);
+}}
+
+can_detect_termination! {
+    case_01: (2, 0.06, vec![100., 95., 91., 90., 90.], vec![false, false, false, true, true]),
+    case_02: (2, 0.01, vec![100., 95., 91., 90., 90., 90.], vec![false, false, false, false, false, true]),
+    case_03: (1, 0.1, vec![0., 0., 1.], vec![false, true, false]),
+}
+
+fn can_detect_termination_impl(generations: usize, epsilon: f64, costs: Vec<f64>, expected: Vec<bool>) {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+    let termination = MinImprovement::new(generations, epsilon);
+
+    let result = costs
+        .into_iter()
+        .enumerate()
+        .map(|(generation, cost)| {
+            refinement_ctx.statistics.generation = generation;
+
+            termination.update_and_check(&mut refinement_ctx, cost)
+        })
+        .collect::<Vec<bool>>();
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::helpers::models::domain::create_simple_insertion_ctx;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::examples::create_example_problem;
+use crate::solver::termination::{AllAssigned, CompositeTermination};
+
+parameterized_test! {can_detect_termination, (target, is_empty, expected), {
+    can_detect_termination_impl(target, is_empty, expected);
+}}
+
+can_detect_termination! {
+    case_01_above: (1000., false, true),
+    case_02_below: (1., false, false),
+    case_03_empty: (1000., true, false),
+}
+
+fn can_detect_termination_impl(target: f64, is_empty: bool, expected: bool) {
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    if !is_empty {
+        refinement_ctx.population.add(create_simple_insertion_ctx(100., 0));
+    }
+
+    let result = TargetCost::new(target).is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_combine_with_all_assigned, (target, unassigned, is_all, expected), {
+    can_combine_with_all_assigned_impl(target, unassigned, is_all, expected);
+}}
+
+can_combine_with_all_assigned! {
+    case_01_any_both: (1000., 0, false, true),
+    case_02_any_one: (1., 0, false, true),
+    case_03_any_none: (1., 1, false, false),
+    case_04_all_both: (1000., 0, true, true),
+    case_05_all_one: (1., 0, true, false),
+    case_06_all_other: (1000., 1, true, false),
+}
+
+fn can_combine_with_all_assigned_impl(target: f64, unassigned: usize, is_all: bool, expected: bool) {
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    refinement_ctx.population.add(create_simple_insertion_ctx(100., unassigned));
+    let terminations: Vec<Box<dyn Termination + Send + Sync>> =
+        vec![Box::new(TargetCost::new(target)), Box::new(AllAssigned::default())];
+    let termination =
+        if is_all { CompositeTermination::new_all(terminations) } else { CompositeTermination::new(terminations) };
+
+    let result = termination.is_termination(&mut refinement_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,48 @@
+use super::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::examples::create_example_problem;
+
+parameterized_test! {can_apply_linear_schedule, (termination_estimate, expected), {
+    can_apply_linear_schedule_impl(termination_estimate, expected);
+}}
+
+can_apply_linear_schedule! {
+    case01_start: (0., vec![(1, 10.), (2, 0.), (3, 5.)]),
+    case02_middle: (0.5, vec![(1, 55.), (2, 50.), (3, 5.)]),
+    case03_end: (1., vec![(1, 100.), (2, 100.), (3, 5.)]),
+    case04_after_end: (2., vec![(1, 100.), (2, 100.), (3, 5.)]),
+}
+
+fn can_apply_linear_schedule_impl(termination_estimate: f64, expected: Vec<(i32, Cost)>) {
+    let weights = Arc::new(ConstraintWeights::new(&[(1, 10.), (2, 0.), (3, 5.)]));
+    let schedule = WeightSchedule::new_linear(weights.clone(), &[(1, 100.), (2, 100.), (4, 100.)]);
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    refinement_ctx.statistics.termination_estimate = termination_estimate;
+
+    schedule.apply(&refinement_ctx);
+
+    assert_eq!(weights.all(), expected);
+}
+
+#[test]
+fn can_detect_weight_changes() {
+    let weights = Arc::new(ConstraintWeights::new(&[(1, 10.)]));
+    let schedule = WeightSchedule::new(
+        weights.clone(),
+        Arc::new(|statistics, weights| {
+            let _ = weights.set(1, if statistics.generation > 1 { 20. } else { 10. });
+        }),
+    );
+    let mut refinement_ctx = create_default_refinement_ctx(create_example_problem());
+    set_weight_schedule(&mut refinement_ctx, Arc::new(schedule));
+
+    refinement_ctx.statistics.generation = 1;
+    assert!(!apply_weight_schedule(&refinement_ctx));
+
+    refinement_ctx.statistics.generation = 2;
+    assert!(apply_weight_schedule(&refinement_ctx));
+    assert_eq!(weights.get(1), Some(20.));
+
+    refinement_ctx.statistics.generation = 3;
+    assert!(!apply_weight_schedule(&refinement_ctx));
+}
@@ -0,0 +1,64 @@
+use super::*;
+use rand::RngCore;
+
+#[test]
+fn can_use_phase_thread_pools() {
+    let parallelism = Parallelism::default()
+        .with_phase_threads(ParallelismPhase::Mutation, 2)
+        .with_phase_threads(ParallelismPhase::Evaluation, 1);
+
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Construction), None);
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Mutation), Some(2));
+    assert_eq!(parallelism.phase_threads(ParallelismPhase::Evaluation), Some(1));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn can_execute_on_phase_thread_pool() {
+    let parallelism = Parallelism::default().with_phase_threads(ParallelismPhase::Mutation, 3);
+
+    let mutation_threads = parallelism.phase_execute(ParallelismPhase::Mutation, rayon::current_num_threads);
+    let construction_threads = parallelism.phase_execute(ParallelismPhase::Construction, rayon::current_num_threads);
+
+    assert_eq!(mutation_threads, 3);
+    assert_eq!(construction_threads, rayon::current_num_threads());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn can_execute_on_solver_thread_pool() {
+    let parallelism = Parallelism::default().with_solver_threads(2).with_phase_threads(ParallelismPhase::Mutation, 3);
+
+    let mutation_threads = parallelism.phase_execute(ParallelismPhase::Mutation, rayon::current_num_threads);
+    let construction_threads = parallelism.phase_execute(ParallelismPhase::Construction, rayon::current_num_threads);
+
+    assert_eq!(parallelism.solver_threads(), Some(2));
+    assert_eq!(parallelism.available_cpus(), 2);
+    assert_eq!(mutation_threads, 3);
+    assert_eq!(construction_threads, 2);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn can_use_single_thread() {
+    let parallelism = Parallelism::new_single_thread();
+
+    let evaluation_threads = parallelism.phase_execute(ParallelismPhase::Evaluation, rayon::current_num_threads);
+
+    assert_eq!(parallelism.solver_threads(), Some(1));
+    assert_eq!(parallelism.available_cpus(), 1);
+    assert_eq!(evaluation_threads, 1);
+}
+
+#[test]
+fn can_use_random_stream_only_in_deterministic_mode() {
+    let deterministic = Environment::new_deterministic(42, Parallelism::default());
+    let non_deterministic = Environment::new(deterministic.random.clone(), Parallelism::default());
+    let get_value =
+        |environment: &Environment| environment.stream_execute(0, || environment.random.get_rng().next_u64());
+
+    assert!(deterministic.is_deterministic);
+    assert!(!non_deterministic.is_deterministic);
+    assert_eq!(get_value(&deterministic), get_value(&deterministic));
+    assert_ne!(get_value(&non_deterministic), get_value(&non_deterministic));
+}
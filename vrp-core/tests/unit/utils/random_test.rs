@@ -20,3 +20,111 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_generate_pcg32_reference_values() {
+    let mut rng = Pcg32::new(42, 54);
+
+    let values = (0..6).map(|_| rng.next_u32()).collect::<Vec<_>>();
+
+    assert_eq!(values, vec![0xa15c_02b7, 0x7b47_f409, 0xba1d_3330, 0x83d2_f293, 0xbfa4_784b, 0xcbed_606e]);
+}
+
+parameterized_test! {can_reproduce_values_with_seed, algorithm, {
+    can_reproduce_values_with_seed_impl(algorithm);
+}}
+
+can_reproduce_values_with_seed! {
+    case01: RandomAlgorithm::Std,
+    case02: RandomAlgorithm::ChaCha,
+    case03: RandomAlgorithm::Pcg,
+    case04: RandomAlgorithm::Small,
+}
+
+fn can_reproduce_values_with_seed_impl(algorithm: RandomAlgorithm) {
+    let get_values = |seed: u64| {
+        let random = DefaultRandom::new(algorithm, Some(seed));
+        (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>()
+    };
+
+    let values = get_values(42);
+
+    assert_eq!(values, get_values(42));
+    assert_ne!(values, get_values(43));
+    // NOTE each call should use its own stream
+    assert!(values.iter().any(|value| *value != values[0]));
+}
+
+#[test]
+fn can_use_different_streams_per_thread() {
+    let random = Arc::new(DefaultRandom::new(RandomAlgorithm::Pcg, Some(42)));
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+
+    let main_value = random.get_rng().next_u64();
+    let pool_value = pool.install({
+        let random = random.clone();
+        move || {
+            random.counter.store(0, std::sync::atomic::Ordering::Relaxed);
+            random.get_rng().next_u64()
+        }
+    });
+
+    assert_ne!(main_value, pool_value);
+    assert_eq!(random.seed(), Some(42));
+    assert_eq!(random.algorithm(), RandomAlgorithm::Pcg);
+}
+
+#[test]
+fn can_create_random_with_known_seed() {
+    let explicit = DefaultRandom::new_with_known_seed(RandomAlgorithm::Std, Some(42));
+    let generated = DefaultRandom::new_with_known_seed(RandomAlgorithm::Std, None);
+
+    assert_eq!(explicit.seed(), Some(42));
+    assert!(generated.seed().is_some());
+    assert_eq!(DefaultRandom::default().seed(), None);
+}
+
+#[test]
+fn can_derive_different_seeds() {
+    let seeds = (0..100).map(|stream| derive_seed(42, stream)).collect::<std::collections::HashSet<_>>();
+
+    assert_eq!(seeds.len(), 100);
+}
+
+#[test]
+fn can_reproduce_values_within_random_stream() {
+    let random = Arc::new(DefaultRandom::new(RandomAlgorithm::Pcg, Some(42)));
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+    let get_values = |random: &DefaultRandom, stream: u64| {
+        with_random_stream(stream, || (0..5).map(|_| random.get_rng().next_u64()).collect::<Vec<_>>())
+    };
+
+    let main_values = get_values(random.as_ref(), 1);
+    let pool_values = pool.install({
+        let random = random.clone();
+        move || {
+            random.get_rng().next_u64();
+            get_values(random.as_ref(), 1)
+        }
+    });
+
+    assert_eq!(main_values, pool_values);
+    assert_ne!(main_values, get_values(random.as_ref(), 2));
+    assert!(!is_random_stream());
+}
+
+#[test]
+fn can_restore_outer_random_stream() {
+    let random = DefaultRandom::new(RandomAlgorithm::Pcg, Some(42));
+
+    let (outer, inner) = with_random_stream(1, || {
+        let first = random.get_rng().next_u64();
+        let inner = with_random_stream(2, || random.get_rng().next_u64());
+        let second = random.get_rng().next_u64();
+
+        (vec![first, second], inner)
+    });
+
+    assert_eq!(outer, with_random_stream(1, || (0..2).map(|_| random.get_rng().next_u64()).collect::<Vec<_>>()));
+    assert_eq!(inner, with_random_stream(2, || random.get_rng().next_u64()));
+}
@@ -13,6 +13,7 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), String> {
     check_jobs_presence(ctx)?;
     check_jobs_match(ctx)?;
     check_dispatch(ctx)?;
+    check_values(ctx)?;
 
     Ok(())
 }
@@ -150,6 +151,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
 
 /// Checks job constraint violations.
 fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
+    let is_time_relaxed = ctx.is_relaxed(&RelaxableConstraintType::TimeWindow);
     let job_ids = ctx
         .solution
         .tours
@@ -159,14 +161,18 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                 stop.activities
                     .iter()
                     .filter(move |activity| {
-                        try_match_job(
-                            tour,
-                            stop,
-                            activity,
-                            get_job_index(&ctx.core_problem),
-                            get_coord_index(&ctx.core_problem),
-                        )
-                        .is_err()
+                        // NOTE activity of a known job cannot be matched by time when time windows are relaxed
+                        let is_relaxed_job = is_time_relaxed && ctx.job_map.contains_key(&activity.job_id);
+
+                        !is_relaxed_job
+                            && try_match_job(
+                                tour,
+                                stop,
+                                activity,
+                                get_job_index(&ctx.core_problem),
+                                get_coord_index(&ctx.core_problem),
+                            )
+                            .is_err()
                     })
                     .map(|activity| {
                         format!(
@@ -256,3 +262,32 @@ fn check_dispatch(ctx: &CheckerContext) -> Result<(), String> {
         Ok(())
     })
 }
+
+/// Checks that total value of served jobs is reported properly in tour and solution statistic.
+fn check_values(ctx: &CheckerContext) -> Result<(), String> {
+    let check_value = |expected: f64, actual: Option<f64>, target: &str| {
+        if (expected - actual.unwrap_or(0.)).abs() > 1E-6 {
+            Err(format!("value mismatch for {} statistic, expected: '{}', got: '{:?}'", target, expected, actual))
+        } else {
+            Ok(())
+        }
+    };
+
+    let total = ctx.solution.tours.iter().try_fold::<_, _, Result<_, String>>(0., |total, tour| {
+        let expected = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .map(|activity| activity.job_id.as_str())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter_map(|job_id| ctx.job_map.get(job_id).and_then(|job| job.value))
+            .sum::<f64>();
+
+        check_value(expected, tour.statistic.value, format!("tour '{}'", tour.vehicle_id).as_str())?;
+
+        Ok(total + expected)
+    })?;
+
+    check_value(total, ctx.solution.statistic.value, "solution")
+}
@@ -68,10 +68,18 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
             .map(|stop| parse_time(&stop.time.arrival))
             .ok_or_else(|| format!("Cannot get arrival for tour '{}'", tour.vehicle_id))?;
 
+        let breaks = vehicle_shift.breaks.as_ref().map_or(&[] as &[VehicleBreak], |breaks| breaks.as_slice());
+        let driving_time = get_driving_times(tour).iter().sum::<f64>();
         let expected_break_count =
-            vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).fold(0, |acc, vehicle_break| {
-                let break_tw = get_break_time_window(tour, vehicle_break).expect("Cannot get break time windows");
-                if break_tw.start < arrival {
+            breaks.iter().zip(get_driving_break_limits(breaks)).fold(0, |acc, (vehicle_break, driving_limit)| {
+                let is_expected = if let Some((threshold, _)) = driving_limit {
+                    threshold <= driving_time
+                } else {
+                    let break_tw = get_break_time_window(tour, vehicle_break).expect("Cannot get break time windows");
+                    break_tw.start < arrival
+                };
+
+                if is_expected {
                     acc + 1
                 } else {
                     acc
@@ -81,16 +89,56 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
         let total_break_count = actual_break_count + get_break_violation_count(&context.solution, tour);
 
         if expected_break_count != total_break_count {
-            Err(format!(
+            return Err(format!(
                 "Amount of breaks does not match, expected: '{}', got '{}' for vehicle '{}', shift index '{}'",
                 expected_break_count, total_break_count, tour.vehicle_id, tour.shift_index
-            ))
+            ));
+        }
+
+        // NOTE continuous driving time cannot be split when driving time break is not assigned
+        if get_break_violation_count(&context.solution, tour) == 0 {
+            check_continuous_driving(context, tour, breaks)
         } else {
             Ok(())
         }
     })
 }
 
+/// Checks that continuous driving time between driving time breaks does not exceed the limit.
+fn check_continuous_driving(context: &CheckerContext, tour: &Tour, breaks: &[VehicleBreak]) -> Result<(), String> {
+    let max_driving_time = match get_driving_limit(breaks) {
+        Some(limit) => limit.max_driving_time,
+        None => return Ok(()),
+    };
+
+    tour.stops.iter().skip(1).zip(get_driving_times(tour)).try_fold(0., |acc, (stop, driving_time)| {
+        let continuous = acc + driving_time;
+        if continuous > max_driving_time {
+            return Err(format!(
+                "Continuous driving time '{}' exceeds limit '{}' for vehicle '{}', shift index '{}'",
+                continuous, max_driving_time, tour.vehicle_id, tour.shift_index
+            ));
+        }
+
+        let has_driving_break = stop.activities.iter().any(|activity| {
+            matches!(context.get_activity_type(tour, stop, activity),
+                Ok(ActivityType::Break(vehicle_break)) if vehicle_break.policy == Some(VehicleBreakPolicy::DrivingTime))
+        });
+
+        Ok(if has_driving_break { 0. } else { continuous })
+    })?;
+
+    Ok(())
+}
+
+/// Returns driving time between each pair of consecutive stops.
+pub(crate) fn get_driving_times(tour: &Tour) -> Vec<f64> {
+    tour.stops
+        .windows(2)
+        .map(|stops| parse_time(&stops[1].time.arrival) - parse_time(&stops[0].time.departure))
+        .collect()
+}
+
 fn as_leg_info_with_break<'a>(
     context: &CheckerContext,
     tour: &Tour,
@@ -114,6 +162,17 @@ fn as_leg_info_with_break<'a>(
 }
 
 fn get_break_time_window(tour: &Tour, vehicle_break: &VehicleBreak) -> Result<TimeWindow, String> {
+    // NOTE driving time break can be taken at any time of the tour
+    if vehicle_break.policy == Some(VehicleBreakPolicy::DrivingTime) {
+        let departure = tour.stops.first().map(|stop| parse_time(&stop.time.departure));
+        let arrival = tour.stops.last().map(|stop| parse_time(&stop.time.arrival));
+
+        return match (departure, arrival) {
+            (Some(departure), Some(arrival)) => Ok(TimeWindow::new(departure, arrival)),
+            _ => Err(format!("Cannot get tour time for tour: '{}'", tour.vehicle_id)),
+        };
+    }
+
     match &vehicle_break.time {
         VehicleBreakTime::TimeWindow(tw) => Ok(parse_time_window(tw)),
         VehicleBreakTime::TimeOffset(offset) => {
@@ -7,11 +7,17 @@ use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
-/// * max vehicle's capacity is not violated
+/// * max vehicle's capacity (including allowed overload and volume) is not violated, unless relaxed
 /// * load change is correct
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let capacity = MultiDimLoad::new(get_vehicle_capacity(vehicle, get_volume_dimension(&context.problem)));
+        let capacity = match vehicle.limits.as_ref().and_then(|limits| limits.overload.as_ref()) {
+            Some(overload) => capacity * (1. + overload.max_ratio),
+            None => capacity,
+        };
+        let is_relaxed = context.is_relaxed(&RelaxableConstraintType::Capacity);
 
         let legs = (0_usize..)
             .zip(tour.stops.windows(2))
@@ -75,7 +81,7 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
                     let from_load = MultiDimLoad::new(from.load.clone());
                     let to_load = MultiDimLoad::new(to.load.clone());
 
-                    if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
+                    if !is_relaxed && (!capacity.can_fit(&from_load) || !capacity.can_fit(&to_load)) {
                         return Err(format!("Load exceeds capacity in tour '{}'", tour.vehicle_id));
                     }
 
@@ -134,13 +140,14 @@ fn get_demand(
     activity: &Activity,
     activity_type: &ActivityType,
 ) -> Result<(DemandType, MultiDimLoad), String> {
+    let volume_dimension = get_volume_dimension(&context.problem);
     let (is_dynamic, demand) = context.visit_job(
         activity,
         &activity_type,
         |job, task| {
             let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
                 && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
-            let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            let demand = get_task_demand(task, volume_dimension).map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
             (is_dynamic, demand)
         },
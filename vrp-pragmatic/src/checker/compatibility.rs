@@ -0,0 +1,69 @@
+use super::*;
+use hashbrown::HashMap;
+
+/// Checks that jobs from incompatible groups are not served in the same tour or, depending on
+/// policy, are not on board of the vehicle at the same time.
+pub fn check_compatibility(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let activities = tour.stops.iter().flat_map(|stop| stop.activities.iter()).collect::<Vec<_>>();
+        let last_idx = activities.len();
+
+        let intervals = activities.iter().enumerate().fold(
+            HashMap::<&str, (&Job, usize, usize)>::default(),
+            |mut acc, (idx, activity)| {
+                if let Some(job) = context.job_map.get(activity.job_id.as_str()) {
+                    let has_pickups = matches!(job.pickups.as_ref(), Some(tasks) if !tasks.is_empty());
+                    let has_deliveries = matches!(job.deliveries.as_ref(), Some(tasks) if !tasks.is_empty());
+
+                    let (start, end) = match (has_pickups, has_deliveries) {
+                        (false, true) => (0, idx),
+                        (true, false) => (idx, last_idx),
+                        _ => (idx, idx),
+                    };
+
+                    acc.entry(activity.job_id.as_str())
+                        .and_modify(|(_, old_start, old_end)| {
+                            *old_start = (*old_start).min(start);
+                            *old_end = (*old_end).max(end);
+                        })
+                        .or_insert((job, start, end));
+                }
+                acc
+            },
+        );
+
+        intervals.iter().try_for_each(|(job_id, (job, start, end))| {
+            let compatibility = match job.compatibility.as_ref() {
+                Some(compatibility) => compatibility,
+                None => return Ok(()),
+            };
+
+            intervals
+                .iter()
+                .filter_map(|(other_id, (other, other_start, other_end))| {
+                    other
+                        .compatibility
+                        .as_ref()
+                        .filter(|other| compatibility.incompatible.contains(&other.group))
+                        .map(|_| (other_id, other_start, other_end))
+                })
+                .try_for_each(|(other_id, other_start, other_end)| {
+                    let is_violated = match compatibility.policy {
+                        Some(JobCompatibilityPolicy::NeverSimultaneouslyLoaded) => {
+                            start < other_end && other_start < end
+                        }
+                        _ => true,
+                    };
+
+                    if is_violated {
+                        Err(format!(
+                            "job '{}' is incompatible with job '{}' in tour '{}'",
+                            other_id, job_id, tour.vehicle_id
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                })
+        })
+    })
+}
@@ -3,10 +3,19 @@
 mod limits_test;
 
 use super::*;
+use crate::checker::breaks::get_driving_times;
+use crate::format::get_coord_index;
+use crate::utils::get_haversine_distance;
+use vrp_core::construction::constraints::Area;
+use vrp_core::models::common::IdDimension;
 
-/// Check that shift limits are not violated:
-/// * max shift time
+/// Check that shift limits are not violated, unless relaxed:
+/// * max shift time, including overtime
 /// * max distance
+/// * tour size
+/// * max radius
+/// * allowed areas
+/// * driving time of vehicle team
 ///
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
@@ -15,7 +24,8 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
 
         if let Some(ref limits) = vehicle.limits {
             if let Some(max_distance) = limits.max_distance {
-                if tour.statistic.distance as f64 > max_distance {
+                let is_relaxed = context.is_relaxed(&RelaxableConstraintType::MaxDistance);
+                if !is_relaxed && tour.statistic.distance as f64 > max_distance {
                     return Err(format!(
                         "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         max_distance, tour.statistic.distance, tour.vehicle_id, tour.shift_index
@@ -24,7 +34,9 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
             }
 
             if let Some(shift_time) = limits.shift_time {
-                if tour.statistic.duration as f64 > shift_time {
+                let shift_time = shift_time + limits.overtime.as_ref().map_or(0., |overtime| overtime.max_time);
+                let is_relaxed = context.is_relaxed(&RelaxableConstraintType::ShiftTime);
+                if !is_relaxed && tour.statistic.duration as f64 > shift_time {
                     return Err(format!(
                         "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         shift_time, tour.statistic.duration, tour.vehicle_id, tour.shift_index
@@ -39,15 +51,112 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
                 let tour_activities = tour.stops.iter().flat_map(|stop| stop.activities.iter()).count();
                 let tour_activities = if tour_activities > extra_activities { tour_activities - extra_activities } else { 0 };
 
-                if tour_activities > tour_size_limit {
+                let is_relaxed = context.is_relaxed(&RelaxableConstraintType::TourSize);
+                if !is_relaxed && tour_activities > tour_size_limit {
                     return Err(format!(
                         "tour size limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
                     ))
                 }
             }
+
+            if let Some(ref max_radius) = limits.max_radius {
+                check_max_radius(context, tour, max_radius)?;
+            }
+
+            if let Some(ref allowed_areas) = limits.allowed_areas {
+                check_allowed_areas(context, tour, allowed_areas)?;
+            }
+        }
+
+        if let Some(ref team) = vehicle.team {
+            check_team_driving(tour, team)?;
         }
 
         Ok(())
     })
 }
+
+fn check_max_radius(context: &CheckerContext, tour: &Tour, max_radius: &VehicleRadius) -> Result<(), String> {
+    let coord_index = get_coord_index(&context.core_problem);
+    let vehicle = context
+        .core_problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.dimens.get_id().map_or(false, |id| *id == tour.vehicle_id))
+        .ok_or_else(|| format!("cannot find vehicle with id '{}'", tour.vehicle_id))?;
+    let get_index = |location: &Location| {
+        coord_index.get_by_loc(location).ok_or_else(|| format!("cannot find location: {:?}", location))
+    };
+
+    let start = tour.stops.first().ok_or_else(|| "empty tour".to_string())?;
+    let start_idx = get_index(&start.location)?;
+    let departure = parse_time(&start.time.departure);
+
+    tour.stops.iter().try_for_each(|stop| {
+        let has_jobs = stop.activities.iter().any(|activity| context.get_job_by_id(&activity.job_id).is_some());
+        if !has_jobs {
+            return Ok(());
+        }
+
+        let distance = if max_radius.airline.unwrap_or(false) {
+            get_haversine_distance(&start.location, &stop.location)
+        } else {
+            let stop_idx = get_index(&stop.location)?;
+            context.core_problem.transport.distance(vehicle.profile, start_idx, stop_idx, departure)
+        };
+
+        if distance > max_radius.distance {
+            Err(format!(
+                "max radius limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                max_radius.distance, distance, tour.vehicle_id, tour.shift_index
+            ))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn check_allowed_areas(context: &CheckerContext, tour: &Tour, allowed_areas: &[AreaLimit]) -> Result<(), String> {
+    let areas = allowed_areas
+        .iter()
+        .map(|area| Area::new(area.priority, area.outer_shape.iter().map(|location| location.to_lat_lng()).collect()))
+        .collect::<Vec<_>>();
+
+    tour.stops.iter().try_for_each(|stop| {
+        let has_jobs = stop.activities.iter().any(|activity| context.get_job_by_id(&activity.job_id).is_some());
+        if !has_jobs || areas.iter().any(|area| area.contains(&stop.location.to_lat_lng())) {
+            Ok(())
+        } else {
+            Err(format!(
+                "allowed areas violation, stop location {:?} is outside of areas, vehicle id '{}', shift index: {}",
+                stop.location, tour.vehicle_id, tour.shift_index
+            ))
+        }
+    })
+}
+
+fn check_team_driving(tour: &Tour, team: &VehicleTeam) -> Result<(), String> {
+    if team.drivers == 0 {
+        return Ok(());
+    }
+
+    // NOTE drivers take turns only after driving, activities of the same stop don't change the driver
+    let driving_times = get_driving_times(tour).into_iter().filter(|driving| *driving > 0.).enumerate().fold(
+        vec![0.; team.drivers],
+        |mut acc, (idx, driving)| {
+            acc[idx % team.drivers] += driving;
+            acc
+        },
+    );
+
+    if let Some(driving_time) = driving_times.iter().find(|&&time| time > team.max_driving_time) {
+        Err(format!(
+            "team driving time violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+            team.max_driving_time, driving_time, tour.vehicle_id, tour.shift_index
+        ))
+    } else {
+        Ok(())
+    }
+}
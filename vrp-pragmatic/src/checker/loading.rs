@@ -0,0 +1,51 @@
+use super::*;
+use hashbrown::HashMap;
+
+/// Checks that loading policies of jobs with pickups and deliveries are respected: jobs loaded
+/// while such job is on board are unloaded before (lifo) or after (fifo) it.
+pub fn check_loading_policies(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let intervals = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .enumerate()
+            .filter(|(_, activity)| activity.activity_type == "pickup" || activity.activity_type == "delivery")
+            .fold(HashMap::<&str, (usize, usize)>::default(), |mut acc, (idx, activity)| {
+                acc.entry(activity.job_id.as_str())
+                    .and_modify(|(start, end)| {
+                        *start = (*start).min(idx);
+                        *end = (*end).max(idx);
+                    })
+                    .or_insert((idx, idx));
+                acc
+            });
+
+        intervals.iter().try_for_each(|(job_id, (outer_start, outer_end))| {
+            let policy = context.job_map.get(*job_id).and_then(|job| job.loading_policy.as_ref());
+            let policy = match policy {
+                Some(policy) => policy,
+                None => return Ok(()),
+            };
+
+            intervals
+                .iter()
+                .filter(|(_, (inner_start, _))| outer_start < inner_start && inner_start < outer_end)
+                .try_for_each(|(other_id, (_, inner_end))| {
+                    let is_violated = match policy {
+                        JobLoadingPolicy::Lifo => inner_end > outer_end,
+                        JobLoadingPolicy::Fifo => inner_end < outer_end,
+                    };
+
+                    if is_violated {
+                        Err(format!(
+                            "job '{}' violates loading policy '{:?}' of job '{}' in tour '{}'",
+                            other_id, policy, job_id, tour.vehicle_id
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                })
+        })
+    })
+}
@@ -28,6 +28,8 @@ enum ActivityType {
     Depot(VehicleDispatch),
     Break(VehicleBreak),
     Reload(VehicleReload),
+    Trailer,
+    Unavailability(VehicleUnavailability),
 }
 
 impl CheckerContext {
@@ -51,6 +53,10 @@ impl CheckerContext {
         check_assignment(&self)?;
         check_routing(&self)?;
         check_limits(&self)?;
+        check_trailers(&self)?;
+        check_unavailability(&self)?;
+        check_loading_policies(&self)?;
+        check_compatibility(&self)?;
 
         Ok(())
     }
@@ -65,6 +71,18 @@ impl CheckerContext {
             .ok_or_else(|| format!("Cannot find vehicle with id '{}'", vehicle_id))
     }
 
+    /// Checks whether violations of given constraint type are allowed by relaxation.
+    fn is_relaxed(&self, constraint_type: &RelaxableConstraintType) -> bool {
+        self.problem.objectives.as_ref().map_or(false, |objectives| {
+            objectives.primary.iter().chain(objectives.secondary.iter().flatten()).any(|objective| match objective {
+                Objective::MinimizeViolations { constraints } => {
+                    constraints.iter().any(|relaxation| relaxation.constraint_type == *constraint_type)
+                }
+                _ => false,
+            })
+        })
+    }
+
     /// Gets activity operation time range in seconds since Unix epoch.
     fn get_activity_time(&self, stop: &Stop, activity: &Activity) -> TimeWindow {
         let time = activity
@@ -126,18 +144,24 @@ impl CheckerContext {
                 .breaks
                 .as_ref()
                 .and_then(|breaks| {
-                    breaks.iter().find(|b| match &b.time {
-                        VehicleBreakTime::TimeWindow(tw) => parse_time_window(tw).intersects(&time),
-                        VehicleBreakTime::TimeOffset(offset) => {
-                            assert_eq!(offset.len(), 2);
-                            // NOTE make expected time window wider due to reschedule departure
-                            let stops = &tour.stops;
-                            let start = parse_time(&stops.first().unwrap().time.arrival) + *offset.first().unwrap();
-                            let end = parse_time(&stops.first().unwrap().time.departure) + *offset.last().unwrap();
-
-                            TimeWindow::new(start, end).intersects(&time)
-                        }
-                    })
+                    let is_driving_break = |b: &&VehicleBreak| b.policy == Some(VehicleBreakPolicy::DrivingTime);
+                    // NOTE driving time break can be taken at any time, so try to match other breaks first
+                    breaks
+                        .iter()
+                        .filter(|b| !is_driving_break(b))
+                        .find(|b| match &b.time {
+                            VehicleBreakTime::TimeWindow(tw) => parse_time_window(tw).intersects(&time),
+                            VehicleBreakTime::TimeOffset(offset) => {
+                                assert_eq!(offset.len(), 2);
+                                // NOTE make expected time window wider due to reschedule departure
+                                let stops = &tour.stops;
+                                let start = parse_time(&stops.first().unwrap().time.arrival) + *offset.first().unwrap();
+                                let end = parse_time(&stops.first().unwrap().time.departure) + *offset.last().unwrap();
+
+                                TimeWindow::new(start, end).intersects(&time)
+                            }
+                        })
+                        .or_else(|| breaks.iter().find(is_driving_break))
                 })
                 .map(|b| ActivityType::Break(b.clone()))
                 .ok_or_else(|| format!("Cannot find break for tour '{}'", tour.vehicle_id)),
@@ -148,12 +172,33 @@ impl CheckerContext {
                 .and_then(|reload| reload.iter().find(|r| r.location == location && r.tag == activity.job_tag))
                 .map(|r| ActivityType::Reload(r.clone()))
                 .ok_or_else(|| format!("Cannot find reload for tour '{}'", tour.vehicle_id)),
+            "unavailability" => shift
+                .unavailability
+                .as_ref()
+                .and_then(|periods| {
+                    periods.iter().find(|period| {
+                        period.location == location
+                            && period.tag == activity.job_tag
+                            && parse_time_window(&period.time).intersects(&time)
+                    })
+                })
+                .map(|period| ActivityType::Unavailability(period.clone()))
+                .ok_or_else(|| format!("Cannot find unavailability period for tour '{}'", tour.vehicle_id)),
             "dispatch" => shift
                 .dispatch
                 .as_ref()
                 .and_then(|dispatch| dispatch.iter().find(|d| d.location == location))
                 .map(|d| ActivityType::Depot(d.clone()))
                 .ok_or_else(|| format!("Cannot find dispatch for tour '{}'", tour.vehicle_id)),
+            "detach" | "attach" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .trailer
+                .as_ref()
+                .and_then(|trailer| {
+                    trailer.parkings.iter().find(|p| p.location == location && p.tag == activity.job_tag)
+                })
+                .map(|_| ActivityType::Trailer)
+                .ok_or_else(|| format!("Cannot find trailer parking for tour '{}'", tour.vehicle_id)),
             _ => Err(format!("Unknown activity type: '{}'", activity.activity_type)),
         }
     }
@@ -252,5 +297,17 @@ use crate::checker::relations::check_relations;
 
 mod routing;
 use crate::checker::routing::check_routing;
+
+mod trailers;
+use crate::checker::trailers::check_trailers;
+
+mod unavailability;
+use crate::checker::unavailability::check_unavailability;
+
+mod loading;
+use crate::checker::loading::check_loading_policies;
+
+mod compatibility;
+use crate::checker::compatibility::check_compatibility;
 use hashbrown::HashMap;
 use std::sync::Arc;
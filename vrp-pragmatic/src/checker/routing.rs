@@ -5,6 +5,7 @@ mod routing_test;
 use super::*;
 use crate::format::CoordIndex;
 use crate::format_time;
+use vrp_core::models::problem::get_percentile_durations;
 
 /// Checks that matrix routing information is used properly.
 pub fn check_routing(context: &CheckerContext) -> Result<(), String> {
@@ -22,6 +23,7 @@ pub fn check_routing(context: &CheckerContext) -> Result<(), String> {
             .get(profile.as_str())
             .and_then(|idx| matrices.get(*idx))
             .ok_or(format!("cannot get matrix for '{}' profile", profile))?;
+        let travel_times = get_travel_times(context, matrix)?;
         let time_offset =
             parse_time(&tour.stops.first().ok_or_else(|| "empty tour".to_string())?.time.departure) as i64;
 
@@ -38,7 +40,7 @@ pub fn check_routing(context: &CheckerContext) -> Result<(), String> {
                 let matrix_idx = from_idx * matrix_size + to_idx;
 
                 let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
-                let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
+                let duration = get_matrix_value(matrix_idx, &travel_times)?;
 
                 let time = time + duration;
                 let total_distance = total_distance + distance;
@@ -137,6 +139,32 @@ fn get_matrix_size(matrices: &[Matrix]) -> usize {
     (matrices.first().unwrap().travel_times.len() as f64).sqrt().round() as usize
 }
 
+/// Returns travel times used to plan tours: when lateness risk is minimized, they are the given
+/// percentile of travel time distribution.
+fn get_travel_times(context: &CheckerContext, matrix: &Matrix) -> Result<Vec<i64>, String> {
+    let probability = context
+        .problem
+        .objectives
+        .iter()
+        .flat_map(|objectives| objectives.primary.iter().chain(objectives.secondary.iter().flatten()))
+        .filter_map(|objective| match objective {
+            Objective::MinimizeLatenessRisk { probability } => Some(*probability),
+            _ => None,
+        })
+        .next();
+
+    match (probability, &matrix.travel_time_deviations) {
+        (Some(probability), Some(deviations)) => {
+            let durations = matrix.travel_times.iter().map(|d| *d as f64).collect::<Vec<_>>();
+            let deviations = deviations.iter().map(|d| *d as f64).collect::<Vec<_>>();
+
+            get_percentile_durations(durations.as_slice(), deviations.as_slice(), probability)
+                .map(|durations| durations.into_iter().map(|d| d.round() as i64).collect())
+        }
+        _ => Ok(matrix.travel_times.clone()),
+    }
+}
+
 fn get_matrix_value(idx: usize, matrix_values: &[i64]) -> Result<i64, String> {
     matrix_values
         .get(idx)
@@ -0,0 +1,55 @@
+use super::*;
+use vrp_core::models::common::{Load, MultiDimLoad};
+
+/// Checks that vehicle trailer is used correctly:
+/// * trailer is attached back after each detach
+/// * truck only jobs are served while trailer is detached
+/// * vehicle load fits truck capacity while trailer is detached
+pub fn check_trailers(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        if vehicle.trailer.is_none() {
+            return Ok(());
+        }
+
+        let truck_capacity = MultiDimLoad::new(get_truck_capacity(vehicle, get_volume_dimension(&context.problem)));
+        let is_relaxed = context.is_relaxed(&RelaxableConstraintType::Capacity);
+
+        let is_detached = tour.stops.iter().try_fold(false, |is_detached, stop| {
+            let is_detached = stop.activities.iter().try_fold(is_detached, |is_detached, activity| {
+                match (activity.activity_type.as_str(), is_detached) {
+                    ("detach", false) => Ok(true),
+                    ("attach", true) => Ok(false),
+                    ("detach", true) | ("attach", false) => {
+                        Err(format!("unexpected trailer activity '{}' in tour '{}'", activity.job_id, tour.vehicle_id))
+                    }
+                    _ => {
+                        let is_truck_only =
+                            context.job_map.get(&activity.job_id).map_or(false, |job| job.truck_only.unwrap_or(false));
+
+                        if is_truck_only && !is_detached {
+                            Err(format!(
+                                "truck only job '{}' is served with trailer in tour '{}'",
+                                activity.job_id, tour.vehicle_id
+                            ))
+                        } else {
+                            Ok(is_detached)
+                        }
+                    }
+                }
+            })?;
+
+            if is_detached && !is_relaxed && !truck_capacity.can_fit(&MultiDimLoad::new(stop.load.clone())) {
+                return Err(format!("load exceeds truck capacity in tour '{}'", tour.vehicle_id));
+            }
+
+            Ok(is_detached)
+        })?;
+
+        if is_detached {
+            Err(format!("trailer is not attached back in tour '{}'", tour.vehicle_id))
+        } else {
+            Ok(())
+        }
+    })
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Checks that vehicle stays at unavailability place during the whole period when its tour is used.
+pub fn check_unavailability(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let shift = context.get_vehicle_shift(tour)?;
+        let periods = shift.unavailability.as_ref().map_or(0, |periods| periods.len());
+
+        let served = tour.stops.iter().try_fold(0, |acc, stop| {
+            stop.activities.iter().try_fold(acc, |acc, activity| {
+                match context.get_activity_type(tour, stop, activity)? {
+                    ActivityType::Unavailability(period) => {
+                        let period_time = parse_time_window(&period.time);
+                        let activity_time = context.get_activity_time(stop, activity);
+
+                        if activity_time.start > period_time.start || activity_time.end < period_time.end {
+                            Err(format!(
+                                "vehicle is not idle during unavailability period in tour '{}'",
+                                tour.vehicle_id
+                            ))
+                        } else {
+                            Ok(acc + 1)
+                        }
+                    }
+                    _ => Ok(acc),
+                }
+            })
+        })?;
+
+        if served != periods {
+            Err(format!(
+                "expected {} unavailability periods, but got {} in tour '{}'",
+                periods, served, tour.vehicle_id
+            ))
+        } else {
+            Ok(())
+        }
+    })
+}
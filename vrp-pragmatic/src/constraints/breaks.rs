@@ -17,12 +17,14 @@ use vrp_core::models::solution::Activity;
 pub struct BreakModule {
     conditional: ConditionalJobModule,
     constraints: Vec<ConstraintVariant>,
+    codes: Vec<i32>,
 }
 
 impl BreakModule {
     pub fn new(code: i32) -> Self {
         Self {
             conditional: ConditionalJobModule::new(create_job_transition()),
+            codes: vec![code],
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(BreakHardRouteConstraint { code })),
                 ConstraintVariant::HardActivity(Arc::new(BreakHardActivityConstraint { code })),
@@ -51,6 +53,10 @@ impl ConstraintModule for BreakModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct BreakHardActivityConstraint {
@@ -149,6 +155,7 @@ fn is_required_job(routes: &[RouteContext], route_index: Option<usize>, job: &Jo
 /// Removes breaks which conditions are violated after ruin:
 /// * break without location served separately when original job is removed, but break is kept.
 /// * break is defined by interval, but its time is violated. This might happen due to departure time rescheduling.
+/// * break is defined by driving time, but tour driving time is below its threshold.
 fn remove_invalid_breaks(ctx: &mut SolutionContext) {
     let breaks_to_remove = ctx
         .routes
@@ -220,7 +227,15 @@ fn get_break_time_windows(break_job: &'_ Arc<Single>, departure: f64) -> impl It
     break_job.places.first().unwrap().times.iter().map(move |span| span.to_time_window(departure))
 }
 
+fn get_driving_threshold(break_job: &Arc<Single>) -> Option<f64> {
+    break_job.dimens.get_value::<f64>("driving_threshold").cloned()
+}
+
 fn is_time(rc: &RouteContext, break_job: &Arc<Single>) -> bool {
+    if let Some(threshold) = get_driving_threshold(break_job) {
+        return rc.state.get_route_state::<f64>(CONTINUOUS_DRIVING_KEY).cloned().unwrap_or(0.) >= threshold;
+    }
+
     let departure = rc.route.tour.start().unwrap().schedule.departure;
     let arrival = rc.route.tour.end().map_or(0., |end| end.schedule.arrival);
     let actual_shift_time = TimeWindow::new(departure, arrival);
@@ -229,6 +244,11 @@ fn is_time(rc: &RouteContext, break_job: &Arc<Single>) -> bool {
 }
 
 fn is_on_proper_time(rc: &RouteContext, break_job: &Arc<Single>, actual_schedule: &Schedule) -> bool {
+    // NOTE driving time break position is controlled by continuous driving time constraint
+    if get_driving_threshold(break_job).is_some() {
+        return is_time(rc, break_job);
+    }
+
     let departure = rc.route.tour.start().unwrap().schedule.departure;
     let actual_tw = TimeWindow::new(actual_schedule.arrival, actual_schedule.departure);
 
@@ -9,6 +9,7 @@ pub struct DispatchModule {
     conditional: ConditionalJobModule,
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
+    codes: Vec<i32>,
 }
 
 impl DispatchModule {
@@ -20,6 +21,7 @@ impl DispatchModule {
                 ConstraintVariant::HardActivity(Arc::new(DispatchHardActivityConstraint { code })),
             ],
             keys: vec![],
+            codes: vec![code],
         }
     }
 }
@@ -63,6 +65,10 @@ impl ConstraintModule for DispatchModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct DispatchHardActivityConstraint {
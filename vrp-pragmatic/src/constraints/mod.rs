@@ -47,7 +47,13 @@ pub use self::reloads::ReloadMultiTrip;
 mod reachable;
 pub use self::reachable::ReachableModule;
 
+mod trailers;
+pub use self::trailers::TrailerModule;
+
+mod unavailability;
+pub use self::unavailability::UnavailabilityModule;
+
 mod skills;
-pub use self::skills::JobSkills;
-pub use self::skills::SkillsModule;
+pub use self::skills::{has_skills, SkillsModule};
+pub use self::skills::{JobSkills, SkillIndex, SkillSet};
 use vrp_core::construction::heuristics::RouteContext;
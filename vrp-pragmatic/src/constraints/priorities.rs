@@ -3,7 +3,7 @@ use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
+use vrp_core::models::common::PriorityDimension;
 use vrp_core::models::problem::Job;
 use vrp_core::utils::compare_floats;
 
@@ -11,6 +11,7 @@ use vrp_core::utils::compare_floats;
 pub struct PriorityModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
+    codes: Vec<i32>,
 }
 
 impl PriorityModule {
@@ -21,6 +22,7 @@ impl PriorityModule {
                 ConstraintVariant::HardActivity(Arc::new(PriorityHardActivityConstraint { code })),
             ],
             keys: vec![],
+            codes: vec![code],
         }
     }
 }
@@ -39,6 +41,10 @@ impl ConstraintModule for PriorityModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct PrioritySoftRouteConstraint {}
@@ -92,9 +98,5 @@ impl HardActivityConstraint for PriorityHardActivityConstraint {
 }
 
 fn get_priority(job: &Job) -> Option<i32> {
-    match job {
-        Job::Single(job) => job.dimens.get_value::<i32>("priority"),
-        Job::Multi(job) => job.dimens.get_value::<i32>("priority"),
-    }
-    .cloned()
+    job.dimens().get_priority()
 }
@@ -7,6 +7,7 @@ use vrp_core::models::problem::{Job, TransportCost};
 pub struct ReachableModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
+    codes: Vec<i32>,
 }
 
 impl ReachableModule {
@@ -17,6 +18,7 @@ impl ReachableModule {
                 code,
             }))],
             keys: vec![],
+            codes: vec![code],
         }
     }
 }
@@ -35,6 +37,10 @@ impl ConstraintModule for ReachableModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct ReachableHardActivityConstraint {
@@ -2,43 +2,137 @@
 #[path = "../../tests/unit/constraints/skills_test.rs"]
 mod skills_test;
 
-use hashbrown::HashSet;
+use hashbrown::HashMap;
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::Job;
+use vrp_core::models::common::{Cost, ValueDimension};
+use vrp_core::models::problem::{Actor, Job};
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
     /// Vehicle should have all of these skills defined.
-    pub all_of: Option<HashSet<String>>,
+    pub all_of: Option<SkillSet>,
     /// Vehicle should have at least one of these skills defined.
-    pub one_of: Option<HashSet<String>>,
+    pub one_of: Option<SkillSet>,
     /// Vehicle should have none of these skills defined.
-    pub none_of: Option<HashSet<String>>,
+    pub none_of: Option<SkillSet>,
+    /// Vehicle is preferred to have these skills defined: a skill index with a cost penalty
+    /// applied when vehicle has no such skill.
+    pub preferred: Option<Vec<(usize, Cost)>>,
 }
 
-/// A skills module provides way to control jobs/vehicle assignment.
+/// Keeps skill names interned into indices, so skills can be matched using bitsets instead of
+/// comparing sets of strings.
+#[derive(Default)]
+pub struct SkillIndex {
+    indices: HashMap<String, usize>,
+}
+
+impl SkillIndex {
+    /// Creates a new instance of `SkillIndex` from all skill names used in the problem.
+    pub fn new<'a>(skills: impl Iterator<Item = &'a String>) -> Self {
+        let mut names = skills.collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+
+        Self { indices: names.into_iter().enumerate().map(|(idx, name)| (name.clone(), idx)).collect() }
+    }
+
+    /// Returns index of the skill with given name.
+    pub fn get_index(&self, skill: &str) -> Option<usize> {
+        self.indices.get(skill).cloned()
+    }
+
+    /// Creates a skill set from given skill names. Names which are not in the index are ignored.
+    pub fn create_set<'a>(&self, skills: impl Iterator<Item = &'a String>) -> SkillSet {
+        skills.filter_map(|skill| self.indices.get(skill)).fold(SkillSet::new(self.indices.len()), |mut set, idx| {
+            set.insert(*idx);
+            set
+        })
+    }
+}
+
+/// A fixed size bitset of skills where each bit corresponds to the skill index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SkillSet {
+    bits: Vec<u64>,
+}
+
+impl SkillSet {
+    /// Creates an empty skill set which can keep given amount of skills.
+    pub fn new(size: usize) -> Self {
+        Self { bits: vec![0; (size + 63) / 64] }
+    }
+
+    /// Adds skill with given index.
+    pub fn insert(&mut self, index: usize) {
+        let word = index / 64;
+        if word >= self.bits.len() {
+            self.bits.resize(word + 1, 0);
+        }
+
+        self.bits[word] |= 1 << (index % 64);
+    }
+
+    /// Returns true if set has no skills.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|word| *word == 0)
+    }
+
+    /// Returns true if set has skill with given index.
+    pub fn contains(&self, index: usize) -> bool {
+        self.get_word(index / 64) & (1 << (index % 64)) != 0
+    }
+
+    /// Returns true if all skills of the set are in the other one.
+    pub fn is_subset(&self, other: &SkillSet) -> bool {
+        self.bits.iter().enumerate().all(|(idx, word)| word & !other.get_word(idx) == 0)
+    }
+
+    /// Returns true if sets have no skills in common.
+    pub fn is_disjoint(&self, other: &SkillSet) -> bool {
+        self.bits.iter().enumerate().all(|(idx, word)| word & other.get_word(idx) == 0)
+    }
+
+    fn get_word(&self, idx: usize) -> u64 {
+        self.bits.get(idx).cloned().unwrap_or(0)
+    }
+}
+
+/// A skills module provides way to control jobs/vehicle assignment. Preferred skills do not limit
+/// assignment, but add their penalty to the cost of the route served by a vehicle without them.
 pub struct SkillsModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
+    codes: Vec<i32>,
 }
 
 impl SkillsModule {
     pub fn new(code: i32) -> Self {
         Self {
-            constraints: vec![ConstraintVariant::HardRoute(Arc::new(SkillsHardRouteConstraint { code }))],
-            keys: vec![],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(SkillsHardRouteConstraint { code })),
+                ConstraintVariant::SoftRoute(Arc::new(SkillsSoftRouteConstraint {})),
+            ],
+            keys: vec![ROUTE_PENALTY_KEY],
+            codes: vec![code],
         }
     }
 }
 
 impl ConstraintModule for SkillsModule {
-    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
 
-    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let penalty =
+            ctx.route.tour.jobs().map(|job| get_preferred_skills_penalty(ctx.route.actor.as_ref(), &job)).sum::<Cost>();
+
+        ctx.state_mut().put_route_state(ROUTE_PENALTY_KEY, penalty);
+    }
 
     fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
 
@@ -49,6 +143,10 @@ impl ConstraintModule for SkillsModule {
     fn get_constraints(&self) -> Iter<ConstraintVariant> {
         self.constraints.iter()
     }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
 }
 
 struct SkillsHardRouteConstraint {
@@ -57,23 +155,49 @@ struct SkillsHardRouteConstraint {
 
 impl HardRouteConstraint for SkillsHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
-        let job_skills = job.dimens().get_value::<JobSkills>("skills");
-        let vehicle_skills = ctx.route.actor.vehicle.dimens.get_value::<HashSet<String>>("skills");
-
-        if let Some(job_skills) = job_skills {
-            let is_ok = check_all_of(job_skills, &vehicle_skills)
-                && check_one_of(job_skills, &vehicle_skills)
-                && check_none_of(job_skills, &vehicle_skills);
-            if !is_ok {
-                return Some(RouteConstraintViolation { code: self.code });
-            }
+        if has_skills(ctx.route.actor.as_ref(), job) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
         }
+    }
+}
+
+struct SkillsSoftRouteConstraint {}
 
-        None
+impl SoftRouteConstraint for SkillsSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        get_preferred_skills_penalty(ctx.route.actor.as_ref(), job)
     }
 }
 
-fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
+/// Checks whether the actor's vehicle has skills required by the job.
+pub fn has_skills(actor: &Actor, job: &Job) -> bool {
+    let job_skills = job.dimens().get_value::<JobSkills>("skills");
+    let vehicle_skills = actor.vehicle.dimens.get_value::<SkillSet>("skills");
+
+    job_skills.map_or(true, |job_skills| {
+        check_all_of(job_skills, &vehicle_skills)
+            && check_one_of(job_skills, &vehicle_skills)
+            && check_none_of(job_skills, &vehicle_skills)
+    })
+}
+
+/// Returns total penalty of the job's preferred skills which the actor's vehicle does not have.
+pub fn get_preferred_skills_penalty(actor: &Actor, job: &Job) -> Cost {
+    let preferred = job.dimens().get_value::<JobSkills>("skills").and_then(|job_skills| job_skills.preferred.as_ref());
+    let vehicle_skills = actor.vehicle.dimens.get_value::<SkillSet>("skills");
+
+    preferred.map_or(0., |preferred| {
+        preferred
+            .iter()
+            .filter(|(index, _)| !matches!(vehicle_skills, Some(skills) if skills.contains(*index)))
+            .map(|(_, penalty)| *penalty)
+            .sum()
+    })
+}
+
+fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&SkillSet>) -> bool {
     match (job_skills.all_of.as_ref(), vehicle_skills) {
         (Some(job_skills), Some(vehicle_skills)) => job_skills.is_subset(vehicle_skills),
         (Some(skills), None) if skills.is_empty() => true,
@@ -82,16 +206,16 @@ fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>
     }
 }
 
-fn check_one_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
+fn check_one_of(job_skills: &JobSkills, vehicle_skills: &Option<&SkillSet>) -> bool {
     match (job_skills.one_of.as_ref(), vehicle_skills) {
-        (Some(job_skills), Some(vehicle_skills)) => job_skills.iter().any(|skill| vehicle_skills.contains(skill)),
+        (Some(job_skills), Some(vehicle_skills)) => !job_skills.is_disjoint(vehicle_skills),
         (Some(skills), None) if skills.is_empty() => true,
         (Some(_), None) => false,
         _ => true,
     }
 }
 
-fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
+fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&SkillSet>) -> bool {
     match (job_skills.none_of.as_ref(), vehicle_skills) {
         (Some(job_skills), Some(vehicle_skills)) => job_skills.is_disjoint(vehicle_skills),
         _ => true,
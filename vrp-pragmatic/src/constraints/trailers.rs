@@ -0,0 +1,335 @@
+use crate::constraints::*;
+use crate::format::TRAILER_SEGMENT_KEY;
+use hashbrown::HashSet;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Demand, DemandDimension, Load, ValueDimension};
+use vrp_core::models::problem::Job;
+
+/// Implements truck and trailer functionality: jobs marked as truck only can be served only while
+/// vehicle's trailer is parked. Trailer is detached and attached back at the same parking place which
+/// is modeled as a conditional multi job. While trailer is detached, vehicle load is limited by truck capacity.
+pub struct TrailerModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    code: i32,
+    phantom: PhantomData<T>,
+    codes: Vec<i32>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> TrailerModule<T> {
+    pub fn new(code: i32) -> Self {
+        Self {
+            conditional: ConditionalJobModule::new(create_job_transition()),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(TrailerHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(TrailerHardActivityConstraint::<T> {
+                    code,
+                    phantom: PhantomData,
+                })),
+            ],
+            keys: vec![TRAILER_SEGMENT_KEY],
+            codes: vec![code],
+            code,
+            phantom: PhantomData,
+        }
+    }
+
+    fn recalculate_states(&self, ctx: &mut RouteContext) {
+        if get_truck_capacity::<T>(ctx).is_none() {
+            return;
+        }
+
+        let tour = &ctx.route.tour;
+        let detach = tour.all_activities().position(|activity| is_trailer_activity(activity, "detach"));
+        let attach = tour.all_activities().position(|activity| is_trailer_activity(activity, "attach"));
+
+        let segment = detach.map(|detach| {
+            let end = attach.unwrap_or_else(|| tour.total() - 1);
+            let max_load = tour.activities_slice(detach, end).iter().fold(T::default(), |max, activity| {
+                max.max_load(
+                    ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity).cloned().unwrap_or_default(),
+                )
+            });
+
+            TrailerSegment { detach, attach, max_load }
+        });
+
+        ctx.state_mut().put_route_state(TRAILER_SEGMENT_KEY, segment);
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for TrailerModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.conditional.accept_insertion(solution_ctx, route_index, job);
+        self.recalculate_states(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.recalculate_states(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.recalculate_states(route_ctx);
+        });
+
+        // NOTE trailer jobs are promoted only on insertion: otherwise, they would be kept required
+        // for tours with truck only jobs which cannot be served
+        remove_invalid_trailer_usage::<T>(ctx, self.code);
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
+}
+
+/// Specifies a part of the tour where trailer is detached. Attach index is absent when trailer is
+/// not picked up yet, e.g. during multi job insertion.
+struct TrailerSegment<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    detach: usize,
+    attach: Option<usize>,
+    max_load: T,
+}
+
+/// Locks trailer jobs to specific vehicles and allows to park trailer only once per tour.
+struct TrailerHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TrailerHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if let Some(multi) = job.as_multi().filter(|_| is_trailer_job(job)) {
+            let is_assignable = multi.jobs.first().map_or(false, |single| is_single_belongs_to_route(ctx, single))
+                && !has_trailer_activities(ctx);
+
+            if !is_assignable {
+                return Some(RouteConstraintViolation { code: self.code });
+            }
+        }
+
+        None
+    }
+}
+
+/// Keeps truck only jobs between trailer detach and attach activities and checks truck capacity.
+struct TrailerHardActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    code: i32,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> TrailerHardActivityConstraint<T> {
+    fn stop(&self, stopped: bool) -> Option<ActivityConstraintViolation> {
+        Some(ActivityConstraintViolation { code: self.code, stopped })
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstraint
+    for TrailerHardActivityConstraint<T>
+{
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let truck_capacity = get_truck_capacity::<T>(route_ctx)?;
+        let segment =
+            route_ctx.state.get_route_state::<Option<TrailerSegment<T>>>(TRAILER_SEGMENT_KEY).and_then(|s| s.as_ref());
+        let index = activity_ctx.index;
+        let target = activity_ctx.target;
+        let tour = &route_ctx.route.tour;
+
+        if is_trailer_activity(target, "detach") {
+            let first_truck_only = tour.all_activities().position(is_truck_only_activity);
+
+            return match first_truck_only {
+                Some(first) if first <= index => self.stop(true),
+                _ => None,
+            };
+        }
+
+        if is_trailer_activity(target, "attach") {
+            let detach = match segment {
+                Some(segment) if segment.attach.is_none() && segment.detach <= index => segment.detach,
+                _ => return self.stop(false),
+            };
+
+            let last_truck_only = tour.all_activities().rposition(is_truck_only_activity);
+            if last_truck_only.map_or(false, |last| last > index) {
+                return self.stop(false);
+            }
+
+            let max_load = tour.activities_slice(detach, index).iter().fold(T::default(), |max, activity| {
+                max.max_load(
+                    route_ctx
+                        .state
+                        .get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity)
+                        .cloned()
+                        .unwrap_or_default(),
+                )
+            });
+
+            return if truck_capacity.can_fit(&max_load) { None } else { self.stop(true) };
+        }
+
+        if is_truck_only_activity(target) {
+            // NOTE truck only job can be inserted into tour without parked trailer: trailer job is
+            // promoted to required and has to be inserted around it later
+            match segment {
+                Some(segment) if segment.detach > index => return self.stop(false),
+                Some(segment) if segment.attach.map_or(false, |attach| index >= attach) => return self.stop(true),
+                _ => {}
+            }
+        }
+
+        match (segment, target.job.as_ref().and_then(|single| single.dimens.get_demand())) {
+            (Some(segment), Some(demand)) => {
+                if has_truck_overload(segment, demand, &truck_capacity, index) {
+                    self.stop(false)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Checks whether demand inserted at given index overloads truck while trailer is detached.
+/// NOTE this is an approximation: max load of the whole segment is used, so the check is conservative.
+fn has_truck_overload<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    segment: &TrailerSegment<T>,
+    demand: &Demand<T>,
+    truck_capacity: &T,
+    index: usize,
+) -> bool {
+    // static delivery increases load before the activity, pickup increases it after
+    let is_after_detach = index >= segment.detach;
+    let is_before_attach = segment.attach.map_or(true, |attach| index < attach);
+
+    let delivery = if is_after_detach { demand.delivery.0 } else { T::default() };
+    let pickup = if is_before_attach { demand.pickup.0 + demand.pickup.1 } else { T::default() };
+
+    !truck_capacity.can_fit(&(segment.max_load + delivery + pickup))
+}
+
+fn create_job_transition() -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: |ctx, _, job| is_trailer_job(job) && !is_required_trailer_job(ctx, job),
+        promote_required: |ctx, _, job| is_trailer_job(job) && is_required_trailer_job(ctx, job),
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, _| false,
+    })
+}
+
+/// Trailer job is required when its vehicle has not parked trailer yet and there are truck only
+/// jobs to be served.
+fn is_required_trailer_job(ctx: &SolutionContext, job: &Job) -> bool {
+    let route_ctx = ctx.routes.iter().find(|route_ctx| is_trailer_job_of_route(route_ctx, job));
+
+    match route_ctx {
+        Some(route_ctx) if has_trailer_activities(route_ctx) => false,
+        Some(route_ctx) if route_ctx.route.tour.all_activities().any(is_truck_only_activity) => true,
+        _ => ctx.required.iter().any(is_truck_only_job),
+    }
+}
+
+/// Removes trailer jobs from tours without truck only jobs and truck only jobs from tours without
+/// parked trailer, unless trailer job is still required. Also moves unassigned trailer jobs to ignored.
+fn remove_invalid_trailer_usage<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    ctx: &mut SolutionContext,
+    code: i32,
+) {
+    let mut extra_ignored = Vec::new();
+    let mut extra_unassigned = Vec::new();
+    let required = ctx.required.iter().filter(|job| is_trailer_job(job)).cloned().collect::<Vec<_>>();
+
+    ctx.routes.iter_mut().filter(|route_ctx| get_truck_capacity::<T>(route_ctx).is_some()).for_each(|route_ctx| {
+        let jobs = route_ctx.route.tour.jobs().collect::<Vec<_>>();
+        let trailer_jobs = jobs.iter().filter(|job| is_trailer_job(job)).cloned().collect::<Vec<_>>();
+        let truck_only_jobs = jobs.iter().filter(|job| is_truck_only_job(job)).cloned().collect::<Vec<_>>();
+
+        let jobs_to_remove = match (trailer_jobs.is_empty(), truck_only_jobs.is_empty()) {
+            (false, true) => {
+                extra_ignored.extend(trailer_jobs.iter().cloned());
+                trailer_jobs
+            }
+            (true, false) if !required.iter().any(|job| is_trailer_job_of_route(route_ctx, job)) => {
+                extra_unassigned.extend(truck_only_jobs.iter().map(|job| (job.clone(), code)));
+                truck_only_jobs
+            }
+            _ => vec![],
+        };
+
+        jobs_to_remove.iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+    });
+
+    // NOTE remove tours which had trailer jobs only
+    let registry = &mut ctx.registry;
+    ctx.routes.retain(|rc| {
+        if rc.route.tour.has_jobs() {
+            true
+        } else {
+            registry.free_route(rc);
+            false
+        }
+    });
+
+    let unassigned = ctx.unassigned.keys().filter(|job| is_trailer_job(job)).cloned().collect::<HashSet<_>>();
+    ctx.unassigned.retain(|job, _| !unassigned.contains(job));
+
+    ctx.ignored.extend(extra_ignored.into_iter().chain(unassigned));
+    ctx.unassigned.extend(extra_unassigned);
+}
+
+//region Helpers
+
+fn get_truck_capacity<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(route_ctx: &RouteContext) -> Option<T> {
+    route_ctx.route.actor.vehicle.dimens.get_value::<T>("truck_capacity").cloned()
+}
+
+fn has_trailer_activities(route_ctx: &RouteContext) -> bool {
+    route_ctx.route.tour.all_activities().any(|activity| is_trailer_activity(activity, "detach"))
+}
+
+fn is_trailer_job(job: &Job) -> bool {
+    job.as_multi().and_then(|multi| multi.dimens.get_value::<String>("type")).map_or(false, |t| t == "trailer")
+}
+
+fn is_trailer_job_of_route(route_ctx: &RouteContext, job: &Job) -> bool {
+    job.as_multi()
+        .and_then(|multi| multi.jobs.first())
+        .map_or(false, |single| is_single_belongs_to_route(route_ctx, single))
+}
+
+fn is_truck_only_job(job: &Job) -> bool {
+    job.dimens().get_value::<bool>("truck_only").cloned().unwrap_or(false)
+}
+
+fn is_trailer_activity(activity: &Activity, activity_type: &str) -> bool {
+    as_single_job(activity, |single| {
+        single.dimens.get_value::<String>("type").map_or(false, |t| t == activity_type)
+            && single.dimens.get_value::<String>("vehicle_id").is_some()
+    })
+    .is_some()
+}
+
+fn is_truck_only_activity(activity: &Activity) -> bool {
+    activity.retrieve_job().map_or(false, |job| is_truck_only_job(&job))
+}
+
+//endregion
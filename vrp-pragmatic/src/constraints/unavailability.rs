@@ -0,0 +1,117 @@
+use crate::constraints::*;
+use crate::format::UNASSIGNABLE_ROUTE_KEY;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::Job;
+
+/// Implements vehicle unavailability periods: each period is modeled as a job with fixed time
+/// which is inserted into the tour once it is created and locked there. As its duration covers
+/// the whole period, timing constraint prevents scheduling other activities within it.
+pub struct UnavailabilityModule {
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+}
+
+impl UnavailabilityModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            conditional: ConditionalJobModule::new(create_job_transition()),
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(UnavailabilityHardRouteConstraint { code }))],
+            keys: vec![],
+            codes: vec![code],
+        }
+    }
+}
+
+impl ConstraintModule for UnavailabilityModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.conditional.accept_route_state(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE enforce propagation to locked
+        ctx.locked.extend(ctx.routes.iter().flat_map(|route| route.route.tour.jobs().filter(is_unavailability_job)));
+
+        self.conditional.accept_solution_state(ctx);
+
+        // NOTE remove tours which have no jobs to serve
+        let registry = &mut ctx.registry;
+        ctx.routes.retain(|rc| {
+            let has_jobs_to_serve =
+                rc.route.tour.jobs().any(|job| !is_unavailability_job(&job) && !is_dispatch_job(&job));
+
+            if !has_jobs_to_serve {
+                registry.free_route(rc);
+            }
+
+            has_jobs_to_serve
+        });
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
+}
+
+/// Locks unavailability jobs to specific vehicles and prevents usage of routes where they cannot be scheduled.
+struct UnavailabilityHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for UnavailabilityHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        _: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let is_assignable = match job.as_single() {
+            Some(single) if is_unavailability_single(single) => is_single_belongs_to_route(route_ctx, single),
+            _ => !is_unassignable_route(route_ctx),
+        };
+
+        if is_assignable {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+fn create_job_transition() -> Box<dyn JobContextTransition + Send + Sync> {
+    Box::new(ConcreteJobContextTransition {
+        remove_required: |_, _, job| is_unavailability_job(job),
+        promote_required: |_, _, _| false,
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, job| is_unavailability_job(job),
+    })
+}
+
+fn is_unavailability_job(job: &Job) -> bool {
+    job.as_single().map_or(false, is_unavailability_single)
+}
+
+fn is_unavailability_single(single: &Arc<Single>) -> bool {
+    single.dimens.get_value::<String>("type").map_or(false, |t| t == "unavailability")
+}
+
+fn is_dispatch_job(job: &Job) -> bool {
+    job.as_single().and_then(|single| single.dimens.get_value::<String>("type")).map_or(false, |t| t == "dispatch")
+}
+
+fn is_unassignable_route(route_ctx: &RouteContext) -> bool {
+    route_ctx.state.get_route_state::<bool>(UNASSIGNABLE_ROUTE_KEY).cloned().unwrap_or(false)
+}
@@ -1,6 +1,7 @@
 //! Contains some algorithm extensions.
 
 mod only_vehicle_activity_cost;
+pub(crate) use self::only_vehicle_activity_cost::get_crew_schedule;
 pub use self::only_vehicle_activity_cost::OnlyVehicleActivityCost;
 
 mod route_modifier;
@@ -1,8 +1,11 @@
-use vrp_core::models::common::{Cost, Timestamp};
+use std::iter::once;
+use vrp_core::models::common::{Cost, Duration, Timestamp, ValueDimension};
 use vrp_core::models::problem::{ActivityCost, Actor};
 use vrp_core::models::solution::Activity;
 
-/// Uses costs only for vehicle ignoring costs of driver.
+/// Uses costs only for vehicle ignoring costs of driver. When vehicle has a crew, jobs at the
+/// same stop are served in parallel, so service time of the stop is a makespan of its jobs
+/// distributed between crew members.
 pub struct OnlyVehicleActivityCost {}
 
 impl ActivityCost for OnlyVehicleActivityCost {
@@ -12,6 +15,33 @@ impl ActivityCost for OnlyVehicleActivityCost {
 
         waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
     }
+
+    fn duration_after(
+        &self,
+        actor: &Actor,
+        previous: &mut dyn Iterator<Item = &Activity>,
+        activity: &Activity,
+        arrival: Timestamp,
+    ) -> Cost {
+        let duration = self.duration(actor, activity, arrival);
+
+        match get_crew_size(actor) {
+            Some(crew_size) if is_job_activity(activity) => {
+                let mut durations = previous
+                    .take_while(|prev| is_same_stop(prev, activity))
+                    .map(|prev| self.duration(actor, prev, prev.schedule.arrival))
+                    .collect::<Vec<_>>();
+                durations.reverse();
+
+                // NOTE service time of the job is an increase of the stop's makespan
+                let (_, before) = assign_crew(durations.iter().cloned(), crew_size);
+                let (_, after) = assign_crew(durations.iter().cloned().chain(once(duration)), crew_size);
+
+                after - before
+            }
+            _ => duration,
+        }
+    }
 }
 
 impl Default for OnlyVehicleActivityCost {
@@ -19,3 +49,84 @@ impl Default for OnlyVehicleActivityCost {
         Self {}
     }
 }
+
+/// Returns a crew member and time when it starts serving the activity for each given activity.
+/// The value is specified only for job activities of vehicle which has a crew.
+pub(crate) fn get_crew_schedule(actor: &Actor, activities: &[Activity]) -> Vec<Option<(usize, Timestamp)>> {
+    let crew_size = if let Some(crew_size) = get_crew_size(actor) {
+        crew_size
+    } else {
+        return vec![None; activities.len()];
+    };
+
+    let mut schedule = Vec::with_capacity(activities.len());
+    let mut idx = 0;
+
+    while idx < activities.len() {
+        let first = &activities[idx];
+        if !is_job_activity(first) {
+            schedule.push(None);
+            idx += 1;
+            continue;
+        }
+
+        let stop =
+            once(first)
+                .chain(activities[idx + 1..].iter().take_while(|activity| {
+                    is_job_activity(activity) && activity.place.location == first.place.location
+                }))
+                .collect::<Vec<_>>();
+        let start = first.schedule.arrival.max(first.place.time.start);
+        let (members, _) = assign_crew(stop.iter().map(|activity| activity.place.duration), crew_size);
+
+        schedule.extend(
+            members
+                .into_iter()
+                .zip(stop.iter())
+                .map(|((member, offset), activity)| Some((member, (start + offset).max(activity.place.time.start)))),
+        );
+        idx += stop.len();
+    }
+
+    schedule
+}
+
+/// Distributes jobs served at the same stop between crew members: each next job is assigned to
+/// the member who becomes free first. Returns member and start time offset for each job together
+/// with total service time of the stop.
+fn assign_crew(durations: impl Iterator<Item = Duration>, crew_size: usize) -> (Vec<(usize, Duration)>, Duration) {
+    let mut loads = vec![0.; crew_size];
+
+    let members =
+        durations
+            .map(|duration| {
+                let (member, offset) = loads.iter().cloned().enumerate().fold((0, f64::MAX), |acc, (member, load)| {
+                    if load < acc.1 {
+                        (member, load)
+                    } else {
+                        acc
+                    }
+                });
+                loads[member] = offset + duration;
+
+                (member, offset)
+            })
+            .collect();
+
+    (members, loads.into_iter().fold(0., f64::max))
+}
+
+fn get_crew_size(actor: &Actor) -> Option<usize> {
+    actor.vehicle.dimens.get_value::<usize>("crew_size").cloned().filter(|&crew_size| crew_size > 1)
+}
+
+fn is_job_activity(activity: &Activity) -> bool {
+    match activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type")) {
+        Some(activity_type) => matches!(activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service"),
+        None => false,
+    }
+}
+
+fn is_same_stop(prev: &Activity, activity: &Activity) -> bool {
+    prev.place.location == activity.place.location && is_job_activity(prev)
+}
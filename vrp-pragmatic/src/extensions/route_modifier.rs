@@ -3,53 +3,71 @@ use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
 use vrp_core::construction::heuristics::*;
 use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
 use vrp_core::utils::compare_floats;
 
 /// Returns route modifier.
 pub fn get_route_modifier(constraint: Arc<ConstraintPipeline>, job_index: JobIndex) -> RouteModifier {
     RouteModifier::new(move |route_ctx: RouteContext| {
-        let actor = &route_ctx.route.actor;
+        let actor = route_ctx.route.actor.clone();
         let vehicle = &actor.vehicle;
 
         let shift_index = vehicle.dimens.get_value::<usize>("shift_index").expect("cannot find shift index");
         let vehicle_id = vehicle.dimens.get_id().expect("cannot get vehicle id");
 
-        let candidates = (1..)
-            .map(|idx| format!("{}_dispatch_{}_{}", vehicle_id, shift_index, idx))
-            .map(|job_id| job_index.get(&job_id))
-            .take_while(|job| job.is_some())
-            .collect::<Vec<_>>();
-
-        let result = candidates
-            .iter()
-            .filter_map(|job| {
-                job.map(|job| {
-                    evaluate_job_constraint_in_route(job, &constraint, &route_ctx, InsertionPosition::Last, 0., None)
-                })
-                .and_then(|result| match result {
-                    InsertionResult::Success(success) => Some(success),
-                    _ => None,
-                })
-            })
-            .min_by(|a, b| compare_floats(a.cost, b.cost));
-
-        if let Some(success) = result {
-            let mut route_ctx = success.context;
-            let route = route_ctx.route_mut();
-            success.activities.into_iter().for_each(|(activity, index)| {
-                route.tour.insert_at(activity, index + 1);
-            });
-            constraint.accept_route_state(&mut route_ctx);
+        let get_candidates = |job_type: &str| {
+            (1..)
+                .map(|idx| format!("{}_{}_{}_{}", vehicle_id, job_type, shift_index, idx))
+                .map(|job_id| job_index.get(&job_id))
+                .take_while(|job| job.is_some())
+                .flatten()
+                .collect::<Vec<_>>()
+        };
 
-            route_ctx
+        let dispatch = get_candidates("dispatch");
+        let unavailability = get_candidates("unavailability");
+
+        // NOTE dispatch is one of alternatives, unavailability periods have to be inserted all
+        let route_ctx = if dispatch.is_empty() {
+            Some(route_ctx)
         } else {
-            let mut route_ctx = route_ctx;
+            insert_job(&constraint, route_ctx, &dispatch, InsertionPosition::Last)
+        };
 
-            if !candidates.is_empty() {
-                route_ctx.state_mut().put_route_state(UNASSIGNABLE_ROUTE_KEY, true);
-            }
+        let route_ctx = unavailability.into_iter().fold(route_ctx, |route_ctx, job| {
+            route_ctx.and_then(|route_ctx| insert_job(&constraint, route_ctx, &[job], InsertionPosition::Any))
+        });
+
+        route_ctx.unwrap_or_else(|| {
+            let mut route_ctx = RouteContext::new(actor);
+            route_ctx.state_mut().put_route_state(UNASSIGNABLE_ROUTE_KEY, true);
 
             route_ctx
-        }
+        })
     })
 }
+
+/// Inserts the cheapest of candidate jobs into the route. Returns None if none of them can be inserted.
+fn insert_job(
+    constraint: &ConstraintPipeline,
+    route_ctx: RouteContext,
+    candidates: &[&Job],
+    position: InsertionPosition,
+) -> Option<RouteContext> {
+    let result = candidates
+        .iter()
+        .filter_map(|job| match evaluate_job_constraint_in_route(job, constraint, &route_ctx, position, 0., None) {
+            InsertionResult::Success(success) => Some(success),
+            _ => None,
+        })
+        .min_by(|a, b| compare_floats(a.cost, b.cost))?;
+
+    let mut route_ctx = result.context;
+    let route = route_ctx.route_mut();
+    result.activities.into_iter().for_each(|(activity, index)| {
+        route.tour.insert_at(activity, index + 1);
+    });
+    constraint.accept_route_state(&mut route_ctx);
+
+    Some(route_ctx)
+}
@@ -34,6 +34,10 @@ impl CoordIndex {
             vehicle.shifts.iter().for_each(|shift| {
                 index.add(&shift.start.location);
 
+                if let Some(driver) = &shift.start.driver {
+                    index.add(&driver.location);
+                }
+
                 if let Some(end) = &shift.end {
                     index.add(&end.location);
                 }
@@ -53,7 +57,15 @@ impl CoordIndex {
                 if let Some(reloads) = &shift.reloads {
                     reloads.iter().for_each(|reload| index.add(&reload.location));
                 }
+
+                if let Some(unavailability) = &shift.unavailability {
+                    unavailability.iter().for_each(|place| index.add(&place.location));
+                }
             });
+
+            if let Some(trailer) = &vehicle.trailer {
+                trailer.parkings.iter().for_each(|parking| index.add(&parking.location));
+            }
         });
 
         index
@@ -120,8 +120,17 @@ const PRIORITY_CONSTRAINT_CODE: i32 = 9;
 const AREA_CONSTRAINT_CODE: i32 = 10;
 const DISPATCH_CONSTRAINT_CODE: i32 = 11;
 const TOUR_SIZE_CONSTRAINT_CODE: i32 = 12;
+const TRAILER_CONSTRAINT_CODE: i32 = 13;
+const UNAVAILABILITY_CONSTRAINT_CODE: i32 = 14;
+const MAX_RADIUS_CONSTRAINT_CODE: i32 = 15;
+const TEAM_DRIVING_CONSTRAINT_CODE: i32 = 16;
+const DRIVING_REST_CONSTRAINT_CODE: i32 = 17;
+const LOADING_POLICY_CONSTRAINT_CODE: i32 = 18;
+const VALUE_CONSTRAINT_CODE: i32 = 19;
+const COMPATIBILITY_CONSTRAINT_CODE: i32 = 20;
 
 pub(crate) const UNASSIGNABLE_ROUTE_KEY: i32 = 100;
+pub(crate) const TRAILER_SEGMENT_KEY: i32 = 101;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
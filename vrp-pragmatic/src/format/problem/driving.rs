@@ -0,0 +1,36 @@
+//! Contains logic to map driving time breaks to continuous driving time limits.
+
+use crate::format::problem::{VehicleBreak, VehicleBreakPolicy, VehicleBreakTime};
+use vrp_core::construction::constraints::DrivingLimit;
+
+/// Returns for each break its driving time limits as (threshold, max continuous driving time)
+/// pair, if the break uses driving time policy. The break becomes required once driving time
+/// of the tour reaches threshold: offset start plus offset end per each preceding driving time
+/// break of the shift.
+pub(crate) fn get_driving_break_limits(breaks: &[VehicleBreak]) -> Vec<Option<(f64, f64)>> {
+    breaks
+        .iter()
+        .scan(0., |preceding, vehicle_break| {
+            Some(match (&vehicle_break.policy, &vehicle_break.time) {
+                (Some(VehicleBreakPolicy::DrivingTime), VehicleBreakTime::TimeOffset(offset)) if offset.len() == 2 => {
+                    let (start, end) = (*offset.first().unwrap(), *offset.last().unwrap());
+                    let threshold = *preceding + start;
+                    *preceding += end;
+
+                    Some((threshold, end))
+                }
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+/// Returns continuous driving time limit defined by driving time breaks, if there are any.
+pub(crate) fn get_driving_limit(breaks: &[VehicleBreak]) -> Option<DrivingLimit> {
+    get_driving_break_limits(breaks).into_iter().flatten().map(|(_, max)| max).fold(None, |acc, max| {
+        Some(acc.map_or(DrivingLimit { max_driving_time: max, rests: 1 }, |acc: DrivingLimit| DrivingLimit {
+            max_driving_time: acc.max_driving_time.min(max),
+            rests: acc.rests + 1,
+        }))
+    })
+}
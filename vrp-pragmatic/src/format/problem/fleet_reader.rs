@@ -2,16 +2,18 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
+use crate::constraints::{SkillIndex, SkillSet};
 use crate::extensions::create_typed_actor_groups;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::{get_driving_limit, get_truck_capacity, get_vehicle_capacity, Matrix, Objective};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
-use vrp_core::construction::constraints::{Area, TravelLimitFunc};
+use vrp_core::construction::constraints::{is_location_in_area, Area, DrivingTeam, TravelLimitFunc};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
+use vrp_core::solver::objectives::TravelDeviationFunc;
 
 pub(crate) fn create_transport_costs(
     api_problem: &ApiProblem,
@@ -35,6 +37,8 @@ pub(crate) fn create_transport_costs(
         ));
     }
 
+    let probability = get_lateness_risk_probability(api_problem);
+
     let matrix_data = matrices
         .iter()
         .enumerate()
@@ -63,19 +67,160 @@ pub(crate) fn create_transport_costs(
                 )
             };
 
-            MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
+            let durations = match (probability, &matrix.travel_time_deviations) {
+                (Some(probability), Some(deviations)) => {
+                    let deviations = deviations.iter().map(|d| *d as f64).collect::<Vec<_>>();
+                    get_percentile_durations(durations.as_slice(), deviations.as_slice(), probability)?
+                }
+                _ => durations,
+            };
+
+            Ok(MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances))
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>, String>>()?;
 
     let matrix_profiles = matrix_data.iter().map(|data| data.profile).collect::<HashSet<_>>().len();
     if fleet_profiles.len() != matrix_profiles {
         return Err("amount of fleet profiles does not match matrix profiles".to_string());
     }
 
-    create_matrix_transport_cost(matrix_data)
+    let bucket_sizes = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| {
+            profile.time_bucket.and_then(|size| fleet_profiles.get(&profile.name).map(|p| (*p, size)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    if bucket_sizes.is_empty() {
+        create_matrix_transport_cost(matrix_data)
+    } else {
+        create_matrix_transport_cost_with_time_buckets(matrix_data, bucket_sizes)
+    }
+}
+
+/// Creates a function which returns travel time deviations between two locations using routing
+/// matrices. Returns `None` if no matrix has travel time deviations specified.
+pub(crate) fn create_travel_deviation_func(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+) -> Option<TravelDeviationFunc> {
+    if matrices.iter().all(|matrix| matrix.travel_time_deviations.is_none()) {
+        return None;
+    }
+
+    let fleet_profiles = get_profile_map(api_problem);
+    let deviations = matrices.iter().enumerate().fold(HashMap::new(), |mut acc, (idx, matrix)| {
+        let profile = matrix.profile.as_ref().and_then(|p| fleet_profiles.get(p)).cloned().unwrap_or(idx as i32);
+        // NOTE use the first matrix per profile as deviations are not time dependent
+        if let Some(deviations) = &matrix.travel_time_deviations {
+            acc.entry(profile).or_insert_with(|| {
+                let size = (deviations.len() as f64).sqrt().round() as usize;
+                (size, deviations.iter().map(|d| *d as f64).collect::<Vec<_>>())
+            });
+        }
+        acc
+    });
+
+    Some(Arc::new(move |profile, from, to| {
+        deviations.get(&profile).and_then(|(size, deviations)| deviations.get(from * size + to)).cloned().unwrap_or(0.)
+    }))
+}
+
+/// Creates a function which returns monetary fees for traveling between two locations using routing
+/// matrix tolls and vehicle zone fees. Returns `None` if neither tolls nor zone fees are specified.
+pub(crate) fn create_toll_func(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+    coord_index: &CoordIndex,
+) -> Option<TollFunc> {
+    let has_tolls = matrices.iter().any(|matrix| matrix.tolls.is_some());
+    let has_zone_fees = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.costs.zone_fees.is_some());
+
+    if !has_tolls && !has_zone_fees {
+        return None;
+    }
+
+    let fleet_profiles = get_profile_map(api_problem);
+    let tolls = matrices.iter().enumerate().fold(HashMap::new(), |mut acc, (idx, matrix)| {
+        let profile = matrix.profile.as_ref().and_then(|p| fleet_profiles.get(p)).cloned().unwrap_or(idx as i32);
+        // NOTE use the first matrix per profile as tolls are not time dependent
+        if let Some(tolls) = &matrix.tolls {
+            acc.entry(profile).or_insert_with(|| ((tolls.len() as f64).sqrt().round() as usize, tolls.clone()));
+        }
+        acc
+    });
+
+    let zone_fees = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.costs.zone_fees.as_ref().map(|zone_fees| (vehicle, zone_fees)))
+        .map(|(vehicle, zone_fees)| {
+            let locations = coord_index.unique().iter().map(|location| location.to_lat_lng()).collect::<Vec<_>>();
+            let zones = zone_fees
+                .iter()
+                .map(|zone| {
+                    let outer_shape = zone.outer_shape.iter().map(|location| location.to_lat_lng()).collect::<Vec<_>>();
+                    let is_inside = locations
+                        .iter()
+                        .map(|location| is_location_in_area(location, outer_shape.as_slice()))
+                        .collect::<Vec<_>>();
+
+                    (zone.fee, is_inside)
+                })
+                .collect::<Vec<_>>();
+
+            (vehicle.type_id.clone(), zones)
+        })
+        .collect::<HashMap<_, _>>();
+
+    Some(Arc::new(move |actor, from, to| {
+        let toll = tolls
+            .get(&actor.vehicle.profile)
+            .and_then(|(size, tolls)| tolls.get(from * size + to))
+            .cloned()
+            .unwrap_or(0.);
+
+        let zone_fee = actor
+            .vehicle
+            .dimens
+            .get_value::<String>("type_id")
+            .and_then(|type_id| zone_fees.get(type_id))
+            .map_or(0., |zones| {
+                zones
+                    .iter()
+                    .filter(|(_, is_inside)| {
+                        !is_inside.get(from).cloned().unwrap_or(false) && is_inside.get(to).cloned().unwrap_or(false)
+                    })
+                    .map(|(fee, _)| fee)
+                    .sum()
+            });
+
+        toll + zone_fee
+    }))
 }
 
-pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
+/// Returns a probability of lateness risk objective, if it is specified.
+pub(crate) fn get_lateness_risk_probability(api_problem: &ApiProblem) -> Option<f64> {
+    api_problem
+        .objectives
+        .iter()
+        .flat_map(|objectives| objectives.primary.iter().chain(objectives.secondary.iter().flatten()))
+        .filter_map(|objective| match objective {
+            Objective::MinimizeLatenessRisk { probability } => Some(*probability),
+            _ => None,
+        })
+        .next()
+}
+
+pub(crate) fn read_fleet(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    coord_index: &CoordIndex,
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Fleet {
     let profiles = get_profile_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
@@ -91,12 +236,15 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let profile = *profiles.get(&vehicle.profile).unwrap() as Profile;
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let overload = vehicle.limits.as_ref().and_then(|l| l.overload.as_ref());
+        let shift_time = vehicle.limits.as_ref().and_then(|l| l.shift_time);
+        let overtime = vehicle.limits.as_ref().and_then(|l| l.overtime.as_ref());
+        let max_radius = vehicle.limits.as_ref().and_then(|l| l.max_radius.as_ref());
         let mut areas = vehicle.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map(|areas| {
             areas
                 .iter()
-                .map(|area| Area {
-                    priority: area.priority,
-                    outer_shape: area.outer_shape.iter().map(|l| l.to_lat_lng()).collect::<Vec<_>>(),
+                .map(|area| {
+                    Area::new(area.priority, area.outer_shape.iter().map(|l| l.to_lat_lng()).collect::<Vec<_>>())
                 })
                 .collect::<Vec<_>>()
         });
@@ -115,6 +263,21 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 (location, time)
             });
 
+            // NOTE driver travels to the vehicle using the same routing profile
+            let driver = shift.start.driver.as_ref().map(|driver| {
+                let location = coord_index.get_by_loc(&driver.location).unwrap();
+                let earliest = parse_time(&driver.earliest);
+                let travel = transport.duration(profile, location, start.0, earliest);
+
+                DriverDetail {
+                    start: Some(VehiclePlace {
+                        location,
+                        time: TimeInterval { earliest: Some(earliest), latest: None },
+                    }),
+                    time: TimeInterval { earliest: Some(earliest + travel), latest: None },
+                }
+            });
+
             let details = vec![VehicleDetail {
                 start: Some(VehiclePlace {
                     location: start.0,
@@ -124,6 +287,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     location,
                     time: TimeInterval { earliest: None, latest: Some(time) },
                 }),
+                driver,
             }];
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
@@ -140,12 +304,52 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_value("tour_size", tour_size);
                 }
 
+                if let Some(max_radius) = max_radius {
+                    dimens.set_value("max_radius", max_radius.distance);
+                    dimens.set_value("radius_airline", max_radius.airline.unwrap_or(false));
+                }
+
+                if let Some(crew_size) = vehicle.crew_size {
+                    dimens.set_value("crew_size", crew_size);
+                }
+
+                if let Some(team) = &vehicle.team {
+                    dimens.set_value(
+                        "team",
+                        DrivingTeam { drivers: team.drivers, max_driving_time: team.max_driving_time },
+                    );
+                }
+
+                if let Some(driving_limit) = shift.breaks.as_ref().and_then(|breaks| get_driving_limit(breaks)) {
+                    dimens.set_value("driving_limit", driving_limit);
+                }
+
+                if let Some(overload) = overload {
+                    dimens.set_capacity_overload(CapacityOverload {
+                        max_ratio: overload.max_ratio,
+                        penalty: overload.penalty,
+                    });
+                }
+
+                if let Some((shift_time, overtime)) = shift_time.zip(overtime) {
+                    dimens.set_shift_overtime(ShiftOvertime { shift_time, penalty: overtime.penalty });
+                }
+
                 if props.has_multi_dimen_capacity {
-                    dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
+                    dimens.set_capacity(MultiDimLoad::new(get_vehicle_capacity(vehicle, props.volume_dimension)));
                 } else {
-                    dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
+                    dimens.set_capacity(SingleDimLoad::new(*get_vehicle_capacity(vehicle, None).first().unwrap()));
+                }
+
+                if vehicle.trailer.is_some() {
+                    let truck_capacity = get_truck_capacity(vehicle, props.volume_dimension);
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_value("truck_capacity", MultiDimLoad::new(truck_capacity));
+                    } else {
+                        dimens.set_value("truck_capacity", SingleDimLoad::new(*truck_capacity.first().unwrap()));
+                    }
                 }
-                add_vehicle_skills(&mut dimens, &vehicle.skills);
+                add_vehicle_skills(&mut dimens, &vehicle.skills, &props.skill_index);
 
                 vehicles.push(Arc::new(Vehicle { profile, costs: costs.clone(), dimens, details: details.clone() }));
             });
@@ -172,7 +376,11 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
         HashMap::new(),
         |mut acc, vehicle| {
             let limits = vehicle.limits.as_ref().unwrap().clone();
-            acc.insert(vehicle.type_id.clone(), (limits.max_distance, limits.shift_time));
+            // NOTE overtime extends shift time limit, its penalty is applied using vehicle dimension
+            let shift_time = limits
+                .shift_time
+                .map(|shift_time| shift_time + limits.overtime.as_ref().map_or(0., |overtime| overtime.max_time));
+            acc.insert(vehicle.type_id.clone(), (limits.max_distance, shift_time));
             acc
         },
     );
@@ -190,7 +398,7 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
-fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
+pub(crate) fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name) == None {
             acc.insert(profile.name.clone(), acc.len() as i32);
@@ -199,8 +407,8 @@ fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
     })
 }
 
-fn add_vehicle_skills(dimens: &mut Dimensions, skills: &Option<Vec<String>>) {
+fn add_vehicle_skills(dimens: &mut Dimensions, skills: &Option<Vec<String>>, skill_index: &SkillIndex) {
     if let Some(skills) = skills {
-        dimens.set_value("skills", skills.iter().cloned().collect::<HashSet<_>>());
+        dimens.set_value::<SkillSet>("skills", skill_index.create_set(skills.iter()));
     }
 }
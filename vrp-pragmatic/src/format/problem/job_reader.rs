@@ -1,20 +1,26 @@
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::reader::fleet_reader::get_profile_map;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
 use crate::format::problem::{
-    JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleDispatch, VehicleReload, VehicleType,
+    get_driving_break_limits, get_task_demand, Clustering, JobCompatibility, JobCompatibilityPolicy, JobLoadingPolicy,
+    JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleDispatch, VehicleReload, VehicleTrailer, VehicleType,
+    VehicleUnavailability, VicinityVisitPolicy,
 };
 use crate::format::{JobIndex, Location};
 use crate::utils::VariableJobPermutation;
 use std::sync::Arc;
+use vrp_core::construction::clustering::vicinity::*;
+use vrp_core::construction::constraints::{Compatibility, CompatibilityPolicy, LoadingPolicy};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 use vrp_core::utils::{compare_floats, Random};
 
-use crate::constraints::JobSkills as ConstraintJobSkills;
+use crate::constraints::{JobSkills as ConstraintJobSkills, SkillIndex};
+use crate::format::problem::Job as FormatJob;
 use crate::format::problem::JobSkills as FormatJobSkills;
 use crate::parse_time;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 
 // TODO configure sample size
@@ -29,7 +35,8 @@ pub(crate) fn read_jobs_with_extra_locks(
     job_index: &mut JobIndex,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> (Jobs, Vec<Arc<Lock>>) {
-    let (mut jobs, mut locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
+    let (jobs, mut locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
+    let mut jobs = create_clusters(api_problem, props, transport.as_ref(), jobs);
     let (conditional_jobs, conditional_locks) = read_conditional_jobs(api_problem, coord_index, job_index);
 
     jobs.extend(conditional_jobs);
@@ -72,7 +79,7 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "dispatch" | "reload" => {
+                        "break" | "dispatch" | "reload" | "unavailability" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -113,7 +120,7 @@ fn read_required_jobs(
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
-        let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
+        let capacity = get_task_demand(task, props.volume_dimension).map_or_else(empty, MultiDimLoad::new);
         let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
 
         let demand = match activity_type {
@@ -127,7 +134,11 @@ fn read_required_jobs(
         let places =
             task.places.iter().map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times))).collect();
 
-        get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index);
+        add_preferred_times(&mut single.dimens, task, coord_index);
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -157,16 +168,9 @@ fn read_required_jobs(
         assert!(!singles.is_empty());
 
         let problem_job = if singles.len() > 1 {
-            get_multi_job(
-                &job.id,
-                job.priority,
-                &job.skills,
-                singles,
-                job.pickups.as_ref().map_or(0, |p| p.len()),
-                random,
-            )
+            get_multi_job(job, singles, job.pickups.as_ref().map_or(0, |p| p.len()), random, &props.skill_index)
         } else {
-            get_single_job(&job.id, singles.into_iter().next().unwrap(), job.priority, &job.skills)
+            get_single_job(job, singles.into_iter().next().unwrap(), &props.skill_index)
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -176,6 +180,69 @@ fn read_required_jobs(
     (jobs, vec![])
 }
 
+/// Replaces jobs located in the vicinity of each other with clusters if clustering is specified.
+/// Only jobs with a single task and without extra requirements are clustered.
+fn create_clusters(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    transport: &(dyn TransportCost + Send + Sync),
+    jobs: Vec<Job>,
+) -> Vec<Job> {
+    let (profile, threshold, visiting) = match api_problem.plan.clustering.as_ref() {
+        Some(Clustering::Vicinity { profile, threshold, visiting }) => (profile, threshold, visiting),
+        None => return jobs,
+    };
+
+    let related_ids = api_problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .collect::<HashSet<_>>();
+
+    let candidate_ids = api_problem
+        .plan
+        .jobs
+        .iter()
+        .filter(|job| {
+            let tasks = job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).flatten().count()
+                + job.replacements.as_ref().map_or(0, |tasks| tasks.len());
+
+            tasks == 1
+                && !related_ids.contains(&job.id)
+                && job.skills.is_none()
+                && job.priority.is_none()
+                && job.value.is_none()
+                && job.must_serve.is_none()
+                && job.truck_only.is_none()
+                && job.compatibility.is_none()
+        })
+        .map(|job| job.id.clone())
+        .collect::<HashSet<_>>();
+
+    let config = ClusterConfig {
+        profile: get_profile_map(api_problem).get(profile).cloned().expect("unknown clustering profile"),
+        threshold: ThresholdPolicy {
+            moving_duration: threshold.duration,
+            moving_distance: threshold.distance,
+            max_jobs_per_cluster: threshold.max_jobs_per_cluster,
+        },
+        visiting: match visiting {
+            VicinityVisitPolicy::ServeInPlace => VisitPolicy::ServeInPlace,
+            VicinityVisitPolicy::WalkFromParking => VisitPolicy::WalkFromParking,
+        },
+        filtering: FilterPolicy {
+            job_filter: Arc::new(move |job| matches!(job.dimens().get_id(), Some(id) if candidate_ids.contains(id))),
+        },
+    };
+
+    if props.has_multi_dimen_capacity {
+        create_job_clusters::<MultiDimLoad>(jobs, transport, &config)
+    } else {
+        create_job_clusters::<SingleDimLoad>(jobs, transport, &config)
+    }
+}
+
 fn read_conditional_jobs(
     api_problem: &ApiProblem,
     coord_index: &CoordIndex,
@@ -196,6 +263,14 @@ fn read_conditional_jobs(
             if let Some(reloads) = &shift.reloads {
                 read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
+
+            if let Some(unavailability) = &shift.unavailability {
+                read_unavailability(coord_index, job_index, &mut jobs, vehicle, shift_index, unavailability);
+            }
+
+            if let Some(trailer) = &vehicle.trailer {
+                read_trailer_parkings(coord_index, job_index, &mut jobs, vehicle, shift_index, trailer);
+            }
         }
     });
 
@@ -210,14 +285,20 @@ fn read_breaks(
     shift_index: usize,
     breaks: &[VehicleBreak],
 ) {
+    let driving_limits = get_driving_break_limits(breaks);
+
     (1..)
-        .zip(breaks.iter())
-        .flat_map(|(break_idx, place)| {
+        .zip(breaks.iter().zip(driving_limits))
+        .flat_map(|(break_idx, (place, driving_limit))| {
             vehicle
                 .vehicle_ids
                 .iter()
-                .map(|vehicle_id| {
+                .map(move |vehicle_id| {
                     let times = match &place.time {
+                        // NOTE driving time break can be taken at any time, its position is controlled by driving time
+                        _ if driving_limit.is_some() => {
+                            vec![TimeSpan::Offset(TimeOffset::new(0., f64::MAX))]
+                        }
                         VehicleBreakTime::TimeWindow(time) if time.len() != 2 => {
                             panic!("Break with invalid time window specified: must have start and end!")
                         }
@@ -241,7 +322,7 @@ fn read_breaks(
                         vec![(None, place.duration, times)]
                     };
 
-                    let job = get_conditional_job(
+                    let mut job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
@@ -251,6 +332,10 @@ fn read_breaks(
                         &None,
                     );
 
+                    if let Some((threshold, _)) = driving_limit {
+                        job.dimens.set_value("driving_threshold", threshold);
+                    }
+
                     (job_id, job)
                 })
                 .collect::<Vec<_>>()
@@ -338,6 +423,83 @@ fn read_reloads(
         .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
 }
 
+fn read_unavailability(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    unavailability: &[VehicleUnavailability],
+) {
+    (1..)
+        .zip(unavailability.iter())
+        .flat_map(|(place_idx, place)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{}_unavailability_{}_{}", vehicle_id, shift_index, place_idx);
+                    // NOTE vehicle has to start waiting exactly at period start and stay till its end
+                    let period = parse_time_window(&place.time);
+                    let times = vec![TimeSpan::Window(TimeWindow::new(period.start, period.start))];
+
+                    let job = get_conditional_job(
+                        coord_index,
+                        vehicle_id.clone(),
+                        &job_id,
+                        "unavailability",
+                        shift_index,
+                        vec![(Some(place.location.clone()), period.end - period.start, times)],
+                        &place.tag,
+                    );
+
+                    (job_id, job)
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
+fn read_trailer_parkings(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    trailer: &VehicleTrailer,
+) {
+    (1..).zip(trailer.parkings.iter()).for_each(|(place_idx, parking)| {
+        vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+            let job_id = format!("{}_trailer_{}_{}", vehicle_id, shift_index, place_idx);
+
+            let singles = vec!["detach", "attach"]
+                .into_iter()
+                .map(|activity_type| {
+                    Arc::new(get_conditional_job(
+                        coord_index,
+                        vehicle_id.clone(),
+                        &job_id,
+                        activity_type,
+                        shift_index,
+                        vec![(Some(parking.location.clone()), parking.duration, parse_times(&None))],
+                        &parking.tag,
+                    ))
+                })
+                .collect();
+
+            let mut dimens: Dimensions = Default::default();
+            dimens.set_id(job_id.as_str());
+            dimens.set_value("type", "trailer".to_string());
+            dimens.set_value("shift_index", shift_index);
+            dimens.set_value("vehicle_id", vehicle_id.clone());
+
+            let job = Job::Multi(Multi::bind(Multi::new(singles, dimens)));
+            job_index.insert(job_id, job.clone());
+            jobs.push(job);
+        })
+    });
+}
+
 fn get_conditional_job(
     coord_index: &CoordIndex,
     vehicle_id: String,
@@ -402,28 +564,36 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(id: &str, single: Single, priority: Option<i32>, skills: &Option<FormatJobSkills>) -> Job {
+fn get_single_job(job: &FormatJob, single: Single, skill_index: &SkillIndex) -> Job {
     let mut single = single;
-    single.dimens.set_id(id);
+    single.dimens.set_id(job.id.as_str());
 
-    add_priority(&mut single.dimens, priority);
-    add_job_skills(&mut single.dimens, skills);
+    add_priority(&mut single.dimens, job.priority);
+    add_value(&mut single.dimens, job.value);
+    add_job_skills(&mut single.dimens, &job.skills, skill_index);
+    add_must_serve(&mut single.dimens, job.must_serve);
+    add_truck_only(&mut single.dimens, job.truck_only);
+    add_compatibility(&mut single.dimens, &job.compatibility);
 
     Job::Single(Arc::new(single))
 }
 
 fn get_multi_job(
-    id: &str,
-    priority: Option<i32>,
-    skills: &Option<FormatJobSkills>,
+    job: &FormatJob,
     singles: Vec<Single>,
     deliveries_start_index: usize,
     random: &Arc<dyn Random + Send + Sync>,
+    skill_index: &SkillIndex,
 ) -> Job {
     let mut dimens: Dimensions = Default::default();
-    dimens.set_id(id);
-    add_priority(&mut dimens, priority);
-    add_job_skills(&mut dimens, skills);
+    dimens.set_id(job.id.as_str());
+    add_priority(&mut dimens, job.priority);
+    add_value(&mut dimens, job.value);
+    add_job_skills(&mut dimens, &job.skills, skill_index);
+    add_must_serve(&mut dimens, job.must_serve);
+    add_truck_only(&mut dimens, job.truck_only);
+    add_loading_policy(&mut dimens, &job.loading_policy);
+    add_compatibility(&mut dimens, &job.compatibility);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -459,20 +629,89 @@ fn add_tag(dimens: &mut Dimensions, tag: &Option<String>) {
     }
 }
 
+fn add_preferred_times(dimens: &mut Dimensions, task: &JobTask, coord_index: &CoordIndex) {
+    let preferred_times = task
+        .places
+        .iter()
+        .filter_map(|place| {
+            let times = place.preferred_times.as_ref()?.iter().map(|tw| parse_time_window(tw)).collect::<Vec<_>>();
+            coord_index.get_by_loc(&place.location).map(|location| (location, times))
+        })
+        .collect::<Vec<_>>();
+
+    if !preferred_times.is_empty() {
+        dimens.set_value("preferred_times", preferred_times);
+    }
+}
+
 fn add_priority(dimens: &mut Dimensions, priority: Option<i32>) {
     if let Some(priority) = priority {
-        dimens.set_value("priority", priority);
+        dimens.set_priority(priority);
+    }
+}
+
+fn add_value(dimens: &mut Dimensions, value: Option<f64>) {
+    if let Some(value) = value {
+        dimens.set_value("value", value);
+    }
+}
+
+fn add_must_serve(dimens: &mut Dimensions, must_serve: Option<bool>) {
+    if must_serve.unwrap_or(false) {
+        dimens.set_value("must_serve", true);
+    }
+}
+
+fn add_truck_only(dimens: &mut Dimensions, truck_only: Option<bool>) {
+    if truck_only.unwrap_or(false) {
+        dimens.set_value("truck_only", true);
     }
 }
 
-fn add_job_skills(dimens: &mut Dimensions, skills: &Option<FormatJobSkills>) {
+fn add_loading_policy(dimens: &mut Dimensions, loading_policy: &Option<JobLoadingPolicy>) {
+    if let Some(loading_policy) = loading_policy {
+        dimens.set_value(
+            "loading_policy",
+            match loading_policy {
+                JobLoadingPolicy::Lifo => LoadingPolicy::Lifo,
+                JobLoadingPolicy::Fifo => LoadingPolicy::Fifo,
+            },
+        );
+    }
+}
+
+fn add_compatibility(dimens: &mut Dimensions, compatibility: &Option<JobCompatibility>) {
+    if let Some(compatibility) = compatibility {
+        dimens.set_value(
+            "compatibility",
+            Compatibility {
+                group: compatibility.group.clone(),
+                incompatible: compatibility.incompatible.clone(),
+                policy: match compatibility.policy {
+                    Some(JobCompatibilityPolicy::NeverSimultaneouslyLoaded) => {
+                        CompatibilityPolicy::NeverSimultaneouslyLoaded
+                    }
+                    _ => CompatibilityPolicy::NeverSameTour,
+                },
+            },
+        );
+    }
+}
+
+fn add_job_skills(dimens: &mut Dimensions, skills: &Option<FormatJobSkills>, skill_index: &SkillIndex) {
     if let Some(skills) = skills {
         dimens.set_value(
             "skills",
             ConstraintJobSkills {
-                all_of: skills.all_of.as_ref().map(|all_of| all_of.iter().cloned().collect()),
-                one_of: skills.one_of.as_ref().map(|any_of| any_of.iter().cloned().collect()),
-                none_of: skills.none_of.as_ref().map(|none_of| none_of.iter().cloned().collect()),
+                all_of: skills.all_of.as_ref().map(|all_of| skill_index.create_set(all_of.iter())),
+                one_of: skills.one_of.as_ref().map(|one_of| skill_index.create_set(one_of.iter())),
+                none_of: skills.none_of.as_ref().map(|none_of| skill_index.create_set(none_of.iter())),
+                preferred: skills.preferred.as_ref().map(|preferred| {
+                    preferred
+                        .iter()
+                        .filter_map(|skill| skill_index.get_index(&skill.name).map(|index| (index, skill.penalty)))
+                        .collect()
+                }),
             },
         );
     }
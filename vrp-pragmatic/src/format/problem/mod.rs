@@ -4,5 +4,14 @@
 mod model;
 pub use self::model::*;
 
+mod plugins;
+pub use self::plugins::{ConstraintPlugins, JobFilterFunc};
+
 mod reader;
 pub use self::reader::PragmaticProblem;
+
+mod volume;
+pub(crate) use self::volume::{get_task_demand, get_truck_capacity, get_vehicle_capacity, get_volume_dimension};
+
+mod driving;
+pub(crate) use self::driving::{get_driving_break_limits, get_driving_limit};
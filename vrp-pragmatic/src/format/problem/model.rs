@@ -51,6 +51,18 @@ pub struct JobSkills {
     /// Vehicle should have none of these skills defined.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub none_of: Option<Vec<String>>,
+    /// Vehicle is preferred to have these skills defined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred: Option<Vec<JobPreferredSkill>>,
+}
+
+/// A job skill which is preferred, but not required.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPreferredSkill {
+    /// A skill name.
+    pub name: String,
+    /// A cost penalty applied when vehicle has no such skill.
+    pub penalty: f64,
 }
 
 /// Specifies a place for sub job.
@@ -63,6 +75,10 @@ pub struct JobPlace {
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
+    /// A list of time windows preferred by customer, time is specified in RFC3339 format. Unlike
+    /// `times`, they are not enforced: arrival outside of them is penalized.
+    #[serde(rename = "preferredTimes", skip_serializing_if = "Option::is_none")]
+    pub preferred_times: Option<Vec<Vec<String>>>,
 }
 
 /// Specifies a job task.
@@ -73,11 +89,53 @@ pub struct JobTask {
     /// Job place demand.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub demand: Option<Vec<i32>>,
+    /// Job place volume. When specified, demand is considered as weight and vehicle volume
+    /// capacity is checked too.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<i32>,
+    /// Job place density: weight per volume unit. Used to calculate volume from the first
+    /// dimension of demand when volume is omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub density: Option<f64>,
     /// A tag which will be propagated back within corresponding activity in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
 }
 
+/// Specifies loading policy of the job with pickups and deliveries.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
+pub enum JobLoadingPolicy {
+    /// Last in, first out: jobs loaded while the job is on board have to be unloaded before it.
+    #[serde(rename(deserialize = "lifo", serialize = "lifo"))]
+    Lifo,
+    /// First in, first out: jobs loaded while the job is on board have to be unloaded after it.
+    #[serde(rename(deserialize = "fifo", serialize = "fifo"))]
+    Fifo,
+}
+
+/// Specifies how the job is kept apart from jobs of incompatible groups.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
+pub enum JobCompatibilityPolicy {
+    /// Jobs cannot be served in the same tour.
+    #[serde(rename(deserialize = "never-same-tour", serialize = "never-same-tour"))]
+    NeverSameTour,
+    /// Jobs can be served in the same tour, but cannot be on board of the vehicle at the same time.
+    #[serde(rename(deserialize = "never-simultaneously-loaded", serialize = "never-simultaneously-loaded"))]
+    NeverSimultaneouslyLoaded,
+}
+
+/// Specifies compatibility group of the job and groups it cannot be mixed with.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobCompatibility {
+    /// A group of the job, e.g. food.
+    pub group: String,
+    /// Groups which are incompatible with the job's group, e.g. chemicals.
+    pub incompatible: Vec<String>,
+    /// A policy applied to the job and jobs from incompatible groups. Default is never same tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<JobCompatibilityPolicy>,
+}
+
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
 /// which follows these rules:
 /// * all of them should be completed or none of them.
@@ -107,6 +165,34 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
 
+    /// Job value collected when the job is served. Used by maximize value objective: such job is
+    /// optional and can be left unassigned when serving it costs more than its value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+
+    /// Specifies whether job must be served: if it cannot be assigned, an infeasibility error is
+    /// returned instead of a solution without it.
+    #[serde(rename = "mustServe")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub must_serve: Option<bool>,
+
+    /// Specifies whether job can be served only by truck without trailer: when vehicle has a trailer,
+    /// it has to be parked before and picked up after serving such job.
+    #[serde(rename = "truckOnly")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truck_only: Option<bool>,
+
+    /// Specifies loading policy of the job with pickups and deliveries: it controls the order in
+    /// which jobs loaded while this job is on board are unloaded.
+    #[serde(rename = "loadingPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading_policy: Option<JobLoadingPolicy>,
+
+    /// Specifies compatibility of the job: jobs from incompatible groups are not mixed in the same
+    /// tour or on the vehicle at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility: Option<JobCompatibility>,
+
     /// A job skills limitations for serving a job.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<JobSkills>,
@@ -120,6 +206,50 @@ pub struct Plan {
     /// List of relations between jobs and vehicles.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<Vec<Relation>>,
+
+    /// Specifies clustering of jobs which are served together to reduce problem size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clustering: Option<Clustering>,
+}
+
+/// Specifies clustering algorithm.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum Clustering {
+    /// Jobs located in the vicinity of each other are served within a single stop.
+    #[serde(rename(deserialize = "vicinity", serialize = "vicinity"))]
+    Vicinity {
+        /// A routing profile used to calculate commute between jobs.
+        profile: String,
+        /// Specifies thresholds for jobs to be clustered.
+        threshold: VicinityThresholdPolicy,
+        /// Specifies how jobs in the cluster are visited.
+        visiting: VicinityVisitPolicy,
+    },
+}
+
+/// Specifies max commute between the cluster's parking and its jobs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VicinityThresholdPolicy {
+    /// Max commute duration in each direction.
+    pub duration: f64,
+    /// Max commute distance in each direction.
+    pub distance: f64,
+    /// Max amount of jobs in one cluster.
+    #[serde(rename = "maxJobsPerCluster")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jobs_per_cluster: Option<usize>,
+}
+
+/// Specifies how jobs in the cluster are visited.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
+pub enum VicinityVisitPolicy {
+    /// All jobs are served in place where vehicle is parked: commute is ignored.
+    #[serde(rename(deserialize = "serve-in-place", serialize = "serve-in-place"))]
+    ServeInPlace,
+    /// Driver walks from the parking to each job and back.
+    #[serde(rename(deserialize = "walk-from-parking", serialize = "walk-from-parking"))]
+    WalkFromParking,
 }
 
 // endregion
@@ -138,6 +268,21 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Fees paid each time vehicle enters specific zone, e.g. congestion charges.
+    #[serde(rename = "zoneFees")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone_fees: Option<Vec<ZoneFee>>,
+}
+
+/// Specifies a fee paid for entering a zone.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneFee {
+    /// A fee paid when vehicle travels from location outside of the zone to location inside it.
+    pub fee: f64,
+    /// A zone outer shape.
+    pub outer_shape: Vec<Location>,
 }
 
 /// Specifies vehicle shift start.
@@ -154,6 +299,21 @@ pub struct ShiftStart {
 
     /// Shift start location.
     pub location: Location,
+
+    /// Specifies a place where driver starts before taking the vehicle at shift start location.
+    /// Vehicle cannot depart before driver reaches it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<DriverStart>,
+}
+
+/// Specifies a place where driver starts.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverStart {
+    /// Earliest time when driver is available at the location in RFC3339 format.
+    pub earliest: String,
+
+    /// Driver start location.
+    pub location: Location,
 }
 
 /// Specifies vehicle shift end.
@@ -191,6 +351,11 @@ pub struct VehicleShift {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub breaks: Option<Vec<VehicleBreak>>,
 
+    /// Vehicle unavailability periods, e.g. scheduled maintenance, when vehicle has to stay idle
+    /// at specific location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailability: Option<Vec<VehicleUnavailability>>,
+
     /// Vehicle reloads which allows vehicle to visit place where goods can be loaded or
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -240,6 +405,44 @@ pub struct VehicleReload {
     pub tag: Option<String>,
 }
 
+/// Specifies a period when vehicle is not available for serving jobs and has to stay at given location.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleUnavailability {
+    /// A place location.
+    pub location: Location,
+
+    /// A time window with time specified in RFC3339 format.
+    pub time: Vec<String>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// Specifies a detachable vehicle trailer.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleTrailer {
+    /// Trailer capacity which extends vehicle capacity while trailer is attached.
+    pub capacity: Vec<i32>,
+
+    /// A list of places where trailer can be parked.
+    pub parkings: Vec<TrailerParking>,
+}
+
+/// Specifies a place where vehicle can park its trailer and pick it up later.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TrailerParking {
+    /// A place location.
+    pub location: Location,
+
+    /// A duration needed to detach or attach trailer (service time).
+    pub duration: f64,
+
+    /// A tag which will be propagated back within corresponding activities in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -263,6 +466,53 @@ pub struct VehicleLimits {
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_areas: Option<Vec<AreaLimit>>,
+
+    /// Specifies max distance from vehicle start location to any job served in the tour.
+    /// No radius restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_radius: Option<VehicleRadius>,
+
+    /// Specifies how much vehicle capacity can be exceeded.
+    /// No overload is allowed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overload: Option<VehicleOverload>,
+
+    /// Specifies how much shift time can be exceeded.
+    /// No overtime is allowed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<VehicleOvertime>,
+}
+
+/// Specifies max radius of vehicle operations.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleRadius {
+    /// Max distance from vehicle start location.
+    pub distance: f64,
+    /// Specifies whether straight line distance is used instead of routed one.
+    /// Requires geo coordinates. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub airline: Option<bool>,
+}
+
+/// Specifies vehicle capacity overload.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOverload {
+    /// Max overload as a ratio of vehicle capacity, e.g. 0.1 means 10%.
+    pub max_ratio: f64,
+    /// A penalty cost per each overloaded unit.
+    pub penalty: f64,
+}
+
+/// Specifies vehicle shift time overtime.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOvertime {
+    /// Max time which can be worked above shift time.
+    pub max_time: f64,
+    /// A penalty cost per each time unit of overtime.
+    pub penalty: f64,
 }
 
 /// Specifies area limit.
@@ -298,6 +548,20 @@ pub struct VehicleBreak {
     /// Break locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locations: Option<Vec<Location>>,
+
+    /// Break policy. When omitted, break time is interpreted as a time window or shift time offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<VehicleBreakPolicy>,
+}
+
+/// Vehicle break policy.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
+pub enum VehicleBreakPolicy {
+    /// Break time offset is measured in continuous driving time instead of shift time: the break
+    /// is required once driving time of the tour reaches offset start, and continuous driving
+    /// time between such breaks cannot exceed offset end.
+    #[serde(rename(deserialize = "driving-time", serialize = "driving-time"))]
+    DrivingTime,
 }
 
 /// Specifies a vehicle type.
@@ -322,6 +586,25 @@ pub struct VehicleType {
     /// Vehicle capacity.
     pub capacity: Vec<i32>,
 
+    /// Vehicle volume capacity. When specified, capacity is considered as weight and both
+    /// weight and volume are checked. No volume restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<i32>,
+
+    /// Vehicle trailer. While trailer is detached, vehicle load is limited by its own capacity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailer: Option<VehicleTrailer>,
+
+    /// Vehicle team. When specified, drivers take turns driving the vehicle and driving time
+    /// is limited per driver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<VehicleTeam>,
+
+    /// Vehicle crew size. When bigger than one, jobs at the same stop are served in parallel
+    /// by the crew. By default, service durations of such jobs are summed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew_size: Option<usize>,
+
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
@@ -331,6 +614,18 @@ pub struct VehicleType {
     pub limits: Option<VehicleLimits>,
 }
 
+/// Specifies a team of drivers which take turns driving the vehicle: while one driver drives,
+/// another one rests.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTeam {
+    /// Amount of drivers in the team.
+    pub drivers: usize,
+
+    /// Max driving time of each driver per shift.
+    pub max_driving_time: f64,
+}
+
 /// Specifies routing profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Profile {
@@ -342,9 +637,21 @@ pub struct Profile {
     pub profile_type: String,
 
     /// Approximation speed (meters per second). Used only when routing matrix is not specified.
-    /// Default value is 10.
+    /// Default value depends on profile type: 4.2 for bike, 1.4 for foot and 10 for others.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Approximation distance multiplier which accounts for road network detours. Used only when
+    /// routing matrix is not specified. Default value is 1.
+    #[serde(rename(deserialize = "distanceMultiplier", serialize = "distanceMultiplier"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_multiplier: Option<f64>,
+
+    /// Time bucket size (in seconds) used to precompute time dependent routing matrices of the profile.
+    /// When specified, step values of bucket start are used instead of interpolation between matrices.
+    #[serde(rename(deserialize = "timeBucket", serialize = "timeBucket"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_bucket: Option<f64>,
 }
 
 /// Specifies fleet.
@@ -370,6 +677,21 @@ pub struct Objectives {
     /// by the secondary objective if it improves the primary one.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub secondary: Option<Vec<Objective>>,
+    /// Weights of objective functions. When specified for a list of objectives, its objectives are
+    /// compared by weighted sum of their values instead of dominance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weights: Option<ObjectiveWeights>,
+}
+
+/// Specifies weights of objective functions per list of objectives.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ObjectiveWeights {
+    /// Weights of primary objective functions in the same order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<Vec<f64>>,
+    /// Weights of secondary objective functions in the same order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<Vec<f64>>,
 }
 
 /// Specifies objective function types.
@@ -388,6 +710,10 @@ pub enum Objective {
     #[serde(rename(deserialize = "maximize-tours", serialize = "maximize-tours"))]
     MaximizeTours,
 
+    /// An objective to maximize total value of served jobs.
+    #[serde(rename(deserialize = "maximize-value", serialize = "maximize-value"))]
+    MaximizeValue,
+
     /// An objective to minimize amount of unassigned jobs.
     #[serde(rename(deserialize = "minimize-unassigned", serialize = "minimize-unassigned"))]
     MinimizeUnassignedJobs {
@@ -431,6 +757,108 @@ pub enum Objective {
         #[serde(skip_serializing_if = "Option::is_none")]
         options: Option<BalanceOptions>,
     },
+
+    /// An objective to minimize risk to be late when travel times are uncertain.
+    #[serde(rename(deserialize = "minimize-lateness-risk", serialize = "minimize-lateness-risk"))]
+    MinimizeLatenessRisk {
+        /// A desired probability to arrive in time: travel times are planned using this percentile
+        /// of their distribution defined by travel time deviations.
+        probability: f64,
+    },
+
+    /// An objective to minimize amount of jobs assigned to a different vehicle than in the previous solution.
+    #[serde(rename(deserialize = "minimize-driver-changes", serialize = "minimize-driver-changes"))]
+    MinimizeDriverChanges {
+        /// Job assignments of the previous solution.
+        previous: Vec<PreviousAssignment>,
+        /// An insertion penalty for each job assigned to a different vehicle. Default is 100.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weight: Option<f64>,
+    },
+
+    /// An objective to make tours geographically compact by minimizing total area of their convex hulls.
+    #[serde(rename(deserialize = "compact-tours", serialize = "compact-tours"))]
+    CompactTours {
+        /// An insertion penalty per square kilometer of tour area increase. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weight: Option<f64>,
+    },
+
+    /// An objective to maximize amount of activities started within time windows preferred by customers.
+    #[serde(rename(deserialize = "maximize-preferred-times", serialize = "maximize-preferred-times"))]
+    MaximizePreferredTimes {
+        /// An insertion penalty for each missed preferred time window. Default is 10.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weight: Option<f64>,
+    },
+
+    /// An objective to keep some vehicle capacity and shift time unused in each tour, so late
+    /// orders can be inserted without rebuilding the plan.
+    #[serde(rename(deserialize = "reserve-slack", serialize = "reserve-slack"))]
+    ReserveSlack {
+        /// A ratio of vehicle capacity to keep unused, e.g. 0.1. Default is 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        capacity: Option<f64>,
+        /// A ratio of vehicle shift time to keep unused, e.g. 0.1. Default is 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        time: Option<f64>,
+        /// An insertion penalty per unit of slack deficit. Default is 1000.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weight: Option<f64>,
+    },
+
+    /// An objective to minimize total weighted violation of relaxable hard constraints. Listed
+    /// constraints are allowed to be violated, so the least violating solution is returned when
+    /// no feasible one exists.
+    #[serde(rename(deserialize = "minimize-violations", serialize = "minimize-violations"))]
+    MinimizeViolations {
+        /// Hard constraints which can be violated.
+        constraints: Vec<ConstraintRelaxation>,
+    },
+}
+
+/// Specifies jobs served by the vehicle in the previous solution.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviousAssignment {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// List of job ids.
+    pub jobs: Vec<String>,
+}
+
+/// Specifies a hard constraint which can be violated at a penalty.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ConstraintRelaxation {
+    /// A type of the constraint.
+    #[serde(rename = "type")]
+    pub constraint_type: RelaxableConstraintType,
+    /// An insertion penalty for each violation. Default is 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+}
+
+/// Specifies hard constraint types which can be relaxed.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq, Eq, Hash)]
+pub enum RelaxableConstraintType {
+    /// Vehicle capacity.
+    #[serde(rename(deserialize = "capacity", serialize = "capacity"))]
+    Capacity,
+    /// Job time windows.
+    #[serde(rename(deserialize = "time-window", serialize = "time-window"))]
+    TimeWindow,
+    /// Job skills.
+    #[serde(rename(deserialize = "skills", serialize = "skills"))]
+    Skills,
+    /// Vehicle max distance limit.
+    #[serde(rename(deserialize = "max-distance", serialize = "max-distance"))]
+    MaxDistance,
+    /// Vehicle shift time limit.
+    #[serde(rename(deserialize = "shift-time", serialize = "shift-time"))]
+    ShiftTime,
+    /// Vehicle tour size limit.
+    #[serde(rename(deserialize = "tour-size", serialize = "tour-size"))]
+    TourSize,
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -486,6 +914,14 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Standard deviations of travel times (in seconds) to model travel time uncertainty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_time_deviations: Option<Vec<i64>>,
+
+    /// Monetary fees (e.g. tolls) paid for traveling between locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolls: Option<Vec<f64>>,
 }
 
 // endregion
@@ -1,17 +1,50 @@
-use crate::core::models::common::ValueDimension;
+use crate::constraints::has_skills;
+use crate::core::models::common::{IdDimension, ValueDimension};
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::BalanceOptions;
 use crate::format::problem::Objective::*;
+use crate::format::problem::{BalanceOptions, ConstraintRelaxation, PreviousAssignment, RelaxableConstraintType};
+use crate::format::*;
+use hashbrown::HashMap;
+use std::ops::Deref;
 use std::sync::Arc;
-use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
+use vrp_core::algorithms::geometry::Point;
+use vrp_core::construction::constraints::*;
+use vrp_core::models::common::TimeWindow;
 use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::{ObjectiveCost, TargetConstraint, TargetObjective};
+use vrp_core::models::problem::{
+    ActivityCost, ObjectiveBuilder, ObjectiveCost, TargetConstraint, TargetObjective, TransportCost,
+};
+use vrp_core::models::solution::Activity;
 use vrp_core::solver::objectives::*;
 
+/// A default insertion penalty for each job assigned to a different vehicle.
+const DEFAULT_DRIVER_CHANGE_WEIGHT: f64 = 100.;
+
+/// A default insertion penalty per square kilometer of tour area increase.
+const DEFAULT_COMPACTNESS_WEIGHT: f64 = 1.;
+
+/// An approximate length of one degree of latitude in kilometers.
+const KM_PER_DEGREE: f64 = 111.32;
+
+/// A default insertion penalty for each missed preferred time window.
+const DEFAULT_PREFERRED_TIMES_WEIGHT: f64 = 10.;
+
+/// A default insertion penalty per unit of slack deficit.
+const DEFAULT_SLACK_WEIGHT: f64 = 1000.;
+
+/// A default insertion penalty for each violation of relaxable constraint.
+const DEFAULT_VIOLATION_WEIGHT: f64 = 1000.;
+
+#[allow(clippy::too_many_arguments)]
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
     props: &ProblemProperties,
+    deviation_func: Option<TravelDeviationFunc>,
+    total_violations: Option<TotalViolations>,
+    coord_index: Arc<CoordIndex>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
 ) -> Arc<ObjectiveCost> {
     Arc::new(if let Some(objectives) = &api_problem.objectives {
         let mut map_objectives = |objectives: &Vec<_>| {
@@ -26,16 +59,24 @@ pub fn create_objective(
                     constraint.add_module(Box::new(FleetUsageConstraintModule::new_maximized()));
                     core_objectives.push(Box::new(TotalRoutes::new_maximized()))
                 }
+                MaximizeValue => {
+                    let (module, objective) = TotalValue::maximize(
+                        Arc::new(|job| job.dimens().get_value::<f64>("value").cloned().unwrap_or(0.)),
+                        transport.clone(),
+                        activity.clone(),
+                        VALUE_CONSTRAINT_CODE,
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
                 MinimizeUnassignedJobs { breaks } => {
                     if let Some(breaks) = *breaks {
-                        core_objectives.push(Box::new(TotalUnassignedJobs::new(Arc::new(move |_, job, _| {
-                            job.dimens().get_value::<String>("type").map_or(1., |job_type| {
-                                if job_type == "break" {
-                                    breaks
-                                } else {
-                                    1.
-                                }
-                            })
+                        core_objectives.push(Box::new(TotalUnassignedJobs::new(Arc::new(move |ctx, job, _| match job
+                            .dimens()
+                            .get_value::<String>("type")
+                        {
+                            Some(job_type) if job_type == "break" => breaks,
+                            _ => get_priority_weight(ctx, job),
                         }))))
                     } else {
                         core_objectives.push(Box::new(TotalUnassignedJobs::default()))
@@ -64,6 +105,50 @@ pub fn create_objective(
                     constraint.add_module(module);
                     core_objectives.push(objective);
                 }
+                MinimizeDriverChanges { previous, weight } => {
+                    let (module, objective) = DriverConsistency::new_with_constraint(
+                        get_previous_assignment(previous),
+                        weight.unwrap_or(DEFAULT_DRIVER_CHANGE_WEIGHT),
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
+                MinimizeLatenessRisk { probability } => {
+                    let deviation_func = deviation_func.clone().unwrap_or_else(|| Arc::new(|_, _, _| 0.));
+                    let (module, objective) = LatenessRisk::new_with_constraint(deviation_func, *probability);
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
+                CompactTours { weight } => {
+                    let (module, objective) = RouteCompactness::new_with_constraint(
+                        get_location_point_func(coord_index.clone()),
+                        weight.unwrap_or(DEFAULT_COMPACTNESS_WEIGHT),
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
+                MaximizePreferredTimes { weight } => {
+                    let (module, objective) = PreferredTimes::new_with_constraint(
+                        transport.clone(),
+                        get_preferred_times_func(),
+                        weight.unwrap_or(DEFAULT_PREFERRED_TIMES_WEIGHT),
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
+                ReserveSlack { capacity, time, weight } => {
+                    let (module, objective) = get_slack_reservation(
+                        props,
+                        capacity.unwrap_or(0.),
+                        time.unwrap_or(0.),
+                        weight.unwrap_or(DEFAULT_SLACK_WEIGHT),
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
+                MinimizeViolations { .. } => {
+                    core_objectives.push(Box::new(total_violations.clone().expect("total violations are expected")))
+                }
             });
             core_objectives
         };
@@ -71,13 +156,167 @@ pub fn create_objective(
         let primary_objectives = map_objectives(&objectives.primary);
         let secondary_objectives = map_objectives(&objectives.secondary.clone().unwrap_or_else(Vec::new));
 
-        ObjectiveCost::new(primary_objectives, secondary_objectives)
+        let weights = objectives.weights.as_ref();
+        let builder = add_objective_layer(
+            ObjectiveBuilder::default(),
+            primary_objectives,
+            weights.and_then(|weights| weights.primary.as_ref()),
+        );
+        let builder =
+            add_objective_layer(builder, secondary_objectives, weights.and_then(|weights| weights.secondary.as_ref()));
+
+        builder.build().expect("objectives are expected to be validated")
     } else {
         constraint.add_module(Box::new(FleetUsageConstraintModule::new_minimized()));
         ObjectiveCost::default()
     })
 }
 
+fn add_objective_layer(
+    builder: ObjectiveBuilder,
+    objectives: Vec<TargetObjective>,
+    weights: Option<&Vec<f64>>,
+) -> ObjectiveBuilder {
+    match (objectives.is_empty(), weights) {
+        (true, _) => builder,
+        (false, Some(weights)) => {
+            builder.add_weighted_layer(objectives.into_iter().zip(weights.iter().cloned()).collect())
+        }
+        (false, None) => builder.add_layer(objectives),
+    }
+}
+
+/// Creates total violations objective with relaxable constraints if it is specified.
+pub fn create_total_violations(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    limits: TravelLimitFunc,
+) -> Option<TotalViolations> {
+    let relaxations = api_problem.objectives.as_ref().and_then(|objectives| {
+        objectives.primary.iter().chain(objectives.secondary.iter().flatten()).find_map(|objective| match objective {
+            MinimizeViolations { constraints } => Some(constraints),
+            _ => None,
+        })
+    })?;
+
+    Some(TotalViolations::new(
+        relaxations.iter().map(|relaxation| create_relaxable_constraint(relaxation, props, limits.clone())).collect(),
+    ))
+}
+
+fn create_relaxable_constraint(
+    relaxation: &ConstraintRelaxation,
+    props: &ProblemProperties,
+    limits: TravelLimitFunc,
+) -> RelaxableConstraint {
+    let (code, violation_func): (i32, ViolationCountFunc) = match relaxation.constraint_type {
+        RelaxableConstraintType::Capacity => (
+            CAPACITY_CONSTRAINT_CODE,
+            if props.has_multi_dimen_capacity {
+                Arc::new(count_capacity_violations::<MultiDimLoad>)
+            } else {
+                Arc::new(count_capacity_violations::<SingleDimLoad>)
+            },
+        ),
+        RelaxableConstraintType::TimeWindow => (
+            TIME_CONSTRAINT_CODE,
+            Arc::new(|route_ctx| {
+                route_ctx
+                    .route
+                    .tour
+                    .all_activities()
+                    .filter(|activity| activity.schedule.arrival > activity.place.time.end)
+                    .count()
+            }),
+        ),
+        RelaxableConstraintType::Skills => (
+            SKILL_CONSTRAINT_CODE,
+            Arc::new(|route_ctx| {
+                route_ctx.route.tour.jobs().filter(|job| !has_skills(route_ctx.route.actor.as_ref(), job)).count()
+            }),
+        ),
+        RelaxableConstraintType::MaxDistance => (
+            DISTANCE_LIMIT_CONSTRAINT_CODE,
+            Arc::new(move |route_ctx| {
+                let limit = limits.deref()(route_ctx.route.actor.as_ref()).0;
+                let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+
+                limit.map_or(0, |limit| if distance > limit { 1 } else { 0 })
+            }),
+        ),
+        RelaxableConstraintType::ShiftTime => (
+            DURATION_LIMIT_CONSTRAINT_CODE,
+            Arc::new(move |route_ctx| {
+                let limit = limits.deref()(route_ctx.route.actor.as_ref()).1;
+                let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+
+                limit.map_or(0, |limit| if duration > limit { 1 } else { 0 })
+            }),
+        ),
+        RelaxableConstraintType::TourSize => (
+            TOUR_SIZE_CONSTRAINT_CODE,
+            Arc::new(|route_ctx| {
+                let limit = route_ctx.route.actor.vehicle.dimens.get_value::<usize>("tour_size").cloned();
+                let size = route_ctx.route.tour.activity_count();
+
+                limit.map_or(0, |limit| size.saturating_sub(limit))
+            }),
+        ),
+    };
+
+    RelaxableConstraint { code, weight: relaxation.weight.unwrap_or(DEFAULT_VIOLATION_WEIGHT), violation_func }
+}
+
+/// Returns a function which projects location coordinates to a plane in kilometers.
+fn get_location_point_func(coord_index: Arc<CoordIndex>) -> LocationPointFunc {
+    Arc::new(move |location| match coord_index.get_by_idx(location) {
+        Some(Location::Coordinate { lat, lng }) => {
+            Some(Point::new(lng * KM_PER_DEGREE * lat.to_radians().cos(), lat * KM_PER_DEGREE))
+        }
+        _ => None,
+    })
+}
+
+/// Returns a function which gets preferred time windows of the activity place from job dimensions.
+pub fn get_preferred_times_func() -> PreferredTimesFunc {
+    Arc::new(get_preferred_times)
+}
+
+fn get_preferred_times(activity: &Activity) -> Option<&[TimeWindow]> {
+    activity
+        .job
+        .as_ref()?
+        .dimens
+        .get_value::<Vec<(usize, Vec<TimeWindow>)>>("preferred_times")?
+        .iter()
+        .find(|(location, _)| *location == activity.place.location)
+        .map(|(_, times)| times.as_slice())
+}
+
+fn get_previous_assignment(previous: &[PreviousAssignment]) -> PreviousAssignmentFunc {
+    let assignments = previous
+        .iter()
+        .flat_map(|assignment| {
+            assignment.jobs.iter().map(move |job_id| (job_id.clone(), assignment.vehicle_id.clone()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    Arc::new(move |job| job.dimens().get_id().and_then(|job_id| assignments.get(job_id)).cloned())
+}
+
+fn get_slack_reservation(
+    props: &ProblemProperties,
+    capacity: f64,
+    time: f64,
+    weight: f64,
+) -> (TargetConstraint, TargetObjective) {
+    if props.has_multi_dimen_capacity {
+        SlackReservation::new_with_constraint::<MultiDimLoad>(capacity, time, weight)
+    } else {
+        SlackReservation::new_with_constraint::<SingleDimLoad>(capacity, time, weight)
+    }
+}
+
 fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>) {
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
@@ -0,0 +1,87 @@
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::problem::{Actor, Job};
+
+/// A function which checks whether job can be served by given actor.
+pub type JobFilterFunc = Arc<dyn Fn(&Actor, &Job) -> bool + Send + Sync>;
+
+/// Specifies user defined constraint modules which are added to the end of constraint pipeline
+/// generated from pragmatic problem definition. Constraint codes of such modules should not clash
+/// with codes of built-in constraints: they are reported as unknown reason of unassigned jobs.
+#[derive(Default)]
+pub struct ConstraintPlugins {
+    modules: Vec<(Box<dyn ConstraintModule + Send + Sync>, i32)>,
+}
+
+impl ConstraintPlugins {
+    /// Adds a custom constraint module.
+    pub fn with_module(self, module: Box<dyn ConstraintModule + Send + Sync>) -> Self {
+        self.with_prioritized_module(module, 0)
+    }
+
+    /// Adds a custom constraint module with given evaluation priority of its hard constraints. Built-in
+    /// modules have zero priority, so expensive constraints can be evaluated after them using
+    /// positive priority.
+    pub fn with_prioritized_module(mut self, module: Box<dyn ConstraintModule + Send + Sync>, priority: i32) -> Self {
+        self.modules.push((module, priority));
+        self
+    }
+
+    /// Adds a constraint module which allows to assign job only to actors accepted by given
+    /// function. Job and vehicle properties are accessible via their dimensions, e.g. `tag` of
+    /// job tasks, `type_id` and `id` of vehicles.
+    pub fn with_job_filter(self, code: i32, filter: JobFilterFunc) -> Self {
+        self.with_module(Box::new(JobFilterModule {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(JobFilterHardRouteConstraint { code, filter }))],
+            keys: vec![],
+            codes: vec![code],
+        }))
+    }
+
+    pub(crate) fn into_modules(self) -> Vec<(Box<dyn ConstraintModule + Send + Sync>, i32)> {
+        self.modules
+    }
+}
+
+struct JobFilterModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    codes: Vec<i32>,
+}
+
+impl ConstraintModule for JobFilterModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+
+    fn violation_codes(&self) -> Iter<i32> {
+        self.codes.iter()
+    }
+}
+
+struct JobFilterHardRouteConstraint {
+    code: i32,
+    filter: JobFilterFunc,
+}
+
+impl HardRouteConstraint for JobFilterHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if (self.filter)(ctx.route.actor.as_ref(), job) {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
@@ -11,15 +11,21 @@ mod fleet_reader;
 #[path = "./objective_reader.rs"]
 mod objective_reader;
 
-use self::fleet_reader::{create_transport_costs, read_fleet, read_travel_limits};
+use self::fleet_reader::{
+    create_toll_func, create_transport_costs, create_travel_deviation_func, get_lateness_risk_probability, read_fleet,
+    read_travel_limits,
+};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
-use self::objective_reader::create_objective;
+use self::objective_reader::{create_objective, create_total_violations, get_preferred_times_func};
 use crate::constraints::*;
 use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::format::problem::{
+    deserialize_matrix, deserialize_problem, get_volume_dimension, ConstraintPlugins, Matrix, Profile,
+    VehicleBreakPolicy,
+};
 use crate::format::*;
-use crate::utils::get_approx_transportation;
+use crate::utils::{get_approx_transportation, get_haversine_distance};
 use crate::validation::ValidationContext;
 use crate::{get_unique_locations, parse_time};
 use hashbrown::HashSet;
@@ -28,20 +34,27 @@ use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::models::common::{MultiDimLoad, SingleDimLoad, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{ActivityCost, Fleet, TransportCost};
+use vrp_core::models::problem::{create_toll_transport_cost, ActivityCost, Fleet, TransportCost};
 use vrp_core::models::{Extras, Lock, Problem};
+use vrp_core::solver::objectives::{LatenessRisk, PreferredTimes, TotalViolations};
 use vrp_core::utils::{compare_floats, DefaultRandom, Random};
 
 pub type ApiProblem = crate::format::problem::Problem;
 
 /// Reads specific problem definition from various sources.
-pub trait PragmaticProblem {
+pub trait PragmaticProblem: Sized {
     /// Reads problem defined in pragmatic format.
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>>;
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_plugins(ConstraintPlugins::default())
+    }
+
+    /// Reads problem defined in pragmatic format and adds user defined constraint modules to
+    /// its constraint pipeline.
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>>;
 }
 
 impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(self.0)?;
 
         let mut matrices = vec![];
@@ -49,20 +62,20 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, plugins)
     }
 }
 
 impl<R: Read> PragmaticProblem for BufReader<R> {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(self)?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, plugins)
     }
 }
 
 impl PragmaticProblem for (String, Vec<String>) {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(BufReader::new(self.0.as_bytes()))?;
 
         let mut matrices = vec![];
@@ -70,42 +83,43 @@ impl PragmaticProblem for (String, Vec<String>) {
             matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, plugins)
     }
 }
 
 impl PragmaticProblem for String {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(BufReader::new(self.as_bytes()))?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, plugins)
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_matrices(self.0, self.1)
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
+        map_to_problem_with_matrices(self.0, self.1, plugins)
     }
 }
 
 impl PragmaticProblem for ApiProblem {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_approx(self)
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
+        map_to_problem_with_approx(self, plugins)
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
-    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+    fn read_pragmatic_with_plugins(self, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
         if let Some(matrices) = self.1 {
-            (self.0, matrices).read_pragmatic()
+            (self.0, matrices).read_pragmatic_with_plugins(plugins)
         } else {
-            self.0.read_pragmatic()
+            self.0.read_pragmatic_with_plugins(plugins)
         }
     }
 }
 
 pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
+    volume_dimension: Option<usize>,
     has_breaks: bool,
     has_skills: bool,
     has_unreachable_locations: bool,
@@ -114,31 +128,63 @@ pub struct ProblemProperties {
     has_priorities: bool,
     has_area_limits: bool,
     has_tour_size_limits: bool,
+    has_trailers: bool,
+    has_unavailability: bool,
+    has_preferred_times: bool,
+    has_radius_limits: bool,
+    has_team_driving: bool,
+    has_driving_rests: bool,
+    has_loading_policies: bool,
+    has_compatibility: bool,
+    skill_index: SkillIndex,
+}
+
+/// Returns approximation speed of the profile: either specified explicitly or default for its type.
+fn get_approx_speed(profile: &Profile) -> f64 {
+    // NOTE invalid values are reported by validation
+    profile.speed.filter(|speed| *speed > 0.).unwrap_or(match profile.profile_type.as_str() {
+        "bike" | "bicycle" => 4.2,
+        "foot" | "pedestrian" => 1.4,
+        _ => 10.,
+    })
+}
+
+/// Returns approximation distance multiplier of the profile.
+fn get_approx_distance_multiplier(profile: &Profile) -> f64 {
+    profile.distance_multiplier.filter(|multiplier| *multiplier > 0.).unwrap_or(1.)
 }
 
 fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
-    const DEFAULT_SPEED: f64 = 10.;
-    // get each speed value once
-    let speeds = problem
+    let get_params = |profile: &Profile| (get_approx_speed(profile), get_approx_distance_multiplier(profile));
+
+    // get each pair of speed and distance multiplier once
+    let params = problem
         .fleet
         .profiles
         .iter()
-        .map(|profile| profile.speed.unwrap_or(DEFAULT_SPEED))
-        .map(|speed| speed.to_bits())
-        .collect::<HashSet<u64>>();
-    let speeds = speeds.into_iter().map(f64::from_bits).collect::<Vec<_>>();
+        .map(|profile| {
+            let (speed, multiplier) = get_params(profile);
+            (speed.to_bits(), multiplier.to_bits())
+        })
+        .collect::<HashSet<_>>();
+    let params = params
+        .into_iter()
+        .map(|(speed, multiplier)| (f64::from_bits(speed), f64::from_bits(multiplier)))
+        .collect::<Vec<_>>();
 
     let locations = get_unique_locations(&problem);
-    let approx_data = get_approx_transportation(&locations, speeds.as_slice());
+    let approx_data = get_approx_transportation(&locations, params.as_slice());
 
     problem
         .fleet
         .profiles
         .iter()
         .map(move |profile| {
-            let speed = profile.speed.clone().unwrap_or(DEFAULT_SPEED);
-            let idx =
-                speeds.iter().position(|s| compare_floats(*s, speed) == Equal).expect("Cannot find profile speed");
+            let (speed, multiplier) = get_params(profile);
+            let idx = params
+                .iter()
+                .position(|(s, m)| compare_floats(*s, speed) == Equal && compare_floats(*m, multiplier) == Equal)
+                .expect("Cannot find profile approximation parameters");
 
             Matrix {
                 profile: Some(profile.name.clone()),
@@ -146,26 +192,35 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                travel_time_deviations: None,
+                tolls: None,
             }
         })
         .collect()
 }
 
-fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_approx(problem: ApiProblem, plugins: ConstraintPlugins) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    let matrices = if coord_index.get_used_types().1 { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    let (matrices, is_approximated) =
+        if coord_index.get_used_types().1 { (vec![], false) } else { (create_approx_matrices(&problem), true) };
+    map_to_problem(problem, matrices, coord_index, is_approximated, plugins)
 }
 
-fn map_to_problem_with_matrices(problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_matrices(
+    problem: ApiProblem,
+    matrices: Vec<Matrix>,
+    plugins: ConstraintPlugins,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, false, plugins)
 }
 
 fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    is_approximated: bool,
+    plugins: ConstraintPlugins,
 ) -> Result<Problem, Vec<FormatError>> {
     ValidationContext::new(&api_problem, Some(&matrices)).validate()?;
 
@@ -179,8 +234,12 @@ fn map_to_problem(
             format!("Check matrix routing data: '{}'", err),
         )]
     })?;
+    let transport = match create_toll_func(&api_problem, &matrices, &coord_index) {
+        Some(toll_func) => create_toll_transport_cost(transport, toll_func),
+        None => transport,
+    };
     let activity = Arc::new(OnlyVehicleActivityCost::default());
-    let fleet = read_fleet(&api_problem, &problem_props, &coord_index);
+    let fleet = read_fleet(&api_problem, &problem_props, &coord_index, transport.as_ref());
 
     // TODO pass random from outside as there might be need to have it initialized with seed
     //      at the moment, this random instance is used only by multi job permutation generator
@@ -197,6 +256,7 @@ fn map_to_problem(
     );
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect::<Vec<_>>();
     let limits = read_travel_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
+    let total_violations = create_total_violations(&api_problem, &problem_props, limits.clone());
     let mut constraint = create_constraint_pipeline(
         coord_index.clone(),
         &fleet,
@@ -206,10 +266,48 @@ fn map_to_problem(
         &locks,
         limits,
     );
+    plugins.into_modules().into_iter().for_each(|(module, priority)| {
+        constraint.add_module_with_priority(module, priority);
+    });
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
-    let constraint = Arc::new(constraint);
-    let extras = Arc::new(create_extras(constraint.clone(), &problem_props, job_index, coord_index));
+    let deviation_func = create_travel_deviation_func(&api_problem, &matrices);
+    let objective = create_objective(
+        &api_problem,
+        &mut constraint,
+        &problem_props,
+        deviation_func.clone(),
+        total_violations.clone(),
+        coord_index.clone(),
+        activity.clone(),
+        transport.clone(),
+    );
+    let constraint = Arc::new(match &total_violations {
+        Some(total_violations) => total_violations.relax(constraint),
+        None => constraint,
+    });
+    let lateness_risk = get_lateness_risk_probability(&api_problem)
+        .map(|probability| LatenessRisk::new(deviation_func.unwrap_or_else(|| Arc::new(|_, _, _| 0.)), probability));
+    let preferred_times =
+        if problem_props.has_preferred_times { Some(PreferredTimes::new(get_preferred_times_func())) } else { None };
+    let mut extras = create_extras(
+        constraint.clone(),
+        &problem_props,
+        job_index,
+        coord_index,
+        lateness_risk,
+        preferred_times,
+        total_violations,
+    );
+    if is_approximated {
+        let speeds = api_problem
+            .fleet
+            .profiles
+            .iter()
+            .map(|profile| (profile.name.clone(), get_approx_speed(profile)))
+            .collect::<Vec<_>>();
+        extras.insert("routing_approximation".to_owned(), Arc::new(speeds));
+    }
+    let extras = Arc::new(extras);
 
     Ok(Problem {
         fleet: Arc::new(fleet),
@@ -244,6 +342,16 @@ fn create_constraint_pipeline(
 
     add_capacity_module(&mut constraint, &props);
 
+    if props.has_driving_rests {
+        constraint.add_module(Box::new(DrivingRestModule::new(
+            Arc::new(|actor| actor.vehicle.dimens.get_value::<DrivingLimit>("driving_limit").cloned()),
+            // NOTE driving threshold is set only for driving time breaks
+            Arc::new(|single| single.dimens.get_value::<f64>("driving_threshold").is_some()),
+            transport.clone(),
+            DRIVING_REST_CONSTRAINT_CODE,
+        )));
+    }
+
     if props.has_breaks {
         constraint.add_module(Box::new(BreakModule::new(BREAK_CONSTRAINT_CODE)));
     }
@@ -256,6 +364,10 @@ fn create_constraint_pipeline(
         constraint.add_module(Box::new(DispatchModule::new(DISPATCH_CONSTRAINT_CODE)));
     }
 
+    if props.has_unavailability {
+        constraint.add_module(Box::new(UnavailabilityModule::new(UNAVAILABILITY_CONSTRAINT_CODE)));
+    }
+
     if props.has_priorities {
         constraint.add_module(Box::new(PriorityModule::new(PRIORITY_CONSTRAINT_CODE)));
     }
@@ -272,10 +384,37 @@ fn create_constraint_pipeline(
         add_tour_size_module(&mut constraint)
     }
 
+    if props.has_radius_limits {
+        add_radius_module(&mut constraint, transport.clone(), coord_index.clone());
+    }
+
+    if props.has_loading_policies {
+        constraint.add_module(Box::new(PrecedenceConstraintModule::new(
+            Arc::new(|multi| multi.dimens.get_value::<LoadingPolicy>("loading_policy").cloned()),
+            LOADING_POLICY_CONSTRAINT_CODE,
+        )));
+    }
+
+    if props.has_compatibility {
+        add_compatibility_module(&mut constraint, props);
+    }
+
+    if props.has_team_driving {
+        constraint.add_module(Box::new(TeamDrivingModule::new(
+            Arc::new(|actor| actor.vehicle.dimens.get_value::<DrivingTeam>("team").cloned()),
+            transport.clone(),
+            TEAM_DRIVING_CONSTRAINT_CODE,
+        )));
+    }
+
     if props.has_area_limits {
         add_area_module(&mut constraint, coord_index);
     }
 
+    if props.has_trailers {
+        add_trailer_module(&mut constraint, props);
+    }
+
     constraint
 }
 
@@ -300,6 +439,24 @@ fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemPrope
     });
 }
 
+fn add_trailer_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(TrailerModule::<MultiDimLoad>::new(TRAILER_CONSTRAINT_CODE))
+    } else {
+        Box::new(TrailerModule::<SingleDimLoad>::new(TRAILER_CONSTRAINT_CODE))
+    });
+}
+
+fn add_compatibility_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
+    let resolver: CompatibilityResolver = Arc::new(|job| job.dimens().get_value::<Compatibility>("compatibility"));
+
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(CompatibilityConstraintModule::<MultiDimLoad>::new(resolver, COMPATIBILITY_CONSTRAINT_CODE))
+    } else {
+        Box::new(CompatibilityConstraintModule::<SingleDimLoad>::new(resolver, COMPATIBILITY_CONSTRAINT_CODE))
+    });
+}
+
 fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIndex>) {
     constraint.add_module(Box::new(AreaModule::new(
         Arc::new(|actor| actor.vehicle.dimens.get_value::<Vec<Area>>("areas")),
@@ -312,6 +469,27 @@ fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIn
     )));
 }
 
+fn add_radius_module(
+    constraint: &mut ConstraintPipeline,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    coord_index: Arc<CoordIndex>,
+) {
+    constraint.add_module(Box::new(RadiusModule::new(
+        Arc::new(|actor| actor.vehicle.dimens.get_value::<f64>("max_radius").cloned()),
+        Arc::new(move |actor, from, to| {
+            if actor.vehicle.dimens.get_value::<bool>("radius_airline").cloned().unwrap_or(false) {
+                match (coord_index.get_by_idx(from), coord_index.get_by_idx(to)) {
+                    (Some(from), Some(to)) => get_haversine_distance(&from, &to),
+                    _ => panic!("cannot find location!"),
+                }
+            } else {
+                transport.distance(actor.vehicle.profile, from, to, actor.detail.time.start)
+            }
+        }),
+        MAX_RADIUS_CONSTRAINT_CODE,
+    )));
+}
+
 fn add_tour_size_module(constraint: &mut ConstraintPipeline) {
     constraint.add_module(Box::new(TourSizeModule::new(
         Arc::new(|actor| actor.vehicle.dimens.get_value::<usize>("tour_size").cloned()),
@@ -324,6 +502,9 @@ fn create_extras(
     props: &ProblemProperties,
     job_index: JobIndex,
     coord_index: Arc<CoordIndex>,
+    lateness_risk: Option<LatenessRisk>,
+    preferred_times: Option<PreferredTimes>,
+    total_violations: Option<TotalViolations>,
 ) -> Extras {
     let mut extras = Extras::default();
     extras.insert(
@@ -333,10 +514,22 @@ fn create_extras(
     extras.insert("coord_index".to_owned(), coord_index);
     extras.insert("job_index".to_owned(), Arc::new(job_index.clone()));
 
-    if props.has_dispatch {
+    if props.has_dispatch || props.has_unavailability {
         extras.insert("route_modifier".to_owned(), Arc::new(get_route_modifier(constraint, job_index)));
     }
 
+    if let Some(lateness_risk) = lateness_risk {
+        extras.insert("lateness_risk".to_owned(), Arc::new(lateness_risk));
+    }
+
+    if let Some(preferred_times) = preferred_times {
+        extras.insert("preferred_times".to_owned(), Arc::new(preferred_times));
+    }
+
+    if let Some(total_violations) = total_violations {
+        extras.insert("total_violations".to_owned(), Arc::new(total_violations));
+    }
+
     extras
 }
 
@@ -347,7 +540,9 @@ fn parse_time_window(tw: &[String]) -> TimeWindow {
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
     let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
+    let volume_dimension = get_volume_dimension(api_problem);
+    let has_multi_dimen_capacity = volume_dimension.is_some()
+        || api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
         || api_problem.plan.jobs.iter().any(|job| {
             job.pickups
                 .iter()
@@ -363,6 +558,23 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|shift| shift.breaks.as_ref().map_or(false, |b| !b.is_empty()));
 
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    let skill_index = SkillIndex::new(
+        api_problem
+            .plan
+            .jobs
+            .iter()
+            .filter_map(|job| job.skills.as_ref())
+            .flat_map(|skills| {
+                skills
+                    .all_of
+                    .iter()
+                    .chain(skills.one_of.iter())
+                    .chain(skills.none_of.iter())
+                    .flat_map(|s| s.iter())
+                    .chain(skills.preferred.iter().flat_map(|preferred| preferred.iter().map(|skill| &skill.name)))
+            })
+            .chain(api_problem.fleet.vehicles.iter().filter_map(|v| v.skills.as_ref()).flat_map(|s| s.iter())),
+    );
     let has_dispatch = api_problem
         .fleet
         .vehicles
@@ -382,9 +594,36 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|v| v.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map_or(false, |a| !a.is_empty()));
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
+    let has_radius_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_radius.is_some()));
+    let has_trailers = api_problem.fleet.vehicles.iter().any(|v| v.trailer.is_some());
+    let has_team_driving = api_problem.fleet.vehicles.iter().any(|v| v.team.is_some());
+    let has_driving_rests = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|t| t.shifts.iter().flat_map(|s| s.breaks.iter().flatten()))
+        .any(|b| b.policy == Some(VehicleBreakPolicy::DrivingTime));
+    let has_loading_policies = api_problem.plan.jobs.iter().any(|job| job.loading_policy.is_some());
+    let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+    let has_unavailability = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|t| t.shifts.iter().any(|s| s.unavailability.as_ref().map_or(false, |periods| !periods.is_empty())));
+    let has_preferred_times = api_problem.plan.jobs.iter().any(|job| {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .chain(job.replacements.iter())
+            .chain(job.services.iter())
+            .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
+            .any(|place| place.preferred_times.as_ref().map_or(false, |times| !times.is_empty()))
+    });
 
     ProblemProperties {
         has_multi_dimen_capacity,
+        volume_dimension,
         has_breaks,
         has_skills,
         has_unreachable_locations,
@@ -393,5 +632,14 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_priorities,
         has_area_limits,
         has_tour_size_limits,
+        has_trailers,
+        has_unavailability,
+        has_preferred_times,
+        has_radius_limits,
+        has_team_driving,
+        has_driving_rests,
+        has_loading_policies,
+        has_compatibility,
+        skill_index,
     }
 }
@@ -0,0 +1,84 @@
+//! Contains logic to map weight and volume of jobs and vehicles to multi dimensional capacity.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/volume_test.rs"]
+mod volume_test;
+
+use crate::format::problem::{JobTask, Problem, VehicleType};
+
+/// Returns index of volume dimension in capacity vector if volume is used within the problem.
+/// Volume dimension follows all dimensions used by vehicle capacity or job demand.
+pub(crate) fn get_volume_dimension(problem: &Problem) -> Option<usize> {
+    let tasks = problem.plan.jobs.iter().flat_map(|job| {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .chain(job.replacements.iter())
+            .chain(job.services.iter())
+            .flat_map(|tasks| tasks.iter())
+    });
+
+    let has_volume = problem.fleet.vehicles.iter().any(|vehicle| vehicle.volume.is_some())
+        || tasks.clone().any(|task| task.volume.is_some() || task.density.is_some());
+
+    if has_volume {
+        let vehicle_dimensions = problem.fleet.vehicles.iter().map(|vehicle| vehicle.capacity.len());
+        let job_dimensions = tasks.map(|task| task.demand.as_ref().map_or(0, |demand| demand.len()));
+
+        Some(vehicle_dimensions.chain(job_dimensions).max().unwrap_or(0).max(1))
+    } else {
+        None
+    }
+}
+
+/// Returns vehicle capacity with volume dimension, if it is used. No volume restrictions are
+/// applied when vehicle has no volume specified. Trailer capacity, if any, is included.
+pub(crate) fn get_vehicle_capacity(vehicle: &VehicleType, volume_dimension: Option<usize>) -> Vec<i32> {
+    let capacity = match &vehicle.trailer {
+        Some(trailer) => (0..vehicle.capacity.len().max(trailer.capacity.len()))
+            .map(|idx| {
+                vehicle.capacity.get(idx).cloned().unwrap_or(0) + trailer.capacity.get(idx).cloned().unwrap_or(0)
+            })
+            .collect(),
+        None => vehicle.capacity.clone(),
+    };
+
+    with_vehicle_volume(vehicle, capacity, volume_dimension)
+}
+
+/// Returns capacity of the vehicle without its trailer with volume dimension, if it is used.
+pub(crate) fn get_truck_capacity(vehicle: &VehicleType, volume_dimension: Option<usize>) -> Vec<i32> {
+    with_vehicle_volume(vehicle, vehicle.capacity.clone(), volume_dimension)
+}
+
+fn with_vehicle_volume(vehicle: &VehicleType, capacity: Vec<i32>, volume_dimension: Option<usize>) -> Vec<i32> {
+    match volume_dimension {
+        Some(dimension) => with_volume(capacity, dimension, vehicle.volume.unwrap_or(i32::MAX)),
+        None => capacity,
+    }
+}
+
+/// Returns job task demand with volume dimension, if it is used. When volume is omitted, it is
+/// calculated from task density and weight which is the first dimension of the demand.
+pub(crate) fn get_task_demand(task: &JobTask, volume_dimension: Option<usize>) -> Option<Vec<i32>> {
+    let dimension = match volume_dimension {
+        Some(dimension) => dimension,
+        None => return task.demand.clone(),
+    };
+
+    let weight = task.demand.as_ref().and_then(|demand| demand.first().cloned()).unwrap_or(0);
+    let volume = task.volume.or_else(|| task.density.map(|density| (weight as f64 / density).ceil() as i32));
+
+    match (task.demand.clone(), volume) {
+        (Some(demand), volume) => Some(with_volume(demand, dimension, volume.unwrap_or(0))),
+        (None, Some(volume)) => Some(with_volume(vec![], dimension, volume)),
+        (None, None) => None,
+    }
+}
+
+fn with_volume(mut capacity: Vec<i32>, dimension: usize, volume: i32) -> Vec<i32> {
+    capacity.resize(dimension, 0);
+    capacity.push(volume);
+
+    capacity
+}
@@ -68,7 +68,7 @@ pub(crate) fn try_match_job(
 
             Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
         }
-        "break" | "dispatch" | "reload" => Ok(Some(
+        "break" | "dispatch" | "reload" | "unavailability" => Ok(Some(
             (1..)
                 .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
                 .map(|job_id| job_index.get(&job_id))
@@ -80,6 +80,26 @@ pub(crate) fn try_match_job(
                 .next()
                 .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
         )),
+        "detach" | "attach" => Ok(Some(
+            (1..)
+                .map(|idx| format!("{}_trailer_{}_{}", tour.vehicle_id, tour.shift_index, idx))
+                .map(|job_id| job_index.get(&job_id))
+                .take_while(|job| job.is_some())
+                .filter_map(|job| job.and_then(|job| job.as_multi().map(|multi| (job.clone(), multi.clone()))))
+                .flat_map(|(job, multi)| {
+                    multi
+                        .jobs
+                        .iter()
+                        .filter(|single| single.dimens.get_value::<String>("type").map_or(false, |t| t == ctx.act_type))
+                        .map(|single| (job.clone(), single.clone()))
+                        .collect::<Vec<_>>()
+                })
+                .filter_map(|(job, single)| {
+                    match_place(&single, false, &ctx).map(|place| JobInfo(job, single, place, ctx.time.clone()))
+                })
+                .next()
+                .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
+        )),
         _ => Err(format!("unknown activity type: {}", activity.activity_type)),
     }
 }
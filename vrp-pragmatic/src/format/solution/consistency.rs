@@ -0,0 +1,27 @@
+use crate::format::problem::PreviousAssignment;
+use crate::format::solution::Solution;
+use hashbrown::HashSet;
+
+/// Gets job assignments from the solution which can be used with `minimize-driver-changes`
+/// objective when the problem is solved next time.
+pub fn get_previous_assignments(solution: &Solution) -> Vec<PreviousAssignment> {
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut visited = HashSet::new();
+            let jobs = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| {
+                    matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                })
+                .filter(|activity| visited.insert(activity.job_id.clone()))
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+
+            PreviousAssignment { vehicle_id: tour.vehicle_id.clone(), jobs }
+        })
+        .collect()
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/explanation_test.rs"]
+mod explanation_test;
+
+use super::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{find_job_relaxation, InsertionContext};
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::Environment;
+
+/// Constraint codes which can be relaxed to explain why job is unassigned.
+const RELAXABLE_CODES: &[i32] = &[
+    CAPACITY_CONSTRAINT_CODE,
+    TIME_CONSTRAINT_CODE,
+    SKILL_CONSTRAINT_CODE,
+    DISTANCE_LIMIT_CONSTRAINT_CODE,
+    DURATION_LIMIT_CONSTRAINT_CODE,
+    TOUR_SIZE_CONSTRAINT_CODE,
+    AREA_CONSTRAINT_CODE,
+];
+
+/// Explains why jobs are unassigned in the solution: for each unassigned job, relaxes constraints
+/// (capacity, time windows, skills, distance and shift time limits, etc.) on a copy of the problem
+/// and reports a minimal relaxation which makes the job assignable.
+pub fn explain_unassigned_jobs(problem: Arc<Problem>, solution: &Solution) -> Vec<UnassignedJobExplanation> {
+    explain_jobs(problem, solution, |job| job.dimens().get_value::<String>("vehicle_id").is_none())
+}
+
+/// Checks that all jobs marked as must serve are assigned in the solution. Returns an infeasibility
+/// error which lists such unassigned jobs together with the constraints blocking their insertion.
+pub fn check_must_serve_jobs(problem: Arc<Problem>, solution: &Solution) -> Result<(), FormatError> {
+    let is_must_serve = |job: &Job| job.dimens().get_value::<bool>("must_serve").cloned().unwrap_or(false);
+
+    if !solution.unassigned.iter().any(|(job, _)| is_must_serve(job)) {
+        return Ok(());
+    }
+
+    let details = explain_jobs(problem, solution, is_must_serve)
+        .into_iter()
+        .map(|explanation| {
+            let reasons = explanation
+                .relaxation
+                .map(|relaxation| {
+                    relaxation.reasons.iter().map(|reason| reason.code.as_str()).collect::<Vec<_>>().join(", ")
+                })
+                .unwrap_or_else(|| "UNKNOWN".to_string());
+
+            format!("{}: [{}]", explanation.job_id, reasons)
+        })
+        .collect::<Vec<_>>();
+
+    Err(FormatError::new(
+        "E0005".to_string(),
+        "cannot serve must-serve jobs".to_string(),
+        format!("relax constraints blocking their assignment: {}", details.join(", ")),
+    ))
+}
+
+fn explain_jobs<F>(problem: Arc<Problem>, solution: &Solution, filter: F) -> Vec<UnassignedJobExplanation>
+where
+    F: Fn(&Job) -> bool,
+{
+    let insertion_ctx = InsertionContext::new_from_solution(
+        problem,
+        (
+            Solution {
+                registry: solution.registry.deep_copy(),
+                routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+                unassigned: solution.unassigned.clone(),
+                extras: solution.extras.clone(),
+            },
+            None,
+        ),
+        Arc::new(Environment::default()),
+    );
+
+    solution
+        .unassigned
+        .iter()
+        .filter(|(job, _)| filter(job))
+        .map(|(job, _)| UnassignedJobExplanation {
+            job_id: job.dimens().get_id().expect("job id expected").clone(),
+            relaxation: find_job_relaxation(&insertion_ctx, job, RELAXABLE_CODES).map(|relaxation| {
+                UnassignedJobRelaxation {
+                    vehicle_id: relaxation.actor.vehicle.dimens.get_id().expect("vehicle id expected").clone(),
+                    reasons: relaxation
+                        .codes
+                        .iter()
+                        .map(|code| {
+                            let (code, description) = map_code_reason(*code);
+                            UnassignedJobReason { code: code.to_string(), description: description.to_string() }
+                        })
+                        .collect(),
+                }
+            }),
+        })
+        .collect()
+}
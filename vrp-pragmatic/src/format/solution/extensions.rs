@@ -8,6 +8,7 @@ impl Default for Statistic {
             distance: 0,
             duration: 0,
             times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+            value: None,
         }
     }
 }
@@ -26,6 +27,10 @@ impl Add for Statistic {
                 waiting: self.times.waiting + rhs.times.waiting,
                 break_time: self.times.break_time + rhs.times.break_time,
             },
+            value: match (self.value, rhs.value) {
+                (Some(left), Some(right)) => Some(left + right),
+                (value, None) | (None, value) => value,
+            },
         }
     }
 }
@@ -5,6 +5,12 @@ pub use self::model::*;
 
 pub(crate) mod activity_matcher;
 
+mod consistency;
+pub use self::consistency::get_previous_assignments;
+
+mod explanation;
+pub use self::explanation::{check_must_serve_jobs, explain_unassigned_jobs};
+
 mod geo_serializer;
 pub use self::geo_serializer::serialize_solution_as_geojson;
 
@@ -15,6 +21,7 @@ mod extensions;
 
 mod writer;
 pub use self::writer::create_solution;
+pub use self::writer::write_pareto_json;
 pub use self::writer::PragmaticSolution;
 
 use super::*;
@@ -39,6 +46,24 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         TOUR_SIZE_CONSTRAINT_CODE => {
             ("TOUR_SIZE_CONSTRAINT", "cannot be assigned due to tour size constraint of vehicle")
         }
+        TRAILER_CONSTRAINT_CODE => ("TRAILER_CONSTRAINT", "cannot be served while vehicle trailer is attached"),
+        UNAVAILABILITY_CONSTRAINT_CODE => {
+            ("UNAVAILABILITY_CONSTRAINT", "cannot be assigned due to vehicle unavailability")
+        }
+        MAX_RADIUS_CONSTRAINT_CODE => ("MAX_RADIUS_CONSTRAINT", "cannot be assigned due to max radius of vehicle"),
+        TEAM_DRIVING_CONSTRAINT_CODE => {
+            ("TEAM_DRIVING_CONSTRAINT", "cannot be assigned due to driving time limit of vehicle team")
+        }
+        DRIVING_REST_CONSTRAINT_CODE => {
+            ("DRIVING_REST_CONSTRAINT", "cannot be assigned due to continuous driving time limit")
+        }
+        LOADING_POLICY_CONSTRAINT_CODE => {
+            ("LOADING_POLICY_CONSTRAINT", "cannot be assigned due to loading policy of another job")
+        }
+        VALUE_CONSTRAINT_CODE => ("VALUE_CONSTRAINT", "serving job costs more than its value"),
+        COMPATIBILITY_CONSTRAINT_CODE => {
+            ("COMPATIBILITY_CONSTRAINT", "cannot be assigned due to incompatibility with another job")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -57,6 +82,14 @@ fn map_reason_code(reason: &str) -> i32 {
         "AREA_CONSTRAINT" => AREA_CONSTRAINT_CODE,
         "DISPATCH_CONSTRAINT" => DISPATCH_CONSTRAINT_CODE,
         "TOUR_SIZE_CONSTRAINT" => TOUR_SIZE_CONSTRAINT_CODE,
+        "TRAILER_CONSTRAINT" => TRAILER_CONSTRAINT_CODE,
+        "UNAVAILABILITY_CONSTRAINT" => UNAVAILABILITY_CONSTRAINT_CODE,
+        "MAX_RADIUS_CONSTRAINT" => MAX_RADIUS_CONSTRAINT_CODE,
+        "TEAM_DRIVING_CONSTRAINT" => TEAM_DRIVING_CONSTRAINT_CODE,
+        "DRIVING_REST_CONSTRAINT" => DRIVING_REST_CONSTRAINT_CODE,
+        "LOADING_POLICY_CONSTRAINT" => LOADING_POLICY_CONSTRAINT_CODE,
+        "VALUE_CONSTRAINT" => VALUE_CONSTRAINT_CODE,
+        "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         _ => -1,
     }
 }
@@ -27,6 +27,9 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Total value of served jobs. Available only when some served job has value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
 }
 
 /// Represents a schedule.
@@ -65,6 +68,12 @@ pub struct Activity {
     /// Job tag.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_tag: Option<String>,
+    /// Time window chosen to serve the activity. Specified only when job has multiple time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_window: Option<Interval>,
+    /// Crew member who serves the activity. Specified only when vehicle has a crew.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew_member: Option<usize>,
 }
 
 /// A stop is a place where vehicle is supposed to be parked.
@@ -117,6 +126,28 @@ pub struct UnassignedJob {
     pub reasons: Vec<UnassignedJobReason>,
 }
 
+/// Explains why job is unassigned.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobExplanation {
+    /// Job id.
+    pub job_id: String,
+    /// A minimal constraint relaxation which makes job assignable. Not set when job cannot be
+    /// assigned even if all supported constraints are relaxed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relaxation: Option<UnassignedJobRelaxation>,
+}
+
+/// Specifies constraints to be relaxed in order to make job assignable.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobRelaxation {
+    /// A vehicle id which can serve the job when constraints are relaxed.
+    pub vehicle_id: String,
+    /// Constraints to be relaxed. Empty when job can be assigned without relaxation.
+    pub reasons: Vec<UnassignedJobReason>,
+}
+
 /// Specifies a type of violation.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -132,6 +163,19 @@ pub enum Violation {
         /// A reason of violation.
         reason: String,
     },
+
+    /// A relaxable hard constraint violation.
+    #[serde(rename(deserialize = "constraint", serialize = "constraint"))]
+    Constraint {
+        /// An id of a vehicle which tour violates the constraint.
+        vehicle_id: String,
+        /// Index of the shift.
+        shift_index: usize,
+        /// A code of the violated constraint.
+        code: String,
+        /// Amount of violations in the tour.
+        count: usize,
+    },
 }
 
 /// Encapsulates different measurements regarding algorithm evaluation.
@@ -189,12 +233,117 @@ pub struct Population {
     pub individuals: Vec<Individual>,
 }
 
+/// Contains route geometry metrics which help to analyze visual quality of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteGeometry {
+    /// Route overlap: a sum of pairwise intersection areas of tours' convex hulls divided by
+    /// a sum of their areas.
+    pub overlap: f64,
+    /// Amount of crossings between segments of different tours.
+    pub crossings: usize,
+}
+
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
+    /// Route geometry metrics. Available only when requested explicitly and all locations are
+    /// specified by geocoordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<RouteGeometry>,
+    /// Vehicle capacity overloads. Available only when some tour exceeds its vehicle capacity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overloads: Option<Vec<TourOverload>>,
+    /// Tours robustness against travel time uncertainty. Available only when lateness risk
+    /// objective is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub robustness: Option<Vec<TourRobustness>>,
+    /// Monetary fees (tolls, zone fees) paid in tours. Available only when some tour has fees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolls: Option<Vec<TourToll>>,
+    /// Preferred time windows hits in tours. Available only when some job has preferred time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<Vec<TourPreferences>>,
+    /// Warnings about assumptions made while solving the problem, e.g. routing approximation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+    /// Information required to reproduce the solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// Specifies solver run settings required to reproduce the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// A master seed of random number generator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// A solver version.
+    pub version: String,
+    /// A hash of solver configuration, if it is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<String>,
+    /// Amount of available threads.
+    pub threads: usize,
+}
+
+/// Specifies how much vehicle capacity is exceeded in the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourOverload {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Max amount of overloaded units per capacity dimension.
+    pub overload: Vec<i32>,
+}
+
+/// Specifies monetary fees paid in the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourToll {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Total amount of tolls and zone fees included in tour cost.
+    pub amount: f64,
+}
+
+/// Specifies how robust the tour is against travel time uncertainty.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourRobustness {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Minimum time (in seconds) between planned arrival and end of time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_slack: Option<i64>,
+    /// Minimum probability to arrive at tour activity within its time window.
+    pub on_time_probability: f64,
+}
+
+/// Specifies how many activities in the tour are started within their preferred time windows.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourPreferences {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Amount of activities started within their preferred time windows.
+    pub hits: usize,
+    /// Amount of activities with preferred time windows.
+    pub total: usize,
+    /// A ratio of hits to total amount.
+    pub hit_rate: f64,
 }
 
 /// A VRP solution.
@@ -220,11 +369,25 @@ pub struct Solution {
     pub extras: Option<Extras>,
 }
 
+/// Specifies a solution from a set of mutually non-dominated solutions found for multi-objective problem.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ParetoSolution {
+    /// Objectives fitness values in the order of problem objectives.
+    pub objectives: Vec<f64>,
+    /// A solution.
+    pub solution: Solution,
+}
+
 /// Serializes solution into json format.
 pub fn serialize_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, solution).map_err(Error::from)
 }
 
+/// Serializes non-dominated solutions into json format.
+pub fn serialize_pareto_solutions<W: Write>(writer: BufWriter<W>, solutions: &[ParetoSolution]) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, solutions).map_err(Error::from)
+}
+
 /// Deserializes solution from json format.
 pub fn deserialize_solution<R: Read>(reader: BufReader<R>) -> Result<Solution, Error> {
     serde_json::from_reader(reader).map_err(Error::from)
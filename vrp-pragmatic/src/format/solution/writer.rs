@@ -2,17 +2,24 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
+use crate::extensions::get_crew_schedule;
 use crate::format::coord_index::CoordIndex;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
+use crate::format::Location;
 use crate::format::*;
 use crate::format_time;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use vrp_core::algorithms::geometry::{count_route_crossings, get_route_overlap, Point};
+use vrp_core::construction::clustering::vicinity::{ClusterDimension, ClusterInfo};
 use vrp_core::construction::constraints::route_intervals;
+use vrp_core::construction::heuristics::{RouteContext, RouteState};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::Multi;
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
+use vrp_core::solver::objectives::{LatenessRisk, PreferredTimes, TotalViolations};
 use vrp_core::solver::Metrics;
 
 type ApiActivity = crate::format::solution::model::Activity;
@@ -61,6 +68,23 @@ impl<W: Write> PragmaticSolution<W> for (Solution, Metrics) {
     }
 }
 
+/// Serializes a set of non-dominated solutions within their objectives fitness values in pragmatic json format.
+pub fn write_pareto_json<W: Write>(
+    problem: &Problem,
+    solutions: &[(Solution, Vec<f64>)],
+    writer: BufWriter<W>,
+) -> Result<(), String> {
+    let solutions = solutions
+        .iter()
+        .map(|(solution, objectives)| ParetoSolution {
+            objectives: objectives.clone(),
+            solution: create_solution(problem, solution, None),
+        })
+        .collect::<Vec<_>>();
+
+    serialize_pareto_solutions(writer, solutions.as_slice()).map_err(|err| err.to_string())
+}
+
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
     pub load: Option<MultiDimLoad>,
@@ -86,9 +110,9 @@ pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
     let unassigned = create_unassigned(solution);
-    let violations = create_violations(solution);
+    let violations = create_violations(problem, solution);
 
-    let extras = create_extras(solution, metrics);
+    let extras = create_extras(problem, solution, tours.as_slice(), metrics);
 
     ApiSolution { statistic, tours, unassigned, violations, extras }
 }
@@ -153,6 +177,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                         None
                     },
                     job_tag: None,
+                    time_window: None,
+                    crew_member: None,
                 }],
             });
             (start_idx + 1, start)
@@ -160,9 +186,12 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+        let activities = route.tour.activities_slice(start_idx, end_idx);
+        let crew_schedule = get_crew_schedule(actor, activities);
+
+        let mut leg = activities.iter().zip(crew_schedule).fold(
             Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
+            |leg, (act, crew)| {
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
@@ -177,6 +206,11 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let is_break = activity_type == "break";
 
                 let job_tag = act.job.as_ref().and_then(|job| job.dimens.get_value::<String>("tag").cloned());
+                // NOTE report chosen time window only when there is a choice
+                let time_window =
+                    act.job.as_ref().filter(|job| job.places.iter().any(|place| place.times.len() > 1)).map(|_| {
+                        Interval { start: format_time(act.place.time.start), end: format_time(act.place.time.end) }
+                    });
                 let job_id = match activity_type.as_str() {
                     "pickup" | "delivery" | "replacement" | "service" => {
                         let single = act.job.as_ref().unwrap();
@@ -191,7 +225,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let arrival = prev_departure + driving;
                 let start = act.schedule.arrival.max(act.place.time.start);
                 let waiting = start - act.schedule.arrival;
-                let serving = problem.activity.duration(route.actor.as_ref(), act, act.schedule.arrival);
+                // NOTE service time can depend on previous activity, e.g. when jobs are served in parallel
+                let serving = act.schedule.departure - start;
                 let departure = start + serving;
 
                 // total cost and distance
@@ -221,17 +256,27 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
 
                 last.time.departure = format_time(departure);
                 last.load = load.as_vec();
-                last.activities.push(ApiActivity {
-                    job_id,
-                    activity_type: activity_type.clone(),
-                    location: if !is_new_location && activity_type == "dispatch" {
-                        None
-                    } else {
-                        Some(coord_index.get_by_idx(act.place.location).unwrap())
-                    },
-                    time: Some(Interval { start: format_time(arrival), end: format_time(departure) }),
-                    job_tag,
-                });
+                match act.job.as_ref().and_then(|job| job.dimens.get_cluster_info()) {
+                    Some(cluster) => last.activities.extend(create_cluster_activities(cluster, start, coord_index)),
+                    None => last.activities.push(ApiActivity {
+                        job_id,
+                        activity_type: activity_type.clone(),
+                        location: if !is_new_location && activity_type == "dispatch" {
+                            None
+                        } else {
+                            Some(coord_index.get_by_idx(act.place.location).unwrap())
+                        },
+                        time: Some(match crew {
+                            Some((_, start)) => {
+                                Interval { start: format_time(start), end: format_time(start + act.place.duration) }
+                            }
+                            None => Interval { start: format_time(arrival), end: format_time(departure) },
+                        }),
+                        job_tag,
+                        time_window,
+                        crew_member: crew.map(|(member, _)| member),
+                    }),
+                }
 
                 Leg {
                     last_detail: Some((act.place.location, act.schedule.departure)),
@@ -245,6 +290,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                             waiting: leg.statistic.times.waiting + waiting as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                         },
+                        value: None,
                     },
                     load: Some(load),
                 }
@@ -267,6 +313,11 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
         });
 
     leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.value = route
+        .tour
+        .jobs()
+        .filter_map(|job| job.dimens().get_value::<f64>("value").cloned())
+        .fold(None, |acc, value| Some(acc.unwrap_or(0.) + value));
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
     tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
@@ -289,17 +340,50 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
+/// Creates activities of jobs clustered into one stop: each job is started after commute from
+/// the parking and followed by commute back to it.
+fn create_cluster_activities(cluster: &[ClusterInfo], start: Timestamp, coord_index: &CoordIndex) -> Vec<ApiActivity> {
+    cluster
+        .iter()
+        .scan(start, |time, info| {
+            let single = info.job.to_single();
+            let start = *time + info.commute.forward.duration;
+            let end = start + info.service_time;
+            *time = end + info.commute.backward.duration;
+
+            Some(ApiActivity {
+                job_id: single.dimens.get_id().cloned().expect("job id expected"),
+                activity_type: single.dimens.get_value::<String>("type").cloned().expect("activity type expected"),
+                location: single
+                    .places
+                    .first()
+                    .and_then(|place| place.location)
+                    .and_then(|l| coord_index.get_by_idx(l)),
+                time: Some(Interval { start: format_time(start), end: format_time(end) }),
+                job_tag: single.dimens.get_value::<String>("tag").cloned(),
+                time_window: None,
+                crew_member: None,
+            })
+        })
+        .collect()
+}
+
 fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_value::<String>("vehicle_id").is_none())
-        .map(|(job, code)| {
+        .flat_map(|(job, code)| {
             let (code, reason) = map_code_reason(*code);
-            UnassignedJob {
+            let jobs = job
+                .dimens()
+                .get_cluster_info()
+                .map_or_else(|| vec![job.clone()], |cluster| cluster.iter().map(|info| info.job.clone()).collect());
+
+            jobs.into_iter().map(move |job| UnassignedJob {
                 job_id: job.dimens().get_id().expect("job id expected").clone(),
                 reasons: vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string() }],
-            }
+            })
         })
         .collect::<Vec<_>>();
 
@@ -310,9 +394,8 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
-fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
-    // NOTE at the moment only break violation is mapped
-    let violations = solution
+fn create_violations(problem: &Problem, solution: &Solution) -> Option<Vec<Violation>> {
+    let break_violations = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_value::<String>("type").map_or(false, |t| t == "break"))
@@ -320,8 +403,38 @@ fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
             vehicle_id: job.dimens().get_value::<String>("vehicle_id").expect("vehicle id").clone(),
             shift_index: *job.dimens().get_value::<usize>("shift_index").expect("shift index"),
             reason: map_code_reason(*code).1.to_string(),
-        })
-        .collect::<Vec<_>>();
+        });
+
+    let constraint_violations = problem
+        .extras
+        .get("total_violations")
+        .and_then(|s| s.downcast_ref::<TotalViolations>())
+        .map_or_else(Vec::new, |total_violations| {
+            solution
+                .routes
+                .iter()
+                .flat_map(|route| {
+                    let mut route_ctx =
+                        RouteContext::new_with_state(Arc::new(route.deep_copy()), Arc::new(RouteState::default()));
+                    problem.constraint.accept_route_state(&mut route_ctx);
+
+                    let vehicle_id = route.actor.vehicle.dimens.get_id().expect("vehicle id").clone();
+                    let shift_index =
+                        *route.actor.vehicle.dimens.get_value::<usize>("shift_index").expect("shift index");
+
+                    total_violations.get_route_violations(&route_ctx).into_iter().map(move |(code, count)| {
+                        Violation::Constraint {
+                            vehicle_id: vehicle_id.clone(),
+                            shift_index,
+                            code: map_code_reason(code).0.to_string(),
+                            count,
+                        }
+                    })
+                })
+                .collect()
+        });
+
+    let violations = break_violations.chain(constraint_violations).collect::<Vec<_>>();
 
     if violations.is_empty() {
         None
@@ -364,41 +477,246 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     }
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&Metrics>) -> Option<Extras> {
-    if let Some(metrics) = metrics {
-        Some(Extras {
-            metrics: Some(ApiMetrics {
-                duration: metrics.duration,
-                generations: metrics.generations,
-                speed: metrics.speed,
-                evolution: metrics
-                    .evolution
-                    .iter()
-                    .map(|g| ApiGeneration {
-                        number: g.number,
-                        timestamp: g.timestamp,
-                        i_all_ratio: g.i_all_ratio,
-                        i_1000_ratio: g.i_1000_ratio,
-                        is_improvement: g.is_improvement,
-                        population: AppPopulation {
-                            individuals: g
-                                .population
-                                .individuals
-                                .iter()
-                                .map(|i| ApiIndividual {
-                                    tours: i.tours,
-                                    unassigned: i.unassigned,
-                                    cost: i.cost,
-                                    improvement: i.improvement,
-                                    fitness: i.fitness.clone(),
-                                })
-                                .collect(),
-                        },
-                    })
-                    .collect(),
-            }),
+fn create_extras(problem: &Problem, solution: &Solution, tours: &[Tour], metrics: Option<&Metrics>) -> Option<Extras> {
+    let overloads = create_overloads(problem, solution, tours);
+    let robustness = create_robustness(problem, solution, tours);
+    let tolls = create_tolls(problem, solution, tours);
+    let preferences = create_preferences(problem, solution, tours);
+    let warnings = create_warnings(problem);
+    let metadata = solution.extras.get("metadata").and_then(|metadata| metadata.downcast_ref::<Metadata>()).cloned();
+    let geometry = solution
+        .extras
+        .get("route_geometry")
+        .and_then(|is_requested| is_requested.downcast_ref::<bool>())
+        .filter(|is_requested| **is_requested)
+        .and_then(|_| create_route_geometry(tours));
+
+    if metrics.is_none()
+        && geometry.is_none()
+        && overloads.is_none()
+        && robustness.is_none()
+        && tolls.is_none()
+        && preferences.is_none()
+        && warnings.is_none()
+        && metadata.is_none()
+    {
+        return None;
+    }
+
+    Some(Extras {
+        metrics: metrics.map(|metrics| ApiMetrics {
+            duration: metrics.duration,
+            generations: metrics.generations,
+            speed: metrics.speed,
+            evolution: metrics
+                .evolution
+                .iter()
+                .map(|g| ApiGeneration {
+                    number: g.number,
+                    timestamp: g.timestamp,
+                    i_all_ratio: g.i_all_ratio,
+                    i_1000_ratio: g.i_1000_ratio,
+                    is_improvement: g.is_improvement,
+                    population: AppPopulation {
+                        individuals: g
+                            .population
+                            .individuals
+                            .iter()
+                            .map(|i| ApiIndividual {
+                                tours: i.tours,
+                                unassigned: i.unassigned,
+                                cost: i.cost,
+                                improvement: i.improvement,
+                                fitness: i.fitness.clone(),
+                            })
+                            .collect(),
+                    },
+                })
+                .collect(),
+        }),
+        geometry,
+        overloads,
+        robustness,
+        tolls,
+        preferences,
+        warnings,
+        metadata,
+    })
+}
+
+fn create_warnings(problem: &Problem) -> Option<Vec<String>> {
+    problem.extras.get("routing_approximation").and_then(|speeds| speeds.downcast_ref::<Vec<(String, f64)>>()).map(
+        |speeds| {
+            let speeds =
+                speeds.iter().map(|(profile, speed)| format!("'{}': {}m/s", profile, speed)).collect::<Vec<_>>();
+
+            vec![format!(
+                "routing matrix is not specified: distances are approximated using haversine formula and \
+                 durations using profile speeds ({})",
+                speeds.join(", ")
+            )]
+        },
+    )
+}
+
+fn create_tolls(problem: &Problem, solution: &Solution, tours: &[Tour]) -> Option<Vec<TourToll>> {
+    let tolls = solution
+        .routes
+        .iter()
+        .zip(tours.iter())
+        .filter_map(|(route, tour)| {
+            let amount = route
+                .tour
+                .legs()
+                .filter_map(|(activities, _)| match activities {
+                    [from, to] => Some(problem.transport.toll(
+                        route.actor.as_ref(),
+                        from.place.location,
+                        to.place.location,
+                        from.schedule.departure,
+                    )),
+                    _ => None,
+                })
+                .sum::<f64>();
+
+            if amount > 0. {
+                Some(TourToll { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index, amount })
+            } else {
+                None
+            }
         })
+        .collect::<Vec<_>>();
+
+    if tolls.is_empty() {
+        None
     } else {
+        Some(tolls)
+    }
+}
+
+fn create_robustness(problem: &Problem, solution: &Solution, tours: &[Tour]) -> Option<Vec<TourRobustness>> {
+    let lateness_risk = problem.extras.get("lateness_risk").and_then(|s| s.downcast_ref::<LatenessRisk>())?;
+
+    Some(
+        solution
+            .routes
+            .iter()
+            .zip(tours.iter())
+            .map(|(route, tour)| {
+                let min_slack = route
+                    .tour
+                    .all_activities()
+                    .skip(1)
+                    .filter(|activity| activity.place.time.end < std::f64::MAX)
+                    .map(|activity| (activity.place.time.end - activity.schedule.arrival).round() as i64)
+                    .min();
+
+                let on_time_probability = lateness_risk
+                    .get_on_time_probabilities(route)
+                    .into_iter()
+                    .fold(1_f64, |acc, probability| acc.min(probability));
+
+                TourRobustness {
+                    vehicle_id: tour.vehicle_id.clone(),
+                    shift_index: tour.shift_index,
+                    min_slack,
+                    on_time_probability,
+                }
+            })
+            .collect(),
+    )
+}
+
+fn create_preferences(problem: &Problem, solution: &Solution, tours: &[Tour]) -> Option<Vec<TourPreferences>> {
+    let preferred_times = problem.extras.get("preferred_times").and_then(|s| s.downcast_ref::<PreferredTimes>())?;
+
+    let preferences = solution
+        .routes
+        .iter()
+        .zip(tours.iter())
+        .filter_map(|(route, tour)| {
+            let (total, hits) = preferred_times.get_route_hits(route);
+
+            if total > 0 {
+                Some(TourPreferences {
+                    vehicle_id: tour.vehicle_id.clone(),
+                    shift_index: tour.shift_index,
+                    hits,
+                    total,
+                    hit_rate: hits as f64 / total as f64,
+                })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if preferences.is_empty() {
+        None
+    } else {
+        Some(preferences)
+    }
+}
+
+fn create_overloads(problem: &Problem, solution: &Solution, tours: &[Tour]) -> Option<Vec<TourOverload>> {
+    let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+
+    let overloads = solution
+        .routes
+        .iter()
+        .zip(tours.iter())
+        .filter(|(route, _)| route.actor.vehicle.dimens.get_capacity_overload().is_some())
+        .filter_map(|(route, tour)| {
+            let capacity = get_vehicle_capacity(&route.actor.vehicle.dimens, is_multi_dimen)?;
+            let overload = tour.stops.iter().fold(vec![0; capacity.len()], |acc, stop| {
+                acc.iter()
+                    .zip(capacity.iter())
+                    .enumerate()
+                    .map(|(idx, (overload, capacity))| {
+                        (*overload).max(stop.load.get(idx).cloned().unwrap_or(0) - *capacity)
+                    })
+                    .collect()
+            });
+
+            if overload.iter().any(|value| *value > 0) {
+                Some(TourOverload { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index, overload })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if overloads.is_empty() {
         None
+    } else {
+        Some(overloads)
     }
 }
+
+fn get_vehicle_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Vec<i32>> {
+    if is_multi_dimen {
+        dimens.get_capacity().map(|capacity: &MultiDimLoad| capacity.as_vec())
+    } else {
+        dimens.get_capacity().map(|capacity: &SingleDimLoad| vec![capacity.value])
+    }
+}
+
+fn create_route_geometry(tours: &[Tour]) -> Option<RouteGeometry> {
+    let routes = tours
+        .iter()
+        .map(|tour| {
+            tour.stops
+                .iter()
+                .map(|stop| match stop.location {
+                    Location::Coordinate { lat, lng } => Some(Point::new(lng, lat)),
+                    Location::Reference { .. } => None,
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(RouteGeometry {
+        overlap: get_route_overlap(routes.as_slice()),
+        crossings: count_route_crossings(routes.as_slice()),
+    })
+}
@@ -5,25 +5,26 @@ mod approx_transportation_test;
 use crate::format::Location;
 use vrp_core::utils::parallel_collect;
 
-/// Gets approximated durations and distances rounded to nearest integer.
-pub fn get_approx_transportation(locations: &[Location], speeds: &[f64]) -> Vec<(Vec<i64>, Vec<i64>)> {
-    assert!(!speeds.is_empty());
-    assert!(speeds.iter().all(|&speed| speed > 0.));
+/// Gets approximated durations and distances rounded to nearest integer for each pair of speed and
+/// distance multiplier. The multiplier is applied to haversine distance to account for road network detours.
+pub fn get_approx_transportation(locations: &[Location], params: &[(f64, f64)]) -> Vec<(Vec<i64>, Vec<i64>)> {
+    assert!(!params.is_empty());
+    assert!(params.iter().all(|&(speed, multiplier)| speed > 0. && multiplier > 0.));
 
-    let distances =
-        locations.iter().flat_map(|l1| locations.iter().map(move |l2| get_distance(l1, l2))).collect::<Vec<_>>();
+    let distances = locations
+        .iter()
+        .flat_map(|l1| locations.iter().map(move |l2| get_haversine_distance(l1, l2)))
+        .collect::<Vec<_>>();
 
-    let distances_rounded = distances.iter().map(|distance| distance.round() as i64).collect::<Vec<_>>();
+    parallel_collect(params, |&(speed, multiplier)| {
+        let distances = distances.iter().map(|distance| distance * multiplier);
 
-    parallel_collect(speeds, |speed| {
-        let durations = distances.iter().map(|distance| (distance / speed).round() as i64).collect::<Vec<_>>();
-
-        (durations, distances_rounded.clone())
+        distances.map(|distance| ((distance / speed).round() as i64, distance.round() as i64)).unzip()
     })
 }
 
 /// Gets distance between two points using haversine formula.
-fn get_distance(p1: &Location, p2: &Location) -> f64 {
+pub fn get_haversine_distance(p1: &Location, p2: &Location) -> f64 {
     let (p1_lat, p1_lng) = as_lat_lon(p1.clone());
     let (p2_lat, p2_lng) = as_lat_lon(p2.clone());
 
@@ -178,6 +178,180 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job has correct volume and density.
+fn check_e1108_invalid_volume(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().any(|task| {
+                task.volume.map_or(false, |volume| volume < 0)
+                    || task.density.map_or(false, |density| !density.is_finite() || density <= 0.)
+                    || (task.density.is_some() && task.demand.is_none())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has invalid volume".to_string(),
+            format!("fix negative volume or non-positive density in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job's preferred time windows are correct.
+fn check_e1109_preferred_time_window_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.preferred_times.as_ref())
+                .any(|tws| !check_raw_time_windows(tws, false))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid preferred time windows in jobs".to_string(),
+            format!(
+                "change job task place preferred time windows so that they don't intersect, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that loading policy is specified only for jobs with pickups and deliveries.
+fn check_e1110_loading_policy_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let has_pickups = matches!(job.pickups.as_ref(), Some(tasks) if !tasks.is_empty());
+            let has_deliveries = matches!(job.deliveries.as_ref(), Some(tasks) if !tasks.is_empty());
+
+            job.loading_policy.is_some() && !(has_pickups && has_deliveries)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid loading policy in jobs".to_string(),
+            format!("remove loading policy from jobs without pickups and deliveries, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that preferred skills have non-negative penalty.
+fn check_e1111_preferred_skills_penalty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.skills
+                .as_ref()
+                .and_then(|skills| skills.preferred.as_ref())
+                .iter()
+                .flat_map(|preferred| preferred.iter())
+                .any(|skill| skill.penalty < 0.)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "negative penalty of preferred skills in jobs".to_string(),
+            format!("specify non-negative penalty of preferred skills, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job value is non-negative.
+fn check_e1112_negative_value(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| matches!(job.value, Some(value) if value < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "negative value of jobs".to_string(),
+            format!("specify non-negative value of jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job compatibility group is not empty and not incompatible with itself.
+fn check_e1113_compatibility_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            matches!(job.compatibility.as_ref(),
+                Some(compatibility) if compatibility.group.is_empty() || compatibility.incompatible.contains(&compatibility.group))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid compatibility in jobs".to_string(),
+            format!(
+                "specify non-empty compatibility group which is not listed as incompatible, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that clustering uses known routing profile and non-negative thresholds.
+fn check_e1114_clustering_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let (profile, threshold) = match ctx.problem.plan.clustering.as_ref() {
+        Some(Clustering::Vicinity { profile, threshold, .. }) => (profile, threshold),
+        None => return Ok(()),
+    };
+
+    let has_profile = ctx.problem.fleet.profiles.iter().any(|p| p.name == *profile);
+    let has_valid_threshold = threshold.duration >= 0.
+        && threshold.distance >= 0.
+        && !matches!(threshold.max_jobs_per_cluster, Some(max_jobs) if max_jobs < 2);
+
+    if has_profile && has_valid_threshold {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid clustering settings".to_string(),
+            format!(
+                "specify known profile, non-negative thresholds and max jobs per cluster greater than one, \
+                 profile: '{}'",
+                profile
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -189,5 +363,12 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_volume(ctx),
+        check_e1109_preferred_time_window_correctness(ctx),
+        check_e1110_loading_policy_correctness(ctx),
+        check_e1111_preferred_skills_penalty(ctx),
+        check_e1112_negative_value(ctx),
+        check_e1113_compatibility_correctness(ctx),
+        check_e1114_clustering_correctness(ctx),
     ])
 }
@@ -4,6 +4,7 @@ mod objectives_test;
 
 use super::*;
 use crate::format::problem::Objective::*;
+use hashbrown::HashSet;
 
 /// Checks that objective is not empty when specified.
 fn check_e1600_empty_objective(objectives: &[&Objective]) -> Result<(), FormatError> {
@@ -27,11 +28,18 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
+                MaximizeValue => acc.entry("maximize-value"),
                 MinimizeUnassignedJobs { .. } => acc.entry("minimize-unassigned"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
                 BalanceDuration { .. } => acc.entry("balance-duration"),
+                MinimizeLatenessRisk { .. } => acc.entry("minimize-lateness-risk"),
+                MinimizeDriverChanges { .. } => acc.entry("minimize-driver-changes"),
+                CompactTours { .. } => acc.entry("compact-tours"),
+                MaximizePreferredTimes { .. } => acc.entry("maximize-preferred-times"),
+                ReserveSlack { .. } => acc.entry("reserve-slack"),
+                MinimizeViolations { .. } => acc.entry("minimize-violations"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -70,6 +78,118 @@ fn check_e1602_no_cost_value_objective(objectives: &[&Objective]) -> Result<(),
     }
 }
 
+/// Checks that lateness risk objective has a valid probability.
+fn check_e1603_invalid_lateness_risk_probability(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid = objectives.iter().any(|objective| match objective {
+        MinimizeLatenessRisk { probability } => !(*probability > 0. && *probability < 1.),
+        _ => false,
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1603".to_string(),
+            "invalid lateness risk probability".to_string(),
+            "specify probability of 'minimize-lateness-risk' objective in (0, 1) range".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that violations objective has valid relaxable constraints.
+fn check_e1604_invalid_constraint_relaxation(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid = objectives.iter().any(|objective| match objective {
+        MinimizeViolations { constraints } => {
+            let types = constraints.iter().map(|relaxation| &relaxation.constraint_type).collect::<HashSet<_>>();
+            let has_invalid_weight = constraints.iter().any(|relaxation| relaxation.weight.map_or(false, |w| w < 0.));
+
+            constraints.is_empty() || types.len() != constraints.len() || has_invalid_weight
+        }
+        _ => false,
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1604".to_string(),
+            "invalid constraint relaxation".to_string(),
+            "specify at least one unique constraint type with non-negative weight in 'minimize-violations' objective"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that compact tours objective is used only with geo coordinates.
+fn check_e1605_compact_tours_without_coordinates(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_compact_tours = objectives.iter().any(|objective| matches!(objective, CompactTours { .. }));
+    let (_, has_indices) = ctx.coord_index.get_used_types();
+
+    if has_compact_tours && has_indices {
+        Err(FormatError::new(
+            "E1605".to_string(),
+            "compact tours objective requires geo coordinates".to_string(),
+            "use geo coordinates for all locations or remove 'compact-tours' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that reserve slack objective has valid ratios.
+fn check_e1606_invalid_slack_reservation(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let is_invalid_ratio = |ratio: &Option<f64>| ratio.map_or(false, |ratio| !(0. ..=1.).contains(&ratio));
+    let has_invalid = objectives.iter().any(|objective| match objective {
+        ReserveSlack { capacity, time, weight } => {
+            is_invalid_ratio(capacity) || is_invalid_ratio(time) || weight.map_or(false, |weight| weight < 0.)
+        }
+        _ => false,
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1606".to_string(),
+            "invalid slack reservation".to_string(),
+            "specify capacity and time ratios in [0, 1] range and non-negative weight in 'reserve-slack' objective"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that objective weights match objectives and are non-negative.
+fn check_e1607_invalid_objective_weights(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid = |objectives: Option<&Vec<Objective>>, weights: Option<&Vec<f64>>| match weights {
+        Some(weights) => {
+            objectives.map_or(0, Vec::len) != weights.len()
+                || weights.iter().any(|weight| !weight.is_finite() || *weight < 0.)
+        }
+        None => false,
+    };
+
+    let has_invalid = match ctx.problem.objectives.as_ref() {
+        Some(Objectives { primary, secondary, weights: Some(weights) }) => {
+            is_invalid(Some(primary), weights.primary.as_ref())
+                || is_invalid(secondary.as_ref(), weights.secondary.as_ref())
+        }
+        _ => false,
+    };
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1607".to_string(),
+            "invalid objective weights".to_string(),
+            "specify non-negative weight for each objective in the same order as objectives".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| {
         Some(&objectives.primary)
@@ -86,6 +206,11 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1600_empty_objective(&objectives),
             check_e1601_duplicate_objectives(&objectives),
             check_e1602_no_cost_value_objective(&objectives),
+            check_e1603_invalid_lateness_risk_probability(&objectives),
+            check_e1604_invalid_constraint_relaxation(&objectives),
+            check_e1605_compact_tours_without_coordinates(ctx, &objectives),
+            check_e1606_invalid_slack_reservation(&objectives),
+            check_e1607_invalid_objective_weights(ctx),
         ])
     } else {
         Ok(())
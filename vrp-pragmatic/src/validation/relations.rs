@@ -181,6 +181,7 @@ fn check_e1206_relation_has_no_missing_shift_properties(
                 "break" => vehicle_shift.breaks.is_none(),
                 "dispatch" => vehicle_shift.dispatch.is_none(),
                 "reload" => vehicle_shift.reloads.is_none(),
+                "unavailability" => vehicle_shift.unavailability.is_none(),
                 "arrival" => vehicle_shift.end.is_none(),
                 _ => false,
             })
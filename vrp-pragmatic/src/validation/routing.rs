@@ -3,7 +3,9 @@
 mod routing_test;
 
 use super::*;
+use crate::parse_time_safe;
 use hashbrown::HashSet;
+use vrp_core::models::problem::{get_time_bucket_count, MAX_TIME_BUCKETS};
 
 /// Checks that no duplicated profile names specified.
 fn check_e1500_duplicated_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -146,6 +148,164 @@ fn check_e1506_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that travel time deviations have the same size as travel times and are non-negative.
+fn check_e1507_invalid_travel_time_deviations(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter(|matrix| {
+            matrix.travel_time_deviations.as_ref().map_or(false, |deviations| {
+                deviations.len() != matrix.travel_times.len() || deviations.iter().any(|deviation| *deviation < 0)
+            })
+        })
+        .map(|matrix| matrix.profile.clone().unwrap_or_else(|| "<unknown>".to_string()))
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid travel time deviations".to_string(),
+            format!(
+                "ensure that travel time deviations are non-negative and have the same size as travel times, \
+                 matrix profiles: '{}'",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that tolls have the same size as travel times and are non-negative.
+fn check_e1508_invalid_tolls(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles =
+        ctx.matrices
+            .iter()
+            .flat_map(|matrices| matrices.iter())
+            .filter(|matrix| {
+                matrix.tolls.as_ref().map_or(false, |tolls| {
+                    tolls.len() != matrix.travel_times.len() || tolls.iter().any(|toll| *toll < 0.)
+                })
+            })
+            .map(|matrix| matrix.profile.clone().unwrap_or_else(|| "<unknown>".to_string()))
+            .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "invalid tolls".to_string(),
+            format!(
+                "ensure that tolls are non-negative and have the same size as travel times, matrix profiles: '{}'",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that zone fees are non-negative, have valid shapes and are not used with location indices.
+fn check_e1509_invalid_zone_fees(ctx: &ValidationContext, location_types: (bool, bool)) -> Result<(), FormatError> {
+    let (_, has_indices) = location_types;
+
+    let type_ids = ctx
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter(|vehicle| {
+            vehicle.costs.zone_fees.as_ref().map_or(false, |zone_fees| {
+                has_indices || zone_fees.iter().any(|zone| zone.fee < 0. || zone.outer_shape.len() < 3)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1509".to_string(),
+            "invalid zone fees".to_string(),
+            format!(
+                "ensure that zone fees are non-negative, have at least three points in outer shape and \
+                 coordinates are used everywhere, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that profile approximation parameters are positive.
+fn check_e1510_invalid_approximation_params(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid = |value: Option<f64>| value.map_or(false, |value| value <= 0.);
+
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            is_invalid(profile.speed) || is_invalid(profile.distance_multiplier) || is_invalid(profile.time_bucket)
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1510".to_string(),
+            "invalid profile approximation parameters".to_string(),
+            format!(
+                "ensure that speed, distance multiplier and time bucket are positive, profile names: '{}'",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that time bucket size does not produce too many time buckets.
+fn check_e1511_too_many_time_buckets(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.time_bucket.filter(|size| *size > 0.).map(|size| (profile, size)))
+        .filter(|(profile, size)| {
+            let timestamps = ctx
+                .matrices
+                .iter()
+                .flat_map(|matrices| matrices.iter())
+                .filter(|matrix| matrix.profile.as_ref() == Some(&profile.name))
+                .filter_map(|matrix| matrix.timestamp.as_ref().and_then(|time| parse_time_safe(time).ok()))
+                .collect::<Vec<_>>();
+
+            let start = timestamps.iter().cloned().fold(f64::MAX, f64::min);
+            let end = timestamps.iter().cloned().fold(f64::MIN, f64::max);
+
+            !timestamps.is_empty() && get_time_bucket_count(start, end, *size) > MAX_TIME_BUCKETS
+        })
+        .map(|(profile, _)| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1511".to_string(),
+            "too many time buckets".to_string(),
+            format!(
+                "increase time bucket size so that matrix timestamps span at most {} buckets, profile names: '{}'",
+                MAX_TIME_BUCKETS,
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let location_types = ctx.coord_index.get_used_types();
@@ -158,5 +318,10 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>>
         check_e1504_limit_areas_cannot_be_used_with_indices(ctx, location_types),
         check_e1505_index_size_mismatch(ctx),
         check_e1506_profiles_exist(ctx),
+        check_e1507_invalid_travel_time_deviations(ctx),
+        check_e1508_invalid_tolls(ctx),
+        check_e1509_invalid_zone_fees(ctx, location_types),
+        check_e1510_invalid_approximation_params(ctx),
+        check_e1511_too_many_time_buckets(ctx),
     ])
 }
@@ -207,6 +207,237 @@ fn check_e1306_vehicle_dispatch_is_correct(ctx: &ValidationContext) -> Result<()
     }
 }
 
+/// Checks that vehicle volume is not negative.
+fn check_e1307_vehicle_has_no_negative_volume(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.volume.map_or(false, |volume| volume < 0))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "vehicle has negative volume".to_string(),
+            format!("fix negative volume in vehicle types with ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that driver start time is correct and is not after vehicle shift start latest or shift end.
+fn check_e1308_vehicle_driver_start_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, shift_time| {
+            shift.start.driver.as_ref().map_or(true, |driver| {
+                get_time_window(&driver.earliest, &driver.earliest).map_or(false, |driver_time| {
+                    let start_latest = shift.start.latest.as_ref().and_then(|latest| get_time_window(latest, latest));
+
+                    shift_time.map_or(true, |shift_time| driver_time.start <= shift_time.end)
+                        && !matches!(start_latest, Some(start_latest) if driver_time.start > start_latest.end)
+                })
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1308".to_string(),
+            "invalid driver start in vehicle shift".to_string(),
+            format!(
+                "ensure that driver start time is valid and is not after shift start latest and shift end, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle trailer has capacity of the same dimension as vehicle and at least one parking.
+fn check_e1309_vehicle_trailer_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.trailer.as_ref().map_or(false, |trailer| {
+                trailer.capacity.len() != vehicle.capacity.len()
+                    || trailer.capacity.iter().any(|capacity| *capacity < 0)
+                    || trailer.parkings.is_empty()
+                    || trailer.parkings.iter().any(|parking| parking.duration < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle trailer".to_string(),
+            format!(
+                "ensure that trailer capacity has the same dimension as vehicle capacity, is not negative \
+                 and at least one parking place is specified, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle unavailability periods are correct and fit shift time.
+fn check_e1310_vehicle_unavailability_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, shift_time| {
+            shift
+                .unavailability
+                .as_ref()
+                .map(|periods| {
+                    let tws = periods.iter().map(|period| get_time_window_from_vec(&period.time)).collect::<Vec<_>>();
+
+                    check_time_windows(&tws, false)
+                        && shift_time.as_ref().map_or(true, |shift_time| {
+                            tws.into_iter()
+                                .map(|tw| tw.unwrap())
+                                .all(|tw| tw.start >= shift_time.start && tw.end <= shift_time.end)
+                        })
+                })
+                .unwrap_or(true)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid unavailability period in vehicle shift".to_string(),
+            format!(
+                "ensure that unavailability periods do not overlap and are within shift time, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle max radius limit is correct.
+fn check_e1311_vehicle_max_radius_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let (_, has_indices) = ctx.coord_index.get_used_types();
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle
+                .limits
+                .as_ref()
+                .and_then(|limits| limits.max_radius.as_ref())
+                .map_or(false, |radius| radius.distance < 0. || (radius.airline.unwrap_or(false) && has_indices))
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle max radius limit".to_string(),
+            format!(
+                "ensure that max radius is not negative and airline distance is used only with geo coordinates, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle team is correct.
+fn check_e1312_vehicle_team_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.team.as_ref().map_or(false, |team| team.drivers == 0 || team.max_driving_time < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle team".to_string(),
+            format!(
+                "ensure that team has at least one driver and max driving time is not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle driving time breaks are correct.
+fn check_e1313_vehicle_driving_breaks_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift
+                .breaks
+                .as_ref()
+                .map(|breaks| {
+                    breaks.iter().filter(|b| b.policy == Some(VehicleBreakPolicy::DrivingTime)).all(|b| match &b.time {
+                        VehicleBreakTime::TimeOffset(offset) if offset.len() == 2 => {
+                            let (start, end) = (offset[0], offset[1]);
+                            start >= 0. && start < end
+                        }
+                        _ => false,
+                    })
+                })
+                .unwrap_or(true)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid driving time break".to_string(),
+            format!(
+                "ensure that driving time break has time offset with start less than end and both are not negative, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle shift overtime is correct.
+fn check_e1314_vehicle_overtime_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| match vehicle.limits.as_ref() {
+            Some(VehicleLimits { shift_time, overtime: Some(overtime), .. }) => {
+                shift_time.is_none() || overtime.max_time < 0. || overtime.penalty < 0.
+            }
+            _ => false,
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle overtime".to_string(),
+            format!(
+                "ensure that overtime is used with shift time limit and its max time and penalty are not negative, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>,
@@ -254,5 +485,13 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
         check_e1306_vehicle_dispatch_is_correct(ctx),
+        check_e1307_vehicle_has_no_negative_volume(ctx),
+        check_e1308_vehicle_driver_start_is_correct(ctx),
+        check_e1309_vehicle_trailer_is_correct(ctx),
+        check_e1310_vehicle_unavailability_is_correct(ctx),
+        check_e1311_vehicle_max_radius_is_correct(ctx),
+        check_e1312_vehicle_team_is_correct(ctx),
+        check_e1313_vehicle_driving_breaks_are_correct(ctx),
+        check_e1314_vehicle_overtime_is_correct(ctx),
     ])
 }
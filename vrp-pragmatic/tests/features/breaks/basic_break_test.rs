@@ -2,6 +2,7 @@ use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format_time;
 use crate::helpers::*;
+use crate::parse_time;
 
 #[test]
 fn can_assign_break_between_jobs() {
@@ -9,6 +10,7 @@ fn can_assign_break_between_jobs() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -18,6 +20,7 @@ fn can_assign_break_between_jobs() {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![6., 0.].to_loc()]),
+                        policy: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -39,6 +42,7 @@ fn can_assign_break_between_jobs() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,9 +95,58 @@ fn can_assign_break_between_jobs() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
         }
     );
 }
+
+#[test]
+fn can_skip_break_which_violates_time_window_of_next_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![5., 0.]),
+                create_delivery_job_with_times("job2", vec![10., 0.], vec![(0, 11)], 1.),
+            ],
+            relations: Option::None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak {
+                        time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
+                        duration: 2.0,
+                        locations: None,
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![Objective::MinimizeUnassignedJobs { breaks: Some(0.1) }],
+            secondary: Some(vec![Objective::MinimizeCost]),
+            weights: None,
+        }),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.violations.map_or(0, |violations| violations.len()), 1);
+    let tour = solution.tours.first().expect("no tour");
+    let job2_stop = tour
+        .stops
+        .iter()
+        .find(|stop| stop.activities.iter().any(|activity| activity.job_id == "job2"))
+        .expect("no job2 stop");
+    assert!(parse_time(&job2_stop.time.arrival) <= 11.);
+    assert!(tour.stops.iter().flat_map(|stop| stop.activities.iter()).all(|activity| activity.job_id != "break"));
+}
@@ -9,6 +9,7 @@ fn can_assign_break_using_second_location() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -23,6 +24,7 @@ fn can_assign_break_using_second_location() {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![1., 0.].to_loc(), vec![11., 0.].to_loc()]),
+                        policy: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -44,6 +46,7 @@ fn can_assign_break_using_second_location() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,6 +99,7 @@ fn can_assign_break_using_second_location() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
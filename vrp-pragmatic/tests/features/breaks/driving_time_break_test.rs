@@ -0,0 +1,88 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem_with_driving_break(jobs: Vec<Job>, offset: Vec<f64>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: Option::None, clustering: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak {
+                        time: VehicleBreakTime::TimeOffset(offset),
+                        duration: 2.,
+                        locations: None,
+                        policy: Some(VehicleBreakPolicy::DrivingTime),
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_driving_break_to_split_continuous_driving() {
+    let problem = create_problem_with_driving_break(
+        vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
+        vec![15., 25.],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let break_stops = solution.tours[0]
+        .stops
+        .iter()
+        .filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "break"))
+        .collect::<Vec<_>>();
+    assert_eq!(break_stops.len(), 1);
+    assert_eq!(break_stops[0].location, vec![20., 0.].to_loc());
+    assert_eq!(solution.statistic.times.break_time, 2);
+}
+
+#[test]
+fn can_skip_driving_break_when_driving_time_is_below_threshold() {
+    let problem = create_problem_with_driving_break(vec![create_delivery_job("job1", vec![5., 0.])], vec![15., 25.]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+}
+
+#[test]
+fn can_reject_job_which_requires_longer_continuous_driving() {
+    let problem = create_problem_with_driving_break(vec![create_delivery_job("job1", vec![30., 0.])], vec![15., 25.]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 0.,
+                distance: 0,
+                duration: 0,
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
+            },
+            tours: vec![],
+            unassigned: Some(vec![UnassignedJob {
+                job_id: "job1".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: "DRIVING_REST_CONSTRAINT".to_string(),
+                    description: "cannot be assigned due to continuous driving time limit".to_string()
+                }]
+            }]),
+            ..create_empty_solution()
+        }
+    );
+}
@@ -9,6 +9,7 @@ fn can_assign_interval_break_between_jobs() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![15., 0.])],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -17,6 +18,7 @@ fn can_assign_interval_break_between_jobs() {
                         time: VehicleBreakTime::TimeOffset(vec![5., 10.]),
                         duration: 2.0,
                         locations: None,
+                        policy: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -38,6 +40,7 @@ fn can_assign_interval_break_between_jobs() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,6 +73,8 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -80,6 +85,8 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             }
                         ],
                     },
@@ -105,6 +112,7 @@ fn can_assign_interval_break_between_jobs() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -123,6 +131,7 @@ fn can_assign_interval_break_with_reload() {
                 create_delivery_job("job4", vec![25., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -131,6 +140,7 @@ fn can_assign_interval_break_with_reload() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: vec![0., 0.].to_loc(),
+                        driver: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
@@ -142,7 +152,9 @@ fn can_assign_interval_break_with_reload() {
                         time: VehicleBreakTime::TimeOffset(vec![8., 12.]),
                         duration: 2.0,
                         locations: None,
+                        policy: None,
                     }]),
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: Some(vec![vec![format_time(0.), format_time(1000.)]]),
                         location: vec![0., 0.].to_loc(),
@@ -169,6 +181,7 @@ fn can_assign_interval_break_with_reload() {
                 distance: 60,
                 duration: 69,
                 times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -201,6 +214,8 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -211,6 +226,8 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             }
                         ],
                     },
@@ -260,6 +277,7 @@ fn can_assign_interval_break_with_reload() {
                     distance: 60,
                     duration: 69,
                     times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -276,6 +294,7 @@ fn can_consider_departure_rescheduling() {
                 create_delivery_job_with_times("job2", vec![10., 0.], vec![(10, 30)], 1.),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -284,6 +303,7 @@ fn can_consider_departure_rescheduling() {
                         time: VehicleBreakTime::TimeOffset(vec![10., 12.]),
                         duration: 2.0,
                         locations: None,
+                        policy: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -9,6 +9,7 @@ fn can_use_two_breaks() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![99., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -17,17 +18,20 @@ fn can_use_two_breaks() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: vec![0., 0.].to_loc(),
+                        driver: None,
                     },
                     breaks: Some(vec![
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
                             duration: 2.0,
                             locations: Some(vec![vec![6., 0.].to_loc()]),
+                            policy: None,
                         },
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
                             duration: 2.0,
                             locations: None,
+                            policy: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -50,6 +54,7 @@ fn can_use_two_breaks() {
                 distance: 198,
                 duration: 204,
                 times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -98,6 +103,8 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -108,6 +115,8 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             }
                         ],
                     },
@@ -125,6 +134,7 @@ fn can_use_two_breaks() {
                     distance: 198,
                     duration: 204,
                     times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
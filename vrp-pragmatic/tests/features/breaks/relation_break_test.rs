@@ -32,11 +32,17 @@ fn get_solution(
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    breaks: Some(vec![VehicleBreak { time: break_time, duration: 2.0, locations: break_locations }]),
+                    breaks: Some(vec![VehicleBreak {
+                        time: break_time,
+                        duration: 2.0,
+                        locations: break_locations,
+                        policy: None,
+                    }]),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -75,6 +81,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -127,6 +134,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -155,6 +163,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -207,6 +216,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
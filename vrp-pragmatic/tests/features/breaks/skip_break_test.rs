@@ -9,6 +9,7 @@ fn can_skip_break_when_vehicle_not_used() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![
@@ -18,6 +19,7 @@ fn can_skip_break_when_vehicle_not_used() {
                             earliest: format_time(0.),
                             latest: None,
                             location: vec![100., 0.].to_loc(),
+                            driver: None,
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
@@ -29,7 +31,9 @@ fn can_skip_break_when_vehicle_not_used() {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
                             duration: 2.0,
                             locations: Some(vec![vec![6., 0.].to_loc()]),
+                            policy: None,
                         }]),
+                        unavailability: None,
                         reloads: None,
                     }],
                     ..create_default_vehicle_type()
@@ -52,6 +56,7 @@ fn can_skip_break_when_vehicle_not_used() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -96,6 +101,7 @@ fn can_skip_break_when_vehicle_not_used() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -109,6 +115,7 @@ fn can_skip_break_when_jobs_completed() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], 10.)],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -117,6 +124,7 @@ fn can_skip_break_when_jobs_completed() {
                         time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
                         duration: 2.0,
                         locations: Some(vec![vec![6., 0.].to_loc()]),
+                        policy: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -138,6 +146,7 @@ fn can_skip_break_when_jobs_completed() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -174,6 +183,7 @@ fn can_skip_break_when_jobs_completed() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             violations: Some(vec![Violation::Break {
@@ -192,6 +202,7 @@ fn can_skip_second_break_when_jobs_completed() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -201,11 +212,13 @@ fn can_skip_second_break_when_jobs_completed() {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
                             duration: 2.0,
                             locations: Some(vec![vec![6., 0.].to_loc()]),
+                            policy: None,
                         },
                         VehicleBreak {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
                             duration: 2.0,
                             locations: None,
+                            policy: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -228,6 +241,7 @@ fn can_skip_second_break_when_jobs_completed() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -280,6 +294,7 @@ fn can_skip_second_break_when_jobs_completed() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
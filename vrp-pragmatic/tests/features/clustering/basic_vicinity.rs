@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_cluster_jobs_in_vicinity, (visiting, max_jobs, expected), {
+    can_cluster_jobs_in_vicinity_impl(visiting, max_jobs, expected);
+}}
+
+can_cluster_jobs_in_vicinity! {
+    case01_serve_in_place: (VicinityVisitPolicy::ServeInPlace, None, vec![vec!["job2", "job1", "job3"], vec!["job4"]]),
+    case02_walk_from_parking: (VicinityVisitPolicy::WalkFromParking, None, vec![vec!["job2", "job1", "job3"], vec!["job4"]]),
+    case03_max_jobs: (VicinityVisitPolicy::ServeInPlace, Some(2), vec![vec!["job2", "job1"], vec!["job3"], vec!["job4"]]),
+}
+
+fn can_cluster_jobs_in_vicinity_impl(visiting: VicinityVisitPolicy, max_jobs: Option<usize>, expected: Vec<Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![10., 0.]),
+                create_delivery_job("job2", vec![11., 0.]),
+                create_delivery_job("job3", vec![12., 0.]),
+                create_delivery_job("job4", vec![30., 0.]),
+            ],
+            relations: None,
+            clustering: Some(Clustering::Vicinity {
+                profile: "car".to_string(),
+                threshold: VicinityThresholdPolicy { duration: 1., distance: 1., max_jobs_per_cluster: max_jobs },
+                visiting,
+            }),
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let mut stops = get_ids_from_tour(solution.tours.first().unwrap())
+        .into_iter()
+        .filter(|ids| ids.iter().all(|id| id != "departure" && id != "arrival"))
+        .collect::<Vec<_>>();
+    stops.sort();
+    assert_eq!(stops, expected);
+}
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_compatibility(job: Job, group: &str, incompatible: &str, policy: JobCompatibilityPolicy) -> Job {
+    Job {
+        compatibility: Some(JobCompatibility {
+            group: group.to_string(),
+            incompatible: vec![incompatible.to_string()],
+            policy: Some(policy),
+        }),
+        ..job
+    }
+}
+
+parameterized_test! {can_use_compatibility_policy, (policy, expected), {
+    can_use_compatibility_policy_impl(policy, expected);
+}}
+
+can_use_compatibility_policy! {
+    case01_never_same_tour: (JobCompatibilityPolicy::NeverSameTour, vec![vec!["chemicals"], vec!["food"]]),
+    case02_never_simultaneously_loaded: (JobCompatibilityPolicy::NeverSimultaneouslyLoaded, vec![vec!["food", "chemicals"]]),
+}
+
+fn can_use_compatibility_policy_impl(policy: JobCompatibilityPolicy, expected: Vec<Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_compatibility(
+                    create_delivery_job("food", vec![1., 0.]),
+                    "food",
+                    "chemicals",
+                    policy.clone(),
+                ),
+                create_job_with_compatibility(
+                    create_pickup_job("chemicals", vec![2., 0.]),
+                    "chemicals",
+                    "food",
+                    policy,
+                ),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            get_ids_from_tour(tour)
+                .into_iter()
+                .flatten()
+                .filter(|id| id != "departure" && id != "arrival")
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    tours.sort();
+    assert_eq!(tours, expected);
+}
@@ -8,6 +8,7 @@ fn create_problem_with_dispatch(dispatch: Option<Vec<VehicleDispatch>>) -> Probl
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![3., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -39,6 +40,7 @@ fn can_assign_single_dispatch() {
                 distance: 14,
                 duration: 18,
                 times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,6 +93,7 @@ fn can_assign_single_dispatch() {
                     distance: 14,
                     duration: 18,
                     times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -192,6 +195,7 @@ fn create_problem_with_dispatch_5jobs(vehicle_ids: Vec<&str>, dispatch: Option<V
                 create_delivery_job("job5", vec![2., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
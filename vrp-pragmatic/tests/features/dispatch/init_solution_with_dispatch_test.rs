@@ -16,6 +16,7 @@ fn can_use_init_solution_with_dispatch() {
                 create_delivery_job_with_times("job2", vec![1., 0.], vec![(7, 100)], 1.),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -44,6 +45,7 @@ fn can_use_init_solution_with_dispatch() {
             distance: 4,
             duration: 10,
             times: Timing { driving: 4, serving: 6, waiting: 0, break_time: 0 },
+            value: None,
         },
         tours: vec![
             Tour {
@@ -69,6 +71,8 @@ fn can_use_init_solution_with_dispatch() {
                                     end: "1970-01-01T00:00:02Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -79,6 +83,8 @@ fn can_use_init_solution_with_dispatch() {
                                     end: "1970-01-01T00:00:04Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                         ],
                     },
@@ -104,6 +110,7 @@ fn can_use_init_solution_with_dispatch() {
                     distance: 2,
                     duration: 5,
                     times: Timing { driving: 2, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             },
             Tour {
@@ -129,6 +136,8 @@ fn can_use_init_solution_with_dispatch() {
                                     end: "1970-01-01T00:00:04Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -139,6 +148,8 @@ fn can_use_init_solution_with_dispatch() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
+                                time_window: None,
+                                crew_member: None,
                             },
                         ],
                     },
@@ -164,6 +175,7 @@ fn can_use_init_solution_with_dispatch() {
                     distance: 2,
                     duration: 5,
                     times: Timing { driving: 2, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             },
         ],
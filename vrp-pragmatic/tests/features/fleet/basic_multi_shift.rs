@@ -12,12 +12,18 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 create_delivery_job_with_times("job2", vec![10., 0.], vec![(100, 200)], 1.),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: vec![0., 0.].to_loc(),
+                            driver: None,
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(99.).to_string(),
@@ -30,6 +36,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             earliest: format_time(100.),
                             latest: None,
                             location: vec![0., 0.].to_loc(),
+                            driver: None,
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
@@ -58,6 +65,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 distance: 40,
                 duration: 42,
                 times: Timing { driving: 40, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -95,6 +103,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -132,6 +141,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
             ],
@@ -5,7 +5,7 @@ use crate::helpers::*;
 #[test]
 fn can_use_vehicle_with_open_end() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None, clustering: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
@@ -27,6 +27,7 @@ fn can_use_vehicle_with_open_end() {
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -55,6 +56,7 @@ fn can_use_vehicle_with_open_end() {
                     distance: 1,
                     duration: 2,
                     times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,55 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_build_compact_tours() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("north1", vec![1., 1.]),
+                create_delivery_job("south1", vec![1., -1.]),
+                create_delivery_job("north2", vec![2., 1.]),
+                create_delivery_job("south2", vec![2., -1.]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs { breaks: None }, CompactTours { weight: None }],
+            secondary: Some(vec![MinimizeCost]),
+            weights: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities.iter())
+                .filter(|activity| activity.activity_type == "delivery")
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+            job_ids.sort();
+            job_ids
+        })
+        .collect::<Vec<_>>();
+    tours.sort();
+    assert_eq!(tours, vec![vec!["north1", "north2"], vec!["south1", "south2"]]);
+}
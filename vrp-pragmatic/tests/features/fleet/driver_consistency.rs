@@ -0,0 +1,52 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_keep_previous_driver_assignments() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: Option::None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![
+                MinimizeUnassignedJobs { breaks: None },
+                MinimizeDriverChanges {
+                    previous: vec![
+                        PreviousAssignment { vehicle_id: "my_vehicle_1".to_string(), jobs: vec!["job1".to_string()] },
+                        PreviousAssignment { vehicle_id: "my_vehicle_2".to_string(), jobs: vec!["job2".to_string()] },
+                    ],
+                    weight: None,
+                },
+            ],
+            secondary: Some(vec![MinimizeCost]),
+            weights: None,
+        }),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut assignments = get_previous_assignments(&solution)
+        .into_iter()
+        .map(|assignment| (assignment.vehicle_id, assignment.jobs))
+        .collect::<Vec<_>>();
+    assignments.sort();
+    assert_eq!(
+        assignments,
+        vec![
+            ("my_vehicle_1".to_string(), vec!["job1".to_string()]),
+            ("my_vehicle_2".to_string(), vec!["job2".to_string()])
+        ]
+    );
+}
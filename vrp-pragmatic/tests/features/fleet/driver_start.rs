@@ -0,0 +1,32 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_start_tour_when_driver_reaches_vehicle() {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None, clustering: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        driver: Some(DriverStart { earliest: format_time(0.), location: vec![10., 0.].to_loc() }),
+                        ..shift.start
+                    },
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops[0].time.departure, format_time(10.));
+}
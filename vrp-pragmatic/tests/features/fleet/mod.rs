@@ -1,4 +1,13 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod compact_tours;
+mod driver_consistency;
+mod driver_start;
 mod multi_dimens;
+mod parallel_service;
+mod slack_reservation;
+mod team_driving;
+mod tolls;
+mod truck_trailer;
 mod unreachable_jobs;
+mod weight_volume;
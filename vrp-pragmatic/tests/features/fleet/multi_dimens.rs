@@ -11,6 +11,7 @@ fn can_use_two_dimensions() {
                 create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 0]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -34,6 +35,7 @@ fn can_use_two_dimensions() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,6 +72,7 @@ fn can_use_two_dimensions() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -80,7 +83,11 @@ fn can_use_two_dimensions() {
 #[test]
 fn can_unassign_due_to_dimension_mismatch() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, 1])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, 1])],
+            relations: None,
+            clustering: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
@@ -103,6 +110,7 @@ fn can_unassign_due_to_dimension_mismatch() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
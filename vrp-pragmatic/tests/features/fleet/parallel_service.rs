@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_serve_jobs_at_the_same_stop_in_parallel, (crew_size, expected_serving, expected_members), {
+    can_serve_jobs_at_the_same_stop_in_parallel_impl(crew_size, expected_serving, expected_members);
+}}
+
+can_serve_jobs_at_the_same_stop_in_parallel! {
+    case01_no_crew: (None, 30, vec![None, None, None]),
+    case02_single_member_crew: (Some(1), 30, vec![None, None, None]),
+    case03_two_members_crew: (Some(2), 20, vec![Some(0), Some(1), Some(0)]),
+    case04_three_members_crew: (Some(3), 10, vec![Some(0), Some(1), Some(2)]),
+}
+
+fn can_serve_jobs_at_the_same_stop_in_parallel_impl(
+    crew_size: Option<usize>,
+    expected_serving: i64,
+    expected_members: Vec<Option<usize>>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", vec![1., 0.], 10.),
+                create_delivery_job_with_duration("job2", vec![1., 0.], 10.),
+                create_delivery_job_with_duration("job3", vec![1., 0.], 10.),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { crew_size, ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops.len(), 3);
+    assert_eq!(tour.stops[1].activities.len(), 3);
+    assert_eq!(tour.statistic.times.serving, expected_serving);
+    assert_eq!(solution.statistic.times.serving, expected_serving);
+    assert_eq!(
+        tour.stops[1].activities.iter().map(|activity| activity.crew_member).collect::<Vec<_>>(),
+        expected_members
+    );
+}
@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+parameterized_test! {can_limit_driving_time_per_driver, (drivers, expected_unassigned), {
+    can_limit_driving_time_per_driver_impl(drivers, expected_unassigned);
+}}
+
+can_limit_driving_time_per_driver! {
+    case01_single_driver: (1, Some(vec!["job2"])),
+    case02_two_drivers: (2, None),
+}
+
+fn can_limit_driving_time_per_driver_impl(drivers: usize, expected_unassigned: Option<Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                team: Some(VehicleTeam { drivers, max_driving_time: 30. }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        expected_unassigned.map(|job_ids| job_ids
+            .into_iter()
+            .map(|job_id| UnassignedJob {
+                job_id: job_id.to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: "TEAM_DRIVING_CONSTRAINT".to_string(),
+                    description: "cannot be assigned due to driving time limit of vehicle team".to_string()
+                }]
+            })
+            .collect())
+    );
+}
+
+#[test]
+fn can_keep_driver_for_jobs_at_same_location() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![20., 0.]), create_delivery_job("job2", vec![20., 0.])],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                team: Some(VehicleTeam { drivers: 2, max_driving_time: 20. }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+}
@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![5., 0.])], relations: None, clustering: None },
+        fleet: Fleet { vehicles, profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn create_zone_fee(fee: f64) -> ZoneFee {
+    ZoneFee {
+        fee,
+        outer_shape: vec![(4., -1.), (6., -1.), (6., 1.), (4., 1.)]
+            .into_iter()
+            .map(|(lat, lng)| Location::Coordinate { lat, lng })
+            .collect(),
+    }
+}
+
+#[test]
+fn can_add_matrix_tolls_to_tour_cost() {
+    let problem = create_test_problem(vec![create_default_vehicle("my_vehicle")]);
+    let matrix = create_matrix_from_problem(&problem);
+    let tolled_matrix = Matrix { tolls: Some(vec![10.; matrix.travel_times.len()]), ..matrix.clone() };
+
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    let tolled_solution = solve_with_metaheuristic(problem, Some(vec![tolled_matrix]));
+
+    assert!(solution.extras.is_none());
+    assert_eq!((tolled_solution.statistic.cost - solution.statistic.cost).round(), 20.);
+    assert_eq!(
+        tolled_solution.extras.and_then(|extras| extras.tolls),
+        Some(vec![TourToll { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0, amount: 20. }])
+    );
+}
+
+#[test]
+fn can_avoid_vehicle_with_zone_fee() {
+    let problem = create_test_problem(vec![
+        VehicleType {
+            costs: VehicleCosts { zone_fees: Some(vec![create_zone_fee(100.)]), ..create_default_vehicle_costs() },
+            ..create_default_vehicle("cheap")
+        },
+        VehicleType {
+            costs: VehicleCosts { fixed: Some(20.), ..create_default_vehicle_costs() },
+            ..create_default_vehicle("expensive")
+        },
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "expensive_1");
+    assert!(solution.extras.is_none());
+}
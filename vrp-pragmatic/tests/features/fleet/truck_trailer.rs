@@ -0,0 +1,107 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_truck_only_job(id: &str, location: Vec<f64>) -> Job {
+    Job { truck_only: Some(true), ..create_delivery_job(id, location) }
+}
+
+fn create_vehicle_with_trailer(capacity: Vec<i32>, trailer_capacity: Vec<i32>) -> VehicleType {
+    VehicleType {
+        capacity,
+        trailer: Some(VehicleTrailer {
+            capacity: trailer_capacity,
+            parkings: vec![TrailerParking { location: vec![5., 0.].to_loc(), duration: 10., tag: None }],
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn get_activity_types(solution: &Solution) -> Vec<String> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.activity_type.clone())
+        .collect()
+}
+
+#[test]
+fn can_serve_truck_only_jobs_while_trailer_is_parked() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![3., 0.], vec![3]),
+                create_truck_only_job("job2", vec![8., 0.]),
+                create_truck_only_job("job3", vec![9., 0.]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_trailer(vec![2], vec![3])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activity_types = get_activity_types(&solution);
+    let detach = activity_types.iter().position(|t| t == "detach").expect("no detach activity");
+    let attach = activity_types.iter().position(|t| t == "attach").expect("no attach activity");
+    assert_eq!(attach - detach, 3);
+}
+
+#[test]
+fn can_skip_trailer_parking_without_truck_only_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![3., 0.], vec![3]),
+                create_delivery_job("job2", vec![8., 0.]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_trailer(vec![2], vec![3])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(!get_activity_types(&solution).iter().any(|t| t == "detach" || t == "attach"));
+}
+
+#[test]
+fn can_unassign_truck_only_job_when_truck_capacity_is_exceeded() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                truck_only: Some(true),
+                ..create_delivery_job_with_demand("job1", vec![8., 0.], vec![3])
+            }],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_trailer(vec![2], vec![3])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+}
@@ -5,7 +5,7 @@ use crate::helpers::*;
 #[test]
 fn can_use_vehicle_with_open_end() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None, clustering: None },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
     };
@@ -15,6 +15,8 @@ fn can_use_vehicle_with_open_end() {
         travel_times: vec![0, 1, 1, 0],
         distances: vec![0, 1, 1, 0],
         error_codes: Some(vec![0, 1, 1, 1]),
+        travel_time_deviations: None,
+        tolls: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -27,6 +29,7 @@ fn can_use_vehicle_with_open_end() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_weight_volume(id: &str, location: Vec<f64>, weight: i32, volume: Option<i32>) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            demand: Some(vec![weight]),
+            volume,
+            density: if volume.is_none() { Some(0.5) } else { None },
+            ..create_task(location)
+        }]),
+        ..create_job(id)
+    }
+}
+
+parameterized_test! {can_restrict_load_by_weight_or_volume, (jobs, expected_unassigned, expected_load), {
+    can_restrict_load_by_weight_or_volume_impl(jobs, expected_unassigned, expected_load);
+}}
+
+can_restrict_load_by_weight_or_volume! {
+    case01_volume_binds: (vec![(2, Some(6)), (2, Some(6))], 1, vec![2, 6]),
+    case02_weight_binds: (vec![(6, Some(2)), (6, Some(2))], 1, vec![6, 2]),
+    case03_both_fit: (vec![(5, Some(5)), (5, Some(5))], 0, vec![10, 10]),
+    case04_density: (vec![(3, None), (3, None)], 1, vec![3, 6]),
+}
+
+fn can_restrict_load_by_weight_or_volume_impl(
+    jobs: Vec<(i32, Option<i32>)>,
+    expected_unassigned: usize,
+    expected_load: Vec<i32>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: jobs
+                .into_iter()
+                .enumerate()
+                .map(|(idx, (weight, volume))| {
+                    create_job_with_weight_volume(&format!("job{}", idx + 1), vec![idx as f64 + 1., 0.], weight, volume)
+                })
+                .collect(),
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![10],
+                volume: Some(10),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), expected_unassigned);
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops[0].load, expected_load);
+}
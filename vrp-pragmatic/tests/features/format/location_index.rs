@@ -9,6 +9,7 @@ fn create_test_problem() -> Problem {
         plan: Plan {
             jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -17,6 +18,7 @@ fn create_test_problem() -> Problem {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Reference { index: 2 },
+                        driver: None,
                     },
                     ..create_default_open_vehicle_shift()
                 }],
@@ -35,6 +37,8 @@ fn create_test_matrix() -> Matrix {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        travel_time_deviations: None,
+        tolls: None,
     }
 }
 
@@ -52,7 +56,8 @@ fn can_use_location_index() {
                 cost: 18.,
                 distance: 3,
                 duration: 5,
-                times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 }
+                times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,7 +102,8 @@ fn can_use_location_index() {
                     cost: 18.,
                     distance: 3,
                     duration: 5,
-                    times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 }
+                    times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 }
             }],
             unassigned: None,
@@ -6,13 +6,19 @@ use crate::helpers::*;
 #[test]
 fn can_limit_by_area() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None },
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.])],
+            relations: Option::None,
+            clustering: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits {
                     max_distance: None,
                     shift_time: None,
                     tour_size: None,
+                    max_radius: None,
+                    overload: None,
                     allowed_areas: Some(vec![AreaLimit {
                         priority: None,
                         outer_shape: vec![
@@ -22,6 +28,7 @@ fn can_limit_by_area() {
                             Location::new_coordinate(-5., 5.),
                         ],
                     }]),
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -41,6 +48,7 @@ fn can_limit_by_area() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -0,0 +1,58 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(overload: Option<VehicleOverload>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![6]),
+                create_delivery_job_with_demand("job2", vec![2., 0.], vec![5]),
+            ],
+            relations: Option::None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    allowed_areas: None,
+                    max_radius: None,
+                    overload,
+                    overtime: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_overload_vehicle_capacity() {
+    let problem = create_test_problem(Some(VehicleOverload { max_ratio: 0.2, penalty: 100. }));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.extras.and_then(|extras| extras.overloads),
+        Some(vec![TourOverload { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0, overload: vec![1] }])
+    );
+}
+
+#[test]
+fn can_skip_job_when_overload_is_not_allowed() {
+    let problem = create_test_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+    assert!(solution.extras.is_none());
+}
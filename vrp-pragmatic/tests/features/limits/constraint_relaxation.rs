@@ -0,0 +1,71 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(jobs: Vec<Job>, constraint_type: RelaxableConstraintType) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: Option::None, clustering: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        objectives: Some(Objectives {
+            primary: vec![
+                MinimizeUnassignedJobs { breaks: None },
+                MinimizeViolations { constraints: vec![ConstraintRelaxation { constraint_type, weight: None }] },
+            ],
+            secondary: Some(vec![MinimizeCost]),
+            weights: None,
+        }),
+    }
+}
+
+fn create_constraint_violation(code: &str, count: usize) -> Violation {
+    Violation::Constraint { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0, code: code.to_string(), count }
+}
+
+#[test]
+fn can_violate_capacity_when_no_feasible_solution() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_demand("job1", vec![1., 0.], vec![6]),
+            create_delivery_job_with_demand("job2", vec![2., 0.], vec![5]),
+        ],
+        RelaxableConstraintType::Capacity,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.violations, Some(vec![create_constraint_violation("CAPACITY_CONSTRAINT", 1)]));
+}
+
+#[test]
+fn can_violate_time_window_when_no_feasible_solution() {
+    let problem = create_test_problem(
+        vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 5)], 0.)],
+        RelaxableConstraintType::TimeWindow,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.violations, Some(vec![create_constraint_violation("TIME_WINDOW_CONSTRAINT", 1)]));
+}
+
+#[test]
+fn can_avoid_violations_when_feasible_solution_exists() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_demand("job1", vec![1., 0.], vec![5]),
+            create_delivery_job_with_demand("job2", vec![2., 0.], vec![5]),
+        ],
+        RelaxableConstraintType::Capacity,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.violations.is_none());
+}
@@ -5,14 +5,21 @@ use crate::helpers::*;
 #[test]
 fn can_limit_by_max_distance() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![100., 0.])],
+            relations: Option::None,
+            clustering: None,
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits {
                     max_distance: Some(99.),
                     shift_time: None,
                     tour_size: None,
+                    max_radius: None,
+                    overload: None,
                     allowed_areas: None,
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -26,6 +33,8 @@ fn can_limit_by_max_distance() {
         travel_times: vec![1, 1, 1, 1],
         distances: vec![1, 100, 100, 1],
         error_codes: Option::None,
+        travel_time_deviations: None,
+        tolls: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -38,6 +47,7 @@ fn can_limit_by_max_distance() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem_with_radius(airline: bool) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![0.001, 0.]), create_delivery_job("job2", vec![0.1, 0.])],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    allowed_areas: None,
+                    max_radius: Some(VehicleRadius { distance: 1000., airline: Some(airline) }),
+                    overload: None,
+                    overtime: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_limit_by_max_radius, airline, {
+    can_limit_by_max_radius_impl(airline);
+}}
+
+can_limit_by_max_radius! {
+    case01_routed: false,
+    case02_airline: true,
+}
+
+fn can_limit_by_max_radius_impl(airline: bool) {
+    let problem = create_problem_with_radius(airline);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, None, 100);
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "MAX_RADIUS_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to max radius of vehicle".to_string()
+            }]
+        }])
+    );
+}
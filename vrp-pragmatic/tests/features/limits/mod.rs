@@ -1,4 +1,8 @@
 mod area_allowance;
+mod capacity_overload;
+mod constraint_relaxation;
 mod max_distance;
+mod max_radius;
+mod shift_overtime;
 mod shift_time;
 mod tour_size;
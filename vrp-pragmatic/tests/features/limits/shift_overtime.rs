@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_shift_overtime, (max_time, is_assigned), {
+    can_use_shift_overtime_impl(max_time, is_assigned);
+}}
+
+can_use_shift_overtime! {
+    case01_enough_overtime: (60., true),
+    case02_not_enough_overtime: (40., false),
+}
+
+fn can_use_shift_overtime_impl(max_time: f64, is_assigned: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![100., 0.])],
+            relations: Option::None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: Some(150.),
+                    tour_size: None,
+                    max_radius: None,
+                    overload: None,
+                    allowed_areas: None,
+                    overtime: Some(VehicleOvertime { max_time, penalty: 10. }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![1, 100, 100, 1],
+        distances: vec![1, 1, 1, 1],
+        error_codes: Option::None,
+        travel_time_deviations: None,
+        tolls: None,
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), if is_assigned { 1 } else { 0 });
+    assert_eq!(solution.unassigned.is_none(), is_assigned);
+}
@@ -8,7 +8,10 @@ fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
             max_distance: None,
             shift_time: Some(shift_time),
             tour_size: None,
+            max_radius: None,
+            overload: None,
             allowed_areas: None,
+            overtime: None,
         }),
         ..create_default_vehicle_type()
     }
@@ -17,7 +20,11 @@ fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
 #[test]
 fn can_limit_one_job_by_shift_time() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![100., 0.])],
+            relations: Option::None,
+            clustering: None,
+        },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(99.)],
             profiles: create_default_profiles(),
@@ -30,6 +37,8 @@ fn can_limit_one_job_by_shift_time() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: Option::None,
+        travel_time_deviations: None,
+        tolls: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -42,6 +51,7 @@ fn can_limit_one_job_by_shift_time() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -68,6 +78,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 create_delivery_job_with_duration("job5", vec![5., 0.], 10.),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(40.)],
@@ -87,6 +98,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 distance: 6,
                 duration: 36,
                 times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -139,6 +151,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     distance: 6,
                     duration: 36,
                     times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![
@@ -168,6 +181,7 @@ fn can_serve_job_when_it_starts_late() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(100, 200)], 10.)],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(50.)],
@@ -12,6 +12,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 create_delivery_job("job3", vec![3., 0.]),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -21,6 +22,9 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                     shift_time: None,
                     allowed_areas: None,
                     tour_size: Some(2),
+                    max_radius: None,
+                    overload: None,
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -40,6 +44,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -76,6 +81,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
@@ -1,12 +1,15 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation and combination.
 
 mod breaks;
+mod clustering;
+mod compatibility;
 mod dispatch;
 mod fleet;
 mod format;
 mod limits;
 mod multjob;
 mod pickdev;
+mod plugins;
 mod priorities;
 mod relations;
 mod reload;
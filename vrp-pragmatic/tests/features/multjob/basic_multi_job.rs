@@ -15,6 +15,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 ),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -34,6 +35,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,6 +99,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -114,6 +117,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 vec![((6., 0.), 1., vec![2])],
             )],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -133,6 +137,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 distance: 12,
                 duration: 15,
                 times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -188,6 +193,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     distance: 12,
                     duration: 15,
                     times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -13,6 +13,7 @@ fn can_assign_replacement_job() {
                 create_pickup_job("job3", vec![3., 0.]),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -42,6 +43,7 @@ fn can_assign_replacement_job() {
                 distance: 4,
                 duration: 7,
                 times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -94,6 +96,7 @@ fn can_assign_replacement_job() {
                     distance: 4,
                     duration: 7,
                     times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
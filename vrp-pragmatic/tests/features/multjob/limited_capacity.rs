@@ -19,6 +19,7 @@ fn can_handle_limited_capacity() {
                 ),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -38,6 +39,7 @@ fn can_handle_limited_capacity() {
                 distance: 36,
                 duration: 42,
                 times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -120,6 +122,7 @@ fn can_handle_limited_capacity() {
                     distance: 36,
                     duration: 42,
                     times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
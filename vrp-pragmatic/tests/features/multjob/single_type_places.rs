@@ -9,6 +9,7 @@ fn can_use_only_deliveries_as_static_demand() {
         plan: Plan {
             jobs: vec![create_multi_job("job1", vec![], vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])])],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -31,6 +32,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 distance: 8,
                 duration: 11,
                 times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -69,6 +71,7 @@ fn can_use_only_deliveries_as_static_demand() {
                     distance: 8,
                     duration: 11,
                     times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -82,6 +85,7 @@ fn can_use_only_pickups_as_static_demand() {
         plan: Plan {
             jobs: vec![create_multi_job("job1", vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])], vec![])],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -111,6 +115,7 @@ fn can_use_only_pickups_as_static_demand() {
                 distance: 10,
                 duration: 13,
                 times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -157,6 +162,7 @@ fn can_use_only_pickups_as_static_demand() {
                     distance: 10,
                     duration: 13,
                     times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
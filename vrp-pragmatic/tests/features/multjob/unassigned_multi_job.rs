@@ -12,6 +12,7 @@ fn can_unassign_multi_job_due_to_capacity() {
                 vec![((6., 0.), 1., vec![3])],
             )],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
@@ -31,6 +32,7 @@ fn can_unassign_multi_job_due_to_capacity() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
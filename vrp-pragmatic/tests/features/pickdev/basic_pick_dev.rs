@@ -5,7 +5,11 @@ use crate::helpers::*;
 #[test]
 fn can_use_one_pickup_delivery_job_with_one_vehicle() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.])], relations: None },
+        plan: Plan {
+            jobs: vec![create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.])],
+            relations: None,
+            clustering: None,
+        },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
     };
@@ -21,6 +25,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 distance: 4,
                 duration: 6,
                 times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,6 +72,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     distance: 4,
                     duration: 6,
                     times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,47 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem_with_loading_policy(policy: Option<JobLoadingPolicy>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { loading_policy: policy, ..create_pickup_delivery_job("job1", vec![1., 0.], vec![5., 0.]) },
+                create_pickup_delivery_job("job2", vec![2., 0.], vec![10., 0.]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_use_loading_policy, (policy, expected), {
+    can_use_loading_policy_impl(policy, expected);
+}}
+
+can_use_loading_policy! {
+    case01_no_policy: (None, vec!["job1", "job2", "job1", "job2"]),
+    case02_lifo: (Some(JobLoadingPolicy::Lifo), vec!["job2", "job1", "job1", "job2"]),
+    case03_fifo: (Some(JobLoadingPolicy::Fifo), vec!["job1", "job2", "job1", "job2"]),
+}
+
+fn can_use_loading_policy_impl(policy: Option<JobLoadingPolicy>, expected: Vec<&str>) {
+    let problem = create_problem_with_loading_policy(policy);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]).into_iter().skip(1).flatten().collect::<Vec<_>>(),
+        expected.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
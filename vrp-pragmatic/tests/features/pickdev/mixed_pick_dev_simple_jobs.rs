@@ -12,6 +12,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 create_delivery_job("job3", vec![4., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -28,6 +29,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 distance: 8,
                 duration: 12,
                 times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,6 +92,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     distance: 8,
                     duration: 12,
                     times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 }
             }],
             ..create_empty_solution()
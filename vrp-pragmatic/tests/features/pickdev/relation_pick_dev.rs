@@ -16,6 +16,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -38,6 +39,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 distance: 50,
                 duration: 54,
                 times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,6 +104,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     distance: 50,
                     duration: 54,
                     times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,40 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::common::IdDimension;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::population::create_elitism_population;
+use vrp_core::solver::RefinementContext;
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_use_job_filter_plugin() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let plugins = ConstraintPlugins::default()
+        .with_job_filter(1000, Arc::new(|_, job| job.dimens().get_id().map_or(true, |id| id != "job2")));
+    let environment = Arc::new(Environment::default());
+
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic_with_plugins(plugins).unwrap());
+    let population = create_elitism_population(problem.clone(), environment.clone());
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone(), None);
+    let solution = RecreateWithCheapest::default()
+        .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment))
+        .solution
+        .to_solution(problem.extras.clone());
+
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes[0].tour.job_count(), 1);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert_eq!(solution.unassigned[0].0.dimens().get_id().unwrap(), "job2");
+    assert_eq!(solution.unassigned[0].1, 1000);
+}
@@ -0,0 +1 @@
+mod job_filter_plugin;
@@ -12,6 +12,7 @@ fn can_follow_priorities() {
                 create_delivery_job("job3", vec![7., 0.]),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -34,6 +35,7 @@ fn can_follow_priorities() {
                 distance: 20,
                 duration: 23,
                 times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -86,6 +88,7 @@ fn can_follow_priorities() {
                     distance: 20,
                     duration: 23,
                     times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
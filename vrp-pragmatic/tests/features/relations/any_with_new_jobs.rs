@@ -17,6 +17,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 vehicle_id: "my_vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -40,6 +41,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 distance: 3,
                 duration: 6,
                 times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -84,6 +86,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     distance: 3,
                     duration: 6,
                     times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -29,6 +29,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     shift_index: None,
                 },
             ]),
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -45,6 +46,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 distance: 18,
                 duration: 25,
                 times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -129,6 +131,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     distance: 18,
                     duration: 25,
                     times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -29,6 +29,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     shift_index: None,
                 },
             ]),
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -45,6 +46,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 distance: 22,
                 duration: 29,
                 times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -129,6 +131,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     distance: 22,
                     duration: 29,
                     times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -176,6 +179,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                     shift_index: None,
                 },
             ]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -200,6 +204,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 distance: 26,
                 duration: 34,
                 times: Timing { driving: 26, serving: 8, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -253,6 +258,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -306,6 +312,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
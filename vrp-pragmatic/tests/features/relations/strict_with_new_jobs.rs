@@ -32,6 +32,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                     shift_index: None,
                 },
             ]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -55,6 +56,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 distance: 42,
                 duration: 52,
                 times: Timing { driving: 42, serving: 10, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -124,6 +126,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 22,
                         duration: 27,
                         times: Timing { driving: 22, serving: 5, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -193,6 +196,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 20,
                         duration: 25,
                         times: Timing { driving: 20, serving: 5, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
@@ -30,6 +30,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                     shift_index: None,
                 },
             ]),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -53,6 +54,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 distance: 34,
                 duration: 42,
                 times: Timing { driving: 34, serving: 8, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -114,6 +116,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 20,
                         duration: 24,
                         times: Timing { driving: 20, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -175,6 +178,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 14,
                         duration: 18,
                         times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
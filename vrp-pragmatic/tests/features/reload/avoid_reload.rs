@@ -24,11 +24,17 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 ),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
@@ -36,6 +42,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
                         location: vec![0., 0.].to_loc(),
@@ -62,6 +69,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -125,6 +133,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
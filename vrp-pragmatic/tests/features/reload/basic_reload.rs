@@ -30,11 +30,16 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
 
 fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned: Option<Vec<UnassignedJob>>) {
     let problem = Problem {
-        plan: Plan { jobs, relations: Option::None },
+        plan: Plan { jobs, relations: Option::None, clustering: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
@@ -42,6 +47,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
                         location: vec![0., 0.].to_loc(),
@@ -68,6 +74,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -120,6 +127,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned,
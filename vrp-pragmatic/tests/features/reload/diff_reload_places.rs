@@ -14,11 +14,17 @@ fn can_use_reloads_with_different_locations() {
                 create_delivery_job("job5", vec![30., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.),
@@ -26,6 +32,7 @@ fn can_use_reloads_with_different_locations() {
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![
                         VehicleReload {
                             times: None,
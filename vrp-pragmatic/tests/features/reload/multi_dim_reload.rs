@@ -12,11 +12,17 @@ fn can_use_multi_dim_capacity() {
                 create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 1]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
@@ -24,6 +30,7 @@ fn can_use_multi_dim_capacity() {
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
                         location: vec![0., 0.].to_loc(),
@@ -50,6 +57,7 @@ fn can_use_multi_dim_capacity() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,6 +110,7 @@ fn can_use_multi_dim_capacity() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
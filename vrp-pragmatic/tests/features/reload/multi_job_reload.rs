@@ -19,11 +19,17 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 ),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
@@ -31,6 +37,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
                         location: vec![0., 0.].to_loc(),
@@ -57,6 +64,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 distance: 14,
                 duration: 22,
                 times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -144,6 +152,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     distance: 14,
                     duration: 22,
                     times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -165,10 +174,11 @@ fn can_properly_handle_load_without_capacity_violation() {
                 create_pickup_job_with_demand("job2", vec![67., 0.], vec![2]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003, zone_fees: None },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
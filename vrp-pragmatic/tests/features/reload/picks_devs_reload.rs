@@ -15,11 +15,17 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 create_pickup_job("p2", vec![5., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
@@ -27,6 +33,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
                         location: vec![3., 0.].to_loc(),
@@ -53,6 +60,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 distance: 6,
                 duration: 12,
                 times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -121,6 +129,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     distance: 6,
                     duration: 12,
                     times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
@@ -12,6 +12,7 @@ fn can_wait_for_job_start() {
                 all_of_skills(vec!["unique_skill".to_string()]),
             )],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![
@@ -40,6 +41,7 @@ fn can_wait_for_job_start() {
                 distance: 18,
                 duration: 19,
                 times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -76,6 +78,7 @@ fn can_wait_for_job_start() {
                     distance: 18,
                     duration: 19,
                     times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
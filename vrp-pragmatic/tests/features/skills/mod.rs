@@ -1,2 +1,3 @@
 mod basic_skill;
+mod preferred_skills;
 mod unassigned_due_to_skills;
@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_vehicle_with_skill, (penalty, expected), {
+    can_prefer_vehicle_with_skill_impl(penalty, expected);
+}}
+
+can_prefer_vehicle_with_skill! {
+    case01_high_penalty: (100., "vehicle_with_skill_1"),
+    case02_low_penalty: (1., "vehicle_without_skill_1"),
+}
+
+fn can_prefer_vehicle_with_skill_impl(penalty: f64, expected: &str) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills(
+                "job1",
+                vec![1., 0.],
+                JobSkills {
+                    all_of: None,
+                    one_of: None,
+                    none_of: None,
+                    preferred: Some(vec![JobPreferredSkill { name: "unique_skill".to_string(), penalty }]),
+                },
+            )],
+            relations: Option::None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle_without_skill"),
+                VehicleType {
+                    type_id: "vehicle_with_skill".to_string(),
+                    vehicle_ids: vec!["vehicle_with_skill_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    skills: Some(vec!["unique_skill".to_string()]),
+                    ..create_default_vehicle_type()
+                },
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected);
+}
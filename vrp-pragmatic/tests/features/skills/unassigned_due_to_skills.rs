@@ -12,6 +12,7 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
                 all_of_skills(vec!["unique_skill".to_string()]),
             )],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("vehicle_without_skill")],
@@ -31,6 +32,7 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -14,6 +14,7 @@ fn can_use_multiple_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(40, 50)], 0.),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -30,6 +31,7 @@ fn can_use_multiple_times() {
                 distance: 100,
                 duration: 130,
                 times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -76,14 +78,27 @@ fn can_use_multiple_times() {
                         ("1970-01-01T00:00:50Z", "1970-01-01T00:00:50Z"),
                         50
                     ),
-                    create_stop_with_activity(
-                        "job3",
-                        "delivery",
-                        (30., 0.),
-                        0,
-                        ("1970-01-01T00:01:10Z", "1970-01-01T00:01:40Z"),
-                        70
-                    ),
+                    Stop {
+                        location: vec![30., 0.].to_loc(),
+                        time: Schedule {
+                            arrival: "1970-01-01T00:01:10Z".to_string(),
+                            departure: "1970-01-01T00:01:40Z".to_string(),
+                        },
+                        distance: 70,
+                        load: vec![0],
+                        activities: vec![Activity {
+                            job_id: "job3".to_string(),
+                            activity_type: "delivery".to_string(),
+                            location: None,
+                            time: None,
+                            job_tag: None,
+                            time_window: Some(Interval {
+                                start: "1970-01-01T00:01:40Z".to_string(),
+                                end: "1970-01-01T00:02:00Z".to_string(),
+                            }),
+                            crew_member: None,
+                        }],
+                    },
                     create_stop_with_activity(
                         "arrival",
                         "arrival",
@@ -98,6 +113,7 @@ fn can_use_multiple_times() {
                     distance: 100,
                     duration: 130,
                     times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
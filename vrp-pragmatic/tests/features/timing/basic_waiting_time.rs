@@ -12,6 +12,7 @@ fn can_wait_for_job_start() {
                 create_delivery_job_with_times("job2", vec![2., 0.], vec![(10, 20)], 0.),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -28,6 +29,7 @@ fn can_wait_for_job_start() {
                 distance: 4,
                 duration: 12,
                 times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -72,6 +74,7 @@ fn can_wait_for_job_start() {
                     distance: 4,
                     duration: 12,
                     times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -85,6 +88,7 @@ fn can_skip_initial_waiting() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(10, 20)], 10.)],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -101,6 +105,7 @@ fn can_skip_initial_waiting() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -137,6 +142,7 @@ fn can_skip_initial_waiting() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -150,6 +156,7 @@ fn can_consider_latest_departure_time() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(10, 20)], 10.)],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -158,6 +165,7 @@ fn can_consider_latest_departure_time() {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: Some("1970-01-01T00:00:05Z".to_string()),
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        driver: None,
                     },
                     ..create_default_vehicle_shift()
                 }],
@@ -179,6 +187,7 @@ fn can_consider_latest_departure_time() {
                 distance: 2,
                 duration: 16,
                 times: Timing { driving: 2, serving: 10, waiting: 4, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -215,6 +224,7 @@ fn can_consider_latest_departure_time() {
                     distance: 2,
                     duration: 16,
                     times: Timing { driving: 2, serving: 10, waiting: 4, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
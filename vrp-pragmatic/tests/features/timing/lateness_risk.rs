@@ -0,0 +1,50 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(job_end_time: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, job_end_time)], 0.)],
+            relations: Option::None,
+            clustering: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs { breaks: None }, MinimizeLatenessRisk { probability: 0.9 }],
+            secondary: Some(vec![MinimizeCost]),
+            weights: None,
+        }),
+    }
+}
+
+fn create_test_matrix(problem: &Problem) -> Matrix {
+    let matrix = create_matrix_from_problem(problem);
+
+    Matrix { travel_time_deviations: Some(vec![5; matrix.travel_times.len()]), ..matrix }
+}
+
+#[test]
+fn can_plan_travel_times_using_percentile() {
+    let problem = create_test_problem(20);
+    let matrix = create_test_matrix(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let robustness = solution.extras.and_then(|extras| extras.robustness).expect("no robustness");
+    assert_eq!(robustness.len(), 1);
+    assert_eq!(robustness[0].min_slack, Some(4));
+    assert_eq!((robustness[0].on_time_probability * 1000.).round() / 1000., 0.977);
+}
+
+#[test]
+fn can_skip_job_which_cannot_be_served_with_desired_probability() {
+    let problem = create_test_problem(12);
+    let matrix = create_test_matrix(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+}
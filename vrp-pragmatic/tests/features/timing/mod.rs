@@ -1,4 +1,7 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod lateness_risk;
+mod preferred_times;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod vehicle_unavailability;
@@ -0,0 +1,66 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_preferred_times(id: &str, location: Vec<f64>, preferred_times: Vec<(i32, i32)>) -> Job {
+    let job = create_delivery_job(id, location);
+    let mut task = job.deliveries.as_ref().unwrap().first().unwrap().clone();
+    task.places = vec![JobPlace { preferred_times: convert_times(&preferred_times), ..task.places[0].clone() }];
+
+    Job { deliveries: Some(vec![task]), ..job }
+}
+
+#[test]
+fn can_serve_jobs_within_preferred_times() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_preferred_times("job1", vec![10., 0.], vec![(0, 12)]),
+                create_job_with_preferred_times("job2", vec![5., 0.], vec![(14, 30)]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        objectives: Some(Objectives {
+            primary: vec![MinimizeUnassignedJobs { breaks: None }, MaximizePreferredTimes { weight: None }],
+            secondary: Some(vec![MinimizeCost]),
+            weights: None,
+        }),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["departure", "job1", "job2", "arrival"]);
+    let preferences = solution.extras.and_then(|extras| extras.preferences).expect("no preferences in extras");
+    assert_eq!(preferences.len(), 1);
+    assert_eq!((preferences[0].hits, preferences[0].total), (2, 2));
+}
+
+#[test]
+fn can_report_preferred_times_hits_without_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_job_with_preferred_times("job1", vec![10., 0.], vec![(100, 200)])],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let preferences = solution.extras.and_then(|extras| extras.preferences).expect("no preferences in extras");
+    assert_eq!((preferences[0].hits, preferences[0].total), (0, 1));
+    assert_eq!(preferences[0].hit_rate, 0.);
+}
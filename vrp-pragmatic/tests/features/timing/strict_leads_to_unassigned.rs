@@ -14,6 +14,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(0, 10)], 0.),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -30,6 +31,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 distance: 80,
                 duration: 80,
                 times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,6 +92,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     distance: 80,
                     duration: 80,
                     times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
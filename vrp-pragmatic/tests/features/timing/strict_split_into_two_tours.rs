@@ -14,6 +14,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 create_delivery_job_with_times("job5", vec![50., 0.], vec![(50, 60)], 10.),
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -36,6 +37,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 distance: 140,
                 duration: 200,
                 times: Timing { driving: 140, serving: 50, waiting: 10, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -67,14 +69,27 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             ("1970-01-01T00:01:00Z", "1970-01-01T00:01:10Z"),
                             50,
                         ),
-                        create_stop_with_activity(
-                            "job3",
-                            "delivery",
-                            (30., 0.),
-                            0,
-                            ("1970-01-01T00:01:30Z", "1970-01-01T00:01:50Z"),
-                            70,
-                        ),
+                        Stop {
+                            location: vec![30., 0.].to_loc(),
+                            time: Schedule {
+                                arrival: "1970-01-01T00:01:30Z".to_string(),
+                                departure: "1970-01-01T00:01:50Z".to_string(),
+                            },
+                            distance: 70,
+                            load: vec![0],
+                            activities: vec![Activity {
+                                job_id: "job3".to_string(),
+                                activity_type: "delivery".to_string(),
+                                location: None,
+                                time: None,
+                                job_tag: None,
+                                time_window: Some(Interval {
+                                    start: "1970-01-01T00:01:40Z".to_string(),
+                                    end: "1970-01-01T00:02:00Z".to_string(),
+                                }),
+                                crew_member: None,
+                            }],
+                        },
                         create_stop_with_activity(
                             "arrival",
                             "arrival",
@@ -89,6 +104,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         distance: 100,
                         duration: 140,
                         times: Timing { driving: 100, serving: 30, waiting: 10, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -134,6 +150,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         distance: 40,
                         duration: 60,
                         times: Timing { driving: 40, serving: 20, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
             ],
@@ -0,0 +1,72 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_vehicle_with_unavailability(time: (f64, f64)) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            unavailability: Some(vec![VehicleUnavailability {
+                location: vec![5., 0.].to_loc(),
+                time: vec![format_time(time.0), format_time(time.1)],
+                tag: None,
+            }]),
+            ..create_default_vehicle_shift()
+        }],
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_block_activities_during_unavailability_period() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 50)], 0.),
+                create_delivery_job("job2", vec![20., 0.]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_unavailability((10., 100.))],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+    let stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities.iter().any(|activity| activity.activity_type == "unavailability"))
+        .expect("no unavailability activity");
+    assert!(parse_time(&stop.time.arrival) <= 10.);
+    assert_eq!(stop.time.departure, format_time(100.));
+}
+
+#[test]
+fn can_skip_unavailability_period_for_unused_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 50)], 0.)],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_unavailability((10., 100.))],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+}
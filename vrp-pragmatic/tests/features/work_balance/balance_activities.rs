@@ -32,6 +32,7 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
                 create_delivery_job("job2.1", vec![2., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![
@@ -54,6 +55,7 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
         objectives: Some(Objectives {
             primary: vec![BalanceActivities { options: Some(BalanceOptions { threshold, tolerance: None }) }],
             secondary: Some(vec![MinimizeCost]),
+            weights: None,
         }),
         ..create_empty_problem()
     };
@@ -15,6 +15,7 @@ fn can_balance_max_load() {
                 create_delivery_job("job6", vec![6., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -28,6 +29,7 @@ fn can_balance_max_load() {
         objectives: Some(Objectives {
             primary: vec![BalanceMaxLoad { options: None }],
             secondary: Some(vec![MinimizeCost]),
+            weights: None,
         }),
         ..create_empty_problem()
     };
@@ -39,3 +41,48 @@ fn can_balance_max_load() {
     assert_eq!(solution.tours.first().unwrap().stops.len(), 4);
     assert_eq!(solution.tours.last().unwrap().stops.len(), 4);
 }
+
+#[test]
+fn can_balance_max_load_with_skills() {
+    let create_job = |id: &str, location: Vec<f64>| {
+        create_delivery_job_with_skills(id, location, all_of_skills(vec!["unique_skill".to_string()]))
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job("job1", vec![1., 0.]),
+                create_job("job2", vec![2., 0.]),
+                create_job("job3", vec![3., 0.]),
+                create_job("job4", vec![4., 0.]),
+                create_job("job5", vec![5., 0.]),
+                create_job("job6", vec![6., 0.]),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![5],
+                skills: Some(vec!["unique_skill".to_string()]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        objectives: Some(Objectives {
+            primary: vec![BalanceMaxLoad { options: None }],
+            secondary: Some(vec![MinimizeCost]),
+            weights: None,
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(solution.tours.first().unwrap().stops.len(), 4);
+    assert_eq!(solution.tours.last().unwrap().stops.len(), 4);
+}
@@ -63,15 +63,20 @@ pub fn default_job_prototype() -> impl Strategy<Value = Job> {
 
 pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
     from_costs(vec![
-        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003 },
-        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005 },
+        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003, zone_fees: None },
+        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005, zone_fees: None },
     ])
 }
 
 pub fn default_shift_places_prototype() -> impl Strategy<Value = (ShiftStart, Option<ShiftEnd>)> {
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
-            ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
+            ShiftStart {
+                earliest: default_time_plus_offset(9),
+                latest: None,
+                location: location.clone(),
+                driver: None,
+            },
             Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location }),
         ))
     })
@@ -82,6 +87,7 @@ pub fn default_breaks_prototype() -> impl Strategy<Value = Option<Vec<VehicleBre
         time: VehicleBreakTime::TimeWindow(vec![default_time_plus_offset(12), default_time_plus_offset(14)]),
         duration: 3600.,
         locations: None,
+        policy: None,
     }]))
 }
 
@@ -54,14 +54,19 @@ prop_compose! {
        Job {
             id: Uuid::new_v4().to_string(),
             pickups: Some(vec![
-             JobTask { places: vec![pickup], demand: demand.clone(), tag: Some("p1".to_owned())}
+             JobTask { places: vec![pickup], demand: demand.clone(), volume: None, density: None, tag: Some("p1".to_owned())}
             ]),
             deliveries: Some(vec![
-             JobTask { places: vec![delivery], demand: demand.clone(), tag: Some("d1".to_owned())}
+             JobTask { places: vec![delivery], demand: demand.clone(), volume: None, density: None, tag: Some("d1".to_owned())}
             ]),
             replacements: None,
             services: None,
             priority,
+            value: None,
+            must_serve: None,
+            truck_only: None,
+            loading_policy: None,
+            compatibility: None,
             skills,
         }
     }
@@ -74,7 +79,7 @@ pub fn generate_jobs(job_proto: impl Strategy<Value = Job>, range: Range<usize>)
 
 /// Generates job plan.
 pub fn generate_plan(jobs_proto: impl Strategy<Value = Vec<Job>>) -> impl Strategy<Value = Plan> {
-    jobs_proto.prop_map(|jobs| Plan { jobs, relations: None })
+    jobs_proto.prop_map(|jobs| Plan { jobs, relations: None, clustering: None })
 }
 
 prop_compose! {
@@ -101,6 +106,11 @@ prop_compose! {
             replacements,
             services,
             priority,
+            value: None,
+            must_serve: None,
+            truck_only: None,
+            loading_policy: None,
+            compatibility: None,
             skills,
         }
     }
@@ -117,7 +127,7 @@ prop_compose! {
      demand in demand_proto,
      tag in tags
     ) -> JobTask {
-       JobTask { places: vec![place], demand, tag}
+       JobTask { places: vec![place], demand, volume: None, density: None, tag}
     }
 }
 
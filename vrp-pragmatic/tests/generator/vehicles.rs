@@ -31,6 +31,7 @@ prop_compose! {
             costs,
             shifts,
             capacity,
+            volume: None,
             skills,
             limits,
         }
@@ -74,6 +75,7 @@ prop_compose! {
             time,
             duration,
             locations,
+            policy: None,
         }
     }
 }
@@ -104,6 +106,7 @@ prop_compose! {
           end: places.1,
           dispatch,
           breaks,
+          unavailability: None,
           reloads
         }
     }
@@ -30,6 +30,7 @@ pub fn test_vehicle(id: &str) -> Vehicle {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: Default::default() }),
             end: Some(VehiclePlace { location: 0, time: Default::default() }),
+            driver: None,
         }],
     }
 }
@@ -4,11 +4,11 @@ use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: Vec<f64>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1. }
+    JobPlace { times: None, preferred_times: None, location: location.to_loc(), duration: 1. }
 }
 
 pub fn create_task(location: Vec<f64>) -> JobTask {
-    JobTask { places: vec![create_job_place(location)], demand: Some(vec![1]), tag: None }
+    JobTask { places: vec![create_job_place(location)], demand: Some(vec![1]), volume: None, density: None, tag: None }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -19,6 +19,11 @@ pub fn create_job(id: &str) -> Job {
         replacements: None,
         services: None,
         priority: None,
+        value: None,
+        must_serve: None,
+        truck_only: None,
+        loading_policy: None,
+        compatibility: None,
         skills: None,
     }
 }
@@ -44,6 +49,8 @@ pub fn create_delivery_job_with_duration(id: &str, location: Vec<f64>, duration:
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, ..create_job_place(location) }],
             demand: Some(vec![1]),
+            volume: None,
+            density: None,
             tag: None,
         }]),
         ..create_job(id)
@@ -55,6 +62,8 @@ pub fn create_delivery_job_with_times(id: &str, location: Vec<f64>, times: Vec<(
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location) }],
             demand: Some(vec![1]),
+            volume: None,
+            density: None,
             tag: None,
         }]),
         ..create_job(id)
@@ -99,6 +108,8 @@ pub fn create_pickup_delivery_job_with_params(
                 ..create_job_place(pickup.0.clone())
             }],
             demand: Some(demand.clone()),
+            volume: None,
+            density: None,
             tag: Some("p1".to_string()),
         }]),
         deliveries: Some(vec![JobTask {
@@ -108,6 +119,8 @@ pub fn create_pickup_delivery_job_with_params(
                 ..create_job_place(delivery.0.clone())
             }],
             demand: Some(demand.clone()),
+            volume: None,
+            density: None,
             tag: Some("d1".to_string()),
         }]),
 
@@ -118,8 +131,15 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1. }],
+            places: vec![JobPlace {
+                times: None,
+                preferred_times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+            }],
             demand: Some(vec![1]),
+            volume: None,
+            density: None,
             tag: None,
         }]),
         ..create_job(id)
@@ -138,6 +158,8 @@ pub fn create_multi_job(
             .map(|(i, (location, duration, demand))| JobTask {
                 places: vec![JobPlace { duration, ..create_job_place(vec![location.0, location.1]) }],
                 demand: Some(demand),
+                volume: None,
+                density: None,
                 tag: Some(format!("{}{}", prefix, i + 1)),
             })
             .collect::<Vec<_>>();
@@ -158,17 +180,23 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc(), driver: None },
         end: None,
         dispatch: None,
         breaks: None,
+        unavailability: None,
         reloads: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![start.0, start.1].to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            location: vec![start.0, start.1].to_loc(),
+            driver: None,
+        },
         end: Some(ShiftEnd {
             earliest: None,
             latest: format_time(1000.).to_string(),
@@ -176,12 +204,13 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
         }),
         dispatch: None,
         breaks: None,
+        unavailability: None,
         reloads: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., zone_fees: None }
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -200,18 +229,28 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         costs: create_default_vehicle_costs(),
         shifts: vec![create_default_vehicle_shift()],
         capacity,
+        volume: None,
+        trailer: None,
+        team: None,
+        crew_size: None,
         skills: None,
         limits: None,
     }
 }
 
 pub fn create_default_profiles() -> Vec<Profile> {
-    vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }]
+    vec![Profile {
+        name: "car".to_string(),
+        profile_type: "car".to_string(),
+        speed: None,
+        distance_multiplier: None,
+        time_bucket: None,
+    }]
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
-        plan: Plan { jobs: vec![], relations: None },
+        plan: Plan { jobs: vec![], relations: None, clustering: None },
         fleet: Fleet { vehicles: vec![], profiles: vec![] },
         objectives: None,
     }
@@ -228,6 +267,8 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data.clone(),
         error_codes: None,
+        travel_time_deviations: None,
+        tolls: None,
     }
 }
 
@@ -254,10 +295,10 @@ pub fn to_strings(data: Vec<&str>) -> Vec<String> {
 }
 
 pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
-    JobSkills { all_of: Some(skills), one_of: None, none_of: None }
+    JobSkills { all_of: Some(skills), one_of: None, none_of: None, preferred: None }
 }
 
-fn convert_times(times: &Vec<(i32, i32)>) -> Option<Vec<Vec<String>>> {
+pub fn convert_times(times: &Vec<(i32, i32)>) -> Option<Vec<Vec<String>>> {
     if times.is_empty() {
         None
     } else {
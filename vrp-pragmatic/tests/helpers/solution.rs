@@ -62,6 +62,8 @@ fn create_stop_with_activity_impl(
             location: None,
             time: None,
             job_tag,
+            time_window: None,
+            crew_member: None,
         }],
     }
 }
@@ -128,6 +128,8 @@ fn check_jobs_impl(
             .map(|(idx, _)| JobTask {
                 places: vec![],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
+                volume: None,
+                density: None,
                 tag: Some(format!("{}{}", tgt, idx)),
             })
             .collect()
@@ -146,10 +148,13 @@ fn check_jobs_impl(
                     replacements: Some(create_tasks("replacement", &tasks)),
                     services: Some(create_tasks("service", &tasks)),
                     priority: None,
+                    must_serve: None,
+                    truck_only: None,
                     skills: None,
                 })
                 .collect(),
             relations: None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -183,6 +188,7 @@ fn can_detect_time_window_violation() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(1, 2)], 1.)],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -193,6 +199,7 @@ fn can_detect_time_window_violation() {
             distance: 2,
             duration: 3,
             times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -229,6 +236,7 @@ fn can_detect_time_window_violation() {
                 distance: 2,
                 duration: 3,
                 times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
         }],
         ..create_empty_solution()
@@ -243,7 +251,7 @@ fn can_detect_time_window_violation() {
 #[test]
 fn can_detect_dispatch_violations() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job("job1", vec![2., 0.])], relations: None },
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![2., 0.])], relations: None, clustering: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
@@ -300,3 +308,76 @@ fn can_detect_dispatch_violations() {
 
     assert_eq!(result, Err("tour should have dispatch, but none is found: 'my_vehicle_1'".to_owned()));
 }
+
+parameterized_test! {can_check_values, (tour_value, solution_value, expected), {
+    can_check_values_impl(tour_value, solution_value, expected);
+}}
+
+can_check_values! {
+    case_01: (Some(10.), Some(10.), Ok(())),
+    case_02: (None, None, Err("value mismatch for tour 'my_vehicle_1' statistic, expected: '10', got: 'None'")),
+    case_03: (Some(5.), Some(5.), Err("value mismatch for tour 'my_vehicle_1' statistic, expected: '10', got: 'Some(5.0)'")),
+    case_04: (Some(10.), None, Err("value mismatch for solution statistic, expected: '10', got: 'None'")),
+}
+
+fn can_check_values_impl(tour_value: Option<f64>, solution_value: Option<f64>, expected: Result<(), &str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { value: Some(10.), ..create_delivery_job("job1", vec![1., 0.]) },
+                Job { value: Some(20.), ..create_delivery_job("job2", vec![2., 0.]) },
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic { value: solution_value, ..Statistic::default() },
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                    2,
+                ),
+            ],
+            statistic: Statistic { value: tour_value, ..Statistic::default() },
+        }],
+        unassigned: Some(vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "VALUE_CONSTRAINT".to_string(),
+                description: "serving job costs more than its value".to_string(),
+            }],
+        }]),
+        ..create_empty_solution()
+    };
+
+    let result = check_values(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected.map_err(|err| err.to_string()));
+}
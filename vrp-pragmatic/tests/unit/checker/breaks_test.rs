@@ -78,18 +78,30 @@ fn can_check_breaks_impl(
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![0., 0.].to_loc(),
                     }),
                     dispatch: None,
-                    breaks: Some(vec![VehicleBreak { time: break_times, duration: 2.0, locations: None }]),
+                    breaks: Some(vec![VehicleBreak {
+                        time: break_times,
+                        duration: 2.0,
+                        locations: None,
+                        policy: None,
+                    }]),
+                    unavailability: None,
                     reloads: None,
                 }],
                 capacity: vec![5],
@@ -106,6 +118,8 @@ fn can_check_breaks_impl(
         location: None,
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
+        time_window: None,
+        crew_member: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -114,6 +128,8 @@ fn can_check_breaks_impl(
             location: None,
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
+            time_window: None,
+            crew_member: None,
         });
     }
 
@@ -123,6 +139,7 @@ fn can_check_breaks_impl(
             distance: 4,
             duration: 8,
             times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -169,6 +186,7 @@ fn can_check_breaks_impl(
                 distance: 4,
                 duration: 8,
                 times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
         }],
         violations,
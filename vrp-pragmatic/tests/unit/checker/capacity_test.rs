@@ -32,11 +32,17 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 create_pickup_delivery_job("job5", vec![1., 0.], vec![5., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: vec![0., 0.].to_loc(),
+                        driver: None,
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
@@ -44,6 +50,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                     }),
                     dispatch: None,
                     breaks: None,
+                    unavailability: None,
                     reloads: Some(vec![VehicleReload {
                         times: None,
                         location: vec![0., 0.].to_loc(),
@@ -64,6 +71,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
             distance: 1,
             duration: 2,
             times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -93,6 +101,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: None,
+                            time_window: None,
+                            crew_member: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -100,6 +110,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: Some("p1".to_string()),
+                            time_window: None,
+                            crew_member: None,
                         },
                     ],
                 },
@@ -117,6 +129,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         location: None,
                         time: None,
                         job_tag: None,
+                        time_window: None,
+                        crew_member: None,
                     }],
                 },
                 Stop {
@@ -137,6 +151,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:09Z".to_string(),
                             }),
                             job_tag: None,
+                            time_window: None,
+                            crew_member: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -147,6 +163,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:10Z".to_string(),
                             }),
                             job_tag: None,
+                            time_window: None,
+                            crew_member: None,
                         },
                     ],
                 },
@@ -181,6 +199,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
         }],
         ..create_empty_solution()
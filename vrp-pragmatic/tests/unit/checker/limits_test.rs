@@ -1,6 +1,7 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
+use std::iter::once;
 use vrp_core::models::examples::create_example_problem;
 
 fn create_test_problem(limits: Option<VehicleLimits>) -> Problem {
@@ -61,8 +62,15 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance, shift_time, tour_size: None, allowed_areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        tour_size: None,
+        allowed_areas: None,
+        max_radius: None,
+        overload: None,
+        overtime: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
 
@@ -77,7 +85,10 @@ pub fn can_check_tour_size_limit() {
         max_distance: None,
         shift_time: None,
         tour_size: Some(2),
+        max_radius: None,
+        overload: None,
         allowed_areas: None,
+        overtime: None,
     }));
     let solution = create_test_solution(
         Statistic::default(),
@@ -133,3 +144,112 @@ pub fn can_check_tour_size_limit() {
             .to_string())
     );
 }
+
+parameterized_test! {can_check_allowed_areas, (location, expected), {
+    can_check_allowed_areas_impl(location, expected);
+}}
+
+can_check_allowed_areas! {
+    case01_inside: ((1., 1.), true),
+    case02_outside: ((3., 1.), false),
+}
+
+fn can_check_allowed_areas_impl(location: (f64, f64), expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![location.0, location.1])],
+            relations: None,
+            clustering: None,
+        },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            max_radius: None,
+            overload: None,
+            allowed_areas: Some(vec![AreaLimit {
+                priority: None,
+                outer_shape: vec![
+                    Location::new_coordinate(0., 0.),
+                    Location::new_coordinate(0., 2.),
+                    Location::new_coordinate(2., 2.),
+                    Location::new_coordinate(2., 0.),
+                ],
+            }]),
+            overtime: None,
+        }))
+    };
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                (format_time(0.).as_str(), format_time(0.).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                location,
+                0,
+                (format_time(1.).as_str(), format_time(1.).as_str()),
+                1,
+            ),
+        ],
+    );
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+parameterized_test! {can_check_team_driving_time, (locations, max_driving_time, expected), {
+    can_check_team_driving_time_impl(locations, max_driving_time, expected);
+}}
+
+can_check_team_driving_time! {
+    case01_fits: (vec![10., 30.], 40., Ok(())),
+    case02_exceeds: (vec![10., 30.], 35., Err("team driving time violation, expected: not more than 35, got: 40, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+    case03_same_location_fits: (vec![10., 10., 30.], 40., Ok(())),
+    case04_same_location_exceeds: (vec![10., 10., 30.], 35., Err("team driving time violation, expected: not more than 35, got: 40, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_team_driving_time_impl(locations: Vec<f64>, max_driving_time: f64, expected: Result<(), String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["some_real_vehicle".to_string()],
+                team: Some(VehicleTeam { drivers: 2, max_driving_time }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let create_stop = |job_id: &str, activity_type: &str, location: f64, time: f64| {
+        create_stop_with_activity(
+            job_id,
+            activity_type,
+            (location, 0.),
+            0,
+            (format_time(time).as_str(), format_time(time).as_str()),
+            location as i64,
+        )
+    };
+    let last = locations.last().cloned().unwrap();
+    let stops =
+        once(create_stop("departure", "departure", 0., 0.))
+            .chain(locations.iter().enumerate().map(|(idx, location)| {
+                create_stop(format!("job{}", idx + 1).as_str(), "delivery", *location, *location)
+            }))
+            .chain(once(create_stop("arrival", "arrival", 0., 2. * last)))
+            .collect();
+    let solution = create_test_solution(Statistic::default(), stops);
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
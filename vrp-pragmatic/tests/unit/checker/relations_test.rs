@@ -78,6 +78,7 @@ mod single {
                     create_pickup_job("job5", vec![5., 0.]),
                 ],
                 relations,
+                clustering: None,
             },
             fleet: Fleet {
                 vehicles: vec![VehicleType {
@@ -86,7 +87,12 @@ mod single {
                     profile: "car".to_string(),
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: vec![0., 0.].to_loc(),
+                            driver: None,
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
@@ -97,7 +103,9 @@ mod single {
                             time: VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
                             duration: 2.0,
                             locations: None,
+                            policy: None,
                         }]),
+                        unavailability: None,
                         reloads: Some(vec![VehicleReload {
                             times: None,
                             location: vec![0., 0.].to_loc(),
@@ -106,6 +114,10 @@ mod single {
                         }]),
                     }],
                     capacity: vec![5],
+                    volume: None,
+                    trailer: None,
+                    team: None,
+                    crew_size: None,
                     skills: None,
                     limits: None,
                 }],
@@ -119,6 +131,7 @@ mod single {
                 distance: 16,
                 duration: 25,
                 times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![
                 VehicleTour {
@@ -157,6 +170,8 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    time_window: None,
+                                    crew_member: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -164,6 +179,8 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    time_window: None,
+                                    crew_member: None,
                                 },
                             ],
                         },
@@ -213,6 +230,7 @@ mod single {
                         distance: 16,
                         duration: 25,
                         times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                        value: None,
                     },
                 },
                 VehicleTour {
@@ -8,6 +8,7 @@ fn create_test_problem() -> Problem {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -20,6 +21,7 @@ fn create_test_statistic() -> Statistic {
         distance: 4,
         duration: 6,
         times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+        value: None,
     }
 }
 
@@ -51,6 +53,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         location: None,
                         time: None,
                         job_tag: None,
+                        time_window: None,
+                        crew_member: None,
                     }],
                 },
                 Stop {
@@ -64,6 +68,8 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         location: Some(vec![2., 0.].to_loc()),
                         time: None,
                         job_tag: None,
+                        time_window: None,
+                        crew_member: None,
                     }],
                 },
                 create_stop_with_activity(
@@ -173,3 +179,24 @@ fn can_check_solution_statistic() {
         ))
     );
 }
+
+#[test]
+fn can_use_percentile_travel_times_when_lateness_risk_is_minimized() {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![Objective::MinimizeLatenessRisk { probability: 0.9 }],
+            secondary: None,
+            weights: None,
+        }),
+        ..create_test_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let matrix = Matrix { travel_time_deviations: Some(vec![5; matrix.travel_times.len()]), ..matrix };
+    let expected = matrix.travel_times.iter().map(|duration| duration + 6).collect::<Vec<_>>();
+    let solution = create_test_solution(create_test_statistic(), &[(1., 1), (3., 2), (6., 4)]);
+    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix.clone()]), solution);
+
+    let result = get_travel_times(&ctx, &matrix);
+
+    assert_eq!(result, Ok(expected));
+}
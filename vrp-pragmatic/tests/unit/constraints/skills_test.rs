@@ -1,33 +1,49 @@
-use crate::constraints::{JobSkills, SkillsModule};
+use crate::constraints::{JobSkills, SkillIndex, SkillSet, SkillsModule};
 use crate::extensions::create_typed_actor_groups;
 use crate::helpers::*;
-use hashbrown::HashSet;
-use std::iter::FromIterator;
 use std::sync::Arc;
-use vrp_core::construction::constraints::{ConstraintPipeline, RouteConstraintViolation};
+use vrp_core::construction::constraints::{ConstraintPipeline, RouteConstraintViolation, ROUTE_PENALTY_KEY};
 use vrp_core::construction::heuristics::{RouteContext, RouteState};
+use vrp_core::models::common::Cost;
 use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::{Fleet, Job, Vehicle};
+use vrp_core::models::problem::{Fleet, Job, Single, Vehicle};
+
+fn create_skill_set(skills: Vec<&str>) -> SkillSet {
+    let all = vec!["s1".to_string(), "s2".to_string(), "s3".to_string()];
+    let skills = skills.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    SkillIndex::new(all.iter()).create_set(skills.iter())
+}
 
 fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>, none_of: Option<Vec<&str>>) -> Job {
     let mut single = create_single_with_location(None);
     single.dimens.set_value(
         "skills",
         JobSkills {
-            all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
-            one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
-            none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+            all_of: all_of.map(create_skill_set),
+            one_of: one_of.map(create_skill_set),
+            none_of: none_of.map(create_skill_set),
+            preferred: None,
         },
     );
 
     Job::Single(Arc::new(single))
 }
 
+fn create_job_with_preferred_skills(preferred: Vec<(usize, Cost)>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(0));
+    single
+        .dimens
+        .set_value("skills", JobSkills { all_of: None, one_of: None, none_of: None, preferred: Some(preferred) });
+
+    Arc::new(single)
+}
+
 fn create_vehicle_with_skills(skills: Option<Vec<&str>>) -> Vehicle {
     let mut vehicle = test_vehicle("v1");
 
     if let Some(skills) = skills {
-        vehicle.dimens.set_value("skills", HashSet::<String>::from_iter(skills.iter().map(|s| s.to_string())));
+        vehicle.dimens.set_value("skills", create_skill_set(skills));
     }
 
     vehicle
@@ -99,3 +115,85 @@ fn can_check_skills_impl(
 
     assert_eq!(actual, expected)
 }
+
+parameterized_test! {can_estimate_preferred_skills, (preferred, vehicle_skills, expected), {
+    can_estimate_preferred_skills_impl(preferred, vehicle_skills, expected);
+}}
+
+can_estimate_preferred_skills! {
+    case01_no_vehicle_skills: (vec![(0, 10.)], None, 10.),
+    case02_has_skill: (vec![(0, 10.)], Some(vec!["s1"]), 0.),
+    case03_missing_skill: (vec![(0, 10.)], Some(vec!["s2"]), 10.),
+    case04_partially_missing: (vec![(0, 10.), (1, 5.)], Some(vec!["s2", "s3"]), 10.),
+    case05_all_missing: (vec![(0, 10.), (1, 5.)], Some(vec!["s3"]), 15.),
+}
+
+fn can_estimate_preferred_skills_impl(
+    preferred: Vec<(usize, Cost)>,
+    vehicle_skills: Option<Vec<&str>>,
+    expected: Cost,
+) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(create_vehicle_with_skills(vehicle_skills))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let job = Job::Single(create_job_with_preferred_skills(preferred));
+
+    let pipeline = ConstraintPipeline::default().add_module(Box::new(SkillsModule::new(0)));
+
+    assert_eq!(pipeline.evaluate_hard_route(&create_solution_context_for_fleet(&fleet), &route_ctx, &job), None);
+    assert_eq!(pipeline.evaluate_soft_route(&create_solution_context_for_fleet(&fleet), &route_ctx, &job), expected);
+}
+
+#[test]
+fn can_add_preferred_skills_penalty_to_route_state() {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(create_vehicle_with_skills(Some(vec!["s2"])))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let single = create_job_with_preferred_skills(vec![(0, 10.), (1, 5.)]);
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![create_activity_with_job_at_location(single, 0)])),
+        Arc::new(RouteState::default()),
+    );
+
+    ConstraintPipeline::default().add_module(Box::new(SkillsModule::new(0))).accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<Cost>(ROUTE_PENALTY_KEY).cloned(), Some(10.));
+}
+
+parameterized_test! {can_match_skill_sets, (left, right, expected), {
+    let left = create_skill_set(left);
+    let right = create_skill_set(right);
+
+    assert_eq!((left.is_subset(&right), left.is_disjoint(&right)), expected);
+}}
+
+can_match_skill_sets! {
+    case01: (vec![], vec![], (true, true)),
+    case02: (vec!["s1"], vec!["s1", "s2"], (true, false)),
+    case03: (vec!["s1", "s2"], vec!["s1"], (false, false)),
+    case04: (vec!["s1"], vec!["s2", "s3"], (false, true)),
+}
+
+#[test]
+fn can_use_skill_sets_with_many_skills() {
+    let names = (0..150).map(|idx| format!("s{}", idx)).collect::<Vec<_>>();
+    let index = SkillIndex::new(names.iter());
+
+    let all = index.create_set(names.iter());
+    let last = index.create_set(names.iter().skip(149));
+
+    assert!(last.is_subset(&all));
+    assert!(!all.is_subset(&last));
+    assert!(!last.is_disjoint(&all));
+    assert!(last.is_disjoint(&index.create_set(names.iter().take(149))));
+    assert!(last.contains(index.get_index("s149").unwrap()));
+    assert!(!last.contains(index.get_index("s148").unwrap()));
+}
@@ -11,6 +11,8 @@ fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size:
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        travel_time_deviations: None,
+        tolls: None,
     }
 }
 
@@ -21,6 +23,8 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        travel_time_deviations: None,
+        tolls: None,
     }
 }
 
@@ -30,7 +34,13 @@ fn create_problem(profiles: &[&str]) -> Problem {
             vehicles: vec![],
             profiles: profiles
                 .iter()
-                .map(|p| Profile { name: p.to_string(), profile_type: "car".to_string(), speed: None })
+                .map(|p| Profile {
+                    name: p.to_string(),
+                    profile_type: "car".to_string(),
+                    speed: None,
+                    distance_multiplier: None,
+                    time_bucket: None,
+                })
                 .collect(),
         },
         ..create_empty_problem()
@@ -161,3 +171,20 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+#[test]
+fn can_create_transport_costs_with_time_buckets() {
+    let mut problem = create_problem(&["car1", "car2"]);
+    problem.fleet.profiles[0].time_bucket = Some(10.);
+    let matrices = &[
+        matrix(Some("car1"), Some(0.), 1, 4),
+        matrix(Some("car2"), Some(0.), 1, 4),
+        matrix(Some("car1"), Some(20.), 3, 4),
+        matrix(Some("car2"), Some(20.), 3, 4),
+    ];
+
+    let transport = create_transport_costs(&problem, matrices).unwrap();
+
+    assert_eq!(transport.distance(0, 0, 1, 5.), 1.);
+    assert_eq!(transport.distance(1, 0, 1, 5.), 1.5);
+}
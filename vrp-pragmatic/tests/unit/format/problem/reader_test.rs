@@ -1,10 +1,8 @@
 use super::create_approx_matrices;
-use crate::constraints::JobSkills as ConstraintJobSkills;
+use crate::constraints::{JobSkills as ConstraintJobSkills, SkillIndex, SkillSet};
 use crate::format::problem::Profile as FormatProfile;
 use crate::format::problem::*;
 use crate::helpers::*;
-use hashbrown::HashSet;
-use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Jobs, Multi, Place, Single};
@@ -44,21 +42,24 @@ fn assert_demand(demand: &Demand<MultiDimLoad>, expected: &Demand<MultiDimLoad>)
     assert_eq!(demand.delivery.1.as_vec(), expected.delivery.1.as_vec());
 }
 
+fn create_expected_skills(expected: Vec<String>) -> SkillSet {
+    let all = vec!["unique".to_string(), "unique1".to_string(), "unique2".to_string()];
+    SkillIndex::new(all.iter()).create_set(expected.iter())
+}
+
 fn assert_job_skills(dimens: &Dimensions, expected: Option<Vec<String>>) {
     let skills = dimens.get("skills").and_then(|any| any.downcast_ref::<ConstraintJobSkills>());
     if let Some(expected) = expected {
-        let expected = HashSet::from_iter(expected.iter().cloned());
-        assert_eq!(skills.unwrap().all_of, Some(expected));
+        assert_eq!(skills.unwrap().all_of, Some(create_expected_skills(expected)));
     } else {
         assert!(skills.is_none());
     }
 }
 
 fn assert_vehicle_skills(dimens: &Dimensions, expected: Option<Vec<String>>) {
-    let skills = dimens.get("skills").and_then(|any| any.downcast_ref::<HashSet<String>>());
+    let skills = dimens.get("skills").and_then(|any| any.downcast_ref::<SkillSet>());
     if let Some(expected) = expected {
-        let expected = HashSet::from_iter(expected.iter().cloned());
-        assert_eq!(skills.unwrap().clone(), expected);
+        assert_eq!(skills.unwrap().clone(), create_expected_skills(expected));
     } else {
         assert!(skills.is_none());
     }
@@ -74,6 +75,7 @@ fn can_read_complex_problem() {
                     pickups: None,
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
+                            preferred_times: None,
                             times: Some(vec![
                                 vec!["1970-01-01T00:00:00Z".to_string(), "1970-01-01T00:01:40Z".to_string()],
                                 vec!["1970-01-01T00:01:50Z".to_string(), "1970-01-01T00:02:00Z".to_string()],
@@ -82,17 +84,22 @@ fn can_read_complex_problem() {
                             duration: 100.0,
                         }],
                         demand: Some(vec![0, 1]),
+                        volume: None,
+                        density: None,
                         tag: Some("my_delivery".to_string()),
                     }]),
                     replacements: None,
                     services: None,
                     priority: None,
+                    must_serve: None,
+                    truck_only: None,
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
                 },
                 Job {
                     id: "pickup_delivery_job".to_string(),
                     pickups: Some(vec![JobTask {
                         places: vec![JobPlace {
+                            preferred_times: None,
                             times: Some(vec![vec![
                                 "1970-01-01T00:00:10Z".to_string(),
                                 "1970-01-01T00:00:30Z".to_string(),
@@ -101,10 +108,13 @@ fn can_read_complex_problem() {
                             duration: 110.0,
                         }],
                         demand: Some(vec![2]),
+                        volume: None,
+                        density: None,
                         tag: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
+                            preferred_times: None,
                             times: Some(vec![vec![
                                 "1970-01-01T00:00:50Z".to_string(),
                                 "1970-01-01T00:01:00Z".to_string(),
@@ -113,11 +123,15 @@ fn can_read_complex_problem() {
                             duration: 120.0,
                         }],
                         demand: Some(vec![2]),
+                        volume: None,
+                        density: None,
                         tag: None,
                     }]),
                     replacements: None,
                     services: None,
                     priority: None,
+                    must_serve: None,
+                    truck_only: None,
                     skills: None,
                 },
                 Job {
@@ -125,6 +139,7 @@ fn can_read_complex_problem() {
 
                     pickups: Some(vec![JobTask {
                         places: vec![JobPlace {
+                            preferred_times: None,
                             times: Some(vec![vec![
                                 "1970-01-01T00:00:10Z".to_string(),
                                 "1970-01-01T00:01:10Z".to_string(),
@@ -133,28 +148,34 @@ fn can_read_complex_problem() {
                             duration: 90.0,
                         }],
                         demand: Some(vec![3]),
+                        volume: None,
+                        density: None,
                         tag: None,
                     }]),
                     deliveries: None,
                     replacements: None,
                     services: None,
                     priority: None,
+                    must_serve: None,
+                    truck_only: None,
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
                 },
             ],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., zone_fees: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: None,
                         location: vec![52.4862, 13.45148].to_loc(),
+                        driver: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
@@ -169,16 +190,25 @@ fn can_read_complex_problem() {
                         ]),
                         duration: 100.0,
                         locations: Some(vec![vec![52.48315, 13.4330].to_loc()]),
+                        policy: None,
                     }]),
+                    unavailability: None,
                     reloads: None,
                 }],
                 capacity: vec![10, 1],
+                volume: None,
+                trailer: None,
+                team: None,
+                crew_size: None,
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
                 limits: Some(VehicleLimits {
                     max_distance: Some(123.1),
                     shift_time: Some(100.),
                     tour_size: Some(3),
+                    max_radius: None,
+                    overload: None,
                     allowed_areas: None,
+                    overtime: None,
                 }),
             }],
             profiles: create_default_profiles(),
@@ -191,6 +221,8 @@ fn can_read_complex_problem() {
         travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
         distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
         error_codes: Option::None,
+        travel_time_deviations: None,
+        tolls: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -290,6 +322,21 @@ fn can_deserialize_minimal_problem_and_matrix() {
     );
 }
 
+fn create_profile(
+    name: &str,
+    profile_type: &str,
+    speed: Option<f64>,
+    distance_multiplier: Option<f64>,
+) -> FormatProfile {
+    FormatProfile {
+        name: name.to_string(),
+        profile_type: profile_type.to_string(),
+        speed,
+        distance_multiplier,
+        time_bucket: None,
+    }
+}
+
 #[test]
 fn can_create_approximation_matrices() {
     let problem = Problem {
@@ -299,29 +346,41 @@ fn can_create_approximation_matrices() {
                 create_delivery_job("job2", vec![52.5165, 13.3808]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                FormatProfile { name: "car1".to_string(), profile_type: "car".to_string(), speed: Some(8.) },
-                FormatProfile { name: "car2".to_string(), profile_type: "car".to_string(), speed: Some(10.) },
-                FormatProfile { name: "car3".to_string(), profile_type: "car".to_string(), speed: Some(5.) },
-                FormatProfile { name: "car4".to_string(), profile_type: "car".to_string(), speed: None },
+                create_profile("car1", "car", Some(8.), None),
+                create_profile("car2", "car", Some(10.), None),
+                create_profile("car3", "car", Some(5.), None),
+                create_profile("car4", "car", None, None),
+                create_profile("bike", "bike", None, None),
+                create_profile("foot", "foot", None, None),
+                create_profile("courier", "bike", None, Some(1.2)),
             ],
         },
         ..create_empty_problem()
     };
 
     let matrices = create_approx_matrices(&problem);
-    assert_eq!(matrices.len(), 4);
+    assert_eq!(matrices.len(), 7);
 
-    for &(profile, duration) in &[("car1", 635), ("car2", 508), ("car3", 1016), ("car4", 508)] {
+    for &(profile, duration, distance) in &[
+        ("car1", 635, 5078),
+        ("car2", 508, 5078),
+        ("car3", 1016, 5078),
+        ("car4", 508, 5078),
+        ("bike", 1209, 5078),
+        ("foot", 3627, 5078),
+        ("courier", 1451, 6094),
+    ] {
         let matrix = matrices.iter().find(|m| m.profile.as_ref().unwrap().as_str() == profile).unwrap();
 
         assert!(matrix.error_codes.is_none());
         assert!(matrix.timestamp.is_none());
 
-        assert_eq!(matrix.distances, &[0, 5078, 5078, 0]);
+        assert_eq!(matrix.distances, &[0, distance, distance, 0]);
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
@@ -0,0 +1,59 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_task_with_volume(demand: Option<Vec<i32>>, volume: Option<i32>, density: Option<f64>) -> JobTask {
+    JobTask { demand, volume, density, ..create_task(vec![1., 0.]) }
+}
+
+fn create_problem_with_volume(vehicle_volume: Option<i32>, task: JobTask) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![Job { deliveries: Some(vec![task]), ..create_job("job1") }],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { volume: vehicle_volume, ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_get_volume_dimension, (vehicle_volume, task, expected), {
+    let problem = create_problem_with_volume(vehicle_volume, task);
+
+    assert_eq!(get_volume_dimension(&problem), expected);
+}}
+
+can_get_volume_dimension! {
+    case01_no_volume: (None, create_task_with_volume(Some(vec![1]), None, None), None),
+    case02_vehicle_volume: (Some(10), create_task_with_volume(Some(vec![1]), None, None), Some(1)),
+    case03_job_volume: (None, create_task_with_volume(Some(vec![1, 2]), Some(3), None), Some(2)),
+    case04_job_density: (None, create_task_with_volume(Some(vec![1]), None, Some(2.)), Some(1)),
+}
+
+parameterized_test! {can_get_task_demand, (task, volume_dimension, expected), {
+    assert_eq!(get_task_demand(&task, volume_dimension), expected);
+}}
+
+can_get_task_demand! {
+    case01_no_volume: (create_task_with_volume(Some(vec![3]), Some(2), None), None, Some(vec![3])),
+    case02_volume: (create_task_with_volume(Some(vec![3]), Some(2), None), Some(1), Some(vec![3, 2])),
+    case03_density: (create_task_with_volume(Some(vec![3]), None, Some(2.)), Some(1), Some(vec![3, 2])),
+    case04_volume_and_density: (create_task_with_volume(Some(vec![3]), Some(5), Some(2.)), Some(1), Some(vec![3, 5])),
+    case05_no_job_volume: (create_task_with_volume(Some(vec![3]), None, None), Some(1), Some(vec![3, 0])),
+    case06_padding: (create_task_with_volume(Some(vec![3]), Some(1), None), Some(2), Some(vec![3, 0, 1])),
+    case07_no_demand: (create_task_with_volume(None, None, None), Some(1), None),
+}
+
+#[test]
+fn can_get_vehicle_capacity() {
+    let vehicle = VehicleType { volume: Some(20), ..create_vehicle_with_capacity("v1", vec![10]) };
+    let unlimited = create_vehicle_with_capacity("v2", vec![10]);
+
+    assert_eq!(get_vehicle_capacity(&vehicle, None), vec![10]);
+    assert_eq!(get_vehicle_capacity(&vehicle, Some(1)), vec![10, 20]);
+    assert_eq!(get_vehicle_capacity(&unlimited, Some(1)), vec![10, i32::MAX]);
+}
@@ -0,0 +1,77 @@
+use super::{check_must_serve_jobs, explain_unassigned_jobs};
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_explain_unassigned_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job_with_skills("job3", vec![3., 0.], all_of_skills(vec!["unknown".to_string()])),
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let (solution, _, _) = Builder::new(problem.clone(), Arc::new(Environment::default()))
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+
+    let explanations = explain_unassigned_jobs(problem, &solution);
+
+    assert_eq!(explanations.len(), 1);
+    assert_eq!(explanations[0].job_id, "job3");
+    let relaxation = explanations[0].relaxation.clone().expect("relaxation is expected");
+    assert_eq!(relaxation.vehicle_id, "my_vehicle_1");
+    assert_eq!(
+        relaxation.reasons.into_iter().map(|reason| reason.code).collect::<Vec<_>>(),
+        vec!["CAPACITY_CONSTRAINT".to_string(), "SKILL_CONSTRAINT".to_string()]
+    );
+}
+
+#[test]
+fn can_report_unassigned_must_serve_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job {
+                    must_serve: Some(true),
+                    ..create_delivery_job_with_skills("job2", vec![2., 0.], all_of_skills(vec!["unknown".to_string()]))
+                },
+            ],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let (solution, _, _) = Builder::new(problem.clone(), Arc::new(Environment::default()))
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap()
+        .solve()
+        .unwrap();
+
+    let error = check_must_serve_jobs(problem, &solution).expect_err("must serve error is expected");
+
+    assert_eq!(error.code, "E0005");
+    assert!(error.action.contains("job2: [SKILL_CONSTRAINT]"));
+}
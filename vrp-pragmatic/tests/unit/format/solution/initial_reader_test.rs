@@ -20,6 +20,7 @@ fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
                 create_pickup_job("job3", vec![5., 0.]),
             ],
             relations: None,
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -38,6 +39,7 @@ fn create_default_breaks() -> Option<Vec<VehicleBreak>> {
         time: VehicleBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
         duration: 2.0,
         locations: None,
+        policy: None,
     }])
 }
 
@@ -85,6 +87,7 @@ fn can_read_basic_init_solution() {
             distance: 8,
             duration: 14,
             times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 2 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -134,6 +137,8 @@ fn can_read_basic_init_solution() {
                                 end: "1970-01-01T00:00:06Z".to_string(),
                             }),
                             job_tag: Some("p2".to_owned()),
+                            time_window: None,
+                            crew_member: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
@@ -144,6 +149,8 @@ fn can_read_basic_init_solution() {
                                 end: "1970-01-01T00:00:08Z".to_string(),
                             }),
                             job_tag: None,
+                            time_window: None,
+                            crew_member: None,
                         },
                     ],
                 },
@@ -170,6 +177,7 @@ fn can_read_basic_init_solution() {
                 distance: 8,
                 duration: 14,
                 times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 2 },
+                value: None,
             },
         }],
         unassigned: create_unassigned_jobs(&["job3"]),
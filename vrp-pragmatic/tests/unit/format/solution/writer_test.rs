@@ -8,6 +8,7 @@ fn can_create_solution() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -24,6 +25,7 @@ fn can_create_solution() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -68,6 +70,7 @@ fn can_create_solution() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -81,6 +84,7 @@ fn can_merge_activities_in_one_stop() {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: Option::None,
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
         ..create_empty_problem()
@@ -96,6 +100,7 @@ fn can_merge_activities_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+            value: None,
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -15,7 +15,7 @@ fn can_calculate_distance_between_two_locations() {
     let l1 = Location::Coordinate { lat: 52.52599, lng: 13.45413 };
     let l2 = Location::Coordinate { lat: 52.5165, lng: 13.3808 };
 
-    let distance = get_distance(&l1, &l2);
+    let distance = get_haversine_distance(&l1, &l2);
 
     assert_eq!(distance.round(), 5078.);
 }
@@ -24,7 +24,7 @@ fn can_calculate_distance_between_two_locations() {
 fn can_use_approximated_with_matrix_costs() {
     let locations = get_test_locations();
     let speed = 10.;
-    let approx_data = get_approx_transportation(&locations, &[speed]);
+    let approx_data = get_approx_transportation(&locations, &[(speed, 1.)]);
     assert_eq!(approx_data.len(), 1);
 
     let (durations, distances) = approx_data.first().unwrap();
@@ -42,3 +42,15 @@ fn can_use_approximated_with_matrix_costs() {
         assert_eq!(duration.round(), (distance / speed).round());
     });
 }
+
+#[test]
+fn can_apply_distance_multiplier() {
+    let locations = get_test_locations();
+    let approx_data = get_approx_transportation(&locations, &[(10., 1.), (10., 1.5)]);
+    assert_eq!(approx_data.len(), 2);
+
+    let (durations, distances) = approx_data.last().unwrap();
+
+    assert_eq!(distances.as_slice(), &[0, 4571, 7617, 4571, 0, 3083, 7617, 3083, 0]);
+    assert_eq!(durations.as_slice(), &[0, 457, 762, 457, 0, 308, 762, 308, 0]);
+}
@@ -21,7 +21,11 @@ can_detect_reserved_ids! {
 
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])],
+            relations: None,
+            clustering: None,
+        },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
     };
@@ -46,9 +50,12 @@ fn can_detect_empty_job() {
                 replacements: None,
                 services: None,
                 priority: None,
+                must_serve: None,
+                truck_only: None,
                 skills: None,
             }],
             relations: None,
+            clustering: None,
         },
         ..create_empty_problem()
     };
@@ -61,7 +68,11 @@ fn can_detect_empty_job() {
 #[test]
 fn can_detect_negative_duration() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], -10.)], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_duration("job1", vec![1., 0.], -10.)],
+            relations: None,
+            clustering: None,
+        },
         ..create_empty_problem()
     };
 
@@ -73,7 +84,11 @@ fn can_detect_negative_duration() {
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])],
+            relations: None,
+            clustering: None,
+        },
         ..create_empty_problem()
     };
 
@@ -81,3 +96,187 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_volume, (demand, volume, density, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask { demand, volume, density, ..create_task(vec![1., 0.]) }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+            clustering: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_invalid_volume(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_volume! {
+    case01: (Some(vec![1]), Some(1), Some(2.), None),
+    case02: (Some(vec![1]), Some(-1), None, Some("E1108".to_string())),
+    case03: (Some(vec![1]), None, Some(0.), Some("E1108".to_string())),
+    case04: (None, None, Some(1.), Some("E1108".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_preferred_times, (preferred_times, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace { preferred_times: convert_times(&preferred_times), ..create_job_place(vec![1., 0.]) }],
+                    ..create_task(vec![1., 0.])
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+            clustering: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1109_preferred_time_window_correctness(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_preferred_times! {
+    case01: (vec![(0, 10), (20, 30)], None),
+    case02: (vec![(10, 0)], Some("E1109".to_string())),
+    case03: (vec![(0, 20), (10, 30)], Some("E1109".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_loading_policy, (has_pickup, has_delivery, expected), {
+    let location = vec![1., 0.];
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                pickups: if has_pickup { Some(vec![create_task(location.clone())]) } else { None },
+                deliveries: if has_delivery { Some(vec![create_task(location.clone())]) } else { None },
+                loading_policy: Some(JobLoadingPolicy::Lifo),
+                ..create_job("job1")
+            }],
+            relations: None,
+            clustering: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_loading_policy_correctness(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_loading_policy! {
+    case01: (true, true, None),
+    case02: (false, true, Some("E1110".to_string())),
+    case03: (true, false, Some("E1110".to_string())),
+}
+
+parameterized_test! {can_detect_negative_preferred_skills_penalty, (penalty, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                skills: Some(JobSkills {
+                    all_of: None,
+                    one_of: None,
+                    none_of: None,
+                    preferred: Some(vec![JobPreferredSkill { name: "skill".to_string(), penalty }]),
+                }),
+                ..create_delivery_job("job1", vec![1., 0.])
+            }],
+            relations: None,
+            clustering: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1111_preferred_skills_penalty(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_negative_preferred_skills_penalty! {
+    case01: (0., None),
+    case02: (10., None),
+    case03: (-1., Some("E1111".to_string())),
+}
+
+parameterized_test! {can_detect_negative_value, (value, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { value, ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+            clustering: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1112_negative_value(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_negative_value! {
+    case01: (None, None),
+    case02: (Some(10.), None),
+    case03: (Some(-1.), Some("E1112".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_compatibility, (group, incompatible, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                compatibility: Some(JobCompatibility {
+                    group: group.to_string(),
+                    incompatible: incompatible.into_iter().map(|group: &str| group.to_string()).collect(),
+                    policy: None,
+                }),
+                ..create_delivery_job("job1", vec![1., 0.])
+            }],
+            relations: None,
+            clustering: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1113_compatibility_correctness(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_compatibility! {
+    case01: ("food", vec!["chemicals"], None),
+    case02: ("", vec!["chemicals"], Some("E1113".to_string())),
+    case03: ("food", vec!["food", "chemicals"], Some("E1113".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_clustering, (profile, duration, max_jobs, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.])],
+            relations: None,
+            clustering: Some(Clustering::Vicinity {
+                profile: profile.to_string(),
+                threshold: VicinityThresholdPolicy { duration, distance: 10., max_jobs_per_cluster: max_jobs },
+                visiting: VicinityVisitPolicy::ServeInPlace,
+            }),
+        },
+        fleet: Fleet { vehicles: vec![], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1114_clustering_correctness(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_clustering! {
+    case01: ("car", 10., Some(2), None),
+    case02: ("truck", 10., None, Some("E1114".to_string())),
+    case03: ("car", -1., None, Some("E1114".to_string())),
+    case04: ("car", 10., Some(1), Some("E1114".to_string())),
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::format::problem::Objective::*;
-use crate::helpers::create_empty_problem;
+use crate::helpers::{create_empty_problem, create_job};
 
 fn min_cost() -> Objective {
     MinimizeCost
@@ -25,10 +25,10 @@ parameterized_test! {can_detect_empty_objective, (objectives, expected), {
 }}
 
 can_detect_empty_objective! {
-    case01: (Some(Objectives { primary: vec![], secondary: None }), Some(())),
-    case02: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case03: (Some(Objectives { primary: vec![], secondary: Some(vec![]) }), Some(())),
-    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
+    case01: (Some(Objectives { primary: vec![], secondary: None, weights: None }), Some(())),
+    case02: (Some(Objectives { primary: vec![min_cost() ], secondary: None, weights: None }), None),
+    case03: (Some(Objectives { primary: vec![], secondary: Some(vec![]), weights: None }), Some(())),
+    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), weights: None }), None),
 }
 
 fn can_detect_empty_objective_impl(objectives: Option<Objectives>, expected: Option<()>) {
@@ -46,16 +46,18 @@ parameterized_test! {can_detect_duplicates, (objectives, expected), {
 }}
 
 can_detect_duplicates! {
-    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
-    case03: (Some(Objectives { primary: vec![min_cost()], secondary: Some(vec![min_cost() ]) }), Some("minimize-cost".to_owned())),
+    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None, weights: None }), None),
+    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), weights: None }), None),
+    case03: (Some(Objectives { primary: vec![min_cost()], secondary: Some(vec![min_cost() ]), weights: None }), Some("minimize-cost".to_owned())),
     case04: (Some(Objectives {
             primary: vec![min_cost()],
-            secondary: Some(vec![min_cost() ]) }),
+            secondary: Some(vec![min_cost() ]),
+            weights: None }),
         Some("minimize-cost".to_owned())),
     case05: (Some(Objectives {
             primary: vec![min_cost(), balance_dist(), balance_dist()],
-            secondary: Some(vec![min_cost() ]) }),
+            secondary: Some(vec![min_cost() ]),
+            weights: None }),
         Some("balance-distance,minimize-cost".to_owned())),
 }
 
@@ -74,10 +76,10 @@ parameterized_test! {can_detect_missing_cost_objective, (objectives, expected),
 }}
 
 can_detect_missing_cost_objective! {
-    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None}), None),
-    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]) }), None),
-    case03: (Some(Objectives { primary: vec![balance_dist()], secondary: None }), Some(())),
-    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![balance_dist() ]) }), Some(())),
+    case01: (Some(Objectives { primary: vec![min_cost() ], secondary: None, weights: None }), None),
+    case02: (Some(Objectives { primary: vec![], secondary: Some(vec![min_cost() ]), weights: None }), None),
+    case03: (Some(Objectives { primary: vec![balance_dist()], secondary: None, weights: None }), Some(())),
+    case04: (Some(Objectives { primary: vec![], secondary: Some(vec![balance_dist() ]), weights: None }), Some(())),
 }
 
 fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expected: Option<()>) {
@@ -89,3 +91,138 @@ fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expect
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1602".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_lateness_risk_probability, (probability, expected), {
+    can_detect_invalid_lateness_risk_probability_impl(probability, expected);
+}}
+
+can_detect_invalid_lateness_risk_probability! {
+    case01: (0.9, None),
+    case02: (0., Some(())),
+    case03: (1., Some(())),
+    case04: (-0.5, Some(())),
+}
+
+fn can_detect_invalid_lateness_risk_probability_impl(probability: f64, expected: Option<()>) {
+    let objectives =
+        Objectives { primary: vec![min_cost(), MinimizeLatenessRisk { probability }], secondary: None, weights: None };
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1603_invalid_lateness_risk_probability(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1603".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_constraint_relaxation, (relaxations, expected), {
+    can_detect_invalid_constraint_relaxation_impl(relaxations, expected);
+}}
+
+can_detect_invalid_constraint_relaxation! {
+    case01: (vec![(RelaxableConstraintType::Capacity, None), (RelaxableConstraintType::TimeWindow, Some(10.))], None),
+    case02: (vec![], Some(())),
+    case03: (vec![(RelaxableConstraintType::Capacity, None), (RelaxableConstraintType::Capacity, None)], Some(())),
+    case04: (vec![(RelaxableConstraintType::Skills, Some(-1.))], Some(())),
+}
+
+fn can_detect_invalid_constraint_relaxation_impl(
+    relaxations: Vec<(RelaxableConstraintType, Option<f64>)>,
+    expected: Option<()>,
+) {
+    let constraints = relaxations
+        .into_iter()
+        .map(|(constraint_type, weight)| ConstraintRelaxation { constraint_type, weight })
+        .collect();
+    let objectives =
+        Objectives { primary: vec![min_cost(), MinimizeViolations { constraints }], secondary: None, weights: None };
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1604_invalid_constraint_relaxation(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1604".to_string()));
+}
+
+parameterized_test! {can_detect_compact_tours_without_coordinates, (location, expected), {
+    can_detect_compact_tours_without_coordinates_impl(location, expected);
+}}
+
+can_detect_compact_tours_without_coordinates! {
+    case01: (Location::Coordinate { lat: 1., lng: 0. }, None),
+    case02: (Location::Reference { index: 0 }, Some(())),
+}
+
+fn can_detect_compact_tours_without_coordinates_impl(location: Location, expected: Option<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                services: Some(vec![JobTask {
+                    places: vec![JobPlace { location, duration: 0., times: None, preferred_times: None }],
+                    demand: None,
+                    volume: None,
+                    density: None,
+                    tag: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+            clustering: None,
+        },
+        objectives: Some(Objectives {
+            primary: vec![min_cost(), CompactTours { weight: None }],
+            secondary: None,
+            weights: None,
+        }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1605_compact_tours_without_coordinates(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1605".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_slack_reservation, (capacity, time, weight, expected), {
+    let objectives =
+        Objectives { primary: vec![min_cost(), ReserveSlack { capacity, time, weight }], secondary: None, weights: None };
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1606_invalid_slack_reservation(&objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1606".to_string()));
+}}
+
+can_detect_invalid_slack_reservation! {
+    case01: (Some(0.1), Some(0.2), None, None),
+    case02: (None, None, Some(10.), None),
+    case03: (Some(1.1), None, None, Some(())),
+    case04: (None, Some(-0.1), None, Some(())),
+    case05: (Some(0.1), None, Some(-1.), Some(())),
+}
+
+parameterized_test! {can_detect_invalid_objective_weights, (primary, secondary, expected), {
+    let objectives = Objectives {
+        primary: vec![min_cost(), balance_dist()],
+        secondary: None,
+        weights: Some(ObjectiveWeights { primary, secondary }),
+    };
+    let problem = Problem { objectives: Some(objectives), ..create_empty_problem() };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1607_invalid_objective_weights(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1607".to_string()));
+}}
+
+can_detect_invalid_objective_weights! {
+    case01: (Some(vec![1., 10.]), None, None),
+    case02: (None, None, None),
+    case03: (Some(vec![1.]), None, Some(())),
+    case04: (Some(vec![1., -1.]), None, Some(())),
+    case05: (Some(vec![1., 10.]), Some(vec![1.]), Some(())),
+}
@@ -50,6 +50,7 @@ fn can_detect_relation_errors_impl(
                 vehicle_id,
                 shift_index,
             }]),
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
@@ -95,6 +96,7 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
                 vehicle_id: "vehicle_1".to_string(),
                 shift_index: None,
             }]),
+            clustering: None,
         },
         fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
         ..create_empty_problem()
@@ -138,6 +140,7 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
                     })
                     .collect(),
             ),
+            clustering: None,
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("car"), create_default_vehicle("truck")],
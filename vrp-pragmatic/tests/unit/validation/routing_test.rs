@@ -8,8 +8,20 @@ fn can_detect_duplicates() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                Profile { name: "my_vehicle".to_string(), profile_type: "car".to_string(), speed: None },
-                Profile { name: "my_vehicle".to_string(), profile_type: "truck".to_string(), speed: None },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "car".to_string(),
+                    speed: None,
+                    distance_multiplier: None,
+                    time_bucket: None,
+                },
+                Profile {
+                    name: "my_vehicle".to_string(),
+                    profile_type: "truck".to_string(),
+                    speed: None,
+                    distance_multiplier: None,
+                    time_bucket: None,
+                },
             ],
         },
         ..create_empty_problem()
@@ -37,6 +49,7 @@ fn can_detect_mixed_locations() {
         plan: Plan {
             jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job("job2", vec![1.0, 0.])],
             relations: None,
+            clustering: None,
         },
         ..create_empty_problem()
     };
@@ -50,7 +63,7 @@ fn can_detect_mixed_locations() {
 #[test]
 fn can_detect_missing_matrix_when_indices_used() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 0)], relations: None },
+        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 0)], relations: None, clustering: None },
         ..create_empty_problem()
     };
     let ctx = ValidationContext::new(&problem, None);
@@ -63,13 +76,15 @@ fn can_detect_missing_matrix_when_indices_used() {
 #[test]
 fn can_detect_limit_areas_with_indices() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 0)], relations: None },
+        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 0)], relations: None, clustering: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 limits: Some(VehicleLimits {
                     max_distance: None,
                     shift_time: None,
                     tour_size: None,
+                    max_radius: None,
+                    overload: None,
                     allowed_areas: Some(vec![AreaLimit {
                         priority: None,
                         outer_shape: vec![
@@ -79,6 +94,7 @@ fn can_detect_limit_areas_with_indices() {
                             Location::new_coordinate(-5., 5.),
                         ],
                     }]),
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -103,6 +119,7 @@ fn can_detect_index_mismatch() {
                 create_delivery_job_with_index("job3", 2),
             ],
             relations: None,
+            clustering: None,
         },
         ..create_empty_problem()
     };
@@ -112,6 +129,8 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        travel_time_deviations: None,
+        tolls: None,
     }];
     let ctx = ValidationContext::new(&problem, Some(&matrices));
 
@@ -128,7 +147,13 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: "car".to_string(), ..create_default_vehicle_type() },
                 VehicleType { profile: "truck".to_string(), ..create_default_vehicle_type() },
             ],
-            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                distance_multiplier: None,
+                time_bucket: None,
+            }],
         },
         ..create_empty_problem()
     };
@@ -138,3 +163,174 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1506".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_travel_time_deviations, (deviations, expected), {
+    can_detect_invalid_travel_time_deviations_impl(deviations, expected);
+}}
+
+can_detect_invalid_travel_time_deviations! {
+    case01: (None, None),
+    case02: (Some(vec![0, 1, 1, 0]), None),
+    case03: (Some(vec![0, 1, 1]), Some(())),
+    case04: (Some(vec![0, -1, 1, 0]), Some(())),
+}
+
+fn can_detect_invalid_travel_time_deviations_impl(deviations: Option<Vec<i64>>, expected: Option<()>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+        travel_time_deviations: deviations,
+        tolls: None,
+    }];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1507_invalid_travel_time_deviations(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1507".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_tolls, (tolls, expected), {
+    can_detect_invalid_tolls_impl(tolls, expected);
+}}
+
+can_detect_invalid_tolls! {
+    case01: (None, None),
+    case02: (Some(vec![0., 1.5, 1., 0.]), None),
+    case03: (Some(vec![0., 1., 1.]), Some(())),
+    case04: (Some(vec![0., -1., 1., 0.]), Some(())),
+}
+
+fn can_detect_invalid_tolls_impl(tolls: Option<Vec<f64>>, expected: Option<()>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+        travel_time_deviations: None,
+        tolls,
+    }];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1508_invalid_tolls(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1508".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_zone_fees, (fee, shape_size, has_indices, expected), {
+    can_detect_invalid_zone_fees_impl(fee, shape_size, has_indices, expected);
+}}
+
+can_detect_invalid_zone_fees! {
+    case01: (10., 3, false, None),
+    case02: (-1., 3, false, Some(())),
+    case03: (10., 2, false, Some(())),
+    case04: (10., 3, true, Some(())),
+}
+
+fn can_detect_invalid_zone_fees_impl(fee: f64, shape_size: usize, has_indices: bool, expected: Option<()>) {
+    let outer_shape = (0..shape_size).map(|idx| Location::Coordinate { lat: idx as f64, lng: 0. }).collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    zone_fees: Some(vec![ZoneFee { fee, outer_shape }]),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1509_invalid_zone_fees(&ctx, (true, has_indices));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1509".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_approximation_params, (speed, distance_multiplier, time_bucket, expected), {
+    can_detect_invalid_approximation_params_impl(speed, distance_multiplier, time_bucket, expected);
+}}
+
+can_detect_invalid_approximation_params! {
+    case01: (None, None, None, None),
+    case02: (Some(5.), Some(1.3), Some(300.), None),
+    case03: (Some(0.), None, None, Some(())),
+    case04: (None, Some(-1.), None, Some(())),
+    case05: (None, None, Some(0.), Some(())),
+}
+
+fn can_detect_invalid_approximation_params_impl(
+    speed: Option<f64>,
+    distance_multiplier: Option<f64>,
+    time_bucket: Option<f64>,
+    expected: Option<()>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed,
+                distance_multiplier,
+                time_bucket,
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1510_invalid_approximation_params(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1510".to_string()));
+}
+
+parameterized_test! {can_detect_too_many_time_buckets, (time_bucket, expected), {
+    can_detect_too_many_time_buckets_impl(time_bucket, expected);
+}}
+
+can_detect_too_many_time_buckets! {
+    case01: (None, None),
+    case02: (Some(3600.), None),
+    case03: (Some(60.), None),
+    case04: (Some(1.), Some(())),
+}
+
+fn can_detect_too_many_time_buckets_impl(time_bucket: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![Profile {
+                name: "car".to_string(),
+                profile_type: "car".to_string(),
+                speed: None,
+                distance_multiplier: None,
+                time_bucket,
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let create_matrix = |timestamp: &str| Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: Some(timestamp.to_string()),
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+        travel_time_deviations: None,
+        tolls: None,
+    };
+    let matrices = vec![create_matrix("2020-07-04T00:00:00Z"), create_matrix("2020-07-04T12:00:00Z")];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+
+    let result = check_e1511_too_many_time_buckets(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1511".to_string()));
+}
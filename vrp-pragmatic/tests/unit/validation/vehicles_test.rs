@@ -31,9 +31,12 @@ fn can_detect_invalid_area_impl(allowed_shapes: Option<Vec<Vec<Location>>>, expe
                     max_distance: None,
                     shift_time: None,
                     tour_size: None,
+                    max_radius: None,
+                    overload: None,
                     allowed_areas: allowed_shapes.map(|shapes| {
                         shapes.into_iter().map(|shape| AreaLimit { priority: None, outer_shape: shape }).collect()
                     }),
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -89,3 +92,242 @@ fn can_detect_invalid_dispatch_impl(dispatch: &[(f64, (f64, f64))], expected: Op
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_negative_volume, (volume, expected), {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { volume, ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_vehicle_has_no_negative_volume(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_negative_volume! {
+    case01: (None, None),
+    case02: (Some(10), None),
+    case03: (Some(-1), Some("E1307".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_driver_start, (earliest, latest, expected), {
+    let shift = create_default_vehicle_shift();
+    let shift = VehicleShift {
+        start: ShiftStart {
+            latest,
+            driver: Some(DriverStart { earliest, location: Location::Coordinate { lat: 1., lng: 0. } }),
+            ..shift.start
+        },
+        ..shift
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1308_vehicle_driver_start_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_driver_start! {
+    case01: (format_time(0.), None, None),
+    case02: (format_time(1000.), None, None),
+    case03: (format_time(1001.), None, Some("E1308".to_string())),
+    case04: ("invalid".to_string(), None, Some("E1308".to_string())),
+    case05: (format_time(100.), Some(format_time(100.)), None),
+    case06: (format_time(101.), Some(format_time(100.)), Some("E1308".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_trailer, (trailer_capacity, duration, has_parkings, expected), {
+    let parkings = if has_parkings {
+        vec![TrailerParking { location: Location::Coordinate { lat: 1., lng: 0. }, duration, tag: None }]
+    } else {
+        vec![]
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                trailer: Some(VehicleTrailer { capacity: trailer_capacity, parkings }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_trailer_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_trailer! {
+    case01: (vec![10], 0., true, None),
+    case02: (vec![10, 5], 0., true, Some("E1309".to_string())),
+    case03: (vec![-1], 0., true, Some("E1309".to_string())),
+    case04: (vec![10], -1., true, Some("E1309".to_string())),
+    case05: (vec![10], 0., false, Some("E1309".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_unavailability, (periods, expected), {
+    let periods = periods
+        .into_iter()
+        .map(|(start, end)| VehicleUnavailability {
+            location: Location::Coordinate { lat: 1., lng: 0. },
+            time: vec![format_time(start), format_time(end)],
+            tag: None,
+        })
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { unavailability: Some(periods), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_unavailability_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_unavailability! {
+    case01: (vec![(10., 100.)], None),
+    case02: (vec![(10., 100.), (200., 300.)], None),
+    case03: (vec![(100., 10.)], Some("E1310".to_string())),
+    case04: (vec![(10., 100.), (50., 300.)], Some("E1310".to_string())),
+    case05: (vec![(900., 1100.)], Some("E1310".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_max_radius, (distance, airline, job_location, expected), {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", job_location)],
+            relations: None,
+            clustering: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    allowed_areas: None,
+                    max_radius: Some(VehicleRadius { distance, airline: Some(airline) }),
+                    overload: None,
+                    overtime: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_max_radius_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_max_radius! {
+    case01: (100., false, 0, None),
+    case02: (-1., false, 0, Some("E1311".to_string())),
+    case03: (100., true, 0, Some("E1311".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_team, (drivers, max_driving_time, expected), {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                team: Some(VehicleTeam { drivers, max_driving_time }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1312_vehicle_team_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_team! {
+    case01: (2, 3600., None),
+    case02: (0, 3600., Some("E1312".to_string())),
+    case03: (2, -1., Some("E1312".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_driving_break, (time, expected), {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak {
+                        time,
+                        duration: 2700.,
+                        locations: None,
+                        policy: Some(VehicleBreakPolicy::DrivingTime),
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1313_vehicle_driving_breaks_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_driving_break! {
+    case01: (VehicleBreakTime::TimeOffset(vec![14400., 16200.]), None),
+    case02: (VehicleBreakTime::TimeOffset(vec![16200., 14400.]), Some("E1313".to_string())),
+    case03: (VehicleBreakTime::TimeOffset(vec![-1., 16200.]), Some("E1313".to_string())),
+    case04: (VehicleBreakTime::TimeOffset(vec![14400.]), Some("E1313".to_string())),
+    case05: (VehicleBreakTime::TimeWindow(vec![format_time(0.), format_time(100.)]), Some("E1313".to_string())),
+}
+
+parameterized_test! {can_detect_invalid_overtime, (shift_time, max_time, penalty, expected), {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time,
+                    tour_size: None,
+                    max_radius: None,
+                    overload: None,
+                    allowed_areas: None,
+                    overtime: Some(VehicleOvertime { max_time, penalty }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_overtime_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}}
+
+can_detect_invalid_overtime! {
+    case01: (Some(3600.), 600., 1., None),
+    case02: (None, 600., 1., Some("E1314".to_string())),
+    case03: (Some(3600.), -1., 1., Some("E1314".to_string())),
+    case04: (Some(3600.), 600., -1., Some("E1314".to_string())),
+}
@@ -0,0 +1,85 @@
+//! Contains a registry of best known solutions for bundled benchmark instances and functionality
+//! to compare a solution with them.
+
+#[cfg(test)]
+#[path = "../tests/unit/best_known_test.rs"]
+mod best_known_test;
+
+use vrp_core::models::{Problem, Solution};
+
+const BEST_KNOWN_DATA: &str = include_str!("../data/best_known.txt");
+
+/// Represents a best known solution of benchmark instance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestKnownSolution {
+    /// Amount of used vehicles.
+    pub vehicles: usize,
+    /// Total distance.
+    pub distance: f64,
+}
+
+/// Represents a gap between a solution and the best known one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolutionGap {
+    /// Difference in amount of used vehicles, positive value means that more vehicles are used.
+    pub vehicles: i64,
+    /// Relative difference in total distance in percents, positive value means that the solution is worse.
+    pub distance: f64,
+}
+
+impl BestKnownSolution {
+    /// Calculates a gap between the best known solution and the solution with given vehicles amount and distance.
+    pub fn get_gap(&self, vehicles: usize, distance: f64) -> SolutionGap {
+        SolutionGap {
+            vehicles: vehicles as i64 - self.vehicles as i64,
+            distance: (distance - self.distance) / self.distance * 100.,
+        }
+    }
+}
+
+/// Returns the best known solution for benchmark instance specified by its format (e.g. `solomon`)
+/// and instance name (e.g. `C101.100`). Instance name is case insensitive.
+pub fn get_best_known_solution(format: &str, name: &str) -> Option<BestKnownSolution> {
+    BEST_KNOWN_DATA
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            if let [line_format, line_name, vehicles, distance] = line.split_whitespace().collect::<Vec<_>>().as_slice()
+            {
+                Some((*line_format, *line_name, vehicles.parse::<usize>().ok()?, distance.parse::<f64>().ok()?))
+            } else {
+                None
+            }
+        })
+        .find(|(line_format, line_name, _, _)| *line_format == format && line_name.eq_ignore_ascii_case(name))
+        .map(|(_, _, vehicles, distance)| BestKnownSolution { vehicles, distance })
+}
+
+/// Calculates total distance of the solution using problem's transport costs.
+pub fn get_solution_distance(problem: &Problem, solution: &Solution) -> f64 {
+    solution
+        .routes
+        .iter()
+        .map(|route| {
+            let profile = route.actor.vehicle.profile;
+            route.tour.legs().fold(0., |acc, (activities, _)| match activities {
+                [from, to] => {
+                    acc + problem.transport.distance(
+                        profile,
+                        from.place.location,
+                        to.place.location,
+                        from.schedule.departure,
+                    )
+                }
+                _ => acc,
+            })
+        })
+        .sum()
+}
+
+/// Calculates a gap between the solution and the best known one if it is present in registry.
+pub fn get_solution_gap(format: &str, name: &str, problem: &Problem, solution: &Solution) -> Option<SolutionGap> {
+    get_best_known_solution(format, name)
+        .map(|best_known| best_known.get_gap(solution.routes.len(), get_solution_distance(problem, solution)))
+}
@@ -0,0 +1,89 @@
+use crate::common::read_line;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::solution::{Activity, Registry, Route, Tour};
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::Random;
+
+/// Reads solution written in text format: one route per line in form `Route N : job_id1 job_id2 ..`.
+/// All other lines are ignored. Sub jobs of multi jobs are referenced by their own ids.
+/// NOTE: Solution feasibility is not checked.
+pub(crate) fn read_init_text_solution<R: Read>(
+    mut reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, String> {
+    let mut buffer = String::new();
+
+    let mut solution = Solution {
+        registry: Registry::new(&problem.fleet, random),
+        routes: vec![],
+        unassigned: Default::default(),
+        extras: problem.extras.clone(),
+    };
+
+    let id_map = problem.jobs.all().fold(HashMap::<String, Arc<Single>>::new(), |mut acc, job| {
+        let singles = match &job {
+            Job::Single(single) => vec![single.clone()],
+            Job::Multi(multi) => multi.jobs.clone(),
+        };
+
+        singles.into_iter().filter_map(|single| single.dimens.get_id().cloned().map(|id| (id, single))).for_each(
+            |(id, single)| {
+                acc.insert(id, single);
+            },
+        );
+
+        acc
+    });
+
+    loop {
+        match read_line(&mut reader, &mut buffer) {
+            Ok(read) if read > 0 => {
+                if !buffer.trim_start().starts_with("Route") {
+                    continue;
+                }
+
+                let route: Vec<_> = buffer.split(':').collect();
+                if route.len() != 2 {
+                    return Err(format!("unexpected route line: '{}'", buffer.trim()));
+                }
+
+                let actor = solution.registry.next().next().ok_or_else(|| "not enough actors".to_string())?;
+                let mut tour = Tour::new(&actor);
+
+                route.last().unwrap().split_whitespace().try_for_each(|id| {
+                    let single = id_map.get(id).ok_or_else(|| format!("cannot find job with id '{}'", id))?;
+                    let place = single.places.first().unwrap();
+                    tour.insert_last(Activity {
+                        place: vrp_core::models::solution::Place {
+                            location: place.location.unwrap(),
+                            duration: place.duration,
+                            time: place.times.first().and_then(|span| span.as_time_window()).unwrap(),
+                        },
+                        schedule: Schedule::new(0.0, 0.0),
+                        job: Some(single.clone()),
+                    });
+
+                    Ok::<_, String>(())
+                })?;
+
+                solution.registry.use_actor(&actor);
+                solution.routes.push(Route { actor, tour });
+            }
+            Ok(_) => break,
+            Err(error) => {
+                if buffer.is_empty() {
+                    break;
+                } else {
+                    return Err(error);
+                }
+            }
+        }
+    }
+
+    Ok(solution)
+}
@@ -1,5 +1,8 @@
 //! Contains common text reading and writing functionality.
 
+mod initial_reader;
+pub(crate) use self::initial_reader::*;
+
 mod text_reader;
 pub(crate) use self::text_reader::*;
 
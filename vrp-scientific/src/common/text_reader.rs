@@ -76,6 +76,7 @@ pub(crate) fn create_fleet_with_distance_costs(
                             location,
                             time: TimeInterval { earliest: None, latest: Some(time.end) },
                         }),
+                        driver: None,
                     }],
                 })
             })
@@ -0,0 +1,193 @@
+//! Contains an experiment runner which helps to evaluate algorithmic changes: it solves benchmark instances
+//! multiple times with different seeds using two or more solver configurations and compares results
+//! using statistical significance tests.
+
+#[cfg(test)]
+#[path = "../tests/unit/experiments_test.rs"]
+mod experiments_test;
+
+use std::io::Write;
+use std::sync::Arc;
+use vrp_core::algorithms::statistics::*;
+use vrp_core::models::Problem;
+use vrp_core::solver::Builder;
+use vrp_core::utils::{DefaultRandom, Environment, Parallelism};
+
+/// A function which creates a solver builder for given problem and environment.
+pub type BuilderFunc = Arc<dyn Fn(Arc<Problem>, Arc<Environment>) -> Builder + Send + Sync>;
+
+/// Specifies solver configuration used in the experiment.
+pub struct ExperimentConfig {
+    /// A configuration name.
+    pub name: String,
+    /// A function to create solver builder.
+    pub builder_func: BuilderFunc,
+}
+
+impl ExperimentConfig {
+    /// Creates a new instance of `ExperimentConfig`.
+    pub fn new(name: &str, builder_func: BuilderFunc) -> Self {
+        Self { name: name.to_string(), builder_func }
+    }
+}
+
+/// Contains descriptive statistics of solution costs.
+#[derive(Clone, Debug)]
+pub struct CostStatistics {
+    /// Mean cost.
+    pub mean: f64,
+    /// Median cost.
+    pub median: f64,
+    /// Standard deviation of cost.
+    pub stdev: f64,
+    /// Minimum cost.
+    pub min: f64,
+    /// Maximum cost.
+    pub max: f64,
+}
+
+impl CostStatistics {
+    fn new(costs: &[f64]) -> Self {
+        Self {
+            mean: get_mean(costs),
+            median: get_median(costs),
+            stdev: get_stdev(costs),
+            min: costs.iter().cloned().fold(f64::MAX, f64::min),
+            max: costs.iter().cloned().fold(f64::MIN, f64::max),
+        }
+    }
+}
+
+/// Contains results of one configuration on one instance.
+pub struct InstanceResult {
+    /// Instance name.
+    pub instance: String,
+    /// Configuration name.
+    pub config: String,
+    /// Solution costs in the same order as seeds.
+    pub costs: Vec<f64>,
+    /// Descriptive statistics of costs.
+    pub statistics: CostStatistics,
+}
+
+/// Contains comparison of a configuration with the baseline (the first one) on one instance.
+pub struct InstanceComparison {
+    /// Instance name.
+    pub instance: String,
+    /// Baseline configuration name.
+    pub baseline: String,
+    /// Compared configuration name.
+    pub candidate: String,
+    /// Mann-Whitney U test result which treats runs as independent samples.
+    pub mann_whitney: Option<SignificanceResult>,
+    /// Wilcoxon signed-rank test result which treats runs with the same seed as paired samples.
+    pub wilcoxon: Option<SignificanceResult>,
+}
+
+/// Contains experiment results.
+pub struct ExperimentReport {
+    /// Results per instance and configuration.
+    pub results: Vec<InstanceResult>,
+    /// Comparisons of each configuration with the baseline per instance.
+    pub comparisons: Vec<InstanceComparison>,
+}
+
+impl ExperimentReport {
+    /// Writes report in csv format: a table with descriptive statistics followed by a table with
+    /// comparison results, separated by an empty line.
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> Result<(), String> {
+        let mut lines = vec!["instance,config,runs,mean,median,stdev,min,max".to_string()];
+        lines.extend(self.results.iter().map(|result| {
+            let stats = &result.statistics;
+            format!(
+                "{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3}",
+                result.instance,
+                result.config,
+                result.costs.len(),
+                stats.mean,
+                stats.median,
+                stats.stdev,
+                stats.min,
+                stats.max
+            )
+        }));
+
+        lines.push(String::new());
+        lines.push("instance,baseline,candidate,mann_whitney_p,wilcoxon_p".to_string());
+        lines.extend(self.comparisons.iter().map(|comparison| {
+            let format_p = |result: &Option<SignificanceResult>| {
+                result.as_ref().map_or("n/a".to_string(), |result| format!("{:.4}", result.p_value))
+            };
+            format!(
+                "{},{},{},{},{}",
+                comparison.instance,
+                comparison.baseline,
+                comparison.candidate,
+                format_p(&comparison.mann_whitney),
+                format_p(&comparison.wilcoxon)
+            )
+        }));
+
+        lines.into_iter().try_for_each(|line| writeln!(writer, "{}", line).map_err(|err| err.to_string()))
+    }
+}
+
+/// Runs experiment: solves each instance with each configuration once per seed. The first configuration
+/// is used as a baseline for comparison.
+/// NOTE results are reproducible only when solver configuration does not rely on time or parallelism.
+pub fn run_experiment(
+    instances: &[(String, Arc<Problem>)],
+    configs: &[ExperimentConfig],
+    seeds: &[u64],
+) -> Result<ExperimentReport, String> {
+    if configs.len() < 2 {
+        return Err("at least two configurations are required".to_string());
+    }
+
+    if seeds.is_empty() {
+        return Err("at least one seed is required".to_string());
+    }
+
+    let results = instances
+        .iter()
+        .flat_map(|(instance, problem)| configs.iter().map(move |config| (instance, problem, config)))
+        .map(|(instance, problem, config)| {
+            let costs = seeds
+                .iter()
+                .map(|seed| {
+                    let environment = Arc::new(Environment::new(
+                        Arc::new(DefaultRandom::new_with_seed(*seed)),
+                        Parallelism::default(),
+                    ));
+                    (config.builder_func)(problem.clone(), environment)
+                        .build()
+                        .and_then(|solver| solver.solve())
+                        .map(|(_, cost, _)| cost)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(InstanceResult {
+                instance: instance.clone(),
+                config: config.name.clone(),
+                statistics: CostStatistics::new(costs.as_slice()),
+                costs,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let comparisons = results
+        .chunks(configs.len())
+        .flat_map(|chunk| {
+            let baseline = chunk.first().unwrap();
+            chunk.iter().skip(1).map(move |candidate| InstanceComparison {
+                instance: baseline.instance.clone(),
+                baseline: baseline.config.clone(),
+                candidate: candidate.config.clone(),
+                mann_whitney: mann_whitney_u_test(baseline.costs.as_slice(), candidate.costs.as_slice()),
+                wilcoxon: wilcoxon_signed_rank_test(baseline.costs.as_slice(), candidate.costs.as_slice()),
+            })
+        })
+        .collect();
+
+    Ok(ExperimentReport { results, comparisons })
+}
@@ -0,0 +1,8 @@
+//! Contains functionality to read heterogeneous fleet vehicle routing problem (HFVRP), e.g. Golden or Taillard
+//! benchmark instances, and write its solution.
+
+mod reader;
+pub use self::reader::HfvrpProblem;
+
+mod writer;
+pub use self::writer::HfvrpSolution;
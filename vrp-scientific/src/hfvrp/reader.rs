@@ -0,0 +1,201 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hfvrp/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use crate::utils::CoordIndex;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::{Extras, Problem};
+
+/// A trait to read heterogeneous fleet vehicle routing problem. The expected format is the following:
+///
+/// ```text
+/// <customers number>
+/// <vehicle types number>
+/// <capacity> <fixed cost> <variable cost> <amount>
+/// ...
+/// <id> <x> <y> <demand>
+/// ...
+/// ```
+///
+/// Each vehicle type line specifies vehicle's capacity, fixed cost paid when the vehicle is used, cost per
+/// distance unit and amount of available vehicles: zero means unlimited amount (fleet size and mix problem).
+/// The first node line specifies the depot, the rest are customers.
+pub trait HfvrpProblem {
+    /// Reads hfvrp problem.
+    fn read_hfvrp(self) -> Result<Problem, String>;
+}
+
+impl<R: Read> HfvrpProblem for BufReader<R> {
+    fn read_hfvrp(self) -> Result<Problem, String> {
+        HfvrpReader { buffer: String::new(), reader: self, coord_index: CoordIndex::default(), customers: 0 }
+            .read_problem()
+    }
+}
+
+impl HfvrpProblem for String {
+    fn read_hfvrp(self) -> Result<Problem, String> {
+        BufReader::new(self.as_bytes()).read_hfvrp()
+    }
+}
+
+struct VehicleTypeLine {
+    capacity: usize,
+    fixed: f64,
+    variable: f64,
+    amount: usize,
+}
+
+struct NodeLine {
+    id: usize,
+    location: (i32, i32),
+    demand: usize,
+}
+
+struct HfvrpReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    coord_index: CoordIndex,
+    customers: usize,
+}
+
+impl<R: Read> TextReader for HfvrpReader<R> {
+    fn read_fleet(&mut self) -> Result<Fleet, String> {
+        self.customers = self.read_number("customers number")?;
+        let types = self.read_number("vehicle types number")?;
+        let vehicle_types = (0..types).map(|_| self.read_vehicle_type()).collect::<Result<Vec<_>, _>>()?;
+        let depot = self.read_node()?;
+
+        let location = self.coord_index.collect(depot.location);
+        let vehicles = vehicle_types
+            .iter()
+            .enumerate()
+            .flat_map(|(type_idx, vehicle_type)| {
+                let amount = if vehicle_type.amount == 0 { self.customers } else { vehicle_type.amount };
+                (0..amount).map(move |_| (type_idx, vehicle_type))
+            })
+            .enumerate()
+            .map(|(idx, (type_idx, vehicle_type))| {
+                let mut dimens = create_dimens_with_id("v", idx);
+                dimens.set_capacity(SingleDimLoad::new(vehicle_type.capacity as i32));
+                dimens.set_value("type_id", type_idx);
+
+                Arc::new(Vehicle {
+                    profile: 0,
+                    costs: Costs {
+                        fixed: vehicle_type.fixed,
+                        per_distance: vehicle_type.variable,
+                        per_driving_time: 0.0,
+                        per_waiting_time: 0.0,
+                        per_service_time: 0.0,
+                    },
+                    dimens,
+                    details: vec![VehicleDetail {
+                        start: Some(VehiclePlace { location, time: TimeInterval { earliest: Some(0.), latest: None } }),
+                        end: Some(VehiclePlace { location, time: TimeInterval { earliest: None, latest: None } }),
+                        driver: None,
+                    }],
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if vehicles.is_empty() {
+            return Err("no vehicles are defined".to_string());
+        }
+
+        Ok(Fleet::new(
+            vec![Arc::new(Driver {
+                costs: Costs {
+                    fixed: 0.0,
+                    per_distance: 0.0,
+                    per_driving_time: 0.0,
+                    per_waiting_time: 0.0,
+                    per_service_time: 0.0,
+                },
+                dimens: create_dimens_with_id("driver", 0),
+                details: Default::default(),
+            })],
+            vehicles,
+            Box::new(|_| Box::new(|actor| *actor.vehicle.dimens.get_value::<usize>("type_id").unwrap())),
+        ))
+    }
+
+    fn read_jobs(&mut self) -> Result<Vec<Job>, String> {
+        (0..self.customers)
+            .map(|_| {
+                let customer = self.read_node()?;
+
+                let mut dimens = create_dimens_with_id("", customer.id);
+                dimens.set_demand(Demand::<SingleDimLoad> {
+                    pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+                    delivery: (SingleDimLoad::new(customer.demand as i32), SingleDimLoad::default()),
+                });
+
+                Ok(Job::Single(Arc::new(Single {
+                    places: vec![Place {
+                        location: Some(self.coord_index.collect(customer.location)),
+                        duration: 0.,
+                        times: vec![TimeSpan::Window(TimeWindow::max())],
+                    }],
+                    dimens,
+                })))
+            })
+            .collect()
+    }
+
+    fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        self.coord_index.create_transport(false)
+    }
+
+    fn create_extras(&self) -> Extras {
+        Extras::default()
+    }
+}
+
+impl<R: Read> HfvrpReader<R> {
+    fn read_number(&mut self, name: &str) -> Result<usize, String> {
+        self.read_non_empty_line()?;
+        self.buffer.trim().parse::<usize>().map_err(|_| format!("cannot parse {}: '{}'", name, self.buffer.trim()))
+    }
+
+    fn read_vehicle_type(&mut self) -> Result<VehicleTypeLine, String> {
+        self.read_non_empty_line()?;
+        let (capacity, fixed, variable, amount) = self
+            .buffer
+            .split_whitespace()
+            .map(|value| value.parse::<f64>().ok())
+            .try_collect()
+            .and_then(|(capacity, fixed, variable, amount)| Some((capacity?, fixed?, variable?, amount?)))
+            .ok_or_else(|| format!("cannot read vehicle type line: '{}'", self.buffer.trim()))?;
+
+        Ok(VehicleTypeLine { capacity: capacity as usize, fixed, variable, amount: amount as usize })
+    }
+
+    fn read_node(&mut self) -> Result<NodeLine, String> {
+        self.read_non_empty_line()?;
+        let (id, x, y, demand) = self
+            .buffer
+            .split_whitespace()
+            .map(|value| value.parse::<i32>().ok())
+            .try_collect()
+            .and_then(|(id, x, y, demand)| Some((id?, x?, y?, demand?)))
+            .ok_or_else(|| format!("cannot read node line: '{}'", self.buffer.trim()))?;
+
+        Ok(NodeLine { id: id as usize, location: (x, y), demand: demand as usize })
+    }
+
+    fn read_non_empty_line(&mut self) -> Result<(), String> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Err("unexpected end of file".to_string());
+            }
+
+            if !self.buffer.trim().is_empty() {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::common::write_text_solution;
+use std::io::{BufWriter, Write};
+use vrp_core::models::Solution;
+
+/// A trait to write hfvrp solution.
+pub trait HfvrpSolution<W: Write> {
+    /// Writes hfvrp solution.
+    fn write_hfvrp(&self, writer: BufWriter<W>) -> Result<(), String>;
+}
+
+impl<W: Write> HfvrpSolution<W> for Solution {
+    fn write_hfvrp(&self, writer: BufWriter<W>) -> Result<(), String> {
+        let mut writer = writer;
+        write_text_solution(&mut writer, self).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+}
@@ -6,6 +6,9 @@
 //!
 //! - **solomon**: see [Solomon benchmark](https://www.sintef.no/projectweb/top/vrptw/solomon-benchmark)
 //! - **lilim**: see [Li&Lim benchmark](https://www.sintef.no/projectweb/top/pdptw/li-lim-benchmark)
+//! - **hfvrp**: heterogeneous fleet VRP instances, e.g. Golden or Taillard benchmarks
+//! - **tsplib**: a subset of [TSPLIB95](http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/) format used
+//!   by [CVRPLIB](http://vrp.atd-lab.inf.puc-rio.br/index.php/en/) instances (e.g. X-series)
 
 #![warn(missing_docs)]
 
@@ -20,7 +23,11 @@ mod known_problems_test;
 
 pub use vrp_core as core;
 
+pub mod best_known;
 pub mod common;
+pub mod experiments;
+pub mod hfvrp;
 pub mod lilim;
 pub mod solomon;
+pub mod tsplib;
 mod utils;
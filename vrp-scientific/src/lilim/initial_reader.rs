@@ -0,0 +1,20 @@
+#[cfg(test)]
+#[path = "../../tests/unit/lilim/init_solution_reader_test.rs"]
+mod init_solution_reader_test;
+
+use crate::common::read_init_text_solution;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::Random;
+
+/// Reads initial solution from a buffer. Customer ids are expected to be the same as in the problem
+/// definition, e.g. a solution produced by [`LilimSolution`](crate::lilim::LilimSolution).
+/// NOTE: Solution feasibility is not checked.
+pub fn read_init_solution<R: Read>(
+    reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, String> {
+    read_init_text_solution(reader, problem, random)
+}
@@ -104,7 +104,7 @@ impl<R: Read> TextReader for LilimReader<R> {
     }
 
     fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        self.matrix.create_transport()
+        self.matrix.create_transport(false)
     }
 
     fn create_extras(&self) -> Extras {
@@ -114,16 +114,16 @@ impl<R: Read> TextReader for LilimReader<R> {
 
 impl<R: Read> LilimReader<R> {
     fn create_single_job(&mut self, customer: &JobLine) -> Arc<Single> {
-        let mut dimens = create_dimens_with_id("c", customer.id);
+        let mut dimens = create_dimens_with_id("", customer.id);
         dimens.set_demand(if customer.demand > 0 {
             Demand::<SingleDimLoad> {
-                pickup: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand as i32)),
+                pickup: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand)),
                 delivery: (SingleDimLoad::default(), SingleDimLoad::default()),
             }
         } else {
             Demand::<SingleDimLoad> {
                 pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
-                delivery: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand as i32)),
+                delivery: (SingleDimLoad::default(), SingleDimLoad::new(-customer.demand)),
             }
         });
 
@@ -133,7 +133,7 @@ impl<R: Read> LilimReader<R> {
                 duration: customer.service as f64,
                 times: vec![TimeSpan::Window(customer.tw.clone())],
             }],
-            dimens: Default::default(),
+            dimens,
         })
     }
 
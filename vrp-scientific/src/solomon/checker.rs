@@ -0,0 +1,107 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solomon/checker_test.rs"]
+mod checker_test;
+
+use std::collections::HashSet;
+use vrp_core::models::common::*;
+use vrp_core::models::solution::Route;
+use vrp_core::models::{Problem, Solution};
+
+/// Contains solution summary in the form used to report results in the literature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolomonSummary {
+    /// Amount of used vehicles.
+    pub vehicles: usize,
+    /// Total distance rounded to two decimal places.
+    pub distance: f64,
+}
+
+/// Checks solomon solution using community's conventions: distances between customers are euclidean
+/// ones calculated with double precision without rounding, travel time equals to distance, no time window
+/// or capacity violations are tolerated. Only total distance is rounded to two decimal places.
+/// Returns solution summary if the solution is valid.
+pub fn check_solomon_solution(problem: &Problem, solution: &Solution) -> Result<SolomonSummary, String> {
+    if !solution.unassigned.is_empty() {
+        return Err(format!("solution has {} unassigned jobs", solution.unassigned.len()));
+    }
+
+    let mut served = HashSet::new();
+    for single in solution.routes.iter().flat_map(|route| route.tour.all_activities()).filter_map(|a| a.job.as_ref()) {
+        let id = single.dimens.get_id().ok_or_else(|| "job without id".to_string())?;
+        if !served.insert(id.clone()) {
+            return Err(format!("job '{}' is served more than once", id));
+        }
+    }
+
+    if served.len() != problem.jobs.size() {
+        return Err(format!("expected {} jobs to be served, got {}", problem.jobs.size(), served.len()));
+    }
+
+    let distance = solution
+        .routes
+        .iter()
+        .enumerate()
+        .try_fold(0., |acc, (idx, route)| check_route(problem, route, idx + 1).map(|d| acc + d))?;
+
+    Ok(SolomonSummary { vehicles: solution.routes.len(), distance: (distance * 100.).round() / 100. })
+}
+
+/// Checks route and returns its total distance. Violations are reported using route number as it is
+/// written in the solution: vehicles are identical, so actor assigned to the route is not relevant.
+fn check_route(problem: &Problem, route: &Route, route_number: usize) -> Result<f64, String> {
+    let actor = route.actor.as_ref();
+    let vehicle_id = actor.vehicle.dimens.get_id().cloned().unwrap_or_default();
+    let profile = actor.vehicle.profile;
+    let capacity: &SingleDimLoad =
+        actor.vehicle.dimens.get_capacity().ok_or_else(|| format!("vehicle '{}' has no capacity", vehicle_id))?;
+
+    let start = actor.detail.start.as_ref().ok_or_else(|| format!("vehicle '{}' has no start", vehicle_id))?;
+    let end = actor.detail.end.as_ref().ok_or_else(|| format!("vehicle '{}' has no end", vehicle_id))?;
+
+    let (mut location, mut time, mut distance) = (start.location, start.time.earliest.unwrap_or(0.), 0.);
+    let mut load = SingleDimLoad::default();
+
+    for single in route.tour.all_activities().filter_map(|a| a.job.as_ref()) {
+        let id = single.dimens.get_id().cloned().unwrap_or_default();
+        let place = single.places.first().ok_or_else(|| format!("job '{}' has no place", id))?;
+        let next = place.location.ok_or_else(|| format!("job '{}' has no location", id))?;
+        let tw = place
+            .times
+            .first()
+            .and_then(|span| span.as_time_window())
+            .ok_or_else(|| format!("job '{}' has no time window", id))?;
+
+        distance += problem.transport.distance(profile, location, next, time);
+        let arrival = time + problem.transport.duration(profile, location, next, time);
+        if arrival > tw.end {
+            return Err(format!(
+                "job '{}' is served too late: arrival at {}, time window end is {}",
+                id, arrival, tw.end
+            ));
+        }
+
+        let demand: &Demand<SingleDimLoad> =
+            single.dimens.get_demand().ok_or_else(|| format!("job '{}' has no demand", id))?;
+        load = load + demand.delivery.0 + demand.pickup.0;
+
+        time = arrival.max(tw.start) + place.duration;
+        location = next;
+    }
+
+    if load > *capacity {
+        return Err(format!("route {}: vehicle capacity {} is exceeded: {}", route_number, capacity.value, load.value));
+    }
+
+    distance += problem.transport.distance(profile, location, end.location, time);
+    let arrival = time + problem.transport.duration(profile, location, end.location, time);
+    if let Some(latest) = end.time.latest {
+        if arrival > latest {
+            return Err(format!(
+                "route {}: vehicle returns too late: arrival at {}, depot closes at {}",
+                route_number, arrival, latest
+            ));
+        }
+    }
+
+    Ok(distance)
+}
@@ -1,5 +1,8 @@
 //! Contains functionality to read solomon problem and write its solution.
 
+mod checker;
+pub use self::checker::{check_solomon_solution, SolomonSummary};
+
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
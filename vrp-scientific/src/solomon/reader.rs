@@ -98,7 +98,7 @@ impl<R: Read> TextReader for SolomonReader<R> {
     }
 
     fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        self.coord_index.create_transport()
+        self.coord_index.create_transport(false)
     }
 
     fn create_extras(&self) -> Extras {
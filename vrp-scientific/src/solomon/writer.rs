@@ -1,16 +1,34 @@
 use crate::common::write_text_solution;
+use crate::solomon::check_solomon_solution;
 use std::io::{BufWriter, Write};
-use vrp_core::models::Solution;
+use vrp_core::models::{Problem, Solution};
 
 /// A trait to write solomon solution.
 pub trait SolomonSolution<W: Write> {
     /// Writes solomon solution.
     fn write_solomon(&self, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Writes solomon solution followed by amount of used vehicles and total distance. The solution is
+    /// validated using community's rounding rules, see [`check_solomon_solution`].
+    fn write_solomon_with_summary(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> SolomonSolution<W> for Solution {
     fn write_solomon(&self, writer: BufWriter<W>) -> Result<(), String> {
-        write_text_solution(writer, &self).map_err(|err| err.to_string())?;
+        let mut writer = writer;
+        write_text_solution(&mut writer, self).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+
+    fn write_solomon_with_summary(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let summary = check_solomon_solution(problem, self)?;
+
+        let mut writer = writer;
+        write_text_solution(&mut writer, self).map_err(|err| err.to_string())?;
+        writer
+            .write_all(format!("Vehicles: {}\nDistance: {:.2}\n", summary.vehicles, summary.distance).as_bytes())
+            .map_err(|err| err.to_string())?;
+
         Ok(())
     }
 }
@@ -0,0 +1,7 @@
+//! Contains functionality to read tsplib95 (e.g. CVRPLIB) problem and write its solution.
+
+mod reader;
+pub use self::reader::TsplibProblem;
+
+mod writer;
+pub use self::writer::TsplibSolution;
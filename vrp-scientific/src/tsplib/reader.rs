@@ -0,0 +1,222 @@
+#[cfg(test)]
+#[path = "../../tests/unit/tsplib/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use crate::utils::CoordIndex;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::{Extras, Problem};
+
+/// A trait to read tsplib95 problem. Please note that it is very basic implementation of the format
+/// specification which supports only CVRP instances with `EUC_2D` edge weight type.
+pub trait TsplibProblem {
+    /// Reads tsplib95 problem.
+    fn read_tsplib(self) -> Result<Problem, String>;
+}
+
+impl<R: Read> TsplibProblem for BufReader<R> {
+    fn read_tsplib(self) -> Result<Problem, String> {
+        TsplibReader {
+            buffer: String::new(),
+            reader: self,
+            coord_index: CoordIndex::default(),
+            dimension: None,
+            capacity: None,
+            coordinates: Default::default(),
+            demands: Default::default(),
+            depot_id: None,
+        }
+        .read_problem()
+    }
+}
+
+impl TsplibProblem for String {
+    fn read_tsplib(self) -> Result<Problem, String> {
+        BufReader::new(self.as_bytes()).read_tsplib()
+    }
+}
+
+struct TsplibReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    coord_index: CoordIndex,
+    dimension: Option<usize>,
+    capacity: Option<usize>,
+    coordinates: HashMap<usize, (i32, i32)>,
+    demands: HashMap<usize, usize>,
+    depot_id: Option<usize>,
+}
+
+impl<R: Read> TextReader for TsplibReader<R> {
+    fn read_fleet(&mut self) -> Result<Fleet, String> {
+        self.read_specification()?;
+        self.read_sections()?;
+
+        let dimension = self.dimension.ok_or_else(|| "DIMENSION is not specified".to_string())?;
+        let capacity = self.capacity.ok_or_else(|| "CAPACITY is not specified".to_string())?;
+        let depot_id = self.depot_id.ok_or_else(|| "DEPOT_SECTION is not specified".to_string())?;
+
+        if self.coordinates.len() != dimension {
+            return Err(format!("expected {} nodes in NODE_COORD_SECTION, got {}", dimension, self.coordinates.len()));
+        }
+
+        let depot_location = self.get_location(depot_id)?;
+
+        // NOTE tsplib95 format has no information about vehicle amount, so use maximum possible
+        Ok(create_fleet_with_distance_costs(dimension - 1, capacity, depot_location, TimeWindow::max()))
+    }
+
+    fn read_jobs(&mut self) -> Result<Vec<Job>, String> {
+        let depot_id = self.depot_id.ok_or_else(|| "DEPOT_SECTION is not specified".to_string())?;
+
+        let mut ids = self.coordinates.keys().cloned().filter(|id| *id != depot_id).collect::<Vec<_>>();
+        ids.sort_unstable();
+
+        ids.into_iter()
+            .map(|id| {
+                let location = self.get_location(id)?;
+                let demand = *self.demands.get(&id).ok_or_else(|| format!("cannot find demand for node {}", id))?;
+
+                let mut dimens = create_dimens_with_id("", id);
+                dimens.set_demand(Demand::<SingleDimLoad> {
+                    pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+                    delivery: (SingleDimLoad::new(demand as i32), SingleDimLoad::default()),
+                });
+
+                Ok(Job::Single(Arc::new(Single {
+                    places: vec![Place {
+                        location: Some(location),
+                        duration: 0.,
+                        times: vec![TimeSpan::Window(TimeWindow::max())],
+                    }],
+                    dimens,
+                })))
+            })
+            .collect()
+    }
+
+    fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        // NOTE EUC_2D specification requires distances to be rounded to the nearest integer
+        self.coord_index.create_transport(true)
+    }
+
+    fn create_extras(&self) -> Extras {
+        Extras::default()
+    }
+}
+
+impl<R: Read> TsplibReader<R> {
+    fn read_specification(&mut self) -> Result<(), String> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Err("unexpected end of file while reading specification part".to_string());
+            }
+
+            let line = self.buffer.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.ends_with("_SECTION") {
+                return Ok(());
+            }
+
+            let (key, value) = match line.find(':') {
+                Some(idx) => (line[..idx].trim().to_string(), line[idx + 1..].trim().to_string()),
+                None => return Err(format!("unexpected specification line: '{}'", line)),
+            };
+
+            match key.as_str() {
+                "TYPE" if value != "CVRP" => return Err(format!("unsupported problem type: '{}'", value)),
+                "EDGE_WEIGHT_TYPE" if value != "EUC_2D" => {
+                    return Err(format!("unsupported edge weight type: '{}'", value))
+                }
+                "DIMENSION" => self.dimension = Some(parse_value(key.as_str(), value.as_str())?),
+                "CAPACITY" => self.capacity = Some(parse_value(key.as_str(), value.as_str())?),
+                _ => {}
+            }
+        }
+    }
+
+    fn read_sections(&mut self) -> Result<(), String> {
+        // NOTE the buffer contains the section header read by specification part
+        let mut section = self.buffer.trim().to_string();
+
+        loop {
+            section = match section.as_str() {
+                "NODE_COORD_SECTION" => self.read_section(|reader, line| {
+                    let (id, x, y) = line
+                        .split_whitespace()
+                        .map(|value| value.parse::<i32>().ok())
+                        .try_collect()
+                        .and_then(|(id, x, y)| Some((id?, x?, y?)))
+                        .ok_or_else(|| format!("cannot read node coord line: '{}'", line))?;
+                    reader.coordinates.insert(id as usize, (x, y));
+
+                    Ok(())
+                })?,
+                "DEMAND_SECTION" => self.read_section(|reader, line| {
+                    let (id, demand) = line
+                        .split_whitespace()
+                        .map(|value| value.parse::<usize>().ok())
+                        .try_collect()
+                        .and_then(|(id, demand)| Some((id?, demand?)))
+                        .ok_or_else(|| format!("cannot read demand line: '{}'", line))?;
+                    reader.demands.insert(id, demand);
+
+                    Ok(())
+                })?,
+                "DEPOT_SECTION" => self.read_section(|reader, line| {
+                    let id = line.parse::<i32>().map_err(|_| format!("cannot read depot line: '{}'", line))?;
+                    match (id, reader.depot_id) {
+                        (-1, _) => {}
+                        (id, None) if id > 0 => reader.depot_id = Some(id as usize),
+                        (id, None) => return Err(format!("invalid depot id: '{}'", id)),
+                        (_, Some(_)) => return Err("multiple depots are not supported".to_string()),
+                    }
+
+                    Ok(())
+                })?,
+                "EOF" | "" => return Ok(()),
+                section => return Err(format!("unsupported section: '{}'", section)),
+            };
+        }
+    }
+
+    /// Reads section lines till the next section header which is returned.
+    fn read_section<F>(&mut self, mut line_func: F) -> Result<String, String>
+    where
+        F: FnMut(&mut Self, &str) -> Result<(), String>,
+    {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Ok(String::new());
+            }
+
+            let line = self.buffer.trim().to_string();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.ends_with("_SECTION") || line == "EOF" {
+                return Ok(line);
+            }
+
+            line_func(self, line.as_str())?;
+        }
+    }
+
+    fn get_location(&mut self, id: usize) -> Result<Location, String> {
+        let coordinate = *self.coordinates.get(&id).ok_or_else(|| format!("cannot find coordinates of node {}", id))?;
+
+        Ok(self.coord_index.collect(coordinate))
+    }
+}
+
+fn parse_value(key: &str, value: &str) -> Result<usize, String> {
+    value.parse::<usize>().map_err(|_| format!("cannot parse {} value: '{}'", key, value))
+}
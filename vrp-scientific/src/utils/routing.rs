@@ -24,7 +24,7 @@ impl CoordIndex {
         }
     }
 
-    pub fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    pub fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
         let matrix_values = self
             .locations
             .iter()
@@ -32,7 +32,13 @@ impl CoordIndex {
                 self.locations.iter().map(move |&(x2, y2)| {
                     let x = x1 as f64 - x2 as f64;
                     let y = y1 as f64 - y2 as f64;
-                    (x * x + y * y).sqrt()
+                    let distance = (x * x + y * y).sqrt();
+
+                    if is_rounded {
+                        distance.round()
+                    } else {
+                        distance
+                    }
                 })
             })
             .collect::<Vec<f64>>();
@@ -0,0 +1,42 @@
+use super::*;
+use crate::helpers::*;
+use crate::solomon::read_init_solution;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_get_best_known_solution() {
+    assert_eq!(
+        get_best_known_solution("solomon", "c101.100"),
+        Some(BestKnownSolution { vehicles: 10, distance: 828.94 })
+    );
+    assert_eq!(get_best_known_solution("lilim", "LC101"), Some(BestKnownSolution { vehicles: 10, distance: 828.94 }));
+    assert_eq!(
+        get_best_known_solution("lilim", "LC1_10_2"),
+        Some(BestKnownSolution { vehicles: 90, distance: 42477.41 })
+    );
+    assert_eq!(get_best_known_solution("lilim", "C101.100"), None);
+    assert_eq!(get_best_known_solution("solomon", "unknown"), None);
+}
+
+#[test]
+fn can_calculate_gap() {
+    let best_known = BestKnownSolution { vehicles: 10, distance: 800. };
+
+    assert_eq!(best_known.get_gap(11, 840.), SolutionGap { vehicles: 1, distance: 5. });
+    assert_eq!(best_known.get_gap(9, 760.), SolutionGap { vehicles: -1, distance: -5. });
+}
+
+#[test]
+fn can_get_zero_gap_for_best_known_solution_file() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_c101_100_problem());
+    let file = get_test_resource("../../examples/data/scientific/solomon/C101.100.best.txt").unwrap();
+    let solution = read_init_solution(BufReader::new(file), problem.clone(), environment.random.clone()).unwrap();
+
+    let gap = get_solution_gap("solomon", "C101.100", problem.as_ref(), &solution).unwrap();
+
+    assert_eq!(gap.vehicles, 0);
+    assert!(gap.distance.abs() < 0.001);
+}
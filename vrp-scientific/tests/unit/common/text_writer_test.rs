@@ -40,3 +40,35 @@ fn can_write_solomon_solution() {
 
     assert_eq!(buffer, "Solution\nRoute 1: 1\n");
 }
+
+#[test]
+fn can_write_solomon_solution_with_summary() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(
+        SolomonBuilder::new()
+            .set_title("Trivial problem")
+            .set_vehicle((1, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 1, 1, 5, 1000, 5))
+            .build()
+            .read_solomon()
+            .unwrap(),
+    );
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(Elitism::new(problem.clone(), environment.random.clone(), 1, 1)),
+        environment.clone(),
+        None,
+    );
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    RecreateWithCheapest::default()
+        .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment))
+        .solution
+        .to_solution(problem.extras.clone())
+        .write_solomon_with_summary(problem.as_ref(), writer)
+        .unwrap();
+
+    assert_eq!(buffer, "Solution\nRoute 1: 1\nVehicles: 1\nDistance: 2.83\n");
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_config(name: &str, generations: usize) -> ExperimentConfig {
+    ExperimentConfig::new(
+        name,
+        Arc::new(move |problem, environment| {
+            Builder::new(problem, environment).with_max_generations(Some(generations))
+        }),
+    )
+}
+
+#[test]
+fn can_run_experiment() {
+    let instances = vec![("C101.25".to_string(), Arc::new(create_c101_25_problem()))];
+    let configs = vec![create_config("baseline", 1), create_config("candidate", 2)];
+
+    let report = run_experiment(instances.as_slice(), configs.as_slice(), &[1, 2, 3]).unwrap();
+
+    assert_eq!(report.results.len(), 2);
+    assert!(report.results.iter().all(|result| result.costs.len() == 3));
+    assert!(report.results.iter().all(|result| result.statistics.min <= result.statistics.median));
+    assert!(report.results.iter().all(|result| result.statistics.median <= result.statistics.max));
+    assert_eq!(report.comparisons.len(), 1);
+    assert_eq!(report.comparisons[0].baseline, "baseline");
+    assert_eq!(report.comparisons[0].candidate, "candidate");
+
+    let mut buffer = Vec::new();
+    report.write_csv(&mut buffer).unwrap();
+    let report = String::from_utf8(buffer).unwrap();
+    assert!(report.starts_with("instance,config,runs,mean,median,stdev,min,max\nC101.25,baseline,3,"));
+    assert!(report.contains("\n\ninstance,baseline,candidate,mann_whitney_p,wilcoxon_p\nC101.25,baseline,candidate,"));
+}
+
+#[test]
+fn can_validate_experiment_settings() {
+    let instances = vec![("C101.25".to_string(), Arc::new(create_c101_25_problem()))];
+
+    assert!(run_experiment(instances.as_slice(), &[create_config("baseline", 1)], &[1]).is_err());
+    assert!(run_experiment(instances.as_slice(), &[create_config("a", 1), create_config("b", 1)], &[]).is_err());
+}
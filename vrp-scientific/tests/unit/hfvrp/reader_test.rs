@@ -0,0 +1,59 @@
+use crate::helpers::*;
+use crate::hfvrp::HfvrpProblem;
+use vrp_core::models::common::ValueDimension;
+
+fn create_example_problem() -> String {
+    r#"
+3
+2
+10 20 1.0 1
+20 35 1.5 0
+
+0 0 0 0
+1 3 4 5
+2 0 3 7
+3 2 2 4
+"#
+    .to_string()
+}
+
+#[test]
+fn can_read_hfvrp_format() {
+    let problem = create_example_problem().read_hfvrp().unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["1", "2", "3"]);
+    assert_eq!(get_job_demands(&problem), vec![5, 7, 4]);
+    assert_eq!(problem.fleet.drivers.len(), 1);
+    assert_eq!(problem.fleet.vehicles.len(), 4);
+    assert_eq!(problem.fleet.groups.len(), 2);
+
+    let get_vehicle_type = |type_id: usize| {
+        problem
+            .fleet
+            .vehicles
+            .iter()
+            .filter(|v| *v.dimens.get_value::<usize>("type_id").unwrap() == type_id)
+            .collect::<Vec<_>>()
+    };
+    let (small, big) = (get_vehicle_type(0), get_vehicle_type(1));
+    assert_eq!(small.len(), 1);
+    assert_eq!(big.len(), 3);
+    assert_eq!((small[0].costs.fixed, small[0].costs.per_distance), (20., 1.));
+    assert_eq!((big[0].costs.fixed, big[0].costs.per_distance), (35., 1.5));
+}
+
+parameterized_test! {can_detect_invalid_format, (from, to, expected), {
+    can_detect_invalid_format_impl(from, to, expected);
+}}
+
+can_detect_invalid_format! {
+    case01: ("\n3\n", "\nthree\n", "cannot parse customers number: 'three'"),
+    case02: ("10 20 1.0 1", "10 20 1.0", "cannot read vehicle type line: '10 20 1.0'"),
+    case03: ("3 2 2 4\n", "", "unexpected end of file"),
+}
+
+fn can_detect_invalid_format_impl(from: &str, to: &str, expected: &str) {
+    let result = create_example_problem().replacen(from, to, 1).read_hfvrp().map(|_| ());
+
+    assert_eq!(result, Err(expected.to_string()));
+}
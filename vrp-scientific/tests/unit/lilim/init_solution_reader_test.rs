@@ -0,0 +1,38 @@
+use super::*;
+use crate::helpers::*;
+use crate::lilim::LilimSolution;
+use std::io::BufWriter;
+use vrp_core::algorithms::nsga2::Objective;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::ObjectiveCost;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::population::Elitism;
+use vrp_core::solver::RefinementContext;
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_write_and_read_lilim_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_lc101_problem());
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(Elitism::new(problem.clone(), environment.random.clone(), 1, 1)),
+        environment.clone(),
+        None,
+    );
+    let insertion_ctx = RecreateWithCheapest::default()
+        .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment.clone()));
+    let expected_routes = get_customer_ids_from_routes_sorted(&insertion_ctx);
+    let expected_cost = ObjectiveCost::default().fitness(&insertion_ctx);
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    insertion_ctx.solution.to_solution(problem.extras.clone()).write_lilim(writer).unwrap();
+
+    let solution = read_init_solution(BufReader::new(buffer.as_bytes()), problem.clone(), environment.random.clone())
+        .expect("cannot read initial solution");
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected_routes);
+    assert_eq!(ObjectiveCost::default().fitness(&insertion_ctx).round(), expected_cost.round());
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::helpers::*;
+use crate::solomon::{read_init_solution, SolomonProblem};
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::utils::Environment;
+
+fn read_solution(problem: Arc<Problem>, solution: &str) -> Solution {
+    let environment = Environment::default();
+    read_init_solution(BufReader::new(solution.as_bytes()), problem, environment.random).unwrap()
+}
+
+#[test]
+fn can_check_best_known_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_c101_100_problem());
+    let file = get_test_resource("../../examples/data/scientific/solomon/C101.100.best.txt").unwrap();
+    let solution = read_init_solution(BufReader::new(file), problem.clone(), environment.random.clone()).unwrap();
+
+    let summary = check_solomon_solution(problem.as_ref(), &solution);
+
+    assert_eq!(summary, Ok(SolomonSummary { vehicles: 10, distance: 828.94 }));
+}
+
+parameterized_test! {can_detect_violations, (routes, expected), {
+    can_detect_violations_impl(routes, expected);
+}}
+
+can_detect_violations! {
+    case01: ("Route 1: 1 2\n", "expected 3 jobs to be served, got 2"),
+    case02: ("Route 1: 1 2 3\nRoute 2: 2\n", "job '2' is served more than once"),
+    case03: ("Route 1: 3 2 1\n", "route 1: vehicle capacity 3 is exceeded: 4"),
+    case04: ("Route 1: 2 1 3\n", "job '3' is served too late: arrival at 8, time window end is 3"),
+    case05: ("Route 1: 3 1\nRoute 2: 2\n", "route 1: vehicle returns too late: arrival at 9, depot closes at 8"),
+}
+
+fn can_detect_violations_impl(routes: &str, expected: &str) {
+    let problem = Arc::new(
+        SolomonBuilder::new()
+            .set_vehicle((2, 3))
+            .add_customer((0, 0, 0, 0, 0, 8, 0))
+            .add_customer((1, 1, 0, 1, 0, 8, 3))
+            .add_customer((2, 2, 0, 1, 0, 8, 0))
+            .add_customer((3, 3, 0, 2, 0, 3, 0))
+            .build()
+            .read_solomon()
+            .unwrap(),
+    );
+    let solution = read_solution(problem.clone(), routes);
+
+    let result = check_solomon_solution(problem.as_ref(), &solution);
+
+    assert_eq!(result, Err(expected.to_string()));
+}
@@ -0,0 +1,71 @@
+use crate::helpers::*;
+use crate::tsplib::TsplibProblem;
+use vrp_core::models::common::Location;
+
+fn create_example_problem() -> String {
+    r#"
+NAME : example
+COMMENT : example CVRP problem
+TYPE : CVRP
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+CAPACITY : 10
+NODE_COORD_SECTION
+1 0 0
+2 3 4
+3 0 3
+4 2 2
+DEMAND_SECTION
+1 0
+2 3
+3 5
+4 2
+DEPOT_SECTION
+ 1
+ -1
+EOF
+"#
+    .to_string()
+}
+
+#[test]
+fn can_read_tsplib_format() {
+    let problem = create_example_problem().read_tsplib().unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["2", "3", "4"]);
+    assert_eq!(get_job_demands(&problem), vec![3, 5, 2]);
+    assert_eq!(get_job_durations(&problem), vec![0., 0., 0.]);
+    assert_eq!(get_vehicle_capacity(&problem), 10);
+    assert_eq!(problem.fleet.drivers.len(), 1);
+    assert_eq!(problem.fleet.vehicles.len(), 3);
+}
+
+#[test]
+fn can_use_rounded_euclidean_distances() {
+    let problem = create_example_problem().read_tsplib().unwrap();
+    let get_distance = |from: Location, to: Location| problem.transport.distance(0, from, to, 0.);
+
+    assert_eq!(get_distance(0, 1), 5.);
+    assert_eq!(get_distance(0, 2), 3.);
+    assert_eq!(get_distance(0, 3), 3.);
+    assert_eq!(get_distance(1, 3), 2.);
+}
+
+parameterized_test! {can_detect_invalid_format, (from, to, expected), {
+    can_detect_invalid_format_impl(from, to, expected);
+}}
+
+can_detect_invalid_format! {
+    case01: ("TYPE : CVRP", "TYPE : TSP", "unsupported problem type: 'TSP'"),
+    case02: ("EDGE_WEIGHT_TYPE : EUC_2D", "EDGE_WEIGHT_TYPE : GEO", "unsupported edge weight type: 'GEO'"),
+    case03: ("CAPACITY : 10\n", "", "CAPACITY is not specified"),
+    case04: ("DIMENSION : 4", "DIMENSION : 5", "expected 5 nodes in NODE_COORD_SECTION, got 4"),
+    case05: ("4 2\nDEPOT", "DEPOT", "cannot find demand for node 4"),
+    case06: ("2 3 4", "2 3.5 4", "cannot read node coord line: '2 3.5 4'"),
+}
+
+fn can_detect_invalid_format_impl(from: &str, to: &str, expected: &str) {
+    let result = create_example_problem().replacen(from, to, 1).read_tsplib().map(|_| ());
+
+    assert_eq!(result, Err(expected.to_string()));
+}